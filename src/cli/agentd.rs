@@ -0,0 +1,468 @@
+//! `dtop agentd`: a collector keeping the host connections, containers and stats
+//! history, which the TUI attaches to over a local Unix socket
+//!
+//! On attach the TUI gets every host's containers with their stats history, then the
+//! container events and stats samples as JSON lines. It starts without waiting for
+//! Docker, and its sparklines and memory forecasts cover the time the collector has been
+//! running instead of starting empty. The TUI still connects to the hosts itself, in the
+//! background, for actions, logs and shells.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::cli::config::{AgentdConfig, Config};
+use crate::core::types::{
+    AppEvent, Container, ContainerKey, ContainerStats, HealthStatus, HostId, ImagePlatform,
+};
+
+/// Live messages buffered per attached TUI before it is dropped as too slow
+#[cfg(unix)]
+const CLIENT_BUFFER: usize = 4096;
+
+/// The socket path as configured, or the default one
+pub fn socket_path(config: Option<&AgentdConfig>) -> Option<PathBuf> {
+    match config.and_then(|config| config.socket.as_deref()) {
+        Some(path) => match path.strip_prefix("~/") {
+            Some(rest) => Some(dirs::home_dir()?.join(rest)),
+            None => Some(PathBuf::from(path)),
+        },
+        // The runtime directory is private to the user
+        None => Some(
+            dirs::runtime_dir()
+                .or_else(dirs::cache_dir)?
+                .join("dtop")
+                .join("agentd.sock"),
+        ),
+    }
+}
+
+/// What the collector sends to attached TUIs, one JSON object per line
+#[derive(Debug, Serialize, Deserialize)]
+enum AgentMessage {
+    /// All containers of a host, with their stats history
+    ContainerList(HostId, Vec<Container>),
+    ContainerCreated(Box<Container>),
+    ContainerDestroyed(ContainerKey),
+    ContainerExited(ContainerKey, Option<i64>),
+    ContainerRestartCount(ContainerKey, i64),
+    ContainerPlatform(ContainerKey, ImagePlatform),
+    ContainerStat(ContainerKey, Box<ContainerStats>),
+    ContainerHealthChanged(ContainerKey, HealthStatus, DateTime<Utc>),
+    ContainerPauseChanged(ContainerKey, bool),
+    ContainerRenamed(ContainerKey, String),
+    ContainerExec(ContainerKey, String),
+    ContainerLimitsUpdated(ContainerKey, Option<u64>),
+    ConnectionError(HostId, String),
+    HostReconnecting(HostId, u32),
+    HostReconnected(HostId),
+}
+
+impl AgentMessage {
+    /// The message passing an event on to the TUI; None for events only the collector
+    /// needs
+    fn from_event(event: &AppEvent) -> Option<Self> {
+        Some(match event {
+            AppEvent::InitialContainerList(host_id, containers) => {
+                AgentMessage::ContainerList(host_id.clone(), containers.clone())
+            }
+            AppEvent::ContainerCreated(container) => {
+                AgentMessage::ContainerCreated(container.clone())
+            }
+            AppEvent::ContainerDestroyed(key) => AgentMessage::ContainerDestroyed(key.clone()),
+            AppEvent::ContainerExited(key, code) => {
+                AgentMessage::ContainerExited(key.clone(), *code)
+            }
+            AppEvent::ContainerRestartCount(key, count) => {
+                AgentMessage::ContainerRestartCount(key.clone(), *count)
+            }
+            AppEvent::ContainerPlatform(key, platform) => {
+                AgentMessage::ContainerPlatform(key.clone(), platform.clone())
+            }
+            AppEvent::ContainerStat(key, stats) => {
+                AgentMessage::ContainerStat(key.clone(), Box::new(stats.clone()))
+            }
+            AppEvent::ContainerHealthChanged(key, health, time) => {
+                AgentMessage::ContainerHealthChanged(key.clone(), health.clone(), *time)
+            }
+            AppEvent::ContainerPauseChanged(key, paused) => {
+                AgentMessage::ContainerPauseChanged(key.clone(), *paused)
+            }
+            AppEvent::ContainerRenamed(key, name) => {
+                AgentMessage::ContainerRenamed(key.clone(), name.clone())
+            }
+            AppEvent::ContainerExec(key, command) => {
+                AgentMessage::ContainerExec(key.clone(), command.clone())
+            }
+            AppEvent::ContainerLimitsUpdated(key, limit) => {
+                AgentMessage::ContainerLimitsUpdated(key.clone(), *limit)
+            }
+            AppEvent::ConnectionError(host_id, error) => {
+                AgentMessage::ConnectionError(host_id.clone(), error.clone())
+            }
+            AppEvent::HostReconnecting(host_id, attempt) => {
+                AgentMessage::HostReconnecting(host_id.clone(), *attempt)
+            }
+            AppEvent::HostReconnected(host_id) => AgentMessage::HostReconnected(host_id.clone()),
+            _ => return None,
+        })
+    }
+
+    fn into_event(self) -> AppEvent {
+        match self {
+            AgentMessage::ContainerList(host_id, containers) => {
+                AppEvent::InitialContainerList(host_id, containers)
+            }
+            AgentMessage::ContainerCreated(container) => AppEvent::ContainerCreated(container),
+            AgentMessage::ContainerDestroyed(key) => AppEvent::ContainerDestroyed(key),
+            AgentMessage::ContainerExited(key, code) => AppEvent::ContainerExited(key, code),
+            AgentMessage::ContainerRestartCount(key, count) => {
+                AppEvent::ContainerRestartCount(key, count)
+            }
+            AgentMessage::ContainerPlatform(key, platform) => {
+                AppEvent::ContainerPlatform(key, platform)
+            }
+            AgentMessage::ContainerStat(key, stats) => AppEvent::ContainerStat(key, *stats),
+            AgentMessage::ContainerHealthChanged(key, health, time) => {
+                AppEvent::ContainerHealthChanged(key, health, time)
+            }
+            AgentMessage::ContainerPauseChanged(key, paused) => {
+                AppEvent::ContainerPauseChanged(key, paused)
+            }
+            AgentMessage::ContainerRenamed(key, name) => AppEvent::ContainerRenamed(key, name),
+            AgentMessage::ContainerExec(key, command) => AppEvent::ContainerExec(key, command),
+            AgentMessage::ContainerLimitsUpdated(key, limit) => {
+                AppEvent::ContainerLimitsUpdated(key, limit)
+            }
+            AgentMessage::ConnectionError(host_id, error) => {
+                AppEvent::ConnectionError(host_id, error)
+            }
+            AgentMessage::HostReconnecting(host_id, attempt) => {
+                AppEvent::HostReconnecting(host_id, attempt)
+            }
+            AgentMessage::HostReconnected(host_id) => AppEvent::HostReconnected(host_id),
+        }
+    }
+
+    fn to_line(&self) -> Option<String> {
+        match serde_json::to_string(self) {
+            Ok(mut line) => {
+                line.push('\n');
+                Some(line)
+            }
+            Err(e) => {
+                tracing::warn!("Failed to encode agentd message: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Binds the socket so that other users can never connect to it, since container names,
+/// labels and stats are for this user only: it is created in a directory only the user
+/// can enter, restricted to the user, then moved into place
+#[cfg(unix)]
+fn bind_private(path: &std::path::Path) -> std::io::Result<tokio::net::UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(std::path::Path::new("."));
+    let private = dir.join(format!(".dtop-agentd-{}", std::process::id()));
+    // Left by an earlier collector with the same process ID
+    let _ = std::fs::remove_dir_all(&private);
+    std::fs::DirBuilder::new().mode(0o700).create(&private)?;
+
+    let staged = private.join("agentd.sock");
+    let result = tokio::net::UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_dir_all(&private);
+    result
+}
+
+/// Connects to all hosts and serves their containers and stats to attached TUIs until
+/// interrupted
+#[cfg(unix)]
+pub async fn run_agentd(config: &Config, show_all: bool) -> Result<(), Box<dyn std::error::Error>> {
+    use std::collections::{HashMap, HashSet};
+    use std::os::unix::fs::DirBuilderExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::UnixStream;
+    use tokio::sync::{broadcast, mpsc};
+
+    use crate::cli::connect::{establish_connections, spawn_remaining_connections_handler};
+    use crate::core::app_state::AppState;
+    use crate::core::types::SortField;
+    use crate::docker::connection::container_manager;
+
+    let path = socket_path(config.agentd.as_ref())
+        .ok_or("Could not determine a directory for the agentd socket, set agentd.socket")?;
+
+    // A socket left by a collector that didn't exit cleanly is replaced; a live one is not
+    if path.exists() {
+        if UnixStream::connect(&path).await.is_ok() {
+            return Err(format!("dtop agentd is already running on {}", path.display()).into());
+        }
+        std::fs::remove_file(&path)?;
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)?;
+    }
+
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(1000);
+    let connection_result = establish_connections(config, tx.clone()).await?;
+
+    let listener = bind_private(&path)?;
+    let first_host = connection_result.first_host;
+
+    let mut connected_hosts = HashMap::new();
+    connected_hosts.insert(first_host.host_id.clone(), first_host.clone());
+
+    let manager_tx = tx.clone();
+    tokio::spawn(async move {
+        container_manager(first_host, manager_tx).await;
+    });
+    spawn_remaining_connections_handler(connection_result.remaining_rx, tx.clone());
+
+    // The same event handling as the UI, just never rendered
+    let mut state = AppState::new(connected_hosts, tx, show_all, SortField::Name);
+    let (live_tx, _) = broadcast::channel::<String>(CLIENT_BUFFER);
+
+    eprintln!("dtop agentd listening on {}", path.display());
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => {
+                let Some(event) = event else { break };
+                let message = AgentMessage::from_event(&event);
+                state.handle_event(event);
+                if let Some(line) = message.as_ref().and_then(AgentMessage::to_line) {
+                    // No receivers just means no TUI is attached
+                    let _ = live_tx.send(line);
+                }
+            }
+            accepted = listener.accept() => {
+                let (mut stream, _) = match accepted {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        tracing::warn!("Failed to accept an agentd connection: {}", e);
+                        continue;
+                    }
+                };
+
+                // Subscribed before the current state is taken, so nothing falls in between
+                let mut live_rx = live_tx.subscribe();
+                let hosts: HashSet<&HostId> = state
+                    .connected_hosts
+                    .keys()
+                    .chain(state.containers.keys().map(|key| &key.host_id))
+                    .collect();
+                let greeting: String = hosts
+                    .into_iter()
+                    .filter_map(|host_id| {
+                        let containers = state
+                            .containers
+                            .values()
+                            .filter(|container| &container.host_id == host_id)
+                            .cloned()
+                            .collect();
+                        AgentMessage::ContainerList(host_id.clone(), containers).to_line()
+                    })
+                    .collect();
+
+                tokio::spawn(async move {
+                    if stream.write_all(greeting.as_bytes()).await.is_err() {
+                        return;
+                    }
+                    // A TUI too slow to keep up is dropped; it reports the collector gone
+                    while let Ok(line) = live_rx.recv().await {
+                        if stream.write_all(line.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub async fn run_agentd(
+    _config: &Config,
+    _show_all: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    Err("dtop agentd needs Unix domain sockets, which this platform doesn't have".into())
+}
+
+/// Attaches to a running collector, sending its containers and stats as events. Returns
+/// false when none is listening, and the TUI monitors the hosts itself.
+#[cfg(unix)]
+pub async fn attach(config: &AgentdConfig, tx: crate::core::types::EventSender) -> bool {
+    use std::collections::HashSet;
+    use tokio::io::{AsyncBufReadExt, BufReader};
+    use tokio::net::UnixStream;
+
+    let Some(path) = socket_path(Some(config)) else {
+        return false;
+    };
+    let stream = match UnixStream::connect(&path).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            tracing::debug!("No dtop agentd on {}: {}", path.display(), e);
+            return false;
+        }
+    };
+
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(stream).lines();
+        let mut hosts = HashSet::new();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let message = match serde_json::from_str::<AgentMessage>(&line) {
+                Ok(message) => message,
+                Err(e) => {
+                    tracing::debug!("Skipping agentd message: {}", e);
+                    continue;
+                }
+            };
+            if let AgentMessage::ContainerList(host_id, _) = &message {
+                hosts.insert(host_id.clone());
+            }
+            if tx.send(message.into_event()).await.is_err() {
+                return;
+            }
+        }
+
+        // Without the collector nothing updates anymore
+        for host_id in hosts {
+            let error = "dtop agentd stopped; restart dtop to monitor the host".to_string();
+            let _ = tx.send(AppEvent::ConnectionError(host_id, error)).await;
+        }
+    });
+    true
+}
+
+#[cfg(not(unix))]
+pub async fn attach(_config: &AgentdConfig, _tx: crate::core::types::EventSender) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_socket_path() {
+        let config = AgentdConfig {
+            socket: Some("/run/dtop.sock".to_string()),
+        };
+        assert_eq!(
+            socket_path(Some(&config)),
+            Some(PathBuf::from("/run/dtop.sock"))
+        );
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        let mut stats = ContainerStats {
+            cpu: 12.5,
+            memory_used_bytes: 1024,
+            ..Default::default()
+        };
+        stats.cpu_history.extend([10.0, 12.5]);
+
+        let event = AppEvent::ContainerStat(key.clone(), stats);
+        let line = AgentMessage::from_event(&event).unwrap().to_line().unwrap();
+        let message: AgentMessage = serde_json::from_str(line.trim_end()).unwrap();
+        let AppEvent::ContainerStat(received_key, received) = message.into_event() else {
+            panic!("expected a stats event");
+        };
+        assert_eq!(received_key, key);
+        assert_eq!(received.cpu, 12.5);
+        assert_eq!(received.memory_used_bytes, 1024);
+        assert_eq!(received.cpu_history, [10.0, 12.5]);
+
+        // UI events stay in the collector
+        assert!(AgentMessage::from_event(&AppEvent::Quit).is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_attach() {
+        use tokio::io::AsyncWriteExt;
+        use tokio::net::UnixListener;
+        use tokio::sync::mpsc;
+
+        let path = std::env::temp_dir().join(format!("dtop-agentd-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let config = AgentdConfig {
+            socket: Some(path.to_string_lossy().into_owned()),
+        };
+        let (tx, mut rx) = mpsc::channel(10);
+
+        // Nothing listening: the UI connects to the hosts itself
+        assert!(!attach(&config, tx.clone()).await);
+
+        let listener = UnixListener::bind(&path).unwrap();
+        let collector = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let line = AgentMessage::ContainerList("local".to_string(), Vec::new())
+                .to_line()
+                .unwrap();
+            stream.write_all(line.as_bytes()).await.unwrap();
+        });
+        assert!(attach(&config, tx).await);
+
+        let Some(AppEvent::InitialContainerList(host_id, containers)) = rx.recv().await else {
+            panic!("expected the container list");
+        };
+        assert_eq!(host_id, "local");
+        assert!(containers.is_empty());
+
+        // The collector went away
+        collector.await.unwrap();
+        let Some(AppEvent::ConnectionError(host_id, _)) = rx.recv().await else {
+            panic!("expected a connection error");
+        };
+        assert_eq!(host_id, "local");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_bind_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path =
+            std::env::temp_dir().join(format!("dtop-agentd-private-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let listener = bind_private(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(
+            !std::env::temp_dir()
+                .join(format!(".dtop-agentd-{}", std::process::id()))
+                .exists()
+        );
+
+        // Still reachable once moved into place
+        let client = tokio::net::UnixStream::connect(&path);
+        let (accepted, connected) = tokio::join!(listener.accept(), client);
+        assert!(accepted.is_ok() && connected.is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
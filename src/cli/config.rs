@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Configuration for a single Docker host
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -14,6 +15,25 @@ pub struct HostConfig {
     /// Optional filters for this host (e.g., ["status=running", "name=nginx"])
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<Vec<String>>,
+
+    /// Whether to show exec sessions started in this host's containers in the events timeline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_events: Option<bool>,
+
+    /// Whether to periodically check this host's data-root disk space and load with a
+    /// short-lived busybox container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_check: Option<bool>,
+
+    /// Shells tried in order for shell sessions in this host's containers
+    /// (default: bash, then sh; PowerShell, then cmd for Windows containers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shells: Option<Vec<String>>,
+
+    /// Poll one-shot stats of this host's containers instead of keeping a stream open for
+    /// each, for hosts with hundreds of containers (default: streams)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats_polling: Option<StatsPollingConfig>,
     // Future fields can be added here as optional fields
     // #[serde(skip_serializing_if = "Option::is_none")]
     // pub custom_name: Option<String>,
@@ -30,13 +50,392 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<String>,
 
+    /// Color theme (default, colorblind, high-contrast)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+
+    /// Terminal background (auto, dark, light); auto queries the terminal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+
+    /// Terminal color support (auto, truecolor, 256, 16); auto reads TERM/COLORTERM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_mode: Option<String>,
+
+    /// Selected-row highlight (background, reverse, underline); defaults to the theme's
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_style: Option<String>,
+
+    /// Shade every other row in the container list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zebra: Option<bool>,
+
+    /// Show the container ID column (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_column: Option<bool>,
+
+    /// Show a `docker ps` style Status text column (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_column: Option<bool>,
+
+    /// Show the Restarts column (default: false; always shown when sorting by restarts)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restarts_column: Option<bool>,
+
+    /// Show the writable layer Size column, fetched at startup (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_column: Option<bool>,
+
+    /// Show the image Platform column, highlighting emulated architectures (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_column: Option<bool>,
+
+    /// Show the PIDs column (default: false; always shown when sorting by PIDs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pids_column: Option<bool>,
+
+    /// Show per-host container counts and resource usage above the list (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_summary: Option<bool>,
+
+    /// Redraw less often, without sparklines and with fewer colors, for high-latency
+    /// links (default: on in SSH sessions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub low_bandwidth: Option<bool>,
+
+    /// Time between two screen refreshes in milliseconds, 100 to 10000; sparkline samples
+    /// are taken every 4 refreshes (default: 500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_ms: Option<u64>,
+
+    /// Show the event loop indicator (events/s, frames/s, render time, lag, channel
+    /// backlog) in the bottom right corner; F12 toggles it (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loop_metrics: Option<bool>,
+
+    /// Show CPU usage as a share of all the host's CPUs instead of one core, so a
+    /// container using 2 of 8 cores shows 25% rather than 200% (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_normalized: Option<bool>,
+
+    /// What the memory column shows on terminals too narrow for sparklines (percent,
+    /// used, limit; default: percent)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_display: Option<String>,
+
+    /// Show Created times as dates instead of "2 hours ago" (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub absolute_timestamps: Option<bool>,
+
+    /// chrono format for absolute timestamps (default: "%Y-%m-%d %H:%M")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_format: Option<String>,
+
+    /// Seconds a container gets to stop before it is killed on Stop and Restart (default: 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_timeout: Option<u32>,
+
+    /// Seconds a started or restarted container is watched before reporting whether it
+    /// stayed up (default: 10, 0 to turn off)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_secs: Option<u64>,
+
+    /// Times Start, Stop, Restart and Remove are tried again, with backoff, after a
+    /// transient failure such as a busy daemon (default: 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_retries: Option<u32>,
+
+    /// Containers or objects acted on at once by project actions, cleanup and volume
+    /// removal (default: 4)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_concurrency: Option<usize>,
+
+    /// Save the last log lines of a container before Stop, Restart and Remove
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_snapshots: Option<LogSnapshotsConfig>,
+
+    /// Where the file browser saves downloads (default: the user's download directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_dir: Option<String>,
+
+    /// Share container stats streams with other dtop instances on this machine, so
+    /// each container is streamed from Docker once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats_sharing: Option<StatsSharingConfig>,
+
+    /// Keep stats samples and container events in a SQLite database, for `dtop report`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_db: Option<HistoryDbConfig>,
+
+    /// Attach to a running `dtop agentd` collector instead of connecting to the hosts
+    /// for monitoring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agentd: Option<AgentdConfig>,
+
+    /// Days since it exited after which a container is suggested in the cleanup view (default: 7)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cleanup_exited_days: Option<u64>,
+
+    /// Alerts on container CPU, memory and health, shown as banners
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alerts: Option<AlertsConfig>,
+
+    /// Highlighting of stats far from each container's own recent baseline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anomalies: Option<AnomaliesConfig>,
+
+    /// When a container restarting over and over is flagged in the container list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_loop: Option<RestartLoopConfig>,
+
+    /// Periodic snapshot reports of all containers, written to a directory or POSTed to a URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reports: Option<ReportsConfig>,
+
+    /// CPU and memory budgets per label value, compared with actual usage in the grouped view
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budgets: Option<Vec<BudgetConfig>>,
+
+    /// Shells tried for shell sessions in the containers carrying a label, before the
+    /// host's shells
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shells_by_label: Option<Vec<ShellRuleConfig>>,
+
     /// Show all containers (default shows only running containers)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
 
-    /// Default sort field (uptime, name, cpu, memory)
+    /// Default sort field (uptime, name, cpu, memory, restarts, blkread, blkwrite, pids, script)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort: Option<String>,
+
+    /// UI language (en, es, de); defaults to the LANG environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /// Optional Rhai scripts for derived columns, custom sort keys, and row styles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scripts: Option<ScriptsConfig>,
+
+    /// Registry credentials for image pulls and pushes, keyed by registry host
+    /// (override the credentials from ~/.docker/config.json)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registries: Option<HashMap<String, RegistryConfig>>,
+}
+
+/// Shells for the containers carrying a label
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShellRuleConfig {
+    /// Container label as key=value
+    pub label: String,
+
+    /// Shells tried in order
+    pub shells: Vec<String>,
+}
+
+/// Credentials for a single registry
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RegistryConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// OAuth refresh token (instead of username/password)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_token: Option<String>,
+}
+
+/// How often a polled host's containers are polled, and how many at a time
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct StatsPollingConfig {
+    /// Seconds between two polls of a container (default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_secs: Option<u64>,
+
+    /// Containers polled at the same time (default: 8)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<usize>,
+}
+
+/// Where and how many log lines are saved before a container is stopped or removed
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LogSnapshotsConfig {
+    /// Directory the snapshots are written to (default: dtop/log-snapshots in the user data directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+
+    /// Number of most recent log lines saved (default: 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<usize>,
+}
+
+/// Where stats samples and container events are stored, and for how long
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct HistoryDbConfig {
+    /// Database file (default: dtop/history.db in the user data directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Days samples and events are kept (default: 7)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_days: Option<u64>,
+}
+
+/// Where the `dtop agentd` collector listens for the TUI
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AgentdConfig {
+    /// Path of the Unix socket (default: dtop/agentd.sock in the user runtime directory,
+    /// or the cache directory where there is none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+}
+
+/// Where the dtop instances on this machine share their stats streams
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StatsSharingConfig {
+    /// Directory of the leases and samples (default: dtop/stats in the user runtime directory,
+    /// or the cache directory where there is none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+}
+
+/// How far from its baseline a value must be to be highlighted, and how many samples
+/// the baseline covers
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AnomaliesConfig {
+    /// Standard deviations from the baseline mean (default: 3)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sigma: Option<f64>,
+
+    /// Stats updates in the baseline, about one per second (default: 300)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samples: Option<usize>,
+}
+
+/// How many restarts in how many minutes make a restart loop
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RestartLoopConfig {
+    /// Restarts a container must exceed to be flagged (default: 3)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restarts: Option<u32>,
+
+    /// Minutes the restarts are counted over (default: 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<u64>,
+}
+
+/// How often a snapshot report is made, in which format, and where it goes
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ReportsConfig {
+    /// Minutes between reports (default: 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_minutes: Option<u64>,
+
+    /// Report format: json or markdown (default: json)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Directory each report is written to as a new file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+
+    /// URL each report is POSTed to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Alert rules and how a firing alert is signaled besides its banner
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AlertsConfig {
+    #[serde(default)]
+    pub rules: Vec<AlertRuleConfig>,
+
+    /// Ring the terminal bell when an alert fires (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bell: Option<bool>,
+
+    /// Ask the terminal for a desktop notification (OSC 9) when an alert fires (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub desktop: Option<bool>,
+
+    /// URL a JSON description of each fired alert is POSTed to, for severities routed to it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+
+    /// How each severity (info, warning, critical) is signaled besides its banner: a list
+    /// of toast, bell, desktop and webhook. Severities not listed use bell and desktop
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing: Option<HashMap<String, Vec<String>>>,
+
+    /// Start in do-not-disturb mode, where only critical alerts are signaled (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub do_not_disturb: Option<bool>,
+}
+
+/// A single alert rule
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AlertRuleConfig {
+    /// What is watched: cpu, memory or unhealthy
+    pub metric: String,
+
+    /// Percentage the metric must exceed (cpu and memory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub above: Option<f64>,
+
+    /// Seconds the condition must hold before the alert fires (default: 0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub for_secs: Option<u64>,
+
+    /// info, warning or critical (default: warning)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+}
+
+/// Resources the running containers carrying a label are budgeted
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BudgetConfig {
+    /// Label the budget is for, as key=value (e.g. team=payments)
+    pub label: String,
+
+    /// CPUs the containers may use together
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<f64>,
+
+    /// Memory in GB the containers may use together
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_gb: Option<f64>,
+}
+
+/// Scripting hooks evaluated against each container
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ScriptsConfig {
+    /// Derived columns shown in the container list
+    #[serde(default)]
+    pub columns: Vec<ScriptColumnConfig>,
+
+    /// Script computing a custom sort key (used with `sort: script`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+
+    /// Script returning a color name for the row (empty string for default style)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_style: Option<String>,
+}
+
+/// A single derived column computed by a script
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScriptColumnConfig {
+    /// Column header text
+    pub header: String,
+
+    /// Rhai script whose result is displayed in the column
+    pub script: String,
+
+    /// Optional column width (defaults to 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
 }
 
 impl Config {
@@ -52,8 +451,7 @@ impl Config {
 
         for path in config_paths {
             if path.exists() {
-                let contents = std::fs::read_to_string(&path)?;
-                let config: Config = serde_yaml::from_str(&contents)?;
+                let config = Self::load_from(&path)?;
                 return Ok((config, Some(path)));
             }
         }
@@ -61,6 +459,38 @@ impl Config {
         Ok((Config::default(), None))
     }
 
+    /// Load the config file at the given path
+    pub fn load_from(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_yaml::from_str(&contents)?)
+    }
+
+    /// Set one top-level key of the config file to `value` (a YAML scalar), changing only
+    /// that line so comments and formatting are kept; the key is appended when missing
+    /// The file is replaced atomically, so a failed write leaves it as it was
+    pub fn save_preference(
+        path: &Path,
+        key: &str,
+        value: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let contents = std::fs::read_to_string(path)?;
+        let updated = set_top_level_key(&contents, key, value);
+
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| format!("Invalid config path: {}", path.display()))?;
+        let tmp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+        std::fs::write(&tmp_path, updated)
+            .and_then(|_| {
+                std::fs::set_permissions(&tmp_path, std::fs::metadata(path)?.permissions())
+            })
+            .and_then(|_| std::fs::rename(&tmp_path, path))
+            .inspect_err(|_| {
+                let _ = std::fs::remove_file(&tmp_path);
+            })?;
+        Ok(())
+    }
+
     /// Get list of potential config file paths in priority order
     fn get_config_paths() -> Vec<PathBuf> {
         // 1. Relative paths (current directory)
@@ -108,6 +538,10 @@ impl Config {
                     } else {
                         Some(cli_filters.clone())
                     },
+                    exec_events: None,
+                    disk_check: None,
+                    shells: None,
+                    stats_polling: None,
                 })
                 .collect();
         } else if !cli_filters.is_empty() {
@@ -135,6 +569,33 @@ impl Config {
     }
 }
 
+/// Replaces the value of a top-level `key:` line, keeping a trailing comment, or appends
+/// the key when no such line exists
+fn set_top_level_key(contents: &str, key: &str, value: &str) -> String {
+    let prefix = format!("{}:", key);
+    let mut found = false;
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|line| match line.strip_prefix(&prefix) {
+            Some(rest) if !found && (rest.is_empty() || rest.starts_with([' ', '\t'])) => {
+                found = true;
+                match rest.find(" #") {
+                    Some(comment) => format!("{} {}{}", prefix, value, &rest[comment..]),
+                    None => format!("{} {}", prefix, value),
+                }
+            }
+            _ => line.to_string(),
+        })
+        .collect();
+    if !found {
+        lines.push(format!("{} {}", prefix, value));
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,10 +613,15 @@ mod tests {
                 host: "ssh://user@server1".to_string(),
                 dozzle: None,
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None,
             sort: None,
+            ..Default::default()
         };
 
         let merged = config.merge_with_cli_hosts(
@@ -176,10 +642,15 @@ mod tests {
                 host: "ssh://user@server1".to_string(),
                 dozzle: Some("https://dozzle.example.com".to_string()),
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None,
             sort: None,
+            ..Default::default()
         };
 
         let merged =
@@ -200,6 +671,7 @@ mod tests {
             icons: None,
             all: None,
             sort: None,
+            ..Default::default()
         };
 
         let merged =
@@ -243,12 +715,69 @@ hosts:
         assert_eq!(config.hosts[1].dozzle, None);
     }
 
+    #[test]
+    fn test_yaml_deserialization_with_exec_events() {
+        let yaml = r#"
+hosts:
+  - host: ssh://user@shared
+    exec_events: true
+  - host: local
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.hosts[0].exec_events, Some(true));
+        assert_eq!(config.hosts[1].exec_events, None);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_disk_check() {
+        let yaml = r#"
+hosts:
+  - host: ssh://user@server1
+    disk_check: true
+  - host: local
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.hosts[0].disk_check, Some(true));
+        assert_eq!(config.hosts[1].disk_check, None);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_stats_polling() {
+        let yaml = r#"
+hosts:
+  - host: ssh://user@big-host
+    stats_polling:
+      interval_secs: 10
+      concurrency: 16
+  - host: ssh://user@other
+    stats_polling: {}
+  - host: local
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.hosts[0].stats_polling,
+            Some(StatsPollingConfig {
+                interval_secs: Some(10),
+                concurrency: Some(16),
+            })
+        );
+        assert_eq!(
+            config.hosts[1].stats_polling,
+            Some(StatsPollingConfig::default())
+        );
+        assert_eq!(config.hosts[2].stats_polling, None);
+    }
+
     #[test]
     fn test_host_config_without_dozzle() {
         let host = HostConfig {
             host: "local".to_string(),
             dozzle: None,
             filter: None,
+            exec_events: None,
+            disk_check: None,
+            shells: None,
+            stats_polling: None,
         };
         assert_eq!(host.host, "local");
         assert_eq!(host.dozzle, None);
@@ -261,6 +790,10 @@ hosts:
             host: "ssh://user@host".to_string(),
             dozzle: Some("https://dozzle.example.com".to_string()),
             filter: None,
+            exec_events: None,
+            disk_check: None,
+            shells: None,
+            stats_polling: None,
         };
         assert_eq!(host.host, "ssh://user@host");
         assert_eq!(host.dozzle.as_deref(), Some("https://dozzle.example.com"));
@@ -273,10 +806,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: Some(vec!["status=running".to_string()]),
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None,
             sort: None,
+            ..Default::default()
         };
 
         let cli_filters = vec!["name=nginx".to_string()];
@@ -296,10 +834,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: Some(vec!["status=running".to_string()]),
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None,
             sort: None,
+            ..Default::default()
         };
 
         let merged =
@@ -318,10 +861,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: Some(false), // Config says false
             sort: None,
+            ..Default::default()
         };
 
         let merged =
@@ -336,10 +884,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: Some(true), // Config says true
             sort: None,
+            ..Default::default()
         };
 
         let merged =
@@ -354,10 +907,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None, // No config value
             sort: None,
+            ..Default::default()
         };
 
         let merged =
@@ -372,10 +930,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None,
             sort: Some("name".to_string()), // Config says name
+            ..Default::default()
         };
 
         let merged = config.merge_with_cli_hosts(
@@ -395,10 +958,15 @@ hosts:
                 host: "local".to_string(),
                 dozzle: None,
                 filter: None,
+                exec_events: None,
+                disk_check: None,
+                shells: None,
+                stats_polling: None,
             }],
             icons: None,
             all: None,
             sort: Some("memory".to_string()), // Config says memory
+            ..Default::default()
         };
 
         let merged =
@@ -417,4 +985,391 @@ sort: cpu
         assert_eq!(config.hosts.len(), 1);
         assert_eq!(config.sort, Some("cpu".to_string()));
     }
+
+    #[test]
+    fn test_yaml_deserialization_with_scripts() {
+        let yaml = r#"
+hosts:
+  - host: local
+sort: script
+scripts:
+  columns:
+    - header: Load
+      script: cpu + memory
+      width: 8
+  sort: memory_used
+  row_style: 'if cpu > 90.0 { "red" } else { "" }'
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let scripts = config.scripts.unwrap();
+        assert_eq!(scripts.columns.len(), 1);
+        assert_eq!(scripts.columns[0].header, "Load");
+        assert_eq!(scripts.columns[0].width, Some(8));
+        assert_eq!(scripts.sort.as_deref(), Some("memory_used"));
+        assert!(scripts.row_style.is_some());
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_locale() {
+        let yaml = r#"
+hosts:
+  - host: local
+locale: de
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.locale.as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_theme() {
+        let yaml = r#"
+hosts:
+  - host: local
+theme: colorblind
+background: light
+color_mode: "16"
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.theme.as_deref(), Some("colorblind"));
+        assert_eq!(config.background.as_deref(), Some("light"));
+        assert_eq!(config.color_mode.as_deref(), Some("16"));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_row_styles() {
+        let yaml = r#"
+hosts:
+  - host: local
+selection_style: underline
+zebra: true
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.selection_style.as_deref(), Some("underline"));
+        assert_eq!(config.zebra, Some(true));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_id_column() {
+        let yaml = r#"
+hosts:
+  - host: local
+id_column: false
+status_column: true
+restarts_column: true
+size_column: true
+platform_column: true
+pids_column: true
+host_summary: false
+loop_metrics: true
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.id_column, Some(false));
+        assert_eq!(config.status_column, Some(true));
+        assert_eq!(config.restarts_column, Some(true));
+        assert_eq!(config.size_column, Some(true));
+        assert_eq!(config.platform_column, Some(true));
+        assert_eq!(config.pids_column, Some(true));
+        assert_eq!(config.host_summary, Some(false));
+        assert_eq!(config.loop_metrics, Some(true));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_registries() {
+        let yaml = r#"
+hosts:
+  - host: local
+registries:
+  ghcr.io:
+    username: bot
+    password: secret
+  registry.local:5000:
+    identity_token: refresh-token
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let registries = config.registries.unwrap();
+        assert_eq!(registries["ghcr.io"].username.as_deref(), Some("bot"));
+        assert_eq!(registries["ghcr.io"].password.as_deref(), Some("secret"));
+        assert_eq!(
+            registries["registry.local:5000"].identity_token.as_deref(),
+            Some("refresh-token")
+        );
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_timestamps() {
+        let yaml = r#"
+hosts:
+  - host: local
+absolute_timestamps: true
+timestamp_format: "%d/%m %H:%M"
+cpu_normalized: true
+memory_display: limit
+low_bandwidth: false
+refresh_ms: 1000
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.absolute_timestamps, Some(true));
+        assert_eq!(config.cpu_normalized, Some(true));
+        assert_eq!(config.memory_display.as_deref(), Some("limit"));
+        assert_eq!(config.low_bandwidth, Some(false));
+        assert_eq!(config.refresh_ms, Some(1000));
+        assert_eq!(config.timestamp_format.as_deref(), Some("%d/%m %H:%M"));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_stop_timeout() {
+        let yaml = r#"
+hosts:
+  - host: local
+stop_timeout: 60
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.stop_timeout, Some(60));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_action_retries() {
+        let yaml = r#"
+hosts:
+  - host: local
+action_retries: 3
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.action_retries, Some(3));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_bulk_concurrency() {
+        let yaml = r#"
+hosts:
+  - host: local
+bulk_concurrency: 8
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.bulk_concurrency, Some(8));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_log_snapshots() {
+        let yaml = r#"
+hosts:
+  - host: local
+log_snapshots:
+  dir: ~/dtop-logs
+  lines: 500
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let snapshots = config.log_snapshots.unwrap();
+        assert_eq!(snapshots.dir.as_deref(), Some("~/dtop-logs"));
+        assert_eq!(snapshots.lines, Some(500));
+
+        // An empty section enables snapshots with the defaults
+        let yaml = r#"
+hosts:
+  - host: local
+log_snapshots: {}
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.log_snapshots.is_some());
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_stats_sharing() {
+        let yaml = r#"
+hosts:
+  - host: local
+stats_sharing:
+  dir: /tmp/dtop-stats
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let sharing = config.stats_sharing.unwrap();
+        assert_eq!(sharing.dir.as_deref(), Some("/tmp/dtop-stats"));
+
+        // Off unless the section is there
+        let yaml = r#"
+hosts:
+  - host: local
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.stats_sharing.is_none());
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_download_dir() {
+        let yaml = r#"
+hosts:
+  - host: local
+download_dir: ~/container-files
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.download_dir.as_deref(), Some("~/container-files"));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_history_db() {
+        let yaml = r#"
+hosts:
+  - host: local
+history_db:
+  path: ~/dtop-history.db
+  retention_days: 30
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let history_db = config.history_db.unwrap();
+        assert_eq!(history_db.path.as_deref(), Some("~/dtop-history.db"));
+        assert_eq!(history_db.retention_days, Some(30));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_agentd() {
+        let yaml = r#"
+hosts:
+  - host: local
+agentd:
+  socket: /run/user/1000/dtop.sock
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let agentd = config.agentd.unwrap();
+        assert_eq!(agentd.socket.as_deref(), Some("/run/user/1000/dtop.sock"));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_shells() {
+        let yaml = r#"
+hosts:
+  - host: local
+    shells: [zsh, bash, sh]
+  - host: ssh://user@server1
+shells_by_label:
+  - label: app=alpine
+    shells: [ash]
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.hosts[0].shells.as_deref(),
+            Some(&["zsh".to_string(), "bash".to_string(), "sh".to_string()][..])
+        );
+        assert_eq!(config.hosts[1].shells, None);
+        let rules = config.shells_by_label.unwrap();
+        assert_eq!(rules[0].label, "app=alpine");
+        assert_eq!(rules[0].shells, vec!["ash".to_string()]);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_restart_loop() {
+        let yaml = r#"
+hosts:
+  - host: local
+restart_loop:
+  restarts: 5
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let restart_loop = config.restart_loop.unwrap();
+        assert_eq!(restart_loop.restarts, Some(5));
+        assert_eq!(restart_loop.minutes, None);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_alert_routing() {
+        let yaml = r#"
+hosts:
+  - host: local
+alerts:
+  rules:
+    - metric: unhealthy
+      severity: critical
+  webhook: https://hooks.example.com/dtop
+  routing:
+    info: [toast]
+    critical: [bell, webhook]
+  do_not_disturb: true
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let alerts = config.alerts.unwrap();
+        assert_eq!(alerts.rules[0].severity.as_deref(), Some("critical"));
+        assert_eq!(
+            alerts.webhook.as_deref(),
+            Some("https://hooks.example.com/dtop")
+        );
+        let routing = alerts.routing.unwrap();
+        assert_eq!(routing["critical"], vec!["bell", "webhook"]);
+        assert_eq!(alerts.do_not_disturb, Some(true));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_anomalies() {
+        let yaml = r#"
+hosts:
+  - host: local
+anomalies:
+  sigma: 2.5
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let anomalies = config.anomalies.unwrap();
+        assert_eq!(anomalies.sigma, Some(2.5));
+        assert_eq!(anomalies.samples, None);
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_budgets() {
+        let yaml = r#"
+hosts:
+  - host: local
+budgets:
+  - label: team=payments
+    cpus: 8
+    memory_gb: 16
+  - label: com.docker.compose.project=shop
+    memory_gb: 2.5
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let budgets = config.budgets.unwrap();
+        assert_eq!(budgets.len(), 2);
+        assert_eq!(budgets[0].label, "team=payments");
+        assert_eq!(budgets[0].cpus, Some(8.0));
+        assert_eq!(budgets[0].memory_gb, Some(16.0));
+        assert_eq!(budgets[1].cpus, None);
+        assert_eq!(budgets[1].memory_gb, Some(2.5));
+    }
+
+    #[test]
+    fn test_yaml_deserialization_with_reports() {
+        let yaml = r#"
+hosts:
+  - host: local
+reports:
+  interval_minutes: 30
+  format: markdown
+  dir: ~/dtop-reports
+  url: https://example.com/dtop
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let reports = config.reports.unwrap();
+        assert_eq!(reports.interval_minutes, Some(30));
+        assert_eq!(reports.format.as_deref(), Some("markdown"));
+        assert_eq!(reports.dir.as_deref(), Some("~/dtop-reports"));
+        assert_eq!(reports.url.as_deref(), Some("https://example.com/dtop"));
+    }
+
+    #[test]
+    fn test_set_top_level_key_keeps_comments() {
+        let contents = "# My hosts\nhosts:\n  - host: local # the laptop\n\ncpu_normalized: false # set by '%'\n";
+        assert_eq!(
+            set_top_level_key(contents, "cpu_normalized", "true"),
+            "# My hosts\nhosts:\n  - host: local # the laptop\n\ncpu_normalized: true # set by '%'\n"
+        );
+
+        // Commented out or nested keys are left alone, the key is appended instead
+        let contents = "# cpu_normalized: true\nalerts:\n  cpu_normalized: 1";
+        assert_eq!(
+            set_top_level_key(contents, "cpu_normalized", "true"),
+            "# cpu_normalized: true\nalerts:\n  cpu_normalized: 1\ncpu_normalized: true\n"
+        );
+
+        // Only the whole key matches
+        assert_eq!(
+            set_top_level_key("cpu_normalized_x: 1\n", "cpu_normalized", "true"),
+            "cpu_normalized_x: 1\ncpu_normalized: true\n"
+        );
+    }
 }
@@ -7,6 +7,10 @@ use crate::cli::config::{Config, HostConfig};
 use crate::cli::filters::parse_filters;
 use crate::core::types::AppEvent;
 use crate::docker::connection::{DockerHost, connect_docker, container_manager};
+use crate::docker::stats::{DEFAULT_POLL_CONCURRENCY, DEFAULT_POLL_INTERVAL_SECS, StatsPolling};
+use crate::docker::stats_share::StatsShare;
+use crate::docker::systemd::CommandHost;
+use crate::ui::i18n;
 
 /// Result of establishing connections to Docker hosts
 pub struct ConnectionResult {
@@ -28,6 +32,12 @@ pub async fn establish_connections(
     // Create a channel for receiving successful connections
     let (conn_tx, mut conn_rx) = mpsc::channel::<DockerHost>(total_hosts);
 
+    // Stats streams are shared with other instances only when configured
+    let stats_share = config
+        .stats_sharing
+        .as_ref()
+        .and_then(StatsShare::from_config);
+
     // Spawn all connection attempts in parallel
     let connection_handles: Vec<_> = config
         .hosts
@@ -36,10 +46,12 @@ pub async fn establish_connections(
             let host_config = host_config.clone();
             let conn_tx = conn_tx.clone();
             let error_tx = event_tx.clone();
+            let stats_share = stats_share.clone();
 
             tokio::spawn(async move {
                 match connect_and_verify_host(&host_config).await {
-                    Ok(docker_host) => {
+                    Ok(mut docker_host) => {
+                        docker_host.stats_share = stats_share;
                         let _ = conn_tx.send(docker_host).await;
                     }
                     Err(e) => {
@@ -115,6 +127,26 @@ pub async fn establish_connections(
     })
 }
 
+/// Connects to all hosts in the background, only for actions, logs and shells: with
+/// `dtop agentd` attached, the containers and stats come from it
+pub fn spawn_action_connections(config: Config, event_tx: mpsc::Sender<AppEvent>) {
+    tokio::spawn(async move {
+        let mut connection_result = match establish_connections(&config, event_tx.clone()).await {
+            Ok(connection_result) => connection_result,
+            Err(e) => {
+                tracing::error!("{}", e);
+                return;
+            }
+        };
+        let _ = event_tx
+            .send(AppEvent::HostConnected(connection_result.first_host))
+            .await;
+        while let Some(docker_host) = connection_result.remaining_rx.recv().await {
+            let _ = event_tx.send(AppEvent::HostConnected(docker_host)).await;
+        }
+    });
+}
+
 /// Spawns background task to handle remaining host connections
 pub fn spawn_remaining_connections_handler(
     mut remaining_rx: mpsc::Receiver<DockerHost>,
@@ -146,26 +178,41 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
     debug!("Attempting to connect to host: {}", host_spec);
 
     // Attempt to connect
-    let docker = connect_docker(host_spec).map_err(|e| {
-        format!(
-            "Failed to create Docker client for host '{}': {}",
-            host_spec, e
-        )
-    })?;
+    let msg = i18n::messages();
+    let docker = connect_docker(host_spec)
+        .map_err(|e| i18n::fill(msg.host_client_failed, &[&host_spec, &e]))?;
 
     debug!("Successfully created Docker client for host: {}", host_spec);
 
     // Parse filters if provided
     let filters = if let Some(ref filter_list) = host_config.filter {
         parse_filters(filter_list)
-            .map_err(|e| format!("Failed to parse filters for host '{}': {}", host_spec, e))?
+            .map_err(|e| i18n::fill(msg.host_filters_invalid, &[&host_spec, &e]))?
     } else {
         HashMap::new()
     };
 
     // Create host ID and DockerHost instance
     let host_id = create_host_id(host_spec);
-    let docker_host = DockerHost::new(host_id, docker, host_config.dozzle.clone(), filters);
+    let mut docker_host = DockerHost::new(host_id, docker, host_config.dozzle.clone(), filters);
+    docker_host.exec_events = host_config.exec_events.unwrap_or(false);
+    docker_host.disk_check = host_config.disk_check.unwrap_or(false);
+    docker_host.shells = host_config
+        .shells
+        .clone()
+        .filter(|shells| !shells.is_empty());
+    docker_host.command_host = CommandHost::from_host_spec(host_spec);
+    docker_host.stats_polling = host_config.stats_polling.as_ref().map(|polling| {
+        StatsPolling::new(
+            Duration::from_secs(
+                polling
+                    .interval_secs
+                    .unwrap_or(DEFAULT_POLL_INTERVAL_SECS)
+                    .max(1),
+            ),
+            polling.concurrency.unwrap_or(DEFAULT_POLL_CONCURRENCY),
+        )
+    });
 
     // Verify the connection actually works by pinging Docker with timeout
     debug!("Pinging Docker daemon at host: {}", host_spec);
@@ -174,6 +221,10 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
     match tokio::time::timeout(ping_timeout, docker_host.docker.ping()).await {
         Ok(Ok(_)) => {
             debug!("Successfully pinged Docker daemon at host: {}", host_spec);
+            if let Ok(version) = docker_host.docker.version().await {
+                docker_host.windows = version.os.as_deref() == Some("windows");
+                docker_host.architecture = version.arch;
+            }
             Ok(docker_host)
         }
         Ok(Err(e)) => {
@@ -186,14 +237,11 @@ pub async fn connect_and_verify_host(host_config: &HostConfig) -> Result<DockerH
             {
                 debug!("  Level {}: {}", level + 1, err);
             }
-            Err(format!(
-                "Docker daemon ping failed for host '{}': {}",
-                host_spec, e
-            ))
+            Err(i18n::fill(msg.host_ping_failed, &[&host_spec, &e]))
         }
-        Err(_) => Err(format!(
-            "Docker daemon ping timeout for host '{}' (>10s)",
-            host_spec
+        Err(_) => Err(i18n::fill(
+            msg.host_ping_timeout,
+            &[&host_spec, &ping_timeout.as_secs()],
         )),
     }
 }
@@ -0,0 +1,455 @@
+//! `dtop report`: a summary of the stats samples and events kept in the history
+//! database: the top CPU and memory consumers, and per container its usage with graphs
+//! over the whole period, restarts and uptime. Printed as text, or as JSON
+//!
+//! Uptime is the share of the minutes dtop was recording on the container's host in
+//! which the container was sampled, i.e. running, so the time dtop itself wasn't
+//! running doesn't count as downtime.
+
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::cli::config::Config;
+use crate::core::history_db::{Event, HistoryReader, Sample, db_path};
+use crate::core::types::ContainerKey;
+use crate::ui::formatters::format_bytes;
+
+/// Columns of the graphs
+const GRAPH_WIDTH: usize = 48;
+
+const GRAPH_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Containers listed under each top consumers heading
+const TOP_COUNT: usize = 5;
+
+/// Prints the report of the period to stdout, for the containers whose name contains
+/// the filter
+pub fn run_report(
+    since: &str,
+    name_filter: Option<&str>,
+    json: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let period = parse_period(since)?;
+    let (config, _) = Config::load_with_path()?;
+    let history_db = config
+        .history_db
+        .ok_or("No history is kept: add a history_db section to the config file")?;
+    let path = db_path(&history_db).ok_or("Could not determine the history database path")?;
+
+    let reader = HistoryReader::open(&path)?;
+    let now = Utc::now();
+    let start = now - period;
+    let samples = reader.samples(start, name_filter)?;
+    let events = reader.events(start, name_filter)?;
+
+    let report = Report::new(&samples, &events, start, now);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", report.to_text(&samples));
+    }
+    Ok(())
+}
+
+/// "30m", "24h", "7d"
+fn parse_period(value: &str) -> Result<TimeDelta, String> {
+    let error = || format!("invalid period '{}' (e.g. 30m, 24h, 7d)", value);
+    let (number, unit) = value.split_at(value.len().saturating_sub(1));
+    let number: i64 = number.parse().map_err(|_| error())?;
+    match unit {
+        "m" => Ok(TimeDelta::minutes(number)),
+        "h" => Ok(TimeDelta::hours(number)),
+        "d" => Ok(TimeDelta::days(number)),
+        _ => Err(error()),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Report<'a> {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    /// By average CPU, highest first
+    top_cpu: Vec<TopConsumer>,
+    /// By average memory, highest first
+    top_memory: Vec<TopConsumer>,
+    /// By name
+    containers: Vec<ContainerSummary>,
+    events: Vec<EventRow<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct TopConsumer {
+    name: String,
+    host: String,
+    average_percent: f64,
+}
+
+/// What happened to a container over the period; the usage is zero without samples
+#[derive(Debug, Serialize)]
+struct ContainerSummary {
+    name: String,
+    host: String,
+    container_id: String,
+    samples: usize,
+    cpu_avg: f64,
+    cpu_max: f64,
+    memory_avg: f64,
+    memory_max: f64,
+    memory_peak_bytes: u64,
+    pids_max: u64,
+    /// Started again after exiting
+    restarts: usize,
+    /// None when dtop recorded nothing on the host
+    uptime_percent: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct EventRow<'a> {
+    time: DateTime<Utc>,
+    name: &'a str,
+    host: &'a str,
+    container_id: &'a str,
+    event: &'a str,
+    detail: Option<&'a str>,
+}
+
+impl<'a> Report<'a> {
+    fn new(
+        samples: &[Sample],
+        events: &'a [Event],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        // Minutes in which each host, and each container, was sampled
+        let minute = |sample: &Sample| sample.time.timestamp().div_euclid(60);
+        let mut host_minutes: HashMap<&str, HashSet<i64>> = HashMap::new();
+        let mut by_container: HashMap<&ContainerKey, Vec<&Sample>> = HashMap::new();
+        for sample in samples {
+            host_minutes
+                .entry(&sample.key.host_id)
+                .or_default()
+                .insert(minute(sample));
+            by_container.entry(&sample.key).or_default().push(sample);
+        }
+
+        let mut by_container_events: HashMap<&ContainerKey, Vec<&Event>> = HashMap::new();
+        for event in events {
+            by_container_events
+                .entry(&event.key)
+                .or_default()
+                .push(event);
+        }
+
+        let keys: HashSet<&ContainerKey> = by_container
+            .keys()
+            .chain(by_container_events.keys())
+            .copied()
+            .collect();
+        let mut containers: Vec<ContainerSummary> = keys
+            .into_iter()
+            .map(|key| {
+                let samples = by_container.get(key).map(Vec::as_slice).unwrap_or_default();
+                let events = by_container_events
+                    .get(key)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                // The name it had last
+                let name = samples
+                    .last()
+                    .map(|sample| sample.name.clone())
+                    .or_else(|| events.last().map(|event| event.name.clone()))
+                    .unwrap_or_default();
+
+                let count = samples.len().max(1) as f64;
+                let avg = |value: fn(&Sample) -> f64| {
+                    samples.iter().map(|s| value(s)).sum::<f64>() / count
+                };
+                let max = |value: fn(&Sample) -> f64| {
+                    samples.iter().map(|s| value(s)).fold(0.0, f64::max)
+                };
+
+                let uptime_percent = host_minutes.get(key.host_id.as_str()).map(|observed| {
+                    let running: HashSet<i64> = samples.iter().map(|s| minute(s)).collect();
+                    running.len() as f64 / observed.len() as f64 * 100.0
+                });
+
+                ContainerSummary {
+                    name,
+                    host: key.host_id.clone(),
+                    container_id: key.container_id.clone(),
+                    samples: samples.len(),
+                    cpu_avg: avg(|s| s.cpu),
+                    cpu_max: max(|s| s.cpu),
+                    memory_avg: avg(|s| s.memory),
+                    memory_max: max(|s| s.memory),
+                    memory_peak_bytes: samples
+                        .iter()
+                        .map(|s| s.memory_used_bytes)
+                        .max()
+                        .unwrap_or(0),
+                    pids_max: samples.iter().map(|s| s.pids).max().unwrap_or(0),
+                    restarts: count_restarts(events),
+                    uptime_percent,
+                }
+            })
+            .collect();
+        containers.sort_by(|a, b| (&a.name, &a.host).cmp(&(&b.name, &b.host)));
+
+        let top = |value: fn(&ContainerSummary) -> f64| {
+            let mut sampled: Vec<&ContainerSummary> =
+                containers.iter().filter(|c| c.samples > 0).collect();
+            sampled.sort_by(|a, b| value(b).total_cmp(&value(a)));
+            sampled
+                .into_iter()
+                .take(TOP_COUNT)
+                .map(|c| TopConsumer {
+                    name: c.name.clone(),
+                    host: c.host.clone(),
+                    average_percent: value(c),
+                })
+                .collect()
+        };
+
+        Self {
+            from: start,
+            to: end,
+            top_cpu: top(|c| c.cpu_avg),
+            top_memory: top(|c| c.memory_avg),
+            events: events
+                .iter()
+                .map(|event| EventRow {
+                    time: event.time,
+                    name: &event.name,
+                    host: &event.key.host_id,
+                    container_id: &event.key.container_id,
+                    event: &event.event,
+                    detail: event.detail.as_deref(),
+                })
+                .collect(),
+            containers,
+        }
+    }
+
+    /// The report as text, with the graphs drawn from the samples
+    fn to_text(&self, samples: &[Sample]) -> String {
+        let local = |time: DateTime<Utc>| time.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+        let mut text = format!("History from {} to {}\n", local(self.from), local(self.to));
+
+        if self.containers.is_empty() {
+            text.push_str("\nNothing was recorded in this period\n");
+            return text;
+        }
+
+        for (title, top) in [
+            ("Top CPU (average)", &self.top_cpu),
+            ("Top memory (average)", &self.top_memory),
+        ] {
+            if top.is_empty() {
+                continue;
+            }
+            text.push_str(&format!("\n{}\n", title));
+            for consumer in top {
+                text.push_str(&format!(
+                    "  {:>5.1}%  {} ({})\n",
+                    consumer.average_percent, consumer.name, consumer.host
+                ));
+            }
+        }
+
+        for container in &self.containers {
+            let uptime = container
+                .uptime_percent
+                .map(|uptime| format!("  uptime {:.1}%", uptime))
+                .unwrap_or_default();
+            let restarts = match container.restarts {
+                0 => String::new(),
+                1 => "  1 restart".to_string(),
+                n => format!("  {} restarts", n),
+            };
+            text.push_str(&format!(
+                "\n{} ({})  {} samples{}{}\n",
+                container.name, container.host, container.samples, uptime, restarts
+            ));
+            if container.samples == 0 {
+                continue;
+            }
+
+            let container_samples: Vec<&Sample> = samples
+                .iter()
+                .filter(|s| {
+                    s.key.host_id == container.host && s.key.container_id == container.container_id
+                })
+                .collect();
+            text.push_str(&format!(
+                "  CPU     avg {:>5.1}%  max {:>5.1}%  {}\n",
+                container.cpu_avg,
+                container.cpu_max,
+                graph(&container_samples, |s| s.cpu, self.from, self.to)
+            ));
+            text.push_str(&format!(
+                "  Memory  avg {:>5.1}%  max {:>5.1}%  {}  peak {}\n",
+                container.memory_avg,
+                container.memory_max,
+                graph(&container_samples, |s| s.memory, self.from, self.to),
+                format_bytes(container.memory_peak_bytes)
+            ));
+            text.push_str(&format!("  PIDs    max {}\n", container.pids_max));
+        }
+
+        if !self.events.is_empty() {
+            text.push_str("\nEvents\n");
+            for event in &self.events {
+                let detail = event
+                    .detail
+                    .map(|detail| format!(" {}", detail))
+                    .unwrap_or_default();
+                text.push_str(&format!(
+                    "  {}  {} ({})  {}{}\n",
+                    local(event.time),
+                    event.name,
+                    event.host,
+                    event.event,
+                    detail
+                ));
+            }
+        }
+
+        text
+    }
+}
+
+/// Starts that follow an exit of the same container, in time order
+fn count_restarts(events: &[&Event]) -> usize {
+    events
+        .windows(2)
+        .filter(|pair| pair[0].event == "exited" && pair[1].event == "started")
+        .count()
+}
+
+/// The values averaged per column over the period, scaled to the highest; blank where
+/// nothing was recorded (the container wasn't running, or dtop wasn't)
+fn graph(
+    samples: &[&Sample],
+    value: fn(&Sample) -> f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> String {
+    let span = (end - start).num_seconds().max(1) as f64;
+    let mut columns = vec![(0.0, 0usize); GRAPH_WIDTH];
+    for sample in samples {
+        let offset = (sample.time - start).num_seconds().max(0) as f64;
+        let column = ((offset / span * GRAPH_WIDTH as f64) as usize).min(GRAPH_WIDTH - 1);
+        columns[column].0 += value(sample);
+        columns[column].1 += 1;
+    }
+
+    let averages: Vec<Option<f64>> = columns
+        .iter()
+        .map(|&(sum, count)| (count > 0).then(|| sum / count as f64))
+        .collect();
+    let highest = averages.iter().flatten().fold(0.0, |a: f64, &b| a.max(b));
+    averages
+        .iter()
+        .map(|average| match average {
+            None => ' ',
+            Some(_) if highest <= 0.0 => GRAPH_LEVELS[0],
+            Some(average) => {
+                let level = (average / highest * (GRAPH_LEVELS.len() - 1) as f64).round();
+                GRAPH_LEVELS[level as usize]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(name: &str, time: DateTime<Utc>, cpu: f64) -> Sample {
+        Sample {
+            time,
+            key: ContainerKey::new("local".to_string(), format!("{}-id", name)),
+            name: name.to_string(),
+            cpu,
+            memory: 50.0,
+            memory_used_bytes: 1024 * 1024,
+            network_tx_bytes_per_sec: 0.0,
+            network_rx_bytes_per_sec: 0.0,
+            block_read_bytes_per_sec: 0.0,
+            block_write_bytes_per_sec: 0.0,
+            pids: 4,
+        }
+    }
+
+    fn event(name: &str, time: DateTime<Utc>, event: &str, detail: Option<&str>) -> Event {
+        Event {
+            time,
+            key: ContainerKey::new("local".to_string(), format!("{}-id", name)),
+            name: name.to_string(),
+            event: event.to_string(),
+            detail: detail.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_period() {
+        assert_eq!(parse_period("30m"), Ok(TimeDelta::minutes(30)));
+        assert_eq!(parse_period("24h"), Ok(TimeDelta::hours(24)));
+        assert_eq!(parse_period("7d"), Ok(TimeDelta::days(7)));
+        assert!(parse_period("7").is_err());
+        assert!(parse_period("1w").is_err());
+        assert!(parse_period("").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let start = DateTime::from_timestamp(0, 0).unwrap();
+        let end = start + TimeDelta::hours(1);
+        let minute = |n| start + TimeDelta::minutes(n);
+        // web runs all 4 recorded minutes, db 2 of them
+        let samples = [
+            sample("web", minute(0), 10.0),
+            sample("web", minute(1), 10.0),
+            sample("web", minute(2), 10.0),
+            sample("web", minute(59), 30.0),
+            sample("db", minute(0), 50.0),
+            sample("db", minute(59), 70.0),
+        ];
+        let events = [
+            event("db", minute(5), "exited", Some("137")),
+            event("db", minute(6), "started", None),
+            event("db", minute(7), "health", Some("healthy")),
+            event("cron", minute(8), "started", None),
+        ];
+
+        let report = Report::new(&samples, &events, start, end);
+        let names: Vec<&str> = report.containers.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["cron", "db", "web"]);
+        assert_eq!(report.top_cpu[0].name, "db");
+        assert_eq!(report.top_cpu[0].average_percent, 60.0);
+        assert_eq!(report.top_cpu.len(), 2);
+
+        let db = &report.containers[1];
+        assert_eq!(db.restarts, 1);
+        assert_eq!(db.uptime_percent, Some(50.0));
+        assert_eq!(report.containers[2].uptime_percent, Some(100.0));
+        assert_eq!(report.containers[0].samples, 0);
+
+        let text = report.to_text(&samples);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[3], "   60.0%  db (local)");
+        assert!(text.contains("\ncron (local)  0 samples  uptime 0.0%\n"));
+        assert!(text.contains("\ndb (local)  2 samples  uptime 50.0%  1 restart\n"));
+        assert!(text.contains("  CPU     avg  15.0%  max  30.0%  ▃"));
+        assert!(text.contains("db (local)  exited 137"));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["containers"][1]["restarts"], 1);
+        assert_eq!(json["top_memory"][0]["average_percent"], 50.0);
+        assert_eq!(json["events"][0]["detail"], "137");
+
+        let empty = Report::new(&[], &[], start, end);
+        assert!(empty.to_text(&[]).contains("Nothing was recorded"));
+    }
+}
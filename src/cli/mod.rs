@@ -1,5 +1,10 @@
+pub mod agentd;
 pub mod config;
 pub mod connect;
+pub mod doctor;
 pub mod filters;
+pub mod history_report;
+pub mod report;
+pub mod snapshot;
 #[cfg(feature = "self-update")]
 pub mod update;
@@ -0,0 +1,268 @@
+use chrono::{DateTime, Local};
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::cli::config::ReportsConfig;
+use crate::cli::snapshot::snapshot_json;
+use crate::core::app_state::AppState;
+use crate::core::types::{Container, ContainerState};
+
+/// Minutes between reports when the config doesn't say
+const DEFAULT_REPORT_INTERVAL_MINUTES: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportFormat {
+    Json,
+    Markdown,
+}
+
+impl ReportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Markdown => "md",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            ReportFormat::Json => "application/json",
+            ReportFormat::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+}
+
+/// Periodically records the state of every container while the UI runs, as a file
+/// in a directory and/or a POST to a URL
+pub struct Reporter {
+    interval: Duration,
+    format: ReportFormat,
+    dir: Option<PathBuf>,
+    url: Option<String>,
+    client: reqwest::Client,
+    next_report: Instant,
+}
+
+impl Reporter {
+    /// The reporter as configured; the first report is made one interval after startup,
+    /// once the hosts have been connected and stats have come in
+    pub fn from_config(config: &ReportsConfig) -> Result<Self, String> {
+        let format = match config.format.as_deref() {
+            None | Some("json") => ReportFormat::Json,
+            Some("markdown") | Some("md") => ReportFormat::Markdown,
+            Some(other) => {
+                return Err(format!(
+                    "unknown report format '{}' (json, markdown)",
+                    other
+                ));
+            }
+        };
+        if config.dir.is_none() && config.url.is_none() {
+            return Err("reports need a dir or a url to send them to".to_string());
+        }
+        let dir = match config.dir.as_deref() {
+            // Expand ~ like a shell would
+            Some(dir) => Some(match dir.strip_prefix("~/") {
+                Some(rest) => dirs::home_dir()
+                    .ok_or("could not determine the home directory for reports.dir")?
+                    .join(rest),
+                None => PathBuf::from(dir),
+            }),
+            None => None,
+        };
+        let minutes = config
+            .interval_minutes
+            .unwrap_or(DEFAULT_REPORT_INTERVAL_MINUTES)
+            .max(1);
+        let interval = Duration::from_secs(minutes * 60);
+
+        Ok(Self {
+            interval,
+            format,
+            dir,
+            url: config.url.clone(),
+            client: reqwest::Client::new(),
+            next_report: Instant::now() + interval,
+        })
+    }
+
+    /// Makes a report when one is due; it is written and sent in the background so
+    /// the UI never waits on disk or network
+    pub fn tick(&mut self, state: &AppState) {
+        if Instant::now() < self.next_report {
+            return;
+        }
+        // From now rather than from when it was due, so time spent in a shell doesn't
+        // cause a burst of reports
+        self.next_report = Instant::now() + self.interval;
+
+        let mut containers: Vec<&Container> = state.containers.values().collect();
+        containers.sort_by(|a, b| (&a.host_id, &a.name).cmp(&(&b.host_id, &b.name)));
+        let now = Local::now();
+        let body = report_body(self.format, &containers, now);
+
+        if let Some(dir) = &self.dir {
+            let path = dir.join(format!(
+                "dtop-report-{}.{}",
+                now.format("%Y%m%d-%H%M%S"),
+                self.format.extension()
+            ));
+            let body = body.clone();
+            tokio::spawn(async move {
+                if let Err(e) = write_report(&path, body).await {
+                    tracing::warn!("Failed to write report: {}", e);
+                }
+            });
+        }
+
+        if let Some(url) = &self.url {
+            let request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, self.format.content_type())
+                .body(body);
+            tokio::spawn(async move {
+                match request.send().await.and_then(|r| r.error_for_status()) {
+                    Ok(_) => tracing::debug!("Report sent"),
+                    Err(e) => tracing::warn!("Failed to send report: {}", e),
+                }
+            });
+        }
+    }
+}
+
+async fn write_report(path: &std::path::Path, body: String) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    tokio::fs::write(path, body)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// The report text for the containers, which are expected sorted by host and name
+fn report_body(format: ReportFormat, containers: &[&Container], now: DateTime<Local>) -> String {
+    match format {
+        // The containers are the same objects `dtop snapshot` prints
+        ReportFormat::Json => json!({
+            "generated_at": now.to_rfc3339(),
+            "containers": snapshot_json(containers),
+        })
+        .to_string(),
+        ReportFormat::Markdown => report_markdown(containers, now),
+    }
+}
+
+/// A heading with the counts, followed by a table with one row per container
+fn report_markdown(containers: &[&Container], now: DateTime<Local>) -> String {
+    let hosts: BTreeSet<&str> = containers.iter().map(|c| c.host_id.as_str()).collect();
+    let running = containers
+        .iter()
+        .filter(|c| c.state == ContainerState::Running)
+        .count();
+
+    let mut report = format!(
+        "# dtop report {}\n\n{} of {} containers running on {} host(s)\n\n",
+        now.format("%Y-%m-%d %H:%M:%S"),
+        running,
+        containers.len(),
+        hosts.len()
+    );
+    report.push_str("| Host | Name | Image | State | Health | CPU % | Memory % | Restarts |\n");
+    report.push_str("| --- | --- | --- | --- | --- | --- | --- | --- |\n");
+    for container in containers {
+        let running = container.state == ContainerState::Running;
+        // Stats are only meaningful for running containers
+        let stat = |value: f64| {
+            if running {
+                format!("{:.1}", value)
+            } else {
+                String::new()
+            }
+        };
+        let cells = [
+            container.host_id.clone(),
+            container.name.clone(),
+            container.image.clone(),
+            format!("{:?}", container.state).to_lowercase(),
+            container
+                .health
+                .as_ref()
+                .map(|health| format!("{:?}", health).to_lowercase())
+                .unwrap_or_default(),
+            stat(container.stats.cpu),
+            stat(container.stats.memory),
+            container.restart_count.to_string(),
+        ];
+        let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        report.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ContainerStats;
+    use chrono::TimeZone;
+    use serde_json::Value;
+
+    fn container(name: &str, state: ContainerState) -> Container {
+        Container {
+            name: name.to_string(),
+            image: "nginx:1.27".to_string(),
+            state,
+            restart_count: 1,
+            stats: ContainerStats {
+                cpu: 12.5,
+                memory: 40.0,
+                ..Default::default()
+            },
+            ..Container::test_default()
+        }
+    }
+
+    #[test]
+    fn test_report_body() {
+        let web = container("web", ContainerState::Running);
+        let db = container("db", ContainerState::Exited);
+        let now = Local.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap();
+
+        let json: Value =
+            serde_json::from_str(&report_body(ReportFormat::Json, &[&db, &web], now)).unwrap();
+        assert_eq!(json["generated_at"], now.to_rfc3339());
+        assert_eq!(json["containers"][1]["name"], "web");
+
+        let markdown = report_body(ReportFormat::Markdown, &[&db, &web], now);
+        assert!(markdown.starts_with("# dtop report 2026-01-02 03:04:05\n"));
+        assert!(markdown.contains("1 of 2 containers running on 1 host(s)"));
+        assert!(markdown.contains("| local | db | nginx:1.27 | exited |  |  |  | 1 |\n"));
+        assert!(markdown.contains("| local | web | nginx:1.27 | running |  | 12.5 | 40.0 | 1 |\n"));
+    }
+
+    #[test]
+    fn test_reporter_from_config() {
+        let config = ReportsConfig {
+            format: Some("xml".to_string()),
+            dir: Some("/tmp".to_string()),
+            ..Default::default()
+        };
+        assert!(Reporter::from_config(&config).is_err());
+
+        // Reports have to go somewhere
+        assert!(Reporter::from_config(&ReportsConfig::default()).is_err());
+
+        let config = ReportsConfig {
+            format: Some("markdown".to_string()),
+            url: Some("http://localhost:8080/reports".to_string()),
+            ..Default::default()
+        };
+        let reporter = Reporter::from_config(&config).unwrap();
+        assert_eq!(reporter.format, ReportFormat::Markdown);
+        assert_eq!(reporter.interval, Duration::from_secs(3600));
+    }
+}
@@ -0,0 +1,156 @@
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use crate::cli::config::Config;
+use crate::cli::connect::{establish_connections, spawn_remaining_connections_handler};
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, Container, ContainerKey, ContainerState, SortField};
+use crate::docker::connection::container_manager;
+
+/// Longest wait for slow hosts and stats before printing what has arrived
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Stats samples needed per running container: Docker's first sample has no CPU
+/// baseline and network rates need two readings
+const SNAPSHOT_SAMPLES: usize = 2;
+
+/// Connects to all hosts, collects one round of container stats and prints them
+/// as JSON to stdout, without touching the terminal
+pub async fn run_snapshot(
+    config: &Config,
+    show_all: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (tx, mut rx) = mpsc::channel::<AppEvent>(1000);
+
+    let connection_result = establish_connections(config, tx.clone()).await?;
+    let first_host = connection_result.first_host;
+
+    let mut connected_hosts = HashMap::new();
+    connected_hosts.insert(first_host.host_id.clone(), first_host.clone());
+
+    let manager_tx = tx.clone();
+    tokio::spawn(async move {
+        container_manager(first_host, manager_tx).await;
+    });
+    spawn_remaining_connections_handler(connection_result.remaining_rx, tx.clone());
+
+    // The same event handling as the UI, just never rendered
+    let mut state = AppState::new(connected_hosts, tx, show_all, SortField::Name);
+
+    let mut hosts_done = HashSet::new();
+    let mut samples: HashMap<ContainerKey, usize> = HashMap::new();
+    let deadline = Instant::now() + SNAPSHOT_TIMEOUT;
+
+    while let Ok(Some(event)) = tokio::time::timeout_at(deadline, rx.recv()).await {
+        match &event {
+            AppEvent::InitialContainerList(host_id, _) => {
+                hosts_done.insert(host_id.clone());
+            }
+            AppEvent::ConnectionError(host_id, error) => {
+                eprintln!("Failed to connect to {}: {}", host_id, error);
+                hosts_done.insert(host_id.clone());
+            }
+            AppEvent::ContainerStat(key, _) => *samples.entry(key.clone()).or_default() += 1,
+            _ => {}
+        }
+        state.handle_event(event);
+
+        let all_sampled = state
+            .containers
+            .iter()
+            .filter(|(_, container)| container.state == ContainerState::Running)
+            .all(|(key, _)| samples.get(key).copied().unwrap_or(0) >= SNAPSHOT_SAMPLES);
+        if hosts_done.len() >= config.hosts.len() && all_sampled {
+            break;
+        }
+    }
+
+    let mut containers: Vec<&Container> = state
+        .containers
+        .values()
+        .filter(|container| show_all || container.state == ContainerState::Running)
+        .collect();
+    containers.sort_by(|a, b| (&a.host_id, &a.name).cmp(&(&b.host_id, &b.name)));
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&snapshot_json(&containers))?
+    );
+
+    Ok(())
+}
+
+/// JSON array with one object per container
+pub(crate) fn snapshot_json(containers: &[&Container]) -> Value {
+    containers
+        .iter()
+        .map(|container| {
+            json!({
+                "host": container.host_id,
+                "id": container.id,
+                "name": container.name,
+                "image": container.image,
+                "state": format!("{:?}", container.state).to_lowercase(),
+                "health": container
+                    .health
+                    .as_ref()
+                    .map(|health| format!("{:?}", health).to_lowercase()),
+                "compose_project": container.compose_project,
+                "compose_service": container.compose_service,
+                "created": container.created.map(|created| created.to_rfc3339()),
+                "restart_count": container.restart_count,
+                "time_to_healthy_secs": container
+                    .time_to_healthy
+                    .map(|duration| duration.as_seconds_f64()),
+                "cpu_percent": container.stats.cpu,
+                "memory_percent": container.stats.memory,
+                "memory_used_bytes": container.stats.memory_used_bytes,
+                "memory_limit_bytes": container.stats.memory_limit_bytes,
+                "network_tx_bytes_per_sec": container.stats.network_tx_bytes_per_sec,
+                "network_rx_bytes_per_sec": container.stats.network_rx_bytes_per_sec,
+                "block_read_bytes_per_sec": container.stats.block_read_bytes_per_sec,
+                "block_write_bytes_per_sec": container.stats.block_write_bytes_per_sec,
+                "pids": container.stats.pids,
+                "pids_limit": container.stats.pids_limit,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{ContainerStats, HealthStatus};
+
+    #[test]
+    fn test_snapshot_json() {
+        let container = Container {
+            health: Some(HealthStatus::Healthy),
+            restart_count: 2,
+            compose_service: Some("web".to_string()),
+            compose_project: Some("shop".to_string()),
+            stats: ContainerStats {
+                cpu: 12.5,
+                memory_used_bytes: 1024,
+                ..Default::default()
+            },
+            ..Container::test_default()
+        };
+
+        let json = snapshot_json(&[&container]);
+        let entry = &json[0];
+        assert_eq!(entry["host"], "local");
+        assert_eq!(entry["name"], "web");
+        assert_eq!(entry["state"], "running");
+        assert_eq!(entry["health"], "healthy");
+        assert_eq!(entry["compose_project"], "shop");
+        assert_eq!(entry["compose_service"], "web");
+        assert_eq!(entry["created"], Value::Null);
+        assert_eq!(entry["restart_count"], 2);
+        assert_eq!(entry["cpu_percent"], 12.5);
+        assert_eq!(entry["memory_used_bytes"], 1024);
+    }
+}
@@ -0,0 +1,511 @@
+//! Threshold alerts on container stats and health, from the `alerts:` config section
+//!
+//! Rules are evaluated whenever a container's stats or health change. An alert fires
+//! once its condition has held for the rule's duration and stays in effect (shown as a
+//! banner) until the condition clears, after which it can fire again. How a fired alert
+//! is signaled besides its banner is routed by the rule's severity; in do-not-disturb
+//! mode only critical alerts are signaled at all.
+
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use crate::cli::config::{AlertRuleConfig, AlertsConfig};
+use crate::core::types::{Container, ContainerKey, HealthStatus};
+
+/// What an alert rule watches
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlertCondition {
+    /// CPU usage above the percentage
+    Cpu(f64),
+    /// Memory usage above the percentage of the limit
+    Memory(f64),
+    /// The health check failing
+    Unhealthy,
+}
+
+/// How urgent an alert is, which decides how it is signaled
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl AlertSeverity {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "info" => Ok(AlertSeverity::Info),
+            "warning" => Ok(AlertSeverity::Warning),
+            "critical" => Ok(AlertSeverity::Critical),
+            other => Err(format!(
+                "unknown alert severity '{}' (info, warning, critical)",
+                other
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AlertSeverity::Info => "info",
+            AlertSeverity::Warning => "warning",
+            AlertSeverity::Critical => "critical",
+        }
+    }
+}
+
+/// Where a fired alert is signaled besides its banner (the toast, always shown)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AlertRoute {
+    pub bell: bool,
+    pub desktop: bool,
+    pub webhook: bool,
+}
+
+impl AlertRoute {
+    fn from_channels(channels: &[String]) -> Result<Self, String> {
+        let mut route = AlertRoute::default();
+        for channel in channels {
+            match channel.as_str() {
+                "toast" => {}
+                "bell" => route.bell = true,
+                "desktop" => route.desktop = true,
+                "webhook" => route.webhook = true,
+                other => {
+                    return Err(format!(
+                        "unknown alert channel '{}' (toast, bell, desktop, webhook)",
+                        other
+                    ));
+                }
+            }
+        }
+        Ok(route)
+    }
+}
+
+/// A configured alert rule
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlertRule {
+    pub condition: AlertCondition,
+    /// How long the condition must hold before the alert fires
+    pub duration: Duration,
+    pub severity: AlertSeverity,
+}
+
+impl AlertRule {
+    fn from_config(config: &AlertRuleConfig) -> Result<Self, String> {
+        let above = || {
+            config
+                .above
+                .ok_or_else(|| format!("alert on '{}' needs a threshold (above)", config.metric))
+        };
+        let condition = match config.metric.as_str() {
+            "cpu" => AlertCondition::Cpu(above()?),
+            "memory" => AlertCondition::Memory(above()?),
+            "unhealthy" => AlertCondition::Unhealthy,
+            other => {
+                return Err(format!(
+                    "unknown alert metric '{}' (cpu, memory, unhealthy)",
+                    other
+                ));
+            }
+        };
+        Ok(Self {
+            condition,
+            duration: Duration::from_secs(config.for_secs.unwrap_or(0)),
+            severity: AlertSeverity::parse(config.severity.as_deref().unwrap_or("warning"))?,
+        })
+    }
+
+    /// The current value of the watched metric, None for health
+    fn value(&self, container: &Container) -> Option<f64> {
+        match self.condition {
+            AlertCondition::Cpu(_) => Some(container.stats.cpu),
+            AlertCondition::Memory(_) => Some(container.stats.memory),
+            AlertCondition::Unhealthy => None,
+        }
+    }
+
+    /// Whether the container is past the rule's threshold right now
+    fn breached(&self, container: &Container) -> bool {
+        match self.condition {
+            AlertCondition::Cpu(limit) => container.stats.cpu > limit,
+            AlertCondition::Memory(limit) => container.stats.memory > limit,
+            AlertCondition::Unhealthy => container.health == Some(HealthStatus::Unhealthy),
+        }
+    }
+}
+
+/// An alert in effect for a container
+#[derive(Clone, Debug, PartialEq)]
+pub struct FiredAlert {
+    pub key: ContainerKey,
+    pub rule: AlertRule,
+    /// Latest value of the watched metric (None for health)
+    pub value: Option<f64>,
+    /// When the condition started holding
+    pub since: Instant,
+}
+
+impl FiredAlert {
+    /// Plain text for the desktop notification ("web: cpu 95.2% > 90%")
+    fn summary(&self, name: &str) -> String {
+        match (self.rule.condition, self.value) {
+            (AlertCondition::Cpu(limit), Some(value)) => {
+                format!("{}: cpu {:.1}% > {}%", name, value, limit)
+            }
+            (AlertCondition::Memory(limit), Some(value)) => {
+                format!("{}: memory {:.1}% > {}%", name, value, limit)
+            }
+            _ => format!("{}: unhealthy", name),
+        }
+    }
+}
+
+/// Evaluates the alert rules against every container
+#[derive(Debug)]
+pub struct Alerts {
+    rules: Vec<AlertRule>,
+    /// How each severity is signaled; severities not routed use `default_route`
+    routes: HashMap<AlertSeverity, AlertRoute>,
+    /// The bell and desktop notification as configured for all alerts
+    default_route: AlertRoute,
+    /// Where alerts routed to the webhook are POSTed
+    webhook: Option<String>,
+    client: reqwest::Client,
+    /// When each (container, rule index) started breaching, fired or not
+    breaches: HashMap<(ContainerKey, usize), Instant>,
+    /// Alerts in effect by (container, rule index)
+    firing: HashMap<(ContainerKey, usize), FiredAlert>,
+}
+
+impl Alerts {
+    /// Builds the evaluator from the config, None when no rules are configured
+    pub fn from_config(config: &AlertsConfig) -> Result<Option<Self>, String> {
+        let rules = config
+            .rules
+            .iter()
+            .map(AlertRule::from_config)
+            .collect::<Result<Vec<_>, _>>()?;
+        if rules.is_empty() {
+            return Ok(None);
+        }
+
+        let routes = config
+            .routing
+            .iter()
+            .flatten()
+            .map(|(severity, channels)| {
+                Ok((
+                    AlertSeverity::parse(severity)?,
+                    AlertRoute::from_channels(channels)?,
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, String>>()?;
+        if config.webhook.is_none() && routes.values().any(|route| route.webhook) {
+            return Err("alerts routed to the webhook need a webhook URL".to_string());
+        }
+
+        Ok(Some(Self {
+            rules,
+            routes,
+            default_route: AlertRoute {
+                bell: config.bell.unwrap_or(false),
+                desktop: config.desktop.unwrap_or(false),
+                webhook: false,
+            },
+            webhook: config.webhook.clone(),
+            client: reqwest::Client::new(),
+            breaches: HashMap::new(),
+            firing: HashMap::new(),
+        }))
+    }
+
+    /// Checks the rules against a container's latest stats and health, returning the
+    /// alerts that fired just now
+    pub fn evaluate(
+        &mut self,
+        key: &ContainerKey,
+        container: &Container,
+        now: Instant,
+    ) -> Vec<FiredAlert> {
+        let mut fired = Vec::new();
+
+        for (idx, rule) in self.rules.iter().enumerate() {
+            let id = (key.clone(), idx);
+            if !rule.breached(container) {
+                self.breaches.remove(&id);
+                self.firing.remove(&id);
+                continue;
+            }
+
+            let since = *self.breaches.entry(id.clone()).or_insert(now);
+            if let Some(alert) = self.firing.get_mut(&id) {
+                alert.value = rule.value(container);
+            } else if now.duration_since(since) >= rule.duration {
+                let alert = FiredAlert {
+                    key: key.clone(),
+                    rule: *rule,
+                    value: rule.value(container),
+                    since,
+                };
+                self.firing.insert(id, alert.clone());
+                fired.push(alert);
+            }
+        }
+
+        fired
+    }
+
+    /// Drops the state of a container that stopped or is gone
+    pub fn forget(&mut self, key: &ContainerKey) {
+        self.breaches.retain(|(k, _), _| k != key);
+        self.firing.retain(|(k, _), _| k != key);
+    }
+
+    /// Alerts in effect, oldest first
+    pub fn firing(&self) -> Vec<&FiredAlert> {
+        let mut firing: Vec<&FiredAlert> = self.firing.values().collect();
+        firing.sort_by_key(|alert| alert.since);
+        firing
+    }
+
+    /// How alerts of the severity are signaled
+    pub fn route(&self, severity: AlertSeverity) -> AlertRoute {
+        self.routes
+            .get(&severity)
+            .copied()
+            .unwrap_or(self.default_route)
+    }
+
+    /// Rings the bell, asks for a desktop notification and/or calls the webhook, as
+    /// routed for the alert's severity. Only critical alerts are signaled in
+    /// do-not-disturb mode
+    pub fn notify(&self, alert: &FiredAlert, name: &str, do_not_disturb: bool) {
+        if do_not_disturb && alert.rule.severity < AlertSeverity::Critical {
+            return;
+        }
+        let route = self.route(alert.rule.severity);
+
+        if route.webhook
+            && let Some(url) = &self.webhook
+            // Not within a runtime in tests
+            && let Ok(runtime) = tokio::runtime::Handle::try_current()
+        {
+            let body = json!({
+                "host": alert.key.host_id,
+                "container": name,
+                "severity": alert.rule.severity.name(),
+                "summary": alert.summary(name),
+            });
+            let request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+            runtime.spawn(async move {
+                if let Err(e) = request.send().await.and_then(|r| r.error_for_status()) {
+                    tracing::warn!("Failed to call the alert webhook: {}", e);
+                }
+            });
+        }
+
+        if !route.bell && !route.desktop {
+            return;
+        }
+
+        let mut stdout = std::io::stdout();
+        if route.bell {
+            let _ = write!(stdout, "\x07");
+        }
+        if route.desktop {
+            // Control characters would end the sequence early
+            let summary: String = alert
+                .summary(name)
+                .chars()
+                .filter(|c| !c.is_control())
+                .collect();
+            let _ = write!(stdout, "\x1b]9;dtop: {}\x07", summary);
+        }
+        let _ = stdout.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ContainerStats;
+
+    fn container(cpu: f64, health: Option<HealthStatus>) -> Container {
+        Container {
+            health,
+            stats: ContainerStats {
+                cpu,
+                ..ContainerStats::default()
+            },
+            ..Container::test_default()
+        }
+    }
+
+    fn alerts(rules: Vec<AlertRuleConfig>) -> Alerts {
+        Alerts::from_config(&AlertsConfig {
+            rules,
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap()
+    }
+
+    fn rule(metric: &str, above: Option<f64>, for_secs: Option<u64>) -> AlertRuleConfig {
+        AlertRuleConfig {
+            metric: metric.to_string(),
+            above,
+            for_secs,
+            severity: None,
+        }
+    }
+
+    #[test]
+    fn test_cpu_alert_fires_after_duration() {
+        let mut alerts = alerts(vec![rule("cpu", Some(90.0), Some(30))]);
+        let key = ContainerKey::new("local".to_string(), "abc123456789".to_string());
+        let start = Instant::now();
+
+        assert!(
+            alerts
+                .evaluate(&key, &container(95.0, None), start)
+                .is_empty()
+        );
+        let later = start + Duration::from_secs(29);
+        assert!(
+            alerts
+                .evaluate(&key, &container(97.0, None), later)
+                .is_empty()
+        );
+
+        let fired = alerts.evaluate(
+            &key,
+            &container(96.0, None),
+            start + Duration::from_secs(30),
+        );
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].value, Some(96.0));
+        assert_eq!(fired[0].since, start);
+
+        // Fires once, then stays in effect with the latest value
+        let later = start + Duration::from_secs(31);
+        assert!(
+            alerts
+                .evaluate(&key, &container(99.0, None), later)
+                .is_empty()
+        );
+        assert_eq!(alerts.firing()[0].value, Some(99.0));
+
+        // A dip below the threshold clears it and restarts the clock
+        let later = start + Duration::from_secs(32);
+        assert!(
+            alerts
+                .evaluate(&key, &container(50.0, None), later)
+                .is_empty()
+        );
+        assert!(alerts.firing().is_empty());
+        let later = start + Duration::from_secs(33);
+        assert!(
+            alerts
+                .evaluate(&key, &container(95.0, None), later)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_unhealthy_alert_and_forget() {
+        let mut alerts = alerts(vec![rule("unhealthy", None, None)]);
+        let key = ContainerKey::new("local".to_string(), "abc123456789".to_string());
+        let now = Instant::now();
+
+        let healthy = container(0.0, Some(HealthStatus::Healthy));
+        assert!(alerts.evaluate(&key, &healthy, now).is_empty());
+        let unhealthy = container(0.0, Some(HealthStatus::Unhealthy));
+        let fired = alerts.evaluate(&key, &unhealthy, now);
+        assert_eq!(fired[0].summary("web"), "web: unhealthy");
+
+        alerts.forget(&key);
+        assert!(alerts.firing().is_empty());
+    }
+
+    #[test]
+    fn test_alerts_from_config() {
+        let config = |rules| AlertsConfig {
+            rules,
+            bell: Some(true),
+            ..Default::default()
+        };
+        assert!(Alerts::from_config(&config(Vec::new())).unwrap().is_none());
+        assert!(Alerts::from_config(&config(vec![rule("cpu", None, None)])).is_err());
+        assert!(Alerts::from_config(&config(vec![rule("disk", Some(1.0), None)])).is_err());
+
+        let alerts = Alerts::from_config(&config(vec![rule("memory", Some(95.0), None)]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            alerts.rules,
+            vec![AlertRule {
+                condition: AlertCondition::Memory(95.0),
+                duration: Duration::ZERO,
+                severity: AlertSeverity::Warning,
+            }]
+        );
+        assert!(alerts.route(AlertSeverity::Warning).bell);
+    }
+
+    #[test]
+    fn test_alert_routing() {
+        let mut critical = rule("unhealthy", None, None);
+        critical.severity = Some("critical".to_string());
+        let config = |routing: &[(&str, &[&str])], webhook: Option<&str>| AlertsConfig {
+            rules: vec![critical.clone()],
+            bell: Some(true),
+            webhook: webhook.map(str::to_string),
+            routing: Some(
+                routing
+                    .iter()
+                    .map(|(severity, channels)| {
+                        (
+                            severity.to_string(),
+                            channels.iter().map(|c| c.to_string()).collect(),
+                        )
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let alerts = Alerts::from_config(&config(
+            &[("info", &["toast"]), ("critical", &["bell", "webhook"])],
+            Some("http://localhost:9000/alerts"),
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(alerts.rules[0].severity, AlertSeverity::Critical);
+        assert_eq!(alerts.route(AlertSeverity::Info), AlertRoute::default());
+        assert_eq!(
+            alerts.route(AlertSeverity::Critical),
+            AlertRoute {
+                bell: true,
+                desktop: false,
+                webhook: true,
+            }
+        );
+        // Not routed: the bell as configured for all alerts
+        assert!(alerts.route(AlertSeverity::Warning).bell);
+
+        // The webhook needs a URL, and names are checked
+        assert!(Alerts::from_config(&config(&[("critical", &["webhook"])], None)).is_err());
+        assert!(Alerts::from_config(&config(&[("urgent", &["bell"])], None)).is_err());
+        assert!(Alerts::from_config(&config(&[("info", &["email"])], None)).is_err());
+        let mut unknown = config(&[], None);
+        unknown.rules[0].severity = Some("high".to_string());
+        assert!(Alerts::from_config(&unknown).is_err());
+    }
+}
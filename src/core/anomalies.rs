@@ -0,0 +1,217 @@
+//! Anomaly highlighting from a rolling baseline per container metric, from the
+//! `anomalies:` config section
+//!
+//! Each metric of a container keeps its most recent samples as a baseline. A value more
+//! than `sigma` standard deviations away from their mean is unusual for that container
+//! and is highlighted in the container list. Values are only judged once enough samples
+//! are in, and the deviation has a floor per metric so the jitter of an idle container
+//! doesn't count as unusual.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::cli::config::AnomaliesConfig;
+use crate::core::types::{ContainerKey, ContainerStats};
+
+/// Standard deviations from the baseline a value must exceed when the config doesn't say
+pub const DEFAULT_ANOMALY_SIGMA: f64 = 3.0;
+
+/// Samples in a baseline when the config doesn't say (about 5 minutes of stats)
+pub const DEFAULT_ANOMALY_SAMPLES: usize = 300;
+
+/// Samples a baseline needs before values are judged against it
+const MIN_SAMPLES: usize = 30;
+
+/// A container metric with a baseline
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AnomalyMetric {
+    Cpu,
+    Memory,
+    NetworkTx,
+    NetworkRx,
+}
+
+impl AnomalyMetric {
+    pub const ALL: [AnomalyMetric; 4] = [
+        AnomalyMetric::Cpu,
+        AnomalyMetric::Memory,
+        AnomalyMetric::NetworkTx,
+        AnomalyMetric::NetworkRx,
+    ];
+
+    fn value(self, stats: &ContainerStats) -> f64 {
+        match self {
+            AnomalyMetric::Cpu => stats.cpu,
+            AnomalyMetric::Memory => stats.memory,
+            AnomalyMetric::NetworkTx => stats.network_tx_bytes_per_sec,
+            AnomalyMetric::NetworkRx => stats.network_rx_bytes_per_sec,
+        }
+    }
+
+    /// Smallest standard deviation assumed for the metric, in its unit
+    fn min_deviation(self) -> f64 {
+        match self {
+            // Percentage points
+            AnomalyMetric::Cpu => 2.0,
+            AnomalyMetric::Memory => 1.0,
+            // 10 KB/s
+            AnomalyMetric::NetworkTx | AnomalyMetric::NetworkRx => 10.0 * 1024.0,
+        }
+    }
+}
+
+/// The recent samples of a metric
+#[derive(Debug, Default)]
+struct Baseline {
+    samples: VecDeque<f64>,
+}
+
+impl Baseline {
+    /// Mean and standard deviation of the samples, once there are enough of them
+    fn mean_deviation(&self) -> Option<(f64, f64)> {
+        if self.samples.len() < MIN_SAMPLES {
+            return None;
+        }
+        let count = self.samples.len() as f64;
+        let mean = self.samples.iter().sum::<f64>() / count;
+        let variance = self
+            .samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        Some((mean, variance.sqrt()))
+    }
+
+    fn push(&mut self, value: f64, capacity: usize) {
+        self.samples.push_back(value);
+        while self.samples.len() > capacity {
+            self.samples.pop_front();
+        }
+    }
+}
+
+/// Baselines of a container and the metrics currently unusual
+#[derive(Debug, Default)]
+struct ContainerBaselines {
+    baselines: HashMap<AnomalyMetric, Baseline>,
+    anomalous: HashSet<AnomalyMetric>,
+}
+
+/// Rolling baselines of every container's metrics
+#[derive(Debug)]
+pub struct Anomalies {
+    sigma: f64,
+    samples: usize,
+    containers: HashMap<ContainerKey, ContainerBaselines>,
+}
+
+impl Anomalies {
+    pub fn from_config(config: &AnomaliesConfig) -> Result<Self, String> {
+        let sigma = config.sigma.unwrap_or(DEFAULT_ANOMALY_SIGMA);
+        if sigma <= 0.0 {
+            return Err(format!("anomalies sigma must be positive, got {}", sigma));
+        }
+        let samples = config.samples.unwrap_or(DEFAULT_ANOMALY_SAMPLES);
+        if samples < MIN_SAMPLES {
+            return Err(format!(
+                "anomalies samples must be at least {}, got {}",
+                MIN_SAMPLES, samples
+            ));
+        }
+        Ok(Self {
+            sigma,
+            samples,
+            containers: HashMap::new(),
+        })
+    }
+
+    /// Judges a container's new stats against its baselines, then adds them to the
+    /// baselines so a lasting change becomes the new normal
+    pub fn observe(&mut self, key: &ContainerKey, stats: &ContainerStats) {
+        let container = self.containers.entry(key.clone()).or_default();
+        for metric in AnomalyMetric::ALL {
+            let value = metric.value(stats);
+            let baseline = container.baselines.entry(metric).or_default();
+
+            let unusual = baseline.mean_deviation().is_some_and(|(mean, deviation)| {
+                (value - mean).abs() > self.sigma * deviation.max(metric.min_deviation())
+            });
+            if unusual {
+                container.anomalous.insert(metric);
+            } else {
+                container.anomalous.remove(&metric);
+            }
+
+            baseline.push(value, self.samples);
+        }
+    }
+
+    /// Whether the container's latest value of the metric is unusual
+    pub fn is_anomalous(&self, key: &ContainerKey, metric: AnomalyMetric) -> bool {
+        self.containers
+            .get(key)
+            .is_some_and(|container| container.anomalous.contains(&metric))
+    }
+
+    /// Drops the baselines of a container that is gone
+    pub fn forget(&mut self, key: &ContainerKey) {
+        self.containers.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(cpu: f64) -> ContainerStats {
+        ContainerStats {
+            cpu,
+            memory: 20.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_anomalies_from_config() {
+        let anomalies = Anomalies::from_config(&AnomaliesConfig::default()).unwrap();
+        assert_eq!(anomalies.sigma, DEFAULT_ANOMALY_SIGMA);
+        assert_eq!(anomalies.samples, DEFAULT_ANOMALY_SAMPLES);
+
+        let config = |sigma, samples| AnomaliesConfig {
+            sigma: Some(sigma),
+            samples: Some(samples),
+        };
+        assert!(Anomalies::from_config(&config(0.0, 300)).is_err());
+        assert!(Anomalies::from_config(&config(3.0, 5)).is_err());
+    }
+
+    #[test]
+    fn test_anomaly_against_baseline() {
+        let mut anomalies = Anomalies::from_config(&AnomaliesConfig::default()).unwrap();
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+
+        // Not judged until the baseline has enough samples
+        let new_key = ContainerKey::new("local".to_string(), "def456".to_string());
+        anomalies.observe(&new_key, &stats(10.0));
+        anomalies.observe(&new_key, &stats(90.0));
+        assert!(!anomalies.is_anomalous(&new_key, AnomalyMetric::Cpu));
+
+        for i in 0..MIN_SAMPLES {
+            anomalies.observe(&key, &stats(if i % 2 == 0 { 10.0 } else { 14.0 }));
+        }
+        // Within the deviation floor of the mean
+        anomalies.observe(&key, &stats(16.0));
+        assert!(!anomalies.is_anomalous(&key, AnomalyMetric::Cpu));
+
+        anomalies.observe(&key, &stats(60.0));
+        assert!(anomalies.is_anomalous(&key, AnomalyMetric::Cpu));
+        assert!(!anomalies.is_anomalous(&key, AnomalyMetric::Memory));
+
+        // Cleared once the value is back to normal
+        anomalies.observe(&key, &stats(12.0));
+        assert!(!anomalies.is_anomalous(&key, AnomalyMetric::Cpu));
+
+        anomalies.forget(&key);
+        assert!(!anomalies.containers.contains_key(&key));
+    }
+}
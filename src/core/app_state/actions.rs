@@ -1,5 +1,12 @@
+use std::time::Instant;
+use tui_input::Input;
+
 use crate::core::app_state::AppState;
-use crate::core::types::{ContainerAction, ContainerKey, RenderAction, ViewState};
+use crate::core::types::{
+    Container, ContainerAction, ContainerKey, NotificationKind, RenderAction, VerifyOutcome,
+    ViewState,
+};
+use crate::docker::actions::ActionOptions;
 
 impl AppState {
     pub(super) fn handle_show_action_menu(&mut self) -> RenderAction {
@@ -8,12 +15,13 @@ impl AppState {
             return RenderAction::None;
         }
 
-        // Get the selected container
-        let Some(selected_idx) = self.table_state.selected() else {
-            return RenderAction::None;
-        };
+        // A project header gets the actions for the whole project
+        if let Some(project) = self.selected_project().cloned() {
+            return self.handle_show_project_action_menu(project);
+        }
 
-        let Some(container_key) = self.sorted_container_keys.get(selected_idx) else {
+        // Get the selected container
+        let Some(container_key) = self.selected_container_key() else {
             return RenderAction::None;
         };
 
@@ -33,18 +41,94 @@ impl AppState {
             return RenderAction::Render; // Force redraw
         }
 
+        // Then the details popup
+        if self.show_details {
+            self.show_details = false;
+            return RenderAction::Render; // Force redraw
+        }
+
+        // Then the replica comparison
+        if self.show_replicas {
+            self.show_replicas = false;
+            return RenderAction::Render; // Force redraw
+        }
+
         // Handle Escape based on current view state
         match self.view_state {
             ViewState::SearchMode => {
                 // Exit search mode and clear filter
                 return self.handle_exit_search_mode();
             }
+            ViewState::LogView(_) if self.has_log_search() => {
+                return self.handle_clear_log_search();
+            }
             ViewState::LogView(_) => {
                 // Exit log view
                 return self.handle_exit_log_view();
             }
-            ViewState::ActionMenu(_) => {
-                // Exit action menu
+            ViewState::ImageHistory(_) => {
+                return self.handle_exit_image_history();
+            }
+            ViewState::ProcessList(_) => {
+                return self.handle_exit_process_list();
+            }
+            ViewState::FileBrowser(_) => {
+                return self.handle_exit_file_browser();
+            }
+            ViewState::EnvVars(_) => {
+                return self.handle_cancel_env_vars();
+            }
+            ViewState::ErrorDetail => {
+                return self.handle_close_error_detail();
+            }
+            ViewState::Build => {
+                return self.handle_close_build();
+            }
+            ViewState::ExecOutput(_) => {
+                return self.handle_close_exec_output();
+            }
+            ViewState::NetworkPicker(_) => {
+                return self.handle_close_network_picker();
+            }
+            ViewState::ExportMenu => {
+                return self.handle_close_export_menu();
+            }
+            ViewState::PruneMenu(_) => {
+                return self.handle_cancel_prune_menu();
+            }
+            ViewState::MaintenanceMenu(_) => {
+                return self.handle_close_maintenance_menu();
+            }
+            ViewState::CopyMenu(_) => {
+                return self.handle_close_copy_menu();
+            }
+            ViewState::VolumeList => {
+                return self.handle_exit_volumes();
+            }
+            ViewState::Cleanup => {
+                return self.handle_exit_cleanup();
+            }
+            ViewState::DiskUsage => {
+                return self.handle_exit_disk_usage();
+            }
+            ViewState::Timeline => {
+                return self.handle_exit_timeline();
+            }
+            ViewState::NetworkList => {
+                return self.handle_exit_networks();
+            }
+            ViewState::ShellSessions => {
+                return self.handle_exit_shell_sessions();
+            }
+            ViewState::Notifications => {
+                return self.handle_exit_notifications();
+            }
+            ViewState::ActionMenu(_)
+            | ViewState::ProjectActionMenu(_)
+            | ViewState::ProjectPlan(_, _)
+            | ViewState::StopTimeoutPrompt(_, _)
+            | ViewState::ExecPrompt(_) => {
+                // Exit action menu, plan or prompt
             }
             _ => {
                 // Ignore Escape in other views
@@ -62,6 +146,25 @@ impl AppState {
     }
 
     pub(super) fn handle_select_action_up(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::NetworkPicker(_)) {
+            return self.move_network_selection(-1);
+        }
+        if self.view_state == ViewState::ExportMenu {
+            return self.move_export_selection(-1);
+        }
+        if matches!(self.view_state, ViewState::PruneMenu(_)) {
+            return self.move_prune_selection(-1);
+        }
+        if matches!(self.view_state, ViewState::MaintenanceMenu(_)) {
+            return self.move_maintenance_selection(-1);
+        }
+        if matches!(self.view_state, ViewState::CopyMenu(_)) {
+            return self.move_copy_selection(-1);
+        }
+        if matches!(self.view_state, ViewState::ProjectActionMenu(_)) {
+            return self.move_project_action_selection(-1);
+        }
+
         // Only handle in action menu view
         let ViewState::ActionMenu(ref container_key) = self.view_state else {
             return RenderAction::None;
@@ -72,7 +175,7 @@ impl AppState {
             return RenderAction::None;
         };
 
-        let available_actions = ContainerAction::available_for_state(&container.state);
+        let available_actions = self.actions_for(container);
 
         if available_actions.is_empty() {
             return RenderAction::None;
@@ -89,6 +192,25 @@ impl AppState {
     }
 
     pub(super) fn handle_select_action_down(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::NetworkPicker(_)) {
+            return self.move_network_selection(1);
+        }
+        if self.view_state == ViewState::ExportMenu {
+            return self.move_export_selection(1);
+        }
+        if matches!(self.view_state, ViewState::PruneMenu(_)) {
+            return self.move_prune_selection(1);
+        }
+        if matches!(self.view_state, ViewState::MaintenanceMenu(_)) {
+            return self.move_maintenance_selection(1);
+        }
+        if matches!(self.view_state, ViewState::CopyMenu(_)) {
+            return self.move_copy_selection(1);
+        }
+        if matches!(self.view_state, ViewState::ProjectActionMenu(_)) {
+            return self.move_project_action_selection(1);
+        }
+
         // Only handle in action menu view
         let ViewState::ActionMenu(ref container_key) = self.view_state else {
             return RenderAction::None;
@@ -99,7 +221,7 @@ impl AppState {
             return RenderAction::None;
         };
 
-        let available_actions = ContainerAction::available_for_state(&container.state);
+        let available_actions = self.actions_for(container);
 
         if available_actions.is_empty() {
             return RenderAction::None;
@@ -116,6 +238,18 @@ impl AppState {
     }
 
     pub(super) fn handle_execute_action(&mut self) -> RenderAction {
+        if let ViewState::ProjectActionMenu(project) = &self.view_state {
+            let project = project.clone();
+            let Some(action) = self
+                .action_menu_state
+                .selected()
+                .and_then(|idx| ContainerAction::available_for_project().get(idx).copied())
+            else {
+                return RenderAction::None;
+            };
+            return self.show_project_plan(project, action);
+        }
+
         // Only handle in action menu view
         let ViewState::ActionMenu(ref container_key) = self.view_state else {
             return RenderAction::None;
@@ -131,12 +265,25 @@ impl AppState {
             return RenderAction::None;
         };
 
-        let available_actions = ContainerAction::available_for_state(&container.state);
+        let available_actions = self.actions_for(container);
 
         let Some(&action) = available_actions.get(selected_idx) else {
             return RenderAction::None;
         };
 
+        // Prunes need what to prune picked first, maintenance how long for
+        if action == ContainerAction::Prune {
+            return self.open_prune_menu(container_key.host_id.clone());
+        }
+        if action == ContainerAction::Maintenance {
+            return self.open_maintenance_menu(container_key.host_id.clone());
+        }
+
+        // Commands need the command line entered first
+        if action == ContainerAction::ExecCommand {
+            return self.open_exec_prompt(container_key.clone());
+        }
+
         // Get the Docker host for this container
         let Some(host) = self.connected_hosts.get(&container_key.host_id) else {
             // Silently fail if host not found
@@ -154,16 +301,100 @@ impl AppState {
             return RenderAction::StartShell(container_key_clone);
         }
 
+        // Files are listed in the background once the browser is shown
+        if action == ContainerAction::BrowseFiles {
+            return self.open_file_browser(container_key.clone(), host.clone());
+        }
+
+        // The health check runs in the background, its output shown in a pane
+        if action == ContainerAction::RunHealthcheck {
+            return self.open_healthcheck_output(container_key.clone(), host.clone());
+        }
+
+        // systemctl runs on the host itself rather than through Docker
+        if action == ContainerAction::RestartUnit {
+            if let (Some(command_host), Some(unit)) =
+                (host.command_host.clone(), container.systemd_unit())
+            {
+                tokio::spawn(crate::docker::systemd::execute_unit_restart(
+                    command_host,
+                    container_key.clone(),
+                    unit.to_string(),
+                    self.event_tx.clone(),
+                ));
+            }
+
+            // Close the action menu immediately
+            self.view_state = ViewState::ContainerList;
+            self.action_menu_state.select(None);
+
+            return RenderAction::Render; // Force draw
+        }
+
+        // Network actions need a network picked first
+        if matches!(
+            action,
+            ContainerAction::ConnectNetwork | ContainerAction::DisconnectNetwork
+        ) {
+            return self.open_network_picker(container_key.clone(), action);
+        }
+
+        // Image actions need the image name and registry credentials
+        if matches!(
+            action,
+            ContainerAction::PullImage | ContainerAction::PushImage
+        ) {
+            let host_clone = host.clone();
+            let container_key_clone = container_key.clone();
+            let image = container.image.clone();
+            let credentials = self.registry_credentials.clone();
+            let tx_clone = self.event_tx.clone();
+
+            tokio::spawn(async move {
+                crate::docker::actions::execute_image_action(
+                    host_clone,
+                    container_key_clone,
+                    action,
+                    image,
+                    credentials,
+                    tx_clone,
+                )
+                .await;
+            });
+
+            // Close the action menu immediately
+            self.view_state = ViewState::ContainerList;
+            self.action_menu_state.select(None);
+
+            return RenderAction::Render; // Force draw
+        }
+
+        let container_key = container_key.clone();
+        self.spawn_container_action(container_key, action, self.stop_timeout)
+    }
+
+    /// Runs a start/stop/restart/remove in the background and closes the action menu
+    fn spawn_container_action(
+        &mut self,
+        container_key: ContainerKey,
+        action: ContainerAction,
+        stop_timeout: u32,
+    ) -> RenderAction {
+        let Some(host) = self.connected_hosts.get(&container_key.host_id) else {
+            return RenderAction::None;
+        };
+
         // Spawn async task to execute the action
         let host_clone = host.clone();
-        let container_key_clone = container_key.clone();
+        let options = self.action_options(stop_timeout);
         let tx_clone = self.event_tx.clone();
 
         tokio::spawn(async move {
             crate::docker::actions::execute_container_action(
                 host_clone,
-                container_key_clone,
+                container_key,
                 action,
+                options,
                 tx_clone,
             )
             .await;
@@ -176,6 +407,107 @@ impl AppState {
         RenderAction::Render // Force draw
     }
 
+    /// How container actions are run, with the given stop timeout
+    pub(super) fn action_options(&self, stop_timeout: u32) -> ActionOptions {
+        ActionOptions {
+            stop_timeout,
+            snapshots: self.log_snapshots.clone(),
+            verify_secs: self.verify_secs,
+            retries: self.action_retries,
+        }
+    }
+
+    /// Asks for the stop timeout of the highlighted Stop or Restart, starting from the
+    /// configured one
+    pub(super) fn handle_prompt_stop_timeout(&mut self) -> RenderAction {
+        let ViewState::ActionMenu(ref container_key) = self.view_state else {
+            return RenderAction::None;
+        };
+        let Some(action) = self.highlighted_action() else {
+            return RenderAction::None;
+        };
+        if !matches!(action, ContainerAction::Stop | ContainerAction::Restart) {
+            return RenderAction::None;
+        }
+
+        self.view_state = ViewState::StopTimeoutPrompt(container_key.clone(), action);
+        self.stop_timeout_input = Input::new(self.stop_timeout.to_string());
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// The actions in a container's action menu: those for its state, restarting its
+    /// systemd unit when it has one on a host commands can be run on, and pruning its host
+    /// or putting it in maintenance
+    pub fn actions_for(&self, container: &Container) -> Vec<ContainerAction> {
+        let mut actions = ContainerAction::available_for_state(&container.state);
+        let has_command_host = self
+            .connected_hosts
+            .get(&container.host_id)
+            .is_some_and(|host| host.command_host.is_some());
+        if !actions.is_empty() && has_command_host && container.systemd_unit().is_some() {
+            actions.push(ContainerAction::RestartUnit);
+        }
+        if !actions.is_empty() {
+            actions.push(ContainerAction::Prune);
+            actions.push(ContainerAction::Maintenance);
+        }
+        actions
+    }
+
+    /// The action highlighted in a container's action menu
+    pub fn highlighted_action(&self) -> Option<ContainerAction> {
+        let ViewState::ActionMenu(ref container_key) = self.view_state else {
+            return None;
+        };
+        let container = self.containers.get(container_key)?;
+        let index = self.action_menu_state.selected()?;
+        self.actions_for(container).get(index).copied()
+    }
+
+    /// Edits the stop timeout; only digits are taken
+    pub(super) fn handle_stop_timeout_key_event(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+
+        if !matches!(
+            key_event.code,
+            KeyCode::Char('0'..='9')
+                | KeyCode::Backspace
+                | KeyCode::Delete
+                | KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Home
+                | KeyCode::End
+        ) {
+            return RenderAction::None;
+        }
+
+        use tui_input::backend::crossterm::EventHandler;
+        self.stop_timeout_input
+            .handle_event(&crossterm::event::Event::Key(key_event));
+
+        RenderAction::Render // Force redraw to show the edited timeout
+    }
+
+    /// Runs the prompted Stop or Restart with the entered timeout
+    /// (the configured one when left empty)
+    pub(super) fn handle_run_with_stop_timeout(&mut self) -> RenderAction {
+        let ViewState::StopTimeoutPrompt(ref container_key, action) = self.view_state else {
+            return RenderAction::None;
+        };
+        let container_key = container_key.clone();
+        let stop_timeout = self
+            .stop_timeout_input
+            .value()
+            .parse()
+            .unwrap_or(self.stop_timeout);
+
+        self.spawn_container_action(container_key, action, stop_timeout)
+    }
+
     pub(super) fn handle_action_in_progress(
         &mut self,
         _key: ContainerKey,
@@ -188,23 +520,67 @@ impl AppState {
 
     pub(super) fn handle_action_success(
         &mut self,
-        _key: ContainerKey,
+        key: ContainerKey,
         _action: ContainerAction,
     ) -> RenderAction {
+        // A retried action no longer shows as retrying
+        if self.retrying_actions.remove(&key).is_some() {
+            return RenderAction::Render;
+        }
+
         // TODO: Could show a success toast/notification in the UI in the future
         // The container state will be updated by Docker events
         // so we don't need to manually update it here
         RenderAction::None // Don't force redraw - Docker events will trigger updates
     }
 
+    /// Shows that an action is tried again, until it ends, and keeps why in the
+    /// notifications panel
+    pub(super) fn handle_action_retrying(
+        &mut self,
+        key: ContainerKey,
+        action: ContainerAction,
+        retry: u32,
+        error: String,
+    ) -> RenderAction {
+        tracing::debug!("Retrying {:?} of {}: {}", action, key.short_id(), error);
+        self.notify(
+            self.container_label(&key),
+            NotificationKind::ActionRetrying(action, retry, error),
+        );
+        self.retrying_actions.insert(key, (action, retry));
+        RenderAction::Render // Redraw to show the retry
+    }
+
+    /// Shows how a started or restarted container fared as a notification
+    pub(super) fn handle_action_verified(
+        &mut self,
+        key: ContainerKey,
+        action: ContainerAction,
+        outcome: VerifyOutcome,
+    ) -> RenderAction {
+        self.notify(
+            self.container_label(&key),
+            NotificationKind::ActionVerified(action, outcome.clone()),
+        );
+        self.action_outcomes
+            .insert(key, (action, outcome, Instant::now()));
+        RenderAction::Render // Redraw to show the notification
+    }
+
+    /// Keeps the error in the notifications panel; the container state won't change
     pub(super) fn handle_action_error(
         &mut self,
-        _key: ContainerKey,
-        _action: ContainerAction,
-        _error: String,
+        key: ContainerKey,
+        action: ContainerAction,
+        error: String,
     ) -> RenderAction {
-        // TODO: Could show an error toast/notification in the UI in the future
-        // For now, silently fail - the container state won't change on error
-        RenderAction::None // Don't force redraw for error messages
+        tracing::warn!("{:?} of {} failed: {}", action, key.short_id(), error);
+        self.retrying_actions.remove(&key);
+        self.notify(
+            self.container_label(&key),
+            NotificationKind::ActionFailed(action, error),
+        );
+        RenderAction::Render // Redraw to update the unacknowledged count
     }
 }
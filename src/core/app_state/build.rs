@@ -0,0 +1,158 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{BuildField, BuildLine, BuildState, BuildStatus, RenderAction, ViewState};
+use crate::docker::build::{build_image, resolve_build_context};
+use crate::ui::i18n;
+
+impl AppState {
+    pub(super) fn handle_show_build_dialog(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        // Build on the selected container's host, or the first connected host
+        let host_id = self
+            .selected_container_key()
+            .map(|key| key.host_id.clone())
+            .or_else(|| self.connected_hosts.keys().min().cloned());
+        let Some(host_id) = host_id else {
+            return RenderAction::None;
+        };
+
+        self.build = Some(BuildState::new(host_id));
+        self.view_state = ViewState::Build;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Passes typing to the focused build dialog field
+    pub(super) fn handle_build_key_event(&mut self, key_event: KeyEvent) -> RenderAction {
+        let Some(build) = &mut self.build else {
+            return RenderAction::None;
+        };
+        if build.status != BuildStatus::Editing {
+            return RenderAction::None;
+        }
+
+        match key_event.code {
+            // Handled by handle_start_build and handle_close_build
+            KeyCode::Enter | KeyCode::Esc => return RenderAction::None,
+            KeyCode::Tab | KeyCode::BackTab => {
+                build.focus = match build.focus {
+                    BuildField::Context => BuildField::Tag,
+                    BuildField::Tag => BuildField::Context,
+                };
+            }
+            _ => {
+                use tui_input::backend::crossterm::EventHandler;
+                let input = match build.focus {
+                    BuildField::Context => &mut build.context_input,
+                    BuildField::Tag => &mut build.tag_input,
+                };
+                input.handle_event(&crossterm::event::Event::Key(key_event));
+                build.error = None;
+            }
+        }
+
+        RenderAction::Render // Force draw - input changed
+    }
+
+    pub(super) fn handle_start_build(&mut self) -> RenderAction {
+        let Some(build) = &mut self.build else {
+            return RenderAction::None;
+        };
+        if build.status != BuildStatus::Editing {
+            return RenderAction::None;
+        }
+
+        let context = match resolve_build_context(build.context_input.value()) {
+            Ok(context) => context,
+            Err(error) => {
+                build.error = Some(error);
+                return RenderAction::Render;
+            }
+        };
+
+        let Some(host) = self.connected_hosts.get(&build.host_id).cloned() else {
+            build.error = Some(i18n::fill(
+                i18n::messages().host_not_connected,
+                &[&build.host_id],
+            ));
+            return RenderAction::Render;
+        };
+
+        let tag = Some(build.tag_input.value().trim().to_string()).filter(|t| !t.is_empty());
+        let tx = self.event_tx.clone();
+        build.handle = Some(tokio::spawn(build_image(host, context, tag, tx)));
+        build.status = BuildStatus::Running;
+
+        RenderAction::Render // Force draw - switch to the output panel
+    }
+
+    pub(super) fn handle_build_output(&mut self, line: BuildLine) -> RenderAction {
+        let Some(build) = &mut self.build else {
+            return RenderAction::None;
+        };
+        build.output.push(line);
+        RenderAction::Render // Force draw - new output
+    }
+
+    pub(super) fn handle_build_finished(&mut self, result: Result<(), String>) -> RenderAction {
+        let Some(build) = &mut self.build else {
+            return RenderAction::None;
+        };
+
+        build.handle = None;
+        build.status = match result {
+            Ok(()) => BuildStatus::Succeeded,
+            Err(error) => {
+                // Errors before the stream started (e.g. archiving) have no output line yet
+                if build.output.last().is_none_or(|line| line.text != error) {
+                    build.output.push(BuildLine {
+                        text: error,
+                        is_error: true,
+                    });
+                }
+                BuildStatus::Failed
+            }
+        };
+
+        RenderAction::Render // Force draw - status changed
+    }
+
+    /// Closes the dialog or output panel, cancelling a running build
+    pub(super) fn handle_close_build(&mut self) -> RenderAction {
+        if let Some(handle) = self.build.take().and_then(|build| build.handle) {
+            handle.abort();
+        }
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Scrolls the build output by `delta` lines (clamped when rendering)
+    pub(super) fn scroll_build_output(&mut self, delta: isize) -> RenderAction {
+        let Some(build) = &mut self.build else {
+            return RenderAction::None;
+        };
+        // Keys are text while the dialog is open
+        if build.status == BuildStatus::Editing {
+            return RenderAction::None;
+        }
+
+        build.scroll_offset = build.scroll_offset.saturating_add_signed(delta);
+        build.follow = false;
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Whether keys should be treated as text for the build dialog
+    pub(super) fn is_editing_build(&self) -> bool {
+        self.view_state == ViewState::Build
+            && self
+                .build
+                .as_ref()
+                .is_some_and(|build| build.status == BuildStatus::Editing)
+    }
+}
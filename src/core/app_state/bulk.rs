@@ -0,0 +1,53 @@
+use std::time::Instant;
+
+use crate::core::app_state::AppState;
+use crate::core::bulk::BulkRun;
+use crate::core::types::{BulkKind, BulkProgress, RenderAction};
+
+impl AppState {
+    /// Registers a bulk action of `total` items for the progress banner, returning the
+    /// handle its tasks run the items through
+    pub(super) fn start_bulk(&mut self, kind: BulkKind, total: usize) -> BulkRun {
+        let id = self.next_bulk_id;
+        self.next_bulk_id += 1;
+        self.bulk_progress.insert(
+            id,
+            BulkProgress {
+                kind,
+                total,
+                done: 0,
+                failed: 0,
+                // Nothing to wait for
+                finished_at: (total == 0).then(Instant::now),
+            },
+        );
+        BulkRun::new(id, self.bulk_concurrency, self.event_tx.clone())
+    }
+
+    pub(super) fn handle_bulk_item_done(&mut self, id: u64, ok: bool) -> RenderAction {
+        let Some(progress) = self.bulk_progress.get_mut(&id) else {
+            return RenderAction::None;
+        };
+
+        if ok {
+            progress.done += 1;
+        } else {
+            progress.failed += 1;
+        }
+        if progress.remaining() == 0 {
+            progress.finished_at = Some(Instant::now());
+        }
+
+        RenderAction::Render // Force draw - progress changed
+    }
+
+    pub(super) fn handle_bulk_stopped(&mut self, id: u64) -> RenderAction {
+        let Some(progress) = self.bulk_progress.get_mut(&id) else {
+            return RenderAction::None;
+        };
+
+        progress.finished_at.get_or_insert_with(Instant::now);
+
+        RenderAction::Render // Force draw - progress changed
+    }
+}
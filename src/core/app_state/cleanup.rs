@@ -0,0 +1,211 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    AppEvent, BulkKind, CleanupCandidate, CleanupSelection, CleanupState, HostId, RenderAction,
+    ViewState,
+};
+use crate::docker::cleanup::{fetch_cleanup_candidates, remove_cleanup_candidates};
+use crate::docker::connection::DockerHost;
+
+impl AppState {
+    pub(super) fn handle_show_cleanup(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let mut cleanup = CleanupState::default();
+        for host in self.connected_hosts.values() {
+            cleanup.loading.insert(host.host_id.clone());
+            self.spawn_cleanup_fetch(host.clone());
+        }
+
+        self.cleanup = Some(cleanup);
+        self.view_state = ViewState::Cleanup;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Loads the cleanup candidates of a host in the background
+    fn spawn_cleanup_fetch(&self, host: DockerHost) {
+        let exited_days = self.cleanup_exited_days;
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let result = fetch_cleanup_candidates(&host, exited_days).await;
+            let _ = tx.send(AppEvent::CleanupLoaded(host.host_id, result)).await;
+        });
+    }
+
+    pub(super) fn handle_cleanup_loaded(
+        &mut self,
+        host_id: HostId,
+        result: Result<Vec<CleanupCandidate>, String>,
+    ) -> RenderAction {
+        // Ignore results arriving after the view was closed
+        let Some(cleanup) = &mut self.cleanup else {
+            return RenderAction::None;
+        };
+
+        cleanup.loading.remove(&host_id);
+        cleanup
+            .candidates
+            .retain(|candidate| candidate.host_id != host_id);
+        match result {
+            Ok(candidates) => cleanup.candidates.extend(candidates),
+            Err(error) => cleanup.errors.push(format!("{}: {}", host_id, error)),
+        }
+        cleanup.candidates.sort_by(|a, b| {
+            a.host_id
+                .cmp(&b.host_id)
+                .then_with(|| a.kind.cmp(&b.kind))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        // Keep the selection on the list
+        let count = cleanup.candidates.len();
+        let selected = match cleanup.table_state.selected() {
+            _ if count == 0 => None,
+            Some(idx) => Some(idx.min(count - 1)),
+            None => Some(0),
+        };
+        cleanup.table_state.select(selected);
+
+        RenderAction::Render // Force draw - candidates changed
+    }
+
+    /// Asks for confirmation before removing the selected candidate
+    pub(super) fn handle_remove_cleanup_selected(&mut self) -> RenderAction {
+        self.confirm_cleanup(CleanupSelection::Selected)
+    }
+
+    /// Asks for confirmation before removing all candidates of the selected one's kind
+    pub(super) fn handle_remove_cleanup_kind(&mut self) -> RenderAction {
+        let Some(kind) = self
+            .cleanup
+            .as_ref()
+            .and_then(|cleanup| cleanup.selected())
+            .map(|candidate| candidate.kind)
+        else {
+            return RenderAction::None;
+        };
+        self.confirm_cleanup(CleanupSelection::Kind(kind))
+    }
+
+    fn confirm_cleanup(&mut self, selection: CleanupSelection) -> RenderAction {
+        if self.view_state != ViewState::Cleanup {
+            return RenderAction::None;
+        }
+        let Some(cleanup) = &mut self.cleanup else {
+            return RenderAction::None;
+        };
+        // Wait for all hosts so the confirmation covers every candidate
+        if !cleanup.loading.is_empty() || cleanup.confirm.is_some() || cleanup.selected().is_none()
+        {
+            return RenderAction::None;
+        }
+
+        cleanup.confirm = Some(selection);
+        RenderAction::Render // Force draw - show the confirmation
+    }
+
+    /// Removes the confirmed candidates, then reloads the affected hosts
+    pub(super) fn handle_confirm_cleanup(&mut self) -> RenderAction {
+        let Some(cleanup) = &mut self.cleanup else {
+            return RenderAction::None;
+        };
+        if cleanup.confirm.is_none() {
+            return RenderAction::None;
+        }
+
+        let mut by_host: std::collections::HashMap<HostId, Vec<CleanupCandidate>> =
+            Default::default();
+        for candidate in cleanup.removal_candidates() {
+            by_host
+                .entry(candidate.host_id.clone())
+                .or_default()
+                .push(candidate.clone());
+        }
+        cleanup.confirm = None;
+
+        by_host.retain(|host_id, _| self.connected_hosts.contains_key(host_id));
+        let total = by_host.values().map(Vec::len).sum();
+        let bulk = self.start_bulk(BulkKind::Cleanup, total);
+
+        let exited_days = self.cleanup_exited_days;
+        for (host_id, candidates) in by_host {
+            let Some(host) = self.connected_hosts.get(&host_id).cloned() else {
+                continue;
+            };
+            if let Some(cleanup) = &mut self.cleanup {
+                cleanup.loading.insert(host_id.clone());
+            }
+
+            let tx = self.event_tx.clone();
+            let bulk = bulk.clone();
+            tokio::spawn(async move {
+                let errors = remove_cleanup_candidates(&host, &candidates, &bulk).await;
+                let _ = tx
+                    .send(AppEvent::CleanupRemoved(host_id.clone(), errors))
+                    .await;
+                let result = fetch_cleanup_candidates(&host, exited_days).await;
+                let _ = tx.send(AppEvent::CleanupLoaded(host_id, result)).await;
+            });
+        }
+
+        RenderAction::Render // Force draw - hosts are reloading
+    }
+
+    pub(super) fn handle_cleanup_removed(
+        &mut self,
+        host_id: HostId,
+        errors: Vec<String>,
+    ) -> RenderAction {
+        let Some(cleanup) = &mut self.cleanup else {
+            return RenderAction::None;
+        };
+        if errors.is_empty() {
+            return RenderAction::None;
+        }
+
+        cleanup.errors.extend(
+            errors
+                .into_iter()
+                .map(|error| format!("{}: {}", host_id, error)),
+        );
+        RenderAction::Render // Force draw - show the errors
+    }
+
+    /// Moves the candidate selection by `delta`, staying within the list
+    pub(super) fn move_cleanup_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(cleanup) = &mut self.cleanup else {
+            return RenderAction::None;
+        };
+        if cleanup.confirm.is_some() {
+            return RenderAction::None;
+        }
+        let Some(current) = cleanup.table_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = cleanup.candidates.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        cleanup.table_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Closes the confirmation if shown, otherwise the cleanup view
+    pub(super) fn handle_exit_cleanup(&mut self) -> RenderAction {
+        if let Some(cleanup) = &mut self.cleanup
+            && cleanup.confirm.take().is_some()
+        {
+            return RenderAction::Render; // Force draw - confirmation closed
+        }
+
+        self.cleanup = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
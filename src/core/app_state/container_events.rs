@@ -1,17 +1,20 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashSet;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Utc};
 
 use crate::core::app_state::AppState;
 use crate::core::types::{
-    Container, ContainerKey, ContainerState, ContainerStats, HealthStatus, RenderAction,
-    BUCKET_DURATION_SECS, HISTORY_BUFFER_SIZE,
+    COUNT_BUCKET_SECS, Container, ContainerCounts, ContainerKey, ContainerState, ContainerStats,
+    Damage, HISTORY_BUFFER_SIZE, HealthStatus, HostId, ImagePlatform, MEMORY_TREND_BUCKET_SECS,
+    MEMORY_TREND_SIZE, NotificationKind, RenderAction, TimelineKind, current_history_bucket,
 };
 
-/// Returns the current time bucket ID for history synchronization.
-/// This aligns with the tick marker calculation in the sparkline renderer.
-fn get_current_bucket() -> u64 {
+/// Current memory trend bucket ID
+fn get_current_trend_bucket() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs() / BUCKET_DURATION_SECS)
+        .map(|d| d.as_secs() / MEMORY_TREND_BUCKET_SECS)
         .unwrap_or(0)
 }
 
@@ -21,27 +24,83 @@ impl AppState {
         host_id: String,
         container_list: Vec<Container>,
     ) -> RenderAction {
-        for container in container_list {
-            let key = ContainerKey::new(host_id.clone(), container.id.clone());
-            self.containers.insert(key.clone(), container);
-            self.sorted_container_keys.push(key);
+        // After a reconnect the list replaces the host's stale containers
+        let listed: HashSet<&str> = container_list.iter().map(|c| c.full_id.as_str()).collect();
+        let gone: Vec<ContainerKey> = self
+            .containers
+            .keys()
+            .filter(|key| key.host_id == host_id && !listed.contains(key.container_id.as_str()))
+            .cloned()
+            .collect();
+        for key in gone {
+            self.handle_container_destroyed(key);
+        }
+
+        for mut container in container_list {
+            let key = container.key();
+            if let Some(previous) = self.containers.get_mut(&key) {
+                // Keep what only this session knows, including the stats history
+                container.session_restarts = previous.session_restarts;
+                container.size_rw = previous.size_rw;
+                container.platform = previous.platform.take();
+                container.time_to_healthy = previous.time_to_healthy;
+                container.stats = std::mem::take(&mut previous.stats);
+            } else {
+                self.sorted_container_keys.push(key.clone());
+            }
+            self.containers.insert(key, container);
+        }
+
+        self.record_container_counts(&host_id);
+
+        // Size column enabled in the config: fetch sizes once the host's list is in
+        if self.show_size_column {
+            self.spawn_size_fetch(&host_id);
         }
 
         // Force immediate sort when loading initial container list
         self.force_sort_containers();
 
-        // Select first row if we have containers
-        if !self.containers.is_empty() {
+        // Select first row if we have containers and nothing is selected yet
+        if !self.containers.is_empty() && self.table_state.selected().is_none() {
             self.table_state.select(Some(0));
         }
 
         RenderAction::Render // Force draw - table structure changed
     }
 
-    pub(super) fn handle_container_created(&mut self, container: Container) -> RenderAction {
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+    pub(super) fn handle_container_created(&mut self, mut container: Container) -> RenderAction {
+        let key = container.key();
+
+        // A known container starting again is a restart
+        let mut restarts = 0;
+        if let Some(previous) = self.containers.get_mut(&key) {
+            // Docker's count catches restarts whose events were coalesced or missed
+            restarts = (container.restart_count - previous.restart_count).max(1) as usize;
+            container.session_restarts = previous.session_restarts + 1;
+            // Keep the last known size until the next refresh
+            container.size_rw = previous.size_rw;
+            // Same until the platform of the (possibly updated) image comes in
+            container.platform = previous.platform.clone();
+            // Kept until this start is measured
+            container.time_to_healthy = previous.time_to_healthy;
+            container.stats = std::mem::take(&mut previous.stats);
+        } else if let Some(stats) = self
+            .retired_stats
+            .remove(&(key.host_id.clone(), container.name.clone()))
+        {
+            // Recreated (e.g. by compose): continue the graphs of the container it replaces
+            container.stats = stats;
+        }
+        container.stats.mark_deployment();
+        if restarts > 0 {
+            self.record_restarts(&key, restarts);
+        }
+
         self.containers.insert(key.clone(), container);
-        self.sorted_container_keys.push(key);
+        self.sorted_container_keys.push(key.clone());
+        self.record_container_counts(&key.host_id);
+        self.record_timeline(&key, TimelineKind::Started);
 
         // Force immediate sort when new container is added
         self.force_sort_containers();
@@ -55,34 +114,92 @@ impl AppState {
     }
 
     pub(super) fn handle_container_destroyed(&mut self, key: ContainerKey) -> RenderAction {
-        self.containers.remove(&key);
-        self.sorted_container_keys.retain(|k| k != &key);
-
-        // Adjust selection if needed
-        let container_count = self.containers.len();
-        if container_count == 0 {
-            self.table_state.select(None);
-        } else if let Some(selected) = self.table_state.selected()
-            && selected >= container_count
-        {
-            self.table_state.select(Some(container_count - 1));
+        // Recorded first, while the name is still known
+        self.record_timeline(&key, TimelineKind::Removed);
+        if let Some(container) = self.containers.remove(&key) {
+            self.retire_stats(container);
+        }
+        self.log_positions.remove(&key);
+        self.restart_times.remove(&key);
+        if let Some(alerts) = &mut self.alerts {
+            alerts.forget(&key);
         }
+        if let Some(anomalies) = &mut self.anomalies {
+            anomalies.forget(&key);
+        }
+        self.keep_selection(|state| {
+            state.sorted_container_keys.retain(|k| k != &key);
+            state.rebuild_list_rows();
+        });
+        self.record_container_counts(&key.host_id);
 
         RenderAction::Render // Force draw - table structure changed
     }
 
-    pub(super) fn handle_container_state_changed(
+    pub(super) fn handle_container_exited(
         &mut self,
         key: ContainerKey,
-        state: ContainerState,
+        exit_code: Option<i64>,
     ) -> RenderAction {
+        if let Some(alerts) = &mut self.alerts {
+            alerts.forget(&key);
+        }
         if let Some(container) = self.containers.get_mut(&key) {
-            container.state = state;
+            container.state = ContainerState::Exited;
+            container.finished_at = Some(Utc::now());
+            container.exit_code = exit_code;
+            self.record_container_counts(&key.host_id);
+            self.record_timeline(&key, TimelineKind::Exited(exit_code));
             return RenderAction::Render; // Force draw - state changed
         }
         RenderAction::None
     }
 
+    pub(super) fn handle_container_restart_count(
+        &mut self,
+        key: ContainerKey,
+        count: i64,
+    ) -> RenderAction {
+        if let Some(container) = self.containers.get_mut(&key) {
+            container.restart_count = count;
+            return RenderAction::Render; // Force draw - restart count is visible
+        }
+        RenderAction::None
+    }
+
+    pub(super) fn handle_container_platform(
+        &mut self,
+        key: ContainerKey,
+        platform: ImagePlatform,
+    ) -> RenderAction {
+        if let Some(container) = self.containers.get_mut(&key) {
+            container.platform = Some(platform);
+            return RenderAction::Render; // Force draw - platform is visible
+        }
+        RenderAction::None
+    }
+
+    pub(super) fn handle_container_sizes(
+        &mut self,
+        host_id: HostId,
+        result: Result<Vec<(String, i64)>, String>,
+    ) -> RenderAction {
+        let sizes = match result {
+            Ok(sizes) => sizes,
+            Err(error) => {
+                self.notify(host_id, NotificationKind::FetchFailed(error));
+                return RenderAction::Render;
+            }
+        };
+        for (container_id, size) in sizes {
+            let key = ContainerKey::new(host_id.clone(), container_id);
+            if let Some(container) = self.containers.get_mut(&key) {
+                container.size_rw = Some(size);
+            }
+        }
+        RenderAction::Render // Force draw - Size column changed
+    }
+
     pub(super) fn handle_container_stat(
         &mut self,
         key: ContainerKey,
@@ -92,10 +209,13 @@ impl AppState {
             // Preserve existing history
             let mut cpu_history = std::mem::take(&mut container.stats.cpu_history);
             let mut memory_history = std::mem::take(&mut container.stats.memory_history);
+            let mut deploy_markers = std::mem::take(&mut container.stats.deploy_markers);
+            let mut memory_trend = std::mem::take(&mut container.stats.memory_trend);
             let last_bucket = container.stats.last_history_bucket;
+            stats.history_samples = container.stats.history_samples;
 
             // Get current time bucket (synchronized with tick markers)
-            let current_bucket = get_current_bucket();
+            let current_bucket = current_history_bucket();
 
             // Only add to history if we've moved to a new time bucket
             // This ensures history samples align with tick marker intervals
@@ -111,30 +231,236 @@ impl AppState {
                     memory_history.pop_front();
                 }
 
+                // Drop the markers that scrolled out with their samples
+                stats.history_samples += 1;
+                let first_sample = stats.history_samples - cpu_history.len() as u64;
+                deploy_markers.retain(|&sample| sample >= first_sample);
+
                 stats.last_history_bucket = current_bucket;
             } else {
                 // Keep the existing bucket ID if we haven't moved to a new bucket
                 stats.last_history_bucket = last_bucket;
             }
 
+            // The memory trend takes the first sample of each of its longer buckets
+            let trend_bucket = get_current_trend_bucket();
+            if memory_trend
+                .back()
+                .is_none_or(|&(bucket, _)| bucket < trend_bucket)
+            {
+                memory_trend.push_back((trend_bucket, stats.memory_used_bytes));
+                while memory_trend.len() > MEMORY_TREND_SIZE {
+                    memory_trend.pop_front();
+                }
+            }
+
             // Assign history to the new stats
             stats.cpu_history = cpu_history;
             stats.memory_history = memory_history;
+            stats.deploy_markers = deploy_markers;
+            stats.memory_trend = memory_trend;
+
+            if let Some(anomalies) = &mut self.anomalies {
+                anomalies.observe(&key, &stats);
+            }
 
             // Always update displayed values (responsive current values)
+            if container.stats.shown_differently(&stats) {
+                self.damage = self.damage.max(Damage::Stats);
+            }
             container.stats = stats;
+
+            if let Some(history) = &mut self.history {
+                history.record_stats(container);
+            }
+
+            if self.evaluate_alerts(&key) {
+                return RenderAction::Render; // Force draw - alert banner shown
+            }
         }
         RenderAction::None // No force draw - just stats update
     }
 
+    /// Checks the alert rules against a container after its stats or health changed,
+    /// signaling the alerts that fire. Returns whether any fired
+    /// Alerts of hosts in maintenance are tracked but not signaled
+    fn evaluate_alerts(&mut self, key: &ContainerKey) -> bool {
+        let label = self.container_label(key);
+        let in_maintenance = self.in_maintenance(&key.host_id);
+        let (Some(alerts), Some(container)) = (&mut self.alerts, self.containers.get(key)) else {
+            return false;
+        };
+
+        let fired = alerts.evaluate(key, container, Instant::now());
+        if in_maintenance {
+            return false;
+        }
+        for alert in &fired {
+            alerts.notify(alert, &label, self.do_not_disturb);
+        }
+        for alert in &fired {
+            self.notify(
+                label.clone(),
+                NotificationKind::AlertFired(alert.rule.condition, alert.value),
+            );
+        }
+        !fired.is_empty()
+    }
+
     pub(super) fn handle_container_health_changed(
         &mut self,
         key: ContainerKey,
         health: HealthStatus,
+        time: DateTime<Utc>,
     ) -> RenderAction {
         if let Some(container) = self.containers.get_mut(&key) {
-            container.health = Some(health);
+            let changed = container.health.as_ref() != Some(&health);
+            // Healthy for the first time since starting (not recovering from unhealthy)
+            if health == HealthStatus::Healthy
+                && matches!(container.health, None | Some(HealthStatus::Starting))
+                && let Some(started_at) = container.started_at
+                && started_at <= time
+            {
+                container.time_to_healthy = Some(time - started_at);
+            }
+            container.health = Some(health.clone());
+            if changed {
+                self.record_timeline(&key, TimelineKind::HealthChanged(health));
+            }
+            self.evaluate_alerts(&key);
         }
         RenderAction::Render // Force draw - health status changed (visible in UI)
     }
+
+    pub(super) fn handle_container_pause_changed(
+        &mut self,
+        key: ContainerKey,
+        paused: bool,
+    ) -> RenderAction {
+        let Some(container) = self.containers.get_mut(&key) else {
+            return RenderAction::None;
+        };
+
+        // Only a running container can be paused and only a paused one unpaused
+        container.state = match (&container.state, paused) {
+            (ContainerState::Running, true) => ContainerState::Paused,
+            (ContainerState::Paused, false) => ContainerState::Running,
+            _ => return RenderAction::None,
+        };
+        self.record_container_counts(&key.host_id);
+        let kind = if paused {
+            TimelineKind::Paused
+        } else {
+            TimelineKind::Unpaused
+        };
+        self.record_timeline(&key, kind);
+        self.force_sort_containers();
+
+        // The action menu offers different actions for paused containers
+        RenderAction::Render // Force draw - state changed
+    }
+
+    pub(super) fn handle_container_renamed(
+        &mut self,
+        key: ContainerKey,
+        name: String,
+    ) -> RenderAction {
+        let Some(container) = self.containers.get_mut(&key) else {
+            return RenderAction::None;
+        };
+        let previous = std::mem::replace(&mut container.name, name);
+        self.record_timeline(&key, TimelineKind::Renamed(previous));
+
+        // The name can change the sort order and the filter match
+        self.force_sort_containers();
+
+        RenderAction::Render // Force draw - name changed
+    }
+
+    pub(super) fn handle_container_limits_updated(
+        &mut self,
+        key: ContainerKey,
+        memory_limit: Option<u64>,
+    ) -> RenderAction {
+        let Some(container) = self.containers.get_mut(&key) else {
+            return RenderAction::None;
+        };
+
+        // Without a limit the stats report the host's memory, which the next sample brings
+        if let Some(limit) = memory_limit.filter(|limit| *limit > 0) {
+            let stats = &mut container.stats;
+            stats.memory_limit_bytes = limit;
+            stats.memory = (stats.memory_used_bytes as f64 / limit as f64 * 100.0).min(100.0);
+        }
+
+        RenderAction::Render // Force draw - memory column changed
+    }
+
+    /// Records the running/total container counts of a host for the count trend
+    /// Keeps a removed container's stats history for a replacement with the same name
+    /// Records restarts happening now, dropping those that fell out of the window
+    fn record_restarts(&mut self, key: &ContainerKey, restarts: usize) {
+        let now = Instant::now();
+        let times = self.restart_times.entry(key.clone()).or_default();
+        times.extend(std::iter::repeat_n(now, restarts));
+        while times
+            .front()
+            .is_some_and(|time| now.duration_since(*time) > self.restart_loop_window)
+        {
+            times.pop_front();
+        }
+    }
+
+    /// Whether the container restarted more often than allowed within the restart loop window
+    pub fn in_restart_loop(&self, key: &ContainerKey) -> bool {
+        self.restart_times.get(key).is_some_and(|times| {
+            let recent = times
+                .iter()
+                .filter(|time| time.elapsed() <= self.restart_loop_window)
+                .count();
+            recent > self.restart_loop_restarts as usize
+        })
+    }
+
+    /// Containers currently flagged as restart looping
+    pub fn restart_loop_keys(&self) -> HashSet<ContainerKey> {
+        self.restart_times
+            .keys()
+            .filter(|key| self.in_restart_loop(key))
+            .cloned()
+            .collect()
+    }
+
+    fn retire_stats(&mut self, container: Container) {
+        if container.stats.cpu_history.is_empty() {
+            return;
+        }
+        // Histories that would have scrolled out entirely by now are not worth keeping
+        let current_bucket = current_history_bucket();
+        self.retired_stats.retain(|_, stats| {
+            stats.last_history_bucket + HISTORY_BUFFER_SIZE as u64 > current_bucket
+        });
+        self.retired_stats
+            .insert((container.host_id, container.name), container.stats);
+    }
+
+    fn record_container_counts(&mut self, host_id: &HostId) {
+        let counts = self
+            .containers
+            .values()
+            .filter(|c| &c.host_id == host_id)
+            .fold(ContainerCounts::default(), |counts, c| ContainerCounts {
+                running: counts.running + usize::from(c.state == ContainerState::Running),
+                total: counts.total + 1,
+            });
+        let bucket = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() / COUNT_BUCKET_SECS)
+            .unwrap_or(0);
+
+        self.container_counts
+            .entry(host_id.clone())
+            .or_default()
+            .record(counts, bucket);
+    }
 }
@@ -0,0 +1,176 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, DiskUsageRow, DiskUsageState, HostId, RenderAction, ViewState};
+use crate::docker::connection::DockerHost;
+use crate::docker::system::{fetch_disk_usage, prune};
+
+impl AppState {
+    pub(super) fn handle_show_disk_usage(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let mut disk_usage = DiskUsageState::default();
+        for host in self.connected_hosts.values() {
+            disk_usage.loading.insert(host.host_id.clone());
+            self.spawn_disk_usage_fetch(host.clone());
+        }
+
+        self.disk_usage = Some(disk_usage);
+        self.view_state = ViewState::DiskUsage;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Loads the disk usage of a host in the background
+    fn spawn_disk_usage_fetch(&self, host: DockerHost) {
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let result = fetch_disk_usage(&host).await;
+            let _ = tx
+                .send(AppEvent::DiskUsageLoaded(host.host_id, result))
+                .await;
+        });
+    }
+
+    pub(super) fn handle_disk_usage_loaded(
+        &mut self,
+        host_id: HostId,
+        result: Result<Vec<DiskUsageRow>, String>,
+    ) -> RenderAction {
+        // Ignore results arriving after the view was closed
+        let Some(disk_usage) = &mut self.disk_usage else {
+            return RenderAction::None;
+        };
+
+        disk_usage.loading.remove(&host_id);
+        disk_usage.rows.retain(|row| row.host_id != host_id);
+        match result {
+            Ok(rows) => disk_usage.rows.extend(rows),
+            Err(error) => disk_usage.errors.push(format!("{}: {}", host_id, error)),
+        }
+        disk_usage
+            .rows
+            .sort_by(|a, b| a.host_id.cmp(&b.host_id).then(a.kind.cmp(&b.kind)));
+
+        // Keep the selection on the table
+        let count = disk_usage.rows.len();
+        let selected = match disk_usage.table_state.selected() {
+            _ if count == 0 => None,
+            Some(idx) => Some(idx.min(count - 1)),
+            None => Some(0),
+        };
+        disk_usage.table_state.select(selected);
+
+        RenderAction::Render // Force draw - disk usage changed
+    }
+
+    /// Asks for confirmation before pruning the selected kind, if anything is reclaimable
+    pub(super) fn handle_prune_disk_usage_selected(&mut self) -> RenderAction {
+        if self.view_state != ViewState::DiskUsage {
+            return RenderAction::None;
+        }
+        let Some(disk_usage) = &mut self.disk_usage else {
+            return RenderAction::None;
+        };
+        let Some(row) = disk_usage.selected() else {
+            return RenderAction::None;
+        };
+        // Nothing to prune, or a prune of the host is still running
+        if disk_usage.confirm_prune
+            || row.reclaimable == 0
+            || disk_usage.loading.contains(&row.host_id)
+        {
+            return RenderAction::None;
+        }
+
+        disk_usage.confirm_prune = true;
+        RenderAction::Render // Force draw - show the confirmation
+    }
+
+    /// Prunes the confirmed kind, then reloads the host's disk usage
+    pub(super) fn handle_confirm_prune_disk_usage(&mut self) -> RenderAction {
+        let Some(disk_usage) = &mut self.disk_usage else {
+            return RenderAction::None;
+        };
+        if !disk_usage.confirm_prune {
+            return RenderAction::None;
+        }
+        disk_usage.confirm_prune = false;
+
+        let Some((host_id, kind)) = disk_usage
+            .selected()
+            .map(|row| (row.host_id.clone(), row.kind))
+        else {
+            return RenderAction::Render;
+        };
+        let Some(host) = self.connected_hosts.get(&host_id).cloned() else {
+            return RenderAction::Render;
+        };
+        disk_usage.loading.insert(host_id.clone());
+
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let result = prune(&host, kind).await;
+            let _ = tx
+                .send(AppEvent::DiskUsagePruned(host_id.clone(), result))
+                .await;
+            let result = fetch_disk_usage(&host).await;
+            let _ = tx.send(AppEvent::DiskUsageLoaded(host_id, result)).await;
+        });
+
+        RenderAction::Render // Force draw - host is reloading
+    }
+
+    pub(super) fn handle_disk_usage_pruned(
+        &mut self,
+        host_id: HostId,
+        result: Result<u64, String>,
+    ) -> RenderAction {
+        let Some(disk_usage) = &mut self.disk_usage else {
+            return RenderAction::None;
+        };
+
+        match result {
+            Ok(reclaimed) => disk_usage.reclaimed += reclaimed,
+            Err(error) => disk_usage.errors.push(format!("{}: {}", host_id, error)),
+        }
+        RenderAction::Render // Force draw - show what was freed
+    }
+
+    /// Moves the row selection by `delta`, staying within the table
+    pub(super) fn move_disk_usage_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(disk_usage) = &mut self.disk_usage else {
+            return RenderAction::None;
+        };
+        if disk_usage.confirm_prune {
+            return RenderAction::None;
+        }
+        let Some(current) = disk_usage.table_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = disk_usage.rows.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        disk_usage.table_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Closes the confirmation if shown, otherwise the disk usage view
+    pub(super) fn handle_exit_disk_usage(&mut self) -> RenderAction {
+        if let Some(disk_usage) = &mut self.disk_usage
+            && disk_usage.confirm_prune
+        {
+            disk_usage.confirm_prune = false;
+            return RenderAction::Render; // Force draw - confirmation closed
+        }
+
+        self.disk_usage = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -0,0 +1,168 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{AppEvent, ContainerKey, EnvVarsState, RenderAction, ViewState};
+
+impl AppState {
+    pub(super) fn handle_show_env_vars(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let Some(container_key) = self.selected_container_key().cloned() else {
+            return RenderAction::None;
+        };
+        let Some(host) = self.connected_hosts.get(&container_key.host_id).cloned() else {
+            return RenderAction::None;
+        };
+
+        // Fetch the variables in the background
+        let tx = self.event_tx.clone();
+        let key = container_key.clone();
+        tokio::spawn(async move {
+            let result = host.fetch_env(&key.container_id).await;
+            let _ = tx.send(AppEvent::EnvVarsLoaded(key, result)).await;
+        });
+
+        self.env_vars = Some(EnvVarsState {
+            container_key: container_key.clone(),
+            vars: None,
+            error: None,
+            search_input: Default::default(),
+            search_editing: false,
+            reveal_secrets: false,
+            scroll_offset: 0,
+        });
+        self.view_state = ViewState::EnvVars(container_key);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_env_vars_loaded(
+        &mut self,
+        key: ContainerKey,
+        result: Result<Vec<(String, String)>, String>,
+    ) -> RenderAction {
+        // Ignore results for a popup that was already closed
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+        if state.container_key != key {
+            return RenderAction::None;
+        }
+
+        match result {
+            Ok(vars) => state.vars = Some(vars),
+            Err(error) => state.error = Some(error),
+        }
+
+        RenderAction::Render // Force draw - variables loaded
+    }
+
+    /// '*': shows or masks the values of secret-looking variables
+    pub(super) fn handle_toggle_secrets(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::EnvVars(_)) {
+            return RenderAction::None;
+        }
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+
+        state.reveal_secrets = !state.reveal_secrets;
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Scrolls the variables by `delta` rows (clamped to the matching variables)
+    pub(super) fn scroll_env_vars(&mut self, delta: isize) -> RenderAction {
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+
+        let max_offset = state.visible().len().saturating_sub(1);
+        state.scroll_offset = state
+            .scroll_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+
+        RenderAction::Render // Force draw
+    }
+
+    /// '/': starts typing a filter on the variables
+    pub(super) fn handle_start_env_search(&mut self) -> RenderAction {
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+
+        state.search_input.reset();
+        state.search_editing = true;
+        state.scroll_offset = 0;
+
+        RenderAction::Render // Force redraw to show search bar
+    }
+
+    pub(super) fn handle_env_search_key_event(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+        use tui_input::backend::crossterm::EventHandler;
+
+        // Enter and Escape are handled by handle_enter_pressed and handle_cancel_action_menu
+        if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+            return RenderAction::None;
+        }
+
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+
+        state
+            .search_input
+            .handle_event(&crossterm::event::Event::Key(key_event));
+        state.scroll_offset = 0;
+
+        RenderAction::Render // Force redraw to show updated search text
+    }
+
+    /// Enter: stops typing, keeping the filter applied
+    pub(super) fn handle_commit_env_search(&mut self) -> RenderAction {
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+
+        state.search_editing = false;
+
+        RenderAction::Render // Force redraw
+    }
+
+    /// Esc clears the search first, then closes the popup
+    pub(super) fn handle_cancel_env_vars(&mut self) -> RenderAction {
+        let Some(state) = &mut self.env_vars else {
+            return RenderAction::None;
+        };
+
+        if state.search_editing || !state.search_input.value().is_empty() {
+            state.search_input.reset();
+            state.search_editing = false;
+            state.scroll_offset = 0;
+            return RenderAction::Render; // Force redraw to hide search bar
+        }
+
+        self.handle_close_env_vars()
+    }
+
+    pub(super) fn handle_close_env_vars(&mut self) -> RenderAction {
+        self.env_vars = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Whether a filter on the environment variables is being typed
+    pub(super) fn is_editing_env_search(&self) -> bool {
+        matches!(self.view_state, ViewState::EnvVars(_))
+            && self
+                .env_vars
+                .as_ref()
+                .is_some_and(|state| state.search_editing)
+    }
+}
@@ -0,0 +1,145 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    ContainerKey, ExecLine, ExecOutputState, ExecStatus, RenderAction, ViewState,
+};
+use crate::docker::connection::DockerHost;
+use crate::docker::exec::{run_command, run_healthcheck};
+
+impl AppState {
+    /// Runs the container's health check and shows its output
+    pub(super) fn open_healthcheck_output(
+        &mut self,
+        key: ContainerKey,
+        host: DockerHost,
+    ) -> RenderAction {
+        let tx = self.event_tx.clone();
+        let task_key = key.clone();
+        let handle = tokio::spawn(async move {
+            run_healthcheck(host, task_key, tx).await;
+        });
+
+        let mut exec_output = ExecOutputState::new(key.clone());
+        exec_output.handle = Some(handle);
+        self.exec_output = Some(exec_output);
+        self.view_state = ViewState::ExecOutput(key);
+        self.action_menu_state.select(None);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Asks for a command to run, starting from the last one
+    pub(super) fn open_exec_prompt(&mut self, key: ContainerKey) -> RenderAction {
+        self.view_state = ViewState::ExecPrompt(key);
+        self.action_menu_state.select(None);
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_exec_prompt_key_event(&mut self, key_event: KeyEvent) -> RenderAction {
+        // Handled by handle_run_exec_command and handle_cancel_action_menu
+        if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+            return RenderAction::None;
+        }
+
+        use tui_input::backend::crossterm::EventHandler;
+        self.exec_input
+            .handle_event(&crossterm::event::Event::Key(key_event));
+
+        RenderAction::Render // Force draw - input changed
+    }
+
+    /// Runs the entered command and shows its output in a popup
+    pub(super) fn handle_run_exec_command(&mut self) -> RenderAction {
+        let ViewState::ExecPrompt(ref key) = self.view_state else {
+            return RenderAction::None;
+        };
+        let command = self.exec_input.value().trim().to_string();
+        if command.is_empty() {
+            return RenderAction::None;
+        }
+        let Some(host) = self.connected_hosts.get(&key.host_id).cloned() else {
+            return RenderAction::None;
+        };
+
+        let key = key.clone();
+        let tx = self.event_tx.clone();
+        let task_key = key.clone();
+        let handle = tokio::spawn(async move {
+            run_command(host, task_key, command, tx).await;
+        });
+
+        let mut exec_output = ExecOutputState::new(key.clone());
+        exec_output.popup = true;
+        exec_output.handle = Some(handle);
+        self.exec_output = Some(exec_output);
+        self.view_state = ViewState::ExecOutput(key);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// The running exec output for `key`, ignoring events for a closed view
+    fn exec_output_for(&mut self, key: &ContainerKey) -> Option<&mut ExecOutputState> {
+        self.exec_output
+            .as_mut()
+            .filter(|exec_output| &exec_output.container_key == key)
+    }
+
+    pub(super) fn handle_exec_started(
+        &mut self,
+        key: ContainerKey,
+        command: String,
+    ) -> RenderAction {
+        let Some(exec_output) = self.exec_output_for(&key) else {
+            return RenderAction::None;
+        };
+        exec_output.command = Some(command);
+        RenderAction::Render // Force draw - title changed
+    }
+
+    pub(super) fn handle_exec_output(&mut self, key: ContainerKey, line: ExecLine) -> RenderAction {
+        let Some(exec_output) = self.exec_output_for(&key) else {
+            return RenderAction::None;
+        };
+        exec_output.lines.push(line);
+        RenderAction::Render // Force draw - new output
+    }
+
+    pub(super) fn handle_exec_finished(
+        &mut self,
+        key: ContainerKey,
+        status: ExecStatus,
+    ) -> RenderAction {
+        let Some(exec_output) = self.exec_output_for(&key) else {
+            return RenderAction::None;
+        };
+        exec_output.handle = None;
+        exec_output.status = status;
+        RenderAction::Render // Force draw - status changed
+    }
+
+    /// Scrolls the exec output by `delta` lines (clamped when rendering)
+    pub(super) fn scroll_exec_output(&mut self, delta: isize) -> RenderAction {
+        let Some(exec_output) = &mut self.exec_output else {
+            return RenderAction::None;
+        };
+
+        exec_output.scroll_offset = exec_output.scroll_offset.saturating_add_signed(delta);
+        exec_output.follow = false;
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Closes the output, stopping to read from a command still running
+    pub(super) fn handle_close_exec_output(&mut self) -> RenderAction {
+        if let Some(handle) = self
+            .exec_output
+            .take()
+            .and_then(|exec_output| exec_output.handle)
+        {
+            handle.abort();
+        }
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -0,0 +1,64 @@
+use std::time::Instant;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{ExportChoice, ExportOutcome, RenderAction, ViewState};
+
+impl AppState {
+    pub(super) fn handle_show_export_menu(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.export_menu_state.select(Some(0));
+        self.view_state = ViewState::ExportMenu;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Moves the export menu selection by `delta`, staying within the menu
+    pub(super) fn move_export_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(current) = self.export_menu_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let next = current
+            .saturating_add_signed(delta)
+            .min(ExportChoice::ALL.len() - 1);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.export_menu_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Closes the menu and asks the main loop to export the container list behind it
+    pub(super) fn handle_run_export(&mut self) -> RenderAction {
+        let Some(choice) = self
+            .export_menu_state
+            .selected()
+            .and_then(|idx| ExportChoice::ALL.get(idx))
+            .copied()
+        else {
+            return RenderAction::None;
+        };
+
+        self.handle_close_export_menu();
+        RenderAction::Export(choice)
+    }
+
+    pub(super) fn handle_close_export_menu(&mut self) -> RenderAction {
+        self.export_menu_state.select(None);
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_view_exported(&mut self, outcome: ExportOutcome) -> RenderAction {
+        if let ExportOutcome::Failed(error) = &outcome {
+            tracing::warn!("Failed to export the container list: {}", error);
+        }
+        self.export_outcome = Some((outcome, Instant::now()));
+        RenderAction::Render // Force draw - show the notification
+    }
+}
@@ -0,0 +1,221 @@
+use std::path::PathBuf;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    ContainerKey, FileBrowserState, FileEntry, FileKind, RenderAction, ViewState,
+};
+use crate::docker::connection::DockerHost;
+use crate::docker::files::{child_path, download, download_dir, load_directory, parent_path};
+use crate::ui::i18n;
+
+impl AppState {
+    /// Opens the file browser at the root of a container, from the action menu
+    pub(super) fn open_file_browser(
+        &mut self,
+        container_key: ContainerKey,
+        host: DockerHost,
+    ) -> RenderAction {
+        self.file_browser = Some(FileBrowserState {
+            container_key: container_key.clone(),
+            path: "/".to_string(),
+            entries: Vec::new(),
+            loading: true,
+            error: None,
+            downloads: 0,
+            last_download: None,
+            table_state: Default::default(),
+        });
+        self.view_state = ViewState::FileBrowser(container_key.clone());
+        self.action_menu_state.select(None);
+
+        tokio::spawn(load_directory(
+            host,
+            container_key,
+            "/".to_string(),
+            self.event_tx.clone(),
+        ));
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_files_loaded(
+        &mut self,
+        key: ContainerKey,
+        path: String,
+        result: Result<Vec<FileEntry>, String>,
+    ) -> RenderAction {
+        // Ignore results for a view that was already closed
+        let Some(browser) = &mut self.file_browser else {
+            return RenderAction::None;
+        };
+        if browser.container_key != key {
+            return RenderAction::None;
+        }
+
+        browser.loading = false;
+        match result {
+            Ok(entries) => {
+                // Going up selects the directory just left
+                let came_from = browser
+                    .path
+                    .strip_prefix(&path)
+                    .map(|rest| rest.trim_start_matches('/'))
+                    .filter(|rest| !rest.is_empty() && !rest.contains('/'))
+                    .and_then(|name| entries.iter().position(|entry| entry.name == name));
+                let selected = came_from.or((!entries.is_empty()).then_some(0));
+
+                browser.path = path;
+                browser.entries = entries;
+                browser.error = None;
+                browser.table_state.select(selected);
+            }
+            Err(error) => browser.error = Some(error),
+        }
+
+        RenderAction::Render // Force draw - directory changed
+    }
+
+    /// Enter/Right/l: opens the selected directory (or symlink to one)
+    pub(super) fn handle_open_selected_file(&mut self) -> RenderAction {
+        let Some(browser) = &self.file_browser else {
+            return RenderAction::None;
+        };
+        let Some(entry) = browser
+            .table_state
+            .selected()
+            .and_then(|idx| browser.entries.get(idx))
+        else {
+            return RenderAction::None;
+        };
+        if !matches!(entry.kind, FileKind::Directory | FileKind::Symlink) {
+            return RenderAction::None;
+        }
+
+        let path = child_path(&browser.path, &entry.name);
+        self.load_file_browser_path(path)
+    }
+
+    /// Left/h: goes up a directory, or closes the browser at the root
+    pub(super) fn handle_file_browser_up(&mut self) -> RenderAction {
+        let Some(browser) = &self.file_browser else {
+            return RenderAction::None;
+        };
+        match parent_path(&browser.path) {
+            Some(parent) => self.load_file_browser_path(parent),
+            None => self.handle_exit_file_browser(),
+        }
+    }
+
+    /// Lists another directory in the background; the current one stays shown meanwhile
+    fn load_file_browser_path(&mut self, path: String) -> RenderAction {
+        let Some(browser) = &mut self.file_browser else {
+            return RenderAction::None;
+        };
+        let Some(host) = self
+            .connected_hosts
+            .get(&browser.container_key.host_id)
+            .cloned()
+        else {
+            return RenderAction::None;
+        };
+
+        browser.loading = true;
+        tokio::spawn(load_directory(
+            host,
+            browser.container_key.clone(),
+            path,
+            self.event_tx.clone(),
+        ));
+
+        RenderAction::Render // Force draw - show loading
+    }
+
+    /// 'D': downloads the selected file or directory
+    pub(super) fn handle_download_selected_file(&mut self) -> RenderAction {
+        if !matches!(self.view_state, ViewState::FileBrowser(_)) {
+            return RenderAction::None;
+        }
+        let Some(browser) = &mut self.file_browser else {
+            return RenderAction::None;
+        };
+        let Some(entry) = browser
+            .table_state
+            .selected()
+            .and_then(|idx| browser.entries.get(idx))
+        else {
+            return RenderAction::None;
+        };
+        let Some(host) = self
+            .connected_hosts
+            .get(&browser.container_key.host_id)
+            .cloned()
+        else {
+            return RenderAction::None;
+        };
+
+        let name = self
+            .containers
+            .get(&browser.container_key)
+            .map_or(browser.container_key.container_id.as_str(), |c| {
+                c.name.as_str()
+            });
+        let Some(dest_dir) = download_dir(self.download_dir.as_deref(), name) else {
+            browser.last_download = Some(Err(i18n::messages().no_download_dir.to_string()));
+            return RenderAction::Render;
+        };
+
+        browser.downloads += 1;
+        tokio::spawn(download(
+            host,
+            browser.container_key.clone(),
+            child_path(&browser.path, &entry.name),
+            dest_dir,
+            self.event_tx.clone(),
+        ));
+
+        RenderAction::Render // Force draw - show the download running
+    }
+
+    pub(super) fn handle_file_downloaded(
+        &mut self,
+        key: ContainerKey,
+        result: Result<PathBuf, String>,
+    ) -> RenderAction {
+        let Some(browser) = &mut self.file_browser else {
+            return RenderAction::None;
+        };
+        if browser.container_key != key {
+            return RenderAction::None;
+        }
+
+        browser.downloads = browser.downloads.saturating_sub(1);
+        browser.last_download = Some(result);
+
+        RenderAction::Render // Force draw - download finished
+    }
+
+    /// Moves the file selection by `delta`, staying within the directory
+    pub(super) fn move_file_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(browser) = &mut self.file_browser else {
+            return RenderAction::None;
+        };
+        let Some(current) = browser.table_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = browser.entries.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        browser.table_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_exit_file_browser(&mut self) -> RenderAction {
+        self.file_browser = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
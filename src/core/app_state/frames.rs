@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{BuildStatus, Damage, RenderAction, ViewState, bucket_duration};
+
+/// Periodic frame interval over slow links, and with many containers and no recent input
+/// (or the refresh interval, when longer)
+const SLOW_FRAME_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Periodic frame interval while the terminal is not focused (or the refresh interval)
+const UNFOCUSED_FRAME_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Container count from which the refresh slows down between interactions
+const MANY_CONTAINERS: usize = 200;
+
+/// How long after a key press the refresh stays at full rate
+const INTERACTION_WINDOW: Duration = Duration::from_secs(5);
+
+impl AppState {
+    /// How often periodic frames are drawn: every refresh interval, slower when the terminal
+    /// is not focused, over slow links, and with hundreds of containers unless the user is
+    /// interacting
+    pub fn frame_interval(&self) -> Duration {
+        if !self.focused {
+            UNFOCUSED_FRAME_INTERVAL.max(self.refresh_interval)
+        } else if self.low_bandwidth || self.many_containers_idle() {
+            SLOW_FRAME_INTERVAL.max(self.refresh_interval)
+        } else {
+            self.refresh_interval
+        }
+    }
+
+    /// Whether stats streams only pass on one sample per history bucket instead of every one
+    pub fn stats_throttled(&self) -> bool {
+        !self.focused || self.many_containers_idle()
+    }
+
+    /// Whether there are hundreds of containers and no key was pressed lately
+    fn many_containers_idle(&self) -> bool {
+        self.containers.len() >= MANY_CONTAINERS && self.last_input.elapsed() >= INTERACTION_WINDOW
+    }
+
+    /// Redraws at once when the terminal comes back into focus
+    pub(super) fn handle_focus_changed(&mut self, focused: bool) -> RenderAction {
+        self.focused = focused;
+        if focused {
+            RenderAction::Render
+        } else {
+            RenderAction::None
+        }
+    }
+
+    /// Whether the periodic frame, `since` the last one was drawn, would show anything new
+    /// While nothing changed, what moves with the clock alone (sparkline ticks, relative
+    /// times, banners timing out) is redrawn at the history rate
+    pub fn frame_due(&self, since: Duration) -> bool {
+        match self.damage {
+            Damage::Structure => true,
+            Damage::Stats if self.container_list_shown() => true,
+            _ => since >= bucket_duration(),
+        }
+    }
+
+    /// Whether the container list, and so its stats, is on screen (alone or under a popup)
+    fn container_list_shown(&self) -> bool {
+        match &self.view_state {
+            ViewState::LogView(_)
+            | ViewState::ImageHistory(_)
+            | ViewState::ProcessList(_)
+            | ViewState::FileBrowser(_)
+            | ViewState::VolumeList
+            | ViewState::Cleanup
+            | ViewState::DiskUsage
+            | ViewState::Timeline
+            | ViewState::NetworkList
+            | ViewState::ShellSessions
+            | ViewState::Notifications
+            | ViewState::ErrorDetail => false,
+            ViewState::Build => self
+                .build
+                .as_ref()
+                .is_some_and(|build| build.status == BuildStatus::Editing),
+            ViewState::ExecOutput(_) => self.exec_output.as_ref().is_some_and(|e| e.popup),
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{Container, ContainerState, HostSummary};
+
+impl AppState {
+    /// Container counts and summed resource usage of every connected host, ordered by host
+    /// Usage comes from the latest stats of the host's running containers
+    pub fn host_summaries(&self) -> Vec<HostSummary> {
+        let mut summaries: BTreeMap<&str, HostSummary> = self
+            .connected_hosts
+            .keys()
+            .map(|host_id| (host_id.as_str(), HostSummary::new(host_id.clone())))
+            .collect();
+
+        for container in self.containers.values() {
+            let summary = summaries
+                .entry(container.host_id.as_str())
+                .or_insert_with(|| HostSummary::new(container.host_id.clone()));
+            summary.counts.total += 1;
+            if container.state == ContainerState::Running {
+                summary.counts.running += 1;
+                summary.cpu += container.stats.cpu;
+                summary.memory_used_bytes += container.stats.memory_used_bytes;
+            }
+        }
+
+        summaries.into_values().collect()
+    }
+
+    /// What a container's CPU usage is divided by for display: its host's CPU count when
+    /// shown as a share of the whole host, else 1
+    pub fn cpu_divisor(&self, container: &Container) -> f64 {
+        if !self.cpu_normalized {
+            return 1.0;
+        }
+        let host_cpus = self
+            .host_info
+            .get(&container.host_id)
+            .map_or(0, |info| info.cpus);
+        container.stats.host_cpu_divisor(host_cpus)
+    }
+}
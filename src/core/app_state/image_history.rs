@@ -0,0 +1,94 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    AppEvent, ContainerKey, ImageHistoryState, ImageLayer, RenderAction, ViewState,
+};
+
+impl AppState {
+    pub(super) fn handle_show_image_history(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        // Get the selected container
+        let Some(container_key) = self.selected_container_key().cloned() else {
+            return RenderAction::None;
+        };
+
+        let Some(image) = self
+            .containers
+            .get(&container_key)
+            .map(|c| c.image.clone())
+            .filter(|image| !image.is_empty())
+        else {
+            return RenderAction::None;
+        };
+
+        let Some(host) = self.connected_hosts.get(&container_key.host_id).cloned() else {
+            return RenderAction::None;
+        };
+
+        // Fetch the history in the background
+        let tx = self.event_tx.clone();
+        let key = container_key.clone();
+        let image_name = image.clone();
+        tokio::spawn(async move {
+            let result = host.fetch_image_history(&image_name).await;
+            let _ = tx.send(AppEvent::ImageHistoryLoaded(key, result)).await;
+        });
+
+        self.image_history = Some(ImageHistoryState {
+            container_key: container_key.clone(),
+            image,
+            layers: None,
+            error: None,
+            scroll_offset: 0,
+        });
+        self.view_state = ViewState::ImageHistory(container_key);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_image_history_loaded(
+        &mut self,
+        key: ContainerKey,
+        result: Result<Vec<ImageLayer>, String>,
+    ) -> RenderAction {
+        // Ignore results for a view that was already closed
+        let Some(state) = &mut self.image_history else {
+            return RenderAction::None;
+        };
+        if state.container_key != key {
+            return RenderAction::None;
+        }
+
+        match result {
+            Ok(layers) => state.layers = Some(layers),
+            Err(error) => state.error = Some(error),
+        }
+
+        RenderAction::Render // Force draw - layers loaded
+    }
+
+    pub(super) fn handle_exit_image_history(&mut self) -> RenderAction {
+        self.image_history = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Scrolls the layer list by `delta` rows (clamped to the layer count)
+    pub(super) fn scroll_image_history(&mut self, delta: isize) -> RenderAction {
+        let Some(state) = &mut self.image_history else {
+            return RenderAction::None;
+        };
+
+        let layer_count = state.layers.as_ref().map_or(0, Vec::len);
+        let max_offset = layer_count.saturating_sub(1);
+        state.scroll_offset = state
+            .scroll_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+
+        RenderAction::Render // Force draw
+    }
+}
@@ -1,5 +1,8 @@
+use std::time::Instant;
+
 use crate::core::app_state::AppState;
-use crate::core::types::{RenderAction, ViewState};
+use crate::core::clipboard;
+use crate::core::types::{CopyTarget, RenderAction, ViewState};
 
 impl AppState {
     pub(super) fn handle_open_dozzle(&mut self) -> RenderAction {
@@ -14,11 +17,7 @@ impl AppState {
         }
 
         // Get the selected container
-        let Some(selected_idx) = self.table_state.selected() else {
-            return RenderAction::None;
-        };
-
-        let Some(container_key) = self.sorted_container_keys.get(selected_idx) else {
+        let Some(container_key) = self.selected_container_key() else {
             return RenderAction::None;
         };
 
@@ -35,7 +34,7 @@ impl AppState {
         let full_url = format!(
             "{}/container/{}",
             dozzle_url.trim_end_matches('/'),
-            container_key.container_id
+            container_key.short_id()
         );
 
         // Open the URL using the 'open' crate (cross-platform)
@@ -43,4 +42,140 @@ impl AppState {
 
         RenderAction::None // No need to force draw
     }
+
+    pub(super) fn handle_open_port(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        // Don't open URLs in SSH sessions
+        if self.is_ssh_session {
+            return RenderAction::None;
+        }
+
+        let Some(container_key) = self.selected_container_key() else {
+            return RenderAction::None;
+        };
+        let Some(container) = self.containers.get(container_key) else {
+            return RenderAction::None;
+        };
+
+        // The lowest published HTTP port, on the container's own host
+        let Some(port) = container.ports.iter().find(|port| port.is_http()) else {
+            return RenderAction::None;
+        };
+
+        let _ = open::that(port.url(&container_key.host_id));
+
+        RenderAction::None // No need to force draw
+    }
+
+    /// Opens the copy menu for the selected container; 'y' in the menu copies the
+    /// highlighted entry, so 'yy' copies the full ID
+    pub(super) fn handle_show_copy_menu(&mut self) -> RenderAction {
+        if matches!(self.view_state, ViewState::CopyMenu(_)) {
+            return self.handle_run_copy();
+        }
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let Some(container_key) = self.selected_container_key().cloned() else {
+            return RenderAction::None;
+        };
+
+        self.copy_menu_state.select(Some(0));
+        self.view_state = ViewState::CopyMenu(container_key);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Moves the copy menu selection by `delta`, staying within the menu
+    pub(super) fn move_copy_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(current) = self.copy_menu_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let next = current
+            .saturating_add_signed(delta)
+            .min(CopyTarget::ALL.len() - 1);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.copy_menu_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Copies the highlighted entry of the container and closes the menu
+    pub(super) fn handle_run_copy(&mut self) -> RenderAction {
+        let ViewState::CopyMenu(ref container_key) = self.view_state else {
+            return RenderAction::None;
+        };
+        let target = self
+            .copy_menu_state
+            .selected()
+            .and_then(|idx| CopyTarget::ALL.get(idx))
+            .copied();
+        let text = self
+            .containers
+            .get(container_key)
+            .zip(target)
+            .map(|(container, target)| target.text(container));
+
+        self.handle_close_copy_menu();
+        if let Some(text) = text {
+            self.copy_to_clipboard(text);
+        }
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_close_copy_menu(&mut self) -> RenderAction {
+        self.copy_menu_state.select(None);
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Copies the text and keeps the outcome for the notification
+    pub(super) fn copy_to_clipboard(&mut self, text: String) {
+        let result = match clipboard::copy(&text, self.is_ssh_session) {
+            Ok(()) => Ok(text),
+            Err(e) => {
+                tracing::debug!("Failed to copy to the clipboard: {}", e);
+                Err(e)
+            }
+        };
+        self.copy_outcome = Some((result, Instant::now()));
+    }
+
+    pub(super) fn handle_copy_labels(&mut self) -> RenderAction {
+        // Only handle from the details popup
+        if self.view_state != ViewState::ContainerList || !self.show_details {
+            return RenderAction::None;
+        }
+
+        let Some(container) = self
+            .selected_container_key()
+            .and_then(|key| self.containers.get(key))
+        else {
+            return RenderAction::None;
+        };
+        if container.labels.is_empty() {
+            return RenderAction::None;
+        }
+
+        // One key=value per line, as shown in the popup
+        let labels = container
+            .labels
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.copy_to_clipboard(labels);
+
+        RenderAction::Render // Force draw - show the notification
+    }
 }
@@ -0,0 +1,146 @@
+use crate::core::app_state::AppState;
+use crate::core::search_query::SearchQuery;
+use crate::core::types::{LogState, RenderAction, ViewState};
+
+impl AppState {
+    pub(super) fn handle_start_log_search(&mut self) -> RenderAction {
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        state.search_input.reset();
+        state.search_editing = true;
+        state.search_match = None;
+
+        RenderAction::Render // Force redraw to show search bar
+    }
+
+    pub(super) fn handle_log_search_key_event(
+        &mut self,
+        key_event: crossterm::event::KeyEvent,
+    ) -> RenderAction {
+        use crossterm::event::KeyCode;
+        use tui_input::backend::crossterm::EventHandler;
+
+        // Enter and Escape are handled by handle_enter_pressed and handle_cancel_action_menu
+        if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
+            return RenderAction::None;
+        }
+
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        state
+            .search_input
+            .handle_event(&crossterm::event::Event::Key(key_event));
+
+        RenderAction::Render // Force redraw to show updated search text
+    }
+
+    /// Stops typing and jumps to the closest match at or above the bottom of the viewport
+    pub(super) fn handle_commit_log_search(&mut self) -> RenderAction {
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        state.search_editing = false;
+        if state.search_input.value().is_empty() {
+            return RenderAction::Render; // Force redraw to hide search bar
+        }
+
+        let viewport_end = if self.follow_logs {
+            state.log_entries.len()
+        } else {
+            state.scroll_offset + self.last_viewport_height
+        };
+        self.jump_to_log_match(viewport_end, false)
+    }
+
+    /// Esc while typing, or with a search applied, clears the search
+    pub(super) fn handle_clear_log_search(&mut self) -> RenderAction {
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        state.search_input.reset();
+        state.search_editing = false;
+        state.search_match = None;
+
+        RenderAction::Render // Force redraw to hide search bar
+    }
+
+    pub(super) fn handle_log_search_step(&mut self, forward: bool) -> RenderAction {
+        // Only handle in LogView, once a search is applied
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+        let Some(state) = &self.log_state else {
+            return RenderAction::None;
+        };
+        if state.search_editing || state.search_input.value().is_empty() {
+            return RenderAction::None;
+        }
+
+        // Continue from the current match, or from the top of the viewport
+        let from = match state.search_match {
+            Some(current) if forward => current + 1,
+            Some(current) => current,
+            None => state.scroll_offset,
+        };
+        self.jump_to_log_match(from, forward)
+    }
+
+    /// Whether keys should be treated as text for the log search
+    pub(super) fn is_editing_log_search(&self) -> bool {
+        matches!(self.view_state, ViewState::LogView(_))
+            && self
+                .log_state
+                .as_ref()
+                .is_some_and(|state| state.search_editing)
+    }
+
+    /// Whether a log search is being typed or applied
+    pub(super) fn has_log_search(&self) -> bool {
+        self.log_state
+            .as_ref()
+            .is_some_and(|state| state.search_editing || !state.search_input.value().is_empty())
+    }
+
+    /// Selects the first match at or after `from` (forward), or before `from` (backward),
+    /// wrapping around, and scrolls it into the middle of the viewport
+    fn jump_to_log_match(&mut self, from: usize, forward: bool) -> RenderAction {
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        let Some(found) = find_log_match(state, from, forward) else {
+            state.search_match = None;
+            return RenderAction::Render; // Force redraw to show there are no matches
+        };
+
+        state.search_match = Some(found);
+        state.scroll_offset = found.saturating_sub(self.last_viewport_height / 2);
+        self.follow_logs = false;
+
+        RenderAction::Render // Force draw
+    }
+}
+
+/// Index of the next entry matching the log search, wrapping around the loaded entries
+fn find_log_match(state: &LogState, from: usize, forward: bool) -> Option<usize> {
+    let query = SearchQuery::parse(state.search_input.value());
+    let len = state.log_entries.len();
+    if query.is_empty() || len == 0 {
+        return None;
+    }
+
+    let from = from.min(len);
+    let is_match =
+        |idx: &usize| state.is_shown(*idx) && query.matches(&state.log_entries[*idx].plain_text());
+    if forward {
+        (from..len).chain(0..from).find(is_match)
+    } else {
+        (0..from).rev().chain((from..len).rev()).find(is_match)
+    }
+}
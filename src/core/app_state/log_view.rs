@@ -1,6 +1,6 @@
 use crate::core::app_state::AppState;
-use crate::core::types::{ContainerKey, LogState, RenderAction, ViewState};
-use crate::docker::logs::{LogEntry, fetch_older_logs};
+use crate::core::types::{ContainerKey, LogPosition, LogState, RenderAction, ViewState};
+use crate::docker::logs::{LogEntry, LogLevel, fetch_older_logs};
 
 impl AppState {
     pub(super) fn handle_enter_pressed(&mut self) -> RenderAction {
@@ -15,10 +15,31 @@ impl AppState {
                 // Show action menu for selected container
                 self.handle_show_action_menu()
             }
-            ViewState::ActionMenu(_) => {
+            ViewState::ActionMenu(_) | ViewState::ProjectActionMenu(_) => {
                 // Execute selected action
                 self.handle_execute_action()
             }
+            ViewState::ProjectPlan(_, _) => self.handle_run_project_plan(),
+            ViewState::StopTimeoutPrompt(_, _) => self.handle_run_with_stop_timeout(),
+            ViewState::ExecPrompt(_) => self.handle_run_exec_command(),
+            ViewState::LogView(_) if self.is_editing_log_search() => {
+                self.handle_commit_log_search()
+            }
+            ViewState::Build => self.handle_start_build(),
+            ViewState::NetworkPicker(_) => self.handle_pick_network(),
+            ViewState::ExportMenu => self.handle_run_export(),
+            ViewState::PruneMenu(_) => self.handle_run_prune(),
+            ViewState::MaintenanceMenu(_) => self.handle_run_maintenance(),
+            ViewState::CopyMenu(_) => self.handle_run_copy(),
+            ViewState::VolumeList => self.handle_confirm_remove_volumes(),
+            ViewState::Cleanup => self.handle_confirm_cleanup(),
+            ViewState::DiskUsage => self.handle_confirm_prune_disk_usage(),
+            ViewState::ShellSessions => self.handle_attach_shell_session(),
+            ViewState::Notifications => self.handle_acknowledge_notification(),
+            ViewState::FileBrowser(_) => self.handle_open_selected_file(),
+            ViewState::EnvVars(_) if self.is_editing_env_search() => {
+                self.handle_commit_env_search()
+            }
             _ => {
                 // Ignore Enter in other views
                 RenderAction::None
@@ -27,26 +48,42 @@ impl AppState {
     }
 
     pub(super) fn handle_show_log_view(&mut self) -> RenderAction {
+        // Right/l opens a directory in the file browser
+        if matches!(self.view_state, ViewState::FileBrowser(_)) {
+            return self.handle_open_selected_file();
+        }
+
         // Only handle in ContainerList view
         if self.view_state != ViewState::ContainerList {
             return RenderAction::None;
         }
 
-        // Get the selected container
-        let Some(selected_idx) = self.table_state.selected() else {
-            return RenderAction::None;
-        };
+        // Right/l expands a project header
+        if self.selected_project().is_some() {
+            return self.set_selected_project_collapsed(false);
+        }
 
-        let Some(container_key) = self.sorted_container_keys.get(selected_idx) else {
+        // Get the selected container
+        let Some(container_key) = self.selected_container_key().cloned() else {
             return RenderAction::None;
         };
 
         // Get container creation time for progress calculation
-        let container_created_at = self.containers.get(container_key).and_then(|c| c.created);
+        let container_created_at = self.containers.get(&container_key).and_then(|c| c.created);
 
         // Create new log state for this container
         let mut new_log_state = LogState::new(container_key.clone(), container_created_at);
 
+        // Pick up where the logs were left, or start at the bottom
+        let position = self
+            .log_positions
+            .get(&container_key)
+            .cloned()
+            .unwrap_or_default();
+        new_log_state.search_input = position.search.into();
+        new_log_state.restore_top = position.top_timestamp;
+        self.follow_logs = position.top_timestamp.is_none();
+
         // Start streaming logs for this container
         if let Some(host) = self.connected_hosts.get(&container_key.host_id) {
             let host_clone = host.clone();
@@ -64,11 +101,8 @@ impl AppState {
         // Set the log state
         self.log_state = Some(new_log_state);
 
-        // Reset scroll state - start at bottom
-        self.is_at_bottom = true;
-
         // Switch to log view
-        self.view_state = ViewState::LogView(container_key.clone());
+        self.view_state = ViewState::LogView(container_key);
 
         RenderAction::Render // Force draw - view changed
     }
@@ -79,11 +113,34 @@ impl AppState {
             return RenderAction::None;
         }
 
-        // Stop log streaming and cleanup log state
-        if let Some(mut state) = self.log_state.take()
-            && let Some(handle) = state.stream_handle.take()
-        {
-            handle.abort();
+        // Stop log streaming and cleanup log state, remembering where the logs were left
+        if let Some(mut state) = self.log_state.take() {
+            if let Some(handle) = state.stream_handle.take() {
+                handle.abort();
+            }
+
+            let top_timestamp = if self.follow_logs {
+                None
+            } else {
+                // Still waiting for the first batch keeps the previous position
+                state
+                    .log_entries
+                    .get(state.scroll_offset)
+                    .map(|entry| entry.timestamp)
+                    .or(state.restore_top)
+            };
+            let search = if state.search_editing {
+                String::new()
+            } else {
+                state.search_input.value().to_string()
+            };
+            self.log_positions.insert(
+                state.container_key,
+                LogPosition {
+                    top_timestamp,
+                    search,
+                },
+            );
         }
 
         // Switch back to container list view
@@ -102,10 +159,11 @@ impl AppState {
             return RenderAction::None;
         };
 
-        // Scroll up (decrease offset)
-        if state.scroll_offset > 0 {
-            state.scroll_offset = state.scroll_offset.saturating_sub(1);
-            self.is_at_bottom = false; // User scrolled away from bottom
+        // Scroll up (decrease offset), skipping entries hidden by the level filter
+        let target = state.step_shown(state.scroll_offset, -1);
+        if target < state.scroll_offset {
+            state.scroll_offset = target;
+            self.follow_logs = false; // User scrolled away from bottom
 
             // Check if we're near the top (within threshold) - trigger pagination
             const SCROLL_THRESHOLD: usize = 10; // Lines from top to trigger pagination
@@ -130,10 +188,10 @@ impl AppState {
             return RenderAction::None;
         };
 
-        // Increment scroll offset
-        state.scroll_offset = state.scroll_offset.saturating_add(1);
+        // Increment scroll offset, skipping entries hidden by the level filter
+        state.scroll_offset = state.step_shown(state.scroll_offset, 1);
 
-        // Will be clamped in UI and is_at_bottom will be recalculated there
+        // Will be clamped in UI
         RenderAction::Render // Force draw
     }
 
@@ -149,7 +207,7 @@ impl AppState {
 
         // Scroll to top
         state.scroll_offset = 0;
-        self.is_at_bottom = false;
+        self.follow_logs = false;
 
         // Trigger pagination since we're at the top
         self.handle_request_older_logs();
@@ -163,8 +221,61 @@ impl AppState {
             return RenderAction::None;
         }
 
-        // Set is_at_bottom - the actual offset will be calculated in render
-        self.is_at_bottom = true;
+        // Follow again - the actual offset will be calculated in render
+        self.follow_logs = true;
+        RenderAction::Render
+    }
+
+    pub(super) fn handle_toggle_follow_logs(&mut self) -> RenderAction {
+        // Only handle in log view
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        // Pausing keeps the current position, render already stored it in scroll_offset
+        self.follow_logs = !self.follow_logs;
+        RenderAction::Render
+    }
+
+    pub(super) fn handle_toggle_log_level(&mut self, level: LogLevel) -> RenderAction {
+        // Only handle in log view
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        if !state.hidden_levels.remove(&level) {
+            state.hidden_levels.insert(level);
+        }
+
+        // A hidden match can't be the current one
+        if state.search_match.is_some_and(|idx| !state.is_shown(idx)) {
+            state.search_match = None;
+        }
+
+        RenderAction::Render
+    }
+
+    pub(super) fn handle_toggle_collapse_repeats(&mut self) -> RenderAction {
+        // Only handle in log view
+        if !matches!(self.view_state, ViewState::LogView(_)) {
+            return RenderAction::None;
+        }
+
+        let Some(state) = &mut self.log_state else {
+            return RenderAction::None;
+        };
+
+        state.collapse_repeats = !state.collapse_repeats;
+
+        // A folded match can't be the current one
+        if state.search_match.is_some_and(|idx| !state.is_shown(idx)) {
+            state.search_match = None;
+        }
+
         RenderAction::Render
     }
 
@@ -180,9 +291,9 @@ impl AppState {
 
         // Scroll up by half page (similar to vim's Ctrl+U)
         // We'll use the last known viewport height stored in AppState
-        let page_size = self.last_viewport_height / 2;
-        state.scroll_offset = state.scroll_offset.saturating_sub(page_size);
-        self.is_at_bottom = false;
+        let page_size = (self.last_viewport_height / 2) as isize;
+        state.scroll_offset = state.step_shown(state.scroll_offset, -page_size);
+        self.follow_logs = false;
 
         // Check if we're near the top - trigger pagination
         const SCROLL_THRESHOLD: usize = 10;
@@ -205,9 +316,9 @@ impl AppState {
 
         // Scroll down by half page (similar to vim's Ctrl+D)
         // We'll use the last known viewport height stored in AppState
-        let page_size = self.last_viewport_height / 2;
-        state.scroll_offset = state.scroll_offset.saturating_add(page_size);
-        // Will be clamped in UI and is_at_bottom will be recalculated there
+        let page_size = (self.last_viewport_height / 2) as isize;
+        state.scroll_offset = state.step_shown(state.scroll_offset, page_size);
+        // Will be clamped in UI
         RenderAction::Render
     }
 
@@ -284,6 +395,15 @@ impl AppState {
         // Only adjust if this is NOT the initial load (initial load should start at bottom)
         if !is_initial_load {
             state.scroll_offset += num_entries;
+            if let Some(search_match) = &mut state.search_match {
+                *search_match += num_entries;
+            }
+        } else if let Some(top) = state.restore_top.take() {
+            // Scroll back to where the logs were left, if this container was viewed before
+            state.scroll_offset = state
+                .log_entries
+                .partition_point(|entry| entry.timestamp < top);
+            self.follow_logs = false;
         }
 
         RenderAction::Render
@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{HostId, MAINTENANCE_MINUTES, RenderAction, ViewState};
+
+impl AppState {
+    /// Whether a host is in maintenance now
+    pub fn in_maintenance(&self, host_id: &HostId) -> bool {
+        self.maintenance_left(host_id).is_some()
+    }
+
+    /// How long a host stays in maintenance (None when it isn't)
+    pub fn maintenance_left(&self, host_id: &HostId) -> Option<Duration> {
+        let until = self.maintenance.get(host_id)?;
+        until
+            .checked_duration_since(Instant::now())
+            .filter(|left| !left.is_zero())
+    }
+
+    /// The maintenance menu entries for a host: minutes to start (or restart) it for,
+    /// and None to end it, first while the host is in maintenance
+    pub fn maintenance_choices(&self, host_id: &HostId) -> Vec<Option<u64>> {
+        let end = self.in_maintenance(host_id).then_some(None);
+        end.into_iter()
+            .chain(MAINTENANCE_MINUTES.iter().copied().map(Some))
+            .collect()
+    }
+
+    /// Opens the maintenance menu for a host, replacing the action menu
+    pub(super) fn open_maintenance_menu(&mut self, host_id: HostId) -> RenderAction {
+        self.action_menu_state.select(None);
+        self.maintenance_menu_state.select(Some(0));
+        self.view_state = ViewState::MaintenanceMenu(host_id);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Moves the maintenance menu selection by `delta`, staying within the menu
+    pub(super) fn move_maintenance_selection(&mut self, delta: isize) -> RenderAction {
+        let ViewState::MaintenanceMenu(host_id) = &self.view_state else {
+            return RenderAction::None;
+        };
+        let Some(current) = self.maintenance_menu_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = self.maintenance_choices(host_id).len() - 1;
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.maintenance_menu_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Starts maintenance of the host for the highlighted duration, or ends it
+    pub(super) fn handle_run_maintenance(&mut self) -> RenderAction {
+        let ViewState::MaintenanceMenu(host_id) = &self.view_state else {
+            return RenderAction::None;
+        };
+        let Some(choice) = self
+            .maintenance_menu_state
+            .selected()
+            .and_then(|idx| self.maintenance_choices(host_id).get(idx).copied())
+        else {
+            return RenderAction::None;
+        };
+
+        let host_id = host_id.clone();
+        match choice {
+            Some(minutes) => {
+                let until = Instant::now() + Duration::from_secs(minutes * 60);
+                self.maintenance.insert(host_id, until);
+            }
+            None => {
+                self.maintenance.remove(&host_id);
+            }
+        }
+        self.handle_close_maintenance_menu()
+    }
+
+    pub(super) fn handle_close_maintenance_menu(&mut self) -> RenderAction {
+        self.maintenance_menu_state.select(None);
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -1,23 +1,69 @@
+use chrono::TimeDelta;
 use ratatui::widgets::{ListState, TableState};
-use std::collections::HashMap;
-use std::time::Instant;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tui_input::Input;
 
+use crate::core::alerts::Alerts;
+use crate::core::anomalies::Anomalies;
+use crate::core::budgets::Budget;
+use crate::core::history_db::HistoryRecorder;
+use crate::core::loop_metrics::LoopMetrics;
+use crate::core::scripting::ScriptHooks;
 use crate::core::types::{
-    AppEvent, Container, ContainerKey, HostId, LogState, RenderAction, SortField, SortState,
-    ViewState,
+    AppEvent, BuildState, BulkProgress, CLOCK_SKEW_THRESHOLD_SECS, CleanupState, Container,
+    ContainerAction, ContainerKey, ContainerStats, CountHistory, DEFAULT_ACTION_RETRIES,
+    DEFAULT_BULK_CONCURRENCY, DEFAULT_CLEANUP_EXITED_DAYS, DEFAULT_REFRESH_MS,
+    DEFAULT_RESTART_LOOP_MINUTES, DEFAULT_RESTART_LOOP_RESTARTS, DEFAULT_STOP_TIMEOUT_SECS,
+    DEFAULT_TIMESTAMP_FORMAT, DEFAULT_VERIFY_SECS, Damage, DiskUsageState, EnvVarsState,
+    ErrorDetailState, ExecOutputState, ExportOutcome, FileBrowserState, HostId, HostInfo,
+    HostResources, ImageHistoryState, ListRow, LogPosition, LogState, MemoryDisplay,
+    NetworkListState, NetworkPickerState, Notification, NotificationKind, ProcessListState,
+    ProjectKey, PruneReport, PruneTarget, RenderAction, ShellSessionInfo, SortField, SortState,
+    TimelineEntry, VerifyOutcome, ViewState, VolumeListState,
 };
 use crate::docker::connection::DockerHost;
+use crate::docker::log_snapshot::LogSnapshots;
+use crate::docker::registry::RegistryCredentials;
+use crate::docker::shell::ShellRule;
 
 // Import all the event handler modules
 mod actions;
+mod build;
+mod bulk;
+mod cleanup;
 mod container_events;
+mod disk_usage;
+mod env_vars;
+mod exec_output;
+mod export;
+mod file_browser;
+mod frames;
+mod host_summary;
+mod image_history;
 mod integrations;
+mod log_search;
 mod log_view;
+mod maintenance;
 mod navigation;
+mod network_list;
+mod network_picker;
+mod notifications;
+mod process_list;
+mod projects;
+mod prune;
+mod replicas;
 mod search;
+mod selection;
+mod shell_sessions;
 mod sorting;
+mod timeline;
+mod volumes;
+
+use selection::LostSelection;
 
 /// Application state that manages all runtime data
 pub struct AppState {
@@ -25,6 +71,12 @@ pub struct AppState {
     pub containers: HashMap<ContainerKey, Container>,
     /// Pre-sorted list of container keys for efficient rendering
     pub sorted_container_keys: Vec<ContainerKey>,
+    /// Whether the container list is grouped by compose project
+    pub group_by_project: bool,
+    /// Projects whose containers are hidden under their header
+    pub collapsed_projects: HashSet<ProjectKey>,
+    /// Rows of the grouped container list (empty when not grouping)
+    pub list_rows: Vec<ListRow>,
     /// Whether the application should quit
     pub should_quit: bool,
     /// Table selection state
@@ -33,30 +85,201 @@ pub struct AppState {
     pub view_state: ViewState,
     /// Log state for the currently viewed container (None if not viewing logs)
     pub log_state: Option<LogState>,
-    /// Whether the user is at the bottom of the logs (for auto-scroll behavior)
-    pub is_at_bottom: bool,
+    /// Where each container's log view was left this session
+    pub log_positions: HashMap<ContainerKey, LogPosition>,
+    /// Image history for the currently viewed container (None if not in that view)
+    pub image_history: Option<ImageHistoryState>,
+    /// Process list view state (None if not in that view)
+    pub process_list: Option<ProcessListState>,
+    /// File browser state (None if not in that view)
+    pub file_browser: Option<FileBrowserState>,
+    pub env_vars: Option<EnvVarsState>,
+    /// Image build dialog and output (None if not in that view)
+    pub build: Option<BuildState>,
+    /// Output of a command run in a container (None if not in that view)
+    pub exec_output: Option<ExecOutputState>,
+    /// Command line being entered, kept to run again
+    pub exec_input: Input,
+    /// Network picker for connect/disconnect actions (None if not in that view)
+    pub network_picker: Option<NetworkPickerState>,
+    /// Networks view state (None if not in that view)
+    pub network_list: Option<NetworkListState>,
+    /// Volumes view state (None if not in that view)
+    pub volume_list: Option<VolumeListState>,
+    /// Cleanup view state (None if not in that view)
+    pub cleanup: Option<CleanupState>,
+    /// Disk usage view state (None if not in that view)
+    pub disk_usage: Option<DiskUsageState>,
+    /// Days since it exited after which a container is suggested for cleanup
+    pub cleanup_exited_days: u64,
+    /// Recent container events of all hosts, oldest first
+    pub timeline: VecDeque<TimelineEntry>,
+    /// Number of newest timeline entries scrolled past
+    pub timeline_scroll: usize,
+    /// Running shell sessions, oldest first
+    pub shell_sessions: Vec<ShellSessionInfo>,
+    /// Selection in the shell sessions view
+    pub shell_session_state: TableState,
+    /// Errors, alerts and action results of the session, oldest first
+    pub notifications: VecDeque<Notification>,
+    /// Selection in the notifications panel (newest first)
+    pub notification_state: TableState,
+    /// The error detail popup, when open
+    pub error_detail: Option<ErrorDetailState>,
+    /// Whether the log view follows new lines, changed only by explicit actions
+    /// ('f', G, or scrolling up), not by where the view happens to be
+    pub follow_logs: bool,
     /// Last known viewport height for page up/down calculations
     pub last_viewport_height: usize,
+    /// Rows of the container list that fit on screen, for paging through it
+    pub list_viewport_height: usize,
+    /// A selected row hidden by a filter, to select again once it shows up
+    pub lost_selection: Option<LostSelection>,
     /// Connected Docker hosts for log streaming
     pub connected_hosts: HashMap<String, DockerHost>,
     /// Event sender for spawning log streams
     pub event_tx: mpsc::Sender<AppEvent>,
     /// Whether the app is running in an SSH session
     pub is_ssh_session: bool,
+    /// Whether less is drawn (no sparklines) for a slow link
+    pub low_bandwidth: bool,
+    /// Time between two frames while the terminal is focused and the user interacts
+    pub refresh_interval: Duration,
+    /// Whether the terminal has focus (terminals not reporting focus always have it)
+    pub focused: bool,
+    /// When a key was last pressed, to refresh at full rate while the user interacts
+    pub last_input: Instant,
     /// Whether the help popup is currently shown
     pub show_help: bool,
+    /// Whether the details popup for the selected container is shown
+    pub show_details: bool,
+    /// Whether the replica comparison popup for the selected container is shown
+    pub show_replicas: bool,
+    /// Whether the ID column is shown in the container list
+    pub show_id_column: bool,
+    /// Whether the `docker ps` style Status text column is shown
+    pub show_status_column: bool,
+    /// Whether the Restarts column is shown (it is also shown while sorting by restarts)
+    pub show_restarts_column: bool,
+    /// Whether the Size column is shown (sizes are fetched when it is turned on)
+    pub show_size_column: bool,
+    /// Whether the image Platform column is shown
+    pub show_platform_column: bool,
+    /// Whether the PIDs column is shown (it is also shown while sorting by PIDs)
+    pub show_pids_column: bool,
+    /// Whether the per-host summary strip is shown above the container list
+    pub show_host_summary: bool,
+    /// Measurements of the event loop, filled in by the loop itself
+    pub loop_metrics: LoopMetrics,
+    /// What changed on screen since the last frame, reset by the loop once drawn
+    pub damage: Damage,
+    /// Whether the event loop indicator is shown in the bottom right corner
+    pub show_loop_metrics: bool,
+    /// Whether CPU usage is shown as a share of all the host's CPUs instead of one core
+    pub cpu_normalized: bool,
+    /// Config file loaded at startup, which preferences toggled in the UI are written back
+    /// to (None without one, or with --host)
+    pub config_path: Option<PathBuf>,
+    /// What the memory column shows without sparklines
+    pub memory_display: MemoryDisplay,
+    /// Whether timestamps are shown as dates ("2025-10-29 10:15") instead of "2 hours ago"
+    pub absolute_timestamps: bool,
+    /// chrono format string for absolute timestamps
+    pub timestamp_format: String,
+    /// Whether the details popup shows the full 64 character ID
+    pub show_full_id: bool,
     /// Current sort state (field + direction)
     pub sort_state: SortState,
     /// Whether to show all containers (including stopped ones)
     pub show_all_containers: bool,
     /// Action menu list state for selection tracking
     pub action_menu_state: ListState,
+    /// Selected entry of the export menu
+    pub export_menu_state: ListState,
+    /// Result of the last export, shown as a notification for a while
+    pub export_outcome: Option<(ExportOutcome, Instant)>,
+    /// Selected entry of the prune menu
+    pub prune_menu_state: ListState,
+    /// Selection in the copy menu
+    pub copy_menu_state: ListState,
+    /// The last copy to the clipboard (the text copied, or why it failed), shown as a
+    /// notification
+    pub copy_outcome: Option<(Result<String, String>, Instant)>,
+    /// The prune menu entry awaiting confirmation (Enter again), if any
+    pub prune_confirm: Option<PruneTarget>,
+    /// Results of prunes, shown as notifications for a while
+    pub prune_outcomes: Vec<(HostId, PruneTarget, Result<PruneReport, String>, Instant)>,
+    /// Containers or objects a bulk action acts on at once
+    pub bulk_concurrency: usize,
+    /// Bulk actions by id, shown with their progress while running and for a while after
+    pub bulk_progress: BTreeMap<u64, BulkProgress>,
+    /// Id of the next bulk action
+    pub next_bulk_id: u64,
     /// Search input widget
     pub search_input: Input,
+    /// Seconds a container gets to stop before it is killed on Stop and Restart
+    pub stop_timeout: u32,
+    /// Stop timeout being entered for a single Stop or Restart
+    pub stop_timeout_input: Input,
+    /// Saves the last log lines of containers before Stop, Restart and Remove, when configured
+    pub log_snapshots: Option<Arc<LogSnapshots>>,
+    /// Where the file browser saves downloads (the user's download directory if unset)
+    pub download_dir: Option<String>,
+    /// Seconds a started or restarted container is watched before reporting how it fared
+    /// (0 to skip)
+    pub verify_secs: u64,
+    /// Times a container action is tried again after a transient failure (0 to not retry)
+    pub action_retries: u32,
+    /// Actions being retried, with the last retry number, shown until they end
+    pub retrying_actions: HashMap<ContainerKey, (ContainerAction, u32)>,
+    /// How recently watched containers fared, shown as notifications for a while
+    pub action_outcomes: HashMap<ContainerKey, (ContainerAction, VerifyOutcome, Instant)>,
+    /// Configured alert rules and the alerts in effect (None without rules)
+    pub alerts: Option<Alerts>,
+    /// Do-not-disturb mode ('Z'): only critical alerts are signaled and shown as banners,
+    /// the rest are kept in the notifications panel
+    pub do_not_disturb: bool,
+    /// Hosts in maintenance, until when: their alerts and connection errors aren't
+    /// signaled and their containers are greyed out
+    pub maintenance: HashMap<HostId, Instant>,
+    /// Selected entry of the maintenance menu
+    pub maintenance_menu_state: ListState,
+    /// Rolling baselines of container stats, for highlighting unusual values (None
+    /// unless configured)
+    pub anomalies: Option<Anomalies>,
     /// Connection errors to display (host_id -> (error_message, timestamp))
     pub connection_errors: HashMap<HostId, (String, Instant)>,
+    /// Hosts whose connection was lost, with the current retry attempt
+    pub reconnecting_hosts: HashMap<HostId, u32>,
+    /// Hosts whose clock is off from the local one by more than CLOCK_SKEW_THRESHOLD_SECS,
+    /// with the difference (positive when ahead) and when it was measured
+    pub clock_skews: HashMap<HostId, (TimeDelta, Instant)>,
+    /// Latest data-root disk space and load of the hosts with disk_check, and when measured
+    pub host_resources: HashMap<HostId, (HostResources, Instant)>,
+    /// Latest CPU count, memory and load of each host
+    pub host_info: HashMap<HostId, HostInfo>,
     /// Last time containers were sorted (for throttling)
     pub last_sort_time: Instant,
+    /// Compiled scripting hooks from the config (derived columns, sort key, row style)
+    pub script_hooks: Option<ScriptHooks>,
+    /// Running/total container counts per host over the session
+    pub container_counts: HashMap<HostId, CountHistory>,
+    /// Stats history of removed containers by host and name, taken over when they are recreated
+    pub retired_stats: HashMap<(HostId, String), ContainerStats>,
+    /// Restarts within the restart loop window after which a container is flagged
+    pub restart_loop_restarts: u32,
+    /// How far back restarts are counted for restart loop detection
+    pub restart_loop_window: Duration,
+    /// When each container restarted within the restart loop window, oldest first
+    pub restart_times: HashMap<ContainerKey, VecDeque<Instant>>,
+    /// CPU and memory budgets per label value from the config
+    pub budgets: Vec<Budget>,
+    /// Shells per container label from the config
+    pub shell_rules: Vec<ShellRule>,
+    /// Where stats samples and events are stored, when history_db is configured
+    pub history: Option<HistoryRecorder>,
+    /// Registry credentials for image pulls and pushes
+    pub registry_credentials: Arc<RegistryCredentials>,
 }
 
 impl AppState {
@@ -75,22 +298,107 @@ impl AppState {
         Self {
             containers: HashMap::new(),
             sorted_container_keys: Vec::new(),
+            group_by_project: false,
+            collapsed_projects: HashSet::new(),
+            list_rows: Vec::new(),
             should_quit: false,
             table_state: TableState::default(),
             view_state: ViewState::ContainerList,
             log_state: None,
-            is_at_bottom: true,
+            log_positions: HashMap::new(),
+            image_history: None,
+            process_list: None,
+            file_browser: None,
+            env_vars: None,
+            build: None,
+            exec_output: None,
+            exec_input: Input::default(),
+            network_picker: None,
+            network_list: None,
+            volume_list: None,
+            cleanup: None,
+            disk_usage: None,
+            cleanup_exited_days: DEFAULT_CLEANUP_EXITED_DAYS,
+            timeline: VecDeque::new(),
+            timeline_scroll: 0,
+            shell_sessions: Vec::new(),
+            shell_session_state: TableState::default(),
+            notifications: VecDeque::new(),
+            notification_state: TableState::default(),
+            error_detail: None,
+            follow_logs: true,
             last_viewport_height: 20, // Default to 20 lines (will be updated on first render)
+            list_viewport_height: 20,
+            lost_selection: None,
             connected_hosts,
             event_tx,
             is_ssh_session,
+            low_bandwidth: false,
+            refresh_interval: Duration::from_millis(DEFAULT_REFRESH_MS),
+            focused: true,
+            last_input: Instant::now(),
             show_help: false,
+            show_details: false,
+            show_replicas: false,
+            show_id_column: true,
+            show_status_column: false,
+            show_restarts_column: false,
+            show_size_column: false,
+            show_platform_column: false,
+            show_pids_column: false,
+            show_host_summary: false,
+            loop_metrics: LoopMetrics::default(),
+            damage: Damage::Structure,
+            show_loop_metrics: false,
+            cpu_normalized: false,
+            config_path: None,
+            memory_display: MemoryDisplay::default(),
+            absolute_timestamps: false,
+            timestamp_format: DEFAULT_TIMESTAMP_FORMAT.to_string(),
+            show_full_id: false,
             sort_state: SortState::new(sort_field), // Use configured sort field with default direction
             show_all_containers: show_all,
             action_menu_state: ListState::default(), // Default to no selection
+            export_menu_state: ListState::default(),
+            export_outcome: None,
+            prune_menu_state: ListState::default(),
+            copy_menu_state: ListState::default(),
+            copy_outcome: None,
+            prune_confirm: None,
+            prune_outcomes: Vec::new(),
+            bulk_concurrency: DEFAULT_BULK_CONCURRENCY,
+            bulk_progress: BTreeMap::new(),
+            next_bulk_id: 0,
             search_input: Input::default(),
+            stop_timeout: DEFAULT_STOP_TIMEOUT_SECS,
+            stop_timeout_input: Input::default(),
+            log_snapshots: None,
+            download_dir: None,
+            verify_secs: DEFAULT_VERIFY_SECS,
+            action_retries: DEFAULT_ACTION_RETRIES,
+            retrying_actions: HashMap::new(),
+            action_outcomes: HashMap::new(),
+            alerts: None,
+            do_not_disturb: false,
+            maintenance: HashMap::new(),
+            maintenance_menu_state: ListState::default(),
+            anomalies: None,
             connection_errors: HashMap::new(),
+            reconnecting_hosts: HashMap::new(),
+            clock_skews: HashMap::new(),
+            host_resources: HashMap::new(),
+            host_info: HashMap::new(),
             last_sort_time: Instant::now(),
+            script_hooks: None,
+            container_counts: HashMap::new(),
+            retired_stats: HashMap::new(),
+            restart_loop_restarts: DEFAULT_RESTART_LOOP_RESTARTS,
+            restart_loop_window: Duration::from_secs(DEFAULT_RESTART_LOOP_MINUTES * 60),
+            restart_times: HashMap::new(),
+            budgets: Vec::new(),
+            shell_rules: Vec::new(),
+            history: None,
+            registry_credentials: Arc::default(),
         }
     }
 
@@ -104,20 +412,77 @@ impl AppState {
             _ => tracing::debug!("Handling event: {:?}", event),
         }
 
+        // Stats updates tell themselves whether anything shown changed
+        if !matches!(event, AppEvent::ContainerStat(_, _)) {
+            self.damage = Damage::Structure;
+        }
+
+        // Every key goes through SearchKeyEvent first, whatever it does
+        if matches!(event, AppEvent::SearchKeyEvent(_)) {
+            self.last_input = Instant::now();
+        }
+
         match event {
             AppEvent::InitialContainerList(host_id, container_list) => {
                 self.handle_initial_container_list(host_id, container_list)
             }
-            AppEvent::ContainerCreated(container) => self.handle_container_created(container),
+            AppEvent::ContainerCreated(container) => self.handle_container_created(*container),
             AppEvent::ContainerDestroyed(key) => self.handle_container_destroyed(key),
-            AppEvent::ContainerStateChanged(key, state) => {
-                self.handle_container_state_changed(key, state)
+            AppEvent::ContainerExited(key, exit_code) => {
+                self.handle_container_exited(key, exit_code)
+            }
+            AppEvent::ContainerRestartCount(key, count) => {
+                self.handle_container_restart_count(key, count)
+            }
+            AppEvent::ContainerPlatform(key, platform) => {
+                self.handle_container_platform(key, platform)
+            }
+            AppEvent::ContainerSizes(host_id, result) => {
+                self.handle_container_sizes(host_id, result)
             }
             AppEvent::ContainerStat(key, stats) => self.handle_container_stat(key, stats),
-            AppEvent::ContainerHealthChanged(key, health) => {
-                self.handle_container_health_changed(key, health)
+            AppEvent::ContainerHealthChanged(key, health, time) => {
+                self.handle_container_health_changed(key, health, time)
+            }
+            AppEvent::ContainerPauseChanged(key, paused) => {
+                self.handle_container_pause_changed(key, paused)
+            }
+            AppEvent::ContainerRenamed(key, name) => self.handle_container_renamed(key, name),
+            AppEvent::ContainerExec(key, command) => self.handle_container_exec(key, command),
+            AppEvent::ContainerLimitsUpdated(key, memory_limit) => {
+                self.handle_container_limits_updated(key, memory_limit)
             }
             AppEvent::Resize => RenderAction::Render, // Always redraw on resize
+            AppEvent::FocusChanged(focused) => self.handle_focus_changed(focused),
+            // 'q' is text while typing in the build dialog
+            AppEvent::Quit if self.is_editing_build() => RenderAction::None,
+            // Keys are text while typing a command
+            AppEvent::Quit | AppEvent::ToggleHelp
+                if matches!(self.view_state, ViewState::ExecPrompt(_)) =>
+            {
+                RenderAction::None
+            }
+            // Keys are text while typing a log search
+            AppEvent::Quit
+            | AppEvent::ScrollUp
+            | AppEvent::ScrollDown
+            | AppEvent::ScrollToTop
+            | AppEvent::ScrollToBottom
+            | AppEvent::ScrollPageUp
+            | AppEvent::ScrollPageDown
+            | AppEvent::ExitLogView
+            | AppEvent::EnterSearchMode
+            | AppEvent::ToggleHelp
+            | AppEvent::LogSearchNext
+            | AppEvent::LogSearchPrevious
+            | AppEvent::ToggleFollowLogs
+            | AppEvent::ToggleLogLevel(_)
+            | AppEvent::ToggleCollapseRepeats
+            | AppEvent::ToggleSecrets
+                if self.is_editing_log_search() || self.is_editing_env_search() =>
+            {
+                RenderAction::None
+            }
             AppEvent::Quit => {
                 self.should_quit = true;
                 RenderAction::None
@@ -125,10 +490,89 @@ impl AppState {
             AppEvent::SelectPrevious => self.handle_select_previous(),
             AppEvent::SelectNext => self.handle_select_next(),
             AppEvent::EnterPressed => self.handle_enter_pressed(),
-            AppEvent::ExitLogView => self.handle_exit_log_view(),
+            AppEvent::ExitLogView => self.handle_exit_view(),
             AppEvent::ShowLogView => self.handle_show_log_view(),
-            AppEvent::ScrollUp => self.handle_scroll_up(),
-            AppEvent::ScrollDown => self.handle_scroll_down(),
+            AppEvent::ShowImageHistory => self.handle_show_image_history(),
+            AppEvent::ImageHistoryLoaded(key, result) => {
+                self.handle_image_history_loaded(key, result)
+            }
+            AppEvent::NetworksLoaded(key, result) => self.handle_networks_loaded(key, result),
+            AppEvent::ShowProcessList => self.handle_show_process_list(),
+            AppEvent::ProcessesLoaded(key, result) => self.handle_processes_loaded(key, result),
+            AppEvent::ShowEnvVars => self.handle_show_env_vars(),
+            AppEvent::EnvVarsLoaded(key, result) => self.handle_env_vars_loaded(key, result),
+            AppEvent::ToggleSecrets => self.handle_toggle_secrets(),
+            AppEvent::FilesLoaded(key, path, result) => self.handle_files_loaded(key, path, result),
+            AppEvent::DownloadSelectedFile => self.handle_download_selected_file(),
+            AppEvent::FileDownloaded(key, result) => self.handle_file_downloaded(key, result),
+            AppEvent::ShowVolumes => self.handle_show_volumes(),
+            AppEvent::VolumesLoaded(host_id, result) => self.handle_volumes_loaded(host_id, result),
+            AppEvent::RemoveOrphanedVolumes => self.handle_remove_orphaned_volumes(),
+            AppEvent::RemoveSelectedVolume => self.handle_remove_selected_volume(),
+            AppEvent::VolumesRemoved(host_id, errors) => {
+                self.handle_volumes_removed(host_id, errors)
+            }
+            AppEvent::ShowCleanup => self.handle_show_cleanup(),
+            AppEvent::CleanupLoaded(host_id, result) => self.handle_cleanup_loaded(host_id, result),
+            AppEvent::RemoveCleanupSelected => self.handle_remove_cleanup_selected(),
+            AppEvent::RemoveCleanupKind => self.handle_remove_cleanup_kind(),
+            AppEvent::CleanupRemoved(host_id, errors) => {
+                self.handle_cleanup_removed(host_id, errors)
+            }
+            AppEvent::ShowDiskUsage => self.handle_show_disk_usage(),
+            AppEvent::DiskUsageLoaded(host_id, result) => {
+                self.handle_disk_usage_loaded(host_id, result)
+            }
+            AppEvent::PruneDiskUsageSelected => self.handle_prune_disk_usage_selected(),
+            AppEvent::DiskUsagePruned(host_id, result) => {
+                self.handle_disk_usage_pruned(host_id, result)
+            }
+            AppEvent::ShowNetworks => self.handle_show_networks(),
+            AppEvent::NetworkListLoaded(host_id, result) => {
+                self.handle_network_list_loaded(host_id, result)
+            }
+            AppEvent::ShowTimeline => self.handle_show_timeline(),
+            AppEvent::ShowShellSessions => self.handle_show_shell_sessions(),
+            AppEvent::ShowNotifications => self.handle_show_notifications(),
+            AppEvent::ToggleDoNotDisturb => self.handle_toggle_do_not_disturb(),
+            AppEvent::AcknowledgeAllNotifications => self.handle_acknowledge_all_notifications(),
+            AppEvent::ClearNotification => self.handle_clear_notification(),
+            AppEvent::ShowErrorDetail => self.handle_show_error_detail(),
+            AppEvent::CopyErrorDetail => self.handle_copy_error_detail(),
+            AppEvent::ClearAcknowledgedNotifications => {
+                self.handle_clear_acknowledged_notifications()
+            }
+            AppEvent::PromptStopTimeout => self.handle_prompt_stop_timeout(),
+            AppEvent::ShowExportMenu => self.handle_show_export_menu(),
+            AppEvent::ViewExported(outcome) => self.handle_view_exported(outcome),
+            AppEvent::BulkItemDone(id, ok) => self.handle_bulk_item_done(id, ok),
+            AppEvent::BulkStopped(id) => self.handle_bulk_stopped(id),
+            AppEvent::PruneFinished(host_id, target, result) => {
+                self.handle_prune_finished(host_id, target, result)
+            }
+            AppEvent::ShellSessionStarted(info) => self.handle_shell_session_started(info),
+            AppEvent::ShellSessionEnded(id) => self.handle_shell_session_ended(id),
+            AppEvent::ShowBuildDialog => self.handle_show_build_dialog(),
+            AppEvent::BuildOutput(line) => self.handle_build_output(line),
+            AppEvent::BuildFinished(result) => self.handle_build_finished(result),
+            AppEvent::ExecStarted(key, command) => self.handle_exec_started(key, command),
+            AppEvent::ExecOutput(key, line) => self.handle_exec_output(key, line),
+            AppEvent::ExecFinished(key, status) => self.handle_exec_finished(key, status),
+            AppEvent::ScrollUp => self.handle_scroll(-1),
+            AppEvent::ScrollDown => self.handle_scroll(1),
+            // Paging moves the selection in the container list
+            AppEvent::ScrollToTop if self.view_state == ViewState::ContainerList => {
+                self.move_selection(isize::MIN)
+            }
+            AppEvent::ScrollToBottom if self.view_state == ViewState::ContainerList => {
+                self.move_selection(isize::MAX)
+            }
+            AppEvent::ScrollPageUp if self.view_state == ViewState::ContainerList => {
+                self.move_selection(-(self.list_viewport_height.max(1) as isize))
+            }
+            AppEvent::ScrollPageDown if self.view_state == ViewState::ContainerList => {
+                self.move_selection(self.list_viewport_height.max(1) as isize)
+            }
             AppEvent::ScrollToTop => self.handle_scroll_to_top(),
             AppEvent::ScrollToBottom => self.handle_scroll_to_bottom(),
             AppEvent::ScrollPageUp => self.handle_scroll_page_up(),
@@ -138,29 +582,195 @@ impl AppState {
             }
             AppEvent::LogLine(key, log_line) => self.handle_log_line(key, log_line),
             AppEvent::OpenDozzle => self.handle_open_dozzle(),
+            AppEvent::OpenPort => self.handle_open_port(),
             AppEvent::ToggleHelp => self.handle_toggle_help(),
+            AppEvent::ToggleDetails => self.handle_toggle_details(),
+            AppEvent::ToggleReplicas => self.handle_toggle_replicas(),
+            AppEvent::ToggleIdColumn => self.handle_toggle_id_column(),
+            AppEvent::ToggleLoopMetrics => self.handle_toggle_loop_metrics(),
+            AppEvent::ToggleFullId => self.handle_toggle_full_id(),
+            AppEvent::ToggleFollowLogs => self.handle_toggle_follow_logs(),
+            AppEvent::ToggleLogLevel(level) => self.handle_toggle_log_level(level),
+            AppEvent::ToggleCollapseRepeats => self.handle_toggle_collapse_repeats(),
+            AppEvent::ShowCopyMenu => self.handle_show_copy_menu(),
+            AppEvent::CopyLabels => self.handle_copy_labels(),
+            AppEvent::ToggleAbsoluteTimestamps => self.handle_toggle_absolute_timestamps(),
+            AppEvent::ToggleCpuNormalized => self.handle_toggle_cpu_normalized(),
+            AppEvent::CycleMemoryDisplay => self.handle_cycle_memory_display(),
+            AppEvent::RefreshContainerSizes => self.handle_refresh_container_sizes(),
             AppEvent::CycleSortField => self.handle_cycle_sort_field(),
             AppEvent::SetSortField(field) => self.handle_set_sort_field(field),
             AppEvent::ToggleShowAll => self.handle_toggle_show_all(),
+            AppEvent::ToggleGroupByProject => self.handle_toggle_group_by_project(),
             AppEvent::CancelActionMenu => self.handle_cancel_action_menu(),
             AppEvent::SelectActionUp => self.handle_select_action_up(),
             AppEvent::SelectActionDown => self.handle_select_action_down(),
             AppEvent::ActionInProgress(key, action) => self.handle_action_in_progress(key, action),
+            AppEvent::ActionVerified(key, action, outcome) => {
+                self.handle_action_verified(key, action, outcome)
+            }
+            AppEvent::LogSnapshotTaken(key, result) => self.handle_log_snapshot_taken(key, result),
             AppEvent::ActionSuccess(key, action) => self.handle_action_success(key, action),
             AppEvent::ActionError(key, action, error) => {
                 self.handle_action_error(key, action, error)
             }
+            AppEvent::ActionRetrying(key, action, retry, error) => {
+                self.handle_action_retrying(key, action, retry, error)
+            }
             AppEvent::EnterSearchMode => self.handle_enter_search_mode(),
+            AppEvent::LogSearchNext => self.handle_log_search_step(true),
+            AppEvent::LogSearchPrevious => self.handle_log_search_step(false),
+            AppEvent::SearchKeyEvent(key_event) if self.is_editing_log_search() => {
+                self.handle_log_search_key_event(key_event)
+            }
+            AppEvent::SearchKeyEvent(key_event) if self.is_editing_env_search() => {
+                self.handle_env_search_key_event(key_event)
+            }
+            AppEvent::SearchKeyEvent(key_event) if self.view_state == ViewState::Build => {
+                self.handle_build_key_event(key_event)
+            }
+            AppEvent::SearchKeyEvent(key_event)
+                if matches!(self.view_state, ViewState::StopTimeoutPrompt(_, _)) =>
+            {
+                self.handle_stop_timeout_key_event(key_event)
+            }
+            AppEvent::SearchKeyEvent(key_event)
+                if matches!(self.view_state, ViewState::ExecPrompt(_)) =>
+            {
+                self.handle_exec_prompt_key_event(key_event)
+            }
             AppEvent::SearchKeyEvent(key_event) => self.handle_search_key_event(key_event),
             AppEvent::ConnectionError(host_id, error) => {
                 self.handle_connection_error(host_id, error)
             }
             AppEvent::HostConnected(docker_host) => self.handle_host_connected(docker_host),
+            AppEvent::HostReconnecting(host_id, attempt) => {
+                self.handle_host_reconnecting(host_id, attempt)
+            }
+            AppEvent::HostReconnected(host_id) => self.handle_host_reconnected(host_id),
+            AppEvent::HostClockSkew(host_id, skew) => self.handle_host_clock_skew(host_id, skew),
+            AppEvent::HostResources(host_id, resources) => {
+                self.handle_host_resources(host_id, resources)
+            }
+            AppEvent::HostInfo(host_id, info) => self.handle_host_info(host_id, info),
         }
     }
 
-    /// Handles a connection error by storing it with a timestamp
+    /// Routes Up/Down to the scrolling or selection of the current view
+    fn handle_scroll(&mut self, delta: isize) -> RenderAction {
+        match self.view_state {
+            ViewState::LogView(_) if delta < 0 => self.handle_scroll_up(),
+            ViewState::LogView(_) => self.handle_scroll_down(),
+            ViewState::ImageHistory(_) => self.scroll_image_history(delta),
+            ViewState::ProcessList(_) => self.scroll_process_list(delta),
+            ViewState::EnvVars(_) => self.scroll_env_vars(delta),
+            ViewState::ErrorDetail => self.scroll_error_detail(delta),
+            ViewState::FileBrowser(_) => self.move_file_selection(delta),
+            ViewState::Build => self.scroll_build_output(delta),
+            ViewState::ExecOutput(_) => self.scroll_exec_output(delta),
+            ViewState::VolumeList => self.move_volume_selection(delta),
+            ViewState::Cleanup => self.move_cleanup_selection(delta),
+            ViewState::DiskUsage => self.move_disk_usage_selection(delta),
+            ViewState::Timeline => self.scroll_timeline(delta),
+            ViewState::NetworkList => self.move_network_list_selection(delta),
+            ViewState::ShellSessions => self.move_shell_session_selection(delta),
+            ViewState::Notifications => self.move_notification_selection(delta),
+            _ => RenderAction::None,
+        }
+    }
+
+    /// Routes Left/h to leaving the current view, going up a directory in the file browser
+    /// and collapsing a project header in the container list
+    fn handle_exit_view(&mut self) -> RenderAction {
+        match self.view_state {
+            ViewState::LogView(_) => self.handle_exit_log_view(),
+            ViewState::ImageHistory(_) => self.handle_exit_image_history(),
+            ViewState::ProcessList(_) => self.handle_exit_process_list(),
+            ViewState::EnvVars(_) => self.handle_close_env_vars(),
+            ViewState::ErrorDetail => self.handle_close_error_detail(),
+            ViewState::VolumeList => self.handle_exit_volumes(),
+            ViewState::Cleanup => self.handle_exit_cleanup(),
+            ViewState::DiskUsage => self.handle_exit_disk_usage(),
+            ViewState::Timeline => self.handle_exit_timeline(),
+            ViewState::NetworkList => self.handle_exit_networks(),
+            ViewState::Notifications => self.handle_exit_notifications(),
+            ViewState::FileBrowser(_) => self.handle_file_browser_up(),
+            ViewState::ContainerList if self.selected_project().is_some() => {
+                self.set_selected_project_collapsed(true)
+            }
+            _ => RenderAction::None,
+        }
+    }
+
+    /// Number of rows in the container list
+    pub fn row_count(&self) -> usize {
+        if self.group_by_project {
+            self.list_rows.len()
+        } else {
+            self.sorted_container_keys.len()
+        }
+    }
+
+    /// The container in the selected row (None on a project header)
+    pub fn selected_container_key(&self) -> Option<&ContainerKey> {
+        let idx = self.table_state.selected()?;
+        if !self.group_by_project {
+            return self.sorted_container_keys.get(idx);
+        }
+        match self.list_rows.get(idx)? {
+            ListRow::Container(key) => Some(key),
+            ListRow::Project(_) => None,
+        }
+    }
+
+    /// The project whose header is in the selected row
+    pub fn selected_project(&self) -> Option<&ProjectKey> {
+        let idx = self.table_state.selected()?;
+        match self.list_rows.get(idx)? {
+            ListRow::Project(project) if self.group_by_project => Some(project),
+            _ => None,
+        }
+    }
+
+    /// Other hosts running a container with the same name as `key`, sorted
+    pub fn hosts_sharing_name(&self, key: &ContainerKey) -> Vec<&str> {
+        let Some(container) = self.containers.get(key) else {
+            return Vec::new();
+        };
+        let mut hosts: Vec<&str> = self
+            .containers
+            .iter()
+            .filter(|(other, c)| other.host_id != key.host_id && c.name == container.name)
+            .map(|(other, _)| other.host_id.as_str())
+            .collect();
+        hosts.sort_unstable();
+        hosts.dedup();
+        hosts
+    }
+
+    /// Name of a container for views that don't show its host: suffixed with `@host`
+    /// when another host has a container of the same name, the ID if it is gone
+    pub fn container_label(&self, key: &ContainerKey) -> String {
+        match self.containers.get(key) {
+            Some(container) if !self.hosts_sharing_name(key).is_empty() => {
+                format!("{}@{}", container.name, key.host_id)
+            }
+            Some(container) => container.name.clone(),
+            None => key.short_id().to_string(),
+        }
+    }
+
+    /// Handles a connection error by storing it with a timestamp, and keeping it in the
+    /// notifications panel
     fn handle_connection_error(&mut self, host_id: HostId, error: String) -> RenderAction {
+        // A host in maintenance is expected to go away
+        if !self.in_maintenance(&host_id) {
+            self.notify(
+                host_id.clone(),
+                NotificationKind::ConnectionError(error.clone()),
+            );
+        }
+
         // Store the error with current timestamp
         self.connection_errors
             .insert(host_id, (error, Instant::now()));
@@ -185,4 +795,45 @@ impl AppState {
 
         RenderAction::None // No need to force redraw, container list will update via normal events
     }
+
+    /// Shows the reconnecting badge for a host whose connection was lost
+    fn handle_host_reconnecting(&mut self, host_id: HostId, attempt: u32) -> RenderAction {
+        self.reconnecting_hosts.insert(host_id, attempt);
+        RenderAction::Render // Redraw to show the badge
+    }
+
+    /// Hides the reconnecting badge; the fresh container list replaces the stale one
+    fn handle_host_reconnected(&mut self, host_id: HostId) -> RenderAction {
+        self.reconnecting_hosts.remove(&host_id);
+        self.connection_errors.remove(&host_id);
+        RenderAction::Render // Redraw to hide the badge
+    }
+
+    /// Records a host's clock skew when it is large enough to throw off log ordering
+    /// and "time ago" displays; a host back in sync is forgotten
+    fn handle_host_clock_skew(&mut self, host_id: HostId, skew: TimeDelta) -> RenderAction {
+        if skew.num_seconds().abs() >= CLOCK_SKEW_THRESHOLD_SECS {
+            self.clock_skews.insert(host_id, (skew, Instant::now()));
+            RenderAction::Render // Redraw to show the warning
+        } else if self.clock_skews.remove(&host_id).is_some() {
+            RenderAction::Render // Redraw to hide the warning
+        } else {
+            RenderAction::None
+        }
+    }
+
+    /// Records a host's latest disk space and load; the warnings are derived when rendering
+    fn handle_host_resources(&mut self, host_id: HostId, resources: HostResources) -> RenderAction {
+        self.host_resources
+            .insert(host_id, (resources, Instant::now()));
+        RenderAction::Render // Redraw to show or hide the warnings
+    }
+
+    fn handle_host_info(&mut self, host_id: HostId, info: HostInfo) -> RenderAction {
+        if self.host_info.get(&host_id) == Some(&info) {
+            return RenderAction::None;
+        }
+        self.host_info.insert(host_id, info);
+        RenderAction::Render // Redraw to show the new numbers
+    }
 }
@@ -1,5 +1,6 @@
+use crate::cli::config::Config;
 use crate::core::app_state::AppState;
-use crate::core::types::{RenderAction, ViewState};
+use crate::core::types::{AppEvent, RenderAction, ViewState};
 
 impl AppState {
     pub(super) fn handle_select_previous(&mut self) -> RenderAction {
@@ -8,8 +9,8 @@ impl AppState {
             return RenderAction::None;
         }
 
-        let container_count = self.containers.len();
-        if container_count > 0 {
+        let row_count = self.row_count();
+        if row_count > 0 {
             let selected = self.table_state.selected().unwrap_or(0);
             if selected > 0 {
                 self.table_state.select(Some(selected - 1));
@@ -24,18 +25,151 @@ impl AppState {
             return RenderAction::None;
         }
 
-        let container_count = self.containers.len();
-        if container_count > 0 {
+        let row_count = self.row_count();
+        if row_count > 0 {
             let selected = self.table_state.selected().unwrap_or(0);
-            if selected < container_count - 1 {
+            if selected < row_count - 1 {
                 self.table_state.select(Some(selected + 1));
             }
         }
         RenderAction::Render // Force draw - selection changed
     }
 
+    /// Moves the selection of the container list by `delta` rows, stopping at either end
+    pub(super) fn move_selection(&mut self, delta: isize) -> RenderAction {
+        let row_count = self.row_count();
+        if row_count == 0 {
+            return RenderAction::None;
+        }
+
+        let selected = self.table_state.selected().unwrap_or(0);
+        let target = selected.saturating_add_signed(delta).min(row_count - 1);
+        self.table_state.select(Some(target));
+        RenderAction::Render // Force draw - selection changed
+    }
+
     pub(super) fn handle_toggle_help(&mut self) -> RenderAction {
+        // '?' is text while typing in the build dialog
+        if self.is_editing_build() {
+            return RenderAction::None;
+        }
+
         self.show_help = !self.show_help;
         RenderAction::Render // Force redraw to show/hide popup
     }
+
+    pub(super) fn handle_toggle_details(&mut self) -> RenderAction {
+        // Only handle in ContainerList view, and only with a container selected
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+        if !self.show_details && self.table_state.selected().is_none() {
+            return RenderAction::None;
+        }
+
+        self.show_details = !self.show_details;
+        // One popup at a time
+        if self.show_details {
+            self.show_replicas = false;
+        }
+        RenderAction::Render // Force redraw to show/hide popup
+    }
+
+    pub(super) fn handle_toggle_id_column(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.show_id_column = !self.show_id_column;
+        RenderAction::Render // Force redraw - columns changed
+    }
+
+    /// F12 shows or hides the event loop indicator, in any view
+    pub(super) fn handle_toggle_loop_metrics(&mut self) -> RenderAction {
+        self.show_loop_metrics = !self.show_loop_metrics;
+        RenderAction::Render // Force redraw - indicator changed
+    }
+
+    pub(super) fn handle_refresh_container_sizes(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        // Sizes are expensive to compute, so they are only fetched on request
+        self.show_size_column = true;
+        let host_ids: Vec<_> = self.connected_hosts.keys().cloned().collect();
+        for host_id in host_ids {
+            self.spawn_size_fetch(&host_id);
+        }
+
+        RenderAction::Render // Force draw - Size column shown
+    }
+
+    /// Fetches writable layer sizes for a host in the background
+    pub(super) fn spawn_size_fetch(&self, host_id: &str) {
+        let Some(host) = self.connected_hosts.get(host_id).cloned() else {
+            return;
+        };
+        let tx = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            let result = host.fetch_container_sizes().await;
+            let _ = tx
+                .send(AppEvent::ContainerSizes(host.host_id.clone(), result))
+                .await;
+        });
+    }
+
+    pub(super) fn handle_toggle_absolute_timestamps(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.absolute_timestamps = !self.absolute_timestamps;
+        RenderAction::Render // Force redraw - timestamps changed
+    }
+
+    /// '%': switches the CPU column between a share of one core and of the whole host
+    pub(super) fn handle_toggle_cpu_normalized(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.cpu_normalized = !self.cpu_normalized;
+        // Written in the background, the key handler doesn't wait for the disk
+        if let Some(path) = self.config_path.clone() {
+            let value = self.cpu_normalized.to_string();
+            tokio::task::spawn_blocking(move || {
+                if let Err(e) = Config::save_preference(&path, "cpu_normalized", &value) {
+                    tracing::warn!("Failed to save cpu_normalized to {}: {}", path.display(), e);
+                }
+            });
+        }
+        RenderAction::Render // Force redraw - CPU values changed
+    }
+
+    /// '#': switches the memory column between percentage, used, and used/limit
+    pub(super) fn handle_cycle_memory_display(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.memory_display = self.memory_display.next();
+        RenderAction::Render // Force redraw - memory values changed
+    }
+
+    pub(super) fn handle_toggle_full_id(&mut self) -> RenderAction {
+        // Only meaningful while the details popup is open
+        if self.view_state != ViewState::ContainerList || !self.show_details {
+            return RenderAction::None;
+        }
+
+        self.show_full_id = !self.show_full_id;
+        RenderAction::Render // Force redraw - ID changed
+    }
 }
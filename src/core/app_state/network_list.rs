@@ -0,0 +1,92 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    AppEvent, HostId, NetworkInfo, NetworkListState, RenderAction, ViewState,
+};
+use crate::docker::networks::fetch_networks;
+
+impl AppState {
+    pub(super) fn handle_show_networks(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let mut network_list = NetworkListState::default();
+        for host in self.connected_hosts.values() {
+            network_list.loading.insert(host.host_id.clone());
+
+            let host = host.clone();
+            let tx = self.event_tx.clone();
+            tokio::spawn(async move {
+                let result = fetch_networks(&host).await;
+                let _ = tx
+                    .send(AppEvent::NetworkListLoaded(host.host_id, result))
+                    .await;
+            });
+        }
+
+        self.network_list = Some(network_list);
+        self.view_state = ViewState::NetworkList;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_network_list_loaded(
+        &mut self,
+        host_id: HostId,
+        result: Result<Vec<NetworkInfo>, String>,
+    ) -> RenderAction {
+        // Ignore results arriving after the view was closed
+        let Some(network_list) = &mut self.network_list else {
+            return RenderAction::None;
+        };
+
+        network_list.loading.remove(&host_id);
+        network_list
+            .networks
+            .retain(|network| network.host_id != host_id);
+        match result {
+            Ok(networks) => network_list.networks.extend(networks),
+            Err(error) => network_list.errors.push(format!("{}: {}", host_id, error)),
+        }
+        network_list
+            .networks
+            .sort_by(|a, b| a.host_id.cmp(&b.host_id).then_with(|| a.name.cmp(&b.name)));
+
+        // Keep the selection on the list
+        let count = network_list.networks.len();
+        let selected = match network_list.table_state.selected() {
+            _ if count == 0 => None,
+            Some(idx) => Some(idx.min(count - 1)),
+            None => Some(0),
+        };
+        network_list.table_state.select(selected);
+
+        RenderAction::Render // Force draw - networks changed
+    }
+
+    /// Moves the network selection by `delta`, staying within the list
+    pub(super) fn move_network_list_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(network_list) = &mut self.network_list else {
+            return RenderAction::None;
+        };
+        let Some(current) = network_list.table_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = network_list.networks.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        network_list.table_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_exit_networks(&mut self) -> RenderAction {
+        self.network_list = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -0,0 +1,129 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    AppEvent, ContainerAction, ContainerKey, NetworkPickerState, RenderAction, ViewState,
+};
+
+impl AppState {
+    /// Opens the network picker and loads the networks for the action in the background
+    pub(super) fn open_network_picker(
+        &mut self,
+        container_key: ContainerKey,
+        action: ContainerAction,
+    ) -> RenderAction {
+        let Some(host) = self.connected_hosts.get(&container_key.host_id).cloned() else {
+            return RenderAction::None;
+        };
+
+        // Disconnecting lists the container's networks, connecting all the others
+        let attached = action == ContainerAction::DisconnectNetwork;
+        let tx = self.event_tx.clone();
+        let key = container_key.clone();
+        tokio::spawn(async move {
+            let result = host.list_network_choices(&key.container_id, attached).await;
+            let _ = tx.send(AppEvent::NetworksLoaded(key, result)).await;
+        });
+
+        self.network_picker = Some(NetworkPickerState {
+            container_key: container_key.clone(),
+            action,
+            networks: None,
+            error: None,
+            list_state: Default::default(),
+        });
+        self.action_menu_state.select(None);
+        self.view_state = ViewState::NetworkPicker(container_key);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_networks_loaded(
+        &mut self,
+        key: ContainerKey,
+        result: Result<Vec<String>, String>,
+    ) -> RenderAction {
+        // Ignore results for a picker that has been closed or replaced
+        let Some(picker) = &mut self.network_picker else {
+            return RenderAction::None;
+        };
+        if picker.container_key != key {
+            return RenderAction::None;
+        }
+
+        match result {
+            Ok(networks) => {
+                picker
+                    .list_state
+                    .select(if networks.is_empty() { None } else { Some(0) });
+                picker.networks = Some(networks);
+            }
+            Err(error) => picker.error = Some(error),
+        }
+
+        RenderAction::Render // Force draw - networks loaded
+    }
+
+    /// Moves the picker selection by `delta`, staying within the list
+    pub(super) fn move_network_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(picker) = &mut self.network_picker else {
+            return RenderAction::None;
+        };
+        let count = picker.networks.as_ref().map_or(0, Vec::len);
+        let Some(current) = picker.list_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let next = current
+            .saturating_add_signed(delta)
+            .min(count.saturating_sub(1));
+        if next == current {
+            return RenderAction::None;
+        }
+        picker.list_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Connects or disconnects the container to/from the selected network
+    pub(super) fn handle_pick_network(&mut self) -> RenderAction {
+        let Some(picker) = &self.network_picker else {
+            return RenderAction::None;
+        };
+        let Some(network) = picker
+            .list_state
+            .selected()
+            .and_then(|idx| picker.networks.as_ref()?.get(idx))
+            .cloned()
+        else {
+            return RenderAction::None;
+        };
+        let Some(host) = self
+            .connected_hosts
+            .get(&picker.container_key.host_id)
+            .cloned()
+        else {
+            return RenderAction::None;
+        };
+
+        let container_key = picker.container_key.clone();
+        let action = picker.action;
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            crate::docker::actions::execute_network_action(
+                host,
+                container_key,
+                action,
+                network,
+                tx,
+            )
+            .await;
+        });
+
+        self.handle_close_network_picker()
+    }
+
+    pub(super) fn handle_close_network_picker(&mut self) -> RenderAction {
+        self.network_picker = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::Utc;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    ErrorDetailState, NOTIFICATIONS_SIZE, Notification, NotificationKind, RenderAction, ViewState,
+};
+
+impl AppState {
+    /// Keeps a notification in the panel until it is cleared, dropping the oldest beyond
+    /// the limit. A repeat of the newest unacknowledged notification only updates its time
+    pub(super) fn notify(&mut self, source: String, kind: NotificationKind) {
+        if let Some(newest) = self.notifications.back_mut()
+            && !newest.acknowledged
+            && newest.source == source
+            && newest.kind == kind
+        {
+            newest.time = Utc::now();
+            return;
+        }
+
+        self.notifications.push_back(Notification {
+            time: Utc::now(),
+            source,
+            kind,
+            acknowledged: false,
+        });
+        while self.notifications.len() > NOTIFICATIONS_SIZE {
+            self.notifications.pop_front();
+        }
+
+        // Keep the selected notification selected, the new one is listed above it
+        if let Some(idx) = self.notification_state.selected() {
+            self.notification_state
+                .select(Some((idx + 1).min(self.notifications.len() - 1)));
+        }
+    }
+
+    /// Number of notifications not acknowledged yet
+    pub fn unacknowledged_notifications(&self) -> usize {
+        self.notifications
+            .iter()
+            .filter(|n| !n.acknowledged)
+            .count()
+    }
+
+    /// Index into `notifications` of the selected row (rows are newest first)
+    fn selected_notification(&self) -> Option<usize> {
+        let row = self.notification_state.selected()?;
+        (row < self.notifications.len()).then(|| self.notifications.len() - 1 - row)
+    }
+
+    pub(super) fn handle_show_notifications(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let selected = (!self.notifications.is_empty()).then_some(0);
+        self.notification_state.select(selected);
+        self.view_state = ViewState::Notifications;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Moves the selection by `delta`, staying within the list
+    pub(super) fn move_notification_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(current) = self.notification_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = self.notifications.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.notification_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Acknowledges the selected notification and moves on to the next (older) one
+    pub(super) fn handle_acknowledge_notification(&mut self) -> RenderAction {
+        let Some(idx) = self.selected_notification() else {
+            return RenderAction::None;
+        };
+        self.notifications[idx].acknowledged = true;
+        self.move_notification_selection(1);
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_acknowledge_all_notifications(&mut self) -> RenderAction {
+        if self.view_state != ViewState::Notifications {
+            return RenderAction::None;
+        }
+
+        for notification in &mut self.notifications {
+            notification.acknowledged = true;
+        }
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_clear_notification(&mut self) -> RenderAction {
+        if self.view_state != ViewState::Notifications {
+            return RenderAction::None;
+        }
+        let Some(idx) = self.selected_notification() else {
+            return RenderAction::None;
+        };
+
+        self.notifications.remove(idx);
+        self.clamp_notification_selection();
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_clear_acknowledged_notifications(&mut self) -> RenderAction {
+        if self.view_state != ViewState::Notifications {
+            return RenderAction::None;
+        }
+
+        self.notifications.retain(|n| !n.acknowledged);
+        self.clamp_notification_selection();
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Keeps the selection on the list after notifications were cleared
+    fn clamp_notification_selection(&mut self) {
+        let count = self.notifications.len();
+        let selected = match self.notification_state.selected() {
+            _ if count == 0 => None,
+            Some(idx) => Some(idx.min(count - 1)),
+            None => Some(0),
+        };
+        self.notification_state.select(selected);
+    }
+
+    pub(super) fn handle_toggle_do_not_disturb(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.do_not_disturb = !self.do_not_disturb;
+
+        RenderAction::Render // Force draw - banners and title change
+    }
+
+    /// Opens the full error of the selected notification, acknowledging it
+    pub(super) fn handle_show_error_detail(&mut self) -> RenderAction {
+        if self.view_state != ViewState::Notifications {
+            return RenderAction::None;
+        }
+        let Some(idx) = self.selected_notification() else {
+            return RenderAction::None;
+        };
+        // Only errors have more to show than the panel
+        if self.notifications[idx].kind.error().is_none() {
+            return RenderAction::None;
+        }
+
+        self.notifications[idx].acknowledged = true;
+        self.error_detail = Some(ErrorDetailState {
+            notification: self.notifications[idx].clone(),
+            scroll_offset: 0,
+        });
+        self.view_state = ViewState::ErrorDetail;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Scrolls the error by `delta` lines (clamped to its length)
+    pub(super) fn scroll_error_detail(&mut self, delta: isize) -> RenderAction {
+        let Some(detail) = &mut self.error_detail else {
+            return RenderAction::None;
+        };
+
+        let max_offset = detail.chain().len().saturating_sub(1);
+        detail.scroll_offset = detail
+            .scroll_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_copy_error_detail(&mut self) -> RenderAction {
+        if self.view_state != ViewState::ErrorDetail {
+            return RenderAction::None;
+        }
+        let Some(detail) = &self.error_detail else {
+            return RenderAction::None;
+        };
+
+        self.copy_to_clipboard(detail.copy_text());
+
+        RenderAction::Render // Force draw - show the notification
+    }
+
+    /// Closes the error detail popup, back to the notifications panel
+    pub(super) fn handle_close_error_detail(&mut self) -> RenderAction {
+        self.error_detail = None;
+        self.view_state = ViewState::Notifications;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_exit_notifications(&mut self) -> RenderAction {
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -0,0 +1,100 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    ContainerKey, ContainerState, ProcessInfo, ProcessListState, RenderAction, ViewState,
+};
+use crate::docker::top::stream_container_top;
+
+impl AppState {
+    pub(super) fn handle_show_process_list(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        // Only running (or paused) containers have processes
+        let Some(container_key) = self.selected_container_key().cloned() else {
+            return RenderAction::None;
+        };
+        if !self
+            .containers
+            .get(&container_key)
+            .is_some_and(|c| matches!(c.state, ContainerState::Running | ContainerState::Paused))
+        {
+            return RenderAction::None;
+        }
+
+        let Some(host) = self.connected_hosts.get(&container_key.host_id).cloned() else {
+            return RenderAction::None;
+        };
+
+        // Refresh the processes in the background until the view is closed
+        let tx = self.event_tx.clone();
+        let key = container_key.clone();
+        let handle = tokio::spawn(async move {
+            stream_container_top(host, key, tx).await;
+        });
+
+        self.process_list = Some(ProcessListState {
+            container_key: container_key.clone(),
+            processes: None,
+            error: None,
+            scroll_offset: 0,
+            refresh_handle: Some(handle),
+        });
+        self.view_state = ViewState::ProcessList(container_key);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_processes_loaded(
+        &mut self,
+        key: ContainerKey,
+        result: Result<Vec<ProcessInfo>, String>,
+    ) -> RenderAction {
+        // Ignore results for a view that was already closed
+        let Some(state) = &mut self.process_list else {
+            return RenderAction::None;
+        };
+        if state.container_key != key {
+            return RenderAction::None;
+        }
+
+        // A failed refresh keeps the last processes on screen, with the error
+        match result {
+            Ok(processes) => {
+                state.scroll_offset = state.scroll_offset.min(processes.len().saturating_sub(1));
+                state.processes = Some(processes);
+                state.error = None;
+            }
+            Err(error) => state.error = Some(error),
+        }
+
+        RenderAction::Render // Force draw - processes refreshed
+    }
+
+    pub(super) fn handle_exit_process_list(&mut self) -> RenderAction {
+        if let Some(mut state) = self.process_list.take()
+            && let Some(handle) = state.refresh_handle.take()
+        {
+            handle.abort();
+        }
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Scrolls the process list by `delta` rows (clamped to the process count)
+    pub(super) fn scroll_process_list(&mut self, delta: isize) -> RenderAction {
+        let Some(state) = &mut self.process_list else {
+            return RenderAction::None;
+        };
+
+        let process_count = state.processes.as_ref().map_or(0, Vec::len);
+        let max_offset = process_count.saturating_sub(1);
+        state.scroll_offset = state
+            .scroll_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+
+        RenderAction::Render // Force draw
+    }
+}
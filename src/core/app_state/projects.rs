@@ -0,0 +1,273 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::core::app_state::AppState;
+use crate::core::budgets::{Budget, BudgetUsage};
+use crate::core::types::{
+    BulkKind, Container, ContainerAction, ContainerKey, ListRow, PlanStep, ProjectKey, ProjectKind,
+    RenderAction, ViewState,
+};
+
+impl AppState {
+    pub(super) fn handle_toggle_group_by_project(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.group_by_project = !self.group_by_project;
+        self.force_sort_containers();
+
+        // Row indices mean something else now
+        let selected = (self.row_count() > 0).then_some(0);
+        self.table_state.select(selected);
+        self.lost_selection = None;
+
+        RenderAction::Render // Force draw - rows changed
+    }
+
+    /// Groups the sorted containers by compose project or swarm stack and builds the rows with
+    /// project headers
+    /// Projects are ordered by name per host, followed by the containers without a project;
+    /// within a project the sort order is kept
+    pub(super) fn rebuild_list_rows(&mut self) {
+        self.list_rows.clear();
+        if !self.group_by_project {
+            return;
+        }
+
+        let containers = &self.containers;
+        let project = |key: &ContainerKey| -> Option<ProjectKey> {
+            let (kind, name) = containers.get(key)?.project()?;
+            Some(ProjectKey {
+                host_id: key.host_id.clone(),
+                kind,
+                name: name.to_string(),
+            })
+        };
+
+        // Stable sort, so the chosen sort order is kept within each group
+        self.sorted_container_keys.sort_by(|a, b| {
+            a.host_id
+                .cmp(&b.host_id)
+                .then_with(|| match (project(a), project(b)) {
+                    (Some(a), Some(b)) => a.name.cmp(&b.name).then(a.kind.cmp(&b.kind)),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                })
+        });
+
+        let mut current = None;
+        for key in &self.sorted_container_keys {
+            let key_project = project(key);
+            if key_project != current {
+                if let Some(header) = &key_project {
+                    self.list_rows.push(ListRow::Project(header.clone()));
+                }
+                current = key_project.clone();
+            }
+            if key_project.is_some_and(|p| self.collapsed_projects.contains(&p)) {
+                continue;
+            }
+            self.list_rows.push(ListRow::Container(key.clone()));
+        }
+
+        // Collapsing can leave the selection past the end
+        if let Some(selected) = self.table_state.selected()
+            && selected >= self.list_rows.len()
+        {
+            self.table_state.select(self.list_rows.len().checked_sub(1));
+        }
+    }
+
+    /// Expands or collapses the selected project header
+    pub(super) fn set_selected_project_collapsed(&mut self, collapsed: bool) -> RenderAction {
+        let Some(project) = self.selected_project().cloned() else {
+            return RenderAction::None;
+        };
+
+        let changed = if collapsed {
+            self.collapsed_projects.insert(project)
+        } else {
+            self.collapsed_projects.remove(&project)
+        };
+        if !changed {
+            return RenderAction::None;
+        }
+        self.rebuild_list_rows();
+
+        RenderAction::Render // Force draw - rows changed
+    }
+
+    pub(super) fn handle_show_project_action_menu(&mut self, project: ProjectKey) -> RenderAction {
+        // Swarm restarts or replaces the tasks of a stack itself
+        if project.kind == ProjectKind::Stack {
+            return RenderAction::None;
+        }
+
+        self.view_state = ViewState::ProjectActionMenu(project);
+        self.action_menu_state.select(Some(0));
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Moves the project action menu selection by `delta`, staying within the list
+    pub(super) fn move_project_action_selection(&mut self, delta: isize) -> RenderAction {
+        let current = self.action_menu_state.selected().unwrap_or(0);
+        let last = ContainerAction::available_for_project().len() - 1;
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.action_menu_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// All containers of a project, including those hidden by the filters
+    pub fn project_containers(&self, project: &ProjectKey) -> Vec<ContainerKey> {
+        let mut keys: Vec<ContainerKey> = self
+            .containers
+            .iter()
+            .filter(|(key, container)| {
+                key.host_id == project.host_id
+                    && container.project() == Some((project.kind, project.name.as_str()))
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.sort_by(|a, b| a.container_id.cmp(&b.container_id));
+        keys
+    }
+
+    /// The budgets whose label all of the project's containers carry, with what the
+    /// containers carrying the label use together
+    pub fn project_budgets(&self, project: &ProjectKey) -> Vec<(&Budget, BudgetUsage)> {
+        let keys = self.project_containers(project);
+        if keys.is_empty() {
+            return Vec::new();
+        }
+        self.budgets
+            .iter()
+            .filter(|budget| {
+                keys.iter()
+                    .filter_map(|key| self.containers.get(key))
+                    .all(|container| budget.applies_to(container))
+            })
+            .map(|budget| (budget, budget.usage(self.containers.values())))
+            .collect()
+    }
+
+    /// Shows the order the action will run the project's containers in, to be confirmed
+    pub(super) fn show_project_plan(
+        &mut self,
+        project: ProjectKey,
+        action: ContainerAction,
+    ) -> RenderAction {
+        if self.project_plan(&project, action).is_empty() {
+            // Nothing to do, e.g. stopping a project that is already stopped
+            self.view_state = ViewState::ContainerList;
+            self.action_menu_state.select(None);
+        } else {
+            self.view_state = ViewState::ProjectPlan(project, action);
+        }
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// The steps of an action on the containers of the project it is available for:
+    /// containers start after the services they depend on and stop before them;
+    /// a restart stops them all, then starts them again
+    pub fn project_plan(&self, project: &ProjectKey, action: ContainerAction) -> Vec<PlanStep> {
+        let containers: Vec<&Container> = self
+            .project_containers(project)
+            .iter()
+            .filter_map(|key| self.containers.get(key))
+            .filter(|container| {
+                ContainerAction::available_for_state(&container.state).contains(&action)
+            })
+            .collect();
+        let waves = start_waves(&containers);
+
+        let steps = |action: ContainerAction, waves: Vec<Vec<ContainerKey>>| {
+            waves
+                .into_iter()
+                .map(move |containers| PlanStep { action, containers })
+        };
+        match action {
+            ContainerAction::Start => steps(ContainerAction::Start, waves).collect(),
+            ContainerAction::Stop => {
+                steps(ContainerAction::Stop, waves.into_iter().rev().collect()).collect()
+            }
+            ContainerAction::Restart => {
+                steps(ContainerAction::Stop, waves.iter().rev().cloned().collect())
+                    .chain(steps(ContainerAction::Start, waves))
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Runs the confirmed plan step by step in the background
+    pub(super) fn handle_run_project_plan(&mut self) -> RenderAction {
+        let ViewState::ProjectPlan(project, action) = self.view_state.clone() else {
+            return RenderAction::None;
+        };
+        let Some(host) = self.connected_hosts.get(&project.host_id).cloned() else {
+            return RenderAction::None;
+        };
+
+        let steps = self.project_plan(&project, action);
+        let total = steps.iter().map(|step| step.containers.len()).sum();
+        let bulk = self.start_bulk(BulkKind::Project(project, action), total);
+        let options = self.action_options(self.stop_timeout);
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            crate::docker::actions::execute_plan(host, steps, options, bulk, tx).await;
+        });
+
+        // Close the plan immediately
+        self.view_state = ViewState::ContainerList;
+        self.action_menu_state.select(None);
+
+        RenderAction::Render // Force draw
+    }
+}
+
+/// Groups containers into waves that can be started together: each container comes after
+/// the services it depends on (among the given containers; others are taken as satisfied)
+/// Services caught in a dependency cycle are started together in a last wave
+fn start_waves(containers: &[&Container]) -> Vec<Vec<ContainerKey>> {
+    let services: HashSet<&str> = containers
+        .iter()
+        .filter_map(|c| c.compose_service.as_deref())
+        .collect();
+
+    let mut remaining: Vec<&Container> = containers.to_vec();
+    remaining.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut started: HashSet<&str> = HashSet::new();
+    let mut waves = Vec::new();
+
+    while !remaining.is_empty() {
+        let (ready, waiting): (Vec<&Container>, Vec<&Container>) =
+            remaining.into_iter().partition(|container| {
+                container.compose_depends_on.iter().all(|dependency| {
+                    !services.contains(dependency.as_str())
+                        || started.contains(dependency.as_str())
+                        || container.compose_service.as_ref() == Some(dependency)
+                })
+            });
+
+        // A cycle: nothing can go first
+        let (wave, waiting) = if ready.is_empty() {
+            (waiting, Vec::new())
+        } else {
+            (ready, waiting)
+        };
+
+        started.extend(wave.iter().filter_map(|c| c.compose_service.as_deref()));
+        waves.push(wave.iter().map(|c| c.key()).collect());
+        remaining = waiting;
+    }
+
+    waves
+}
@@ -0,0 +1,99 @@
+use std::time::Instant;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    HostId, NotificationKind, PruneReport, PruneTarget, RenderAction, ViewState,
+};
+
+impl AppState {
+    /// Opens the prune menu for a host, replacing the action menu
+    pub(super) fn open_prune_menu(&mut self, host_id: HostId) -> RenderAction {
+        self.action_menu_state.select(None);
+        self.prune_menu_state.select(Some(0));
+        self.prune_confirm = None;
+        self.view_state = ViewState::PruneMenu(host_id);
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Moves the prune menu selection by `delta`, staying within the menu
+    pub(super) fn move_prune_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(current) = self.prune_menu_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let next = current
+            .saturating_add_signed(delta)
+            .min(PruneTarget::ALL.len() - 1);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.prune_menu_state.select(Some(next));
+        // The confirmation is for the entry it was asked on
+        self.prune_confirm = None;
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Asks for confirmation on the first Enter, prunes on the second
+    pub(super) fn handle_run_prune(&mut self) -> RenderAction {
+        let ViewState::PruneMenu(host_id) = &self.view_state else {
+            return RenderAction::None;
+        };
+        let Some(target) = self
+            .prune_menu_state
+            .selected()
+            .and_then(|idx| PruneTarget::ALL.get(idx))
+            .copied()
+        else {
+            return RenderAction::None;
+        };
+
+        if self.prune_confirm != Some(target) {
+            self.prune_confirm = Some(target);
+            return RenderAction::Render; // Force draw - show the confirmation
+        }
+
+        if let Some(host) = self.connected_hosts.get(host_id).cloned() {
+            tokio::spawn(crate::docker::actions::execute_prune(
+                host,
+                target,
+                self.event_tx.clone(),
+            ));
+        }
+        self.handle_close_prune_menu()
+    }
+
+    /// Closes the confirmation if shown, otherwise the menu
+    pub(super) fn handle_cancel_prune_menu(&mut self) -> RenderAction {
+        if self.prune_confirm.take().is_some() {
+            return RenderAction::Render; // Force draw - confirmation closed
+        }
+        self.handle_close_prune_menu()
+    }
+
+    fn handle_close_prune_menu(&mut self) -> RenderAction {
+        self.prune_menu_state.select(None);
+        self.prune_confirm = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_prune_finished(
+        &mut self,
+        host_id: HostId,
+        target: PruneTarget,
+        result: Result<PruneReport, String>,
+    ) -> RenderAction {
+        if let Err(error) = &result {
+            tracing::warn!("Failed to prune on {}: {}", host_id, error);
+        }
+        self.notify(
+            host_id.clone(),
+            NotificationKind::PruneFinished(target, result.clone()),
+        );
+        self.prune_outcomes
+            .push((host_id, target, result, Instant::now()));
+        RenderAction::Render // Force draw - show the notification
+    }
+}
@@ -0,0 +1,53 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{Container, ContainerKey, ContainerState, RenderAction, ViewState};
+
+impl AppState {
+    pub(super) fn handle_toggle_replicas(&mut self) -> RenderAction {
+        // Only handle in ContainerList view, and only with a container selected
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+        if !self.show_replicas && self.selected_container_key().is_none() {
+            return RenderAction::None;
+        }
+
+        self.show_replicas = !self.show_replicas;
+        // One popup at a time
+        if self.show_replicas {
+            self.show_details = false;
+        }
+        RenderAction::Render // Force redraw to show/hide popup
+    }
+
+    /// Running containers (of any host) that are replicas of the given one, including itself,
+    /// ordered by host and name
+    /// Compose containers are replicas when they run the same service of a project of the
+    /// same name; other containers when they run the same image
+    pub fn replica_containers(&self, key: &ContainerKey) -> Vec<ContainerKey> {
+        let Some(container) = self.containers.get(key) else {
+            return Vec::new();
+        };
+
+        let mut replicas: Vec<&Container> = self
+            .containers
+            .values()
+            .filter(|other| {
+                other.state == ContainerState::Running && is_replica_of(other, container)
+            })
+            .collect();
+        replicas.sort_by(|a, b| (&a.host_id, &a.name).cmp(&(&b.host_id, &b.name)));
+
+        replicas.into_iter().map(Container::key).collect()
+    }
+}
+
+/// Whether two containers run the same compose service, or the same image outside of compose
+fn is_replica_of(a: &Container, b: &Container) -> bool {
+    match (&b.compose_project, &b.compose_service) {
+        (Some(project), Some(service)) => {
+            a.compose_project.as_ref() == Some(project)
+                && a.compose_service.as_ref() == Some(service)
+        }
+        _ => a.compose_service.is_none() && !b.image.is_empty() && a.image == b.image,
+    }
+}
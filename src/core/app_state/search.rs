@@ -3,6 +3,15 @@ use crate::core::types::{RenderAction, ViewState};
 
 impl AppState {
     pub(super) fn handle_enter_search_mode(&mut self) -> RenderAction {
+        // '/' in the log view searches the logs
+        if matches!(self.view_state, ViewState::LogView(_)) {
+            return self.handle_start_log_search();
+        }
+        // And filters the environment variables popup
+        if matches!(self.view_state, ViewState::EnvVars(_)) {
+            return self.handle_start_env_search();
+        }
+
         // Only allow entering search mode from ContainerList view
         if self.view_state != ViewState::ContainerList {
             return RenderAction::None;
@@ -33,7 +42,7 @@ impl AppState {
         self.force_sort_containers();
 
         // Adjust selection after clearing filter
-        let container_count = self.sorted_container_keys.len();
+        let container_count = self.row_count();
         if container_count == 0 {
             self.table_state.select(None);
         } else if let Some(selected) = self.table_state.selected()
@@ -73,7 +82,7 @@ impl AppState {
         self.force_sort_containers();
 
         // Adjust selection after filtering
-        let container_count = self.sorted_container_keys.len();
+        let container_count = self.row_count();
         if container_count == 0 {
             self.table_state.select(None);
         } else if let Some(selected) = self.table_state.selected()
@@ -0,0 +1,119 @@
+use crate::core::app_state::AppState;
+use crate::core::types::ListRow;
+
+/// A selected row hidden by a filter, to select again once it shows up
+#[derive(Clone, Debug)]
+pub struct LostSelection {
+    row: ListRow,
+    /// Line of the screen it was on
+    screen_row: usize,
+    /// The row selected instead; moving away from it forgets the lost row
+    instead: Option<ListRow>,
+}
+
+impl LostSelection {
+    /// The row to select again once it shows up: the one lost earlier if any, else the
+    /// selected one
+    fn remember(
+        lost: Option<LostSelection>,
+        selected: Option<ListRow>,
+        screen_row: usize,
+        instead: Option<ListRow>,
+    ) -> Option<LostSelection> {
+        match lost {
+            Some(lost) => Some(LostSelection { instead, ..lost }),
+            None => selected.map(|row| LostSelection {
+                row,
+                screen_row,
+                instead,
+            }),
+        }
+    }
+}
+
+impl AppState {
+    /// The row of the container list at `idx`
+    fn row_at(&self, idx: usize) -> Option<ListRow> {
+        if self.group_by_project {
+            self.list_rows.get(idx).cloned()
+        } else {
+            self.sorted_container_keys
+                .get(idx)
+                .cloned()
+                .map(ListRow::Container)
+        }
+    }
+
+    /// Where `row` is in the container list, if it is shown
+    fn row_index(&self, row: &ListRow) -> Option<usize> {
+        if self.group_by_project {
+            return self.list_rows.iter().position(|r| r == row);
+        }
+        match row {
+            ListRow::Container(key) => self.sorted_container_keys.iter().position(|k| k == key),
+            ListRow::Project(_) => None,
+        }
+    }
+
+    /// Runs `change` on the rows of the container list and selects the same row again
+    /// afterwards, on the same line of the screen, so re-sorts and filters don't lose one's
+    /// place in a long list
+    /// A row hidden by the change (show all, search, a collapsed project) is remembered and
+    /// selected again once it shows up, unless the selection was moved in the meantime
+    pub(super) fn keep_selection(&mut self, change: impl FnOnce(&mut Self)) {
+        let selected_idx = self.table_state.selected();
+        let selected = selected_idx.and_then(|idx| self.row_at(idx));
+        let mut screen_row =
+            selected_idx.map_or(0, |idx| idx.saturating_sub(self.table_state.offset()));
+        let lost = self
+            .lost_selection
+            .take()
+            .filter(|lost| lost.instead == selected);
+
+        change(self);
+
+        let lost_idx = lost.as_ref().and_then(|lost| self.row_index(&lost.row));
+        let selected_now = selected.as_ref().and_then(|row| self.row_index(row));
+        let row_count = self.row_count();
+
+        let idx = match (lost_idx, selected_idx) {
+            (Some(idx), _) => {
+                screen_row = lost.map_or(screen_row, |lost| lost.screen_row);
+                self.lost_selection = None;
+                idx
+            }
+            // Nothing to keep; callers pick the first row where they want one
+            (None, None) => {
+                self.lost_selection = lost.map(|lost| LostSelection {
+                    instead: None,
+                    ..lost
+                });
+                return;
+            }
+            (None, Some(_)) if row_count == 0 => {
+                self.table_state.select(None);
+                self.lost_selection = LostSelection::remember(lost, selected, screen_row, None);
+                return;
+            }
+            (None, Some(old_idx)) => match selected_now {
+                Some(idx) => {
+                    self.lost_selection = lost.map(|lost| LostSelection {
+                        instead: selected.clone(),
+                        ..lost
+                    });
+                    idx
+                }
+                None => {
+                    let idx = old_idx.min(row_count - 1);
+                    self.lost_selection =
+                        LostSelection::remember(lost, selected, screen_row, self.row_at(idx));
+                    idx
+                }
+            },
+        };
+
+        self.table_state.select(Some(idx));
+        let max_offset = row_count.saturating_sub(self.list_viewport_height);
+        *self.table_state.offset_mut() = idx.saturating_sub(screen_row).min(max_offset);
+    }
+}
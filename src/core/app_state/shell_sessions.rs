@@ -0,0 +1,75 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{RenderAction, ShellSessionInfo, ViewState};
+
+impl AppState {
+    pub(super) fn handle_show_shell_sessions(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let selected = (!self.shell_sessions.is_empty()).then_some(0);
+        self.shell_session_state.select(selected);
+        self.view_state = ViewState::ShellSessions;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    pub(super) fn handle_shell_session_started(&mut self, info: ShellSessionInfo) -> RenderAction {
+        self.shell_sessions.push(info);
+        RenderAction::None // The session takes over the terminal, redrawn when it returns
+    }
+
+    pub(super) fn handle_shell_session_ended(&mut self, id: usize) -> RenderAction {
+        self.shell_sessions.retain(|session| session.id != id);
+
+        // Keep the selection on the list
+        let count = self.shell_sessions.len();
+        let selected = match self.shell_session_state.selected() {
+            _ if count == 0 => None,
+            Some(idx) => Some(idx.min(count - 1)),
+            None => Some(0),
+        };
+        self.shell_session_state.select(selected);
+
+        if self.view_state == ViewState::ShellSessions {
+            RenderAction::Render // Force draw - session gone from the list
+        } else {
+            RenderAction::None
+        }
+    }
+
+    /// Moves the session selection by `delta`, staying within the list
+    pub(super) fn move_shell_session_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(current) = self.shell_session_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = self.shell_sessions.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        self.shell_session_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Hands the terminal back to the selected session
+    pub(super) fn handle_attach_shell_session(&mut self) -> RenderAction {
+        let Some(session) = self
+            .shell_session_state
+            .selected()
+            .and_then(|idx| self.shell_sessions.get(idx))
+        else {
+            return RenderAction::None;
+        };
+
+        RenderAction::AttachShell(session.id)
+    }
+
+    pub(super) fn handle_exit_shell_sessions(&mut self) -> RenderAction {
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
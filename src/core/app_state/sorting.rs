@@ -1,5 +1,10 @@
 use crate::core::app_state::AppState;
-use crate::core::types::{ContainerState, RenderAction, SortDirection, SortField, ViewState};
+use crate::core::scripting::compare_sort_keys;
+use crate::core::search_query::{LabelQuery, SearchQuery};
+use crate::core::types::{
+    ContainerState, ContainerStats, RenderAction, SortDirection, SortField, ViewState,
+};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Minimum time between sorts to avoid re-sorting on every frame
@@ -50,18 +55,9 @@ impl AppState {
         self.show_all_containers = !self.show_all_containers;
 
         // Force immediate re-sort/filter when user toggles visibility
+        // The selection follows its container, and comes back to it when shown again
         self.force_sort_containers();
 
-        // Adjust selection if needed after filtering
-        let container_count = self.sorted_container_keys.len();
-        if container_count == 0 {
-            self.table_state.select(None);
-        } else if let Some(selected) = self.table_state.selected()
-            && selected >= container_count
-        {
-            self.table_state.select(Some(container_count - 1));
-        }
-
         RenderAction::Render // Force redraw - visibility changed
     }
 
@@ -85,9 +81,20 @@ impl AppState {
 
         // Update last sort time
         self.last_sort_time = std::time::Instant::now();
-        // Get the search filter (case-insensitive)
-        let search_filter = self.search_input.value().to_lowercase();
-        let has_search_filter = !search_filter.is_empty();
+
+        self.keep_selection(|state| {
+            state.filter_and_sort_keys();
+            state.rebuild_list_rows();
+        });
+    }
+
+    /// Rebuilds the sorted keys from the containers passing the state and search filters
+    fn filter_and_sort_keys(&mut self) {
+        // Parse the search filter (case-insensitive substring unless modifiers say otherwise)
+        let search_query = SearchQuery::parse(self.search_input.value());
+        let has_search_filter = !search_query.is_empty();
+        // `key=value` and `label:` queries search the labels instead
+        let label_query = LabelQuery::parse(&search_query);
 
         // Rebuild sorted_container_keys from containers, filtering by running state and search term
         self.sorted_container_keys = self
@@ -98,10 +105,12 @@ impl AppState {
                 let passes_state_filter = if self.show_all_containers {
                     true // Show all containers
                 } else {
-                    // Only show running containers
+                    // Only show running containers (paused ones too, like `docker ps`)
                     self.containers
                         .get(key)
-                        .map(|c| c.state == ContainerState::Running)
+                        .map(|c| {
+                            matches!(c.state, ContainerState::Running | ContainerState::Paused)
+                        })
                         .unwrap_or(false)
                 };
 
@@ -112,13 +121,16 @@ impl AppState {
                 // Then filter by search term if present
                 if has_search_filter {
                     if let Some(container) = self.containers.get(key) {
-                        // Search in name, id, and host_id (case-insensitive)
-                        let name_matches = container.name.to_lowercase().contains(&search_filter);
-                        let id_matches = container.id.to_lowercase().contains(&search_filter);
-                        let host_matches =
-                            container.host_id.to_lowercase().contains(&search_filter);
-
-                        name_matches || id_matches || host_matches
+                        if let Some(label_query) = &label_query {
+                            return container
+                                .labels
+                                .iter()
+                                .any(|(key, value)| label_query.matches(key, value));
+                        }
+                        // Search in name, id, and host_id
+                        search_query.matches(&container.name)
+                            || search_query.matches(&container.id)
+                            || search_query.matches(&container.host_id)
                     } else {
                         false
                     }
@@ -226,6 +238,107 @@ impl AppState {
                     }
                 });
             }
+            SortField::BlockRead | SortField::BlockWrite => {
+                let rate = |stats: &ContainerStats| {
+                    if self.sort_state.field == SortField::BlockRead {
+                        stats.block_read_bytes_per_sec
+                    } else {
+                        stats.block_write_bytes_per_sec
+                    }
+                };
+                self.sorted_container_keys.sort_by(|a, b| {
+                    let container_a = self.containers.get(a).unwrap();
+                    let container_b = self.containers.get(b).unwrap();
+
+                    // First by host_id
+                    match container_a.host_id.cmp(&container_b.host_id) {
+                        std::cmp::Ordering::Equal => {
+                            let ord = rate(&container_a.stats)
+                                .partial_cmp(&rate(&container_b.stats))
+                                .unwrap_or(std::cmp::Ordering::Equal);
+                            // Reverse if descending
+                            if direction == SortDirection::Descending {
+                                ord.reverse()
+                            } else {
+                                ord
+                            }
+                        }
+                        other => other,
+                    }
+                });
+            }
+            SortField::Restarts => {
+                self.sorted_container_keys.sort_by(|a, b| {
+                    let container_a = self.containers.get(a).unwrap();
+                    let container_b = self.containers.get(b).unwrap();
+
+                    // First by host_id
+                    match container_a.host_id.cmp(&container_b.host_id) {
+                        std::cmp::Ordering::Equal => {
+                            // Restarts seen this session point at a crash loop happening now
+                            let ord = (container_a.session_restarts, container_a.restart_count)
+                                .cmp(&(container_b.session_restarts, container_b.restart_count));
+                            // Reverse if descending
+                            if direction == SortDirection::Descending {
+                                ord.reverse()
+                            } else {
+                                ord
+                            }
+                        }
+                        other => other,
+                    }
+                });
+            }
+            SortField::Pids => {
+                self.sorted_container_keys.sort_by(|a, b| {
+                    let container_a = self.containers.get(a).unwrap();
+                    let container_b = self.containers.get(b).unwrap();
+
+                    // First by host_id
+                    match container_a.host_id.cmp(&container_b.host_id) {
+                        std::cmp::Ordering::Equal => {
+                            let ord = container_a.stats.pids.cmp(&container_b.stats.pids);
+                            // Reverse if descending
+                            if direction == SortDirection::Descending {
+                                ord.reverse()
+                            } else {
+                                ord
+                            }
+                        }
+                        other => other,
+                    }
+                });
+            }
+            SortField::Script => {
+                // Evaluate each sort key once up front rather than inside the comparator
+                let Some(hooks) = &self.script_hooks else {
+                    return;
+                };
+                let sort_keys: HashMap<_, _> = self
+                    .sorted_container_keys
+                    .iter()
+                    .map(|key| {
+                        let sort_key = self.containers.get(key).and_then(|c| hooks.sort_key(c));
+                        (key.clone(), sort_key)
+                    })
+                    .collect();
+
+                self.sorted_container_keys.sort_by(|a, b| {
+                    // First by host_id
+                    match a.host_id.cmp(&b.host_id) {
+                        std::cmp::Ordering::Equal => {
+                            let ord = compare_sort_keys(&sort_keys[a], &sort_keys[b]);
+                            // Reverse if descending
+                            if direction == SortDirection::Descending {
+                                ord.reverse()
+                            } else {
+                                ord
+                            }
+                        }
+                        other => other,
+                    }
+                });
+            }
         }
     }
 }
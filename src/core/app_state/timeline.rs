@@ -0,0 +1,99 @@
+use chrono::Utc;
+use std::path::PathBuf;
+
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    ContainerKey, RenderAction, TIMELINE_SIZE, TimelineEntry, TimelineKind, ViewState,
+};
+
+impl AppState {
+    /// Adds an event to the timeline, dropping the oldest beyond the limit
+    pub(super) fn record_timeline(&mut self, key: &ContainerKey, kind: TimelineKind) {
+        let name = self
+            .containers
+            .get(key)
+            .map_or_else(|| key.short_id().to_string(), |c| c.name.clone());
+
+        let entry = TimelineEntry {
+            time: Utc::now(),
+            key: key.clone(),
+            name,
+            kind,
+        };
+        if let Some(history) = &mut self.history {
+            history.record_event(&entry);
+        }
+        self.timeline.push_back(entry);
+        while self.timeline.len() > TIMELINE_SIZE {
+            self.timeline.pop_front();
+        }
+
+        // Keep the entries on screen while scrolled back
+        if self.timeline_scroll > 0 {
+            self.timeline_scroll = (self.timeline_scroll + 1).min(self.timeline.len() - 1);
+        }
+    }
+
+    pub(super) fn handle_container_exec(
+        &mut self,
+        key: ContainerKey,
+        command: String,
+    ) -> RenderAction {
+        self.record_timeline(&key, TimelineKind::Exec(command));
+
+        if self.view_state == ViewState::Timeline {
+            RenderAction::Render // Force draw - new entry visible
+        } else {
+            RenderAction::None
+        }
+    }
+
+    pub(super) fn handle_log_snapshot_taken(
+        &mut self,
+        key: ContainerKey,
+        result: Result<PathBuf, String>,
+    ) -> RenderAction {
+        if let Err(error) = &result {
+            tracing::warn!("Log snapshot of {} failed: {}", key.short_id(), error);
+        }
+        self.record_timeline(&key, TimelineKind::LogSnapshot(result));
+
+        if self.view_state == ViewState::Timeline {
+            RenderAction::Render // Force draw - new entry visible
+        } else {
+            RenderAction::None
+        }
+    }
+
+    pub(super) fn handle_show_timeline(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        self.timeline_scroll = 0;
+        self.view_state = ViewState::Timeline;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Scrolls the timeline by `delta` entries (positive towards older events)
+    pub(super) fn scroll_timeline(&mut self, delta: isize) -> RenderAction {
+        let max_offset = self.timeline.len().saturating_sub(1);
+        let offset = self
+            .timeline_scroll
+            .saturating_add_signed(delta)
+            .min(max_offset);
+        if offset == self.timeline_scroll {
+            return RenderAction::None;
+        }
+        self.timeline_scroll = offset;
+
+        RenderAction::Render // Force draw
+    }
+
+    pub(super) fn handle_exit_timeline(&mut self) -> RenderAction {
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
@@ -0,0 +1,205 @@
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    AppEvent, BulkKind, HostId, RenderAction, ViewState, VolumeInfo, VolumeListState,
+};
+use crate::docker::connection::DockerHost;
+use crate::docker::volumes::{fetch_volumes, remove_volumes};
+
+impl AppState {
+    pub(super) fn handle_show_volumes(&mut self) -> RenderAction {
+        // Only handle in ContainerList view
+        if self.view_state != ViewState::ContainerList {
+            return RenderAction::None;
+        }
+
+        let mut volume_list = VolumeListState::default();
+        for host in self.connected_hosts.values() {
+            volume_list.loading.insert(host.host_id.clone());
+            self.spawn_volume_fetch(host.clone());
+        }
+
+        self.volume_list = Some(volume_list);
+        self.view_state = ViewState::VolumeList;
+
+        RenderAction::Render // Force draw - view changed
+    }
+
+    /// Loads the volumes of a host in the background
+    fn spawn_volume_fetch(&self, host: DockerHost) {
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let result = fetch_volumes(&host).await;
+            let _ = tx.send(AppEvent::VolumesLoaded(host.host_id, result)).await;
+        });
+    }
+
+    pub(super) fn handle_volumes_loaded(
+        &mut self,
+        host_id: HostId,
+        result: Result<Vec<VolumeInfo>, String>,
+    ) -> RenderAction {
+        // Ignore results arriving after the view was closed
+        let Some(volume_list) = &mut self.volume_list else {
+            return RenderAction::None;
+        };
+
+        volume_list.loading.remove(&host_id);
+        volume_list
+            .volumes
+            .retain(|volume| volume.host_id != host_id);
+        match result {
+            Ok(volumes) => volume_list.volumes.extend(volumes),
+            Err(error) => volume_list.errors.push(format!("{}: {}", host_id, error)),
+        }
+        volume_list
+            .volumes
+            .sort_by(|a, b| a.host_id.cmp(&b.host_id).then_with(|| a.name.cmp(&b.name)));
+
+        // Keep the selection on the list
+        let count = volume_list.volumes.len();
+        let selected = match volume_list.table_state.selected() {
+            _ if count == 0 => None,
+            Some(idx) => Some(idx.min(count - 1)),
+            None => Some(0),
+        };
+        volume_list.table_state.select(selected);
+
+        RenderAction::Render // Force draw - volumes changed
+    }
+
+    /// Asks for confirmation before removing the orphaned volumes
+    pub(super) fn handle_remove_orphaned_volumes(&mut self) -> RenderAction {
+        if self.view_state != ViewState::VolumeList {
+            return RenderAction::None;
+        }
+        let Some(volume_list) = &mut self.volume_list else {
+            return RenderAction::None;
+        };
+        // Wait for all hosts so the confirmation covers every orphan
+        if !volume_list.loading.is_empty() || volume_list.orphans().next().is_none() {
+            return RenderAction::None;
+        }
+
+        volume_list.confirm_remove = true;
+        volume_list.remove_selected = false;
+        RenderAction::Render // Force draw - show the confirmation
+    }
+
+    /// Asks for confirmation before removing the selected volume, if no container uses it
+    pub(super) fn handle_remove_selected_volume(&mut self) -> RenderAction {
+        if self.view_state != ViewState::VolumeList {
+            return RenderAction::None;
+        }
+        let Some(volume_list) = &mut self.volume_list else {
+            return RenderAction::None;
+        };
+        if volume_list.confirm_remove || volume_list.selected_orphan().is_none() {
+            return RenderAction::None;
+        }
+
+        volume_list.confirm_remove = true;
+        volume_list.remove_selected = true;
+        RenderAction::Render // Force draw - show the confirmation
+    }
+
+    /// Removes the confirmed volumes, then reloads the affected hosts
+    pub(super) fn handle_confirm_remove_volumes(&mut self) -> RenderAction {
+        let Some(volume_list) = &mut self.volume_list else {
+            return RenderAction::None;
+        };
+        if !volume_list.confirm_remove {
+            return RenderAction::None;
+        }
+        volume_list.confirm_remove = false;
+
+        let mut by_host: std::collections::HashMap<HostId, Vec<String>> = Default::default();
+        for volume in volume_list.removal_candidates() {
+            by_host
+                .entry(volume.host_id.clone())
+                .or_default()
+                .push(volume.name.clone());
+        }
+
+        by_host.retain(|host_id, _| self.connected_hosts.contains_key(host_id));
+        let total = by_host.values().map(Vec::len).sum();
+        let bulk = self.start_bulk(BulkKind::RemoveVolumes, total);
+
+        for (host_id, names) in by_host {
+            let Some(host) = self.connected_hosts.get(&host_id).cloned() else {
+                continue;
+            };
+            if let Some(volume_list) = &mut self.volume_list {
+                volume_list.loading.insert(host_id.clone());
+            }
+
+            let tx = self.event_tx.clone();
+            let bulk = bulk.clone();
+            tokio::spawn(async move {
+                let errors = remove_volumes(&host, &names, &bulk).await;
+                let _ = tx
+                    .send(AppEvent::VolumesRemoved(host_id.clone(), errors))
+                    .await;
+                let result = fetch_volumes(&host).await;
+                let _ = tx.send(AppEvent::VolumesLoaded(host_id, result)).await;
+            });
+        }
+
+        RenderAction::Render // Force draw - hosts are reloading
+    }
+
+    pub(super) fn handle_volumes_removed(
+        &mut self,
+        host_id: HostId,
+        errors: Vec<String>,
+    ) -> RenderAction {
+        let Some(volume_list) = &mut self.volume_list else {
+            return RenderAction::None;
+        };
+        if errors.is_empty() {
+            return RenderAction::None;
+        }
+
+        volume_list.errors.extend(
+            errors
+                .into_iter()
+                .map(|error| format!("{}: {}", host_id, error)),
+        );
+        RenderAction::Render // Force draw - show the errors
+    }
+
+    /// Moves the volume selection by `delta`, staying within the list
+    pub(super) fn move_volume_selection(&mut self, delta: isize) -> RenderAction {
+        let Some(volume_list) = &mut self.volume_list else {
+            return RenderAction::None;
+        };
+        if volume_list.confirm_remove {
+            return RenderAction::None;
+        }
+        let Some(current) = volume_list.table_state.selected() else {
+            return RenderAction::None;
+        };
+
+        let last = volume_list.volumes.len().saturating_sub(1);
+        let next = current.saturating_add_signed(delta).min(last);
+        if next == current {
+            return RenderAction::None;
+        }
+        volume_list.table_state.select(Some(next));
+
+        RenderAction::Render // Force draw
+    }
+
+    /// Closes the confirmation if shown, otherwise the volumes view
+    pub(super) fn handle_exit_volumes(&mut self) -> RenderAction {
+        if let Some(volume_list) = &mut self.volume_list
+            && volume_list.confirm_remove
+        {
+            volume_list.confirm_remove = false;
+            return RenderAction::Render; // Force draw - confirmation closed
+        }
+
+        self.volume_list = None;
+        self.view_state = ViewState::ContainerList;
+        RenderAction::Render // Force draw - view changed
+    }
+}
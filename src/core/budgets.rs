@@ -0,0 +1,153 @@
+//! CPU and memory budgets per label value, from the `budgets:` config section
+//!
+//! A budget covers every running container carrying its label, across all hosts. In
+//! the grouped view, a project whose containers all carry the label shows the budget's
+//! actual usage against it.
+
+use crate::cli::config::BudgetConfig;
+use crate::core::types::{Container, ContainerState};
+
+/// Bytes in a GB as memory is shown (binary, like the Memory column)
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// A configured budget
+#[derive(Clone, Debug, PartialEq)]
+pub struct Budget {
+    pub key: String,
+    pub value: String,
+    /// CPUs (100% CPU per CPU, as Docker reports it)
+    pub cpus: Option<f64>,
+    pub memory_bytes: Option<u64>,
+}
+
+/// What the containers of a budget use together
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BudgetUsage {
+    pub cpus: f64,
+    pub memory_bytes: u64,
+}
+
+impl Budget {
+    pub fn from_config(config: &BudgetConfig) -> Result<Self, String> {
+        let Some((key, value)) = config.label.split_once('=') else {
+            return Err(format!(
+                "budget label '{}' needs a value (key=value)",
+                config.label
+            ));
+        };
+        if config.cpus.is_none() && config.memory_gb.is_none() {
+            return Err(format!(
+                "budget for '{}' needs cpus or memory_gb",
+                config.label
+            ));
+        }
+        Ok(Self {
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+            cpus: config.cpus,
+            memory_bytes: config
+                .memory_gb
+                .map(|gb| (gb * BYTES_PER_GB).round() as u64),
+        })
+    }
+
+    /// The label as configured, "team=payments"
+    pub fn label(&self) -> String {
+        format!("{}={}", self.key, self.value)
+    }
+
+    /// Whether the container carries the budget's label
+    pub fn applies_to(&self, container: &Container) -> bool {
+        container.labels.get(&self.key) == Some(&self.value)
+    }
+
+    /// What the running containers carrying the label use together
+    pub fn usage<'a>(&self, containers: impl IntoIterator<Item = &'a Container>) -> BudgetUsage {
+        containers
+            .into_iter()
+            .filter(|c| c.state == ContainerState::Running && self.applies_to(c))
+            .fold(BudgetUsage::default(), |usage, c| BudgetUsage {
+                cpus: usage.cpus + c.stats.cpu / 100.0,
+                memory_bytes: usage.memory_bytes + c.stats.memory_used_bytes,
+            })
+    }
+
+    pub fn cpus_over(&self, usage: &BudgetUsage) -> bool {
+        self.cpus.is_some_and(|cpus| usage.cpus > cpus)
+    }
+
+    pub fn memory_over(&self, usage: &BudgetUsage) -> bool {
+        self.memory_bytes
+            .is_some_and(|bytes| usage.memory_bytes > bytes)
+    }
+}
+
+/// The configured budgets, with invalid ones reported
+pub fn budgets_from_config(configs: &[BudgetConfig]) -> Result<Vec<Budget>, String> {
+    configs.iter().map(Budget::from_config).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ContainerStats;
+    use std::collections::BTreeMap;
+
+    fn container(team: &str, state: ContainerState, cpu: f64, memory: u64) -> Container {
+        Container {
+            state,
+            labels: BTreeMap::from([("team".to_string(), team.to_string())]),
+            stats: ContainerStats {
+                cpu,
+                memory_used_bytes: memory,
+                ..Default::default()
+            },
+            ..Container::test_default()
+        }
+    }
+
+    #[test]
+    fn test_budget_from_config() {
+        let config = |label: &str, cpus| BudgetConfig {
+            label: label.to_string(),
+            cpus,
+            memory_gb: None,
+        };
+        let budget = Budget::from_config(&config("team=payments", Some(2.0))).unwrap();
+        assert_eq!(budget.label(), "team=payments");
+        assert_eq!(budget.cpus, Some(2.0));
+        assert!(Budget::from_config(&config("team", Some(2.0))).is_err());
+        assert!(Budget::from_config(&config("team=payments", None)).is_err());
+
+        let budget = Budget::from_config(&BudgetConfig {
+            label: "team=payments".to_string(),
+            cpus: None,
+            memory_gb: Some(0.5),
+        })
+        .unwrap();
+        assert_eq!(budget.memory_bytes, Some(512 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_budget_usage() {
+        let budget = Budget {
+            key: "team".to_string(),
+            value: "payments".to_string(),
+            cpus: Some(1.0),
+            memory_bytes: Some(1000),
+        };
+        let containers = [
+            container("payments", ContainerState::Running, 80.0, 600),
+            container("payments", ContainerState::Running, 40.0, 300),
+            // Neither stopped containers nor other teams count
+            container("payments", ContainerState::Exited, 90.0, 900),
+            container("search", ContainerState::Running, 90.0, 900),
+        ];
+
+        let usage = budget.usage(&containers);
+        assert!((usage.cpus - 1.2).abs() < 1e-9);
+        assert_eq!(usage.memory_bytes, 900);
+        assert!(budget.cpus_over(&usage));
+        assert!(!budget.memory_over(&usage));
+    }
+}
@@ -0,0 +1,156 @@
+//! Bulk actions: the same action run on many containers or objects, possibly across
+//! hosts, with at most a configured number in flight at once
+//!
+//! Every item reports its outcome as BulkItemDone, which drives the progress banner.
+//! Once stopped, items that haven't started yet are skipped on every host.
+
+use futures_util::future::join_all;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Semaphore;
+
+use crate::core::types::{AppEvent, EventSender};
+
+/// A running bulk action, shared by its tasks (one per host)
+#[derive(Clone)]
+pub struct BulkRun {
+    id: u64,
+    permits: Arc<Semaphore>,
+    stopped: Arc<AtomicBool>,
+    tx: EventSender,
+}
+
+impl BulkRun {
+    pub fn new(id: u64, concurrency: usize, tx: EventSender) -> Self {
+        Self {
+            id,
+            permits: Arc::new(Semaphore::new(concurrency.max(1))),
+            stopped: Arc::new(AtomicBool::new(false)),
+            tx,
+        }
+    }
+
+    /// Runs `task` on every item, within the concurrency limit of the whole bulk action,
+    /// returning the errors; items whose turn comes after a stop are skipped
+    pub async fn run<T, F, Fut>(&self, items: impl IntoIterator<Item = T>, task: F) -> Vec<String>
+    where
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        let task = &task;
+        let results = join_all(items.into_iter().map(|item| async move {
+            // The semaphore is never closed
+            let _permit = self.permits.acquire().await.ok();
+            if self.is_stopped() {
+                return Ok(());
+            }
+            let result = task(item).await;
+            let _ = self
+                .tx
+                .send(AppEvent::BulkItemDone(self.id, result.is_ok()))
+                .await;
+            result
+        }))
+        .await;
+        results.into_iter().filter_map(Result::err).collect()
+    }
+
+    /// Whether the bulk action was stopped
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::SeqCst)
+    }
+
+    /// Ends the bulk action early: items not started yet won't run, on any host, while
+    /// those already running finish
+    pub async fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        let _ = self.tx.send(AppEvent::BulkStopped(self.id)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_run_limits_concurrency() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let bulk = BulkRun::new(7, 2, tx);
+        let running = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+
+        let errors = bulk
+            .run(0..6, |i| {
+                let (running, most) = (&running, &most);
+                async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    most.fetch_max(now, Ordering::SeqCst);
+                    tokio::task::yield_now().await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    if i == 3 {
+                        Err("boom".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(errors, ["boom"]);
+        assert_eq!(most.load(Ordering::SeqCst), 2);
+        let mut failed = 0;
+        for _ in 0..6 {
+            match rx.recv().await {
+                Some(AppEvent::BulkItemDone(7, ok)) => failed += usize::from(!ok),
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stop_skips_later_items() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let bulk = BulkRun::new(7, 1, tx);
+        let ran = std::sync::Mutex::new(Vec::new());
+
+        let errors = bulk
+            .run(0..5, |i| {
+                let (bulk, ran) = (&bulk, &ran);
+                async move {
+                    ran.lock().unwrap().push(i);
+                    if i == 1 {
+                        bulk.stop().await;
+                        return Err("boom".to_string());
+                    }
+                    Ok(())
+                }
+            })
+            .await;
+
+        assert_eq!(errors, ["boom"]);
+        assert_eq!(*ran.lock().unwrap(), [0, 1]);
+        assert!(bulk.is_stopped());
+
+        // Later runs of the same bulk action, e.g. another host's, skip everything
+        let errors = bulk.run(0..3, |_| async { Err("ran".to_string()) }).await;
+        assert!(errors.is_empty());
+
+        drop(bulk);
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(format!("{:?}", event));
+        }
+        assert_eq!(
+            events,
+            [
+                "BulkItemDone(7, true)",
+                "BulkStopped(7)",
+                "BulkItemDone(7, false)"
+            ]
+        );
+    }
+}
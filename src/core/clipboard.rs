@@ -0,0 +1,60 @@
+//! Copying text to the clipboard
+//!
+//! Locally the system clipboard tool is used (pbcopy, wl-copy, xclip, xsel, clip). Over
+//! SSH, or when no tool is available, the terminal is asked to set the clipboard with
+//! OSC 52, which reaches the clipboard of the machine the terminal runs on.
+
+use crossterm::clipboard::CopyToClipboard;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Copies the text to the clipboard
+pub fn copy(text: &str, is_ssh_session: bool) -> Result<(), String> {
+    // The system clipboard over SSH would be the remote machine's
+    if !is_ssh_session && copy_with_system_tool(text).is_ok() {
+        return Ok(());
+    }
+    crossterm::execute!(std::io::stdout(), CopyToClipboard::to_clipboard_from(text))
+        .map_err(|e| e.to_string())
+}
+
+/// Clipboard tools to try in order, with their arguments
+fn clipboard_tools() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else if cfg!(windows) {
+        vec![("clip", &[])]
+    } else {
+        let mut tools: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            tools.push(("wl-copy", &[]));
+        }
+        tools.push(("xclip", &["-selection", "clipboard"]));
+        tools.push(("xsel", &["--clipboard", "--input"]));
+        tools
+    }
+}
+
+/// Pipes the text into the first clipboard tool that runs successfully
+fn copy_with_system_tool(text: &str) -> Result<(), String> {
+    for (program, args) in clipboard_tools() {
+        // Output would end up on the UI
+        let Ok(mut child) = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        let written = child
+            .stdin
+            .take()
+            .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        if child.wait().is_ok_and(|status| status.success()) && written {
+            return Ok(());
+        }
+    }
+    Err("no clipboard tool found".to_string())
+}
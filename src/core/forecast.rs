@@ -0,0 +1,120 @@
+//! Forecast of memory exhaustion from the recent memory growth of a container
+//!
+//! A least-squares line is fitted through the memory trend samples. When memory grows
+//! steadily (the line explains most of the variation) the line is extended to the
+//! container's memory limit, giving the time left before an OOM kill is likely.
+
+use std::time::Duration;
+
+use crate::core::types::{ContainerStats, MEMORY_TREND_BUCKET_SECS};
+
+/// Samples needed before a forecast is made (5 minutes)
+const MIN_TREND_SAMPLES: usize = 10;
+
+/// Forecasts closer than this (an hour) are shown as a warning banner
+pub const FORECAST_WARNING: Duration = Duration::from_secs(60 * 60);
+
+/// Share of the variation the growth line must explain to count as steady growth
+const MIN_FIT: f64 = 0.8;
+
+/// Time left before the container's memory use reaches its limit, if it grows
+/// steadily towards a limit
+pub fn memory_exhaustion(stats: &ContainerStats) -> Option<Duration> {
+    let trend = &stats.memory_trend;
+    if stats.memory_limit_bytes == 0 || trend.len() < MIN_TREND_SAMPLES {
+        return None;
+    }
+
+    // Seconds since the first sample against bytes used
+    let first_bucket = trend.front()?.0;
+    let points: Vec<(f64, f64)> = trend
+        .iter()
+        .map(|&(bucket, bytes)| {
+            (
+                ((bucket - first_bucket) * MEMORY_TREND_BUCKET_SECS) as f64,
+                bytes as f64,
+            )
+        })
+        .collect();
+
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance_x: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let variance_y: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+
+    // Bytes per second, and how well the line fits (R²)
+    let slope = covariance / variance_x;
+    let fit = covariance.powi(2) / (variance_x * variance_y);
+    if slope <= 0.0 || fit < MIN_FIT {
+        return None;
+    }
+
+    let remaining = stats
+        .memory_limit_bytes
+        .saturating_sub(stats.memory_used_bytes) as f64;
+    Some(Duration::from_secs_f64(remaining / slope))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1024 * 1024;
+
+    fn stats(trend: impl IntoIterator<Item = u64>) -> ContainerStats {
+        let memory_trend: std::collections::VecDeque<(u64, u64)> = trend
+            .into_iter()
+            .enumerate()
+            .map(|(i, bytes)| (1000 + i as u64, bytes))
+            .collect();
+        ContainerStats {
+            memory_used_bytes: memory_trend.back().map_or(0, |&(_, bytes)| bytes),
+            memory_limit_bytes: 1024 * MB,
+            memory_trend,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_memory_exhaustion_steady_growth() {
+        // 1 MB per sample up to 519 MB: 505 MB left at 1 MB per 30 seconds
+        let forecast = memory_exhaustion(&stats((500..520).map(|mb| mb * MB))).unwrap();
+        assert_eq!(forecast.as_secs(), 505 * MEMORY_TREND_BUCKET_SECS);
+    }
+
+    #[test]
+    fn test_memory_exhaustion_no_forecast() {
+        // Too few samples
+        assert_eq!(
+            memory_exhaustion(&stats((500..505).map(|mb| mb * MB))),
+            None
+        );
+        // Flat or shrinking
+        assert_eq!(memory_exhaustion(&stats([500 * MB; 20])), None);
+        assert_eq!(
+            memory_exhaustion(&stats((500..520).rev().map(|mb| mb * MB))),
+            None
+        );
+        // Up and down rather than steady growth
+        let noisy = (0..20).map(|i| {
+            if i % 2 == 0 {
+                400 * MB
+            } else {
+                600 * MB + i * MB
+            }
+        });
+        assert_eq!(memory_exhaustion(&stats(noisy)), None);
+        // No limit to run into
+        let mut unlimited = stats((500..520).map(|mb| mb * MB));
+        unlimited.memory_limit_bytes = 0;
+        assert_eq!(memory_exhaustion(&unlimited), None);
+    }
+}
@@ -0,0 +1,426 @@
+//! Optional SQLite store of stats samples and container events, from the `history_db:`
+//! config section
+//!
+//! While the UI runs, one stats sample per running container per minute and every
+//! events timeline entry are written to the database, by a thread of its own so the UI
+//! never waits on the disk. Rows older than the retention are deleted at startup and
+//! hourly. `dtop report` reads them back after the fact.
+
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, params};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use crate::cli::config::HistoryDbConfig;
+use crate::core::types::{Container, ContainerKey, TimelineEntry, TimelineKind};
+
+/// Days samples and events are kept when the config doesn't say
+pub const DEFAULT_RETENTION_DAYS: u64 = 7;
+
+/// Time between two stored samples of a container
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Time between deletions of the rows past the retention
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How long a write waits for another dtop writing the same database
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS samples (
+        time INTEGER NOT NULL,
+        host TEXT NOT NULL,
+        container_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        cpu REAL NOT NULL,
+        memory REAL NOT NULL,
+        memory_used_bytes INTEGER NOT NULL,
+        network_tx_bytes_per_sec REAL NOT NULL,
+        network_rx_bytes_per_sec REAL NOT NULL,
+        block_read_bytes_per_sec REAL NOT NULL,
+        block_write_bytes_per_sec REAL NOT NULL,
+        pids INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS samples_time ON samples (time);
+    CREATE TABLE IF NOT EXISTS events (
+        time INTEGER NOT NULL,
+        host TEXT NOT NULL,
+        container_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        event TEXT NOT NULL,
+        detail TEXT
+    );
+    CREATE INDEX IF NOT EXISTS events_time ON events (time);
+";
+
+/// The database path as configured, or the default one in the user data directory
+pub fn db_path(config: &HistoryDbConfig) -> Option<PathBuf> {
+    match config.path.as_deref() {
+        // Expand ~ like a shell would
+        Some(path) => match path.strip_prefix("~/") {
+            Some(rest) => Some(dirs::home_dir()?.join(rest)),
+            None => Some(PathBuf::from(path)),
+        },
+        None => Some(dirs::data_dir()?.join("dtop").join("history.db")),
+    }
+}
+
+/// Opens (creating when missing) the database with its tables
+fn open(path: &Path) -> rusqlite::Result<Connection> {
+    let connection = Connection::open(path)?;
+    connection.busy_timeout(BUSY_TIMEOUT)?;
+    connection.execute_batch(SCHEMA)?;
+    Ok(connection)
+}
+
+/// A stats sample as stored
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub time: DateTime<Utc>,
+    pub key: ContainerKey,
+    pub name: String,
+    pub cpu: f64,
+    pub memory: f64,
+    pub memory_used_bytes: u64,
+    pub network_tx_bytes_per_sec: f64,
+    pub network_rx_bytes_per_sec: f64,
+    pub block_read_bytes_per_sec: f64,
+    pub block_write_bytes_per_sec: f64,
+    pub pids: u64,
+}
+
+/// A container event as stored: "started", "exited", "health"... with its exit code,
+/// health status, command line or previous name
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub time: DateTime<Utc>,
+    pub key: ContainerKey,
+    pub name: String,
+    pub event: String,
+    pub detail: Option<String>,
+}
+
+impl Event {
+    fn from_timeline(entry: &TimelineEntry) -> Self {
+        let (event, detail) = match &entry.kind {
+            TimelineKind::Started => ("started", None),
+            TimelineKind::Exited(code) => ("exited", code.map(|code| code.to_string())),
+            TimelineKind::Removed => ("removed", None),
+            TimelineKind::Paused => ("paused", None),
+            TimelineKind::Unpaused => ("unpaused", None),
+            TimelineKind::Renamed(previous) => ("renamed", Some(previous.clone())),
+            TimelineKind::HealthChanged(health) => {
+                ("health", Some(format!("{:?}", health).to_lowercase()))
+            }
+            TimelineKind::Exec(command) => ("exec", Some(command.clone())),
+            TimelineKind::LogSnapshot(Ok(path)) => {
+                ("log_snapshot", Some(path.display().to_string()))
+            }
+            TimelineKind::LogSnapshot(Err(error)) => ("log_snapshot", Some(error.clone())),
+        };
+        Self {
+            time: entry.time,
+            key: entry.key.clone(),
+            name: entry.name.clone(),
+            event: event.to_string(),
+            detail,
+        }
+    }
+}
+
+enum Record {
+    Sample(Sample),
+    Event(Event),
+}
+
+/// Writes samples and events to the database from a thread of its own
+pub struct HistoryRecorder {
+    tx: mpsc::Sender<Record>,
+    /// When each container's last sample was stored
+    last_sample: HashMap<ContainerKey, Instant>,
+}
+
+impl HistoryRecorder {
+    /// Opens the database as configured and starts the writer thread
+    pub fn from_config(config: &HistoryDbConfig) -> Result<Self, String> {
+        let path = db_path(config).ok_or(
+            "could not determine a directory for the history database, set history_db.path",
+        )?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        let connection = open(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let retention_days = config.retention_days.unwrap_or(DEFAULT_RETENTION_DAYS);
+
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || write_records(connection, rx, retention_days));
+
+        Ok(Self {
+            tx,
+            last_sample: HashMap::new(),
+        })
+    }
+
+    /// Stores the container's stats, at most once per sample interval
+    pub fn record_stats(&mut self, container: &Container) {
+        let key = container.key();
+        let now = Instant::now();
+        if self
+            .last_sample
+            .get(&key)
+            .is_some_and(|last| now.duration_since(*last) < SAMPLE_INTERVAL)
+        {
+            return;
+        }
+        self.last_sample.insert(key.clone(), now);
+
+        let stats = &container.stats;
+        let _ = self.tx.send(Record::Sample(Sample {
+            time: Utc::now(),
+            key,
+            name: container.name.clone(),
+            cpu: stats.cpu,
+            memory: stats.memory,
+            memory_used_bytes: stats.memory_used_bytes,
+            network_tx_bytes_per_sec: stats.network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec: stats.network_rx_bytes_per_sec,
+            block_read_bytes_per_sec: stats.block_read_bytes_per_sec,
+            block_write_bytes_per_sec: stats.block_write_bytes_per_sec,
+            pids: stats.pids,
+        }));
+    }
+
+    /// Stores an events timeline entry
+    pub fn record_event(&mut self, entry: &TimelineEntry) {
+        if matches!(entry.kind, TimelineKind::Removed) {
+            self.last_sample.remove(&entry.key);
+        }
+        let _ = self.tx.send(Record::Event(Event::from_timeline(entry)));
+    }
+}
+
+/// The writer thread: stores records as they come, in one transaction per batch,
+/// until the recorder is dropped
+fn write_records(mut connection: Connection, rx: mpsc::Receiver<Record>, retention_days: u64) {
+    let mut last_prune: Option<Instant> = None;
+    while let Ok(record) = rx.recv() {
+        if last_prune.is_none_or(|last| last.elapsed() >= PRUNE_INTERVAL) {
+            if let Err(e) = prune(&connection, retention_days) {
+                tracing::warn!("Failed to delete old history: {}", e);
+            }
+            last_prune = Some(Instant::now());
+        }
+
+        // Whatever else has queued up goes in the same transaction
+        let records: Vec<Record> = std::iter::once(record).chain(rx.try_iter()).collect();
+        if let Err(e) = insert(&mut connection, &records) {
+            tracing::warn!("Failed to store history: {}", e);
+        }
+    }
+}
+
+fn prune(connection: &Connection, retention_days: u64) -> rusqlite::Result<()> {
+    let cutoff = Utc::now().timestamp() - (retention_days * 24 * 60 * 60) as i64;
+    connection.execute("DELETE FROM samples WHERE time < ?1", [cutoff])?;
+    connection.execute("DELETE FROM events WHERE time < ?1", [cutoff])?;
+    Ok(())
+}
+
+fn insert(connection: &mut Connection, records: &[Record]) -> rusqlite::Result<()> {
+    let transaction = connection.transaction()?;
+    for record in records {
+        match record {
+            Record::Sample(sample) => {
+                transaction.execute(
+                    "INSERT INTO samples VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                    params![
+                        sample.time.timestamp(),
+                        sample.key.host_id,
+                        sample.key.container_id,
+                        sample.name,
+                        sample.cpu,
+                        sample.memory,
+                        sample.memory_used_bytes as i64,
+                        sample.network_tx_bytes_per_sec,
+                        sample.network_rx_bytes_per_sec,
+                        sample.block_read_bytes_per_sec,
+                        sample.block_write_bytes_per_sec,
+                        sample.pids as i64,
+                    ],
+                )?;
+            }
+            Record::Event(event) => {
+                transaction.execute(
+                    "INSERT INTO events VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        event.time.timestamp(),
+                        event.key.host_id,
+                        event.key.container_id,
+                        event.name,
+                        event.event,
+                        event.detail,
+                    ],
+                )?;
+            }
+        }
+    }
+    transaction.commit()
+}
+
+/// Reads the stored history back
+pub struct HistoryReader {
+    connection: Connection,
+}
+
+impl HistoryReader {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Err(format!(
+                "{} doesn't exist yet; it is written while dtop runs with history_db in the config",
+                path.display()
+            ));
+        }
+        let connection = open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Self { connection })
+    }
+
+    /// The samples since the time, of the containers whose name contains the filter,
+    /// oldest first
+    pub fn samples(
+        &self,
+        since: DateTime<Utc>,
+        name_filter: Option<&str>,
+    ) -> rusqlite::Result<Vec<Sample>> {
+        let mut statement = self.connection.prepare(
+            "SELECT time, host, container_id, name, cpu, memory, memory_used_bytes,
+                    network_tx_bytes_per_sec, network_rx_bytes_per_sec,
+                    block_read_bytes_per_sec, block_write_bytes_per_sec, pids
+             FROM samples WHERE time >= ?1 AND instr(name, ?2) > 0 ORDER BY time",
+        )?;
+        statement
+            .query_map(
+                params![since.timestamp(), name_filter.unwrap_or_default()],
+                |row| {
+                    Ok(Sample {
+                        time: timestamp(row.get(0)?),
+                        key: ContainerKey::new(row.get(1)?, row.get(2)?),
+                        name: row.get(3)?,
+                        cpu: row.get(4)?,
+                        memory: row.get(5)?,
+                        memory_used_bytes: row.get::<_, i64>(6)?.max(0) as u64,
+                        network_tx_bytes_per_sec: row.get(7)?,
+                        network_rx_bytes_per_sec: row.get(8)?,
+                        block_read_bytes_per_sec: row.get(9)?,
+                        block_write_bytes_per_sec: row.get(10)?,
+                        pids: row.get::<_, i64>(11)?.max(0) as u64,
+                    })
+                },
+            )?
+            .collect()
+    }
+
+    /// The events since the time, of the containers whose name contains the filter,
+    /// oldest first
+    pub fn events(
+        &self,
+        since: DateTime<Utc>,
+        name_filter: Option<&str>,
+    ) -> rusqlite::Result<Vec<Event>> {
+        let mut statement = self.connection.prepare(
+            "SELECT time, host, container_id, name, event, detail
+             FROM events WHERE time >= ?1 AND instr(name, ?2) > 0 ORDER BY time",
+        )?;
+        statement
+            .query_map(
+                params![since.timestamp(), name_filter.unwrap_or_default()],
+                |row| {
+                    Ok(Event {
+                        time: timestamp(row.get(0)?),
+                        key: ContainerKey::new(row.get(1)?, row.get(2)?),
+                        name: row.get(3)?,
+                        event: row.get(4)?,
+                        detail: row.get(5)?,
+                    })
+                },
+            )?
+            .collect()
+    }
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::HealthStatus;
+    use chrono::TimeDelta;
+
+    fn sample(key: &ContainerKey, name: &str, time: DateTime<Utc>, cpu: f64) -> Record {
+        Record::Sample(Sample {
+            time,
+            key: key.clone(),
+            name: name.to_string(),
+            cpu,
+            memory: 10.0,
+            memory_used_bytes: 1024,
+            network_tx_bytes_per_sec: 0.0,
+            network_rx_bytes_per_sec: 0.0,
+            block_read_bytes_per_sec: 0.0,
+            block_write_bytes_per_sec: 0.0,
+            pids: 3,
+        })
+    }
+
+    #[test]
+    fn test_store_and_read() {
+        let path = std::env::temp_dir().join(format!("dtop-history-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut connection = open(&path).unwrap();
+
+        let now = Utc::now();
+        let web = ContainerKey::new("local".to_string(), "abc".to_string());
+        let db = ContainerKey::new("local".to_string(), "def".to_string());
+        let health = TimelineEntry {
+            time: now,
+            key: web.clone(),
+            name: "web".to_string(),
+            kind: TimelineKind::HealthChanged(HealthStatus::Unhealthy),
+        };
+        insert(
+            &mut connection,
+            &[
+                sample(&web, "web", now - TimeDelta::days(30), 99.0),
+                sample(&web, "web", now - TimeDelta::minutes(1), 20.0),
+                sample(&db, "db", now, 5.0),
+                Record::Event(Event::from_timeline(&health)),
+            ],
+        )
+        .unwrap();
+
+        // Past the retention
+        prune(&connection, 7).unwrap();
+
+        let reader = HistoryReader::open(&path).unwrap();
+        let since = now - TimeDelta::days(1);
+        let samples = reader.samples(since, None).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].name, "web");
+        assert_eq!(samples[0].cpu, 20.0);
+        assert_eq!(samples[0].memory_used_bytes, 1024);
+
+        let samples = reader.samples(since, Some("db")).unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].key, db);
+
+        let events = reader.events(since, Some("web")).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event, "health");
+        assert_eq!(events[0].detail.as_deref(), Some("unhealthy"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
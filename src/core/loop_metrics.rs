@@ -0,0 +1,134 @@
+//! Measurements of the event loop: events handled, channel backlog, render time and how
+//! long the loop was busy handling a batch of events
+//!
+//! Counts are kept per one-second window; the last complete window is what is shown by
+//! the indicator (F12) and logged at debug level, so a slow loop on a large fleet can be
+//! told apart from a slow daemon.
+
+use std::time::{Duration, Instant};
+
+/// Length of a measurement window
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// What the event loop did during the last complete window
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LoopStats {
+    /// Events handled per second
+    pub events_per_sec: f64,
+    /// Frames drawn per second
+    pub fps: f64,
+    /// Average time to draw a frame
+    pub render_time: Duration,
+    /// Longest time spent handling one batch of events, during which nothing is drawn
+    pub lag: Duration,
+    /// Most events waiting in the channel when a batch started
+    pub channel_depth: usize,
+}
+
+/// Counts of the current window, rolled into `LoopStats` once it is over
+#[derive(Debug)]
+pub struct LoopMetrics {
+    window_start: Instant,
+    events: u32,
+    frames: u32,
+    render_time: Duration,
+    lag: Duration,
+    channel_depth: usize,
+    /// The last complete window
+    pub stats: LoopStats,
+}
+
+impl Default for LoopMetrics {
+    fn default() -> Self {
+        Self {
+            window_start: Instant::now(),
+            events: 0,
+            frames: 0,
+            render_time: Duration::ZERO,
+            lag: Duration::ZERO,
+            channel_depth: 0,
+            stats: LoopStats::default(),
+        }
+    }
+}
+
+impl LoopMetrics {
+    /// Records a batch of `events` handled in `elapsed`, `depth` of them waiting when it
+    /// started
+    pub fn record_batch(&mut self, events: u32, depth: usize, elapsed: Duration) {
+        self.roll(Instant::now());
+        self.events += events;
+        self.lag = self.lag.max(elapsed);
+        self.channel_depth = self.channel_depth.max(depth);
+    }
+
+    /// Records a frame drawn in `elapsed`
+    pub fn record_frame(&mut self, elapsed: Duration) {
+        self.roll(Instant::now());
+        self.frames += 1;
+        self.render_time += elapsed;
+    }
+
+    /// Turns the counts into stats once the window is over, starting the next one
+    fn roll(&mut self, now: Instant) {
+        let length = now.duration_since(self.window_start);
+        if length < WINDOW {
+            return;
+        }
+
+        let secs = length.as_secs_f64();
+        self.stats = LoopStats {
+            events_per_sec: self.events as f64 / secs,
+            fps: self.frames as f64 / secs,
+            render_time: self
+                .render_time
+                .checked_div(self.frames)
+                .unwrap_or_default(),
+            lag: self.lag,
+            channel_depth: self.channel_depth,
+        };
+        tracing::debug!("Event loop: {:?}", self.stats);
+
+        *self = Self {
+            window_start: now,
+            stats: self.stats,
+            ..Self::default()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roll_window() {
+        let mut metrics = LoopMetrics::default();
+        let start = metrics.window_start;
+
+        metrics.events = 30;
+        metrics.frames = 4;
+        metrics.render_time = Duration::from_millis(20);
+        metrics.lag = Duration::from_millis(7);
+        metrics.channel_depth = 12;
+
+        // Nothing shown before the window is over
+        metrics.roll(start + Duration::from_millis(500));
+        assert_eq!(metrics.stats, LoopStats::default());
+
+        metrics.roll(start + Duration::from_secs(2));
+        assert_eq!(
+            metrics.stats,
+            LoopStats {
+                events_per_sec: 15.0,
+                fps: 2.0,
+                render_time: Duration::from_millis(5),
+                lag: Duration::from_millis(7),
+                channel_depth: 12,
+            }
+        );
+        // The next window starts from nothing
+        assert_eq!(metrics.events, 0);
+        assert_eq!(metrics.lag, Duration::ZERO);
+    }
+}
@@ -1,2 +1,12 @@
+pub mod alerts;
+pub mod anomalies;
 pub mod app_state;
+pub mod budgets;
+pub mod bulk;
+pub mod clipboard;
+pub mod forecast;
+pub mod history_db;
+pub mod loop_metrics;
+pub mod scripting;
+pub mod search_query;
 pub mod types;
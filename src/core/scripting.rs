@@ -0,0 +1,290 @@
+//! Rhai scripting hooks for derived columns, custom sort keys, and row styles
+//!
+//! Scripts are configured in the `scripts` section of the config file and are
+//! evaluated against each container with the following variables in scope:
+//! `id`, `name`, `host`, `state`, `health`, `cpu`, `memory`, `memory_used`,
+//! `memory_limit`, `net_tx`, `net_rx`, `blk_read`, `blk_write`, `pids`, and `uptime_secs`.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use ratatui::style::{Color, Style};
+use rhai::{AST, Dynamic, Engine, Scope};
+
+use crate::cli::config::ScriptsConfig;
+use crate::core::types::Container;
+
+/// Upper bound on operations per script evaluation, so a runaway loop can't freeze the UI
+const MAX_SCRIPT_OPERATIONS: u64 = 10_000;
+
+/// Default width for derived columns when the config doesn't specify one
+const DEFAULT_COLUMN_WIDTH: u16 = 10;
+
+/// A compiled derived column
+pub struct ScriptColumn {
+    /// Header text shown in the container table
+    pub header: String,
+    /// Column width in characters
+    pub width: u16,
+    ast: AST,
+}
+
+/// Compiled scripting hooks ready for evaluation
+pub struct ScriptHooks {
+    engine: Engine,
+    /// Derived columns rendered after the built-in stats columns
+    pub columns: Vec<ScriptColumn>,
+    sort_key: Option<AST>,
+    row_style: Option<AST>,
+}
+
+impl ScriptHooks {
+    /// Compiles all scripts from the config
+    /// Returns an error naming the offending script if any of them fail to compile
+    pub fn compile(config: &ScriptsConfig) -> Result<Self, String> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_SCRIPT_OPERATIONS);
+
+        let columns = config
+            .columns
+            .iter()
+            .map(|column| {
+                let ast = engine.compile(&column.script).map_err(|e| {
+                    format!(
+                        "Failed to compile script for column '{}': {}",
+                        column.header, e
+                    )
+                })?;
+                Ok(ScriptColumn {
+                    header: column.header.clone(),
+                    width: column.width.unwrap_or(DEFAULT_COLUMN_WIDTH),
+                    ast,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let sort_key = config
+            .sort
+            .as_ref()
+            .map(|script| engine.compile(script))
+            .transpose()
+            .map_err(|e| format!("Failed to compile sort script: {}", e))?;
+
+        let row_style = config
+            .row_style
+            .as_ref()
+            .map(|script| engine.compile(script))
+            .transpose()
+            .map_err(|e| format!("Failed to compile row_style script: {}", e))?;
+
+        Ok(Self {
+            engine,
+            columns,
+            sort_key,
+            row_style,
+        })
+    }
+
+    /// Returns true if a custom sort key script is configured
+    pub fn has_sort_key(&self) -> bool {
+        self.sort_key.is_some()
+    }
+
+    /// Evaluates a derived column for a container, returning "ERR" if the script fails
+    pub fn column_value(&self, column: &ScriptColumn, container: &Container) -> String {
+        match self.eval(&column.ast, container) {
+            Some(value) => format_dynamic(&value),
+            None => "ERR".to_string(),
+        }
+    }
+
+    /// Evaluates the custom sort key for a container
+    pub fn sort_key(&self, container: &Container) -> Option<Dynamic> {
+        self.eval(self.sort_key.as_ref()?, container)
+    }
+
+    /// Evaluates the row style script for a container
+    /// The script should return a color name (e.g. "red") or hex value (e.g. "#ff8800");
+    /// an empty string or unit means no custom style
+    pub fn row_style(&self, container: &Container) -> Option<Style> {
+        let value = self.eval(self.row_style.as_ref()?, container)?;
+        let color_name = value.into_string().ok()?;
+        if color_name.is_empty() {
+            return None;
+        }
+        Color::from_str(&color_name)
+            .ok()
+            .map(|color| Style::default().fg(color))
+    }
+
+    fn eval(&self, ast: &AST, container: &Container) -> Option<Dynamic> {
+        let mut scope = container_scope(container);
+        match self.engine.eval_ast_with_scope::<Dynamic>(&mut scope, ast) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::trace!("Script evaluation failed for {}: {}", container.name, e);
+                None
+            }
+        }
+    }
+}
+
+/// Builds the variable scope exposed to scripts for a single container
+fn container_scope(container: &Container) -> Scope<'static> {
+    let uptime_secs = container
+        .created
+        .map(|created| (chrono::Utc::now() - created).num_seconds())
+        .unwrap_or(0);
+
+    let mut scope = Scope::new();
+    scope.push_constant("id", container.id.clone());
+    scope.push_constant("name", container.name.clone());
+    scope.push_constant("host", container.host_id.clone());
+    scope.push_constant("state", format!("{:?}", container.state).to_lowercase());
+    scope.push_constant(
+        "health",
+        container
+            .health
+            .as_ref()
+            .map(|h| format!("{:?}", h).to_lowercase())
+            .unwrap_or_default(),
+    );
+    scope.push_constant("cpu", container.stats.cpu);
+    scope.push_constant("memory", container.stats.memory);
+    scope.push_constant("memory_used", container.stats.memory_used_bytes as i64);
+    scope.push_constant("memory_limit", container.stats.memory_limit_bytes as i64);
+    scope.push_constant("net_tx", container.stats.network_tx_bytes_per_sec);
+    scope.push_constant("net_rx", container.stats.network_rx_bytes_per_sec);
+    scope.push_constant("blk_read", container.stats.block_read_bytes_per_sec);
+    scope.push_constant("blk_write", container.stats.block_write_bytes_per_sec);
+    scope.push_constant("pids", container.stats.pids as i64);
+    scope.push_constant("uptime_secs", uptime_secs);
+    scope
+}
+
+/// Formats a script result for display, rounding floats to one decimal place
+fn format_dynamic(value: &Dynamic) -> String {
+    if value.is_unit() {
+        String::new()
+    } else if let Ok(f) = value.as_float() {
+        format!("{:.1}", f)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Compares two sort keys: numerically when both are numbers, otherwise as strings
+pub fn compare_sort_keys(a: &Option<Dynamic>, b: &Option<Dynamic>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => match (as_number(a), as_number(b)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            _ => a.to_string().cmp(&b.to_string()),
+        },
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn as_number(value: &Dynamic) -> Option<f64> {
+    value
+        .as_float()
+        .ok()
+        .or_else(|| value.as_int().ok().map(|i| i as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::config::ScriptColumnConfig;
+    use crate::core::types::ContainerStats;
+
+    fn test_container(cpu: f64, memory: f64) -> Container {
+        Container {
+            stats: ContainerStats {
+                cpu,
+                memory,
+                ..Default::default()
+            },
+            ..Container::test_default()
+        }
+    }
+
+    fn hooks(config: ScriptsConfig) -> ScriptHooks {
+        ScriptHooks::compile(&config).expect("scripts should compile")
+    }
+
+    #[test]
+    fn test_derived_column() {
+        let hooks = hooks(ScriptsConfig {
+            columns: vec![ScriptColumnConfig {
+                header: "Load".to_string(),
+                script: "cpu + memory".to_string(),
+                width: None,
+            }],
+            ..Default::default()
+        });
+
+        let column = &hooks.columns[0];
+        assert_eq!(column.width, DEFAULT_COLUMN_WIDTH);
+        assert_eq!(
+            hooks.column_value(column, &test_container(10.0, 5.5)),
+            "15.5"
+        );
+    }
+
+    #[test]
+    fn test_runtime_error_shows_err() {
+        let hooks = hooks(ScriptsConfig {
+            columns: vec![ScriptColumnConfig {
+                header: "Bad".to_string(),
+                script: "undefined_variable * 2".to_string(),
+                width: Some(5),
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            hooks.column_value(&hooks.columns[0], &test_container(0.0, 0.0)),
+            "ERR"
+        );
+    }
+
+    #[test]
+    fn test_compile_error_is_reported() {
+        let result = ScriptHooks::compile(&ScriptsConfig {
+            sort: Some("cpu +".to_string()),
+            ..Default::default()
+        });
+        assert!(result.is_err());
+        assert!(result.err().unwrap().contains("sort script"));
+    }
+
+    #[test]
+    fn test_row_style() {
+        let hooks = hooks(ScriptsConfig {
+            row_style: Some(r#"if cpu > 90.0 { "red" } else { "" }"#.to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            hooks.row_style(&test_container(95.0, 0.0)),
+            Some(Style::default().fg(Color::Red))
+        );
+        assert_eq!(hooks.row_style(&test_container(10.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_compare_sort_keys() {
+        let hooks = hooks(ScriptsConfig {
+            sort: Some("cpu * 2.0".to_string()),
+            ..Default::default()
+        });
+
+        let low = hooks.sort_key(&test_container(10.0, 0.0));
+        let high = hooks.sort_key(&test_container(20.0, 0.0));
+        assert_eq!(compare_sort_keys(&low, &high), Ordering::Less);
+        assert_eq!(compare_sort_keys(&high, &low), Ordering::Greater);
+        assert_eq!(compare_sort_keys(&None, &low), Ordering::Less);
+    }
+}
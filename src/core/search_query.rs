@@ -0,0 +1,262 @@
+//! Search query parsing and matching shared by the container filter and log search
+//!
+//! A query is plain text with optional trailing modifiers:
+//! - `\C` makes the match case-sensitive (default is case-insensitive)
+//! - `\W` only matches whole words
+//!
+//! Modifiers can be combined in any order, e.g. `nginx\W\C`.
+//!
+//! The container filter also searches labels: `team=payments` (or `label:team=payments`)
+//! matches containers whose `team` label contains `payments`, and `label:team` those with
+//! a label key containing `team`.
+
+use std::ops::Range;
+
+/// A parsed search query with its matching options
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// The text to search for (modifiers stripped)
+    pub pattern: String,
+    /// Match case exactly
+    pub case_sensitive: bool,
+    /// Only match when surrounded by non-word characters
+    pub whole_word: bool,
+}
+
+impl SearchQuery {
+    /// Parses raw search input, stripping trailing `\C` and `\W` modifiers
+    pub fn parse(input: &str) -> Self {
+        let mut pattern = input;
+        let mut case_sensitive = false;
+        let mut whole_word = false;
+
+        loop {
+            if let Some(rest) = pattern.strip_suffix("\\C") {
+                case_sensitive = true;
+                pattern = rest;
+            } else if let Some(rest) = pattern.strip_suffix("\\W") {
+                whole_word = true;
+                pattern = rest;
+            } else {
+                break;
+            }
+        }
+
+        Self {
+            pattern: pattern.to_string(),
+            case_sensitive,
+            whole_word,
+        }
+    }
+
+    /// Returns true if there is nothing to search for
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Returns true if the whole haystack matches, ignoring the whole-word option
+    fn equals(&self, haystack: &str) -> bool {
+        self.match_at(haystack, 0) == Some(haystack.len())
+    }
+
+    /// Returns true if the haystack contains at least one match
+    pub fn matches(&self, haystack: &str) -> bool {
+        !self.is_empty() && self.find_iter(haystack).next().is_some()
+    }
+
+    /// Byte ranges of the non-overlapping matches in `haystack`
+    pub fn find_iter<'a>(&'a self, haystack: &'a str) -> impl Iterator<Item = Range<usize>> + 'a {
+        let mut search_from = 0;
+        std::iter::from_fn(move || {
+            while search_from < haystack.len() {
+                let start = search_from;
+                // Advance to the next char boundary for the following attempt
+                search_from += haystack[start..].chars().next().map_or(1, char::len_utf8);
+
+                let Some(end) = self.match_at(haystack, start) else {
+                    continue;
+                };
+                if self.whole_word && !is_word_boundary(haystack, start, end) {
+                    continue;
+                }
+                search_from = end;
+                return Some(start..end);
+            }
+            None
+        })
+    }
+
+    /// Tries to match the pattern starting at byte offset `start`, returning the end offset
+    fn match_at(&self, haystack: &str, start: usize) -> Option<usize> {
+        let mut hay_chars = haystack[start..].char_indices();
+        for pattern_char in self.pattern.chars() {
+            let (_, hay_char) = hay_chars.next()?;
+            let equal = if self.case_sensitive {
+                hay_char == pattern_char
+            } else {
+                hay_char.to_lowercase().eq(pattern_char.to_lowercase())
+            };
+            if !equal {
+                return None;
+            }
+        }
+        Some(
+            hay_chars
+                .next()
+                .map_or(haystack.len(), |(offset, _)| start + offset),
+        )
+    }
+}
+
+/// A search of container labels, the key matched exactly and the value as a search
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelQuery {
+    /// Label key (empty matches any key)
+    key: SearchQuery,
+    /// Value to look for, None when only searching keys (`label:team`)
+    value: Option<SearchQuery>,
+}
+
+impl LabelQuery {
+    /// The label search in a query (`key=value`, `label:key=value` or `label:key`),
+    /// None when the query searches names
+    pub fn parse(query: &SearchQuery) -> Option<Self> {
+        let (pattern, prefixed) = match query.pattern.strip_prefix("label:") {
+            Some(rest) => (rest, true),
+            None => (query.pattern.as_str(), false),
+        };
+        let (key, value) = match pattern.split_once('=') {
+            Some((key, value)) => (key, Some(value)),
+            None if prefixed => (pattern, None),
+            None => return None,
+        };
+
+        // The parts keep the query's modifiers
+        let part = |pattern: &str| SearchQuery {
+            pattern: pattern.to_string(),
+            ..query.clone()
+        };
+        Some(Self {
+            key: part(key),
+            value: value.map(part),
+        })
+    }
+
+    /// Returns true if the label matches
+    pub fn matches(&self, key: &str, value: &str) -> bool {
+        match &self.value {
+            Some(query) => {
+                (self.key.is_empty() || self.key.equals(key))
+                    && (query.is_empty() || query.matches(value))
+            }
+            None => self.key.is_empty() || self.key.matches(key),
+        }
+    }
+}
+
+/// Returns true if the range is not adjacent to other word characters
+fn is_word_boundary(haystack: &str, start: usize, end: usize) -> bool {
+    let before = haystack[..start].chars().next_back();
+    let after = haystack[end..].chars().next();
+    !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain() {
+        let query = SearchQuery::parse("nginx");
+        assert_eq!(query.pattern, "nginx");
+        assert!(!query.case_sensitive);
+        assert!(!query.whole_word);
+    }
+
+    #[test]
+    fn test_parse_modifiers_any_order() {
+        let query = SearchQuery::parse("Web\\C\\W");
+        assert_eq!(query.pattern, "Web");
+        assert!(query.case_sensitive);
+        assert!(query.whole_word);
+
+        let query = SearchQuery::parse("Web\\W\\C");
+        assert_eq!(query.pattern, "Web");
+        assert!(query.case_sensitive && query.whole_word);
+    }
+
+    #[test]
+    fn test_case_insensitive_default() {
+        let query = SearchQuery::parse("NGINX");
+        assert!(query.matches("my-nginx-1"));
+    }
+
+    #[test]
+    fn test_case_sensitive() {
+        let query = SearchQuery::parse("NGINX\\C");
+        assert!(!query.matches("my-nginx-1"));
+        assert!(query.matches("my-NGINX-1"));
+    }
+
+    #[test]
+    fn test_whole_word() {
+        let query = SearchQuery::parse("api\\W");
+        assert!(query.matches("my-api-1"));
+        assert!(query.matches("api"));
+        assert!(!query.matches("rapid"));
+        assert!(!query.matches("api_gateway"));
+    }
+
+    #[test]
+    fn test_match_ranges() {
+        let query = SearchQuery::parse("ab");
+        let ranges: Vec<_> = query.find_iter("ab-AB-cab").collect();
+        assert_eq!(ranges, vec![0..2, 3..5, 7..9]);
+
+        let query = SearchQuery::parse("ab\\W");
+        let ranges: Vec<_> = query.find_iter("ab-AB-cab").collect();
+        assert_eq!(ranges, vec![0..2, 3..5]);
+    }
+
+    #[test]
+    fn test_unicode() {
+        let query = SearchQuery::parse("日本");
+        let ranges: Vec<_> = query.find_iter("コンテナ日本語").collect();
+        assert_eq!(ranges, vec![12..18]);
+    }
+
+    #[test]
+    fn test_label_query() {
+        let label = |input: &str| LabelQuery::parse(&SearchQuery::parse(input));
+        assert_eq!(label("web"), None);
+
+        let query = label("team=pay").unwrap();
+        assert!(query.matches("team", "payments"));
+        assert!(query.matches("Team", "PAYMENTS"));
+        assert!(!query.matches("myteam", "payments"));
+        assert!(!query.matches("team", "billing"));
+
+        let query = label("label:role=db\\W\\C").unwrap();
+        assert!(query.matches("role", "db"));
+        assert!(!query.matches("role", "DB"));
+        assert!(!query.matches("role", "dbadmin"));
+
+        // Keys only, or any key
+        let query = label("label:compose").unwrap();
+        assert!(query.matches("com.docker.compose.project", "shop"));
+        assert!(!query.matches("team", "compose"));
+        assert!(label("=payments").unwrap().matches("team", "payments"));
+        assert!(label("team=").unwrap().matches("team", ""));
+    }
+
+    #[test]
+    fn test_empty_query_matches_nothing() {
+        let query = SearchQuery::parse("\\C");
+        assert!(query.is_empty());
+        assert!(!query.matches("anything"));
+    }
+}
@@ -1,23 +1,115 @@
-use chrono::{DateTime, Utc};
-use std::collections::VecDeque;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
 
-use crate::docker::logs::LogEntry;
+use crate::core::alerts::AlertCondition;
+use crate::docker::logs::{LogEntry, LogLevel};
 
 /// Maximum number of samples to keep in history buffers for sparkline display
 pub const HISTORY_BUFFER_SIZE: usize = 20;
 
-/// Duration in seconds for each history bucket (sparkline position)
-/// This controls how often history samples are recorded.
-/// Tick markers appear every TICK_INTERVAL buckets.
-pub const BUCKET_DURATION_SECS: u64 = 2;
+/// Default time between two frames in milliseconds (`--refresh`, `refresh_ms`)
+pub const DEFAULT_REFRESH_MS: u64 = 500;
+
+/// Accepted refresh intervals in milliseconds
+pub const REFRESH_MS_RANGE: RangeInclusive<u64> = 100..=10_000;
+
+/// Each history bucket (sparkline position) spans this many refresh intervals,
+/// 2 seconds by default
+pub const REFRESH_INTERVALS_PER_BUCKET: u32 = 4;
+
+static BUCKET_DURATION: OnceLock<Duration> = OnceLock::new();
+
+/// Sets the history bucket duration from the refresh interval for the lifetime of the process
+/// Only the first call has an effect
+pub fn init_bucket_duration(refresh: Duration) {
+    let _ = BUCKET_DURATION.set(refresh * REFRESH_INTERVALS_PER_BUCKET);
+}
+
+/// Duration of each history bucket (sparkline position): how often history samples are
+/// recorded. Tick markers appear every TICK_INTERVAL buckets.
+pub fn bucket_duration() -> Duration {
+    BUCKET_DURATION
+        .get()
+        .copied()
+        .unwrap_or(Duration::from_millis(DEFAULT_REFRESH_MS) * REFRESH_INTERVALS_PER_BUCKET)
+}
+
+/// The current history bucket ID (wall clock / bucket duration), shared by the stats
+/// history and the sparkline tick markers so they stay aligned
+pub fn current_history_bucket() -> u64 {
+    let bucket_millis = bucket_duration().as_millis().max(1);
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| (d.as_millis() / bucket_millis) as u64)
+        .unwrap_or(0)
+}
+
+/// Number of samples kept in a container's memory trend (40 samples = 20 minutes)
+pub const MEMORY_TREND_SIZE: usize = 40;
+
+/// Duration in seconds of each memory trend sample, for the memory exhaustion forecast
+pub const MEMORY_TREND_BUCKET_SECS: u64 = 30;
+
+/// Number of samples kept in a host's container count history
+pub const COUNT_HISTORY_SIZE: usize = 20;
+
+/// Duration in seconds of each container count sample (20 samples = 20 minutes)
+pub const COUNT_BUCKET_SECS: u64 = 60;
+
+/// Maximum number of entries kept in the events timeline
+pub const TIMELINE_SIZE: usize = 500;
+
+/// Maximum number of entries kept in the notifications panel
+pub const NOTIFICATIONS_SIZE: usize = 200;
+
+/// Clock difference to a host's daemon from which it is reported, in seconds
+pub const CLOCK_SKEW_THRESHOLD_SECS: i64 = 5;
+
+/// Share of a host's data-root disk in use from which it is reported, in percent
+pub const DISK_WARNING_PERCENT: f64 = 90.0;
+
+/// 1-minute load per CPU of a host from which it is reported
+pub const LOAD_WARNING_PER_CPU: f64 = 2.0;
+
+/// Seconds a container gets to stop gracefully before it is killed, unless configured
+pub const DEFAULT_STOP_TIMEOUT_SECS: u32 = 10;
+
+/// Containers or objects acted on at once by a bulk action, unless configured
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+
+/// Times a container action is tried again after a transient failure, unless configured
+/// (none, retrying is opt-in)
+pub const DEFAULT_ACTION_RETRIES: u32 = 0;
+
+/// Seconds a started or restarted container is watched before reporting how it fared,
+/// unless configured
+pub const DEFAULT_VERIFY_SECS: u64 = 10;
+
+/// Days since it exited after which a container is suggested for cleanup, unless configured
+pub const DEFAULT_CLEANUP_EXITED_DAYS: u64 = 7;
+
+/// Restarts within the restart loop window after which a container is flagged as restart
+/// looping, unless configured
+pub const DEFAULT_RESTART_LOOP_RESTARTS: u32 = 3;
+
+/// Minutes the restarts of a restart loop are counted over, unless configured
+pub const DEFAULT_RESTART_LOOP_MINUTES: u64 = 5;
+
+/// Default chrono format for absolute timestamps (e.g. "2025-10-29 10:15")
+pub const DEFAULT_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M";
 
 /// Host identifier for tracking which Docker host a container belongs to
 pub type HostId = String;
 
 /// Container state as reported by Docker
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ContainerState {
     Running,
     Paused,
@@ -30,7 +122,7 @@ pub enum ContainerState {
 }
 
 /// Container health status from Docker health checks
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HealthStatus {
     Healthy,
     Unhealthy,
@@ -81,20 +173,184 @@ impl FromStr for HealthStatus {
 }
 
 /// Container metadata (static information)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Container {
+    /// Truncated (12 character) ID, as shown by `docker ps`
     pub id: String,
+    /// Full 64 character ID
+    pub full_id: String,
     pub name: String,
+    /// Image the container was created from, as given at creation (tag or ID)
+    pub image: String,
     pub state: ContainerState,
     pub health: Option<HealthStatus>, // None if container has no health check configured
     pub created: Option<DateTime<Utc>>, // When the container was created
+    /// When the container was last started (approximate for containers listed at startup)
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the container last exited (approximate for containers listed at startup)
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Exit code from the last time the container stopped
+    pub exit_code: Option<i64>,
+    /// Restarts performed by Docker's restart policy (inspect `RestartCount`)
+    pub restart_count: i64,
+    /// Times the container was seen starting again during this session
+    pub session_restarts: u32,
+    /// Writable layer size in bytes (only fetched on demand, it is expensive)
+    pub size_rw: Option<i64>,
+    /// Compose project from the `com.docker.compose.project` label
+    pub compose_project: Option<String>,
+    /// Compose service from the `com.docker.compose.service` label
+    pub compose_service: Option<String>,
+    /// Compose services this one depends on, from the `com.docker.compose.depends_on` label
+    pub compose_depends_on: Vec<String>,
+    /// Platform of the image (fetched in the background once the container is known)
+    pub platform: Option<ImagePlatform>,
+    /// How long the container took from its last start to healthy (measured this session)
+    #[serde(skip)]
+    pub time_to_healthy: Option<TimeDelta>,
+    /// Ports published on the host, sorted by container port
+    pub ports: Vec<PortMapping>,
+    /// Labels set on the container, sorted by key
+    pub labels: BTreeMap<String, String>,
     pub stats: ContainerStats,
     pub host_id: HostId,
     pub dozzle_url: Option<String>,
 }
 
+/// Labels naming the systemd unit that manages a container, in order of preference:
+/// set by `podman generate systemd` and Quadlet, and by convention for Docker containers
+/// started from a unit (`docker run --label systemd.unit=%n`)
+pub const SYSTEMD_UNIT_LABELS: [&str; 2] = ["PODMAN_SYSTEMD_UNIT", "systemd.unit"];
+
+/// Label naming the swarm stack a task's container was deployed by (`docker stack deploy`)
+pub const STACK_NAMESPACE_LABEL: &str = "com.docker.stack.namespace";
+
+impl Container {
+    /// The key the container is stored under (by full ID)
+    pub fn key(&self) -> ContainerKey {
+        ContainerKey::new(self.host_id.clone(), self.full_id.clone())
+    }
+
+    /// The systemd unit managing the container, from its labels
+    pub fn systemd_unit(&self) -> Option<&str> {
+        SYSTEMD_UNIT_LABELS
+            .iter()
+            .find_map(|label| self.labels.get(*label))
+            .map(String::as_str)
+            .filter(|unit| !unit.is_empty())
+    }
+
+    /// The compose project or swarm stack the container belongs to, grouped by in the list
+    pub fn project(&self) -> Option<(ProjectKind, &str)> {
+        match &self.compose_project {
+            Some(name) => Some((ProjectKind::Compose, name)),
+            None => self
+                .labels
+                .get(STACK_NAMESPACE_LABEL)
+                .map(|name| (ProjectKind::Stack, name.as_str())),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Container {
+    /// A running "web" container on the local host with nothing else known, for tests
+    /// to override the fields they check
+    pub fn test_default() -> Self {
+        Container {
+            id: "abc123456789".to_string(),
+            full_id: format!("{:0<64}", "abc123456789"),
+            name: "web".to_string(),
+            image: "nginx".to_string(),
+            state: ContainerState::Running,
+            health: None,
+            created: None,
+            started_at: None,
+            finished_at: None,
+            exit_code: None,
+            restart_count: 0,
+            session_restarts: 0,
+            size_rw: None,
+            compose_project: None,
+            compose_service: None,
+            compose_depends_on: Vec::new(),
+            platform: None,
+            time_to_healthy: None,
+            ports: Vec::new(),
+            labels: BTreeMap::new(),
+            stats: ContainerStats::default(),
+            host_id: "local".to_string(),
+            dozzle_url: None,
+        }
+    }
+}
+
+/// OS and architecture an image was built for
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImagePlatform {
+    /// "os/arch[/variant]", e.g. "linux/arm64"
+    pub name: String,
+    /// Whether the architecture differs from the host's (the container runs emulated)
+    pub emulated: bool,
+}
+
+/// Container ports commonly serving HTTP, opened in the browser with 'e'
+const HTTP_PORTS: [u16; 10] = [80, 443, 3000, 5000, 8000, 8080, 8081, 8443, 8888, 9000];
+
+/// A container port published on the host
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortMapping {
+    /// Host address the port is bound to (None for all interfaces)
+    pub host_ip: Option<String>,
+    pub host_port: u16,
+    pub container_port: u16,
+    /// "tcp", "udp" or "sctp"
+    pub protocol: String,
+}
+
+impl PortMapping {
+    /// Whether the container port is one usually serving HTTP(S)
+    pub fn is_http(&self) -> bool {
+        self.protocol == "tcp" && HTTP_PORTS.contains(&self.container_port)
+    }
+
+    /// URL of the port on the given Docker host ("local" is this machine)
+    pub fn url(&self, host_id: &str) -> String {
+        let scheme = if matches!(self.container_port, 443 | 8443) {
+            "https"
+        } else {
+            "http"
+        };
+        // Ports bound to a single address are only reachable there, loopback excepted
+        let host = match self.host_ip.as_deref() {
+            Some(ip) if !ip.starts_with("127.") && ip != "::1" => ip,
+            _ if host_id == "local" => "localhost",
+            _ => host_id,
+        };
+        if host.contains(':') && !host.starts_with('[') {
+            format!("{}://[{}]:{}", scheme, host, self.host_port)
+        } else {
+            format!("{}://{}:{}", scheme, host, self.host_port)
+        }
+    }
+}
+
+impl std::fmt::Display for PortMapping {
+    /// `[ip:]host:container[/protocol]` as in `docker run -p`, TCP implied
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(ip) = &self.host_ip {
+            write!(f, "{}:", ip)?;
+        }
+        write!(f, "{}:{}", self.host_port, self.container_port)?;
+        if self.protocol != "tcp" {
+            write!(f, "/{}", self.protocol)?;
+        }
+        Ok(())
+    }
+}
+
 /// Container runtime statistics (updated frequently)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ContainerStats {
     pub cpu: f64,
     pub memory: f64,
@@ -106,13 +362,103 @@ pub struct ContainerStats {
     pub network_tx_bytes_per_sec: f64,
     /// Network receive rate in bytes per second
     pub network_rx_bytes_per_sec: f64,
+    /// Block device read rate in bytes per second
+    pub block_read_bytes_per_sec: f64,
+    /// Block device write rate in bytes per second
+    pub block_write_bytes_per_sec: f64,
+    /// Number of processes in the container
+    pub pids: u64,
+    /// Maximum number of processes, if the container has a pids limit
+    pub pids_limit: Option<u64>,
+    /// Host CPUs online when sampled; `cpu` is a percentage of one of them (0 when not
+    /// reported)
+    #[serde(default)]
+    pub online_cpus: u32,
     /// Historical CPU usage values for sparkline display
     pub cpu_history: VecDeque<f64>,
     /// Historical memory usage values for sparkline display
     pub memory_history: VecDeque<f64>,
-    /// The bucket ID (wall_clock_secs / BUCKET_DURATION_SECS) of the most recent history entry
+    /// The bucket ID (see `current_history_bucket`) of the most recent history entry
     /// Used to synchronize history updates with tick markers
     pub last_history_bucket: u64,
+    /// Number of samples ever added to the history buffers
+    pub history_samples: u64,
+    /// Sample numbers at which the container was started or recreated, still within the history
+    /// Drawn as deployment markers on the sparklines
+    pub deploy_markers: VecDeque<u64>,
+    /// Memory used in bytes per trend bucket (wall_clock_secs / MEMORY_TREND_BUCKET_SECS),
+    /// oldest first, for the memory exhaustion forecast
+    pub memory_trend: VecDeque<(u64, u64)>,
+}
+
+impl ContainerStats {
+    /// Whether these stats would show differently from `other` in the list: a value
+    /// changed at display precision, or a sample was added to the sparklines
+    pub fn shown_differently(&self, other: &ContainerStats) -> bool {
+        // Percentages show one decimal
+        let percent = |a: f64, b: f64| (a * 10.0).round() != (b * 10.0).round();
+        // Sizes and rates show three significant digits at most; below a byte they're 0
+        let amount = |a: f64, b: f64| {
+            let delta = (a - b).abs();
+            delta >= 1.0 && delta > a.abs().max(b.abs()) * 0.005
+        };
+
+        percent(self.cpu, other.cpu)
+            || percent(self.memory, other.memory)
+            || amount(
+                self.memory_used_bytes as f64,
+                other.memory_used_bytes as f64,
+            )
+            || self.memory_limit_bytes != other.memory_limit_bytes
+            || amount(
+                self.network_tx_bytes_per_sec,
+                other.network_tx_bytes_per_sec,
+            )
+            || amount(
+                self.network_rx_bytes_per_sec,
+                other.network_rx_bytes_per_sec,
+            )
+            || amount(
+                self.block_read_bytes_per_sec,
+                other.block_read_bytes_per_sec,
+            )
+            || amount(
+                self.block_write_bytes_per_sec,
+                other.block_write_bytes_per_sec,
+            )
+            || self.pids != other.pids
+            || self.pids_limit != other.pids_limit
+            || self.history_samples != other.history_samples
+    }
+
+    /// What `cpu` is divided by to get a share of all the host's CPUs: the CPUs online
+    /// when sampled, else the host's count from its info (1 when neither is known)
+    pub fn host_cpu_divisor(&self, host_cpus: u64) -> f64 {
+        match (self.online_cpus, host_cpus) {
+            (0, 0) => 1.0,
+            (0, cpus) => cpus as f64,
+            (cpus, _) => cpus as f64,
+        }
+    }
+
+    /// Marks the next history sample as the start of a deployment
+    pub fn mark_deployment(&mut self) {
+        if self.deploy_markers.back() != Some(&self.history_samples) {
+            self.deploy_markers.push_back(self.history_samples);
+        }
+    }
+
+    /// Positions in the history buffers of the deployment markers
+    pub fn deploy_positions(&self) -> Vec<usize> {
+        let first = self
+            .history_samples
+            .saturating_sub(self.cpu_history.len() as u64);
+        self.deploy_markers
+            .iter()
+            .filter(|&&sample| sample >= first && sample < self.history_samples)
+            .map(|&sample| (sample - first) as usize)
+            .collect()
+    }
 }
 
 impl Default for ContainerStats {
@@ -124,17 +470,26 @@ impl Default for ContainerStats {
             memory_limit_bytes: 0,
             network_tx_bytes_per_sec: 0.0,
             network_rx_bytes_per_sec: 0.0,
+            block_read_bytes_per_sec: 0.0,
+            block_write_bytes_per_sec: 0.0,
+            pids: 0,
+            pids_limit: None,
+            online_cpus: 0,
             cpu_history: VecDeque::with_capacity(HISTORY_BUFFER_SIZE),
             memory_history: VecDeque::with_capacity(HISTORY_BUFFER_SIZE),
             last_history_bucket: 0,
+            history_samples: 0,
+            deploy_markers: VecDeque::new(),
+            memory_trend: VecDeque::new(),
         }
     }
 }
 
 /// Unique key for identifying containers across multiple hosts
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ContainerKey {
     pub host_id: HostId,
+    /// Full 64 character ID (matches event actor IDs; shown truncated)
     pub container_id: String,
 }
 
@@ -145,6 +500,45 @@ impl ContainerKey {
             container_id,
         }
     }
+
+    /// The 12 character ID shown for a container that is no longer known
+    pub fn short_id(&self) -> &str {
+        &self.container_id[..12.min(self.container_id.len())]
+    }
+}
+
+/// Where the containers of a project come from
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ProjectKind {
+    /// A compose project (`com.docker.compose.project` label)
+    Compose,
+    /// A swarm stack (`com.docker.stack.namespace` label); its containers are swarm tasks,
+    /// so there are no project actions
+    Stack,
+}
+
+/// A compose project or swarm stack on a specific host
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ProjectKey {
+    pub host_id: HostId,
+    pub kind: ProjectKind,
+    pub name: String,
+}
+
+/// One step of a project action: the containers acted on together, after the previous
+/// step has finished
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanStep {
+    pub action: ContainerAction,
+    pub containers: Vec<ContainerKey>,
+}
+
+/// A row of the container list while grouping by compose project or swarm stack
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListRow {
+    /// Project header, followed by its containers unless collapsed
+    Project(ProjectKey),
+    Container(ContainerKey),
 }
 
 #[derive(Debug)]
@@ -152,19 +546,37 @@ pub enum AppEvent {
     /// Initial list of containers when app starts for a specific host
     InitialContainerList(HostId, Vec<Container>),
     /// A new container was created/started (host_id is in the Container)
-    ContainerCreated(Container),
+    ContainerCreated(Box<Container>),
     /// A container was stopped/destroyed on a specific host
     ContainerDestroyed(ContainerKey),
-    /// A container's state changed (e.g., from Running to Exited)
-    ContainerStateChanged(ContainerKey, ContainerState),
+    /// A container stopped, with its exit code if Docker reported one
+    ContainerExited(ContainerKey, Option<i64>),
+    /// Docker's restart count for a container, fetched after the initial list
+    ContainerRestartCount(ContainerKey, i64),
+    /// Platform of a container's image, fetched after the container is listed or started
+    ContainerPlatform(ContainerKey, ImagePlatform),
+    /// Writable layer sizes (container ID, bytes) for the containers on a host, or why
+    /// they could not be fetched
+    ContainerSizes(HostId, Result<Vec<(String, i64)>, String>),
     /// Stats update for an existing container on a specific host
     ContainerStat(ContainerKey, ContainerStats),
-    /// Health status changed for a container
-    ContainerHealthChanged(ContainerKey, HealthStatus),
+    /// Health status changed for a container, at the daemon's time of the event
+    ContainerHealthChanged(ContainerKey, HealthStatus, DateTime<Utc>),
+    /// Container paused (true) or unpaused (false)
+    ContainerPauseChanged(ContainerKey, bool),
+    /// Container renamed (new name)
+    ContainerRenamed(ContainerKey, String),
+    /// An exec session started in a container (command line), on hosts with exec events enabled
+    ContainerExec(ContainerKey, String),
+    /// Container resources updated (`docker update`), with the new memory limit in bytes
+    /// (None when unlimited)
+    ContainerLimitsUpdated(ContainerKey, Option<u64>),
     /// User requested to quit
     Quit,
     /// Terminal was resized
     Resize,
+    /// The terminal gained (true) or lost (false) focus
+    FocusChanged(bool),
     /// Move selection up
     SelectPrevious,
     /// Move selection down
@@ -175,6 +587,106 @@ pub enum AppEvent {
     ExitLogView,
     /// User pressed right arrow to show log view
     ShowLogView,
+    /// User pressed 'H' to show the layer history of the selected container's image
+    ShowImageHistory,
+    /// Image history loaded (or failed to load) for a container's image
+    ImageHistoryLoaded(ContainerKey, Result<Vec<ImageLayer>, String>),
+    /// Networks available to the network picker (attachable or attached, by action)
+    NetworksLoaded(ContainerKey, Result<Vec<String>, String>),
+    /// User pressed 'T' to show the processes of the selected container
+    ShowProcessList,
+    /// Processes of a container fetched (or failed to), repeatedly while the view is shown
+    ProcessesLoaded(ContainerKey, Result<Vec<ProcessInfo>, String>),
+    /// A directory of a container listed (or failed to) for the file browser
+    FilesLoaded(ContainerKey, String, Result<Vec<FileEntry>, String>),
+    /// User pressed '$' to show the environment variables of the selected container
+    ShowEnvVars,
+    /// Environment variables of a container fetched (or failed to)
+    EnvVarsLoaded(ContainerKey, Result<Vec<(String, String)>, String>),
+    /// User pressed '*' in the environment variables popup to show or mask secrets
+    ToggleSecrets,
+    /// User pressed 'D' in the file browser to download the selected entry
+    DownloadSelectedFile,
+    /// A file or directory downloaded from a container (local path, or the error)
+    FileDownloaded(ContainerKey, Result<PathBuf, String>),
+    /// User pressed 'V' to show the volumes of all connected hosts
+    ShowVolumes,
+    /// Volumes loaded (or failed to load) for a host
+    VolumesLoaded(HostId, Result<Vec<VolumeInfo>, String>),
+    /// User pressed 'D' in the volumes view to remove the orphaned volumes
+    RemoveOrphanedVolumes,
+    /// User pressed 'x' in the volumes view to remove the selected (orphaned) volume
+    RemoveSelectedVolume,
+    /// Orphaned volumes removed on a host, with an error message per volume that could not be
+    VolumesRemoved(HostId, Vec<String>),
+    /// User pressed 'X' to show the cleanup suggestions for all connected hosts
+    ShowCleanup,
+    /// Cleanup candidates loaded (or failed to load) for a host
+    CleanupLoaded(HostId, Result<Vec<CleanupCandidate>, String>),
+    /// User pressed 'x' in the cleanup view to remove the selected candidate
+    RemoveCleanupSelected,
+    /// User pressed 'D' in the cleanup view to remove all candidates of the selected one's kind
+    RemoveCleanupKind,
+    /// Cleanup candidates removed on a host, with an error message per candidate that could not be
+    CleanupRemoved(HostId, Vec<String>),
+    /// User pressed 'F' to show the disk usage of all connected hosts
+    ShowDiskUsage,
+    /// Disk usage loaded (or failed to load) for a host
+    DiskUsageLoaded(HostId, Result<Vec<DiskUsageRow>, String>),
+    /// User pressed 'x' in the disk usage view to prune the selected kind on its host
+    PruneDiskUsageSelected,
+    /// A kind pruned on a host, with the space freed or an error message
+    DiskUsagePruned(HostId, Result<u64, String>),
+    /// User pressed 'W' to show the networks of all connected hosts
+    ShowNetworks,
+    /// Networks loaded (or failed to load) for a host
+    NetworkListLoaded(HostId, Result<Vec<NetworkInfo>, String>),
+    /// User pressed 'E' to show the events timeline
+    ShowTimeline,
+    /// User pressed 'S' to show the background shell sessions
+    ShowShellSessions,
+    /// User pressed '!' to show the notifications panel
+    ShowNotifications,
+    /// User pressed 'Z' to toggle do-not-disturb mode
+    ToggleDoNotDisturb,
+    /// User pressed 'a' in the notifications panel to acknowledge all notifications
+    AcknowledgeAllNotifications,
+    /// User pressed 'x' in the notifications panel to clear the selected notification
+    ClearNotification,
+    /// User pressed 'D' in the notifications panel to clear the acknowledged notifications
+    ClearAcknowledgedNotifications,
+    /// User pressed 'i' in the notifications panel to show the full error of the selected one
+    ShowErrorDetail,
+    /// User pressed 'y' in the error detail popup to copy the error
+    CopyErrorDetail,
+    /// User pressed Ctrl+S to export the container list
+    ShowExportMenu,
+    /// The container list was exported (or failed to)
+    ViewExported(ExportOutcome),
+    /// A prune picked from the action menu finished on a host (or failed)
+    PruneFinished(HostId, PruneTarget, Result<PruneReport, String>),
+    /// An item of a bulk action finished, successfully or not
+    BulkItemDone(u64, bool),
+    /// A bulk action stopped before all of its items ran
+    BulkStopped(u64),
+    /// User pressed 'T' to enter the stop timeout of the highlighted Stop or Restart
+    PromptStopTimeout,
+    /// A shell session was started (it runs until its shell exits)
+    ShellSessionStarted(ShellSessionInfo),
+    /// The shell of a session exited
+    ShellSessionEnded(usize),
+    /// User pressed 'B' to open the image build dialog
+    ShowBuildDialog,
+    /// A line of output from the running image build
+    BuildOutput(BuildLine),
+    /// The image build finished (Err with the build error)
+    BuildFinished(Result<(), String>),
+    /// A command was started in a container, with its command line
+    ExecStarted(ContainerKey, String),
+    /// A line of output from a command run in a container
+    ExecOutput(ContainerKey, ExecLine),
+    /// A command run in a container finished (or could not be run)
+    ExecFinished(ContainerKey, ExecStatus),
     /// User scrolled up in log view
     ScrollUp,
     /// User scrolled down in log view
@@ -194,14 +706,47 @@ pub enum AppEvent {
     LogLine(ContainerKey, LogEntry),
     /// User pressed 'o' to open Dozzle
     OpenDozzle,
+    /// User pressed 'e' to open the selected container's HTTP port in the browser
+    OpenPort,
     /// User pressed '?' to toggle help
     ToggleHelp,
+    /// User pressed 'i' to toggle the full details popup for the selected container
+    ToggleDetails,
+    /// User pressed 'v' to compare the selected container with its replicas
+    ToggleReplicas,
+    /// User pressed 'I' to show/hide the ID column
+    ToggleIdColumn,
+    /// User pressed F12 to show/hide the event loop indicator
+    ToggleLoopMetrics,
+    /// User pressed 'f' in the details popup to toggle between short and full IDs
+    ToggleFullId,
+    /// User pressed 'f' in the log view to follow new lines or pause
+    ToggleFollowLogs,
+    /// User pressed '1'-'4' in the log view to hide or show a log level
+    ToggleLogLevel(LogLevel),
+    /// User pressed 'd' in the log view to fold repeated lines
+    ToggleCollapseRepeats,
+    /// User pressed 'y' to pick what of the selected container to copy, or again in the
+    /// copy menu to copy the highlighted entry
+    ShowCopyMenu,
+    /// User pressed 'Y' in the details popup to copy the labels (key=value lines)
+    CopyLabels,
+    /// User pressed 'z' to show the Size column and fetch container sizes
+    RefreshContainerSizes,
+    /// User pressed 't' to switch between relative and absolute timestamps
+    ToggleAbsoluteTimestamps,
+    /// User pressed '%' to show CPU usage as a share of one core or of the whole host
+    ToggleCpuNormalized,
+    /// User pressed '#' to switch the memory column between percentage, used, and used/limit
+    CycleMemoryDisplay,
     /// User pressed 's' to cycle sort field
     CycleSortField,
     /// User pressed a key to set a specific sort field
     SetSortField(SortField),
     /// User pressed 'a' to toggle showing all containers (including stopped)
     ToggleShowAll,
+    /// User pressed 'p' to toggle grouping containers by compose project
+    ToggleGroupByProject,
     /// User pressed left arrow or Esc to cancel action menu
     CancelActionMenu,
     /// Navigate up in action menu
@@ -210,18 +755,40 @@ pub enum AppEvent {
     SelectActionDown,
     /// Action is in progress
     ActionInProgress(ContainerKey, ContainerAction),
+    /// The last log lines of a container were saved before stopping or removing it
+    /// (the file written, or why it couldn't be)
+    LogSnapshotTaken(ContainerKey, Result<PathBuf, String>),
     /// Action completed successfully
     ActionSuccess(ContainerKey, ContainerAction),
+    /// A started or restarted container was watched for a while after the action
+    ActionVerified(ContainerKey, ContainerAction, VerifyOutcome),
     /// Action failed with error
     ActionError(ContainerKey, ContainerAction, String),
+    /// Action failed with a transient error and is tried again (retry number, error)
+    ActionRetrying(ContainerKey, ContainerAction, u32, String),
     /// User pressed '/' to enter search mode
     EnterSearchMode,
-    /// Key event for search input (passed to tui-input)
+    /// User pressed 'n' in the log view to jump to the next search match
+    LogSearchNext,
+    /// User pressed 'N' in the log view to jump to the previous search match
+    LogSearchPrevious,
+    /// Key event for text input (search bar, log search or build dialog, passed to tui-input)
     SearchKeyEvent(crossterm::event::KeyEvent),
     /// Connection to a Docker host failed
     ConnectionError(HostId, String),
     /// A new Docker host has successfully connected
     HostConnected(crate::docker::connection::DockerHost),
+    /// A host's event stream was lost; retrying (attempt number)
+    HostReconnecting(HostId, u32),
+    /// A host that was lost answers again; its container list follows
+    HostReconnected(HostId),
+    /// How far a host's daemon clock is ahead of the local one (negative when behind),
+    /// measured on every (re)connect
+    HostClockSkew(HostId, TimeDelta),
+    /// Disk space of a host's data root and its load, measured periodically when enabled
+    HostResources(HostId, HostResources),
+    /// CPU count, memory and (local hosts) load of a host, read periodically
+    HostInfo(HostId, HostInfo),
 }
 
 pub type EventSender = mpsc::Sender<AppEvent>;
@@ -235,6 +802,23 @@ pub enum RenderAction {
     Render,
     /// Start a shell session for a container
     StartShell(ContainerKey),
+    /// Re-attach to a background shell session by id
+    AttachShell(usize),
+    /// Export the visible container list as chosen in the export menu
+    Export(ExportChoice),
+}
+
+/// What changed on screen since the last frame, deciding whether the periodic frame is
+/// drawn or skipped
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Damage {
+    /// Nothing: the frame would come out the same
+    #[default]
+    None,
+    /// Stats cells of the container list (and of the popups over it)
+    Stats,
+    /// Anything else: rows, views, popups, banners
+    Structure,
 }
 
 /// Current view state of the application
@@ -246,8 +830,77 @@ pub enum ViewState {
     LogView(ContainerKey),
     /// Viewing action menu for a specific container
     ActionMenu(ContainerKey),
+    /// Viewing action menu for all containers of a compose project
+    ProjectActionMenu(ProjectKey),
+    /// Reviewing the order a project action will run its containers in, before running it
+    ProjectPlan(ProjectKey, ContainerAction),
+    /// Entering the stop timeout for a single Stop or Restart of a container
+    StopTimeoutPrompt(ContainerKey, ContainerAction),
     /// Search mode active (editing search query)
     SearchMode,
+    /// Viewing the image layer history for a specific container
+    ImageHistory(ContainerKey),
+    /// Image build dialog, then its output
+    Build,
+    /// Picking a network to connect/disconnect a container to/from
+    NetworkPicker(ContainerKey),
+    /// Processes running in a container, refreshed while shown
+    ProcessList(ContainerKey),
+    /// Browsing the files of a container
+    FileBrowser(ContainerKey),
+    /// Environment variables of a container, in a popup over the container list
+    EnvVars(ContainerKey),
+    /// Docker volumes of all connected hosts
+    VolumeList,
+    /// Cleanup suggestions for all connected hosts
+    Cleanup,
+    /// Disk usage of all connected hosts, per kind
+    DiskUsage,
+    /// Container events of all connected hosts, newest first
+    Timeline,
+    /// Docker networks of all connected hosts
+    NetworkList,
+    /// Shell sessions kept running in the background
+    ShellSessions,
+    /// Entering a command to run in a container
+    ExecPrompt(ContainerKey),
+    /// Output of a command run in a container
+    ExecOutput(ContainerKey),
+    /// Picking how to export the container list
+    ExportMenu,
+    /// Picking what to prune on a host, from the action menu
+    PruneMenu(HostId),
+    /// Picking how long a host stays in maintenance, from the action menu
+    MaintenanceMenu(HostId),
+    /// Errors, alerts and action results of the session, newest first
+    Notifications,
+    /// The full error of a notification, over the notifications panel
+    ErrorDetail,
+    /// Picking what of a container to copy to the clipboard
+    CopyMenu(ContainerKey),
+}
+
+/// How a started or restarted container fared while it was watched
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerifyOutcome {
+    /// Still up after `secs` seconds, with its health if it has a healthcheck
+    StayedUp {
+        secs: u64,
+        health: Option<HealthStatus>,
+    },
+    /// Exited, or was restarted by its restart policy, `secs` seconds in
+    /// (with its exit code when it stayed down)
+    Crashed { secs: u64, exit_code: Option<i64> },
+}
+
+impl VerifyOutcome {
+    /// Whether the container is up and not unhealthy
+    pub fn is_ok(&self) -> bool {
+        matches!(
+            self,
+            VerifyOutcome::StayedUp { health, .. } if *health != Some(HealthStatus::Unhealthy)
+        )
+    }
 }
 
 /// Available actions for containers
@@ -258,37 +911,75 @@ pub enum ContainerAction {
     Restart,
     Remove,
     Shell,
+    /// Run a command typed in a prompt and show its output in a popup
+    ExecCommand,
+    /// Browse the container's files and download them
+    BrowseFiles,
+    /// Run the container's health check command and show its output
+    RunHealthcheck,
+    /// Pull the container's image (with registry credentials)
+    PullImage,
+    /// Push the container's image (with registry credentials)
+    PushImage,
+    /// Connect the container to a network picked from the host's networks
+    ConnectNetwork,
+    /// Disconnect the container from one of its networks
+    DisconnectNetwork,
+    /// Restart the systemd unit managing the container, on its host
+    RestartUnit,
+    /// Prune unused objects on the container's host, picked from a submenu
+    Prune,
+    /// Put the container's host in maintenance for a duration picked from a submenu
+    Maintenance,
 }
 
 impl ContainerAction {
-    /// Returns the display name for this action
-    pub fn display_name(self) -> &'static str {
-        match self {
-            ContainerAction::Start => "Start",
-            ContainerAction::Stop => "Stop",
-            ContainerAction::Restart => "Restart",
-            ContainerAction::Remove => "Remove",
-            ContainerAction::Shell => "Shell",
-        }
-    }
-
     /// Returns all available actions for a given container state
     pub fn available_for_state(state: &ContainerState) -> Vec<ContainerAction> {
         match state {
             ContainerState::Running => vec![
                 ContainerAction::Shell,
+                ContainerAction::ExecCommand,
+                ContainerAction::BrowseFiles,
+                ContainerAction::RunHealthcheck,
                 ContainerAction::Stop,
                 ContainerAction::Restart,
                 ContainerAction::Remove,
+                ContainerAction::PullImage,
+                ContainerAction::PushImage,
+                ContainerAction::ConnectNetwork,
+                ContainerAction::DisconnectNetwork,
+            ],
+            ContainerState::Paused => vec![
+                ContainerAction::Stop,
+                ContainerAction::Remove,
+                ContainerAction::PullImage,
+                ContainerAction::PushImage,
+                ContainerAction::ConnectNetwork,
+                ContainerAction::DisconnectNetwork,
+            ],
+            ContainerState::Exited | ContainerState::Created | ContainerState::Dead => vec![
+                ContainerAction::Start,
+                ContainerAction::Remove,
+                ContainerAction::PullImage,
+                ContainerAction::PushImage,
+                ContainerAction::ConnectNetwork,
+                ContainerAction::DisconnectNetwork,
             ],
-            ContainerState::Paused => vec![ContainerAction::Stop, ContainerAction::Remove],
-            ContainerState::Exited | ContainerState::Created | ContainerState::Dead => {
-                vec![ContainerAction::Start, ContainerAction::Remove]
-            }
             ContainerState::Restarting | ContainerState::Removing => vec![],
             ContainerState::Unknown => vec![],
         }
     }
+
+    /// Actions offered for a whole compose project, each applied to the containers
+    /// it is available for
+    pub fn available_for_project() -> Vec<ContainerAction> {
+        vec![
+            ContainerAction::Start,
+            ContainerAction::Stop,
+            ContainerAction::Restart,
+        ]
+    }
 }
 
 /// Sort direction
@@ -350,6 +1041,55 @@ pub enum SortField {
     Cpu,
     /// Sort by memory usage
     Memory,
+    /// Sort by the configured script sort key
+    Script,
+    /// Sort by restarts (this session first, then Docker's restart count)
+    Restarts,
+    /// Sort by block device read rate
+    BlockRead,
+    /// Sort by block device write rate
+    BlockWrite,
+    /// Sort by process count
+    Pids,
+}
+
+/// What the memory column shows without sparklines (narrow terminals)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MemoryDisplay {
+    /// Share of the limit (" 45.2%")
+    #[default]
+    Percent,
+    /// Memory used ("512 M")
+    Used,
+    /// Memory used and the limit ("512 M/2 G")
+    UsedLimit,
+}
+
+impl MemoryDisplay {
+    /// The mode after this one, cycling back to the percentage
+    pub fn next(self) -> Self {
+        match self {
+            MemoryDisplay::Percent => MemoryDisplay::Used,
+            MemoryDisplay::Used => MemoryDisplay::UsedLimit,
+            MemoryDisplay::UsedLimit => MemoryDisplay::Percent,
+        }
+    }
+}
+
+impl std::str::FromStr for MemoryDisplay {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "percent" | "%" => Ok(MemoryDisplay::Percent),
+            "used" => Ok(MemoryDisplay::Used),
+            "limit" | "used/limit" => Ok(MemoryDisplay::UsedLimit),
+            _ => Err(format!(
+                "Invalid memory display: '{}'. Use 'percent', 'used', or 'limit'",
+                s
+            )),
+        }
+    }
 }
 
 impl std::str::FromStr for SortField {
@@ -361,8 +1101,13 @@ impl std::str::FromStr for SortField {
             "name" | "n" => Ok(SortField::Name),
             "cpu" | "c" => Ok(SortField::Cpu),
             "memory" | "mem" | "m" => Ok(SortField::Memory),
+            "script" => Ok(SortField::Script),
+            "restarts" | "r" => Ok(SortField::Restarts),
+            "blkread" => Ok(SortField::BlockRead),
+            "blkwrite" => Ok(SortField::BlockWrite),
+            "pids" => Ok(SortField::Pids),
             _ => Err(format!(
-                "Invalid sort field '{}'. Valid options: uptime, name, cpu, memory",
+                "Invalid sort field '{}'. Valid options: uptime, name, cpu, memory, restarts, blkread, blkwrite, pids, script",
                 s
             )),
         }
@@ -376,18 +1121,29 @@ impl std::fmt::Display for SortField {
             SortField::Name => write!(f, "name"),
             SortField::Cpu => write!(f, "cpu"),
             SortField::Memory => write!(f, "memory"),
+            SortField::Script => write!(f, "script"),
+            SortField::Restarts => write!(f, "restarts"),
+            SortField::BlockRead => write!(f, "blkread"),
+            SortField::BlockWrite => write!(f, "blkwrite"),
+            SortField::Pids => write!(f, "pids"),
         }
     }
 }
 
 impl SortField {
     /// Cycles to the next sort field
+    /// Script, restart, block I/O and PIDs sorting have their own keys/config, so they are not part of the cycle
     pub fn next(self) -> Self {
         match self {
             SortField::Uptime => SortField::Name,
             SortField::Name => SortField::Cpu,
             SortField::Cpu => SortField::Memory,
-            SortField::Memory => SortField::Uptime,
+            SortField::Memory
+            | SortField::Script
+            | SortField::Restarts
+            | SortField::BlockRead
+            | SortField::BlockWrite
+            | SortField::Pids => SortField::Uptime,
         }
     }
 
@@ -398,6 +1154,953 @@ impl SortField {
             SortField::Uptime => SortDirection::Descending, // Newest first
             SortField::Cpu => SortDirection::Descending,    // Highest first
             SortField::Memory => SortDirection::Descending, // Highest first
+            SortField::Script => SortDirection::Descending, // Highest first
+            SortField::Restarts => SortDirection::Descending, // Most restarts first
+            SortField::BlockRead => SortDirection::Descending, // Highest first
+            SortField::BlockWrite => SortDirection::Descending, // Highest first
+            SortField::Pids => SortDirection::Descending,   // Most processes first
+        }
+    }
+}
+
+/// A layer in an image's history (`docker history`)
+#[derive(Clone, Debug)]
+pub struct ImageLayer {
+    pub created: Option<DateTime<Utc>>,
+    /// Dockerfile instruction that produced the layer
+    pub created_by: String,
+    /// Layer size in bytes
+    pub size: i64,
+    /// Tags pointing at this layer
+    pub tags: Vec<String>,
+}
+
+/// Image history state for the currently viewed container
+#[derive(Debug)]
+pub struct ImageHistoryState {
+    /// Which container's image this is
+    pub container_key: ContainerKey,
+    /// Image name the history was requested for
+    pub image: String,
+    /// Layers, newest first (None while loading)
+    pub layers: Option<Vec<ImageLayer>>,
+    /// Error from the Docker API, if loading failed
+    pub error: Option<String>,
+    /// Index of the first visible layer
+    pub scroll_offset: usize,
+}
+
+/// A process running in a container, from `docker top`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessInfo {
+    pub pid: String,
+    /// User name or ID (empty when the host's `ps` doesn't report it)
+    pub user: String,
+    /// CPU usage in percent (None when not reported as a percentage)
+    pub cpu: Option<f64>,
+    pub command: String,
+}
+
+/// State of the process list view
+#[derive(Debug)]
+pub struct ProcessListState {
+    /// Which container's processes these are
+    pub container_key: ContainerKey,
+    /// Processes, busiest first (None while loading)
+    pub processes: Option<Vec<ProcessInfo>>,
+    /// Error from the Docker API, if the last refresh failed
+    pub error: Option<String>,
+    /// Index of the first visible process
+    pub scroll_offset: usize,
+    /// Task refreshing the processes, aborted when the view is closed
+    pub refresh_handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+/// Name fragments of environment variables whose values are masked until revealed
+const SECRET_PATTERNS: [&str; 9] = [
+    "PASSWORD",
+    "PASSWD",
+    "SECRET",
+    "TOKEN",
+    "API_KEY",
+    "ACCESS_KEY",
+    "PRIVATE_KEY",
+    "CREDENTIAL",
+    "AUTH",
+];
+
+/// Whether an environment variable looks like it holds a secret, by its name
+pub fn is_secret_env(name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    SECRET_PATTERNS.iter().any(|pattern| name.contains(pattern))
+}
+
+/// State of the environment variables popup
+#[derive(Debug)]
+pub struct EnvVarsState {
+    /// Which container's variables these are
+    pub container_key: ContainerKey,
+    /// (name, value), sorted by name (None while loading)
+    pub vars: Option<Vec<(String, String)>>,
+    /// Error from the Docker API, if they couldn't be fetched
+    pub error: Option<String>,
+    /// Filter on names, and on values that are shown
+    pub search_input: tui_input::Input,
+    /// Whether the search is being typed
+    pub search_editing: bool,
+    /// Show the values of the variables that look like secrets
+    pub reveal_secrets: bool,
+    /// Index of the first visible variable
+    pub scroll_offset: usize,
+}
+
+impl EnvVarsState {
+    /// The variables matching the search, with secret values masked unless revealed
+    pub fn visible(&self) -> Vec<(&str, &str)> {
+        let query = self.search_input.value().to_lowercase();
+        self.vars
+            .iter()
+            .flatten()
+            .map(|(name, value)| {
+                let value = if !self.reveal_secrets && is_secret_env(name) {
+                    "••••••••"
+                } else {
+                    value.as_str()
+                };
+                (name.as_str(), value)
+            })
+            .filter(|(name, value)| {
+                query.is_empty()
+                    || name.to_lowercase().contains(&query)
+                    || value.to_lowercase().contains(&query)
+            })
+            .collect()
+    }
+}
+
+/// What a file browser entry is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileKind {
+    Directory,
+    File,
+    Symlink,
+    /// Device, pipe or socket
+    Other,
+}
+
+/// An entry of a directory in a container, from `ls -la`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileEntry {
+    pub name: String,
+    pub kind: FileKind,
+    pub size: u64,
+    pub owner: String,
+    /// Modification time as `ls` prints it ("Oct 17 10:15", or the year when older)
+    pub modified: String,
+    /// Where a symlink points
+    pub link_target: Option<String>,
+}
+
+/// State of the file browser
+#[derive(Debug)]
+pub struct FileBrowserState {
+    /// Which container's files these are
+    pub container_key: ContainerKey,
+    /// Directory shown
+    pub path: String,
+    /// Its entries, directories first
+    pub entries: Vec<FileEntry>,
+    /// A directory is being listed
+    pub loading: bool,
+    /// Why the last directory couldn't be listed (the previous one stays shown)
+    pub error: Option<String>,
+    /// Downloads still running
+    pub downloads: usize,
+    /// Where the last download was saved, or why it failed
+    pub last_download: Option<Result<PathBuf, String>>,
+    /// Selected entry
+    pub table_state: ratatui::widgets::TableState,
+}
+
+/// Running and total (running or stopped) containers of a host
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContainerCounts {
+    pub running: usize,
+    pub total: usize,
+}
+
+/// Container counts and resource usage of a host, summed over its containers
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostSummary {
+    pub host_id: HostId,
+    pub counts: ContainerCounts,
+    /// Summed CPU usage of the running containers (100% = one core)
+    pub cpu: f64,
+    /// Summed memory usage of the running containers in bytes
+    pub memory_used_bytes: u64,
+}
+
+impl HostSummary {
+    pub fn new(host_id: HostId) -> Self {
+        Self {
+            host_id,
+            counts: ContainerCounts::default(),
+            cpu: 0.0,
+            memory_used_bytes: 0,
+        }
+    }
+}
+
+/// Capacity of a host, so container usage can be read against it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostInfo {
+    /// Number of CPUs of the host
+    pub cpus: u64,
+    /// Memory of the host in bytes
+    pub memory_bytes: u64,
+    /// Load average over the last minute (None for remote hosts, whose /proc isn't
+    /// readable without a probe container)
+    pub load1: Option<f64>,
+}
+
+/// Disk space of a host's Docker data root and the host's load
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostResources {
+    pub disk_used_bytes: u64,
+    /// Space left to unprivileged writers (excludes blocks reserved for root)
+    pub disk_available_bytes: u64,
+    /// Load average over the last minute
+    pub load1: f64,
+    /// Number of CPUs of the host
+    pub cpus: u64,
+}
+
+impl HostResources {
+    /// Share of the data-root disk in use, in percent (as df computes it, against the
+    /// space usable by containers)
+    pub fn disk_used_percent(&self) -> f64 {
+        let usable = self.disk_used_bytes + self.disk_available_bytes;
+        if usable == 0 {
+            return 0.0;
+        }
+        self.disk_used_bytes as f64 / usable as f64 * 100.0
+    }
+
+    /// Whether the data-root disk is nearly full (containers start failing to write)
+    pub fn disk_low(&self) -> bool {
+        self.disk_used_percent() >= DISK_WARNING_PERCENT
+    }
+
+    /// Whether the host has more runnable work than its CPUs can keep up with
+    pub fn load_high(&self) -> bool {
+        self.cpus > 0 && self.load1 >= self.cpus as f64 * LOAD_WARNING_PER_CPU
+    }
+}
+
+/// Container counts of a host over the session, one sample per COUNT_BUCKET_SECS
+/// A sample keeps the lowest running and highest total count seen during its bucket,
+/// so containers crashing and restarting within a bucket still show up as a dip
+#[derive(Debug, Default)]
+pub struct CountHistory {
+    samples: VecDeque<ContainerCounts>,
+    current: ContainerCounts,
+    last_bucket: u64,
+}
+
+impl CountHistory {
+    /// Records the counts after a change in the given bucket
+    pub fn record(&mut self, counts: ContainerCounts, bucket: u64) {
+        match self.samples.back_mut() {
+            Some(last) if bucket <= self.last_bucket => {
+                last.running = last.running.min(counts.running);
+                last.total = last.total.max(counts.total);
+            }
+            Some(_) => {
+                // Buckets without changes had the last known counts
+                let gap = (bucket - self.last_bucket - 1).min(COUNT_HISTORY_SIZE as u64);
+                for _ in 0..gap {
+                    self.samples.push_back(self.current);
+                }
+                self.samples.push_back(counts);
+                self.last_bucket = bucket;
+            }
+            None => {
+                self.samples.push_back(counts);
+                self.last_bucket = bucket;
+            }
+        }
+        self.current = counts;
+
+        while self.samples.len() > COUNT_HISTORY_SIZE {
+            self.samples.pop_front();
+        }
+    }
+
+    /// The counts after the latest change
+    pub fn current(&self) -> ContainerCounts {
+        self.current
+    }
+
+    /// Samples up to `bucket`, oldest first, with the current counts filling in quiet buckets
+    pub fn samples(&self, bucket: u64) -> Vec<ContainerCounts> {
+        let gap = bucket
+            .saturating_sub(self.last_bucket)
+            .min(COUNT_HISTORY_SIZE as u64) as usize;
+        let mut samples: Vec<ContainerCounts> = self
+            .samples
+            .iter()
+            .copied()
+            .chain(std::iter::repeat_n(self.current, gap))
+            .collect();
+        let excess = samples.len().saturating_sub(COUNT_HISTORY_SIZE);
+        samples.drain(..excess);
+        samples
+    }
+}
+
+/// A Docker volume with the number of containers referencing it
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeInfo {
+    pub host_id: HostId,
+    pub name: String,
+    pub driver: String,
+    /// Path of the volume's data on the host (empty for some remote drivers)
+    pub mountpoint: String,
+    /// Disk usage in bytes (None when the driver does not report it)
+    pub size: Option<i64>,
+    /// Number of containers (running or stopped) using the volume
+    pub containers: usize,
+}
+
+impl VolumeInfo {
+    /// Whether no container references the volume
+    pub fn is_orphaned(&self) -> bool {
+        self.containers == 0
+    }
+}
+
+/// State of the volumes view
+#[derive(Debug, Default)]
+pub struct VolumeListState {
+    /// Volumes of all hosts, sorted by host then name
+    pub volumes: Vec<VolumeInfo>,
+    /// Hosts whose volumes are still loading
+    pub loading: HashSet<HostId>,
+    /// Load and removal errors, shown above the table
+    pub errors: Vec<String>,
+    /// Selected volume
+    pub table_state: ratatui::widgets::TableState,
+    /// Whether the remove orphaned volumes confirmation is shown
+    pub confirm_remove: bool,
+    /// Whether the confirmation is for the selected volume only, instead of all orphans
+    pub remove_selected: bool,
+}
+
+impl VolumeListState {
+    /// Volumes not referenced by any container
+    pub fn orphans(&self) -> impl Iterator<Item = &VolumeInfo> {
+        self.volumes.iter().filter(|volume| volume.is_orphaned())
+    }
+
+    /// Space freed by removing the orphaned volumes (volumes without a known size count as 0)
+    pub fn reclaimable(&self) -> i64 {
+        self.orphans().filter_map(|volume| volume.size).sum()
+    }
+
+    /// The selected volume, if it is orphaned
+    pub fn selected_orphan(&self) -> Option<&VolumeInfo> {
+        self.table_state
+            .selected()
+            .and_then(|idx| self.volumes.get(idx))
+            .filter(|volume| volume.is_orphaned())
+    }
+
+    /// Volumes the remove confirmation is about
+    pub fn removal_candidates(&self) -> Vec<&VolumeInfo> {
+        if self.remove_selected {
+            self.selected_orphan().into_iter().collect()
+        } else {
+            self.orphans().collect()
+        }
+    }
+}
+
+/// What a cleanup candidate is, in the order they are listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CleanupKind {
+    /// A container that exited longer ago than the configured number of days
+    ExitedContainer,
+    /// An untagged image no other image is built on
+    DanglingImage,
+    /// A volume no container references
+    UnusedVolume,
+}
+
+/// Something the cleanup view suggests removing
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanupCandidate {
+    pub host_id: HostId,
+    pub kind: CleanupKind,
+    /// Container ID, image ID or volume name, as Docker removes it by
+    pub id: String,
+    /// Container name, short image ID or volume name
+    pub name: String,
+    /// When the container exited or the image was created (None for volumes)
+    pub since: Option<DateTime<Utc>>,
+    /// Estimated space freed by removing it, in bytes (None when unknown)
+    pub size: Option<i64>,
+}
+
+/// Which candidates a cleanup confirmation is about
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CleanupSelection {
+    /// Only the selected candidate
+    Selected,
+    /// All candidates of a kind
+    Kind(CleanupKind),
+}
+
+/// State of the cleanup view
+#[derive(Debug, Default)]
+pub struct CleanupState {
+    /// Candidates of all hosts, sorted by host, kind then name
+    pub candidates: Vec<CleanupCandidate>,
+    /// Hosts whose candidates are still loading
+    pub loading: HashSet<HostId>,
+    /// Load and removal errors, shown above the table
+    pub errors: Vec<String>,
+    /// Selected candidate
+    pub table_state: ratatui::widgets::TableState,
+    /// The candidates the shown remove confirmation is about, if shown
+    pub confirm: Option<CleanupSelection>,
+}
+
+impl CleanupState {
+    /// The selected candidate
+    pub fn selected(&self) -> Option<&CleanupCandidate> {
+        self.table_state
+            .selected()
+            .and_then(|idx| self.candidates.get(idx))
+    }
+
+    /// Candidates the remove confirmation is about
+    pub fn removal_candidates(&self) -> Vec<&CleanupCandidate> {
+        match self.confirm {
+            Some(CleanupSelection::Selected) => self.selected().into_iter().collect(),
+            Some(CleanupSelection::Kind(kind)) => self
+                .candidates
+                .iter()
+                .filter(|candidate| candidate.kind == kind)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Space freed by removing the candidates (candidates without a known size count as 0)
+pub fn cleanup_reclaimable<'a>(candidates: impl IntoIterator<Item = &'a CleanupCandidate>) -> i64 {
+    candidates
+        .into_iter()
+        .filter_map(|candidate| candidate.size)
+        .sum()
+}
+
+/// What takes up a host's disk space, in the order `docker system df` lists them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiskUsageKind {
+    Images,
+    Containers,
+    Volumes,
+    BuildCache,
+}
+
+/// The disk usage of one kind on a host
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiskUsageRow {
+    pub host_id: HostId,
+    pub kind: DiskUsageKind,
+    /// Number of images, containers, volumes or cache records
+    pub total: i64,
+    /// How many of them are in use
+    pub active: i64,
+    /// Space taken, in bytes
+    pub size: i64,
+    /// Space freed by pruning the ones not in use, in bytes
+    pub reclaimable: i64,
+}
+
+/// State of the disk usage view
+#[derive(Debug, Default)]
+pub struct DiskUsageState {
+    /// Rows of all hosts, sorted by host then kind
+    pub rows: Vec<DiskUsageRow>,
+    /// Hosts whose disk usage is still loading
+    pub loading: HashSet<HostId>,
+    /// Load and prune errors, shown above the table
+    pub errors: Vec<String>,
+    /// Space freed by the prunes made while the view is shown, in bytes
+    pub reclaimed: u64,
+    /// Selected row
+    pub table_state: ratatui::widgets::TableState,
+    /// Whether the prune confirmation is shown
+    pub confirm_prune: bool,
+}
+
+impl DiskUsageState {
+    /// The selected row
+    pub fn selected(&self) -> Option<&DiskUsageRow> {
+        self.table_state
+            .selected()
+            .and_then(|idx| self.rows.get(idx))
+    }
+}
+
+/// A Docker network with the containers attached to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkInfo {
+    pub host_id: HostId,
+    pub name: String,
+    pub driver: String,
+    /// "local", "swarm" or "global"
+    pub scope: String,
+    /// IPAM subnets in CIDR notation
+    pub subnets: Vec<String>,
+    /// Names of the attached containers (running or stopped), sorted
+    pub containers: Vec<String>,
+}
+
+/// State of the networks view
+#[derive(Debug, Default)]
+pub struct NetworkListState {
+    /// Networks of all hosts, sorted by host then name
+    pub networks: Vec<NetworkInfo>,
+    /// Hosts whose networks are still loading
+    pub loading: HashSet<HostId>,
+    /// Load errors, shown above the table
+    pub errors: Vec<String>,
+    /// Selected network
+    pub table_state: ratatui::widgets::TableState,
+}
+
+/// What happened to a container, as shown in the events timeline
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineKind {
+    Started,
+    /// Stopped, with its exit code if Docker reported one
+    Exited(Option<i64>),
+    Removed,
+    Paused,
+    Unpaused,
+    /// Renamed (previous name)
+    Renamed(String),
+    HealthChanged(HealthStatus),
+    /// An exec session started in the container (command line)
+    Exec(String),
+    /// Its last log lines were saved before an action (the file, or why they weren't)
+    LogSnapshot(Result<PathBuf, String>),
+}
+
+/// A container event in the events timeline
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub time: DateTime<Utc>,
+    pub key: ContainerKey,
+    /// Container name at the time of the event
+    pub name: String,
+    pub kind: TimelineKind,
+}
+
+/// A shell session running in a container, attached or in the background
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShellSessionInfo {
+    pub id: usize,
+    pub key: ContainerKey,
+    /// Container name when the session was started
+    pub name: String,
+    pub started_at: DateTime<Utc>,
+}
+
+/// Table format of an export
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    /// Name of the format, as shown once exported
+    pub fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Html => "HTML",
+        }
+    }
+
+    /// File extension for the format
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+        }
+    }
+}
+
+/// Where an export goes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportTarget {
+    Clipboard,
+    /// A new file in the working directory
+    File,
+}
+
+/// An entry of the export menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExportChoice {
+    pub format: ExportFormat,
+    pub target: ExportTarget,
+}
+
+impl ExportChoice {
+    /// The export menu entries, in order
+    pub const ALL: [ExportChoice; 4] = [
+        ExportChoice {
+            format: ExportFormat::Markdown,
+            target: ExportTarget::Clipboard,
+        },
+        ExportChoice {
+            format: ExportFormat::Html,
+            target: ExportTarget::Clipboard,
+        },
+        ExportChoice {
+            format: ExportFormat::Markdown,
+            target: ExportTarget::File,
+        },
+        ExportChoice {
+            format: ExportFormat::Html,
+            target: ExportTarget::File,
+        },
+    ];
+}
+
+/// Result of an export, shown as a notification
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportOutcome {
+    /// Copied to the clipboard, with the number of containers
+    Copied(ExportFormat, usize),
+    /// Written to a file, with the number of containers
+    Saved(PathBuf, usize),
+    Failed(String),
+}
+
+/// What a prune from the action menu removes on a host
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PruneTarget {
+    StoppedContainers,
+    DanglingImages,
+    UnusedVolumes,
+    UnusedNetworks,
+}
+
+impl PruneTarget {
+    /// The prune menu entries, in order
+    pub const ALL: [PruneTarget; 4] = [
+        PruneTarget::StoppedContainers,
+        PruneTarget::DanglingImages,
+        PruneTarget::UnusedVolumes,
+        PruneTarget::UnusedNetworks,
+    ];
+}
+
+/// How long a host can be put in maintenance for, in minutes, from the maintenance menu
+pub const MAINTENANCE_MINUTES: [u64; 5] = [15, 30, 60, 120, 240];
+
+/// What the copy menu copies of a container
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyTarget {
+    FullId,
+    Name,
+    /// `host:full_id`, to tell containers of different hosts apart
+    HostAndId,
+}
+
+impl CopyTarget {
+    /// The copy menu entries, in order ('y' twice copies the first)
+    pub const ALL: [CopyTarget; 3] = [CopyTarget::FullId, CopyTarget::Name, CopyTarget::HostAndId];
+
+    /// The text copied for the container
+    pub fn text(self, container: &Container) -> String {
+        match self {
+            CopyTarget::FullId => container.full_id.clone(),
+            CopyTarget::Name => container.name.clone(),
+            CopyTarget::HostAndId => format!("{}:{}", container.host_id, container.full_id),
+        }
+    }
+}
+
+/// What a bulk action does
+#[derive(Clone, Debug, PartialEq)]
+pub enum BulkKind {
+    /// A start, stop or restart of a compose project
+    Project(ProjectKey, ContainerAction),
+    /// Removing volumes from the volumes view
+    RemoveVolumes,
+    /// Removing cleanup suggestions
+    Cleanup,
+}
+
+/// Progress of an action run on many containers or objects, shown while it runs
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulkProgress {
+    pub kind: BulkKind,
+    pub total: usize,
+    pub done: usize,
+    pub failed: usize,
+    /// When it finished (every item ran, or it was stopped), to keep it shown for a while
+    pub finished_at: Option<std::time::Instant>,
+}
+
+impl BulkProgress {
+    /// Items not run yet
+    pub fn remaining(&self) -> usize {
+        self.total.saturating_sub(self.done + self.failed)
+    }
+}
+
+/// What a prune removed
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PruneReport {
+    /// Number of containers, images, volumes or networks removed
+    pub removed: usize,
+    /// Space freed in bytes (always 0 for networks)
+    pub reclaimed: u64,
+}
+
+/// What a notification in the notifications panel reports
+#[derive(Clone, Debug, PartialEq)]
+pub enum NotificationKind {
+    /// A host could not be reached
+    ConnectionError(String),
+    /// A container action failed, with the error from the Docker API
+    ActionFailed(ContainerAction, String),
+    /// A container action failed with a transient error and is tried again
+    /// (retry number, error from the Docker API)
+    ActionRetrying(ContainerAction, u32, String),
+    /// How a started or restarted container fared
+    ActionVerified(ContainerAction, VerifyOutcome),
+    /// An alert rule fired, with the value of the watched metric (None for health)
+    AlertFired(AlertCondition, Option<f64>),
+    /// A prune picked from the action menu finished
+    PruneFinished(PruneTarget, Result<PruneReport, String>),
+    /// Something fetched in the background could not be, with the error
+    FetchFailed(String),
+}
+
+impl NotificationKind {
+    /// Whether something went wrong, rather than a result to take note of
+    pub fn is_error(&self) -> bool {
+        match self {
+            NotificationKind::ConnectionError(_)
+            | NotificationKind::ActionFailed(_, _)
+            | NotificationKind::AlertFired(_, _)
+            | NotificationKind::FetchFailed(_) => true,
+            // How the retries end is reported on its own
+            NotificationKind::ActionRetrying(_, _, _) => false,
+            NotificationKind::ActionVerified(_, outcome) => !outcome.is_ok(),
+            NotificationKind::PruneFinished(_, result) => result.is_err(),
+        }
+    }
+
+    /// The error from Docker or the connection, when there is one
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            NotificationKind::ConnectionError(error)
+            | NotificationKind::ActionFailed(_, error)
+            | NotificationKind::ActionRetrying(_, _, error)
+            | NotificationKind::PruneFinished(_, Err(error))
+            | NotificationKind::FetchFailed(error) => Some(error),
+            NotificationKind::ActionVerified(_, _)
+            | NotificationKind::AlertFired(_, _)
+            | NotificationKind::PruneFinished(_, Ok(_)) => None,
+        }
+    }
+}
+
+/// An entry of the notifications panel, kept until it is cleared
+#[derive(Clone, Debug, PartialEq)]
+pub struct Notification {
+    pub time: DateTime<Utc>,
+    /// Host or container the notification is about
+    pub source: String,
+    pub kind: NotificationKind,
+    /// Seen in the panel; unacknowledged notifications are counted in the title
+    pub acknowledged: bool,
+}
+
+/// State of the error detail popup
+#[derive(Debug)]
+pub struct ErrorDetailState {
+    /// The notification shown, kept even when it is cleared from the panel
+    pub notification: Notification,
+    pub scroll_offset: usize,
+}
+
+impl ErrorDetailState {
+    /// The error split into its causes, outermost first: "Failed to restart container",
+    /// "Docker responded with status code 500", ...
+    pub fn chain(&self) -> Vec<&str> {
+        self.notification
+            .kind
+            .error()
+            .unwrap_or_default()
+            .split(": ")
+            .collect()
+    }
+
+    /// The error as copied to the clipboard, for pasting into an issue:
+    /// "2024-05-01T12:00:00Z web: Failed to restart container: ..."
+    pub fn copy_text(&self) -> String {
+        let notification = &self.notification;
+        format!(
+            "{} {}: {}",
+            notification
+                .time
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            notification.source,
+            notification.kind.error().unwrap_or_default()
+        )
+    }
+}
+
+/// State of the network picker opened from the action menu
+#[derive(Debug)]
+pub struct NetworkPickerState {
+    /// Container being connected or disconnected
+    pub container_key: ContainerKey,
+    /// ConnectNetwork or DisconnectNetwork
+    pub action: ContainerAction,
+    /// Network names to pick from (None while loading)
+    pub networks: Option<Vec<String>>,
+    /// Error from the Docker API, if loading failed
+    pub error: Option<String>,
+    /// Selected network
+    pub list_state: ratatui::widgets::ListState,
+}
+
+/// Which field of the build dialog has focus
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildField {
+    /// Dockerfile or build context path
+    Context,
+    /// Optional image tag
+    Tag,
+}
+
+/// Progress of an image build
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildStatus {
+    /// Dialog open, the user is entering the path and tag
+    Editing,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// A line of build output
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuildLine {
+    pub text: String,
+    /// Error output (highlighted in the output panel)
+    pub is_error: bool,
+}
+
+/// A line of output from a command run in a container
+#[derive(Debug, Clone)]
+pub struct ExecLine {
+    /// The line parsed like a log line (ANSI colors, JSON, level)
+    pub entry: LogEntry,
+    /// Whether the line came from stderr (highlighted in the output pane)
+    pub is_stderr: bool,
+}
+
+/// Progress of a command run in a container
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecStatus {
+    Running,
+    /// The command exited, with its exit code if the daemon reported it
+    Exited(Option<i64>),
+    /// The command could not be run
+    Failed(String),
+}
+
+/// Output of a command run in a container without a TTY
+#[derive(Debug)]
+pub struct ExecOutputState {
+    pub container_key: ContainerKey,
+    /// Command line, once the command was created
+    pub command: Option<String>,
+    pub lines: Vec<ExecLine>,
+    pub status: ExecStatus,
+    /// First visible output line
+    pub scroll_offset: usize,
+    /// Whether the output follows new lines
+    pub follow: bool,
+    /// Whether the output is shown in a popup over the container list rather than
+    /// full screen (commands typed in)
+    pub popup: bool,
+    /// Handle to the exec task (for cancellation)
+    pub handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl ExecOutputState {
+    pub fn new(container_key: ContainerKey) -> Self {
+        Self {
+            container_key,
+            command: None,
+            lines: Vec::new(),
+            status: ExecStatus::Running,
+            scroll_offset: 0,
+            follow: true,
+            popup: false,
+            handle: None,
+        }
+    }
+}
+
+/// State of the image build dialog and its output
+#[derive(Debug)]
+pub struct BuildState {
+    /// Host the image is built on
+    pub host_id: HostId,
+    /// Dockerfile or build context path input
+    pub context_input: tui_input::Input,
+    /// Image tag input (optional)
+    pub tag_input: tui_input::Input,
+    pub focus: BuildField,
+    pub status: BuildStatus,
+    /// Validation error shown in the dialog
+    pub error: Option<String>,
+    pub output: Vec<BuildLine>,
+    /// First visible output line
+    pub scroll_offset: usize,
+    /// Whether the output follows new lines
+    pub follow: bool,
+    /// Handle to the build task (for cancellation)
+    pub handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl BuildState {
+    pub fn new(host_id: HostId) -> Self {
+        Self {
+            host_id,
+            context_input: tui_input::Input::new(".".to_string()),
+            tag_input: tui_input::Input::default(),
+            focus: BuildField::Context,
+            status: BuildStatus::Editing,
+            error: None,
+            output: Vec::new(),
+            scroll_offset: 0,
+            follow: true,
+            handle: None,
         }
     }
 }
@@ -434,6 +2137,33 @@ pub struct LogState {
 
     /// Track if we're currently fetching older logs (prevent duplicate requests)
     pub fetching_older: bool,
+
+    /// Search typed after '/' in the log view
+    pub search_input: tui_input::Input,
+
+    /// Whether the search is being typed (keys are text, not shortcuts)
+    pub search_editing: bool,
+
+    /// Index of the log entry with the current match, jumped to with n/N
+    pub search_match: Option<usize>,
+
+    /// Timestamp to scroll back to once the first batch of logs arrives
+    pub restore_top: Option<DateTime<Utc>>,
+
+    /// Levels hidden by the level filter (entries without a level are always shown)
+    pub hidden_levels: HashSet<LogLevel>,
+
+    /// Fold runs of near-identical consecutive entries into their newest one
+    pub collapse_repeats: bool,
+}
+
+/// Where the log view of a container was left, restored when it is opened again
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogPosition {
+    /// Timestamp of the top visible entry (None when following the newest logs)
+    pub top_timestamp: Option<DateTime<Utc>>,
+    /// Applied log search
+    pub search: String,
 }
 
 impl LogState {
@@ -450,7 +2180,81 @@ impl LogState {
             total_loaded: 0,
             container_created_at,
             fetching_older: false,
+            search_input: tui_input::Input::default(),
+            search_editing: false,
+            search_match: None,
+            restore_top: None,
+            hidden_levels: HashSet::new(),
+            collapse_repeats: false,
+        }
+    }
+
+    /// Whether some entries are hidden by the level filter or folded as repeats
+    fn hides_entries(&self) -> bool {
+        !self.hidden_levels.is_empty() || self.collapse_repeats
+    }
+
+    fn is_repeat(&self, idx: usize, next: usize) -> bool {
+        match (self.log_entries.get(idx), self.log_entries.get(next)) {
+            (Some(entry), Some(next)) => entry.fingerprint == next.fingerprint,
+            _ => false,
+        }
+    }
+
+    /// Whether the entry at `idx` passes the level filter and isn't folded into a later repeat
+    pub fn is_shown(&self, idx: usize) -> bool {
+        let level_shown = self
+            .log_entries
+            .get(idx)
+            .and_then(|entry| entry.level)
+            .is_none_or(|level| !self.hidden_levels.contains(&level));
+        level_shown && !(self.collapse_repeats && self.is_repeat(idx, idx + 1))
+    }
+
+    /// Number of consecutive near-identical entries ending at `idx` (1 when not collapsing)
+    pub fn repeat_count(&self, idx: usize) -> usize {
+        if !self.collapse_repeats {
+            return 1;
         }
+        1 + (0..idx)
+            .rev()
+            .take_while(|&prev| self.is_repeat(prev, prev + 1))
+            .count()
+    }
+
+    /// Index `steps` shown entries away from `from` (negative is up), stopping at the ends.
+    /// Without a filter this is plain arithmetic, clamped by the log view
+    pub fn step_shown(&self, from: usize, steps: isize) -> usize {
+        if !self.hides_entries() {
+            return from.saturating_add_signed(steps);
+        }
+
+        let mut target = from;
+        for _ in 0..steps.unsigned_abs() {
+            let next = if steps < 0 {
+                (0..target).rev().find(|&idx| self.is_shown(idx))
+            } else {
+                (target + 1..self.log_entries.len()).find(|&idx| self.is_shown(idx))
+            };
+            match next {
+                Some(idx) => target = idx,
+                None => break,
+            }
+        }
+        target
+    }
+
+    /// First index of the last page of shown entries, the furthest the view can scroll
+    pub fn last_page_start(&self, height: usize) -> usize {
+        if !self.hides_entries() {
+            return self.log_entries.len().saturating_sub(height);
+        }
+        (0..self.log_entries.len())
+            .rev()
+            .filter(|&idx| self.is_shown(idx))
+            .take(height)
+            .last()
+            .unwrap_or(0)
     }
 
     /// Calculate what percentage of log history the current visible page represents
@@ -494,6 +2298,143 @@ impl LogState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_secret_env() {
+        assert!(is_secret_env("POSTGRES_PASSWORD"));
+        assert!(is_secret_env("github_token"));
+        assert!(is_secret_env("AWS_SECRET_ACCESS_KEY"));
+        assert!(is_secret_env("BASIC_AUTH"));
+        assert!(!is_secret_env("PATH"));
+        assert!(!is_secret_env("NGINX_PORT"));
+    }
+
+    #[test]
+    fn test_error_detail_copy_text() {
+        let detail = ErrorDetailState {
+            notification: Notification {
+                time: DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+                source: "web".to_string(),
+                kind: NotificationKind::ActionFailed(
+                    ContainerAction::Restart,
+                    "Failed to restart container: Docker responded with status code 500: \
+                     driver failed"
+                        .to_string(),
+                ),
+                acknowledged: false,
+            },
+            scroll_offset: 0,
+        };
+
+        assert_eq!(
+            detail.chain(),
+            vec![
+                "Failed to restart container",
+                "Docker responded with status code 500",
+                "driver failed"
+            ]
+        );
+        assert_eq!(
+            detail.copy_text(),
+            "2024-05-01T12:00:00Z web: Failed to restart container: \
+             Docker responded with status code 500: driver failed"
+        );
+    }
+
+    #[test]
+    fn test_host_resources_warnings() {
+        let resources = |used, available, load1| HostResources {
+            disk_used_bytes: used,
+            disk_available_bytes: available,
+            load1,
+            cpus: 4,
+        };
+
+        let healthy = resources(50, 50, 1.5);
+        assert_eq!(healthy.disk_used_percent(), 50.0);
+        assert!(!healthy.disk_low());
+        assert!(!healthy.load_high());
+
+        assert!(resources(90, 10, 0.0).disk_low());
+        assert!(resources(0, 0, 0.0).disk_used_percent() == 0.0);
+        assert!(resources(50, 50, 8.0).load_high());
+        assert!(!resources(50, 50, 7.9).load_high());
+    }
+
+    #[test]
+    fn test_port_mapping_url() {
+        let port = |host_ip: Option<&str>, host_port, container_port| PortMapping {
+            host_ip: host_ip.map(str::to_string),
+            host_port,
+            container_port,
+            protocol: "tcp".to_string(),
+        };
+
+        assert_eq!(port(None, 8080, 80).url("local"), "http://localhost:8080");
+        assert_eq!(
+            port(None, 8443, 443).url("server.lan"),
+            "https://server.lan:8443"
+        );
+        assert_eq!(
+            port(Some("127.0.0.1"), 3000, 3000).url("server.lan"),
+            "http://server.lan:3000"
+        );
+        assert_eq!(
+            port(Some("10.0.0.5"), 80, 80).url("server.lan"),
+            "http://10.0.0.5:80"
+        );
+        assert_eq!(
+            port(Some("fd00::5"), 80, 80).url("local"),
+            "http://[fd00::5]:80"
+        );
+
+        assert_eq!(
+            port(Some("10.0.0.5"), 8080, 80).to_string(),
+            "10.0.0.5:8080:80"
+        );
+        let dns = PortMapping {
+            protocol: "udp".to_string(),
+            ..port(None, 5353, 53)
+        };
+        assert_eq!(dns.to_string(), "5353:53/udp");
+        assert!(!dns.is_http());
+        assert!(port(None, 32768, 8080).is_http());
+    }
+
+    #[test]
+    fn test_count_history() {
+        let counts = |running, total| ContainerCounts { running, total };
+        let mut history = CountHistory::default();
+
+        history.record(counts(3, 4), 100);
+        // A crash and restart within the bucket keeps the dip
+        history.record(counts(2, 4), 100);
+        history.record(counts(3, 4), 100);
+        assert_eq!(history.samples(100), vec![counts(2, 4)]);
+        assert_eq!(history.current(), counts(3, 4));
+
+        // Quiet buckets repeat the last counts, both when recording and when reading
+        history.record(counts(5, 6), 103);
+        assert_eq!(
+            history.samples(105),
+            vec![
+                counts(2, 4),
+                counts(3, 4),
+                counts(3, 4),
+                counts(5, 6),
+                counts(5, 6),
+                counts(5, 6),
+            ]
+        );
+
+        // Capped to the history size
+        assert_eq!(history.samples(1000).len(), COUNT_HISTORY_SIZE);
+        history.record(counts(1, 1), 1000);
+        assert_eq!(history.samples(1000).len(), COUNT_HISTORY_SIZE);
+        assert_eq!(history.samples(1000).last(), Some(&counts(1, 1)));
+    }
+
     #[test]
     fn test_sort_field_from_str_full_names() {
         assert_eq!("uptime".parse::<SortField>().unwrap(), SortField::Uptime);
@@ -519,6 +2460,41 @@ mod tests {
         assert_eq!("MEM".parse::<SortField>().unwrap(), SortField::Memory);
     }
 
+    #[test]
+    fn test_sort_field_script() {
+        assert_eq!("script".parse::<SortField>().unwrap(), SortField::Script);
+        assert_eq!(SortField::Script.to_string(), "script");
+        // Script sorting is not part of the keyboard cycle
+        assert_eq!(SortField::Script.next(), SortField::Uptime);
+        assert_eq!(SortField::Memory.next(), SortField::Uptime);
+    }
+
+    #[test]
+    fn test_sort_field_restarts() {
+        assert_eq!(
+            "restarts".parse::<SortField>().unwrap(),
+            SortField::Restarts
+        );
+        assert_eq!("r".parse::<SortField>().unwrap(), SortField::Restarts);
+        assert_eq!(SortField::Restarts.to_string(), "restarts");
+        assert_eq!(SortField::Restarts.next(), SortField::Uptime);
+        assert_eq!(
+            SortField::Restarts.default_direction(),
+            SortDirection::Descending
+        );
+    }
+
+    #[test]
+    fn test_sort_field_pids() {
+        assert_eq!("pids".parse::<SortField>().unwrap(), SortField::Pids);
+        assert_eq!(SortField::Pids.to_string(), "pids");
+        assert_eq!(SortField::Pids.next(), SortField::Uptime);
+        assert_eq!(
+            SortField::Pids.default_direction(),
+            SortDirection::Descending
+        );
+    }
+
     #[test]
     fn test_sort_field_from_str_invalid() {
         assert!("invalid".parse::<SortField>().is_err());
@@ -564,4 +2540,55 @@ mod tests {
         assert_eq!(state.field, SortField::Cpu);
         assert_eq!(state.direction, SortDirection::Descending);
     }
+
+    #[test]
+    fn test_stats_shown_differently() {
+        let stats = ContainerStats {
+            cpu: 12.34,
+            memory_used_bytes: 512 * 1024 * 1024,
+            network_rx_bytes_per_sec: 0.2,
+            ..Default::default()
+        };
+
+        // Below display precision
+        let mut same = stats.clone();
+        same.cpu = 12.31;
+        same.memory_used_bytes += 1024;
+        same.network_rx_bytes_per_sec = 0.1;
+        assert!(!stats.shown_differently(&same));
+
+        let mut cpu = stats.clone();
+        cpu.cpu = 12.5;
+        assert!(stats.shown_differently(&cpu));
+
+        let mut memory = stats.clone();
+        memory.memory_used_bytes = 600 * 1024 * 1024;
+        assert!(stats.shown_differently(&memory));
+
+        // A new sparkline sample, even of the same value
+        let mut sample = stats.clone();
+        sample.history_samples += 1;
+        assert!(stats.shown_differently(&sample));
+    }
+
+    #[test]
+    fn test_memory_display() {
+        assert_eq!("used".parse::<MemoryDisplay>(), Ok(MemoryDisplay::Used));
+        assert_eq!(
+            "Limit".parse::<MemoryDisplay>(),
+            Ok(MemoryDisplay::UsedLimit)
+        );
+        assert!("bytes".parse::<MemoryDisplay>().is_err());
+
+        // Cycles back to the percentage
+        let mut display = MemoryDisplay::default();
+        for expected in [
+            MemoryDisplay::Used,
+            MemoryDisplay::UsedLimit,
+            MemoryDisplay::Percent,
+        ] {
+            display = display.next();
+            assert_eq!(display, expected);
+        }
+    }
 }
@@ -1,32 +1,387 @@
-use crate::core::types::{AppEvent, ContainerAction, ContainerKey, EventSender};
+use bollard::errors::Error;
+use bollard::query_parameters::{
+    InspectContainerOptions, PruneContainersOptions, PruneImagesOptions, PruneNetworksOptions,
+    PruneVolumesOptions,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::core::bulk::BulkRun;
+use crate::core::types::{
+    AppEvent, ContainerAction, ContainerKey, EventSender, PlanStep, PruneReport, PruneTarget,
+    VerifyOutcome,
+};
 use crate::docker::connection::DockerHost;
+use crate::docker::log_snapshot::LogSnapshots;
+use crate::docker::registry::RegistryCredentials;
+use crate::ui::i18n;
+
+/// First wait before retrying a failed action, doubled after each retry
+const RETRY_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest wait between retries of an action
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(16);
+
+/// How container actions are run, from the config
+#[derive(Clone)]
+pub struct ActionOptions {
+    /// Seconds Stop and Restart give the container before killing it
+    pub stop_timeout: u32,
+    /// Where the last log lines are saved before Stop, Restart and Remove
+    pub snapshots: Option<Arc<LogSnapshots>>,
+    /// Seconds a started or restarted container is watched for (0 to skip)
+    pub verify_secs: u64,
+    /// Times an action is tried again after a transient failure (0 to not retry)
+    pub retries: u32,
+}
+
+/// Runs the steps of a project action one after the other, the containers of a step
+/// concurrently within the bulk action's limit; a failed step stops the plan, so
+/// dependents aren't started without what they depend on
+pub async fn execute_plan(
+    host: DockerHost,
+    steps: Vec<PlanStep>,
+    options: ActionOptions,
+    bulk: BulkRun,
+    tx: EventSender,
+) {
+    for step in steps {
+        // Stopped while the previous step ran
+        if bulk.is_stopped() {
+            return;
+        }
+        let errors = bulk
+            .run(step.containers, |key| {
+                let succeeded = execute_container_action(
+                    host.clone(),
+                    key,
+                    step.action,
+                    options.clone(),
+                    tx.clone(),
+                );
+                // The error itself is reported by the action
+                async move { succeeded.await.then_some(()).ok_or_else(String::new) }
+            })
+            .await;
+        if !errors.is_empty() {
+            bulk.stop().await;
+            return;
+        }
+    }
+}
 
-/// Executes a container action asynchronously
+/// Executes a container action asynchronously, returning whether it succeeded
+/// Stop and Restart give the container the stop timeout before killing it; with
+/// snapshots, its last log lines are saved before Stop, Restart and Remove.
+/// A transient failure is retried with backoff, each retry reported.
+/// A started or restarted container is then watched for a while
 pub async fn execute_container_action(
     host: DockerHost,
     container_key: ContainerKey,
     action: ContainerAction,
+    options: ActionOptions,
     tx: EventSender,
-) {
+) -> bool {
     // Send in-progress event
     let _ = tx
         .send(AppEvent::ActionInProgress(container_key.clone(), action))
         .await;
 
-    // Execute the action using DockerHost methods
-    let result = match action {
-        ContainerAction::Start => host.start_container(&container_key.container_id).await,
-        ContainerAction::Stop => host.stop_container(&container_key.container_id).await,
-        ContainerAction::Restart => host.restart_container(&container_key.container_id).await,
-        ContainerAction::Remove => host.remove_container(&container_key.container_id).await,
-        ContainerAction::Shell => {
-            // Shell is handled separately in main.rs via StartShell event
-            // This path should never be reached
-            return;
+    // A failed snapshot is reported but doesn't hold back the action
+    if let Some(snapshots) = &options.snapshots
+        && matches!(
+            action,
+            ContainerAction::Stop | ContainerAction::Restart | ContainerAction::Remove
+        )
+    {
+        let result = snapshots.capture(&host, &container_key).await;
+        let _ = tx
+            .send(AppEvent::LogSnapshotTaken(container_key.clone(), result))
+            .await;
+    }
+
+    let msg = i18n::messages();
+    let mut attempt = 0;
+    let result = loop {
+        // Execute the action using DockerHost methods
+        let container_id = &container_key.container_id;
+        let result = match action {
+            ContainerAction::Start => host.start_container(container_id).await,
+            ContainerAction::Stop => {
+                host.stop_container(container_id, options.stop_timeout)
+                    .await
+            }
+            ContainerAction::Restart => {
+                host.restart_container(container_id, options.stop_timeout)
+                    .await
+            }
+            ContainerAction::Remove => host.remove_container(container_id).await,
+            ContainerAction::Shell
+            | ContainerAction::ExecCommand
+            | ContainerAction::BrowseFiles
+            | ContainerAction::RunHealthcheck
+            | ContainerAction::PullImage
+            | ContainerAction::PushImage
+            | ContainerAction::ConnectNetwork
+            | ContainerAction::DisconnectNetwork
+            | ContainerAction::RestartUnit
+            | ContainerAction::Prune
+            | ContainerAction::Maintenance => {
+                // Shell is handled separately in main.rs via StartShell event,
+                // the health check by docker::exec, the file browser by docker::files, image, network and prune actions
+                // by their own functions below, the systemd unit by docker::systemd, maintenance by AppState
+                // This path should never be reached
+                return false;
+            }
+        };
+
+        match result {
+            Err(e) if attempt < options.retries && is_transient(&e) => {
+                let delay = retry_delay(attempt);
+                attempt += 1;
+                let _ = tx
+                    .send(AppEvent::ActionRetrying(
+                        container_key.clone(),
+                        action,
+                        attempt,
+                        e.to_string(),
+                    ))
+                    .await;
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) if attempt > 0 => {
+                break Err(i18n::fill(
+                    msg.action_error_attempts,
+                    &[&msg.action(action), &(attempt + 1), &e],
+                ));
+            }
+            result => {
+                break result.map_err(|e| i18n::fill(msg.action_error, &[&msg.action(action), &e]));
+            }
         }
     };
 
-    // Send result event
+    let succeeded = result.is_ok();
+    if succeeded
+        && options.verify_secs > 0
+        && matches!(action, ContainerAction::Start | ContainerAction::Restart)
+    {
+        // Watch in the background, so that a plan's next step doesn't wait for it
+        tokio::spawn(verify_container_action(
+            host,
+            container_key.clone(),
+            action,
+            options.verify_secs,
+            tx.clone(),
+        ));
+    }
+    send_action_result(container_key, action, result, &tx).await;
+    succeeded
+}
+
+/// Whether a failed action may succeed when tried again: the daemon answered with a
+/// server error (e.g. busy) or couldn't be reached in time
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::DockerResponseServerError { status_code, .. } => *status_code >= 500,
+        Error::RequestTimeoutError
+        | Error::IOError { .. }
+        | Error::HyperResponseError { .. }
+        | Error::HyperLegacyError { .. } => true,
+        _ => false,
+    }
+}
+
+/// Wait before retrying an action the `attempt`th time (from 0), doubled after each
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_INITIAL_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY)
+}
+
+/// Checks a started or restarted container every second for `verify_secs` seconds,
+/// reporting as soon as it goes down, or whether it is still up (and healthy) at the end
+/// Nothing is reported when the host can't be asked
+async fn verify_container_action(
+    host: DockerHost,
+    container_key: ContainerKey,
+    action: ContainerAction,
+    verify_secs: u64,
+    tx: EventSender,
+) {
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    let started = Instant::now();
+    let container_id = &container_key.container_id;
+    let mut restart_count = None;
+
+    let outcome = loop {
+        let secs = started.elapsed().as_secs();
+        let inspect = match host
+            .docker
+            .inspect_container(container_id, None::<InspectContainerOptions>)
+            .await
+        {
+            Ok(inspect) => inspect,
+            // Removed meanwhile, e.g. started with --rm
+            Err(Error::DockerResponseServerError {
+                status_code: 404, ..
+            }) => {
+                break VerifyOutcome::Crashed {
+                    secs,
+                    exit_code: None,
+                };
+            }
+            Err(e) => {
+                tracing::debug!("Could not verify {:?} of {}: {}", action, container_id, e);
+                return;
+            }
+        };
+
+        // A restart policy brings a crashed container back up, bumping its restart count
+        let count = inspect.restart_count.unwrap_or(0);
+        let restarted = restart_count.is_some_and(|previous| count > previous);
+        restart_count = Some(count);
+
+        let state = inspect.state.unwrap_or_default();
+        if !state.running.unwrap_or(false) || restarted {
+            let exit_code = if restarted { None } else { state.exit_code };
+            break VerifyOutcome::Crashed { secs, exit_code };
+        }
+
+        if secs >= verify_secs {
+            let health = state
+                .health
+                .and_then(|h| h.status)
+                .and_then(|status| format!("{:?}", status).parse().ok());
+            break VerifyOutcome::StayedUp {
+                secs: verify_secs,
+                health,
+            };
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    };
+
+    let _ = tx
+        .send(AppEvent::ActionVerified(container_key, action, outcome))
+        .await;
+}
+
+/// Pulls or pushes a container's image asynchronously, with credentials for its registry
+pub async fn execute_image_action(
+    host: DockerHost,
+    container_key: ContainerKey,
+    action: ContainerAction,
+    image: String,
+    credentials: Arc<RegistryCredentials>,
+    tx: EventSender,
+) {
+    let _ = tx
+        .send(AppEvent::ActionInProgress(container_key.clone(), action))
+        .await;
+
+    let registry_auth = credentials.for_image(&image);
+    let result = match action {
+        ContainerAction::PullImage => host.pull_image(&image, registry_auth).await,
+        ContainerAction::PushImage => host.push_image(&image, registry_auth).await,
+        _ => return,
+    };
+
+    send_action_result(container_key, action, result, &tx).await;
+}
+
+/// Connects or disconnects a container to/from a network asynchronously
+pub async fn execute_network_action(
+    host: DockerHost,
+    container_key: ContainerKey,
+    action: ContainerAction,
+    network: String,
+    tx: EventSender,
+) {
+    let _ = tx
+        .send(AppEvent::ActionInProgress(container_key.clone(), action))
+        .await;
+
+    let container_id = &container_key.container_id;
+    let result = match action {
+        ContainerAction::ConnectNetwork => host.connect_network(container_id, &network).await,
+        ContainerAction::DisconnectNetwork => host.disconnect_network(container_id, &network).await,
+        _ => return,
+    };
+
+    send_action_result(container_key, action, result, &tx).await;
+}
+
+/// Prunes the target on the host in the background, reporting what was removed
+pub async fn execute_prune(host: DockerHost, target: PruneTarget, tx: EventSender) {
+    let result = prune(&host, target).await;
+    let _ = tx
+        .send(AppEvent::PruneFinished(host.host_id, target, result))
+        .await;
+}
+
+/// Removes what the target covers, like the matching `docker ... prune`
+/// Images are counted as the daemon reports them deleted, layers included
+async fn prune(host: &DockerHost, target: PruneTarget) -> Result<PruneReport, String> {
+    let docker = &host.docker;
+    let count = |items: Option<Vec<String>>| items.map_or(0, |items| items.len());
+    let reclaimed = |bytes: Option<i64>| bytes.unwrap_or(0).max(0) as u64;
+
+    let report =
+        match target {
+            PruneTarget::StoppedContainers => docker
+                .prune_containers(None::<PruneContainersOptions>)
+                .await
+                .map(|response| PruneReport {
+                    removed: count(response.containers_deleted),
+                    reclaimed: reclaimed(response.space_reclaimed),
+                }),
+            PruneTarget::DanglingImages => docker
+                .prune_images(None::<PruneImagesOptions>)
+                .await
+                .map(|response| PruneReport {
+                    removed: response
+                        .images_deleted
+                        .unwrap_or_default()
+                        .iter()
+                        .filter(|item| item.deleted.is_some())
+                        .count(),
+                    reclaimed: reclaimed(response.space_reclaimed),
+                }),
+            // Named volumes too; by default only anonymous ones are pruned
+            PruneTarget::UnusedVolumes => docker
+                .prune_volumes(Some(PruneVolumesOptions {
+                    filters: Some(HashMap::from([(
+                        "all".to_string(),
+                        vec!["true".to_string()],
+                    )])),
+                }))
+                .await
+                .map(|response| PruneReport {
+                    removed: count(response.volumes_deleted),
+                    reclaimed: reclaimed(response.space_reclaimed),
+                }),
+            PruneTarget::UnusedNetworks => docker
+                .prune_networks(None::<PruneNetworksOptions>)
+                .await
+                .map(|response| PruneReport {
+                    removed: count(response.networks_deleted),
+                    reclaimed: 0,
+                }),
+        };
+    report.map_err(|e| i18n::fill(i18n::messages().prune_error, &[&e]))
+}
+
+/// Sends the success or error event for a finished action
+async fn send_action_result(
+    container_key: ContainerKey,
+    action: ContainerAction,
+    result: Result<(), String>,
+    tx: &EventSender,
+) {
     match result {
         Ok(_) => {
             let _ = tx
@@ -40,3 +395,30 @@ pub async fn execute_container_action(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient() {
+        let server_error = |status_code| Error::DockerResponseServerError {
+            status_code,
+            message: "error".to_string(),
+        };
+
+        assert!(is_transient(&server_error(500)));
+        assert!(is_transient(&server_error(503)));
+        assert!(is_transient(&Error::RequestTimeoutError));
+        // Won't change by trying again
+        assert!(!is_transient(&server_error(404)));
+        assert!(!is_transient(&server_error(409)));
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0), Duration::from_secs(1));
+        assert_eq!(retry_delay(2), Duration::from_secs(4));
+        assert_eq!(retry_delay(10), RETRY_MAX_DELAY);
+    }
+}
@@ -0,0 +1,484 @@
+//! Image builds through the Docker build endpoint
+//!
+//! The build context is read from the local filesystem and uploaded as a tar
+//! archive, so it works the same against remote hosts as `docker build` does.
+//! The archive is streamed while it is written, and leaves out what the context's
+//! .dockerignore excludes.
+
+use bollard::body_try_stream;
+use bollard::models::BuildInfo;
+use bollard::query_parameters::{BuildImageOptions, BuildImageOptionsBuilder};
+use futures_util::stream::StreamExt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tokio_util::io::{ReaderStream, SyncIoBridge};
+
+use crate::core::types::{AppEvent, BuildLine, EventSender};
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Size of the pipe the archive is written into while it is uploaded
+const ARCHIVE_PIPE_SIZE: usize = 64 * 1024;
+
+/// Build context directory and Dockerfile (relative to the context) for a build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildContext {
+    pub directory: PathBuf,
+    pub dockerfile: String,
+}
+
+/// Resolves a path to a build context: either a directory containing a Dockerfile,
+/// or a Dockerfile whose parent directory is the context
+pub fn resolve_build_context(input: &str) -> Result<BuildContext, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(i18n::messages().build_path_missing.to_string());
+    }
+
+    // Expand ~ like a shell would
+    let path = match input.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .ok_or_else(|| i18n::messages().home_dir_unknown.to_string())?
+            .join(rest),
+        None => PathBuf::from(input),
+    };
+
+    if path.is_dir() {
+        if !path.join("Dockerfile").is_file() {
+            return Err(i18n::fill(
+                i18n::messages().no_dockerfile,
+                &[&path.display()],
+            ));
+        }
+        return Ok(BuildContext {
+            directory: path,
+            dockerfile: "Dockerfile".to_string(),
+        });
+    }
+
+    if path.is_file() {
+        let dockerfile = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| {
+                i18n::fill(i18n::messages().invalid_dockerfile_path, &[&path.display()])
+            })?;
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        return Ok(BuildContext {
+            directory,
+            dockerfile,
+        });
+    }
+
+    Err(i18n::fill(
+        i18n::messages().path_not_found,
+        &[&path.display()],
+    ))
+}
+
+/// Builds an image on the host, sending output lines and the result as events
+pub async fn build_image(
+    host: DockerHost,
+    context: BuildContext,
+    tag: Option<String>,
+    tx: EventSender,
+) {
+    let result = run_build(&host, context, tag, &tx).await;
+    let _ = tx.send(AppEvent::BuildFinished(result)).await;
+}
+
+async fn run_build(
+    host: &DockerHost,
+    context: BuildContext,
+    tag: Option<String>,
+    tx: &EventSender,
+) -> Result<(), String> {
+    let mut options = BuildImageOptionsBuilder::default()
+        .dockerfile(&context.dockerfile)
+        .rm(true);
+    if let Some(tag) = &tag {
+        options = options.t(tag);
+    }
+
+    // The archive is written on a blocking thread and read by the upload as it goes
+    let (reader, writer) = tokio::io::duplex(ARCHIVE_PIPE_SIZE);
+    let writer = SyncIoBridge::new(writer);
+    let directory = context.directory.clone();
+    let dockerfile = context.dockerfile.clone();
+    let archiving =
+        tokio::task::spawn_blocking(move || archive_context(&directory, &dockerfile, writer));
+
+    let result = stream_build(host, options.build(), ReaderStream::new(reader), tx).await;
+
+    // A failed archive is why the build failed, unless the upload ended first
+    match archiving.await {
+        Ok(Err(e)) if e.kind() != io::ErrorKind::BrokenPipe => Err(i18n::fill(
+            i18n::messages().archive_failed,
+            &[&context.directory.display(), &e],
+        )),
+        Err(e) => Err(i18n::fill(i18n::messages().archive_context_failed, &[&e])),
+        _ => result,
+    }
+}
+
+/// Uploads the archive and follows the build, sending its output lines
+async fn stream_build(
+    host: &DockerHost,
+    options: BuildImageOptions,
+    archive: ReaderStream<tokio::io::DuplexStream>,
+    tx: &EventSender,
+) -> Result<(), String> {
+    let mut stream = host
+        .docker
+        .build_image(options, None, Some(body_try_stream(archive)));
+
+    let mut build_error = None;
+    while let Some(item) = stream.next().await {
+        let info = item.map_err(|e| i18n::fill(i18n::messages().build_error, &[&e]))?;
+
+        for line in build_info_lines(&info) {
+            let _ = tx.send(AppEvent::BuildOutput(line)).await;
+        }
+
+        if let Some(message) = info.error_detail.and_then(|detail| detail.message) {
+            build_error = Some(message);
+        }
+    }
+
+    match build_error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Packs the context directory into an uncompressed tar archive written to `out`,
+/// without what .dockerignore excludes; the Dockerfile and .dockerignore are always sent
+fn archive_context(directory: &Path, dockerfile: &str, out: impl Write) -> io::Result<()> {
+    let ignore = DockerIgnore::load(directory)?;
+    let always_sent = [dockerfile, ".dockerignore"];
+    let mut builder = tar::Builder::new(io::BufWriter::with_capacity(ARCHIVE_PIPE_SIZE, out));
+    append_context_dir(&mut builder, directory, &[], &ignore, &always_sent)?;
+    builder.into_inner()?.flush()
+}
+
+/// Appends the entries of a context subdirectory (`relative` to the context), sorted by
+/// name so the archive is the same from one build to the next
+fn append_context_dir<W: Write>(
+    builder: &mut tar::Builder<W>,
+    directory: &Path,
+    relative: &[String],
+    ignore: &DockerIgnore,
+    always_sent: &[&str],
+) -> io::Result<()> {
+    let mut entries =
+        std::fs::read_dir(directory.join(relative.join("/")))?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let mut segments = relative.to_vec();
+        segments.push(entry.file_name().to_string_lossy().into_owned());
+        let name = segments.join("/");
+        let excluded = ignore.excludes(&name) && !always_sent.contains(&name.as_str());
+
+        if entry.file_type()?.is_dir() {
+            if !excluded {
+                builder.append_dir(&name, entry.path())?;
+            }
+            // An exception may bring back something inside an excluded directory
+            if !excluded || ignore.has_exceptions() {
+                append_context_dir(builder, directory, &segments, ignore, always_sent)?;
+            }
+        } else if !excluded {
+            builder.append_path_with_name(entry.path(), &name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Patterns of a context's .dockerignore, matched like `docker build` does: the last
+/// matching pattern wins, `!` re-includes, `**` spans directories, and excluding a
+/// directory excludes everything in it
+#[derive(Debug, Default)]
+struct DockerIgnore {
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Debug)]
+struct IgnorePattern {
+    /// Path segments, each a glob
+    segments: Vec<String>,
+    /// A `!` pattern, re-including what earlier patterns excluded
+    exception: bool,
+}
+
+impl DockerIgnore {
+    /// Reads the .dockerignore of a context directory, if there is one
+    fn load(directory: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(directory.join(".dockerignore")) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse(contents: &str) -> Self {
+        let patterns = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (exception, pattern) = match line.strip_prefix('!') {
+                    Some(pattern) => (true, pattern.trim()),
+                    None => (false, line),
+                };
+                let segments: Vec<String> = pattern
+                    .split('/')
+                    .filter(|segment| !segment.is_empty() && *segment != ".")
+                    .map(String::from)
+                    .collect();
+                (!segments.is_empty()).then_some(IgnorePattern {
+                    segments,
+                    exception,
+                })
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    fn has_exceptions(&self) -> bool {
+        self.patterns.iter().any(|pattern| pattern.exception)
+    }
+
+    /// Whether a path relative to the context, with / separators, is left out
+    fn excludes(&self, path: &str) -> bool {
+        let path: Vec<&str> = path.split('/').collect();
+        self.patterns
+            .iter()
+            .rev()
+            .find(|pattern| pattern.matches(&path))
+            .is_some_and(|pattern| !pattern.exception)
+    }
+}
+
+impl IgnorePattern {
+    /// Whether the pattern matches the path or one of its parent directories
+    fn matches(&self, path: &[&str]) -> bool {
+        (1..=path.len()).any(|len| match_segments(&self.segments, &path[..len]))
+    }
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| match_segments(rest, &path[skip..]))
+        }
+        Some((first, rest)) => path.split_first().is_some_and(|(segment, path)| {
+            glob_match(first, segment) && match_segments(rest, path)
+        }),
+    }
+}
+
+/// Matches a path segment against a glob with `*`, `?`, `[...]` classes and `\` escapes
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    glob_chars(&pattern, &text)
+}
+
+fn glob_chars(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len()).any(|skip| glob_chars(&pattern[1..], &text[skip..])),
+        Some('?') => !text.is_empty() && glob_chars(&pattern[1..], &text[1..]),
+        Some('[') if pattern.contains(&']') => {
+            let end = pattern.iter().position(|&c| c == ']').unwrap_or_default();
+            text.first()
+                .is_some_and(|&c| class_matches(&pattern[1..end], c))
+                && glob_chars(&pattern[end + 1..], &text[1..])
+        }
+        Some('\\') if pattern.len() > 1 => {
+            text.first() == Some(&pattern[1]) && glob_chars(&pattern[2..], &text[1..])
+        }
+        Some(c) => text.first() == Some(c) && glob_chars(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Whether a character is in a `[...]` class (without the brackets), `^` or `!` negating it
+fn class_matches(class: &[char], c: char) -> bool {
+    let (negated, class) = match class.first() {
+        Some('^' | '!') => (true, &class[1..]),
+        _ => (false, class),
+    };
+    let mut found = false;
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            found |= (class[i]..=class[i + 2]).contains(&c);
+            i += 3;
+        } else {
+            found |= class[i] == c;
+            i += 1;
+        }
+    }
+    found != negated
+}
+
+/// Converts a build stream message into output lines
+fn build_info_lines(info: &BuildInfo) -> Vec<BuildLine> {
+    let mut lines = Vec::new();
+
+    if let Some(stream) = &info.stream {
+        lines.extend(
+            stream
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| BuildLine {
+                    text: l.trim_end().to_string(),
+                    is_error: is_error_line(l),
+                }),
+        );
+    }
+
+    // Status messages come from base image pulls; skip the per-layer progress updates
+    if let Some(status) = &info.status
+        && info.progress_detail.is_none()
+    {
+        lines.push(BuildLine {
+            text: status.clone(),
+            is_error: false,
+        });
+    }
+
+    if let Some(message) = info.error_detail.as_ref().and_then(|d| d.message.as_ref()) {
+        lines.push(BuildLine {
+            text: message.clone(),
+            is_error: true,
+        });
+    }
+
+    lines
+}
+
+/// Whether a line of build output reports an error
+fn is_error_line(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("ERROR")
+        || line.starts_with("error:")
+        || line.contains("returned a non-zero code")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::ErrorDetail;
+
+    #[test]
+    fn test_resolve_build_context() {
+        let dir = std::env::temp_dir().join(format!("dtop-build-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Directory without a Dockerfile
+        assert!(resolve_build_context(dir.to_str().unwrap()).is_err());
+
+        std::fs::write(dir.join("Dockerfile"), "FROM scratch\n").unwrap();
+        std::fs::write(dir.join("Dockerfile.dev"), "FROM scratch\n").unwrap();
+
+        let context = resolve_build_context(dir.to_str().unwrap()).unwrap();
+        assert_eq!(context.directory, dir);
+        assert_eq!(context.dockerfile, "Dockerfile");
+
+        let context = resolve_build_context(dir.join("Dockerfile.dev").to_str().unwrap()).unwrap();
+        assert_eq!(context.directory, dir);
+        assert_eq!(context.dockerfile, "Dockerfile.dev");
+
+        assert!(resolve_build_context(dir.join("missing").to_str().unwrap()).is_err());
+        assert!(resolve_build_context("  ").is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dockerignore_patterns() {
+        let ignore = DockerIgnore::parse(
+            "# comment\n.git\n/target\n**/node_modules\n*.sec[r-t]et\n\n*.md\n!README.md\n",
+        );
+        assert!(ignore.excludes(".git"));
+        assert!(ignore.excludes(".git/config"));
+        assert!(ignore.excludes("target/debug/dtop"));
+        assert!(ignore.excludes("web/app/node_modules/left-pad/index.js"));
+        assert!(ignore.excludes("node_modules"));
+        assert!(ignore.excludes("prod.secret"));
+        assert!(ignore.excludes("CHANGELOG.md"));
+        assert!(!ignore.excludes("README.md"));
+        assert!(!ignore.excludes("docs/CHANGELOG.md"));
+        assert!(!ignore.excludes("src/target"));
+        assert!(!ignore.excludes("prod.secxet"));
+        assert!(ignore.has_exceptions());
+
+        assert!(!DockerIgnore::default().excludes(".git"));
+    }
+
+    #[test]
+    fn test_archive_context_honors_dockerignore() {
+        let dir = std::env::temp_dir().join(format!("dtop-build-archive-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::create_dir_all(dir.join("target/debug")).unwrap();
+        std::fs::write(dir.join("Dockerfile"), "FROM scratch\n").unwrap();
+        std::fs::write(
+            dir.join(".dockerignore"),
+            ".git\ntarget\n*.env\nDockerfile\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join(".git/HEAD"), "ref: refs/heads/main\n").unwrap();
+        std::fs::write(dir.join("src/main.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(dir.join("target/debug/app"), "binary").unwrap();
+        std::fs::write(dir.join("prod.env"), "TOKEN=secret\n").unwrap();
+
+        let mut archive = Vec::new();
+        archive_context(&dir, "Dockerfile", &mut archive).unwrap();
+        let names: Vec<String> = tar::Archive::new(archive.as_slice())
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().display().to_string())
+            .collect();
+        assert_eq!(names, [".dockerignore", "Dockerfile", "src", "src/main.rs"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_build_info_lines() {
+        let info = BuildInfo {
+            stream: Some("Step 2/3 : RUN make\n\n".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_info_lines(&info),
+            vec![BuildLine {
+                text: "Step 2/3 : RUN make".to_string(),
+                is_error: false,
+            }]
+        );
+
+        let info = BuildInfo {
+            error_detail: Some(ErrorDetail {
+                code: Some(2),
+                message: Some(
+                    "The command '/bin/sh -c make' returned a non-zero code: 2".to_string(),
+                ),
+            }),
+            ..Default::default()
+        };
+        let lines = build_info_lines(&info);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].is_error);
+
+        assert!(is_error_line("ERROR: failed to solve"));
+        assert!(!is_error_line(" ---> Running in 3f2a1b"));
+    }
+}
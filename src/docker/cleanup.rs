@@ -0,0 +1,208 @@
+//! Cleanup suggestions: long exited containers, dangling images and unused volumes
+//!
+//! Sizes are estimates: a container frees its writable layer, an image the layers no
+//! other image shares (its full size is reported), a volume its data when the driver
+//! reports a size.
+
+use bollard::query_parameters::{
+    InspectContainerOptions, ListContainersOptions, ListImagesOptions, RemoveImageOptions,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::HashMap;
+
+use crate::core::bulk::BulkRun;
+use crate::core::types::{CleanupCandidate, CleanupKind};
+use crate::docker::connection::DockerHost;
+use crate::docker::host_resources::PROBE_LABEL;
+use crate::docker::volumes::{fetch_volumes, remove_volumes};
+use crate::ui::i18n;
+
+/// Lists what could be removed from a host: containers exited more than `exited_days`
+/// days ago, dangling images and volumes no container uses
+pub async fn fetch_cleanup_candidates(
+    host: &DockerHost,
+    exited_days: u64,
+) -> Result<Vec<CleanupCandidate>, String> {
+    let mut candidates = fetch_exited_containers(host, exited_days).await?;
+    candidates.extend(fetch_dangling_images(host).await?);
+    candidates.extend(
+        fetch_volumes(host)
+            .await?
+            .into_iter()
+            .filter(|volume| volume.is_orphaned())
+            .map(|volume| CleanupCandidate {
+                host_id: volume.host_id,
+                kind: CleanupKind::UnusedVolume,
+                id: volume.name.clone(),
+                name: volume.name,
+                since: None,
+                size: volume.size,
+            }),
+    );
+
+    Ok(candidates)
+}
+
+/// Exited containers that stopped more than `exited_days` days ago, with their writable
+/// layer size
+async fn fetch_exited_containers(
+    host: &DockerHost,
+    exited_days: u64,
+) -> Result<Vec<CleanupCandidate>, String> {
+    let containers = host
+        .docker
+        .list_containers(Some(ListContainersOptions {
+            all: true,
+            size: true,
+            filters: Some(HashMap::from([(
+                "status".to_string(),
+                vec!["exited".to_string()],
+            )])),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_containers_failed, &[&e]))?;
+
+    let now = Utc::now();
+    let mut candidates = Vec::new();
+    for container in containers {
+        let Some(id) = container.id else {
+            continue;
+        };
+        if container
+            .labels
+            .as_ref()
+            .is_some_and(|labels| labels.contains_key(PROBE_LABEL))
+        {
+            continue;
+        }
+
+        // The list only has the creation time, the exit time needs an inspect
+        let finished_at = host
+            .docker
+            .inspect_container(&id, None::<InspectContainerOptions>)
+            .await
+            .ok()
+            .and_then(|inspect| inspect.state)
+            .and_then(|state| state.finished_at)
+            .and_then(|finished_at| DateTime::parse_from_rfc3339(&finished_at).ok())
+            .map(|finished_at| finished_at.with_timezone(&Utc));
+        if !exited_long_ago(finished_at, now, exited_days) {
+            continue;
+        }
+
+        let name = container
+            .names
+            .and_then(|names| names.into_iter().next())
+            .map(|name| name.trim_start_matches('/').to_string())
+            .unwrap_or_else(|| id[..12.min(id.len())].to_string());
+        candidates.push(CleanupCandidate {
+            host_id: host.host_id.clone(),
+            kind: CleanupKind::ExitedContainer,
+            id,
+            name,
+            since: finished_at,
+            size: container.size_rw,
+        });
+    }
+
+    Ok(candidates)
+}
+
+/// Untagged images that no other image is built on
+async fn fetch_dangling_images(host: &DockerHost) -> Result<Vec<CleanupCandidate>, String> {
+    let images = host
+        .docker
+        .list_images(Some(ListImagesOptions {
+            filters: Some(HashMap::from([(
+                "dangling".to_string(),
+                vec!["true".to_string()],
+            )])),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_images_failed, &[&e]))?;
+
+    Ok(images
+        .into_iter()
+        .map(|image| {
+            let short_id = image.id.trim_start_matches("sha256:");
+            CleanupCandidate {
+                host_id: host.host_id.clone(),
+                kind: CleanupKind::DanglingImage,
+                name: short_id[..12.min(short_id.len())].to_string(),
+                since: DateTime::from_timestamp(image.created, 0),
+                size: (image.size >= 0).then_some(image.size),
+                id: image.id,
+            }
+        })
+        .collect())
+}
+
+/// Whether a container exited more than `days` days before `now`
+/// (an unknown exit time never qualifies)
+fn exited_long_ago(finished_at: Option<DateTime<Utc>>, now: DateTime<Utc>, days: u64) -> bool {
+    finished_at.is_some_and(|finished_at| now - finished_at > TimeDelta::days(days as i64))
+}
+
+/// Removes candidates of a host as part of a bulk action, returning an error message for
+/// each one that could not be removed. Docker refuses to remove an image or volume that got
+/// used since the list was loaded, so those are kept
+/// Kinds go one after the other, so images and volumes are only removed once the
+/// containers that may have used them are gone
+pub async fn remove_cleanup_candidates(
+    host: &DockerHost,
+    candidates: &[CleanupCandidate],
+    bulk: &BulkRun,
+) -> Vec<String> {
+    let of_kind = |kind: CleanupKind| candidates.iter().filter(move |c| c.kind == kind);
+
+    let mut errors = bulk
+        .run(
+            of_kind(CleanupKind::ExitedContainer),
+            |candidate| async move {
+                host.remove_container(&candidate.id).await.map_err(|e| {
+                    i18n::fill(
+                        i18n::messages().remove_container_failed,
+                        &[&candidate.name, &e],
+                    )
+                })
+            },
+        )
+        .await;
+    errors.extend(
+        bulk.run(
+            of_kind(CleanupKind::DanglingImage),
+            |candidate| async move {
+                host.docker
+                    .remove_image(&candidate.id, None::<RemoveImageOptions>, None)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| {
+                        i18n::fill(i18n::messages().remove_image_failed, &[&candidate.name, &e])
+                    })
+            },
+        )
+        .await,
+    );
+    let volumes: Vec<String> = of_kind(CleanupKind::UnusedVolume)
+        .map(|candidate| candidate.id.clone())
+        .collect();
+    errors.extend(remove_volumes(host, &volumes, bulk).await);
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exited_long_ago() {
+        let now = Utc::now();
+        assert!(exited_long_ago(Some(now - TimeDelta::days(8)), now, 7));
+        assert!(!exited_long_ago(Some(now - TimeDelta::days(6)), now, 7));
+        assert!(!exited_long_ago(None, now, 7));
+        // 0 days suggests every exited container
+        assert!(exited_long_ago(Some(now - TimeDelta::seconds(1)), now, 0));
+    }
+}
@@ -1,14 +1,44 @@
+use bollard::auth::DockerCredentials;
 use bollard::query_parameters::{EventsOptions, InspectContainerOptions, ListContainersOptions};
 use bollard::{API_DEFAULT_VERSION, Docker};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use futures_util::stream::StreamExt;
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::core::types::{
     AppEvent, Container, ContainerKey, ContainerState, ContainerStats, EventSender, HostId,
+    ImageLayer, ImagePlatform, PortMapping,
 };
-use crate::docker::stats::stream_container_stats;
+use crate::docker::events::{
+    EventCoalescer, LastAction, PendingEvent, exec_command, is_healthcheck_exec,
+};
+use crate::docker::host_info::monitor_host_info;
+use crate::docker::host_resources::{PROBE_LABEL, monitor_host_resources};
+use crate::docker::registry::split_image_tag;
+use crate::docker::stats::{StatsPolling, poll_container_stats, stream_container_stats};
+use crate::docker::stats_share::StatsShare;
+use crate::docker::status::parse_status;
+use crate::docker::systemd::CommandHost;
+use crate::ui::i18n;
+
+/// Label set by Docker Compose on the containers of a project
+const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
+const COMPOSE_SERVICE_LABEL: &str = "com.docker.compose.service";
+const COMPOSE_DEPENDS_ON_LABEL: &str = "com.docker.compose.depends_on";
+
+/// Attributes of a container event that are not labels
+const EVENT_ATTRIBUTES: [&str; 3] = ["name", "image", "exitCode"];
+
+/// Delays before retrying a failed inspect of a started container
+const INSPECT_RETRY_DELAYS: [Duration; 2] =
+    [Duration::from_millis(100), Duration::from_millis(300)];
+
+/// First wait before retrying a lost host, doubled after each failed attempt
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest wait between retries of a lost host
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
 
 /// Represents a Docker host connection with its identifier
 #[derive(Clone, Debug)]
@@ -17,6 +47,22 @@ pub struct DockerHost {
     pub docker: Docker,
     pub dozzle_url: Option<String>,
     pub filters: HashMap<String, Vec<String>>,
+    /// Whether exec sessions are reported for the events timeline
+    pub exec_events: bool,
+    /// Whether the daemon runs Windows containers (shell and health check defaults)
+    pub windows: bool,
+    /// CPU architecture of the daemon (e.g. amd64), to spot images run emulated
+    pub architecture: Option<String>,
+    /// Whether the data-root disk space and load are measured periodically
+    pub disk_check: bool,
+    /// Where systemd units managing containers can be restarted (None for TCP/TLS hosts)
+    pub command_host: Option<CommandHost>,
+    /// Where stats streams are shared with other dtop instances (None when not configured)
+    pub stats_share: Option<StatsShare>,
+    /// Shells tried for shell sessions (None for the defaults)
+    pub shells: Option<Vec<String>>,
+    /// One-shot stats polling instead of a stream per container (None to stream)
+    pub stats_polling: Option<StatsPolling>,
 }
 
 impl DockerHost {
@@ -31,6 +77,14 @@ impl DockerHost {
             docker,
             dozzle_url,
             filters,
+            exec_events: false,
+            windows: false,
+            architecture: None,
+            disk_check: false,
+            command_host: None,
+            stats_share: None,
+            shells: None,
+            stats_polling: None,
         }
     }
 
@@ -54,10 +108,19 @@ impl DockerHost {
 
         if let Ok(container_list) = self.docker.list_containers(list_options).await {
             let mut initial_containers = Vec::new();
+            let mut container_images = Vec::new();
 
             for container in container_list {
+                // A probe left behind by an interrupted measurement
+                if container
+                    .labels
+                    .as_ref()
+                    .is_some_and(|labels| labels.contains_key(PROBE_LABEL))
+                {
+                    continue;
+                }
+
                 let full_id = container.id.clone().unwrap_or_default();
-                let truncated_id = full_id[..12.min(full_id.len())].to_string();
                 let name = container
                     .names
                     .as_ref()
@@ -80,41 +143,208 @@ impl DockerHost {
                     .as_ref()
                     .and_then(|status| status.parse().ok());
 
+                // Recover approximate start/exit times from the Status text ("Up 3 hours")
+                let timing = container
+                    .status
+                    .as_deref()
+                    .map(|status| parse_status(status, Utc::now()))
+                    .unwrap_or_default();
+
                 // Check if container is running before moving state
                 let is_running = state == ContainerState::Running;
 
                 let container_info = Container {
-                    id: truncated_id.clone(),
+                    id: full_id[..12.min(full_id.len())].to_string(),
+                    full_id: full_id.clone(),
                     name: name.clone(),
+                    image: container.image.clone().unwrap_or_default(),
                     state,
                     health,
                     created,
+                    started_at: timing.started_at,
+                    finished_at: timing.finished_at,
+                    exit_code: timing.exit_code,
+                    // Filled in by fetch_restart_counts below
+                    restart_count: 0,
+                    session_restarts: 0,
+                    size_rw: None,
+                    compose_project: container
+                        .labels
+                        .as_ref()
+                        .and_then(|labels| labels.get(COMPOSE_PROJECT_LABEL))
+                        .cloned(),
+                    compose_service: container
+                        .labels
+                        .as_ref()
+                        .and_then(|labels| labels.get(COMPOSE_SERVICE_LABEL))
+                        .cloned(),
+                    compose_depends_on: container
+                        .labels
+                        .as_ref()
+                        .and_then(|labels| labels.get(COMPOSE_DEPENDS_ON_LABEL))
+                        .map(|label| parse_depends_on(label))
+                        .unwrap_or_default(),
+                    // Filled in by fetch_image_platforms below
+                    platform: None,
+                    time_to_healthy: None,
+                    ports: container
+                        .ports
+                        .as_deref()
+                        .map(ports_from_summary)
+                        .unwrap_or_default(),
+                    labels: container
+                        .labels
+                        .as_ref()
+                        .map(|labels| labels.clone().into_iter().collect())
+                        .unwrap_or_default(),
                     stats: ContainerStats::default(),
                     host_id: self.host_id.clone(),
                     dozzle_url: self.dozzle_url.clone(),
                 };
 
+                if let Some(image_id) = container.image_id {
+                    container_images.push((full_id.clone(), image_id));
+                }
                 initial_containers.push(container_info);
 
                 // Only start monitoring for running containers
                 if is_running {
-                    self.start_container_monitoring(&truncated_id, tx, active_containers);
+                    self.start_container_monitoring(&full_id, tx, active_containers);
                 }
             }
 
-            // Send all initial containers in one event
-            if !initial_containers.is_empty() {
-                let _ = tx
-                    .send(AppEvent::InitialContainerList(
-                        self.host_id.clone(),
-                        initial_containers,
-                    ))
-                    .await;
+            let container_ids: Vec<String> = initial_containers
+                .iter()
+                .map(|c| c.full_id.clone())
+                .collect();
+
+            // Send all initial containers in one event, even none: after a reconnect
+            // the list replaces the host's stale containers
+            let _ = tx
+                .send(AppEvent::InitialContainerList(
+                    self.host_id.clone(),
+                    initial_containers,
+                ))
+                .await;
+
+            // RestartCount and the image platform are only available from inspect;
+            // fetch them without holding up the list
+            let host = self.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                host.fetch_restart_counts(container_ids, &tx).await;
+                host.fetch_image_platforms(container_images, &tx).await;
+            });
+        }
+    }
+
+    /// Pings the daemon with exponential backoff until it answers, reporting each attempt.
+    /// Returns false once the app has stopped listening.
+    async fn wait_for_reconnect(&self, tx: &EventSender) -> bool {
+        let mut attempt = 0;
+        loop {
+            if tx
+                .send(AppEvent::HostReconnecting(
+                    self.host_id.clone(),
+                    attempt + 1,
+                ))
+                .await
+                .is_err()
+            {
+                return false;
+            }
+
+            tokio::time::sleep(reconnect_delay(attempt)).await;
+
+            if self.docker.ping().await.is_ok() {
+                return tx
+                    .send(AppEvent::HostReconnected(self.host_id.clone()))
+                    .await
+                    .is_ok();
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Compares the daemon's clock with the local one and reports the difference
+    async fn check_clock_skew(&self, tx: &EventSender) {
+        let before = Utc::now();
+        let Ok(info) = self.docker.info().await else {
+            return;
+        };
+        let after = Utc::now();
+
+        let Some(daemon_time) = info
+            .system_time
+            .as_deref()
+            .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+        else {
+            return;
+        };
+
+        let skew = clock_skew(daemon_time.with_timezone(&Utc), before, after);
+        let _ = tx
+            .send(AppEvent::HostClockSkew(self.host_id.clone(), skew))
+            .await;
+    }
+
+    /// Inspects each container for Docker's restart count, reporting non-zero counts
+    async fn fetch_restart_counts(&self, container_ids: Vec<String>, tx: &EventSender) {
+        for container_id in container_ids {
+            let Ok(inspect) = self
+                .docker
+                .inspect_container(&container_id, None::<InspectContainerOptions>)
+                .await
+            else {
+                continue;
+            };
+
+            if let Some(count) = inspect.restart_count.filter(|count| *count > 0) {
+                let key = ContainerKey::new(self.host_id.clone(), container_id);
+                let _ = tx.send(AppEvent::ContainerRestartCount(key, count)).await;
+            }
+        }
+    }
+
+    /// Inspects the image of each (container ID, image) pair for its platform,
+    /// inspecting images shared by several containers once
+    async fn fetch_image_platforms(&self, containers: Vec<(String, String)>, tx: &EventSender) {
+        let mut platforms: HashMap<String, Option<ImagePlatform>> = HashMap::new();
+        for (container_id, image) in containers {
+            let platform = match platforms.get(&image) {
+                Some(platform) => platform.clone(),
+                None => {
+                    let platform = self.fetch_image_platform(&image).await;
+                    platforms.insert(image, platform.clone());
+                    platform
+                }
+            };
+
+            if let Some(platform) = platform {
+                let key = ContainerKey::new(self.host_id.clone(), container_id);
+                if tx
+                    .send(AppEvent::ContainerPlatform(key, platform))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
             }
         }
     }
 
-    /// Monitors Docker events for container start/stop/die events
+    /// Inspects an image (ID or reference) for the platform it was built for
+    async fn fetch_image_platform(&self, image: &str) -> Option<ImagePlatform> {
+        let inspect = self.docker.inspect_image(image).await.ok()?;
+        image_platform(
+            inspect.os.as_deref()?,
+            inspect.architecture.as_deref()?,
+            inspect.variant.as_deref(),
+            self.architecture.as_deref(),
+        )
+    }
+
+    /// Monitors Docker events for container start/stop/die events, merging bursts per container
     async fn monitor_docker_events(
         &self,
         tx: &EventSender,
@@ -131,8 +361,18 @@ impl DockerHost {
                 "stop".to_string(),
                 "destroy".to_string(),
                 "health_status".to_string(),
+                "rename".to_string(),
+                "pause".to_string(),
+                "unpause".to_string(),
+                "update".to_string(),
             ],
         );
+        if self.exec_events {
+            filters
+                .get_mut("event")
+                .unwrap()
+                .push("exec_start".to_string());
+        }
 
         // Merge user-provided filters (only event-compatible ones)
         for (key, values) in &self.filters {
@@ -168,122 +408,411 @@ impl DockerHost {
 
         let mut events_stream = self.docker.events(Some(events_options));
 
-        while let Some(event_result) = events_stream.next().await {
-            match event_result {
-                Ok(event) => {
+        // Bursts of events are merged per container before they are handled
+        let mut coalescer = EventCoalescer::default();
+
+        // Healthcheck test per container, to tell probes from exec sessions
+        let mut healthchecks: HashMap<String, Vec<String>> = HashMap::new();
+
+        loop {
+            let next = match coalescer.next_due() {
+                Some(due) => {
+                    let due = tokio::time::Instant::from_std(due);
+                    match tokio::time::timeout_at(due, events_stream.next()).await {
+                        Ok(next) => next,
+                        Err(_) => {
+                            let due = coalescer.take_due(Instant::now());
+                            self.handle_coalesced_events(due, tx, active_containers)
+                                .await;
+                            continue;
+                        }
+                    }
+                }
+                None => events_stream.next().await,
+            };
+
+            match next {
+                Some(Ok(event)) => {
                     if let Some(actor) = event.actor {
+                        // Events carry the container's labels as attributes
+                        if actor
+                            .attributes
+                            .as_ref()
+                            .is_some_and(|attributes| attributes.contains_key(PROBE_LABEL))
+                        {
+                            continue;
+                        }
                         let container_id = actor.id.clone().unwrap_or_default();
                         let action = event.action.unwrap_or_default();
-
-                        match action.as_str() {
-                            "start" => {
-                                self.handle_container_start(&container_id, tx, active_containers)
-                                    .await;
-                            }
-                            "die" | "stop" => {
-                                self.handle_container_stop(&container_id, tx, active_containers)
-                                    .await;
-                            }
-                            "destroy" => {
-                                self.handle_container_destroy(&container_id, tx, active_containers)
-                                    .await;
-                            }
-                            "health_status"
-                            | "health_status: healthy"
-                            | "health_status: unhealthy" => {
-                                self.handle_health_status_change(&container_id, &actor, tx)
-                                    .await;
+                        if let Some(command) = exec_command(&action) {
+                            self.handle_container_exec(
+                                &container_id,
+                                command,
+                                tx,
+                                &mut healthchecks,
+                            )
+                            .await;
+                        } else {
+                            if action == "destroy" {
+                                healthchecks.remove(&container_id);
                             }
-                            _ => {}
+                            let time = match (event.time_nano, event.time) {
+                                (Some(nanos), _) => Some(DateTime::from_timestamp_nanos(nanos)),
+                                (None, Some(secs)) => DateTime::from_timestamp(secs, 0),
+                                (None, None) => None,
+                            };
+                            coalescer.push(&container_id, &action, actor, time, Instant::now());
                         }
                     }
                 }
-                Err(_) => {
-                    // If event stream fails, wait and continue
-                    tokio::time::sleep(Duration::from_secs(1)).await;
-                }
+                // The connection is gone (SSH drop, daemon restart); the caller reconnects
+                Some(Err(_)) | None => break,
             }
+
+            // Events can be due already after a long wait for the stream
+            let due = coalescer.take_due(Instant::now());
+            self.handle_coalesced_events(due, tx, active_containers)
+                .await;
         }
+
+        let pending = coalescer.take_all();
+        self.handle_coalesced_events(pending, tx, active_containers)
+            .await;
     }
 
-    /// Starts monitoring a container by spawning a stats stream task
+    /// Applies merged container events in the order they happened
+    async fn handle_coalesced_events(
+        &self,
+        events: Vec<(String, PendingEvent)>,
+        tx: &EventSender,
+        active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
+    ) {
+        for (container_id, event) in events {
+            if event.last == LastAction::Destroy {
+                self.handle_container_destroy(&container_id, tx, active_containers)
+                    .await;
+                continue;
+            }
+
+            // Restarted: the stop happened before the (last) start
+            if event.last == LastAction::Start
+                && let Some(actor) = &event.stopped
+            {
+                self.handle_container_stop(&container_id, actor, tx, active_containers)
+                    .await;
+            }
+            if let Some(actor) = &event.started {
+                self.handle_container_start(&container_id, actor, tx, active_containers)
+                    .await;
+            }
+            if event.last == LastAction::Stop
+                && let Some(actor) = &event.stopped
+            {
+                self.handle_container_stop(&container_id, actor, tx, active_containers)
+                    .await;
+            }
+            if let Some(actor) = &event.health {
+                let time = event.health_at.unwrap_or_else(Utc::now);
+                self.handle_health_status_change(&container_id, actor, time, tx)
+                    .await;
+            }
+            if let Some(name) = event.renamed {
+                let key = ContainerKey::new(self.host_id.clone(), container_id.clone());
+                let _ = tx.send(AppEvent::ContainerRenamed(key, name)).await;
+            }
+            if let Some(paused) = event.paused {
+                let key = ContainerKey::new(self.host_id.clone(), container_id.clone());
+                let _ = tx.send(AppEvent::ContainerPauseChanged(key, paused)).await;
+            }
+            if event.updated {
+                self.handle_container_update(&container_id, tx).await;
+            }
+        }
+    }
+
+    /// Reports an exec session, unless it is the container's healthcheck
+    async fn handle_container_exec(
+        &self,
+        container_id: &str,
+        command: &str,
+        tx: &EventSender,
+        healthchecks: &mut HashMap<String, Vec<String>>,
+    ) {
+        if !healthchecks.contains_key(container_id)
+            && let Ok(inspect) = self
+                .docker
+                .inspect_container(container_id, None::<InspectContainerOptions>)
+                .await
+        {
+            let test = inspect
+                .config
+                .and_then(|config| config.healthcheck)
+                .and_then(|healthcheck| healthcheck.test)
+                .unwrap_or_default();
+            healthchecks.insert(container_id.to_string(), test);
+        }
+
+        let test = healthchecks
+            .get(container_id)
+            .map_or(&[][..], Vec::as_slice);
+        if is_healthcheck_exec(command, test) {
+            return;
+        }
+
+        let key = ContainerKey::new(self.host_id.clone(), container_id.to_string());
+        let _ = tx
+            .send(AppEvent::ContainerExec(key, command.to_string()))
+            .await;
+    }
+
+    /// Starts monitoring a container by spawning a stats stream (or polling) task
     fn start_container_monitoring(
         &self,
-        truncated_id: &str,
+        container_id: &str,
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
     ) {
         let tx_clone = tx.clone();
         let host_clone = self.clone();
-        let truncated_id_clone = truncated_id.to_string();
+        let container_id_clone = container_id.to_string();
 
-        let handle = tokio::spawn(async move {
-            stream_container_stats(host_clone, truncated_id_clone, tx_clone).await;
-        });
+        let handle = match self.stats_polling.clone() {
+            Some(polling) => tokio::spawn(async move {
+                poll_container_stats(host_clone, container_id_clone, tx_clone, polling).await;
+            }),
+            None => tokio::spawn(async move {
+                stream_container_stats(host_clone, container_id_clone, tx_clone).await;
+            }),
+        };
 
-        active_containers.insert(truncated_id.to_string(), handle);
+        active_containers.insert(container_id.to_string(), handle);
     }
 
     /// Handles a container start event
     async fn handle_container_start(
         &self,
         container_id: &str,
+        actor: &bollard::models::EventActor,
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
     ) {
-        let truncated_id = container_id[..12.min(container_id.len())].to_string();
+        // Already monitored (e.g. a duplicate start event)
+        if active_containers.contains_key(container_id) {
+            return;
+        }
 
-        // Get container details
-        if let Ok(inspect) = self
-            .docker
-            .inspect_container(container_id, None::<InspectContainerOptions>)
-            .await
-        {
-            let name = inspect
-                .name
-                .as_ref()
-                .map(|n| n.trim_start_matches('/').to_string())
-                .unwrap_or_default();
+        // Fall back to what the event itself tells us rather than dropping the container
+        let container = match self.inspect_with_retry(container_id).await {
+            Ok(inspect) => {
+                if let Some(image_id) = inspect.image.clone() {
+                    let host = self.clone();
+                    let tx = tx.clone();
+                    let containers = vec![(container_id.to_string(), image_id)];
+                    tokio::spawn(async move {
+                        host.fetch_image_platforms(containers, &tx).await;
+                    });
+                }
+                self.container_from_inspect(container_id, inspect)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to inspect started container {} on {}, using event attributes: {}",
+                    &container_id[..12.min(container_id.len())],
+                    self.host_id,
+                    e
+                );
+                self.container_from_event_actor(container_id, actor)
+            }
+        };
 
-            let state = inspect
-                .state
-                .as_ref()
-                .and_then(|s| s.status.as_ref())
-                .and_then(|s| format!("{:?}", s).parse().ok())
-                .unwrap_or(ContainerState::Unknown);
+        let _ = tx
+            .send(AppEvent::ContainerCreated(Box::new(container)))
+            .await;
 
-            // Parse health status from state (None if no health check configured)
-            let health = inspect
-                .state
-                .as_ref()
-                .and_then(|s| s.health.as_ref())
-                .and_then(|h| h.status.as_ref())
-                .and_then(|status| format!("{:?}", status).parse().ok());
-
-            // Parse created timestamp from RFC3339 string
-            let created = inspect.created.as_ref().and_then(|created_str| {
-                DateTime::parse_from_rfc3339(created_str)
-                    .ok()
-                    .map(|dt| dt.with_timezone(&Utc))
-            });
-
-            // Start monitoring the new container
-            if !active_containers.contains_key(&truncated_id) {
-                let container = Container {
-                    id: truncated_id.clone(),
-                    name: name.clone(),
-                    state,
-                    health,
-                    created,
-                    stats: ContainerStats::default(),
-                    host_id: self.host_id.clone(),
-                    dozzle_url: self.dozzle_url.clone(),
-                };
+        self.start_container_monitoring(container_id, tx, active_containers);
+    }
 
-                let _ = tx.send(AppEvent::ContainerCreated(container)).await;
+    /// Inspects a container, retrying briefly on failure
+    async fn inspect_with_retry(
+        &self,
+        container_id: &str,
+    ) -> Result<bollard::models::ContainerInspectResponse, bollard::errors::Error> {
+        let inspect = || {
+            self.docker
+                .inspect_container(container_id, None::<InspectContainerOptions>)
+        };
 
-                self.start_container_monitoring(&truncated_id, tx, active_containers);
+        let mut result = inspect().await;
+        for delay in INSPECT_RETRY_DELAYS {
+            // A container that is gone won't come back
+            if matches!(
+                result,
+                Ok(_)
+                    | Err(bollard::errors::Error::DockerResponseServerError {
+                        status_code: 404,
+                        ..
+                    })
+            ) {
+                break;
             }
+            tokio::time::sleep(delay).await;
+            result = inspect().await;
+        }
+        result
+    }
+
+    /// Builds a container from its inspect response
+    fn container_from_inspect(
+        &self,
+        container_id: &str,
+        inspect: bollard::models::ContainerInspectResponse,
+    ) -> Container {
+        let name = inspect
+            .name
+            .as_ref()
+            .map(|n| n.trim_start_matches('/').to_string())
+            .unwrap_or_default();
+
+        let state = inspect
+            .state
+            .as_ref()
+            .and_then(|s| s.status.as_ref())
+            .and_then(|s| format!("{:?}", s).parse().ok())
+            .unwrap_or(ContainerState::Unknown);
+
+        // Parse health status from state (None if no health check configured)
+        let health = inspect
+            .state
+            .as_ref()
+            .and_then(|s| s.health.as_ref())
+            .and_then(|h| h.status.as_ref())
+            .and_then(|status| format!("{:?}", status).parse().ok());
+
+        // Parse created timestamp from RFC3339 string
+        let created = inspect.created.as_ref().and_then(|created_str| {
+            DateTime::parse_from_rfc3339(created_str)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc))
+        });
+
+        let started_at = inspect
+            .state
+            .as_ref()
+            .and_then(|s| s.started_at.as_ref())
+            .and_then(|started_str| DateTime::parse_from_rfc3339(started_str).ok())
+            .map(|dt| dt.with_timezone(&Utc));
+
+        Container {
+            id: container_id[..12.min(container_id.len())].to_string(),
+            full_id: inspect
+                .id
+                .clone()
+                .unwrap_or_else(|| container_id.to_string()),
+            name,
+            image: inspect
+                .config
+                .as_ref()
+                .and_then(|config| config.image.clone())
+                .unwrap_or_default(),
+            state,
+            health,
+            created,
+            started_at,
+            finished_at: None,
+            exit_code: None,
+            restart_count: inspect.restart_count.unwrap_or(0),
+            // Carried over from the previous run by AppState
+            session_restarts: 0,
+            size_rw: None,
+            // Fetched separately by fetch_image_platforms
+            platform: None,
+            time_to_healthy: None,
+            compose_project: inspect
+                .config
+                .as_ref()
+                .and_then(|config| config.labels.as_ref())
+                .and_then(|labels| labels.get(COMPOSE_PROJECT_LABEL))
+                .cloned(),
+            compose_service: inspect
+                .config
+                .as_ref()
+                .and_then(|config| config.labels.as_ref())
+                .and_then(|labels| labels.get(COMPOSE_SERVICE_LABEL))
+                .cloned(),
+            compose_depends_on: inspect
+                .config
+                .as_ref()
+                .and_then(|config| config.labels.as_ref())
+                .and_then(|labels| labels.get(COMPOSE_DEPENDS_ON_LABEL))
+                .map(|label| parse_depends_on(label))
+                .unwrap_or_default(),
+            ports: inspect
+                .network_settings
+                .as_ref()
+                .and_then(|settings| settings.ports.as_ref())
+                .map(ports_from_map)
+                .unwrap_or_default(),
+            labels: inspect
+                .config
+                .as_ref()
+                .and_then(|config| config.labels.clone())
+                .map(|labels| labels.into_iter().collect())
+                .unwrap_or_default(),
+            stats: ContainerStats::default(),
+            host_id: self.host_id.clone(),
+            dozzle_url: self.dozzle_url.clone(),
+        }
+    }
+
+    /// Builds a running container from the attributes of its start event
+    /// (name, image and labels; the rest is unknown without an inspect)
+    fn container_from_event_actor(
+        &self,
+        container_id: &str,
+        actor: &bollard::models::EventActor,
+    ) -> Container {
+        let attribute = |key: &str| {
+            actor
+                .attributes
+                .as_ref()
+                .and_then(|attributes| attributes.get(key))
+                .cloned()
+        };
+        let truncated_id = container_id[..12.min(container_id.len())].to_string();
+
+        Container {
+            name: attribute("name").unwrap_or_else(|| truncated_id.clone()),
+            id: truncated_id,
+            full_id: container_id.to_string(),
+            image: attribute("image").unwrap_or_default(),
+            state: ContainerState::Running,
+            health: None,
+            created: None,
+            started_at: Some(Utc::now()),
+            finished_at: None,
+            exit_code: None,
+            restart_count: 0,
+            session_restarts: 0,
+            size_rw: None,
+            // Event attributes include the container's labels
+            compose_project: attribute(COMPOSE_PROJECT_LABEL),
+            compose_service: attribute(COMPOSE_SERVICE_LABEL),
+            compose_depends_on: attribute(COMPOSE_DEPENDS_ON_LABEL)
+                .map(|label| parse_depends_on(&label))
+                .unwrap_or_default(),
+            platform: None,
+            time_to_healthy: None,
+            ports: Vec::new(),
+            labels: actor
+                .attributes
+                .iter()
+                .flatten()
+                .filter(|(key, _)| !EVENT_ATTRIBUTES.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            stats: ContainerStats::default(),
+            host_id: self.host_id.clone(),
+            dozzle_url: self.dozzle_url.clone(),
         }
     }
 
@@ -291,20 +820,24 @@ impl DockerHost {
     async fn handle_container_stop(
         &self,
         container_id: &str,
+        actor: &bollard::models::EventActor,
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
     ) {
-        let truncated_id = container_id[..12.min(container_id.len())].to_string();
-
         // Stop stats monitoring but keep the container in the list
-        if let Some(handle) = active_containers.remove(&truncated_id) {
+        if let Some(handle) = active_containers.remove(container_id) {
             handle.abort();
 
-            // Send state change event instead of destroying the container
-            let key = ContainerKey::new(self.host_id.clone(), truncated_id);
-            let _ = tx
-                .send(AppEvent::ContainerStateChanged(key, ContainerState::Exited))
-                .await;
+            // Only "die" events carry the exit code
+            let exit_code = actor
+                .attributes
+                .as_ref()
+                .and_then(|attributes| attributes.get("exitCode"))
+                .and_then(|code| code.parse().ok());
+
+            // Send exit event instead of destroying the container
+            let key = ContainerKey::new(self.host_id.clone(), container_id.to_string());
+            let _ = tx.send(AppEvent::ContainerExited(key, exit_code)).await;
         }
     }
 
@@ -315,26 +848,41 @@ impl DockerHost {
         tx: &EventSender,
         active_containers: &mut HashMap<String, tokio::task::JoinHandle<()>>,
     ) {
-        let truncated_id = container_id[..12.min(container_id.len())].to_string();
-
         // Stop monitoring if still active and remove from UI
-        if let Some(handle) = active_containers.remove(&truncated_id) {
+        if let Some(handle) = active_containers.remove(container_id) {
             handle.abort();
         }
 
-        let key = ContainerKey::new(self.host_id.clone(), truncated_id);
+        let key = ContainerKey::new(self.host_id.clone(), container_id.to_string());
         let _ = tx.send(AppEvent::ContainerDestroyed(key)).await;
     }
 
+    /// Handles an update event by reading the new memory limit
+    async fn handle_container_update(&self, container_id: &str, tx: &EventSender) {
+        let Ok(inspect) = self.inspect_with_retry(container_id).await else {
+            return;
+        };
+
+        let memory_limit = inspect
+            .host_config
+            .and_then(|host_config| host_config.memory)
+            .and_then(|memory| u64::try_from(memory).ok())
+            .filter(|memory| *memory > 0);
+
+        let key = ContainerKey::new(self.host_id.clone(), container_id.to_string());
+        let _ = tx
+            .send(AppEvent::ContainerLimitsUpdated(key, memory_limit))
+            .await;
+    }
+
     /// Handles a health_status event
     async fn handle_health_status_change(
         &self,
         container_id: &str,
         actor: &bollard::models::EventActor,
+        time: DateTime<Utc>,
         tx: &EventSender,
     ) {
-        let truncated_id = container_id[..12.min(container_id.len())].to_string();
-
         // Try to get health status from actor attributes
         let health = if let Some(attributes) = &actor.attributes {
             attributes
@@ -361,15 +909,208 @@ impl DockerHost {
 
         // Only send event if we have a valid health status
         if let Some(health_status) = health {
-            let key = ContainerKey::new(self.host_id.clone(), truncated_id);
+            let key = ContainerKey::new(self.host_id.clone(), container_id.to_string());
             let _ = tx
-                .send(AppEvent::ContainerHealthChanged(key, health_status))
+                .send(AppEvent::ContainerHealthChanged(key, health_status, time))
                 .await;
         }
     }
 
+    /// Lists containers with their writable layer sizes (slow: Docker walks each layer)
+    pub async fn fetch_container_sizes(&self) -> Result<Vec<(String, i64)>, String> {
+        let mut list_options = ListContainersOptions {
+            all: true,
+            size: true,
+            ..Default::default()
+        };
+        if !self.filters.is_empty() {
+            list_options.filters = Some(self.filters.clone());
+        }
+
+        let container_list = self
+            .docker
+            .list_containers(Some(list_options))
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().fetch_sizes_failed, &[&e]))?;
+
+        Ok(container_list
+            .into_iter()
+            .filter_map(|container| {
+                let size = container.size_rw?;
+                Some((container.id?, size))
+            })
+            .collect())
+    }
+
+    /// Fetches the layer history of an image, newest layer first
+    pub async fn fetch_image_history(&self, image: &str) -> Result<Vec<ImageLayer>, String> {
+        let history = self
+            .docker
+            .image_history(image)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().fetch_image_history_failed, &[&e]))?;
+
+        Ok(history
+            .into_iter()
+            .map(|item| ImageLayer {
+                created: DateTime::from_timestamp(item.created, 0),
+                created_by: item.created_by,
+                size: item.size,
+                tags: item.tags,
+            })
+            .collect())
+    }
+
+    /// Fetches a container's environment variables as (name, value), sorted by name
+    pub async fn fetch_env(&self, container_id: &str) -> Result<Vec<(String, String)>, String> {
+        let inspect = self
+            .docker
+            .inspect_container(container_id, None::<InspectContainerOptions>)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().inspect_failed, &[&e]))?;
+
+        let mut vars: Vec<(String, String)> = inspect
+            .config
+            .and_then(|config| config.env)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|var| match var.split_once('=') {
+                Some((name, value)) => (name.to_string(), value.to_string()),
+                None => (var, String::new()),
+            })
+            .collect();
+        vars.sort();
+        Ok(vars)
+    }
+
+    /// Pulls an image, authenticating with the given registry credentials
+    pub async fn pull_image(
+        &self,
+        image: &str,
+        credentials: Option<DockerCredentials>,
+    ) -> Result<(), String> {
+        use bollard::query_parameters::CreateImageOptions;
+
+        let options = CreateImageOptions {
+            from_image: Some(image.to_string()),
+            ..Default::default()
+        };
+
+        let mut stream = self.docker.create_image(Some(options), None, credentials);
+        while let Some(progress) = stream.next().await {
+            let progress =
+                progress.map_err(|e| i18n::fill(i18n::messages().pull_failed, &[&image, &e]))?;
+            if let Some(message) = progress.error_detail.and_then(|d| d.message) {
+                return Err(i18n::fill(
+                    i18n::messages().pull_failed,
+                    &[&image, &message],
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Pushes an image, authenticating with the given registry credentials
+    pub async fn push_image(
+        &self,
+        image: &str,
+        credentials: Option<DockerCredentials>,
+    ) -> Result<(), String> {
+        use bollard::query_parameters::PushImageOptions;
+
+        let (repository, tag) = split_image_tag(image);
+        let options = PushImageOptions {
+            tag: Some(tag.to_string()).filter(|t| !t.is_empty()),
+            ..Default::default()
+        };
+
+        let mut stream = self
+            .docker
+            .push_image(repository, Some(options), credentials);
+        while let Some(progress) = stream.next().await {
+            let progress =
+                progress.map_err(|e| i18n::fill(i18n::messages().push_failed, &[&image, &e]))?;
+            if let Some(message) = progress.error_detail.and_then(|d| d.message) {
+                return Err(i18n::fill(
+                    i18n::messages().push_failed,
+                    &[&image, &message],
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists the host's networks the container can be connected to (`attached` false)
+    /// or disconnected from (`attached` true), sorted by name
+    pub async fn list_network_choices(
+        &self,
+        container_id: &str,
+        attached: bool,
+    ) -> Result<Vec<String>, String> {
+        let inspect = self
+            .docker
+            .inspect_container(container_id, None::<InspectContainerOptions>)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().inspect_failed, &[&e]))?;
+        let current: Vec<String> = inspect
+            .network_settings
+            .and_then(|settings| settings.networks)
+            .map(|networks| networks.into_keys().collect())
+            .unwrap_or_default();
+
+        let mut networks: Vec<String> = if attached {
+            current
+        } else {
+            self.docker
+                .list_networks(None)
+                .await
+                .map_err(|e| i18n::fill(i18n::messages().list_networks_failed, &[&e]))?
+                .into_iter()
+                .filter_map(|network| network.name)
+                .filter(|name| !current.contains(name))
+                .collect()
+        };
+        networks.sort();
+        Ok(networks)
+    }
+
+    /// Connects a container to a network
+    pub async fn connect_network(&self, container_id: &str, network: &str) -> Result<(), String> {
+        use bollard::models::NetworkConnectRequest;
+
+        let request = NetworkConnectRequest {
+            container: container_id.to_string(),
+            ..Default::default()
+        };
+
+        self.docker
+            .connect_network(network, request)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().connect_network_failed, &[&network, &e]))
+    }
+
+    /// Disconnects a container from a network
+    pub async fn disconnect_network(
+        &self,
+        container_id: &str,
+        network: &str,
+    ) -> Result<(), String> {
+        use bollard::models::NetworkDisconnectRequest;
+
+        let request = NetworkDisconnectRequest {
+            container: container_id.to_string(),
+            force: Some(false),
+        };
+
+        self.docker
+            .disconnect_network(network, request)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().disconnect_network_failed, &[&network, &e]))
+    }
+
     /// Starts a container
-    pub async fn start_container(&self, container_id: &str) -> Result<(), String> {
+    /// The four container actions keep the Docker error, so a transient one can be retried
+    pub async fn start_container(&self, container_id: &str) -> Result<(), bollard::errors::Error> {
         use bollard::query_parameters::StartContainerOptions;
 
         let options = StartContainerOptions { detach_keys: None };
@@ -377,41 +1118,46 @@ impl DockerHost {
         self.docker
             .start_container(container_id, Some(options))
             .await
-            .map_err(|e| format!("Failed to start container: {}", e))
     }
 
-    /// Stops a container with a 10-second timeout
-    pub async fn stop_container(&self, container_id: &str) -> Result<(), String> {
+    /// Stops a container, killing it when still running after `timeout` seconds
+    pub async fn stop_container(
+        &self,
+        container_id: &str,
+        timeout: u32,
+    ) -> Result<(), bollard::errors::Error> {
         use bollard::query_parameters::StopContainerOptions;
 
         let options = StopContainerOptions {
             signal: None,
-            t: Some(10), // 10 second timeout before force kill
+            t: Some(timeout as i32),
         };
 
         self.docker
             .stop_container(container_id, Some(options))
             .await
-            .map_err(|e| format!("Failed to stop container: {}", e))
     }
 
-    /// Restarts a container with a 10-second timeout
-    pub async fn restart_container(&self, container_id: &str) -> Result<(), String> {
+    /// Restarts a container, killing it when still running after `timeout` seconds
+    pub async fn restart_container(
+        &self,
+        container_id: &str,
+        timeout: u32,
+    ) -> Result<(), bollard::errors::Error> {
         use bollard::query_parameters::RestartContainerOptions;
 
         let options = RestartContainerOptions {
             signal: None,
-            t: Some(10), // 10 second timeout before force kill
+            t: Some(timeout as i32),
         };
 
         self.docker
             .restart_container(container_id, Some(options))
             .await
-            .map_err(|e| format!("Failed to restart container: {}", e))
     }
 
     /// Removes a container (with force option if needed)
-    pub async fn remove_container(&self, container_id: &str) -> Result<(), String> {
+    pub async fn remove_container(&self, container_id: &str) -> Result<(), bollard::errors::Error> {
         use bollard::query_parameters::RemoveContainerOptions;
 
         let options = RemoveContainerOptions {
@@ -423,30 +1169,152 @@ impl DockerHost {
         self.docker
             .remove_container(container_id, Some(options))
             .await
-            .map_err(|e| format!("Failed to remove container: {}", e))
-    }
-
-    /// Runs an interactive shell session inside a container
-    /// This function takes over the terminal completely until the shell exits
-    pub async fn run_shell_session(
-        &self,
-        container_id: &str,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        crate::docker::shell::run_shell_session(self, container_id).await
     }
 }
 
-/// Manages container monitoring for a specific Docker host: fetches initial containers and listens for Docker events
+/// Manages container monitoring for a specific Docker host: fetches initial containers and listens for Docker events.
+/// When the event stream dies the host is retried with backoff and its container list fetched again.
 pub async fn container_manager(host: DockerHost, tx: EventSender) {
     let mut active_containers: HashMap<String, tokio::task::JoinHandle<()>> = HashMap::new();
 
-    // Fetch and start monitoring initial containers
-    host.fetch_initial_containers(&tx, &mut active_containers)
-        .await;
+    // Measured on their own schedule; failures while the host is lost are skipped
+    tokio::spawn(monitor_host_info(host.clone(), tx.clone()));
+    if host.disk_check && !host.windows {
+        tokio::spawn(monitor_host_resources(host.clone(), tx.clone()));
+    }
+
+    loop {
+        // Checked on every (re)connect, the host may have been fixed or rebooted meanwhile
+        host.check_clock_skew(&tx).await;
+
+        // Fetch and start monitoring initial containers
+        host.fetch_initial_containers(&tx, &mut active_containers)
+            .await;
+
+        // Subscribe to Docker events and handle container lifecycle
+        host.monitor_docker_events(&tx, &mut active_containers)
+            .await;
+
+        // Stats streams of a lost connection are dead or about to be; restart them fresh
+        for (_, handle) in active_containers.drain() {
+            handle.abort();
+        }
 
-    // Subscribe to Docker events and handle container lifecycle
-    host.monitor_docker_events(&tx, &mut active_containers)
-        .await;
+        if !host.wait_for_reconnect(&tx).await {
+            return; // The app is shutting down
+        }
+    }
+}
+
+/// Delay before reconnect attempt `attempt` (0-based): doubles up to RECONNECT_MAX_DELAY
+fn reconnect_delay(attempt: u32) -> Duration {
+    RECONNECT_INITIAL_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RECONNECT_MAX_DELAY)
+}
+
+/// How far the daemon's clock is ahead of the local one (negative when behind), given the
+/// daemon's time and the local times before and after asking for it
+/// The daemon's time is compared to the middle of the round trip
+fn clock_skew(
+    daemon_time: DateTime<Utc>,
+    before: DateTime<Utc>,
+    after: DateTime<Utc>,
+) -> TimeDelta {
+    daemon_time - (before + (after - before) / 2)
+}
+
+/// Service names of a compose depends_on label: "db:service_healthy:false,cache:service_started:true"
+/// (service, condition, whether to restart along)
+fn parse_depends_on(label: &str) -> Vec<String> {
+    label
+        .split(',')
+        .filter_map(|dependency| dependency.split(':').next())
+        .map(str::trim)
+        .filter(|service| !service.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Published ports from the `docker ps` summary
+fn ports_from_summary(ports: &[bollard::models::PortSummary]) -> Vec<PortMapping> {
+    sorted_ports(ports.iter().filter_map(|port| {
+        Some(PortMapping {
+            host_ip: bound_ip(port.ip.as_deref()),
+            host_port: port.public_port?,
+            container_port: port.private_port,
+            protocol: port
+                .typ
+                .map_or_else(|| "tcp".to_string(), |typ| typ.to_string()),
+        })
+    }))
+}
+
+/// Published ports from the inspect port map ("80/tcp" => [{HostIp, HostPort}])
+fn ports_from_map(ports: &bollard::models::PortMap) -> Vec<PortMapping> {
+    sorted_ports(ports.iter().flat_map(|(port, bindings)| {
+        let (container_port, protocol) = port.split_once('/').unwrap_or((port, "tcp"));
+        let container_port = container_port.parse().ok();
+        bindings.iter().flatten().filter_map(move |binding| {
+            Some(PortMapping {
+                host_ip: bound_ip(binding.host_ip.as_deref()),
+                host_port: binding.host_port.as_deref()?.parse().ok()?,
+                container_port: container_port?,
+                protocol: protocol.to_string(),
+            })
+        })
+    }))
+}
+
+/// Sorts ports by container port, dropping the IPv6 twin Docker adds for each binding
+fn sorted_ports(ports: impl Iterator<Item = PortMapping>) -> Vec<PortMapping> {
+    let mut ports: Vec<PortMapping> = ports.collect();
+    ports.sort_by(|a, b| {
+        (a.container_port, &a.protocol, a.host_port, &a.host_ip).cmp(&(
+            b.container_port,
+            &b.protocol,
+            b.host_port,
+            &b.host_ip,
+        ))
+    });
+    ports.dedup();
+    ports
+}
+
+/// The address a port is bound to, None for all interfaces
+fn bound_ip(ip: Option<&str>) -> Option<String> {
+    ip.filter(|ip| !matches!(*ip, "" | "0.0.0.0" | "::"))
+        .map(str::to_string)
+}
+
+/// Builds the platform of an image from its inspect fields ("linux", "arm", "v7"),
+/// marking it emulated when its architecture isn't the host's
+fn image_platform(
+    os: &str,
+    architecture: &str,
+    variant: Option<&str>,
+    host_architecture: Option<&str>,
+) -> Option<ImagePlatform> {
+    if os.is_empty() || architecture.is_empty() {
+        return None;
+    }
+
+    let name = match variant.filter(|variant| !variant.is_empty()) {
+        Some(variant) => format!("{}/{}/{}", os, architecture, variant),
+        None => format!("{}/{}", os, architecture),
+    };
+    Some(ImagePlatform {
+        name,
+        emulated: host_architecture.is_some_and(|host| host != architecture),
+    })
+}
+
+/// Directory with the key.pem, cert.pem and ca.pem of TLS hosts: DOCKER_CERT_PATH,
+/// or ~/.docker like the Docker CLI
+pub fn tls_cert_dir() -> std::path::PathBuf {
+    let cert_path = std::env::var("DOCKER_CERT_PATH")
+        .unwrap_or_else(|_| format!("{}/.docker", std::env::var("HOME").unwrap_or_default()));
+    std::path::PathBuf::from(cert_path)
 }
 
 /// Connects to Docker based on the host string
@@ -497,10 +1365,7 @@ pub fn connect_docker(host: &str) -> Result<Docker, Box<dyn std::error::Error>>
     } else if host.starts_with("tls://") {
         // Connect via TLS using environment variables for certificates
         // Expects DOCKER_CERT_PATH to be set with key.pem, cert.pem, and ca.pem files
-        let cert_path = std::env::var("DOCKER_CERT_PATH")
-            .unwrap_or_else(|_| format!("{}/.docker", std::env::var("HOME").unwrap_or_default()));
-
-        let cert_dir = std::path::Path::new(&cert_path);
+        let cert_dir = tls_cert_dir();
         let key_path = cert_dir.join("key.pem");
         let cert_path = cert_dir.join("cert.pem");
         let ca_path = cert_dir.join("ca.pem");
@@ -524,10 +1389,161 @@ pub fn connect_docker(host: &str) -> Result<Docker, Box<dyn std::error::Error>>
             API_DEFAULT_VERSION,
         )?)
     } else {
-        Err(format!(
-            "Invalid host format: '{}'. Use 'local', 'ssh://user@host[:port]', 'tcp://host:port', or 'tls://host:port'",
-            host
-        )
-        .into())
+        Err(i18n::fill(i18n::messages().invalid_host_format, &[&host]).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::EventActor;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_container_from_event_actor() {
+        let docker =
+            Docker::connect_with_http("tcp://localhost:2375", 1, API_DEFAULT_VERSION).unwrap();
+        let host = DockerHost::new("remote".to_string(), docker, None, HashMap::new());
+        let full_id = "0123456789abcdef0123456789abcdef";
+
+        let actor = EventActor {
+            id: Some(full_id.to_string()),
+            attributes: Some(HashMap::from([
+                ("name".to_string(), "web".to_string()),
+                ("image".to_string(), "nginx:latest".to_string()),
+                ("team".to_string(), "payments".to_string()),
+            ])),
+        };
+        let container = host.container_from_event_actor(full_id, &actor);
+        assert_eq!(container.id, "0123456789ab");
+        assert_eq!(container.full_id, full_id);
+        assert_eq!(container.name, "web");
+        assert_eq!(container.image, "nginx:latest");
+        assert_eq!(container.state, ContainerState::Running);
+        assert_eq!(container.host_id, "remote");
+        // The other attributes are the container's labels
+        assert_eq!(
+            container.labels,
+            BTreeMap::from([("team".to_string(), "payments".to_string())])
+        );
+
+        // Without attributes the short ID stands in for the name
+        let actor = EventActor {
+            id: Some(full_id.to_string()),
+            attributes: None,
+        };
+        assert_eq!(
+            host.container_from_event_actor(full_id, &actor).name,
+            "0123456789ab"
+        );
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        assert_eq!(reconnect_delay(0), Duration::from_secs(1));
+        assert_eq!(reconnect_delay(1), Duration::from_secs(2));
+        assert_eq!(reconnect_delay(4), Duration::from_secs(16));
+        assert_eq!(reconnect_delay(6), RECONNECT_MAX_DELAY);
+        assert_eq!(reconnect_delay(100), RECONNECT_MAX_DELAY);
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        let before = DateTime::parse_from_rfc3339("2025-06-15T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let after = before + TimeDelta::milliseconds(200);
+
+        let in_sync = before + TimeDelta::milliseconds(100);
+        assert_eq!(clock_skew(in_sync, before, after), TimeDelta::zero());
+
+        let ahead = before + TimeDelta::seconds(42);
+        assert_eq!(
+            clock_skew(ahead, before, after),
+            TimeDelta::milliseconds(41_900)
+        );
+
+        let behind = before - TimeDelta::seconds(30);
+        assert_eq!(
+            clock_skew(behind, before, after),
+            TimeDelta::milliseconds(-30_100)
+        );
+    }
+
+    #[test]
+    fn test_parse_depends_on() {
+        assert_eq!(
+            parse_depends_on("db:service_healthy:false,cache:service_started:true"),
+            vec!["db".to_string(), "cache".to_string()]
+        );
+        // Older compose versions wrote bare service names
+        assert_eq!(parse_depends_on("db"), vec!["db".to_string()]);
+        assert!(parse_depends_on("").is_empty());
+    }
+
+    #[test]
+    fn test_published_ports() {
+        use bollard::models::{PortBinding, PortSummary, PortSummaryTypeEnum};
+
+        let summary = |ip: &str, private_port, public_port, typ| PortSummary {
+            ip: Some(ip.to_string()),
+            private_port,
+            public_port,
+            typ: Some(typ),
+        };
+        let ports = ports_from_summary(&[
+            summary("0.0.0.0", 80, Some(8080), PortSummaryTypeEnum::TCP),
+            summary("::", 80, Some(8080), PortSummaryTypeEnum::TCP),
+            summary("127.0.0.1", 53, Some(5353), PortSummaryTypeEnum::UDP),
+            // Exposed but not published
+            summary("", 9000, None, PortSummaryTypeEnum::TCP),
+        ]);
+        let labels: Vec<String> = ports.iter().map(|port| port.to_string()).collect();
+        assert_eq!(labels, ["127.0.0.1:5353:53/udp", "8080:80"]);
+
+        let binding = |host_ip: &str, host_port: &str| PortBinding {
+            host_ip: Some(host_ip.to_string()),
+            host_port: Some(host_port.to_string()),
+        };
+        let map = HashMap::from([
+            (
+                "443/tcp".to_string(),
+                Some(vec![binding("0.0.0.0", "8443"), binding("::", "8443")]),
+            ),
+            ("6379/tcp".to_string(), None),
+        ]);
+        assert_eq!(
+            ports_from_map(&map),
+            vec![PortMapping {
+                host_ip: None,
+                host_port: 8443,
+                container_port: 443,
+                protocol: "tcp".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_image_platform() {
+        assert_eq!(
+            image_platform("linux", "arm64", None, Some("arm64")),
+            Some(ImagePlatform {
+                name: "linux/arm64".to_string(),
+                emulated: false,
+            })
+        );
+        assert_eq!(
+            image_platform("linux", "arm", Some("v7"), Some("amd64")),
+            Some(ImagePlatform {
+                name: "linux/arm/v7".to_string(),
+                emulated: true,
+            })
+        );
+        // Unknown host architecture: nothing to compare against
+        assert_eq!(
+            image_platform("linux", "amd64", Some(""), None).map(|p| (p.name, p.emulated)),
+            Some(("linux/amd64".to_string(), false))
+        );
+        assert_eq!(image_platform("", "", None, Some("amd64")), None);
     }
 }
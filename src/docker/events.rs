@@ -0,0 +1,327 @@
+//! Coalescing of Docker container events
+//!
+//! A crashlooping container (or a deployment touching many containers) can emit
+//! hundreds of start/die events per minute, and every start costs an inspect call.
+//! Events are held briefly and merged per container, so a burst turns into a single
+//! update. Containers that were flushed recently are held longer, limiting a
+//! crashlooping container to one inspect per debounce period.
+
+use bollard::models::EventActor;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long events are held so a burst for the same container is merged
+const COALESCE_DELAY: Duration = Duration::from_millis(200);
+
+/// Minimum time between two updates of the same container
+const CHURN_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// The last lifecycle event seen for a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LastAction {
+    Start,
+    Stop,
+    Destroy,
+    /// Only health, rename, update or pause events were seen
+    HealthOnly,
+}
+
+/// Events for one container merged since its last update
+#[derive(Debug, Clone)]
+pub struct PendingEvent {
+    /// Actor of the latest start event, if the container started (once or more)
+    pub started: Option<EventActor>,
+    /// Actor of the latest die/stop event (carries the exit code)
+    pub stopped: Option<EventActor>,
+    /// Actor of the latest health_status event
+    pub health: Option<EventActor>,
+    /// When the daemon reported the latest health_status event
+    pub health_at: Option<DateTime<Utc>>,
+    /// New name from the latest rename event
+    pub renamed: Option<String>,
+    /// Whether the container's resources were updated (`docker update`)
+    pub updated: bool,
+    /// Whether the latest pause/unpause event paused the container
+    /// (reset by start and stop, which decide the state themselves)
+    pub paused: Option<bool>,
+    /// Which lifecycle event came last, deciding the final state
+    pub last: LastAction,
+    due: Instant,
+}
+
+/// Merges container events until they are due
+#[derive(Debug, Default)]
+pub struct EventCoalescer {
+    /// Pending events in arrival order of their container's first event
+    pending: Vec<(String, PendingEvent)>,
+    /// When each container was last flushed
+    last_flushed: HashMap<String, Instant>,
+}
+
+impl EventCoalescer {
+    /// Adds an event (with the daemon's time of it, if known), merging it with
+    /// pending events for the same container
+    pub fn push(
+        &mut self,
+        container_id: &str,
+        action: &str,
+        actor: EventActor,
+        time: Option<DateTime<Utc>>,
+        now: Instant,
+    ) {
+        let index = match self.pending.iter().position(|(id, _)| id == container_id) {
+            Some(index) => index,
+            None => {
+                // Recently updated containers wait out the debounce period
+                let due = match self.last_flushed.get(container_id) {
+                    Some(flushed) => (now + COALESCE_DELAY).max(*flushed + CHURN_DEBOUNCE),
+                    None => now + COALESCE_DELAY,
+                };
+                self.pending.push((
+                    container_id.to_string(),
+                    PendingEvent {
+                        started: None,
+                        stopped: None,
+                        health: None,
+                        health_at: None,
+                        renamed: None,
+                        updated: false,
+                        paused: None,
+                        last: LastAction::HealthOnly,
+                        due,
+                    },
+                ));
+                self.pending.len() - 1
+            }
+        };
+        let event = &mut self.pending[index].1;
+
+        match action {
+            "start" => {
+                event.started = Some(actor);
+                event.last = LastAction::Start;
+                event.paused = None;
+            }
+            "die" => {
+                event.stopped = Some(actor);
+                event.last = LastAction::Stop;
+                event.paused = None;
+            }
+            // "stop" follows "die" without the exit code, so it only counts on its own
+            "stop" if event.last != LastAction::Stop => {
+                event.stopped = Some(actor);
+                event.last = LastAction::Stop;
+                event.paused = None;
+            }
+            "pause" => event.paused = Some(true),
+            "unpause" => event.paused = Some(false),
+            "destroy" => event.last = LastAction::Destroy,
+            "rename" => {
+                event.renamed = actor
+                    .attributes
+                    .as_ref()
+                    .and_then(|attributes| attributes.get("name"))
+                    .map(|name| name.trim_start_matches('/').to_string());
+            }
+            "update" => event.updated = true,
+            _ if action.starts_with("health_status") => {
+                event.health = Some(actor);
+                event.health_at = time;
+            }
+            _ => {}
+        }
+    }
+
+    /// When the next pending container update is due
+    pub fn next_due(&self) -> Option<Instant> {
+        self.pending.iter().map(|(_, event)| event.due).min()
+    }
+
+    /// Removes and returns the container updates that are due
+    pub fn take_due(&mut self, now: Instant) -> Vec<(String, PendingEvent)> {
+        let (due, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|(_, event)| event.due <= now);
+        self.pending = pending;
+
+        self.last_flushed
+            .retain(|_, flushed| now.duration_since(*flushed) < CHURN_DEBOUNCE);
+        for (id, _) in &due {
+            self.last_flushed.insert(id.clone(), now);
+        }
+
+        due
+    }
+
+    /// Removes and returns all pending updates (when the event stream ends)
+    pub fn take_all(&mut self) -> Vec<(String, PendingEvent)> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Command line of an exec event ("exec_start: sh -c ls" -> "sh -c ls")
+///
+/// Exec events are not coalesced: each one is a separate session.
+pub fn exec_command(action: &str) -> Option<&str> {
+    action
+        .strip_prefix("exec_start:")
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+}
+
+/// Whether an exec command is the container's own healthcheck (its `Healthcheck.Test`)
+///
+/// Docker runs healthchecks as exec sessions, so without this every probe would show up.
+pub fn is_healthcheck_exec(command: &str, test: &[String]) -> bool {
+    match test.split_first() {
+        // Run through the image's shell, which is not part of the test
+        Some((kind, args)) if kind == "CMD-SHELL" => {
+            !args.is_empty() && command.ends_with(&args.join(" "))
+        }
+        Some((kind, args)) if kind == "CMD" => !args.is_empty() && command == args.join(" "),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn actor(exit_code: Option<&str>) -> EventActor {
+        EventActor {
+            id: Some("abc".to_string()),
+            attributes: exit_code
+                .map(|code| HashMap::from([("exitCode".to_string(), code.into())])),
+        }
+    }
+
+    #[test]
+    fn test_burst_is_merged() {
+        let mut coalescer = EventCoalescer::default();
+        let now = Instant::now();
+
+        // Crash and restart within the delay
+        coalescer.push("abc", "die", actor(Some("1")), None, now);
+        coalescer.push("abc", "stop", actor(None), None, now);
+        coalescer.push("abc", "start", actor(None), None, now);
+        let healthy_at = Utc::now();
+        coalescer.push(
+            "def",
+            "health_status: healthy",
+            actor(None),
+            Some(healthy_at),
+            now,
+        );
+
+        assert!(coalescer.take_due(now).is_empty());
+        assert_eq!(coalescer.next_due(), Some(now + COALESCE_DELAY));
+
+        let due = coalescer.take_due(now + COALESCE_DELAY);
+        assert_eq!(due.len(), 2);
+        let (id, event) = &due[0];
+        assert_eq!(id, "abc");
+        assert!(event.started.is_some());
+        assert_eq!(event.last, LastAction::Start);
+        // The exit code of the die event is kept over the later stop event
+        let attributes = event.stopped.as_ref().unwrap().attributes.as_ref().unwrap();
+        assert_eq!(attributes["exitCode"], "1");
+
+        assert_eq!(due[1].1.last, LastAction::HealthOnly);
+        assert!(due[1].1.renamed.is_none());
+        assert!(due[1].1.health.is_some());
+        assert_eq!(due[1].1.health_at, Some(healthy_at));
+        assert_eq!(coalescer.next_due(), None);
+    }
+
+    #[test]
+    fn test_churning_container_is_debounced() {
+        let mut coalescer = EventCoalescer::default();
+        let start = Instant::now();
+
+        coalescer.push("abc", "start", actor(None), None, start);
+        let flushed = start + COALESCE_DELAY;
+        assert_eq!(coalescer.take_due(flushed).len(), 1);
+
+        // Dies again right after the update: held until the debounce period is over
+        coalescer.push("abc", "die", actor(Some("137")), None, flushed);
+        coalescer.push("abc", "destroy", actor(None), None, flushed);
+        assert_eq!(coalescer.next_due(), Some(flushed + CHURN_DEBOUNCE));
+
+        // Other containers are not held
+        coalescer.push("def", "start", actor(None), None, flushed);
+        let due = coalescer.take_due(flushed + COALESCE_DELAY);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].0, "def");
+
+        let due = coalescer.take_due(flushed + CHURN_DEBOUNCE);
+        assert_eq!(due[0].1.last, LastAction::Destroy);
+        assert!(coalescer.take_all().is_empty());
+    }
+
+    #[test]
+    fn test_rename_and_update() {
+        let mut coalescer = EventCoalescer::default();
+        let now = Instant::now();
+
+        let renamed = |name: &str| EventActor {
+            id: Some("abc".to_string()),
+            attributes: Some(HashMap::from([
+                ("name".to_string(), name.to_string()),
+                ("oldName".to_string(), "/web".to_string()),
+            ])),
+        };
+        coalescer.push("abc", "rename", renamed("/web-old"), None, now);
+        coalescer.push("abc", "rename", renamed("/web-blue"), None, now);
+        coalescer.push("abc", "update", actor(None), None, now);
+
+        let due = coalescer.take_due(now + COALESCE_DELAY);
+        let event = &due[0].1;
+        assert_eq!(event.renamed.as_deref(), Some("web-blue"));
+        assert!(event.updated);
+        assert_eq!(event.last, LastAction::HealthOnly);
+    }
+
+    #[test]
+    fn test_pause_and_unpause() {
+        let mut coalescer = EventCoalescer::default();
+        let now = Instant::now();
+
+        coalescer.push("abc", "pause", actor(None), None, now);
+        coalescer.push("abc", "unpause", actor(None), None, now);
+        coalescer.push("abc", "pause", actor(None), None, now);
+        coalescer.push("def", "pause", actor(None), None, now);
+        coalescer.push("def", "die", actor(Some("137")), None, now);
+
+        let due = coalescer.take_due(now + COALESCE_DELAY);
+        assert_eq!(due[0].1.paused, Some(true));
+        // Stopping decides the state on its own
+        assert_eq!(due[1].1.paused, None);
+        assert_eq!(due[1].1.last, LastAction::Stop);
+    }
+
+    #[test]
+    fn test_exec_command() {
+        assert_eq!(exec_command("exec_start: /bin/bash "), Some("/bin/bash"));
+        assert_eq!(exec_command("exec_start: sh -c ls"), Some("sh -c ls"));
+        assert_eq!(exec_command("exec_start: "), None);
+        assert_eq!(exec_command("start"), None);
+    }
+
+    #[test]
+    fn test_is_healthcheck_exec() {
+        let shell_test = ["CMD-SHELL", "curl -f http://localhost/ || exit 1"].map(String::from);
+        assert!(is_healthcheck_exec(
+            "/bin/sh -c curl -f http://localhost/ || exit 1",
+            &shell_test
+        ));
+        assert!(!is_healthcheck_exec("/bin/sh", &shell_test));
+
+        let exec_test = ["CMD", "pg_isready", "-U", "postgres"].map(String::from);
+        assert!(is_healthcheck_exec("pg_isready -U postgres", &exec_test));
+        assert!(!is_healthcheck_exec("psql -U postgres", &exec_test));
+
+        assert!(!is_healthcheck_exec("/bin/sh", &[]));
+        assert!(!is_healthcheck_exec("/bin/sh", &["NONE".to_string()]));
+    }
+}
@@ -0,0 +1,183 @@
+use bollard::container::LogOutput;
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use bollard::query_parameters::InspectContainerOptions;
+use chrono::Utc;
+use futures_util::StreamExt;
+
+use crate::core::types::{AppEvent, ContainerKey, EventSender, ExecLine, ExecStatus};
+use crate::docker::connection::DockerHost;
+use crate::docker::logs::LogEntry;
+use crate::ui::i18n;
+
+/// Runs the container's configured health check command, streaming its output
+pub async fn run_healthcheck(host: DockerHost, key: ContainerKey, tx: EventSender) {
+    let status = match fetch_healthcheck_command(&host, &key.container_id).await {
+        Ok(cmd) => run_exec_command(&host, &key, cmd, &tx).await,
+        Err(e) => ExecStatus::Failed(e),
+    };
+    let _ = tx.send(AppEvent::ExecFinished(key, status)).await;
+}
+
+/// The health check command from the container's config
+async fn fetch_healthcheck_command(
+    host: &DockerHost,
+    container_id: &str,
+) -> Result<Vec<String>, String> {
+    let inspect = host
+        .docker
+        .inspect_container(container_id, None::<InspectContainerOptions>)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().inspect_failed, &[&e]))?;
+
+    inspect
+        .config
+        .and_then(|config| config.healthcheck)
+        .and_then(|healthcheck| healthcheck.test)
+        .and_then(|test| healthcheck_command(&test, host.windows))
+        .ok_or_else(|| i18n::messages().no_healthcheck.to_string())
+}
+
+/// Runs a command line typed in by the user with the container's shell, streaming
+/// its output
+pub async fn run_command(host: DockerHost, key: ContainerKey, command: String, tx: EventSender) {
+    let cmd = shell_command(&command, host.windows);
+    let status = run_exec_command(&host, &key, cmd, &tx).await;
+    let _ = tx.send(AppEvent::ExecFinished(key, status)).await;
+}
+
+/// Command line of a HEALTHCHECK test: ["CMD", args...] or ["CMD-SHELL", command],
+/// the latter run by the daemon's default shell
+/// ("NONE" or an empty test means there is none to run)
+fn healthcheck_command(test: &[String], windows: bool) -> Option<Vec<String>> {
+    match test.split_first()? {
+        (kind, args) if kind == "CMD" && !args.is_empty() => Some(args.to_vec()),
+        (kind, [command]) if kind == "CMD-SHELL" => Some(shell_command(command, windows)),
+        _ => None,
+    }
+}
+
+/// A command line run the way Docker runs shell-form commands (cmd on Windows)
+fn shell_command(command: &str, windows: bool) -> Vec<String> {
+    let shell: &[&str] = if windows {
+        &["cmd", "/S", "/C"]
+    } else {
+        &["/bin/sh", "-c"]
+    };
+    shell
+        .iter()
+        .map(|part| part.to_string())
+        .chain([command.to_string()])
+        .collect()
+}
+
+/// Runs a command without a TTY, sending its stdout and stderr line by line
+pub async fn run_exec_command(
+    host: &DockerHost,
+    key: &ContainerKey,
+    cmd: Vec<String>,
+    tx: &EventSender,
+) -> ExecStatus {
+    let command_line = cmd.join(" ");
+    let exec_config = CreateExecOptions {
+        cmd: Some(cmd),
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        tty: Some(false),
+        ..Default::default()
+    };
+
+    let exec = match host
+        .docker
+        .create_exec(&key.container_id, exec_config)
+        .await
+    {
+        Ok(exec) => exec,
+        Err(e) => {
+            return ExecStatus::Failed(i18n::fill(i18n::messages().create_exec_failed, &[&e]));
+        }
+    };
+    let _ = tx
+        .send(AppEvent::ExecStarted(key.clone(), command_line))
+        .await;
+
+    let mut output = match host.docker.start_exec(&exec.id, None).await {
+        Ok(StartExecResults::Attached { output, .. }) => output,
+        Ok(StartExecResults::Detached) => {
+            return ExecStatus::Failed(i18n::messages().exec_detached.to_string());
+        }
+        Err(e) => return ExecStatus::Failed(i18n::fill(i18n::messages().start_exec_failed, &[&e])),
+    };
+
+    // Output arrives in chunks; lines can span several of them
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    while let Some(Ok(chunk)) = output.next().await {
+        let (buffer, is_stderr) = match chunk {
+            LogOutput::StdOut { message } | LogOutput::Console { message } => {
+                stdout.extend_from_slice(&message);
+                (&mut stdout, false)
+            }
+            LogOutput::StdErr { message } => {
+                stderr.extend_from_slice(&message);
+                (&mut stderr, true)
+            }
+            LogOutput::StdIn { .. } => continue,
+        };
+        while let Some(end) = buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=end).collect();
+            send_exec_line(key, &line, is_stderr, tx).await;
+        }
+    }
+
+    // A last line without a newline
+    for (rest, is_stderr) in [(stdout, false), (stderr, true)] {
+        if !rest.is_empty() {
+            send_exec_line(key, &rest, is_stderr, tx).await;
+        }
+    }
+
+    let exit_code = host
+        .docker
+        .inspect_exec(&exec.id)
+        .await
+        .ok()
+        .and_then(|inspect| inspect.exit_code);
+    ExecStatus::Exited(exit_code)
+}
+
+async fn send_exec_line(key: &ContainerKey, line: &[u8], is_stderr: bool, tx: &EventSender) {
+    let text = String::from_utf8_lossy(line);
+    let line = ExecLine {
+        entry: LogEntry::from_message(Utc::now(), text.trim_end_matches(['\r', '\n'])),
+        is_stderr,
+    };
+    let _ = tx.send(AppEvent::ExecOutput(key.clone(), line)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(parts: &[&str]) -> Vec<String> {
+        parts.iter().map(|part| part.to_string()).collect()
+    }
+
+    #[test]
+    fn test_healthcheck_command() {
+        assert_eq!(
+            healthcheck_command(&strings(&["CMD", "curl", "-f", "http://localhost/"]), false),
+            Some(strings(&["curl", "-f", "http://localhost/"]))
+        );
+        assert_eq!(
+            healthcheck_command(&strings(&["CMD-SHELL", "pg_isready || exit 1"]), false),
+            Some(strings(&["/bin/sh", "-c", "pg_isready || exit 1"]))
+        );
+        assert_eq!(
+            healthcheck_command(&strings(&["CMD-SHELL", "ping -n 1 localhost"]), true),
+            Some(strings(&["cmd", "/S", "/C", "ping -n 1 localhost"]))
+        );
+        assert_eq!(healthcheck_command(&strings(&["NONE"]), false), None);
+        assert_eq!(healthcheck_command(&strings(&["CMD"]), false), None);
+        assert_eq!(healthcheck_command(&[], false), None);
+    }
+}
@@ -0,0 +1,304 @@
+//! Files inside a container, for the file browser
+//!
+//! Directories are listed by running `ls -la` in the container, so the container
+//! must be running and have an `ls` (busybox's will do). Downloads use the archive
+//! API, which returns the file or directory as a tar that is unpacked locally as it
+//! streams in.
+
+use bollard::container::LogOutput;
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use bollard::query_parameters::DownloadFromContainerOptions;
+use futures_util::StreamExt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio_util::io::{StreamReader, SyncIoBridge};
+
+use crate::core::types::{AppEvent, ContainerKey, EventSender, FileEntry, FileKind};
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Lists a directory in the background, sending FilesLoaded
+pub async fn load_directory(host: DockerHost, key: ContainerKey, path: String, tx: EventSender) {
+    let result = list_directory(&host, &key.container_id, &path).await;
+    let _ = tx.send(AppEvent::FilesLoaded(key, path, result)).await;
+}
+
+/// Downloads a file or directory into `dest_dir` in the background, sending
+/// FileDownloaded with the local path
+pub async fn download(
+    host: DockerHost,
+    key: ContainerKey,
+    path: String,
+    dest_dir: PathBuf,
+    tx: EventSender,
+) {
+    let result = download_path(&host, &key.container_id, &path, &dest_dir).await;
+    let _ = tx.send(AppEvent::FileDownloaded(key, result)).await;
+}
+
+/// The entries of a directory, directories first, then by name
+async fn list_directory(
+    host: &DockerHost,
+    container_id: &str,
+    path: &str,
+) -> Result<Vec<FileEntry>, String> {
+    // The trailing slash lists the target of a symlinked directory, not the link
+    let exec_config = CreateExecOptions {
+        cmd: Some(vec![
+            "ls".to_string(),
+            "-la".to_string(),
+            format!("{}/", path.trim_end_matches('/')),
+        ]),
+        env: Some(vec!["LC_ALL=C".to_string()]),
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        tty: Some(false),
+        ..Default::default()
+    };
+    let exec = host
+        .docker
+        .create_exec(container_id, exec_config)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_files_failed, &[&e]))?;
+
+    let mut output = match host.docker.start_exec(&exec.id, None).await {
+        Ok(StartExecResults::Attached { output, .. }) => output,
+        Ok(StartExecResults::Detached) => {
+            return Err(i18n::messages().exec_detached.to_string());
+        }
+        Err(e) => return Err(i18n::fill(i18n::messages().list_files_failed, &[&e])),
+    };
+
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    while let Some(Ok(chunk)) = output.next().await {
+        match chunk {
+            LogOutput::StdOut { message } | LogOutput::Console { message } => {
+                stdout.extend_from_slice(&message)
+            }
+            LogOutput::StdErr { message } => stderr.extend_from_slice(&message),
+            LogOutput::StdIn { .. } => {}
+        }
+    }
+
+    let exit_code = host
+        .docker
+        .inspect_exec(&exec.id)
+        .await
+        .ok()
+        .and_then(|inspect| inspect.exit_code);
+    if exit_code != Some(0) {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(match stderr.trim() {
+            "" => i18n::fill(i18n::messages().ls_exited, &[&exit_code.unwrap_or(-1)]),
+            error => error.to_string(),
+        });
+    }
+
+    Ok(parse_listing(&String::from_utf8_lossy(&stdout)))
+}
+
+/// Parses `ls -la` output: mode, links, owner, group, size, date (three fields) and
+/// the name, which may contain spaces (" -> target" for symlinks)
+fn parse_listing(output: &str) -> Vec<FileEntry> {
+    let mut entries: Vec<FileEntry> = output.lines().filter_map(parse_line).collect();
+    entries.sort_by(|a, b| {
+        let is_dir = |entry: &FileEntry| entry.kind == FileKind::Directory;
+        is_dir(b).cmp(&is_dir(a)).then_with(|| a.name.cmp(&b.name))
+    });
+    entries
+}
+
+fn parse_line(line: &str) -> Option<FileEntry> {
+    let (mode, rest) = next_field(line)?;
+    let kind = match mode.chars().next()? {
+        'd' => FileKind::Directory,
+        'l' => FileKind::Symlink,
+        '-' => FileKind::File,
+        'b' | 'c' | 'p' | 's' => FileKind::Other,
+        _ => return None, // "total 12"
+    };
+    let (_links, rest) = next_field(rest)?;
+    let (owner, rest) = next_field(rest)?;
+    let (_group, rest) = next_field(rest)?;
+    let (size, mut rest) = next_field(rest)?;
+    // Devices show "major, minor" instead of a size
+    if size.ends_with(',') {
+        rest = next_field(rest)?.1;
+    }
+    let (month, rest) = next_field(rest)?;
+    let (day, rest) = next_field(rest)?;
+    let (time, rest) = next_field(rest)?;
+    let name = rest.strip_prefix(' ')?;
+
+    let (name, link_target) = match (kind, name.split_once(" -> ")) {
+        (FileKind::Symlink, Some((name, target))) => (name, Some(target.to_string())),
+        _ => (name, None),
+    };
+    if name == "." || name == ".." || name.is_empty() {
+        return None;
+    }
+
+    Some(FileEntry {
+        name: name.to_string(),
+        kind,
+        size: size.parse().unwrap_or(0),
+        owner: owner.to_string(),
+        modified: format!("{} {:>2} {:>5}", month, day, time),
+        link_target,
+    })
+}
+
+/// The next whitespace-separated field and the rest of the line after it
+fn next_field(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    if line.is_empty() {
+        return None;
+    }
+    let end = line.find(char::is_whitespace).unwrap_or(line.len());
+    Some(line.split_at(end))
+}
+
+/// Joins a directory and an entry name into a container path
+pub fn child_path(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+/// The parent of a container path ("/" has none)
+pub fn parent_path(path: &str) -> Option<String> {
+    let path = path.trim_end_matches('/');
+    if path.is_empty() {
+        return None;
+    }
+    match path.rsplit_once('/') {
+        Some(("", _)) | None => Some("/".to_string()),
+        Some((parent, _)) => Some(parent.to_string()),
+    }
+}
+
+/// Where downloads go: the configured directory, or the user's download directory
+/// (the home directory when there is none), with a directory per container
+pub fn download_dir(configured: Option<&str>, container_name: &str) -> Option<PathBuf> {
+    let base = match configured {
+        // Expand ~ like a shell would
+        Some(dir) => match dir.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir()?.join(rest),
+            None => PathBuf::from(dir),
+        },
+        None => dirs::download_dir().or_else(dirs::home_dir)?,
+    };
+    Some(base.join(sanitize(container_name)))
+}
+
+/// Keeps a container name usable as a directory name
+fn sanitize(name: &str) -> String {
+    name.trim_start_matches('/')
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || "-_.".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Fetches the tar of a path and unpacks it into `dest_dir` as it arrives, overwriting
+/// what is there
+async fn download_path(
+    host: &DockerHost,
+    container_id: &str,
+    path: &str,
+    dest_dir: &Path,
+) -> Result<PathBuf, String> {
+    let options = DownloadFromContainerOptions {
+        path: path.to_string(),
+    };
+
+    // Kept aside so a failed download isn't reported as a failed save
+    let download_error = Arc::new(Mutex::new(None));
+    let stream = {
+        let download_error = download_error.clone();
+        let path = path.to_string();
+        host.docker
+            .download_from_container(container_id, Some(options))
+            .map(move |chunk| {
+                chunk.map_err(|e| {
+                    let message = i18n::fill(i18n::messages().download_failed, &[&path, &e]);
+                    *download_error.lock().unwrap() = Some(message.clone());
+                    io::Error::other(message)
+                })
+            })
+    };
+    let archive = SyncIoBridge::new(StreamReader::new(Box::pin(stream)));
+
+    let dest_dir = dest_dir.to_path_buf();
+    let name = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(path);
+    let local_path = dest_dir.join(name);
+    let result = tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(&dest_dir)?;
+        tar::Archive::new(archive).unpack(&dest_dir)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    if let Err(e) = result {
+        return Err(download_error.lock().unwrap().take().unwrap_or_else(|| {
+            i18n::fill(i18n::messages().save_failed, &[&local_path.display(), &e])
+        }));
+    }
+    Ok(local_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_listing() {
+        let output = "\
+total 20
+drwxr-xr-x    1 root     root          4096 Oct 17 10:15 .
+drwxr-xr-x    1 root     root          4096 Oct 17 10:15 ..
+-rw-r--r--    1 root     root           123 Jan  2  2024 my notes.txt
+drwxr-xr-x    2 nginx    nginx         4096 Oct 17 09:01 conf.d
+lrwxrwxrwx    1 root     root            11 Oct 17 09:01 current -> /srv/v2
+crw-rw-rw-    1 root     root        1,   3 Oct 17 09:01 null
+";
+        let entries = parse_listing(output);
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["conf.d", "current", "my notes.txt", "null"]);
+
+        assert_eq!(entries[0].kind, FileKind::Directory);
+        assert_eq!(entries[0].owner, "nginx");
+        assert_eq!(entries[1].kind, FileKind::Symlink);
+        assert_eq!(entries[1].link_target.as_deref(), Some("/srv/v2"));
+        assert_eq!(entries[2].size, 123);
+        assert_eq!(entries[2].modified, "Jan  2  2024");
+        assert_eq!(entries[3].kind, FileKind::Other);
+        assert_eq!(entries[3].modified, "Oct 17 09:01");
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(child_path("/", "etc"), "/etc");
+        assert_eq!(child_path("/etc", "nginx"), "/etc/nginx");
+        assert_eq!(parent_path("/etc/nginx"), Some("/etc".to_string()));
+        assert_eq!(parent_path("/etc"), Some("/".to_string()));
+        assert_eq!(parent_path("/"), None);
+    }
+
+    #[test]
+    fn test_download_dir() {
+        assert_eq!(
+            download_dir(Some("/tmp/dl"), "/my app"),
+            Some(PathBuf::from("/tmp/dl/my_app"))
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use crate::core::types::{AppEvent, EventSender, HostInfo};
+use crate::docker::connection::DockerHost;
+use crate::docker::systemd::CommandHost;
+
+/// How often a host's CPU count, memory and load are read
+const INFO_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Where the local load is read from
+const LOADAVG_PATH: &str = "/proc/loadavg";
+
+/// Reads the host's CPU count and memory from Docker's info every INFO_INTERVAL until the
+/// app stops listening; the load is read from /proc for the local host only
+/// A failed read (host unreachable) is skipped
+pub async fn monitor_host_info(host: DockerHost, tx: EventSender) {
+    let local = host.command_host == Some(CommandHost::Local) && !host.windows;
+
+    while !tx.is_closed() {
+        match host.docker.info().await {
+            Ok(info) => {
+                let load1 = if local {
+                    tokio::fs::read_to_string(LOADAVG_PATH)
+                        .await
+                        .ok()
+                        .and_then(|loadavg| parse_loadavg(&loadavg))
+                } else {
+                    None
+                };
+                let info = HostInfo {
+                    cpus: info.ncpu.unwrap_or(0).max(0) as u64,
+                    memory_bytes: info.mem_total.unwrap_or(0).max(0) as u64,
+                    load1,
+                };
+                let _ = tx
+                    .send(AppEvent::HostInfo(host.host_id.clone(), info))
+                    .await;
+            }
+            Err(e) => tracing::debug!("Host info of {}: {}", host.host_id, e),
+        }
+        tokio::time::sleep(INFO_INTERVAL).await;
+    }
+}
+
+/// The one-minute load of /proc/loadavg ("0.52 0.58 0.59 1/389 12345")
+fn parse_loadavg(loadavg: &str) -> Option<f64> {
+    loadavg.split_whitespace().next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loadavg() {
+        assert_eq!(parse_loadavg("0.52 0.58 0.59 1/389 12345\n"), Some(0.52));
+        assert_eq!(parse_loadavg(""), None);
+        assert_eq!(parse_loadavg("n/a"), None);
+    }
+}
@@ -0,0 +1,210 @@
+use bollard::container::LogOutput;
+use bollard::errors::Error;
+use bollard::models::{ContainerCreateBody, HostConfig};
+use bollard::query_parameters::{
+    CreateContainerOptions, LogsOptions, RemoveContainerOptions, WaitContainerOptions,
+};
+use futures_util::StreamExt;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::core::types::{AppEvent, EventSender, HostResources};
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Label of the short-lived containers reading a host's disk space and load,
+/// kept out of the container list and the events timeline
+pub const PROBE_LABEL: &str = "dtop.probe";
+
+/// Image of the probe containers, pulled when missing
+const PROBE_IMAGE: &str = "busybox:stable";
+
+/// Where the host's data root is mounted inside the probe container
+const PROBE_MOUNT: &str = "/data";
+
+/// How often a host's disk space and load are measured
+const PROBE_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Measures the disk space of the host's data root and its load every PROBE_INTERVAL
+/// until the app stops listening
+/// A failed measurement (host unreachable, image can't be pulled) is skipped
+pub async fn monitor_host_resources(host: DockerHost, tx: EventSender) {
+    while !tx.is_closed() {
+        match probe_host_resources(&host).await {
+            Ok(resources) => {
+                let _ = tx
+                    .send(AppEvent::HostResources(host.host_id.clone(), resources))
+                    .await;
+            }
+            Err(e) => tracing::debug!("Host resources of {}: {}", host.host_id, e),
+        }
+        tokio::time::sleep(PROBE_INTERVAL).await;
+    }
+}
+
+/// Runs `df` on the data root and reads /proc/loadavg in a throwaway busybox container
+/// (Docker's info has neither); the CPU count comes from the info
+async fn probe_host_resources(host: &DockerHost) -> Result<HostResources, String> {
+    let info = host
+        .docker
+        .info()
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().host_info_failed, &[&e]))?;
+    let root = info
+        .docker_root_dir
+        .ok_or_else(|| i18n::messages().unknown_data_root.to_string())?;
+    let cpus = info.ncpu.unwrap_or(0).max(0) as u64;
+
+    let output = run_probe(host, &root).await?;
+    parse_probe_output(&output, cpus)
+        .ok_or_else(|| i18n::fill(i18n::messages().unexpected_output, &[&output]))
+}
+
+/// Creates the probe container (pulling its image if needed), runs it and returns its
+/// output; the container is removed whatever happens
+async fn run_probe(host: &DockerHost, root: &str) -> Result<String, String> {
+    let config = ContainerCreateBody {
+        image: Some(PROBE_IMAGE.to_string()),
+        cmd: Some(vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("df -Pk {} && cat /proc/loadavg", PROBE_MOUNT),
+        ]),
+        labels: Some(HashMap::from([(
+            PROBE_LABEL.to_string(),
+            "true".to_string(),
+        )])),
+        host_config: Some(HostConfig {
+            binds: Some(vec![format!("{}:{}:ro", root, PROBE_MOUNT)]),
+            network_mode: Some("none".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let created = match host
+        .docker
+        .create_container(None::<CreateContainerOptions>, config.clone())
+        .await
+    {
+        Err(Error::DockerResponseServerError {
+            status_code: 404, ..
+        }) => {
+            host.pull_image(PROBE_IMAGE, None).await?;
+            host.docker
+                .create_container(None::<CreateContainerOptions>, config)
+                .await
+        }
+        created => created,
+    }
+    .map_err(|e| i18n::fill(i18n::messages().probe_create_failed, &[&e]))?;
+
+    let output = probe_output(host, &created.id).await;
+
+    let options = RemoveContainerOptions {
+        force: true,
+        ..Default::default()
+    };
+    let _ = host
+        .docker
+        .remove_container(&created.id, Some(options))
+        .await;
+
+    output
+}
+
+/// Starts the probe container, waits for it to exit and collects its standard output
+async fn probe_output(host: &DockerHost, container_id: &str) -> Result<String, String> {
+    host.docker
+        .start_container(container_id, None)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().probe_start_failed, &[&e]))?;
+
+    let mut wait = host
+        .docker
+        .wait_container(container_id, None::<WaitContainerOptions>);
+    while let Some(result) = wait.next().await {
+        result.map_err(|e| i18n::fill(i18n::messages().probe_failed, &[&e]))?;
+    }
+
+    let options = LogsOptions {
+        stdout: true,
+        ..Default::default()
+    };
+    let mut logs = host.docker.logs(container_id, Some(options));
+    let mut output = String::new();
+    while let Some(chunk) = logs.next().await {
+        if let LogOutput::StdOut { message } =
+            chunk.map_err(|e| i18n::fill(i18n::messages().probe_output_failed, &[&e]))?
+        {
+            output.push_str(&String::from_utf8_lossy(&message));
+        }
+    }
+    Ok(output)
+}
+
+/// Parses the output of `df -Pk` (header and one line, sizes in KiB) followed by
+/// /proc/loadavg ("0.52 0.58 0.59 1/389 12345")
+fn parse_probe_output(output: &str, cpus: u64) -> Option<HostResources> {
+    let mut lines = output.lines().filter(|line| !line.trim().is_empty());
+    let _header = lines.next()?;
+
+    // Counted from the end, the filesystem name may contain spaces
+    let df: Vec<&str> = lines.next()?.split_whitespace().collect();
+    let field = |from_end: usize| -> Option<u64> {
+        df.len()
+            .checked_sub(from_end)
+            .and_then(|i| df.get(i))
+            .and_then(|value| value.parse().ok())
+    };
+    let used_kib = field(4)?;
+    let available_kib = field(3)?;
+
+    let load1 = lines.next()?.split_whitespace().next()?.parse().ok()?;
+
+    Some(HostResources {
+        disk_used_bytes: used_kib * 1024,
+        disk_available_bytes: available_kib * 1024,
+        load1,
+        cpus,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_probe_output() {
+        let output = "Filesystem           1024-blocks    Used Available Capacity Mounted on\n\
+                      /dev/sda1              102400     92160     10240  90% /data\n\
+                      3.52 2.58 1.59 2/389 12345\n";
+        assert_eq!(
+            parse_probe_output(output, 2),
+            Some(HostResources {
+                disk_used_bytes: 92160 * 1024,
+                disk_available_bytes: 10240 * 1024,
+                load1: 3.52,
+                cpus: 2,
+            })
+        );
+
+        // Filesystem names with spaces
+        let output = "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+                      My Disk 100 40 60 40% /data\n\
+                      0.10 0.20 0.30 1/100 42\n";
+        let resources = parse_probe_output(output, 1).unwrap();
+        assert_eq!(resources.disk_used_bytes, 40 * 1024);
+        assert_eq!(resources.disk_available_bytes, 60 * 1024);
+
+        // df failed, only its error made it
+        assert_eq!(parse_probe_output("", 1), None);
+        assert_eq!(
+            parse_probe_output(
+                "Filesystem 1024-blocks Used Available Capacity Mounted on\n",
+                1
+            ),
+            None
+        );
+    }
+}
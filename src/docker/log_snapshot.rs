@@ -0,0 +1,127 @@
+use bollard::query_parameters::{InspectContainerOptions, LogsOptions};
+use chrono::{DateTime, Local};
+use futures_util::StreamExt;
+use std::path::PathBuf;
+
+use crate::cli::config::LogSnapshotsConfig;
+use crate::core::types::ContainerKey;
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Log lines saved when the config doesn't say how many
+const DEFAULT_SNAPSHOT_LINES: usize = 1000;
+
+/// Saves the last log lines of a container before it is stopped or removed,
+/// so they survive the container
+#[derive(Debug, Clone)]
+pub struct LogSnapshots {
+    dir: PathBuf,
+    lines: usize,
+}
+
+impl LogSnapshots {
+    /// Snapshots as configured; None when no directory is set and the
+    /// platform's data directory is unknown
+    pub fn from_config(config: &LogSnapshotsConfig) -> Option<Self> {
+        let dir = match config.dir.as_deref() {
+            // Expand ~ like a shell would
+            Some(dir) => match dir.strip_prefix("~/") {
+                Some(rest) => dirs::home_dir()?.join(rest),
+                None => PathBuf::from(dir),
+            },
+            None => dirs::data_dir()?.join("dtop").join("log-snapshots"),
+        };
+
+        Some(Self {
+            dir,
+            lines: config.lines.unwrap_or(DEFAULT_SNAPSHOT_LINES),
+        })
+    }
+
+    /// Writes the container's last log lines, with their timestamps, to
+    /// `<dir>/<host>/<name>-<local time>.log`, returning the file written
+    pub async fn capture(&self, host: &DockerHost, key: &ContainerKey) -> Result<PathBuf, String> {
+        // Name the file after the container rather than its ID when Docker still knows it
+        let name = host
+            .docker
+            .inspect_container(&key.container_id, None::<InspectContainerOptions>)
+            .await
+            .ok()
+            .and_then(|info| info.name)
+            .map(|name| name.trim_start_matches('/').to_string())
+            .unwrap_or_else(|| key.short_id().to_string());
+
+        let options = Some(LogsOptions {
+            stdout: true,
+            stderr: true,
+            timestamps: true,
+            tail: self.lines.to_string(),
+            ..Default::default()
+        });
+
+        let mut log_stream = host.docker.logs(&key.container_id, options);
+        let mut contents = String::new();
+        while let Some(log_result) = log_stream.next().await {
+            let log_output =
+                log_result.map_err(|e| i18n::fill(i18n::messages().read_logs_failed, &[&e]))?;
+            contents.push_str(&log_output.to_string());
+        }
+
+        let path = self.path_for(&key.host_id, &name, Local::now());
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| i18n::fill(i18n::messages().create_failed, &[&dir.display(), &e]))?;
+        }
+        tokio::fs::write(&path, contents)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().write_failed, &[&path.display(), &e]))?;
+
+        Ok(path)
+    }
+
+    /// File a snapshot taken at `time` is written to
+    fn path_for(&self, host_id: &str, name: &str, time: DateTime<Local>) -> PathBuf {
+        self.dir.join(file_name_safe(host_id)).join(format!(
+            "{}-{}.log",
+            file_name_safe(name),
+            time.format("%Y%m%d-%H%M%S")
+        ))
+    }
+}
+
+/// Replaces the characters of a host ID or container name that don't belong in a
+/// file name ("ssh://user@host" -> "ssh___user_host")
+fn file_name_safe(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_snapshot_path() {
+        let snapshots = LogSnapshots::from_config(&LogSnapshotsConfig {
+            dir: Some("/var/log/dtop".to_string()),
+            lines: None,
+        })
+        .unwrap();
+        assert_eq!(snapshots.lines, DEFAULT_SNAPSHOT_LINES);
+
+        let time = Local.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+        assert_eq!(
+            snapshots.path_for("ssh://user@db-host", "app_db.1", time),
+            PathBuf::from("/var/log/dtop/ssh___user_db-host/app_db.1-20260304-050607.log")
+        );
+    }
+}
@@ -8,12 +8,115 @@ use crate::core::types::{AppEvent, ContainerKey, EventSender};
 use crate::docker::connection::DockerHost;
 use crate::docker::json_formatter;
 
+/// Words scanned from the start of a plain text message when looking for its level
+const LEVEL_SCAN_TOKENS: usize = 12;
+
+/// Severity of a log line, detected from its content
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 4] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+        }
+    }
+
+    fn from_word(word: &str) -> Option<Self> {
+        match word.to_ascii_uppercase().as_str() {
+            "ERROR" | "ERR" | "FATAL" | "CRITICAL" | "CRIT" | "PANIC" => Some(LogLevel::Error),
+            "WARN" | "WARNING" => Some(LogLevel::Warn),
+            "INFO" => Some(LogLevel::Info),
+            "DEBUG" | "TRACE" => Some(LogLevel::Debug),
+            _ => None,
+        }
+    }
+
+    /// Detects the level of a plain text message, e.g. "[ERROR] ...", "WARN main: ..."
+    /// or "level=info ...". Lowercase words only count after a level key, so that
+    /// "connection error" in a message isn't taken for its level
+    fn detect(message: &str) -> Option<Self> {
+        let mut after_key = false;
+        for word in message
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .take(LEVEL_SCAN_TOKENS)
+        {
+            let uppercase = word.chars().all(|c| c.is_ascii_uppercase());
+            if (uppercase || after_key)
+                && let Some(level) = Self::from_word(word)
+            {
+                return Some(level);
+            }
+            after_key = is_level_key(word);
+        }
+        None
+    }
+
+    /// Detects the level of a JSON message from its level field
+    fn from_json(json_value: &serde_json::Value) -> Option<Self> {
+        let object = json_value.as_object()?;
+        object
+            .iter()
+            .find(|(key, _)| is_level_key(key))
+            .and_then(|(_, value)| value.as_str())
+            .and_then(Self::from_word)
+    }
+}
+
+fn is_level_key(key: &str) -> bool {
+    matches!(
+        key.to_ascii_lowercase().as_str(),
+        "level" | "lvl" | "severity"
+    )
+}
+
+/// Hashes a message with every run of digits masked, so that lines differing only
+/// in counters, ids or durations ("retry 3/5 in 200ms") fold together
+fn fingerprint(message: &str) -> u64 {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    let mut in_digits = false;
+    for c in message.chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                '#'.hash(&mut hasher);
+            }
+            in_digits = true;
+        } else {
+            c.hash(&mut hasher);
+            in_digits = false;
+        }
+    }
+    hasher.finish()
+}
+
 /// A parsed log entry with timestamp and ANSI-parsed content
 #[derive(Clone, Debug)]
 pub struct LogEntry {
     pub timestamp: DateTime<Utc>,
     /// Parsed ANSI text ready for rendering
     pub text: Text<'static>,
+    /// Level detected from the content, if any
+    pub level: Option<LogLevel>,
+    /// Hash of the message with numbers masked, equal for near-identical lines
+    pub fingerprint: u64,
 }
 
 impl LogEntry {
@@ -29,20 +132,54 @@ impl LogEntry {
             .ok()?
             .with_timezone(&Utc);
 
+        Some(Self::from_message(timestamp, message.trim()))
+    }
+
+    /// Builds an entry from a message without a timestamp prefix (e.g. exec output),
+    /// formatting JSON and parsing ANSI colors
+    pub fn from_message(timestamp: DateTime<Utc>, message: &str) -> Self {
         // Try to detect and format JSON
-        let text = if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(message.trim())
-        {
-            json_formatter::format_json_as_text(&json_value)
+        let mut entry = if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(message) {
+            LogEntry {
+                timestamp,
+                text: json_formatter::format_json_as_text(&json_value),
+                level: LogLevel::from_json(&json_value),
+                fingerprint: 0,
+            }
         } else {
             // Not JSON, try ANSI parsing for colored text
-            message
-                .trim()
+            let text = message
                 .as_bytes()
                 .into_text()
-                .unwrap_or_else(|_| Text::from(message.to_string()))
+                .unwrap_or_else(|_| Text::from(message.to_string()));
+            LogEntry {
+                timestamp,
+                text,
+                level: None,
+                fingerprint: 0,
+            }
         };
+        let plain_text = entry.plain_text();
+        if entry.level.is_none() {
+            entry.level = LogLevel::detect(&plain_text);
+        }
+        entry.fingerprint = fingerprint(&plain_text);
 
-        Some(LogEntry { timestamp, text })
+        entry
+    }
+
+    /// The message without styling, as shown on screen
+    pub fn plain_text(&self) -> String {
+        self.text
+            .lines
+            .first()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 }
 
@@ -265,6 +402,55 @@ mod tests {
         assert!(!entry.text.lines.is_empty());
     }
 
+    #[test]
+    fn test_parse_log_entry_level() {
+        let level = |message: &str| {
+            LogEntry::parse(&format!("2025-10-28T12:34:56.789Z {}", message))
+                .unwrap()
+                .level
+        };
+
+        assert_eq!(level("[ERROR] disk full"), Some(LogLevel::Error));
+        assert_eq!(
+            level("2025/10/28 12:34:56 WARN retrying"),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(
+            level("time=12:34 level=info msg=ready"),
+            Some(LogLevel::Info)
+        );
+        assert_eq!(
+            level(r#"{"msg":"cache miss","severity":"debug"}"#),
+            Some(LogLevel::Debug)
+        );
+        assert_eq!(
+            level("\x1b[31mFATAL\x1b[0m out of memory"),
+            Some(LogLevel::Error)
+        );
+        // Lowercase words in the message aren't a level
+        assert_eq!(level("connection error, retrying"), None);
+        assert_eq!(level("GET /health 200"), None);
+    }
+
+    #[test]
+    fn test_parse_log_entry_fingerprint() {
+        let fingerprint = |message: &str| {
+            LogEntry::parse(&format!("2025-10-28T12:34:56.789Z {}", message))
+                .unwrap()
+                .fingerprint
+        };
+
+        assert_eq!(
+            fingerprint("retry 3/5 in 200ms"),
+            fingerprint("retry 4/5 in 1500ms")
+        );
+        assert_ne!(
+            fingerprint("retry 3/5 in 200ms"),
+            fingerprint("retry 3/5 in 200s")
+        );
+        assert_ne!(fingerprint("connected"), fingerprint("disconnected"));
+    }
+
     #[test]
     fn test_parse_log_entry_with_multiple_spaces() {
         let log_line = "2025-10-28T12:34:56.789Z Message with   multiple spaces";
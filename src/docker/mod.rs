@@ -1,6 +1,22 @@
 pub mod actions;
+pub mod build;
+pub mod cleanup;
 pub mod connection;
+pub mod events;
+pub mod exec;
+pub mod files;
+pub mod host_info;
+pub mod host_resources;
 pub mod json_formatter;
+pub mod log_snapshot;
 pub mod logs;
+pub mod networks;
+pub mod registry;
 pub mod shell;
 pub mod stats;
+pub mod stats_share;
+pub mod status;
+pub mod system;
+pub mod systemd;
+pub mod top;
+pub mod volumes;
@@ -0,0 +1,140 @@
+//! Docker networks and the containers attached to them
+//!
+//! Attachments come from the unfiltered container list (one call per host
+//! instead of an inspect per network), so stopped containers that keep their
+//! network endpoint are listed too.
+
+use bollard::models::{ContainerSummary, Network};
+use bollard::query_parameters::ListContainersOptions;
+use std::collections::HashMap;
+
+use crate::core::types::{HostId, NetworkInfo};
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Lists the networks of a host with their subnets and attached containers
+pub async fn fetch_networks(host: &DockerHost) -> Result<Vec<NetworkInfo>, String> {
+    let networks = host
+        .docker
+        .list_networks(None)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_networks_failed, &[&e]))?;
+
+    let containers = host
+        .docker
+        .list_containers(Some(ListContainersOptions {
+            all: true,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_containers_failed, &[&e]))?;
+    let attached = attached_containers(containers);
+
+    let mut networks: Vec<NetworkInfo> = networks
+        .into_iter()
+        .map(|network| network_info(&host.host_id, network, &attached))
+        .collect();
+    networks.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(networks)
+}
+
+/// Container names per network name, sorted
+fn attached_containers(containers: Vec<ContainerSummary>) -> HashMap<String, Vec<String>> {
+    let mut attached: HashMap<String, Vec<String>> = HashMap::new();
+    for container in containers {
+        let name = container
+            .names
+            .and_then(|names| names.into_iter().next())
+            .map(|name| name.trim_start_matches('/').to_string())
+            .unwrap_or_default();
+        let networks = container
+            .network_settings
+            .and_then(|settings| settings.networks)
+            .unwrap_or_default();
+        for network in networks.into_keys() {
+            attached.entry(network).or_default().push(name.clone());
+        }
+    }
+    for names in attached.values_mut() {
+        names.sort();
+    }
+    attached
+}
+
+fn network_info(
+    host_id: &HostId,
+    network: Network,
+    attached: &HashMap<String, Vec<String>>,
+) -> NetworkInfo {
+    let name = network.name.unwrap_or_default();
+    let subnets = network
+        .ipam
+        .and_then(|ipam| ipam.config)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|config| config.subnet)
+        .collect();
+
+    NetworkInfo {
+        host_id: host_id.clone(),
+        driver: network.driver.unwrap_or_default(),
+        scope: network.scope.unwrap_or_default(),
+        subnets,
+        containers: attached.get(&name).cloned().unwrap_or_default(),
+        name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{ContainerSummaryNetworkSettings, EndpointSettings, Ipam, IpamConfig};
+
+    fn container(name: &str, networks: &[&str]) -> ContainerSummary {
+        ContainerSummary {
+            names: Some(vec![format!("/{}", name)]),
+            network_settings: Some(ContainerSummaryNetworkSettings {
+                networks: Some(
+                    networks
+                        .iter()
+                        .map(|network| (network.to_string(), EndpointSettings::default()))
+                        .collect(),
+                ),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_network_info() {
+        let attached = attached_containers(vec![
+            container("web", &["frontend", "backend"]),
+            container("db", &["backend"]),
+        ]);
+
+        let network = Network {
+            name: Some("backend".to_string()),
+            driver: Some("bridge".to_string()),
+            scope: Some("local".to_string()),
+            ipam: Some(Ipam {
+                config: Some(vec![IpamConfig {
+                    subnet: Some("172.18.0.0/16".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let info = network_info(&"local".to_string(), network, &attached);
+        assert_eq!(info.name, "backend");
+        assert_eq!(info.driver, "bridge");
+        assert_eq!(info.subnets, vec!["172.18.0.0/16".to_string()]);
+        assert_eq!(info.containers, vec!["db".to_string(), "web".to_string()]);
+
+        let unused = network_info(&"local".to_string(), Network::default(), &attached);
+        assert!(unused.containers.is_empty());
+        assert!(unused.subnets.is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! Registry credentials for image pulls and pushes
+//!
+//! Credentials are read from the Docker CLI config (`~/.docker/config.json`, or
+//! `$DOCKER_CONFIG/config.json`) and overridden per registry by the dtop config.
+//! Credential helpers (`credsStore`/`credHelpers`) are not supported; registries
+//! using them need an entry in the dtop config.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bollard::auth::DockerCredentials;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::cli::config::RegistryConfig;
+
+/// Registry used for images without a registry host ("nginx", "library/redis")
+const DEFAULT_REGISTRY: &str = "docker.io";
+
+/// Credentials keyed by normalized registry host
+#[derive(Debug, Default, Clone)]
+pub struct RegistryCredentials {
+    registries: HashMap<String, DockerCredentials>,
+}
+
+/// The parts of the Docker CLI config holding credentials
+#[derive(Debug, Deserialize)]
+struct DockerConfigFile {
+    #[serde(default)]
+    auths: HashMap<String, DockerConfigAuth>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerConfigAuth {
+    /// base64 of "username:password"
+    auth: Option<String>,
+    identitytoken: Option<String>,
+}
+
+impl RegistryCredentials {
+    /// Loads the Docker CLI credentials, then applies the dtop config overrides
+    pub fn load(overrides: &HashMap<String, RegistryConfig>) -> Self {
+        let mut credentials = docker_config_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|contents| Self::from_docker_config(&contents))
+            .unwrap_or_default();
+
+        for (registry, config) in overrides {
+            credentials.registries.insert(
+                normalize_registry(registry),
+                DockerCredentials {
+                    username: config.username.clone(),
+                    password: config.password.clone(),
+                    identitytoken: config.identity_token.clone(),
+                    serveraddress: Some(registry.clone()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        credentials
+    }
+
+    /// Parses the `auths` section of a Docker CLI config file (invalid entries are skipped)
+    pub fn from_docker_config(contents: &str) -> Self {
+        let Ok(config) = serde_json::from_str::<DockerConfigFile>(contents) else {
+            tracing::warn!("Could not parse the Docker config file, ignoring its credentials");
+            return Self::default();
+        };
+
+        let registries = config
+            .auths
+            .into_iter()
+            .filter_map(|(registry, auth)| {
+                let (username, password) = match auth.auth.as_deref().map(decode_auth) {
+                    Some(Some((username, password))) => (Some(username), Some(password)),
+                    Some(None) => return None,
+                    None => (None, None),
+                };
+                if username.is_none() && auth.identitytoken.is_none() {
+                    return None;
+                }
+
+                let credentials = DockerCredentials {
+                    username,
+                    password,
+                    identitytoken: auth.identitytoken,
+                    serveraddress: Some(registry.clone()),
+                    ..Default::default()
+                };
+                Some((normalize_registry(&registry), credentials))
+            })
+            .collect();
+
+        Self { registries }
+    }
+
+    /// Returns the credentials for the registry an image lives in, if any are configured
+    pub fn for_image(&self, image: &str) -> Option<DockerCredentials> {
+        self.registries.get(&registry_for_image(image)).cloned()
+    }
+}
+
+/// `$DOCKER_CONFIG/config.json`, falling back to `~/.docker/config.json`
+fn docker_config_path() -> Option<PathBuf> {
+    match std::env::var_os("DOCKER_CONFIG") {
+        Some(dir) => Some(PathBuf::from(dir).join("config.json")),
+        None => dirs::home_dir().map(|home| home.join(".docker").join("config.json")),
+    }
+}
+
+/// Decodes a base64 "username:password" auth string
+fn decode_auth(auth: &str) -> Option<(String, String)> {
+    let decoded = STANDARD.decode(auth.trim()).ok()?;
+    let decoded = String::from_utf8(decoded).ok()?;
+    let (username, password) = decoded.split_once(':')?;
+    Some((username.to_string(), password.to_string()))
+}
+
+/// Reduces a registry address to its host ("https://index.docker.io/v1/" -> "docker.io")
+fn normalize_registry(registry: &str) -> String {
+    let host = registry
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .split('/')
+        .next()
+        .unwrap_or(registry)
+        .to_lowercase();
+
+    match host.as_str() {
+        "index.docker.io" | "registry-1.docker.io" => DEFAULT_REGISTRY.to_string(),
+        _ => host,
+    }
+}
+
+/// Returns the registry host of an image reference, following Docker's rules:
+/// the first path component is a registry if it has a '.' or ':' or is "localhost"
+pub fn registry_for_image(image: &str) -> String {
+    match image.split_once('/') {
+        Some((first, _)) if first.contains('.') || first.contains(':') || first == "localhost" => {
+            normalize_registry(first)
+        }
+        _ => DEFAULT_REGISTRY.to_string(),
+    }
+}
+
+/// Splits an image reference into repository and tag ("nginx:1.25" -> ("nginx", "1.25"))
+/// Digests and missing tags are left on the repository / default to "latest"
+pub fn split_image_tag(image: &str) -> (&str, &str) {
+    if image.contains('@') {
+        return (image, "");
+    }
+    // A ':' before the last '/' belongs to a registry port, not a tag
+    let name_start = image.rfind('/').map_or(0, |i| i + 1);
+    match image[name_start..].rfind(':') {
+        Some(i) => (&image[..name_start + i], &image[name_start + i + 1..]),
+        None => (image, "latest"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_for_image() {
+        assert_eq!(registry_for_image("nginx"), "docker.io");
+        assert_eq!(registry_for_image("library/nginx:1.25"), "docker.io");
+        assert_eq!(registry_for_image("ghcr.io/amir20/dtop"), "ghcr.io");
+        assert_eq!(
+            registry_for_image("registry.local:5000/team/app:dev"),
+            "registry.local:5000"
+        );
+        assert_eq!(registry_for_image("localhost/app"), "localhost");
+    }
+
+    #[test]
+    fn test_split_image_tag() {
+        assert_eq!(split_image_tag("nginx"), ("nginx", "latest"));
+        assert_eq!(split_image_tag("nginx:1.25"), ("nginx", "1.25"));
+        assert_eq!(
+            split_image_tag("registry.local:5000/app"),
+            ("registry.local:5000/app", "latest")
+        );
+        assert_eq!(
+            split_image_tag("registry.local:5000/app:dev"),
+            ("registry.local:5000/app", "dev")
+        );
+        assert_eq!(
+            split_image_tag("nginx@sha256:abc"),
+            ("nginx@sha256:abc", "")
+        );
+    }
+
+    #[test]
+    fn test_docker_config_credentials() {
+        // "user:secret" and "bot:token:with:colons"
+        let contents = r#"{
+            "auths": {
+                "https://index.docker.io/v1/": {"auth": "dXNlcjpzZWNyZXQ="},
+                "ghcr.io": {"auth": "Ym90OnRva2VuOndpdGg6Y29sb25z"},
+                "quay.io": {"identitytoken": "refresh-token"},
+                "helper.example.com": {}
+            },
+            "credsStore": "desktop"
+        }"#;
+        let credentials = RegistryCredentials::from_docker_config(contents);
+
+        let hub = credentials.for_image("nginx:latest").unwrap();
+        assert_eq!(hub.username.as_deref(), Some("user"));
+        assert_eq!(hub.password.as_deref(), Some("secret"));
+
+        let ghcr = credentials.for_image("ghcr.io/org/app").unwrap();
+        assert_eq!(ghcr.username.as_deref(), Some("bot"));
+        assert_eq!(ghcr.password.as_deref(), Some("token:with:colons"));
+
+        let quay = credentials.for_image("quay.io/org/app").unwrap();
+        assert_eq!(quay.identitytoken.as_deref(), Some("refresh-token"));
+
+        assert!(credentials.for_image("helper.example.com/app").is_none());
+        assert!(
+            RegistryCredentials::from_docker_config("not json")
+                .for_image("nginx")
+                .is_none()
+        );
+    }
+}
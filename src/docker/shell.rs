@@ -1,3 +1,4 @@
+use bollard::Docker;
 use bollard::exec::{CreateExecOptions, ResizeExecOptions, StartExecOptions, StartExecResults};
 use crossterm::{
     cursor,
@@ -6,213 +7,418 @@ use crossterm::{
     terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use futures_util::StreamExt;
-use std::io;
-use tokio::io::AsyncWriteExt as _;
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{self, Write as _};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt as _};
 use tokio::sync::mpsc;
 
+use crate::cli::config::ShellRuleConfig;
+use crate::core::types::{AppEvent, EventSender, ShellSessionInfo};
 use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
 
-/// Runs an interactive shell session inside a container
-/// This function takes over the terminal completely until the shell exits
-pub async fn run_shell_session(
-    host: &DockerHost,
-    container_id: &str,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    use tracing::debug;
-
-    debug!("Starting shell session for container: {}", container_id);
-
-    // Leave alternate screen so shell output is visible and show cursor
-    let mut stdout = io::stdout();
-    execute!(stdout, LeaveAlternateScreen, cursor::Show)?;
-    terminal::disable_raw_mode()?;
-
-    // Print a message so user knows shell is starting
-    println!();
-    println!("Connecting to shell in container {}...", container_id);
-    println!("Press Ctrl+D to exit");
-    println!();
-
-    // Get terminal size
-    let (cols, rows) = terminal::size()?;
-
-    // Create exec instance with /bin/sh (most containers have this)
-    let exec_config = CreateExecOptions {
-        cmd: Some(vec![
-            "sh",
-            "-c",
-            "command -v bash >/dev/null 2>&1 && exec bash || exec sh",
-        ]),
-        attach_stdin: Some(true),
-        attach_stdout: Some(true),
-        attach_stderr: Some(true),
-        tty: Some(true),
-        env: Some(vec!["TERM=xterm-256color"]),
-        ..Default::default()
-    };
+/// Output kept per session to repaint the shell when re-attaching
+const SCROLLBACK_BYTES: usize = 64 * 1024;
 
-    let exec_instance = host
-        .docker
-        .create_exec(container_id, exec_config)
-        .await
-        .map_err(|e| format!("Failed to create exec: {}", e))?;
+/// Delays before retrying a refused resize: right after start the exec process
+/// may not be running yet, and the daemon rejects resizing it
+const RESIZE_RETRY_DELAYS: [Duration; 4] = [
+    Duration::from_millis(10),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+];
 
-    let exec_id = exec_instance.id;
-    debug!("Created exec instance: {}", exec_id);
+/// Shells tried when none are configured: bash when installed, else sh (most
+/// containers have it)
+const DEFAULT_SHELLS: [&str; 2] = ["bash", "sh"];
 
-    // Start the exec session
-    let start_config = StartExecOptions {
-        detach: false,
-        tty: true,
-        ..Default::default()
-    };
+/// Shells tried in Windows containers when none are configured
+const DEFAULT_WINDOWS_SHELLS: [&str; 2] = ["powershell -NoLogo", "cmd"];
 
-    debug!("Starting exec session...");
-    let exec_result = host
-        .docker
-        .start_exec(&exec_id, Some(start_config))
-        .await
-        .map_err(|e| format!("Failed to start exec: {}", e))?;
+/// Shells tried for the containers carrying a label, from the `shells_by_label:`
+/// config section
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShellRule {
+    pub key: String,
+    pub value: String,
+    pub shells: Vec<String>,
+}
 
-    debug!("Exec started, handling attached session");
+impl ShellRule {
+    pub fn from_config(config: &ShellRuleConfig) -> Result<Self, String> {
+        let Some((key, value)) = config.label.split_once('=') else {
+            return Err(i18n::fill(
+                i18n::messages().shell_label_no_value,
+                &[&config.label],
+            ));
+        };
+        if config.shells.is_empty() {
+            return Err(i18n::fill(
+                i18n::messages().shell_list_empty,
+                &[&config.label],
+            ));
+        }
+        Ok(Self {
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+            shells: config.shells.clone(),
+        })
+    }
+}
 
-    // Resize the TTY to match terminal size (after exec starts)
-    let resize_options = ResizeExecOptions {
-        height: rows,
-        width: cols,
+/// The configured shell rules, with invalid ones reported
+pub fn shell_rules_from_config(configs: &[ShellRuleConfig]) -> Result<Vec<ShellRule>, String> {
+    configs.iter().map(ShellRule::from_config).collect()
+}
+
+/// The shells to try in a container: those of the first rule matching its labels,
+/// else those of its host; None for the defaults
+pub fn configured_shells<'a>(
+    host: &'a DockerHost,
+    labels: &BTreeMap<String, String>,
+    rules: &'a [ShellRule],
+) -> Option<&'a [String]> {
+    rules
+        .iter()
+        .find(|rule| labels.get(&rule.key) == Some(&rule.value))
+        .map(|rule| rule.shells.as_slice())
+        .or(host.shells.as_deref())
+}
+
+/// The exec command starting the first installed shell of the list. A single shell
+/// runs directly, so it works in containers without sh; a list is walked by sh (cmd
+/// on Windows), and the last one is started without checking.
+fn shell_command(shells: Option<&[String]>, windows: bool) -> Vec<String> {
+    let shells: Vec<&str> = match shells {
+        Some(shells) if !shells.is_empty() => shells.iter().map(String::as_str).collect(),
+        _ if windows => DEFAULT_WINDOWS_SHELLS.to_vec(),
+        _ => DEFAULT_SHELLS.to_vec(),
+    };
+    let program = |shell: &str| {
+        shell
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string()
     };
-    let _ = host.docker.resize_exec(&exec_id, resize_options).await;
-
-    // Handle the attached session
-    match exec_result {
-        StartExecResults::Attached {
-            mut output,
-            mut input,
-        } => {
-            debug!("Got attached session with input/output streams");
-            // Enable raw mode for the shell session
-            terminal::enable_raw_mode()?;
-
-            // Create channel for input events from blocking thread
-            let (input_tx, mut input_rx) = mpsc::channel::<InputEvent>(32);
-
-            // Spawn blocking thread for crossterm event reading
-            let input_handle = std::thread::spawn(move || {
-                loop {
-                    // 100ms poll timeout - human input doesn't need 1ms responsiveness
-                    if crossterm::event::poll(std::time::Duration::from_millis(100))
-                        .unwrap_or(false)
-                    {
-                        match crossterm::event::read() {
-                            Ok(event) => {
-                                if input_tx.blocking_send(InputEvent::Event(event)).is_err() {
-                                    break; // Channel closed, exit thread
-                                }
-                            }
-                            Err(_) => break,
-                        }
-                    }
 
-                    // Check if we should shutdown (channel closed)
-                    if input_tx.is_closed() {
-                        break;
-                    }
+    let (last, rest) = shells.split_last().expect("shell list is never empty");
+    if rest.is_empty() {
+        return last.split_whitespace().map(String::from).collect();
+    }
+
+    let mut script = String::new();
+    for shell in rest {
+        script.push_str(&if windows {
+            format!("where /q {} && {} || ", program(shell), shell)
+        } else {
+            format!(
+                "command -v {} >/dev/null 2>&1 && exec {} || ",
+                program(shell),
+                shell
+            )
+        });
+    }
+    if windows {
+        script.push_str(last);
+        ["cmd", "/S", "/C"]
+            .into_iter()
+            .map(String::from)
+            .chain([script])
+            .collect()
+    } else {
+        script.push_str(&format!("exec {}", last));
+        vec!["sh".to_string(), "-c".to_string(), script]
+    }
+}
+
+/// Output shared between a session's reader task and the attached terminal
+#[derive(Default)]
+struct SessionOutput {
+    /// Latest output, replayed on attach
+    scrollback: VecDeque<u8>,
+    /// Whether output goes to the terminal as it arrives
+    attached: bool,
+}
+
+/// An interactive shell inside a container that keeps running while detached
+pub struct ShellSession {
+    pub info: ShellSessionInfo,
+    docker: Docker,
+    exec_id: String,
+    input: Pin<Box<dyn AsyncWrite + Send>>,
+    output: Arc<Mutex<SessionOutput>>,
+    /// Reads the shell's output; finishes when the shell exits
+    reader: tokio::task::JoinHandle<()>,
+    /// Last size the exec TTY was resized to (columns, rows)
+    tty_size: Option<(u16, u16)>,
+}
+
+impl ShellSession {
+    /// Starts the first installed of the shells (None for the defaults) in the
+    /// container without taking over the terminal yet. Sends ShellSessionEnded once
+    /// the shell exits.
+    pub async fn start(
+        host: &DockerHost,
+        info: ShellSessionInfo,
+        shells: Option<&[String]>,
+        tx: EventSender,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        use tracing::debug;
+
+        let container_id = &info.key.container_id;
+        debug!("Starting shell session for container: {}", container_id);
+
+        let cmd = shell_command(shells, host.windows);
+        let exec_config = CreateExecOptions {
+            cmd: Some(cmd.iter().map(String::as_str).collect()),
+            attach_stdin: Some(true),
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            tty: Some(true),
+            env: Some(vec!["TERM=xterm-256color"]),
+            ..Default::default()
+        };
+
+        let exec_instance = host
+            .docker
+            .create_exec(container_id, exec_config)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().create_exec_failed, &[&e]))?;
+
+        let exec_id = exec_instance.id;
+        debug!("Created exec instance: {}", exec_id);
+
+        // Start the exec session
+        let start_config = StartExecOptions {
+            detach: false,
+            tty: true,
+            ..Default::default()
+        };
+
+        let exec_result = host
+            .docker
+            .start_exec(&exec_id, Some(start_config))
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().start_exec_failed, &[&e]))?;
+
+        let StartExecResults::Attached { mut output, input } = exec_result else {
+            return Err(i18n::messages().exec_detached.into());
+        };
+
+        // Read output for the whole session, attached or not
+        let shared = Arc::new(Mutex::new(SessionOutput::default()));
+        let reader_output = shared.clone();
+        let id = info.id;
+        let reader = tokio::spawn(async move {
+            while let Some(Ok(chunk)) = output.next().await {
+                let bytes = chunk.into_bytes();
+                let mut shared = reader_output.lock().unwrap_or_else(|e| e.into_inner());
+                if shared.attached {
+                    let mut stdout = io::stdout();
+                    let _ = stdout.write_all(&bytes);
+                    let _ = stdout.flush();
                 }
-            });
-
-            // Spawn async task to read from container and write to stdout
-            let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
-            let output_handle = tokio::spawn(async move {
-                let mut stdout = tokio::io::stdout();
-                loop {
-                    tokio::select! {
-                        biased;
-                        _ = shutdown_rx.recv() => break,
-                        result = output.next() => {
-                            match result {
-                                Some(Ok(output)) => {
-                                    let bytes = output.into_bytes();
-                                    if stdout.write_all(&bytes).await.is_err() {
-                                        break;
-                                    }
-                                    if stdout.flush().await.is_err() {
-                                        break;
-                                    }
-                                }
-                                Some(Err(_)) | None => break,
+                shared.scrollback.extend(bytes.iter());
+                let excess = shared.scrollback.len().saturating_sub(SCROLLBACK_BYTES);
+                shared.scrollback.drain(..excess);
+            }
+            let _ = tx.send(AppEvent::ShellSessionEnded(id)).await;
+        });
+
+        Ok(Self {
+            info,
+            docker: host.docker.clone(),
+            exec_id,
+            input,
+            output: shared,
+            reader,
+            tty_size: None,
+        })
+    }
+
+    /// Whether the shell has exited
+    pub fn is_finished(&self) -> bool {
+        self.reader.is_finished()
+    }
+
+    /// Takes over the terminal until the shell exits or the user detaches with Ctrl+]
+    pub async fn attach(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        use tracing::debug;
+
+        debug!("Attaching to shell session {}", self.info.id);
+
+        // Leave alternate screen so shell output is visible and show cursor
+        let mut stdout = io::stdout();
+        execute!(
+            stdout,
+            LeaveAlternateScreen,
+            Clear(ClearType::All),
+            cursor::MoveTo(0, 0),
+            cursor::Show
+        )?;
+        terminal::disable_raw_mode()?;
+
+        // Print a message so user knows how to leave
+        println!("Shell in container {}", self.info.name);
+        println!("Press Ctrl+D to exit, Ctrl+] to detach and keep it running");
+        println!();
+
+        // Enable raw mode for the shell session
+        terminal::enable_raw_mode()?;
+
+        // Repaint the latest output, then pass new output through as it arrives
+        {
+            let mut shared = self.output.lock().unwrap_or_else(|e| e.into_inner());
+            let (front, back) = shared.scrollback.as_slices();
+            stdout.write_all(front)?;
+            stdout.write_all(back)?;
+            stdout.flush()?;
+            shared.attached = true;
+        }
+
+        // Resize the TTY to match terminal size, which may have changed while detached.
+        // At the same size, a one-row nudge makes full-screen programs (vim, htop)
+        // repaint over the replayed output.
+        let (cols, rows) = terminal::size()?;
+        if self.tty_size == Some((cols, rows)) {
+            self.resize(cols, rows.saturating_sub(1)).await;
+        }
+        self.resize(cols, rows).await;
+
+        // Create channel for input events from blocking thread
+        let (input_tx, mut input_rx) = mpsc::channel::<InputEvent>(32);
+
+        // Spawn blocking thread for crossterm event reading
+        let input_handle = std::thread::spawn(move || {
+            loop {
+                // 100ms poll timeout - human input doesn't need 1ms responsiveness
+                if crossterm::event::poll(Duration::from_millis(100)).unwrap_or(false) {
+                    match crossterm::event::read() {
+                        Ok(event) => {
+                            if input_tx.blocking_send(InputEvent::Event(event)).is_err() {
+                                break; // Channel closed, exit thread
                             }
                         }
+                        Err(_) => break,
                     }
                 }
-            });
 
-            // Main async loop to process input events and send to container
-            let exec_id_clone = exec_id.clone();
-            let docker_clone = host.docker.clone();
-            loop {
-                tokio::select! {
-                    biased;
-                    // Check if output task finished (shell exited)
-                    _ = async {
-                        while !output_handle.is_finished() {
-                            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-                        }
-                    } => {
-                        break;
+                // Check if we should shutdown (channel closed)
+                if input_tx.is_closed() {
+                    break;
+                }
+            }
+        });
+
+        // Main async loop to process input events and send to container
+        loop {
+            tokio::select! {
+                biased;
+                // Check if the reader finished (shell exited)
+                _ = async {
+                    while !self.reader.is_finished() {
+                        tokio::time::sleep(Duration::from_millis(50)).await;
                     }
-                    // Process input events from the blocking thread
-                    event = input_rx.recv() => {
-                        match event {
-                            Some(InputEvent::Event(Event::Key(key_event))) => {
-                                let Some(bytes) = key_to_bytes(key_event) else {
-                                    continue;
-                                };
-
-                                if input.write_all(&bytes).await.is_err() {
-                                    break;
-                                }
-                                if input.flush().await.is_err() {
-                                    break;
-                                }
+                } => {
+                    break;
+                }
+                // Process input events from the blocking thread
+                event = input_rx.recv() => {
+                    match event {
+                        Some(InputEvent::Event(Event::Key(key_event))) => {
+                            if is_detach_key(&key_event) {
+                                debug!("Detached from shell session {}", self.info.id);
+                                break;
+                            }
+
+                            let Some(bytes) = key_to_bytes(key_event) else {
+                                continue;
+                            };
+
+                            if self.input.write_all(&bytes).await.is_err() {
+                                break;
                             }
-                            Some(InputEvent::Event(Event::Resize(cols, rows))) => {
-                                let resize_options = ResizeExecOptions {
-                                    height: rows,
-                                    width: cols,
-                                };
-                                let _ = docker_clone.resize_exec(&exec_id_clone, resize_options).await;
+                            if self.input.flush().await.is_err() {
+                                break;
                             }
-                            Some(InputEvent::Event(_)) => {}
-                            None => break, // Input channel closed
                         }
+                        Some(InputEvent::Event(Event::Resize(cols, rows))) => {
+                            self.resize(cols, rows).await;
+                        }
+                        Some(InputEvent::Event(_)) => {}
+                        None => break, // Input channel closed
                     }
                 }
             }
+        }
+
+        // Stop passing output through; the reader keeps collecting it
+        self.output
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .attached = false;
+
+        // Input thread will exit when channel is dropped
+        drop(input_rx);
+        let _ = input_handle.join();
 
-            // Signal output task to shutdown and wait for completion
-            let _ = shutdown_tx.send(()).await;
-            let _ = output_handle.await;
+        // Restore terminal state
+        terminal::disable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            Clear(ClearType::All),
+            cursor::Hide
+        )?;
+        terminal::enable_raw_mode()?;
 
-            // Input thread will exit when channel is dropped
-            drop(input_rx);
-            let _ = input_handle.join();
+        Ok(())
+    }
+
+    /// Resizes the exec TTY, retrying while the daemon refuses; unchanged sizes are skipped
+    async fn resize(&mut self, cols: u16, rows: u16) {
+        if self.tty_size == Some((cols, rows)) {
+            return;
         }
-        StartExecResults::Detached => {
-            return Err("Exec started in detached mode unexpectedly".into());
+
+        let mut retry_delays = RESIZE_RETRY_DELAYS.iter();
+        loop {
+            let resize_options = ResizeExecOptions {
+                height: rows,
+                width: cols,
+            };
+            match self.docker.resize_exec(&self.exec_id, resize_options).await {
+                Ok(()) => {
+                    self.tty_size = Some((cols, rows));
+                    return;
+                }
+                Err(e) => match retry_delays.next() {
+                    Some(delay) => tokio::time::sleep(*delay).await,
+                    None => {
+                        tracing::debug!("Failed to resize exec {}: {}", self.exec_id, e);
+                        return;
+                    }
+                },
+            }
         }
     }
+}
+
+impl Drop for ShellSession {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
 
-    // Restore terminal state
-    terminal::disable_raw_mode()?;
-    execute!(
-        io::stdout(),
-        EnterAlternateScreen,
-        Clear(ClearType::All),
-        cursor::Hide
-    )?;
-    terminal::enable_raw_mode()?;
-
-    Ok(())
+/// Ctrl+] detaches (some terminals report it as Ctrl+5)
+fn is_detach_key(key_event: &KeyEvent) -> bool {
+    key_event.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(key_event.code, KeyCode::Char(']') | KeyCode::Char('5'))
 }
 
 /// Input events from the blocking crossterm thread
@@ -249,3 +455,65 @@ fn key_to_bytes(key_event: KeyEvent) -> Option<Vec<u8>> {
         _ => return None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_shell_command() {
+        // Defaults: bash when installed, else sh
+        assert_eq!(
+            shell_command(None, false),
+            strings(&[
+                "sh",
+                "-c",
+                "command -v bash >/dev/null 2>&1 && exec bash || exec sh"
+            ])
+        );
+        assert_eq!(
+            shell_command(None, true),
+            strings(&[
+                "cmd",
+                "/S",
+                "/C",
+                "where /q powershell && powershell -NoLogo || cmd"
+            ])
+        );
+
+        // A single shell runs without sh
+        let shells = strings(&["/bin/zsh -l"]);
+        assert_eq!(
+            shell_command(Some(&shells), false),
+            strings(&["/bin/zsh", "-l"])
+        );
+
+        let shells = strings(&["zsh", "ash", "sh"]);
+        assert_eq!(
+            shell_command(Some(&shells), false),
+            strings(&[
+                "sh",
+                "-c",
+                "command -v zsh >/dev/null 2>&1 && exec zsh || \
+                 command -v ash >/dev/null 2>&1 && exec ash || exec sh"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_shell_rule_from_config() {
+        let config = |label: &str, shells: &[&str]| ShellRuleConfig {
+            label: label.to_string(),
+            shells: strings(shells),
+        };
+        let rule = ShellRule::from_config(&config("image=alpine", &["ash"])).unwrap();
+        assert_eq!(rule.key, "image");
+        assert_eq!(rule.value, "alpine");
+        assert!(ShellRule::from_config(&config("alpine", &["ash"])).is_err());
+        assert!(ShellRule::from_config(&config("image=alpine", &[])).is_err());
+    }
+}
@@ -1,112 +1,322 @@
-use bollard::models::ContainerStatsResponse;
+use bollard::errors::Error;
+use bollard::models::{ContainerCpuStats, ContainerStatsResponse};
 use bollard::query_parameters::StatsOptions;
+use chrono::DateTime;
 use futures_util::stream::StreamExt;
-use std::time::Instant;
-
-use crate::core::types::{AppEvent, ContainerKey, ContainerStats, EventSender};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+use crate::core::types::{
+    AppEvent, ContainerKey, ContainerStats, EventSender, current_history_bucket,
+};
 use crate::docker::connection::DockerHost;
+use crate::docker::stats_share::StatsLease;
+
+/// Whether the streams only send one sample per history bucket, set by the event loop
+static THROTTLED: AtomicBool = AtomicBool::new(false);
+
+/// Slows the stats of all streams down to one sample per history bucket (while the
+/// terminal is not focused or hundreds of containers are shown), or back to every sample
+pub fn set_throttled(throttled: bool) {
+    THROTTLED.store(throttled, Ordering::Relaxed);
+}
+
+/// First wait before reopening a stats stream that failed, doubled after each failure
+const STREAM_RETRY_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest wait between two attempts to reopen a stats stream
+const STREAM_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Default time between two polls of a container's stats on a polled host
+pub const DEFAULT_POLL_INTERVAL_SECS: u64 = 5;
+
+/// Default number of containers polled at the same time on a polled host
+pub const DEFAULT_POLL_CONCURRENCY: usize = 8;
+
+/// One-shot stats polling for hosts with too many containers for a stream each: every
+/// container is polled once per interval, with at most `permits` requests at a time
+#[derive(Clone, Debug)]
+pub struct StatsPolling {
+    pub interval: Duration,
+    permits: Arc<Semaphore>,
+}
+
+impl StatsPolling {
+    pub fn new(interval: Duration, concurrency: usize) -> Self {
+        Self {
+            interval,
+            permits: Arc::new(Semaphore::new(concurrency.max(1))),
+        }
+    }
+}
 
 /// Streams stats for a single container and sends updates via the event channel
 ///
-/// Uses exponential decay smoothing to reduce noise in stats:
-/// smoothed = alpha * new_value + (1 - alpha) * previous_smoothed
-///
 /// # Arguments
 /// * `host` - Docker host instance with identifier
-/// * `truncated_id` - Truncated container ID (12 chars) - Docker API accepts partial IDs
+/// * `container_id` - Full container ID, as keyed in the app state
 /// * `tx` - Event sender channel
-pub async fn stream_container_stats(host: DockerHost, truncated_id: String, tx: EventSender) {
+///
+/// With stats sharing, the stream is only opened once no other dtop instance streams
+/// the container; until then the samples it publishes are sent instead.
+///
+/// While throttled, samples are still smoothed and published but only the first one of
+/// each history bucket is sent, so sparklines keep their time scale.
+///
+/// A failed stream is reopened with backoff, so the row doesn't keep stale values. The
+/// stream ending, or the container being gone, ends the task.
+pub async fn stream_container_stats(host: DockerHost, container_id: String, tx: EventSender) {
+    let Some(lease) = acquire_lease(&host, &container_id, &tx).await else {
+        return;
+    };
+
     let stats_options = StatsOptions {
         stream: true,
         one_shot: false,
     };
 
-    let mut stats_stream = host.docker.stats(&truncated_id, Some(stats_options));
+    let mut sampler = StatsSampler::default();
+    let mut last_sent_bucket: Option<u64> = None;
+    let mut retry = 0;
+
+    loop {
+        let mut stats_stream = host
+            .docker
+            .stats(&container_id, Some(stats_options.clone()));
+        let error = loop {
+            match stats_stream.next().await {
+                Some(Ok(stats)) => {
+                    retry = 0;
+                    let stats = sampler.sample(&stats);
+                    lease.publish(&stats).await;
+
+                    let bucket = current_history_bucket();
+                    if THROTTLED.load(Ordering::Relaxed) && last_sent_bucket == Some(bucket) {
+                        continue;
+                    }
+                    last_sent_bucket = Some(bucket);
+
+                    let key = ContainerKey::new(host.host_id.clone(), container_id.clone());
+                    if tx.send(AppEvent::ContainerStat(key, stats)).await.is_err() {
+                        return;
+                    }
+                }
+                Some(Err(e)) => break Some(e),
+                None => break None,
+            }
+        };
 
-    // Smoothing factor: higher alpha = more responsive, lower alpha = smoother
-    // 0.3 provides good balance between responsiveness and smoothness
-    const ALPHA: f64 = 0.3;
+        match error {
+            None
+            | Some(Error::DockerResponseServerError {
+                status_code: 404, ..
+            }) => break,
+            // Also a lost connection: then the container manager restarts the stream
+            // once the host is back, and keeps the container on screen meanwhile
+            Some(e) => {
+                tracing::debug!(
+                    "Stats stream of {} on {} failed, reopening: {}",
+                    &container_id[..12.min(container_id.len())],
+                    host.host_id,
+                    e
+                );
+                tokio::time::sleep(stream_retry_delay(retry)).await;
+                retry += 1;
+            }
+        }
+    }
 
-    let mut smoothed_cpu: Option<f64> = None;
-    let mut smoothed_memory: Option<f64> = None;
-    let mut smoothed_net_tx: Option<f64> = None;
-    let mut smoothed_net_rx: Option<f64> = None;
+    // Notify that this container stream ended
+    let key = ContainerKey::new(host.host_id, container_id);
+    let _ = tx.send(AppEvent::ContainerDestroyed(key)).await;
+}
 
-    // Track previous network stats for rate calculation
-    let mut prev_net_tx: Option<u64> = None;
-    let mut prev_net_rx: Option<u64> = None;
-    let mut prev_timestamp: Option<Instant> = None;
+/// Polls one-shot stats for a single container every polling interval, instead of keeping
+/// a stream open, and sends updates via the event channel
+///
+/// A one-shot sample has no previous CPU reading to compare with, so the previous poll's
+/// stands in: the first poll only takes that reading. A failed poll is retried at the next
+/// interval; the task is stopped by the container manager when the container stops.
+pub async fn poll_container_stats(
+    host: DockerHost,
+    container_id: String,
+    tx: EventSender,
+    polling: StatsPolling,
+) {
+    let Some(lease) = acquire_lease(&host, &container_id, &tx).await else {
+        return;
+    };
+
+    let stats_options = StatsOptions {
+        stream: false,
+        one_shot: true,
+    };
+
+    let mut sampler = StatsSampler::default();
+    let mut previous: Option<(Option<ContainerCpuStats>, Option<String>)> = None;
+
+    while !tx.is_closed() {
+        let result = {
+            let Ok(_permit) = polling.permits.acquire().await else {
+                return;
+            };
+            host.docker
+                .stats(&container_id, Some(stats_options.clone()))
+                .next()
+                .await
+        };
 
-    while let Some(result) = stats_stream.next().await {
         match result {
-            Ok(stats) => {
-                let cpu_percent = calculate_cpu_percentage(&stats);
-                let memory_percent = calculate_memory_percentage(&stats);
-                let (net_tx_rate, net_rx_rate) =
-                    calculate_network_rates(&stats, prev_net_tx, prev_net_rx, prev_timestamp);
-
-                // Update previous network values for next iteration
-                let (tx_bytes, rx_bytes) = extract_network_bytes(&stats);
-                prev_net_tx = tx_bytes;
-                prev_net_rx = rx_bytes;
-                prev_timestamp = Some(Instant::now());
-
-                // Apply exponential moving average
-                let cpu = match smoothed_cpu {
-                    Some(prev) => ALPHA * cpu_percent + (1.0 - ALPHA) * prev,
-                    None => cpu_percent, // First value, no smoothing
-                };
-
-                let memory = match smoothed_memory {
-                    Some(prev) => ALPHA * memory_percent + (1.0 - ALPHA) * prev,
-                    None => memory_percent, // First value, no smoothing
-                };
-
-                let network_tx_bytes_per_sec = match smoothed_net_tx {
-                    Some(prev) => ALPHA * net_tx_rate + (1.0 - ALPHA) * prev,
-                    None => net_tx_rate,
-                };
-
-                let network_rx_bytes_per_sec = match smoothed_net_rx {
-                    Some(prev) => ALPHA * net_rx_rate + (1.0 - ALPHA) * prev,
-                    None => net_rx_rate,
-                };
-
-                // Update smoothed values for next iteration
-                smoothed_cpu = Some(cpu);
-                smoothed_memory = Some(memory);
-                smoothed_net_tx = Some(network_tx_bytes_per_sec);
-                smoothed_net_rx = Some(network_rx_bytes_per_sec);
-
-                // Extract raw memory bytes for display
-                let (memory_used_bytes, memory_limit_bytes) = extract_memory_bytes(&stats);
-
-                let stats = ContainerStats {
-                    cpu,
-                    memory,
-                    memory_used_bytes,
-                    memory_limit_bytes,
-                    network_tx_bytes_per_sec,
-                    network_rx_bytes_per_sec,
-                    ..Default::default()
-                };
-
-                let key = ContainerKey::new(host.host_id.clone(), truncated_id.clone());
-                if tx.send(AppEvent::ContainerStat(key, stats)).await.is_err() {
-                    break;
+            Some(Ok(mut stats)) => {
+                let reading = (stats.cpu_stats.clone(), stats.read.clone());
+                if let Some((precpu_stats, preread)) = previous.replace(reading) {
+                    stats.precpu_stats = precpu_stats;
+                    stats.preread = preread;
+
+                    let stats = sampler.sample(&stats);
+                    lease.publish(&stats).await;
+                    let key = ContainerKey::new(host.host_id.clone(), container_id.clone());
+                    if tx.send(AppEvent::ContainerStat(key, stats)).await.is_err() {
+                        return;
+                    }
                 }
             }
-            Err(_) => break,
+            Some(Err(e)) => tracing::debug!(
+                "Polling stats of {} on {}: {}",
+                &container_id[..12.min(container_id.len())],
+                host.host_id,
+                e
+            ),
+            None => {}
         }
+
+        tokio::time::sleep(polling.interval).await;
     }
+}
 
-    // Notify that this container stream ended
-    let key = ContainerKey::new(host.host_id, truncated_id);
-    let _ = tx.send(AppEvent::ContainerDestroyed(key)).await;
+/// Wait before reopening a failed stats stream the `retry`th time (from 0)
+fn stream_retry_delay(retry: u32) -> Duration {
+    STREAM_RETRY_INITIAL_DELAY
+        .saturating_mul(2u32.saturating_pow(retry))
+        .min(STREAM_RETRY_MAX_DELAY)
+}
+
+/// With stats sharing, waits until no other dtop instance streams the container (None when
+/// the app stops listening meanwhile)
+async fn acquire_lease(
+    host: &DockerHost,
+    container_id: &str,
+    tx: &EventSender,
+) -> Option<StatsLease> {
+    match &host.stats_share {
+        Some(share) => {
+            let key = ContainerKey::new(host.host_id.clone(), container_id.to_string());
+            share.acquire(&key, tx).await
+        }
+        None => Some(StatsLease::unshared()),
+    }
+}
+
+/// Turns raw samples into smoothed stats, with rates from the previous sample
+///
+/// Uses exponential decay smoothing to reduce noise in stats:
+/// smoothed = alpha * new_value + (1 - alpha) * previous_smoothed
+#[derive(Default)]
+struct StatsSampler {
+    smoothed_cpu: Option<f64>,
+    smoothed_memory: Option<f64>,
+    smoothed_net_tx: Option<f64>,
+    smoothed_net_rx: Option<f64>,
+    smoothed_blk_read: Option<f64>,
+    smoothed_blk_write: Option<f64>,
+
+    // Previous network and block I/O stats for rate calculation
+    prev_net_tx: Option<u64>,
+    prev_net_rx: Option<u64>,
+    prev_blk_read: Option<u64>,
+    prev_blk_write: Option<u64>,
+    prev_timestamp: Option<Instant>,
+}
+
+impl StatsSampler {
+    /// Smoothing factor: higher alpha = more responsive, lower alpha = smoother
+    /// 0.3 provides good balance between responsiveness and smoothness
+    const ALPHA: f64 = 0.3;
+
+    fn sample(&mut self, stats: &ContainerStatsResponse) -> ContainerStats {
+        let cpu_percent = calculate_cpu_percentage(stats);
+        let memory_percent = calculate_memory_percentage(stats);
+        let (net_tx_rate, net_rx_rate) = calculate_network_rates(
+            stats,
+            self.prev_net_tx,
+            self.prev_net_rx,
+            self.prev_timestamp,
+        );
+        let (blk_read_rate, blk_write_rate) = calculate_rates(
+            extract_block_io_bytes(stats),
+            (self.prev_blk_read, self.prev_blk_write),
+            self.prev_timestamp,
+        );
+
+        // Update previous network and block I/O values for next iteration
+        let (tx_bytes, rx_bytes) = extract_network_bytes(stats);
+        self.prev_net_tx = tx_bytes;
+        self.prev_net_rx = rx_bytes;
+        (self.prev_blk_read, self.prev_blk_write) = extract_block_io_bytes(stats);
+        self.prev_timestamp = Some(Instant::now());
+
+        // Apply exponential moving average (the first value is taken as is)
+        let smooth = |previous: &mut Option<f64>, value: f64| {
+            let smoothed = match *previous {
+                Some(prev) => Self::ALPHA * value + (1.0 - Self::ALPHA) * prev,
+                None => value,
+            };
+            *previous = Some(smoothed);
+            smoothed
+        };
+        let cpu = smooth(&mut self.smoothed_cpu, cpu_percent);
+        let memory = smooth(&mut self.smoothed_memory, memory_percent);
+        let network_tx_bytes_per_sec = smooth(&mut self.smoothed_net_tx, net_tx_rate);
+        let network_rx_bytes_per_sec = smooth(&mut self.smoothed_net_rx, net_rx_rate);
+        let block_read_bytes_per_sec = smooth(&mut self.smoothed_blk_read, blk_read_rate);
+        let block_write_bytes_per_sec = smooth(&mut self.smoothed_blk_write, blk_write_rate);
+
+        // Extract raw memory bytes for display
+        let (memory_used_bytes, memory_limit_bytes) = extract_memory_bytes(stats);
+        let (pids, pids_limit) = extract_pids(stats);
+        let online_cpus = extract_online_cpus(stats);
+
+        ContainerStats {
+            cpu,
+            memory,
+            memory_used_bytes,
+            memory_limit_bytes,
+            network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec,
+            block_read_bytes_per_sec,
+            block_write_bytes_per_sec,
+            pids,
+            pids_limit,
+            online_cpus,
+            ..Default::default()
+        }
+    }
+}
+
+/// Whether the stats come from a Windows container, which has no cgroup fields
+/// (system CPU usage, online CPUs, memory usage and limit)
+fn is_windows(stats: &ContainerStatsResponse) -> bool {
+    stats.os_type.as_deref() == Some("windows")
 }
 
 /// Calculates CPU usage percentage from container stats
 pub fn calculate_cpu_percentage(stats: &ContainerStatsResponse) -> f64 {
+    if is_windows(stats) {
+        return calculate_windows_cpu_percentage(stats);
+    }
+
     let cpu_stats = match &stats.cpu_stats {
         Some(cs) => cs,
         None => return 0.0,
@@ -139,8 +349,48 @@ pub fn calculate_cpu_percentage(stats: &ContainerStatsResponse) -> f64 {
     }
 }
 
+/// Calculates CPU usage percentage of a Windows container: CPU time is counted
+/// in 100ns intervals, against the time between the two samples on all processors
+fn calculate_windows_cpu_percentage(stats: &ContainerStatsResponse) -> f64 {
+    let total_usage = |cpu_stats: &Option<ContainerCpuStats>| {
+        cpu_stats
+            .as_ref()
+            .and_then(|cs| cs.cpu_usage.as_ref())
+            .and_then(|u| u.total_usage)
+    };
+    let (Some(cpu_usage), Some(precpu_usage)) = (
+        total_usage(&stats.cpu_stats),
+        total_usage(&stats.precpu_stats),
+    ) else {
+        return 0.0;
+    };
+
+    let parse = |date: &Option<String>| {
+        date.as_deref()
+            .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+    };
+    let (Some(read), Some(preread)) = (parse(&stats.read), parse(&stats.preread)) else {
+        return 0.0;
+    };
+
+    let elapsed_nanos = (read - preread).num_nanoseconds().unwrap_or(0);
+    let possible_intervals = (elapsed_nanos / 100) as f64 * stats.num_procs.unwrap_or(0) as f64;
+    let intervals_used = cpu_usage.saturating_sub(precpu_usage) as f64;
+
+    if possible_intervals > 0.0 {
+        intervals_used / possible_intervals * 100.0
+    } else {
+        0.0
+    }
+}
+
 /// Calculates memory usage percentage from container stats
 pub fn calculate_memory_percentage(stats: &ContainerStatsResponse) -> f64 {
+    // Windows reports no memory limit to compare against
+    if is_windows(stats) {
+        return 0.0;
+    }
+
     let memory_stats = match &stats.memory_stats {
         Some(ms) => ms,
         None => return 0.0,
@@ -164,6 +414,11 @@ fn extract_memory_bytes(stats: &ContainerStatsResponse) -> (u64, u64) {
         None => return (0, 0),
     };
 
+    // Windows has no usage or limit; commit bytes is what the container has allocated
+    if is_windows(stats) {
+        return (memory_stats.commitbytes.unwrap_or(0), 0);
+    }
+
     let memory_used = memory_stats.usage.unwrap_or(0);
     let memory_limit = memory_stats.limit.unwrap_or(0);
 
@@ -188,6 +443,69 @@ fn extract_network_bytes(stats: &ContainerStatsResponse) -> (Option<u64>, Option
     (Some(total_tx), Some(total_rx))
 }
 
+/// Extracts total block device bytes (read, write) from container stats
+///
+/// Linux reports per-device entries (op "Read"/"Write" on cgroup v1, "read"/"write"
+/// on cgroup v2); Windows reports totals in the storage stats instead
+fn extract_block_io_bytes(stats: &ContainerStatsResponse) -> (Option<u64>, Option<u64>) {
+    if is_windows(stats) {
+        return match &stats.storage_stats {
+            Some(storage) => (storage.read_size_bytes, storage.write_size_bytes),
+            None => (None, None),
+        };
+    }
+
+    let entries = match stats
+        .blkio_stats
+        .as_ref()
+        .and_then(|blkio| blkio.io_service_bytes_recursive.as_ref())
+    {
+        Some(entries) => entries,
+        None => return (None, None),
+    };
+
+    let mut total_read = 0u64;
+    let mut total_write = 0u64;
+
+    for entry in entries {
+        let value = entry.value.unwrap_or(0);
+        match entry.op.as_deref().map(str::to_lowercase).as_deref() {
+            Some("read") => total_read += value,
+            Some("write") => total_write += value,
+            _ => {}
+        }
+    }
+
+    (Some(total_read), Some(total_write))
+}
+
+/// Extracts the process count and pids limit from container stats
+///
+/// A limit of 0 (or the cgroup's "max", reported as u64::MAX by some engines) means none
+fn extract_pids(stats: &ContainerStatsResponse) -> (u64, Option<u64>) {
+    let Some(pids_stats) = &stats.pids_stats else {
+        return (0, None);
+    };
+    let limit = pids_stats
+        .limit
+        .filter(|&limit| limit != 0 && limit != u64::MAX);
+    (pids_stats.current.unwrap_or(0), limit)
+}
+
+/// Extracts the number of CPUs the CPU percentage is a share of one of
+///
+/// Windows percentages are already a share of all processors, so they count as one
+fn extract_online_cpus(stats: &ContainerStatsResponse) -> u32 {
+    if is_windows(stats) {
+        return 1;
+    }
+    stats
+        .cpu_stats
+        .as_ref()
+        .and_then(|cpu_stats| cpu_stats.online_cpus)
+        .unwrap_or(0)
+}
+
 /// Calculates network transfer rates in bytes per second
 fn calculate_network_rates(
     stats: &ContainerStatsResponse,
@@ -195,16 +513,23 @@ fn calculate_network_rates(
     prev_rx: Option<u64>,
     prev_time: Option<Instant>,
 ) -> (f64, f64) {
-    let (current_tx, current_rx) = extract_network_bytes(stats);
+    calculate_rates(extract_network_bytes(stats), (prev_tx, prev_rx), prev_time)
+}
 
+/// Calculates a pair of byte counter rates in bytes per second
+fn calculate_rates(
+    current: (Option<u64>, Option<u64>),
+    prev: (Option<u64>, Option<u64>),
+    prev_time: Option<Instant>,
+) -> (f64, f64) {
     // If we don't have previous values, return 0
-    let (prev_tx, prev_rx, prev_time) = match (prev_tx, prev_rx, prev_time) {
-        (Some(tx), Some(rx), Some(time)) => (tx, rx, time),
+    let (prev_a, prev_b, prev_time) = match (prev, prev_time) {
+        ((Some(a), Some(b)), Some(time)) => (a, b, time),
         _ => return (0.0, 0.0),
     };
 
-    let (current_tx, current_rx) = match (current_tx, current_rx) {
-        (Some(tx), Some(rx)) => (tx, rx),
+    let (current_a, current_b) = match current {
+        (Some(a), Some(b)) => (a, b),
         _ => return (0.0, 0.0),
     };
 
@@ -213,19 +538,16 @@ fn calculate_network_rates(
         return (0.0, 0.0);
     }
 
-    let tx_delta = current_tx.saturating_sub(prev_tx) as f64;
-    let rx_delta = current_rx.saturating_sub(prev_rx) as f64;
-
-    let tx_rate = tx_delta / elapsed;
-    let rx_rate = rx_delta / elapsed;
+    let a_delta = current_a.saturating_sub(prev_a) as f64;
+    let b_delta = current_b.saturating_sub(prev_b) as f64;
 
-    (tx_rate, rx_rate)
+    (a_delta / elapsed, b_delta / elapsed)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bollard::models::{ContainerCpuStats, ContainerCpuUsage, ContainerMemoryStats};
+    use bollard::models::{ContainerCpuUsage, ContainerMemoryStats};
 
     fn create_cpu_stats(
         total_usage: u64,
@@ -245,6 +567,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stream_retry_delay() {
+        assert_eq!(stream_retry_delay(0), STREAM_RETRY_INITIAL_DELAY);
+        assert_eq!(stream_retry_delay(2), Duration::from_secs(4));
+        assert_eq!(stream_retry_delay(5), STREAM_RETRY_MAX_DELAY);
+        assert_eq!(stream_retry_delay(100), STREAM_RETRY_MAX_DELAY);
+    }
+
+    #[test]
+    fn test_stats_sampler_smooths() {
+        let mut sampler = StatsSampler::default();
+        let sample = |total_usage, system_cpu_usage| ContainerStatsResponse {
+            cpu_stats: Some(create_cpu_stats(total_usage, system_cpu_usage, 1)),
+            precpu_stats: Some(create_cpu_stats(0, 0, 1)),
+            ..Default::default()
+        };
+
+        // The first value is taken as is, the next ones are smoothed
+        assert_eq!(sampler.sample(&sample(100, 100)).cpu, 100.0);
+        let stats = sampler.sample(&sample(0, 100));
+        assert!((stats.cpu - 70.0).abs() < 1e-9);
+        assert_eq!(stats.online_cpus, 1);
+    }
+
     #[test]
     fn test_calculate_cpu_percentage_normal_usage() {
         let stats = ContainerStatsResponse {
@@ -428,4 +774,112 @@ mod tests {
         // Should handle division by zero gracefully
         assert_eq!(calculate_memory_percentage(&stats), 0.0);
     }
+
+    #[test]
+    fn test_calculate_cpu_percentage_windows() {
+        let stats = ContainerStatsResponse {
+            os_type: Some("windows".to_string()),
+            read: Some("2024-01-01T00:00:01Z".to_string()),
+            preread: Some("2024-01-01T00:00:00Z".to_string()),
+            num_procs: Some(2),
+            cpu_stats: Some(create_cpu_stats(15_000_000, 0, 0)),
+            precpu_stats: Some(create_cpu_stats(10_000_000, 0, 0)),
+            ..Default::default()
+        };
+
+        // 1s = 10M intervals of 100ns per processor, 20M on 2 processors
+        // (15M - 10M) / 20M * 100 = 25%
+        assert_eq!(calculate_cpu_percentage(&stats), 25.0);
+    }
+
+    #[test]
+    fn test_windows_memory_uses_commit_bytes() {
+        let stats = ContainerStatsResponse {
+            os_type: Some("windows".to_string()),
+            memory_stats: Some(ContainerMemoryStats {
+                usage: None,
+                limit: None,
+                max_usage: None,
+                stats: None,
+                failcnt: None,
+                commitbytes: Some(300_000_000),
+                commitpeakbytes: Some(400_000_000),
+                privateworkingset: Some(200_000_000),
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(calculate_memory_percentage(&stats), 0.0);
+        assert_eq!(extract_memory_bytes(&stats), (300_000_000, 0));
+    }
+
+    #[test]
+    fn test_extract_block_io_bytes() {
+        use bollard::models::{ContainerBlkioStatEntry, ContainerBlkioStats};
+
+        let entry = |op: &str, value: u64| ContainerBlkioStatEntry {
+            major: Some(8),
+            minor: Some(0),
+            op: Some(op.to_string()),
+            value: Some(value),
+        };
+        let stats = ContainerStatsResponse {
+            blkio_stats: Some(ContainerBlkioStats {
+                io_service_bytes_recursive: Some(vec![
+                    entry("read", 4096),
+                    entry("write", 1024),
+                    entry("Read", 4096),
+                    entry("Total", 9216),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(extract_block_io_bytes(&stats), (Some(8192), Some(1024)));
+    }
+
+    #[test]
+    fn test_extract_block_io_bytes_missing() {
+        // cgroup v2 hosts without the io controller report no entries
+        let stats = ContainerStatsResponse {
+            blkio_stats: Some(Default::default()),
+            ..Default::default()
+        };
+
+        assert_eq!(extract_block_io_bytes(&stats), (None, None));
+    }
+
+    #[test]
+    fn test_extract_pids() {
+        use bollard::models::ContainerPidsStats;
+
+        let stats = |current, limit| ContainerStatsResponse {
+            pids_stats: Some(ContainerPidsStats { current, limit }),
+            ..Default::default()
+        };
+
+        assert_eq!(extract_pids(&stats(Some(12), Some(100))), (12, Some(100)));
+        assert_eq!(extract_pids(&stats(Some(12), Some(0))), (12, None));
+        assert_eq!(extract_pids(&stats(Some(12), Some(u64::MAX))), (12, None));
+        assert_eq!(extract_pids(&ContainerStatsResponse::default()), (0, None));
+    }
+
+    #[test]
+    fn test_extract_online_cpus() {
+        let stats = ContainerStatsResponse {
+            cpu_stats: Some(create_cpu_stats(1_000_000_000, 2_000_000_000, 8)),
+            ..Default::default()
+        };
+        assert_eq!(extract_online_cpus(&stats), 8);
+        assert_eq!(extract_online_cpus(&ContainerStatsResponse::default()), 0);
+
+        // Already a share of all processors
+        let stats = ContainerStatsResponse {
+            os_type: Some("windows".to_string()),
+            num_procs: Some(8),
+            ..Default::default()
+        };
+        assert_eq!(extract_online_cpus(&stats), 1);
+    }
 }
@@ -0,0 +1,294 @@
+//! Sharing of container stats streams between dtop instances on the same machine
+//!
+//! Each container's stats have a lease: a lock file only one instance can hold. The
+//! holder streams the stats from Docker and publishes every sample to a file next to
+//! the lock; the other instances read the samples from there instead of opening a
+//! stream of their own. When the holder stops (the container stopped or dtop quit),
+//! the lock is released and one of the others takes over.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cli::config::StatsSharingConfig;
+use crate::core::types::{AppEvent, ContainerKey, ContainerStats, EventSender};
+
+/// How often a waiting instance reads the published sample and retries the lease
+const FOLLOW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Samples older than this are not shown: the holder is stuck or gone
+const SAMPLE_MAX_AGE_MS: i64 = 5000;
+
+/// Where the leases and samples of all instances are kept
+#[derive(Debug, Clone)]
+pub struct StatsShare {
+    dir: PathBuf,
+}
+
+/// The right to stream a container's stats, held until dropped
+#[derive(Debug)]
+pub struct StatsLease {
+    /// Locked for as long as the lease is held (None when sharing isn't possible)
+    _lock: Option<File>,
+    sample_path: Option<PathBuf>,
+}
+
+/// A stats sample as published for the other instances
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SharedSample {
+    /// When it was published, in milliseconds since the epoch
+    updated_ms: i64,
+    cpu: f64,
+    memory: f64,
+    memory_used_bytes: u64,
+    memory_limit_bytes: u64,
+    network_tx_bytes_per_sec: f64,
+    network_rx_bytes_per_sec: f64,
+    block_read_bytes_per_sec: f64,
+    block_write_bytes_per_sec: f64,
+    pids: u64,
+    pids_limit: Option<u64>,
+    /// Missing from samples of older versions
+    #[serde(default)]
+    online_cpus: u32,
+}
+
+impl SharedSample {
+    fn new(stats: &ContainerStats, updated_ms: i64) -> Self {
+        Self {
+            updated_ms,
+            cpu: stats.cpu,
+            memory: stats.memory,
+            memory_used_bytes: stats.memory_used_bytes,
+            memory_limit_bytes: stats.memory_limit_bytes,
+            network_tx_bytes_per_sec: stats.network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec: stats.network_rx_bytes_per_sec,
+            block_read_bytes_per_sec: stats.block_read_bytes_per_sec,
+            block_write_bytes_per_sec: stats.block_write_bytes_per_sec,
+            pids: stats.pids,
+            pids_limit: stats.pids_limit,
+            online_cpus: stats.online_cpus,
+        }
+    }
+
+    fn stats(&self) -> ContainerStats {
+        ContainerStats {
+            cpu: self.cpu,
+            memory: self.memory,
+            memory_used_bytes: self.memory_used_bytes,
+            memory_limit_bytes: self.memory_limit_bytes,
+            network_tx_bytes_per_sec: self.network_tx_bytes_per_sec,
+            network_rx_bytes_per_sec: self.network_rx_bytes_per_sec,
+            block_read_bytes_per_sec: self.block_read_bytes_per_sec,
+            block_write_bytes_per_sec: self.block_write_bytes_per_sec,
+            pids: self.pids,
+            pids_limit: self.pids_limit,
+            online_cpus: self.online_cpus,
+            ..Default::default()
+        }
+    }
+}
+
+impl StatsShare {
+    /// Sharing as configured; None when no directory is set and the platform has
+    /// neither a runtime nor a cache directory
+    pub fn from_config(config: &StatsSharingConfig) -> Option<Self> {
+        let dir = match config.dir.as_deref() {
+            // Expand ~ like a shell would
+            Some(dir) => match dir.strip_prefix("~/") {
+                Some(rest) => dirs::home_dir()?.join(rest),
+                None => PathBuf::from(dir),
+            },
+            // The runtime directory is private to the user and cleared at logout
+            None => dirs::runtime_dir()
+                .or_else(dirs::cache_dir)?
+                .join("dtop")
+                .join("stats"),
+        };
+        Some(Self { dir })
+    }
+
+    /// `<dir>/<host>/<container ID>.lock` and `.json`
+    fn paths(&self, key: &ContainerKey) -> (PathBuf, PathBuf) {
+        let host: String = key
+            .host_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let base = self.dir.join(host).join(&key.container_id);
+        (base.with_extension("lock"), base.with_extension("json"))
+    }
+
+    /// Waits for the lease on the container's stats, sending the samples another
+    /// instance publishes meanwhile. Returns None once the app stopped listening.
+    ///
+    /// When the directory can't be used, the lease comes at once and is not shared.
+    pub async fn acquire(&self, key: &ContainerKey, tx: &EventSender) -> Option<StatsLease> {
+        let (lock_path, sample_path) = self.paths(key);
+        let lock = match open_lock_file(&lock_path) {
+            Ok(lock) => lock,
+            Err(e) => {
+                tracing::warn!("Stats sharing disabled for {}: {}", key.short_id(), e);
+                return Some(StatsLease {
+                    _lock: None,
+                    sample_path: None,
+                });
+            }
+        };
+
+        let mut last_sent = 0;
+        loop {
+            match lock.try_lock() {
+                Ok(()) => {
+                    return Some(StatsLease {
+                        _lock: Some(lock),
+                        sample_path: Some(sample_path),
+                    });
+                }
+                Err(TryLockError::WouldBlock) => {}
+                Err(TryLockError::Error(e)) => {
+                    tracing::warn!("Stats sharing disabled for {}: {}", key.short_id(), e);
+                    return Some(StatsLease {
+                        _lock: None,
+                        sample_path: None,
+                    });
+                }
+            }
+
+            let now_ms = Utc::now().timestamp_millis();
+            if let Some(sample) = read_sample(&sample_path).await
+                && sample.updated_ms > last_sent
+                && now_ms - sample.updated_ms <= SAMPLE_MAX_AGE_MS
+            {
+                last_sent = sample.updated_ms;
+                if tx
+                    .send(AppEvent::ContainerStat(key.clone(), sample.stats()))
+                    .await
+                    .is_err()
+                {
+                    return None;
+                }
+            }
+            if tx.is_closed() {
+                return None;
+            }
+
+            tokio::time::sleep(FOLLOW_INTERVAL).await;
+        }
+    }
+}
+
+impl StatsLease {
+    /// A lease that isn't shared, for when sharing is off
+    pub fn unshared() -> Self {
+        Self {
+            _lock: None,
+            sample_path: None,
+        }
+    }
+
+    /// Publishes a sample for the instances waiting on the lease
+    pub async fn publish(&self, stats: &ContainerStats) {
+        let Some(path) = &self.sample_path else {
+            return;
+        };
+        let sample = SharedSample::new(stats, Utc::now().timestamp_millis());
+        let Ok(json) = serde_json::to_string(&sample) else {
+            return;
+        };
+
+        // Written aside and renamed so readers never see half a sample
+        let tmp_path = path.with_extension("json.tmp");
+        let result = match tokio::fs::write(&tmp_path, json).await {
+            Ok(()) => tokio::fs::rename(&tmp_path, path).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            tracing::debug!("Failed to publish stats to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn open_lock_file(path: &Path) -> std::io::Result<File> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+}
+
+async fn read_sample(path: &Path) -> Option<SharedSample> {
+    let json = tokio::fs::read_to_string(path).await.ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    fn share(name: &str) -> StatsShare {
+        StatsShare {
+            dir: std::env::temp_dir().join(format!("dtop-{}-{}", name, std::process::id())),
+        }
+    }
+
+    #[test]
+    fn test_paths() {
+        let share = StatsShare {
+            dir: PathBuf::from("/run/dtop"),
+        };
+        let key = ContainerKey::new("ssh://web 1".to_string(), "abc123".to_string());
+        let (lock, sample) = share.paths(&key);
+        assert_eq!(lock, PathBuf::from("/run/dtop/ssh___web_1/abc123.lock"));
+        assert_eq!(sample, PathBuf::from("/run/dtop/ssh___web_1/abc123.json"));
+    }
+
+    #[tokio::test]
+    async fn test_lease_is_shared() {
+        let share = share("stats-share");
+        let key = ContainerKey::new("local".to_string(), "abc123".to_string());
+        let (tx, mut rx) = mpsc::channel(10);
+
+        // The first instance gets the lease at once and publishes
+        let lease = share.acquire(&key, &tx).await.unwrap();
+        let stats = ContainerStats {
+            cpu: 42.0,
+            pids: 7,
+            ..Default::default()
+        };
+        lease.publish(&stats).await;
+
+        // A second one follows its samples until it is released
+        let follower = {
+            let share = share.clone();
+            let key = key.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move { share.acquire(&key, &tx).await })
+        };
+        let Some(AppEvent::ContainerStat(sample_key, sample)) = rx.recv().await else {
+            panic!("expected a stats sample");
+        };
+        assert_eq!(sample_key, key);
+        assert_eq!(sample.cpu, 42.0);
+        assert_eq!(sample.pids, 7);
+        assert!(!follower.is_finished());
+
+        drop(lease);
+        let lease = follower.await.unwrap().unwrap();
+        assert!(lease._lock.is_some());
+
+        std::fs::remove_dir_all(&share.dir).unwrap();
+    }
+}
@@ -0,0 +1,130 @@
+//! Parsing of the `docker ps` style status text returned by the container list API
+//!
+//! The list endpoint only reports start/exit times as human-readable text
+//! ("Up 3 hours (healthy)", "Exited (137) 5 minutes ago"). Recovering approximate
+//! timestamps from it avoids inspecting every container at startup.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Timing information recovered from a container's status text
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ParsedStatus {
+    /// Approximate start time (running/paused containers)
+    pub started_at: Option<DateTime<Utc>>,
+    /// Approximate exit time (exited/restarting containers)
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Last exit code, shown in parentheses after "Exited" and "Restarting"
+    pub exit_code: Option<i64>,
+}
+
+/// Parses status text such as "Up 3 hours (healthy)" or "Exited (0) 2 days ago"
+pub fn parse_status(status: &str, now: DateTime<Utc>) -> ParsedStatus {
+    let status = status.trim();
+
+    if let Some(rest) = status.strip_prefix("Up ") {
+        // Strip trailing "(healthy)", "(Paused)", etc.
+        let duration = rest.split(" (").next().unwrap_or(rest);
+        return ParsedStatus {
+            started_at: parse_human_duration(duration).map(|d| now - d),
+            ..Default::default()
+        };
+    }
+
+    for prefix in ["Exited ", "Restarting "] {
+        if let Some(rest) = status.strip_prefix(prefix) {
+            let (exit_code, ago) = match rest.strip_prefix('(').and_then(|r| r.split_once(") ")) {
+                Some((code, ago)) => (code.parse().ok(), ago),
+                None => (None, rest),
+            };
+            let finished_at = ago
+                .strip_suffix(" ago")
+                .and_then(parse_human_duration)
+                .map(|d| now - d);
+            return ParsedStatus {
+                finished_at,
+                exit_code,
+                ..Default::default()
+            };
+        }
+    }
+
+    ParsedStatus::default()
+}
+
+/// Inverse of Docker's `HumanDuration` ("About an hour", "3 days", ...)
+fn parse_human_duration(text: &str) -> Option<Duration> {
+    match text {
+        "Less than a second" => return Some(Duration::zero()),
+        "About a minute" => return Some(Duration::minutes(1)),
+        "About an hour" => return Some(Duration::hours(1)),
+        _ => {}
+    }
+
+    let (count, unit) = text.split_once(' ')?;
+    let count: i64 = count.parse().ok()?;
+    let duration = match unit.trim_end_matches('s') {
+        "second" => Duration::seconds(count),
+        "minute" => Duration::minutes(count),
+        "hour" => Duration::hours(count),
+        "day" => Duration::days(count),
+        "week" => Duration::weeks(count),
+        "month" => Duration::days(count * 30),
+        "year" => Duration::days(count * 365),
+        _ => return None,
+    };
+    Some(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_running_status() {
+        let parsed = parse_status("Up 3 hours (healthy)", now());
+        assert_eq!(parsed.started_at, Some(now() - Duration::hours(3)));
+        assert_eq!(parsed.finished_at, None);
+        assert_eq!(parsed.exit_code, None);
+
+        let parsed = parse_status("Up About a minute", now());
+        assert_eq!(parsed.started_at, Some(now() - Duration::minutes(1)));
+
+        let parsed = parse_status("Up 2 days (Paused)", now());
+        assert_eq!(parsed.started_at, Some(now() - Duration::days(2)));
+    }
+
+    #[test]
+    fn test_parse_exited_status() {
+        let parsed = parse_status("Exited (137) 5 minutes ago", now());
+        assert_eq!(parsed.exit_code, Some(137));
+        assert_eq!(parsed.finished_at, Some(now() - Duration::minutes(5)));
+        assert_eq!(parsed.started_at, None);
+
+        let parsed = parse_status("Restarting (1) Less than a second ago", now());
+        assert_eq!(parsed.exit_code, Some(1));
+        assert_eq!(parsed.finished_at, Some(now()));
+    }
+
+    #[test]
+    fn test_parse_status_without_times() {
+        assert_eq!(parse_status("Created", now()), ParsedStatus::default());
+        assert_eq!(
+            parse_status("Removal In Progress", now()),
+            ParsedStatus::default()
+        );
+        assert_eq!(parse_status("Up sometime", now()), ParsedStatus::default());
+    }
+
+    #[test]
+    fn test_parse_human_duration_units() {
+        assert_eq!(parse_human_duration("1 second"), Some(Duration::seconds(1)));
+        assert_eq!(parse_human_duration("3 weeks"), Some(Duration::weeks(3)));
+        assert_eq!(parse_human_duration("2 months"), Some(Duration::days(60)));
+        assert_eq!(parse_human_duration("2 years"), Some(Duration::days(730)));
+        assert_eq!(parse_human_duration("2 fortnights"), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Disk usage per kind (`docker system df`) and pruning what is not in use
+//!
+//! Pruning frees what the disk usage counts as reclaimable: stopped containers, images
+//! no container uses, volumes no container mounts and the whole build cache.
+
+use bollard::models::SystemDataUsageResponse;
+use bollard::query_parameters::{
+    DataUsageOptions, PruneBuildOptions, PruneContainersOptions, PruneImagesOptions,
+    PruneVolumesOptions,
+};
+use std::collections::HashMap;
+
+use crate::core::types::{DiskUsageKind, DiskUsageRow, HostId};
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Fetches the disk usage of a host, one row per kind it reports
+pub async fn fetch_disk_usage(host: &DockerHost) -> Result<Vec<DiskUsageRow>, String> {
+    let usage = host
+        .docker
+        .df(None::<DataUsageOptions>)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().disk_usage_failed, &[&e]))?;
+
+    let rows = disk_usage_rows(&host.host_id, usage);
+    if rows.is_empty() {
+        // Daemons older than API 1.52 only report the individual objects
+        return Err(i18n::messages().no_disk_usage_totals.to_string());
+    }
+    Ok(rows)
+}
+
+/// The rows of a disk usage response, leaving out kinds it doesn't report
+fn disk_usage_rows(host_id: &HostId, usage: SystemDataUsageResponse) -> Vec<DiskUsageRow> {
+    let row = |kind,
+               total: Option<i64>,
+               active: Option<i64>,
+               size: Option<i64>,
+               reclaimable: Option<i64>| {
+        Some(DiskUsageRow {
+            host_id: host_id.clone(),
+            kind,
+            total: total?,
+            active: active.unwrap_or(0),
+            size: size.unwrap_or(0),
+            reclaimable: reclaimable.unwrap_or(0),
+        })
+    };
+
+    [
+        usage.images_disk_usage.and_then(|u| {
+            row(
+                DiskUsageKind::Images,
+                u.total_count,
+                u.active_count,
+                u.total_size,
+                u.reclaimable,
+            )
+        }),
+        usage.containers_disk_usage.and_then(|u| {
+            row(
+                DiskUsageKind::Containers,
+                u.total_count,
+                u.active_count,
+                u.total_size,
+                u.reclaimable,
+            )
+        }),
+        usage.volumes_disk_usage.and_then(|u| {
+            row(
+                DiskUsageKind::Volumes,
+                u.total_count,
+                u.active_count,
+                u.total_size,
+                u.reclaimable,
+            )
+        }),
+        usage.build_cache_disk_usage.and_then(|u| {
+            row(
+                DiskUsageKind::BuildCache,
+                u.total_count,
+                u.active_count,
+                u.total_size,
+                u.reclaimable,
+            )
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Prunes what of the kind is not in use on the host, returning the space freed
+pub async fn prune(host: &DockerHost, kind: DiskUsageKind) -> Result<u64, String> {
+    let docker = &host.docker;
+    let reclaimed = match kind {
+        DiskUsageKind::Containers => docker
+            .prune_containers(None::<PruneContainersOptions>)
+            .await
+            .map(|response| response.space_reclaimed),
+        // Unused images, not only dangling ones, as `docker image prune -a`
+        DiskUsageKind::Images => docker
+            .prune_images(Some(PruneImagesOptions {
+                filters: Some(HashMap::from([(
+                    "dangling".to_string(),
+                    vec!["false".to_string()],
+                )])),
+            }))
+            .await
+            .map(|response| response.space_reclaimed),
+        // Named volumes too; by default only anonymous ones are pruned
+        DiskUsageKind::Volumes => docker
+            .prune_volumes(Some(PruneVolumesOptions {
+                filters: Some(HashMap::from([(
+                    "all".to_string(),
+                    vec!["true".to_string()],
+                )])),
+            }))
+            .await
+            .map(|response| response.space_reclaimed),
+        DiskUsageKind::BuildCache => docker
+            .prune_build(Some(PruneBuildOptions {
+                all: Some(true),
+                ..Default::default()
+            }))
+            .await
+            .map(|response| response.space_reclaimed),
+    }
+    .map_err(|e| i18n::fill(i18n::messages().prune_error, &[&e]))?;
+
+    Ok(reclaimed.unwrap_or(0).max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{ImagesDiskUsage, VolumesDiskUsage};
+
+    #[test]
+    fn test_disk_usage_rows() {
+        let usage = SystemDataUsageResponse {
+            images_disk_usage: Some(ImagesDiskUsage {
+                active_count: Some(2),
+                total_count: Some(5),
+                reclaimable: Some(300),
+                total_size: Some(1000),
+                items: None,
+            }),
+            // Kinds without a count are left out
+            volumes_disk_usage: Some(VolumesDiskUsage::default()),
+            ..Default::default()
+        };
+
+        let rows = disk_usage_rows(&"local".to_string(), usage);
+        assert_eq!(
+            rows,
+            vec![DiskUsageRow {
+                host_id: "local".to_string(),
+                kind: DiskUsageKind::Images,
+                total: 5,
+                active: 2,
+                size: 1000,
+                reclaimable: 300,
+            }]
+        );
+    }
+}
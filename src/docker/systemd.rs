@@ -0,0 +1,160 @@
+use std::process::Stdio;
+use tokio::process::Command;
+use url::Url;
+
+use crate::core::types::{AppEvent, ContainerAction, ContainerKey, EventSender};
+use crate::ui::i18n;
+
+/// Where commands for a host's systemd units run: on this machine for the local
+/// daemon, over `ssh` for SSH hosts
+#[derive(Clone, Debug, PartialEq)]
+pub enum CommandHost {
+    Local,
+    Ssh {
+        /// `user@host` or `host`
+        destination: String,
+        port: Option<u16>,
+    },
+}
+
+impl CommandHost {
+    /// The command host for a host specification; None for TCP and TLS hosts, whose
+    /// machine dtop can't run commands on
+    pub fn from_host_spec(host_spec: &str) -> Option<Self> {
+        if host_spec == "local" || host_spec.starts_with("unix://") {
+            return Some(CommandHost::Local);
+        }
+        let url = Url::parse(host_spec).ok()?;
+        if url.scheme() != "ssh" {
+            return None;
+        }
+        let host = url.host_str()?;
+        let destination = match url.username() {
+            "" => host.to_string(),
+            user => format!("{}@{}", user, host),
+        };
+        Some(CommandHost::Ssh {
+            destination,
+            port: url.port(),
+        })
+    }
+
+    /// A command running the program with the arguments on the host
+    pub(crate) fn command(&self, program: &str, args: &[&str]) -> Command {
+        match self {
+            CommandHost::Local => {
+                let mut command = Command::new(program);
+                command.args(args);
+                command
+            }
+            CommandHost::Ssh { destination, port } => {
+                let mut command = Command::new("ssh");
+                // Never wait on a password prompt the UI can't show
+                command.args(["-o", "BatchMode=yes"]);
+                if let Some(port) = port {
+                    command.args(["-p", &port.to_string()]);
+                }
+                command.arg(destination).arg(program).args(args);
+                command
+            }
+        }
+    }
+}
+
+/// Whether a unit name is safe to hand to a remote shell (ssh joins the arguments
+/// into one command line)
+fn is_valid_unit_name(unit: &str) -> bool {
+    !unit.is_empty()
+        && !unit.starts_with('-')
+        && unit
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '@' | '.' | '_' | ':' | '-'))
+}
+
+/// Runs `systemctl` on the host, returning its error output when it fails
+async fn systemctl(command_host: &CommandHost, args: &[&str]) -> Result<(), String> {
+    let mut full_args = vec!["--no-ask-password"];
+    full_args.extend(args);
+    let output = command_host
+        .command("systemctl", &full_args)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().systemctl_failed, &[&e]))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Restarts the unit with `systemctl`, as a system unit first and as a user unit
+/// (rootless Podman) when there is no such system unit
+pub async fn restart_unit(command_host: &CommandHost, unit: &str) -> Result<(), String> {
+    if !is_valid_unit_name(unit) {
+        return Err(i18n::fill(i18n::messages().invalid_unit_name, &[&unit]));
+    }
+
+    match systemctl(command_host, &["restart", unit]).await {
+        Err(e) if e.contains("not found") => {
+            systemctl(command_host, &["--user", "restart", unit]).await
+        }
+        result => result,
+    }
+}
+
+/// Restarts a container's systemd unit asynchronously, reporting it like other actions
+pub async fn execute_unit_restart(
+    command_host: CommandHost,
+    container_key: ContainerKey,
+    unit: String,
+    tx: EventSender,
+) {
+    let action = ContainerAction::RestartUnit;
+    let _ = tx
+        .send(AppEvent::ActionInProgress(container_key.clone(), action))
+        .await;
+
+    let event = match restart_unit(&command_host, &unit).await {
+        Ok(()) => AppEvent::ActionSuccess(container_key, action),
+        Err(e) => AppEvent::ActionError(container_key, action, e),
+    };
+    let _ = tx.send(event).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_host_from_host_spec() {
+        assert_eq!(
+            CommandHost::from_host_spec("local"),
+            Some(CommandHost::Local)
+        );
+        assert_eq!(
+            CommandHost::from_host_spec("ssh://deploy@web-1:2222"),
+            Some(CommandHost::Ssh {
+                destination: "deploy@web-1".to_string(),
+                port: Some(2222),
+            })
+        );
+        assert_eq!(
+            CommandHost::from_host_spec("ssh://web-1"),
+            Some(CommandHost::Ssh {
+                destination: "web-1".to_string(),
+                port: None,
+            })
+        );
+        assert_eq!(CommandHost::from_host_spec("tcp://web-1:2375"), None);
+    }
+
+    #[test]
+    fn test_is_valid_unit_name() {
+        assert!(is_valid_unit_name("container-web.service"));
+        assert!(is_valid_unit_name("app@1.service"));
+        assert!(!is_valid_unit_name("web.service; rm -rf /"));
+        assert!(!is_valid_unit_name("--help"));
+        assert!(!is_valid_unit_name(""));
+    }
+}
@@ -0,0 +1,165 @@
+//! Processes running in a container, from the Docker `top` API
+//!
+//! Docker runs `ps` on the host, so the columns depend on the host's `ps` (Windows
+//! hosts report their own set); the ones shown are picked by title.
+
+use bollard::query_parameters::TopOptions;
+use std::time::Duration;
+
+use crate::core::types::{AppEvent, ContainerKey, EventSender, ProcessInfo};
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// How often the process list is fetched again while it is shown
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// `ps` arguments giving the user and CPU usage of each process
+const PS_ARGS: &str = "aux";
+
+/// Fetches the processes of a container every REFRESH_INTERVAL until the view is closed
+/// (the task is aborted) or the app stops listening
+pub async fn stream_container_top(host: DockerHost, key: ContainerKey, tx: EventSender) {
+    loop {
+        let result = fetch_processes(&host, &key.container_id).await;
+        if tx
+            .send(AppEvent::ProcessesLoaded(key.clone(), result))
+            .await
+            .is_err()
+        {
+            return; // Channel closed
+        }
+        tokio::time::sleep(REFRESH_INTERVAL).await;
+    }
+}
+
+/// The processes of a container, busiest first
+async fn fetch_processes(
+    host: &DockerHost,
+    container_id: &str,
+) -> Result<Vec<ProcessInfo>, String> {
+    // Windows daemons reject ps arguments
+    let options = (!host.windows).then(|| TopOptions {
+        ps_args: PS_ARGS.to_string(),
+    });
+
+    let top = host
+        .docker
+        .top_processes(container_id, options)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_processes_failed, &[&e]))?;
+
+    Ok(parse_processes(
+        &top.titles.unwrap_or_default(),
+        top.processes.unwrap_or_default(),
+    ))
+}
+
+/// Picks the PID, user, CPU and command columns out of the `ps` output, sorted by CPU
+fn parse_processes(titles: &[String], processes: Vec<Vec<String>>) -> Vec<ProcessInfo> {
+    let column = |names: &[&str]| {
+        titles
+            .iter()
+            .position(|title| names.iter().any(|name| title.eq_ignore_ascii_case(name)))
+    };
+    let pid = column(&["PID"]);
+    let user = column(&["USER", "UID"]);
+    let cpu = column(&["%CPU", "C"]);
+    let command = column(&["COMMAND", "CMD", "Name"]);
+
+    let mut processes: Vec<ProcessInfo> = processes
+        .into_iter()
+        .map(|row| {
+            let field = |idx: Option<usize>| {
+                idx.and_then(|idx| row.get(idx))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            ProcessInfo {
+                pid: field(pid),
+                user: field(user),
+                cpu: field(cpu).parse().ok(),
+                command: field(command),
+            }
+        })
+        .collect();
+    processes.sort_by(|a, b| {
+        b.cpu
+            .unwrap_or(0.0)
+            .total_cmp(&a.cpu.unwrap_or(0.0))
+            .then_with(|| a.pid.cmp(&b.pid))
+    });
+
+    processes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_processes_aux() {
+        let titles = strings(&[
+            "USER", "PID", "%CPU", "%MEM", "VSZ", "RSS", "TTY", "STAT", "START", "TIME", "COMMAND",
+        ]);
+        let processes = vec![
+            strings(&[
+                "root",
+                "1",
+                "0.0",
+                "0.1",
+                "1000",
+                "500",
+                "?",
+                "Ss",
+                "10:00",
+                "0:00",
+                "nginx: master process nginx -g daemon off;",
+            ]),
+            strings(&[
+                "101",
+                "29",
+                "12.5",
+                "0.2",
+                "1200",
+                "600",
+                "?",
+                "S",
+                "10:00",
+                "0:03",
+                "nginx: worker process",
+            ]),
+        ];
+
+        let parsed = parse_processes(&titles, processes);
+        assert_eq!(
+            parsed[0],
+            ProcessInfo {
+                pid: "29".to_string(),
+                user: "101".to_string(),
+                cpu: Some(12.5),
+                command: "nginx: worker process".to_string(),
+            }
+        );
+        assert_eq!(parsed[1].pid, "1");
+        assert_eq!(
+            parsed[1].command,
+            "nginx: master process nginx -g daemon off;"
+        );
+    }
+
+    #[test]
+    fn test_parse_processes_windows() {
+        let titles = strings(&["Name", "PID", "CPU", "Private Working Set"]);
+        let processes = vec![strings(&["cmd.exe", "4120", "00:00:00.015", "1.2MB"])];
+
+        let parsed = parse_processes(&titles, processes);
+        assert_eq!(parsed[0].command, "cmd.exe");
+        assert_eq!(parsed[0].pid, "4120");
+        assert_eq!(parsed[0].user, "");
+        assert_eq!(parsed[0].cpu, None);
+    }
+}
@@ -0,0 +1,149 @@
+//! Docker volumes and the containers referencing them
+//!
+//! A volume is orphaned when no container, running or stopped, mounts it. The
+//! references are counted from the unfiltered container list so a host filter
+//! in the dtop config never makes an in-use volume look orphaned.
+
+use bollard::models::{MountPointTypeEnum, Volume};
+use bollard::query_parameters::{DataUsageOptionsBuilder, ListContainersOptions};
+use std::collections::HashMap;
+
+use crate::core::bulk::BulkRun;
+use crate::core::types::VolumeInfo;
+use crate::docker::connection::DockerHost;
+use crate::ui::i18n;
+
+/// Lists the volumes of a host with their size and number of referencing containers
+pub async fn fetch_volumes(host: &DockerHost) -> Result<Vec<VolumeInfo>, String> {
+    let volumes = host
+        .docker
+        .list_volumes(None::<bollard::query_parameters::ListVolumesOptions>)
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_volumes_failed, &[&e]))?
+        .volumes
+        .unwrap_or_default();
+
+    let containers = host
+        .docker
+        .list_containers(Some(ListContainersOptions {
+            all: true,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().list_containers_failed, &[&e]))?;
+
+    let mut references: HashMap<String, usize> = HashMap::new();
+    for mount in containers
+        .into_iter()
+        .flat_map(|c| c.mounts.unwrap_or_default())
+    {
+        if mount.typ == Some(MountPointTypeEnum::VOLUME)
+            && let Some(name) = mount.name
+        {
+            *references.entry(name).or_default() += 1;
+        }
+    }
+
+    // Sizes are best effort: computing them can be slow or unsupported by the driver
+    let sizes = fetch_volume_sizes(host).await.unwrap_or_else(|e| {
+        tracing::warn!("Could not fetch volume sizes for {}: {}", host.host_id, e);
+        HashMap::new()
+    });
+
+    let mut volumes: Vec<VolumeInfo> = volumes
+        .into_iter()
+        .map(|volume| VolumeInfo {
+            host_id: host.host_id.clone(),
+            size: sizes.get(&volume.name).copied(),
+            containers: references.get(&volume.name).copied().unwrap_or(0),
+            name: volume.name,
+            driver: volume.driver,
+            mountpoint: volume.mountpoint,
+        })
+        .collect();
+    volumes.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(volumes)
+}
+
+/// Volume sizes in bytes from the disk usage endpoint (`docker system df -v`)
+async fn fetch_volume_sizes(host: &DockerHost) -> Result<HashMap<String, i64>, String> {
+    let options = DataUsageOptionsBuilder::new()
+        ._type(vec!["volume".to_string()])
+        .verbose(true)
+        .build();
+
+    let usage = host
+        .docker
+        .df(Some(options))
+        .await
+        .map_err(|e| i18n::fill(i18n::messages().disk_usage_failed, &[&e]))?;
+
+    let items = usage
+        .volumes_disk_usage
+        .and_then(|volumes| volumes.items)
+        .unwrap_or_default();
+
+    Ok(volume_sizes(items))
+}
+
+/// Extracts the known sizes from the disk usage volume items (-1 means not computed)
+fn volume_sizes(items: Vec<serde_json::Value>) -> HashMap<String, i64> {
+    items
+        .into_iter()
+        .filter_map(|item| serde_json::from_value::<Volume>(item).ok())
+        .filter_map(|volume| {
+            let size = volume.usage_data?.size;
+            (size >= 0).then_some((volume.name, size))
+        })
+        .collect()
+}
+
+/// Removes volumes by name as part of a bulk action, returning an error message for each
+/// one that could not be removed
+/// Docker refuses to remove volumes that are in use, so a volume that got a container since
+/// the list was loaded is kept
+pub async fn remove_volumes(host: &DockerHost, names: &[String], bulk: &BulkRun) -> Vec<String> {
+    bulk.run(names, |name| async move {
+        host.docker
+            .remove_volume(name, None::<bollard::query_parameters::RemoveVolumeOptions>)
+            .await
+            .map_err(|e| i18n::fill(i18n::messages().remove_volume_failed, &[&name, &e]))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_volume_sizes() {
+        let items = vec![
+            json!({
+                "Name": "data",
+                "Driver": "local",
+                "Mountpoint": "/var/lib/docker/volumes/data/_data",
+                "Labels": {},
+                "Options": {},
+                "Scope": "local",
+                "UsageData": {"Size": 2048, "RefCount": 1}
+            }),
+            json!({
+                "Name": "remote",
+                "Driver": "nfs",
+                "Mountpoint": "",
+                "Labels": {},
+                "Options": {},
+                "Scope": "local",
+                "UsageData": {"Size": -1, "RefCount": 0}
+            }),
+            json!({"unexpected": true}),
+        ];
+
+        let sizes = volume_sizes(items);
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes.get("data"), Some(&2048));
+    }
+}
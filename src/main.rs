@@ -6,6 +6,7 @@ mod ui;
 use clap::Parser;
 use clap::builder::styling::{AnsiColor, Effects, Styles};
 use crossterm::{
+    event::{DisableFocusChange, EnableFocusChange},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -13,6 +14,7 @@ use ratatui::{Terminal, backend::CrosstermBackend};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
@@ -20,19 +22,79 @@ use tokio::sync::mpsc;
 use tracing_subscriber::EnvFilter;
 
 use cli::config::Config;
-use cli::connect::{establish_connections, spawn_remaining_connections_handler};
+use cli::connect::{
+    establish_connections, spawn_action_connections, spawn_remaining_connections_handler,
+};
+use cli::report::Reporter;
+use core::alerts::Alerts;
+use core::anomalies::Anomalies;
 use core::app_state::AppState;
-use core::types::{AppEvent, RenderAction, SortField};
+use core::budgets::{Budget, budgets_from_config};
+use core::history_db::HistoryRecorder;
+use core::scripting::ScriptHooks;
+use core::types::{
+    AppEvent, DEFAULT_ACTION_RETRIES, DEFAULT_BULK_CONCURRENCY, DEFAULT_CLEANUP_EXITED_DAYS,
+    DEFAULT_REFRESH_MS, DEFAULT_RESTART_LOOP_MINUTES, DEFAULT_RESTART_LOOP_RESTARTS,
+    DEFAULT_STOP_TIMEOUT_SECS, DEFAULT_TIMESTAMP_FORMAT, DEFAULT_VERIFY_SECS, Damage,
+    MemoryDisplay, REFRESH_MS_RANGE, RenderAction, ShellSessionInfo, SortField,
+    init_bucket_duration,
+};
 use docker::connection::{DockerHost, container_manager};
+use docker::log_snapshot::LogSnapshots;
+use docker::registry::RegistryCredentials;
+use docker::shell::{ShellRule, ShellSession, configured_shells, shell_rules_from_config};
+use ui::export::export_view;
+use ui::formatters::is_valid_timestamp_format;
+use ui::i18n::{self, Locale};
 use ui::icons::IconStyle;
 use ui::input::keyboard_worker;
 use ui::render::{UiStyles, render_ui};
+use ui::theme::{Background, ColorSupport, SelectionStyle, Theme};
 
 /// Configuration for the event loop
 struct EventLoopConfig {
     icon_style: IconStyle,
+    theme: Theme,
+    background: Background,
+    color_support: ColorSupport,
+    selection_style: Option<SelectionStyle>,
+    zebra: bool,
+    show_id_column: bool,
+    show_status_column: bool,
+    show_restarts_column: bool,
+    show_size_column: bool,
+    show_platform_column: bool,
+    show_pids_column: bool,
+    show_host_summary: bool,
+    show_loop_metrics: bool,
+    /// None to decide from whether this is an SSH session
+    low_bandwidth: Option<bool>,
+    refresh_interval: Duration,
+    cpu_normalized: bool,
+    config_path: Option<PathBuf>,
+    memory_display: MemoryDisplay,
+    absolute_timestamps: bool,
+    timestamp_format: String,
+    stop_timeout: u32,
+    log_snapshots: Option<LogSnapshots>,
+    download_dir: Option<String>,
+    verify_secs: u64,
+    action_retries: u32,
+    bulk_concurrency: usize,
+    cleanup_exited_days: u64,
+    restart_loop_restarts: u32,
+    restart_loop_window: Duration,
     show_all: bool,
     sort_field: SortField,
+    script_hooks: Option<ScriptHooks>,
+    alerts: Option<Alerts>,
+    do_not_disturb: bool,
+    anomalies: Option<Anomalies>,
+    reporter: Option<Reporter>,
+    history: Option<HistoryRecorder>,
+    budgets: Vec<Budget>,
+    shell_rules: Vec<ShellRule>,
+    registry_credentials: RegistryCredentials,
 }
 
 /// Returns custom styles for CLI help output
@@ -85,6 +147,15 @@ struct Args {
     #[arg(short = 'i', long, verbatim_doc_comment)]
     icons: Option<String>,
 
+    /// Color theme for the UI
+    ///
+    /// Options:
+    ///   default        - Green/yellow/red thresholds (default)
+    ///   colorblind     - Blue/yellow/vermillion, safe for deuteranopia and protanopia
+    ///   high-contrast  - Bright bold colors with reversed selection
+    #[arg(short = 't', long, verbatim_doc_comment)]
+    theme: Option<String>,
+
     /// Filter containers (can be specified multiple times)
     ///
     /// Examples:
@@ -128,12 +199,32 @@ struct Args {
     ///   name    - Sort by container name (alphabetically)
     ///   cpu     - Sort by CPU usage (highest first)
     ///   memory  - Sort by memory usage (highest first)
+    ///   restarts - Sort by restarts (most first)
+    ///   blkread  - Sort by block device reads per second (highest first)
+    ///   blkwrite - Sort by block device writes per second (highest first)
+    ///   pids     - Sort by process count (most first)
+    ///   script  - Sort by the scripts.sort key from the config file
     ///
-    /// You can also use short forms: u, n, c, m
+    /// You can also use short forms: u, n, c, m, r
     ///
     /// The sort direction can be toggled in the UI by pressing the same key again.
     #[arg(short = 's', long = "sort", verbatim_doc_comment)]
     sort: Option<String>,
+
+    /// Reduce what is sent to the terminal, for high-latency links
+    ///
+    /// Redraws every 2 seconds instead of every 500ms, drops the sparklines and
+    /// colors fewer cells. On by default in SSH sessions; set 'low_bandwidth: false'
+    /// in the config file to turn that off.
+    #[arg(long, verbatim_doc_comment)]
+    low_bandwidth: bool,
+
+    /// Time between two screen refreshes in milliseconds (100 to 10000)
+    ///
+    /// Defaults to 500. Sparkline samples are taken every 4 refreshes (every 2 seconds
+    /// by default), so a longer interval also makes the sparklines cover more time.
+    #[arg(long, value_name = "MS", verbatim_doc_comment)]
+    refresh: Option<u64>,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -141,6 +232,24 @@ enum Command {
     /// Update dtop to the latest version
     #[cfg(feature = "self-update")]
     Update,
+    /// Print one round of container stats from all hosts as JSON and exit
+    Snapshot,
+    /// Check the config, host connections and terminal, and suggest fixes
+    Doctor,
+    /// Run a collector keeping the connections and stats history for the UI to attach to
+    Agentd,
+    /// Summarize the stats and events stored in the history database
+    Report {
+        /// How far back to go, e.g. 30m, 24h, 7d
+        #[arg(long, default_value = "24h")]
+        since: String,
+        /// Only the containers whose name contains this
+        #[arg(long)]
+        container: Option<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -151,13 +260,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
     // Handle subcommands before initializing Tokio runtime
-    if let Some(command) = args.command {
-        match command {
-            #[cfg(feature = "self-update")]
-            Command::Update => {
-                return cli::update::run_update();
-            }
-        }
+    #[cfg(feature = "self-update")]
+    if let Some(Command::Update) = args.command {
+        return cli::update::run_update();
     }
 
     // Run the main TUI in async context
@@ -166,6 +271,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 #[tokio::main]
 async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    // The doctor reports config problems instead of stopping at the first one
+    if let Some(Command::Doctor) = args.command {
+        return cli::doctor::run_doctor(&args.host, &args.filter).await;
+    }
+
+    // Reading the history only needs the config
+    if let Some(Command::Report {
+        since,
+        container,
+        json,
+    }) = &args.command
+    {
+        return cli::history_report::run_report(since, container.as_deref(), *json);
+    }
+
     // Determine if CLI hosts were explicitly provided
     let cli_provided = !args.host.is_empty();
 
@@ -190,7 +310,7 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
         )
     } else if !config.hosts.is_empty() {
         // No CLI args but config has hosts, use config
-        if let Some(path) = config_path {
+        if let Some(path) = &config_path {
             eprintln!("Loaded config from: {}", path.display());
         }
         config.merge_with_cli_hosts(
@@ -223,6 +343,100 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
         IconStyle::Unicode
     };
 
+    // Select the UI language (config takes precedence over the environment)
+    let locale = match merged_config.locale.as_deref().map(str::parse::<Locale>) {
+        Some(Ok(locale)) => locale,
+        Some(Err(e)) => {
+            eprintln!("{}, using the environment locale", e);
+            Locale::from_env()
+        }
+        None => Locale::from_env(),
+    };
+    i18n::init(locale);
+
+    // Determine color theme (CLI takes precedence over config)
+    let theme = args
+        .theme
+        .as_ref()
+        .or(merged_config.theme.as_ref())
+        .map(|t| {
+            t.parse::<Theme>().unwrap_or_else(|e| {
+                eprintln!("{}, using the default theme", e);
+                Theme::default()
+            })
+        })
+        .unwrap_or_default();
+
+    // Determine color support: explicit config value, or detect from TERM/COLORTERM
+    let color_support = match merged_config.color_mode.as_deref() {
+        None | Some("auto") => ColorSupport::detect(),
+        Some(value) => value.parse::<ColorSupport>().unwrap_or_else(|e| {
+            eprintln!("{}, detecting from the environment", e);
+            ColorSupport::detect()
+        }),
+    };
+
+    // Selected-row highlight override (theme default if unset)
+    let selection_style = merged_config.selection_style.as_deref().and_then(|value| {
+        match value.parse::<SelectionStyle>() {
+            Ok(style) => Some(style),
+            Err(e) => {
+                eprintln!("{}, using the theme default", e);
+                None
+            }
+        }
+    });
+    let zebra = merged_config.zebra.unwrap_or(false);
+
+    // Memory column without sparklines
+    let memory_display = match merged_config.memory_display.as_deref() {
+        None => MemoryDisplay::default(),
+        Some(value) => value.parse::<MemoryDisplay>().unwrap_or_else(|e| {
+            eprintln!("{}, using percent", e);
+            MemoryDisplay::default()
+        }),
+    };
+
+    // Refresh interval (CLI takes precedence over config), which the history buckets follow
+    let refresh_ms = match args.refresh.or(merged_config.refresh_ms) {
+        Some(ms) if REFRESH_MS_RANGE.contains(&ms) => ms,
+        Some(ms) => {
+            eprintln!(
+                "Invalid refresh interval {}ms (use {} to {}), using {}ms",
+                ms,
+                REFRESH_MS_RANGE.start(),
+                REFRESH_MS_RANGE.end(),
+                DEFAULT_REFRESH_MS
+            );
+            DEFAULT_REFRESH_MS
+        }
+        None => DEFAULT_REFRESH_MS,
+    };
+    let refresh_interval = Duration::from_millis(refresh_ms);
+    init_bucket_duration(refresh_interval);
+
+    // Absolute timestamp format (an invalid chrono format would panic when rendered)
+    let timestamp_format = match merged_config.timestamp_format.as_deref() {
+        Some(format) if is_valid_timestamp_format(format) => format.to_string(),
+        Some(format) => {
+            eprintln!(
+                "Invalid timestamp_format '{}', using '{}'",
+                format, DEFAULT_TIMESTAMP_FORMAT
+            );
+            DEFAULT_TIMESTAMP_FORMAT.to_string()
+        }
+        None => DEFAULT_TIMESTAMP_FORMAT.to_string(),
+    };
+
+    // Log snapshots before Stop/Restart/Remove, only when configured
+    let log_snapshots = merged_config.log_snapshots.as_ref().and_then(|config| {
+        let snapshots = LogSnapshots::from_config(config);
+        if snapshots.is_none() {
+            eprintln!("Could not determine a directory for log_snapshots, set log_snapshots.dir");
+        }
+        snapshots
+    });
+
     // Determine show_all setting (CLI or config, defaults to false)
     let show_all = merged_config.all.unwrap_or(false);
 
@@ -233,24 +447,111 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
         .and_then(|s| s.parse::<SortField>().ok())
         .unwrap_or(SortField::Uptime);
 
+    // Restart loop detection thresholds, with defaults for what is not configured
+    let restart_loop = merged_config.restart_loop.clone().unwrap_or_default();
+
+    // Compile scripting hooks up front so syntax errors are reported before the UI starts
+    let script_hooks = merged_config
+        .scripts
+        .as_ref()
+        .map(ScriptHooks::compile)
+        .transpose()?;
+
+    // Invalid alert rules are reported before the UI starts too
+    let alerts = merged_config
+        .alerts
+        .as_ref()
+        .map(Alerts::from_config)
+        .transpose()?
+        .flatten();
+
+    // And the anomaly baselines
+    let anomalies = merged_config
+        .anomalies
+        .as_ref()
+        .map(Anomalies::from_config)
+        .transpose()?;
+
+    // And budgets
+    let budgets = budgets_from_config(merged_config.budgets.as_deref().unwrap_or_default())?;
+
+    // And the shells per container label
+    let shell_rules =
+        shell_rules_from_config(merged_config.shells_by_label.as_deref().unwrap_or_default())?;
+
+    // Same for the periodic reports
+    let reporter = merged_config
+        .reports
+        .as_ref()
+        .map(Reporter::from_config)
+        .transpose()?;
+
+    // Script sorting needs a sort script; fall back to the default otherwise
+    let sort_field = if sort_field == SortField::Script
+        && !script_hooks.as_ref().is_some_and(|h| h.has_sort_key())
+    {
+        eprintln!("Sort field 'script' requires scripts.sort in the config, using uptime");
+        SortField::Uptime
+    } else {
+        sort_field
+    };
+
+    // Headless snapshot: same connections and events, printed as JSON instead of drawn
+    if let Some(Command::Snapshot) = args.command {
+        return cli::snapshot::run_snapshot(&merged_config, show_all).await;
+    }
+
+    // Headless collector the UI attaches to
+    if let Some(Command::Agentd) = args.command {
+        return cli::agentd::run_agentd(&merged_config, show_all).await;
+    }
+
+    // Determine terminal background: explicit config value, or query the terminal (OSC 11).
+    // Only the UI asks, after the headless commands: they may not run in a terminal at all.
+    // This must happen before raw mode/alternate screen so the reply isn't read as input.
+    let background = match merged_config.background.as_deref() {
+        None | Some("auto") => Background::detect().unwrap_or_default(),
+        Some(value) => value.parse::<Background>().unwrap_or_else(|e| {
+            eprintln!("{}, using dark", e);
+            Background::Dark
+        }),
+    };
+
+    // Stats samples and events kept for `dtop report`
+    let history = merged_config
+        .history_db
+        .as_ref()
+        .map(HistoryRecorder::from_config)
+        .transpose()?;
+
     // Create event channel
     let (tx, mut rx) = mpsc::channel::<AppEvent>(1000);
 
-    // Establish connections to all configured hosts
-    let connection_result = establish_connections(&merged_config, tx.clone()).await?;
-
-    // Store first connected host
+    // With a `dtop agentd` collector running, containers and stats come from it and the
+    // UI starts at once; the hosts are only connected to for actions
     let mut connected_hosts: HashMap<String, DockerHost> = HashMap::new();
-    connected_hosts.insert(
-        connection_result.first_host.host_id.clone(),
-        connection_result.first_host.clone(),
-    );
+    let attached = match merged_config.agentd.as_ref() {
+        Some(agentd) => cli::agentd::attach(agentd, tx.clone()).await,
+        None => false,
+    };
+    if attached {
+        spawn_action_connections(merged_config.clone(), tx.clone());
+    } else {
+        // Establish connections to all configured hosts
+        let connection_result = establish_connections(&merged_config, tx.clone()).await?;
+
+        // Store first connected host
+        connected_hosts.insert(
+            connection_result.first_host.host_id.clone(),
+            connection_result.first_host.clone(),
+        );
 
-    // Start container manager for first host
-    spawn_container_manager(connection_result.first_host, tx.clone());
+        // Start container manager for first host
+        spawn_container_manager(connection_result.first_host, tx.clone());
 
-    // Handle remaining connections in background
-    spawn_remaining_connections_handler(connection_result.remaining_rx, tx.clone());
+        // Handle remaining connections in background
+        spawn_remaining_connections_handler(connection_result.remaining_rx, tx.clone());
+    }
 
     // Create pause flag for keyboard worker
     let keyboard_paused = Arc::new(AtomicBool::new(false));
@@ -270,8 +571,70 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
         keyboard_paused,
         EventLoopConfig {
             icon_style,
+            theme,
+            background,
+            color_support,
+            selection_style,
+            zebra,
+            show_id_column: merged_config.id_column.unwrap_or(true),
+            show_status_column: merged_config.status_column.unwrap_or(false),
+            show_restarts_column: merged_config.restarts_column.unwrap_or(false),
+            show_size_column: merged_config.size_column.unwrap_or(false),
+            show_platform_column: merged_config.platform_column.unwrap_or(false),
+            show_pids_column: merged_config.pids_column.unwrap_or(false),
+            show_host_summary: merged_config.host_summary.unwrap_or(true),
+            show_loop_metrics: merged_config.loop_metrics.unwrap_or(false),
+            // The flag can only turn it on, like --all
+            low_bandwidth: if args.low_bandwidth {
+                Some(true)
+            } else {
+                merged_config.low_bandwidth
+            },
+            refresh_interval,
+            cpu_normalized: merged_config.cpu_normalized.unwrap_or(false),
+            // Only a config file that was loaded is written back; none is with --host
+            config_path,
+            memory_display,
+            absolute_timestamps: merged_config.absolute_timestamps.unwrap_or(false),
+            timestamp_format,
+            stop_timeout: merged_config
+                .stop_timeout
+                .unwrap_or(DEFAULT_STOP_TIMEOUT_SECS),
+            log_snapshots,
+            download_dir: merged_config.download_dir.clone(),
+            verify_secs: merged_config.verify_secs.unwrap_or(DEFAULT_VERIFY_SECS),
+            action_retries: merged_config
+                .action_retries
+                .unwrap_or(DEFAULT_ACTION_RETRIES),
+            bulk_concurrency: merged_config
+                .bulk_concurrency
+                .unwrap_or(DEFAULT_BULK_CONCURRENCY),
+            cleanup_exited_days: merged_config
+                .cleanup_exited_days
+                .unwrap_or(DEFAULT_CLEANUP_EXITED_DAYS),
+            restart_loop_restarts: restart_loop
+                .restarts
+                .unwrap_or(DEFAULT_RESTART_LOOP_RESTARTS),
+            restart_loop_window: Duration::from_secs(
+                restart_loop.minutes.unwrap_or(DEFAULT_RESTART_LOOP_MINUTES) * 60,
+            ),
             show_all,
             sort_field,
+            script_hooks,
+            alerts,
+            do_not_disturb: merged_config
+                .alerts
+                .as_ref()
+                .and_then(|alerts| alerts.do_not_disturb)
+                .unwrap_or(false),
+            anomalies,
+            reporter,
+            history,
+            budgets,
+            shell_rules,
+            registry_credentials: RegistryCredentials::load(
+                merged_config.registries.as_ref().unwrap_or(&HashMap::new()),
+            ),
         },
     )
     .await?;
@@ -286,7 +649,7 @@ async fn run_async(args: Args) -> Result<(), Box<dyn std::error::Error>> {
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, Box<dyn std::error::Error>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
     let backend = CrosstermBackend::new(stdout);
     Ok(Terminal::new(backend)?)
 }
@@ -296,7 +659,11 @@ fn cleanup_terminal(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        DisableFocusChange,
+        LeaveAlternateScreen
+    )?;
     terminal.show_cursor()?;
     Ok(())
 }
@@ -325,52 +692,135 @@ async fn run_event_loop(
     config: EventLoopConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut state = AppState::new(connected_hosts, tx, config.show_all, config.sort_field);
-    let draw_interval = Duration::from_millis(500); // Refresh UI every 500ms
+    state.script_hooks = config.script_hooks;
+    state.alerts = config.alerts;
+    state.do_not_disturb = config.do_not_disturb;
+    state.anomalies = config.anomalies;
+    state.budgets = config.budgets;
+    state.shell_rules = config.shell_rules;
+    state.history = config.history;
+    state.registry_credentials = Arc::new(config.registry_credentials);
+    state.show_id_column = config.show_id_column;
+    state.show_status_column = config.show_status_column;
+    state.show_restarts_column = config.show_restarts_column;
+    state.show_size_column = config.show_size_column;
+    state.show_platform_column = config.show_platform_column;
+    state.show_pids_column = config.show_pids_column;
+    state.show_host_summary = config.show_host_summary;
+    state.show_loop_metrics = config.show_loop_metrics;
+    state.cpu_normalized = config.cpu_normalized;
+    state.config_path = config.config_path;
+    state.memory_display = config.memory_display;
+    state.absolute_timestamps = config.absolute_timestamps;
+    state.timestamp_format = config.timestamp_format;
+    state.stop_timeout = config.stop_timeout;
+    state.log_snapshots = config.log_snapshots.map(Arc::new);
+    state.download_dir = config.download_dir;
+    state.verify_secs = config.verify_secs;
+    state.action_retries = config.action_retries;
+    state.bulk_concurrency = config.bulk_concurrency;
+    state.cleanup_exited_days = config.cleanup_exited_days;
+    state.restart_loop_restarts = config.restart_loop_restarts;
+    state.restart_loop_window = config.restart_loop_window;
+    let mut reporter = config.reporter;
+    state.low_bandwidth = config.low_bandwidth.unwrap_or(state.is_ssh_session);
+    state.refresh_interval = config.refresh_interval;
     let mut last_draw = std::time::Instant::now();
 
     // Pre-allocate styles to avoid recreation every frame
-    let styles = UiStyles::with_icon_style(config.icon_style);
+    let mut styles = UiStyles::new(config.icon_style, config.theme, config.background);
+    if let Some(selection_style) = config.selection_style {
+        styles = styles.with_selection_style(selection_style);
+    }
+    if config.zebra {
+        styles = styles.with_zebra_striping();
+    }
+    if state.low_bandwidth {
+        styles = styles.with_fewer_colors();
+    }
+    let styles = styles.downgrade_colors(config.color_support);
+
+    // Shell sessions by id, kept running while detached
+    let mut shell_sessions: HashMap<usize, ShellSession> = HashMap::new();
+    let mut next_shell_id = 1;
 
     while !state.should_quit {
         // Wait for events with timeout - handles both throttling and waiting
+        // The refresh adapts to focus, input and the number of containers
+        let draw_interval = state.frame_interval();
+        docker::stats::set_throttled(state.stats_throttled());
         let action = process_events(rx, &mut state, draw_interval).await;
 
+        // Drop the sessions whose shell exited
+        shell_sessions.retain(|_, session| !session.is_finished());
+
+        if let Some(reporter) = &mut reporter {
+            reporter.tick(&state);
+        }
+
         match action {
             RenderAction::StartShell(container_key) => {
                 // Handle shell request - this takes over the terminal
                 if let Some(host) = state.connected_hosts.get(&container_key.host_id) {
-                    // Pause keyboard worker during shell session
-                    keyboard_paused.store(true, Ordering::Relaxed);
-
-                    // Run shell session - this blocks until shell exits
-                    if let Err(e) = host.run_shell_session(&container_key.container_id).await {
-                        tracing::error!("Shell session error: {}", e);
+                    let container = state.containers.get(&container_key);
+                    let name = container.map_or_else(
+                        || container_key.container_id.clone(),
+                        |container| container.name.clone(),
+                    );
+                    let labels = container.map(|c| c.labels.clone()).unwrap_or_default();
+                    let shells = configured_shells(host, &labels, &state.shell_rules);
+                    let info = ShellSessionInfo {
+                        id: next_shell_id,
+                        key: container_key,
+                        name,
+                        started_at: chrono::Utc::now(),
+                    };
+                    next_shell_id += 1;
+
+                    match ShellSession::start(host, info, shells, state.event_tx.clone()).await {
+                        Ok(session) => {
+                            let id = session.info.id;
+                            state.handle_event(AppEvent::ShellSessionStarted(session.info.clone()));
+                            let session = shell_sessions.entry(id).or_insert(session);
+                            attach_shell_session(
+                                session,
+                                &keyboard_paused,
+                                terminal,
+                                &mut state,
+                                &styles,
+                            )
+                            .await?;
+                        }
+                        Err(e) => tracing::error!("Shell session error: {}", e),
                     }
-
-                    // Resume keyboard worker
-                    keyboard_paused.store(false, Ordering::Relaxed);
-
-                    // Force full redraw after returning from shell
-                    terminal.clear()?;
-                    terminal.draw(|f| {
-                        render_ui(f, &mut state, &styles);
-                    })?;
+                    last_draw = std::time::Instant::now();
+                }
+            }
+            RenderAction::Export(choice) => {
+                let width = terminal.size()?.width;
+                let outcome = export_view(&state, choice, width);
+                state.handle_event(AppEvent::ViewExported(outcome));
+                draw_ui(terminal, &mut state, &styles)?;
+                last_draw = std::time::Instant::now();
+            }
+            RenderAction::AttachShell(id) => {
+                if let Some(session) = shell_sessions.get_mut(&id) {
+                    attach_shell_session(session, &keyboard_paused, terminal, &mut state, &styles)
+                        .await?;
                     last_draw = std::time::Instant::now();
                 }
             }
             RenderAction::Render => {
                 // Force draw requested
-                terminal.draw(|f| {
-                    render_ui(f, &mut state, &styles);
-                })?;
+                draw_ui(terminal, &mut state, &styles)?;
                 last_draw = std::time::Instant::now();
             }
             RenderAction::None => {
-                // Check if we should draw based on interval
-                if last_draw.elapsed() >= draw_interval {
-                    terminal.draw(|f| {
-                        render_ui(f, &mut state, &styles);
-                    })?;
+                // Check if we should draw based on interval, unless the frame would
+                // come out the same
+                let since = last_draw.elapsed();
+                if since >= draw_interval && state.frame_due(since) {
+                    draw_ui(terminal, &mut state, &styles)?;
                     last_draw = std::time::Instant::now();
                 }
             }
@@ -380,6 +830,47 @@ async fn run_event_loop(
     Ok(())
 }
 
+/// Hands the terminal to a shell session until it exits or is detached, then redraws the UI
+async fn attach_shell_session(
+    session: &mut ShellSession,
+    keyboard_paused: &AtomicBool,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    state: &mut AppState,
+    styles: &UiStyles,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Pause keyboard worker during shell session
+    keyboard_paused.store(true, Ordering::Relaxed);
+
+    // Run shell session - this blocks until the shell exits or is detached
+    if let Err(e) = session.attach().await {
+        tracing::error!("Shell session error: {}", e);
+    }
+
+    // Resume keyboard worker
+    keyboard_paused.store(false, Ordering::Relaxed);
+
+    // Force full redraw after returning from shell
+    terminal.clear()?;
+    draw_ui(terminal, state, styles)?;
+
+    Ok(())
+}
+
+/// Draws the UI, timing it for the event loop indicator
+fn draw_ui(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    state: &mut AppState,
+    styles: &UiStyles,
+) -> io::Result<()> {
+    let started = std::time::Instant::now();
+    terminal.draw(|f| {
+        render_ui(f, state, styles);
+    })?;
+    state.loop_metrics.record_frame(started.elapsed());
+    state.damage = Damage::None;
+    Ok(())
+}
+
 /// Processes all pending events from the event channel
 /// Waits with timeout for at least one event, then drains all pending events
 /// Returns the action to take after processing events
@@ -389,8 +880,8 @@ async fn process_events(
     timeout: Duration,
 ) -> RenderAction {
     // Wait for first event with timeout
-    let mut result = match tokio::time::timeout(timeout, rx.recv()).await {
-        Ok(Some(event)) => state.handle_event(event),
+    let event = match tokio::time::timeout(timeout, rx.recv()).await {
+        Ok(Some(event)) => event,
         Ok(None) => {
             // Channel closed
             state.should_quit = true;
@@ -402,18 +893,46 @@ async fn process_events(
         }
     };
 
-    // If we got a shell request, return immediately
-    if matches!(result, RenderAction::StartShell(_)) {
-        return result;
+    // Measured for the event loop indicator
+    let started = std::time::Instant::now();
+    let depth = rx.len() + 1;
+    let (result, handled) = drain_events(event, rx, state);
+    state
+        .loop_metrics
+        .record_batch(handled, depth, started.elapsed());
+
+    result
+}
+
+/// Handles the event, then the pending ones without blocking
+/// Returns the action to take and the number of events handled
+fn drain_events(
+    event: AppEvent,
+    rx: &mut mpsc::Receiver<AppEvent>,
+    state: &mut AppState,
+) -> (RenderAction, u32) {
+    let mut result = state.handle_event(event);
+    let mut handled = 1;
+
+    // If we got a shell or export request, return immediately
+    if matches!(
+        result,
+        RenderAction::StartShell(_) | RenderAction::AttachShell(_) | RenderAction::Export(_)
+    ) {
+        return (result, handled);
     }
 
     // Drain any additional pending events without blocking
     while let Ok(event) = rx.try_recv() {
         let action = state.handle_event(event);
-
-        // StartShell takes priority
-        if matches!(action, RenderAction::StartShell(_)) {
-            return action;
+        handled += 1;
+
+        // Shell and export requests take priority
+        if matches!(
+            action,
+            RenderAction::StartShell(_) | RenderAction::AttachShell(_) | RenderAction::Export(_)
+        ) {
+            return (action, handled);
         }
 
         // Render takes priority over None
@@ -422,7 +941,7 @@ async fn process_events(
         }
     }
 
-    result
+    (result, handled)
 }
 
 fn setup_logging() -> Result<(), Box<dyn std::error::Error>> {
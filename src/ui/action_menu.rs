@@ -2,29 +2,43 @@ use ratatui::{
     Frame,
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Clear, List, ListItem},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
 
 use crate::core::app_state::AppState;
-use crate::core::types::{ContainerAction, ViewState};
+use crate::core::types::{ContainerAction, CopyTarget, PruneTarget, ViewState};
+use crate::ui::formatters::{display_width, format_time_left, truncate_with_ellipsis};
+use crate::ui::i18n;
 use crate::ui::render::UiStyles;
 
-/// Renders a centered action menu popup for a specific container
+/// Renders a centered action menu popup for a specific container or compose project
 pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
-    // Only render if we're in ActionMenu view
-    let ViewState::ActionMenu(ref container_key) = state.view_state else {
-        return;
-    };
-
-    // Get the container info
-    let Some(container) = state.containers.get(container_key) else {
-        return;
+    // Only render if we're in an action menu view
+    let (name, host_id, available_actions) = match &state.view_state {
+        ViewState::ActionMenu(container_key) => {
+            // Get the container info
+            let Some(container) = state.containers.get(container_key) else {
+                return;
+            };
+            (
+                container.name.as_str(),
+                container_key.host_id.as_str(),
+                state.actions_for(container),
+            )
+        }
+        ViewState::ProjectActionMenu(project) => (
+            project.name.as_str(),
+            project.host_id.as_str(),
+            ContainerAction::available_for_project(),
+        ),
+        _ => return,
     };
 
     let area = f.area();
+    let msg = i18n::messages();
 
     // Create a centered popup (40% width, auto height based on actions)
-    let available_actions = ContainerAction::available_for_state(&container.state);
 
     // If no actions available, don't show the menu
     if available_actions.is_empty() {
@@ -43,11 +57,12 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
     // Clear the background area first to prevent bleed-through
     f.render_widget(Clear, popup_area);
 
-    // Create the title with container name
+    // Create the title with container or project name
     let title = format!(
-        " Actions: {} ({}) ",
-        truncate_string(&container.name, 20),
-        truncate_string(&container_key.host_id, 10)
+        " {}: {} ({}) ",
+        msg.actions,
+        truncate_with_ellipsis(name, 20),
+        truncate_with_ellipsis(host_id, 10)
     );
 
     // Render the popup block
@@ -69,13 +84,20 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
     // Render the border first
     f.render_widget(block, popup_area);
 
-    // Create list items from available actions
+    // Create list items from available actions, stops with the time given before killing
     let list_items: Vec<ListItem> = available_actions
         .iter()
         .map(|action| {
             let icon = styles.icons.action(*action);
-            let text = format!(" {}  {}", icon, action.display_name());
-            ListItem::new(text).style(Style::default().fg(Color::White))
+            let text = format!(" {}  {}", icon, msg.action(*action));
+            let mut spans = vec![Span::raw(text)];
+            if matches!(action, ContainerAction::Stop | ContainerAction::Restart) {
+                spans.push(Span::styled(
+                    format!(" ({}s)", state.stop_timeout),
+                    Style::default().fg(Color::Gray),
+                ));
+            }
+            ListItem::new(Line::from(spans)).style(Style::default().fg(Color::White))
         })
         .collect();
 
@@ -101,19 +123,327 @@ pub fn render_action_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles
         1,
     );
 
+    // A container's Stop and Restart take another timeout with T
+    let footer_text = match state.highlighted_action() {
+        Some(ContainerAction::Stop | ContainerAction::Restart) => msg.action_footer_timeout,
+        _ => msg.action_footer,
+    };
     let footer_style = Style::default().fg(Color::Gray);
-    let footer = ratatui::widgets::Paragraph::new("↑/↓: Navigate  Enter: Execute  Esc/←: Cancel")
+    let footer = Paragraph::new(footer_text)
         .style(footer_style)
         .alignment(Alignment::Center);
 
     f.render_widget(footer, footer_area);
 }
 
-/// Truncates a string to the specified length, adding ellipsis if needed
-fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}…", &s[..max_len.saturating_sub(1)])
+/// Renders a small centered prompt for the stop timeout of a single Stop or Restart
+pub fn render_stop_timeout_prompt(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let ViewState::StopTimeoutPrompt(container_key, action) = &state.view_state else {
+        return;
+    };
+    if !state.containers.contains_key(container_key) {
+        return;
     }
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    let popup_width = 44u16.min(area.width.saturating_sub(4));
+    let popup_height = 5u16.min(area.height.saturating_sub(2));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let title = format!(
+        " {}: {} ",
+        msg.action(*action),
+        truncate_with_ellipsis(&state.container_label(container_key), 30)
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(block, popup_area);
+
+    let label = format!("{}: ", msg.stop_timeout_label);
+    let input_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled(label.clone(), styles.header),
+        Span::raw(state.stop_timeout_input.value()),
+    ]));
+    f.render_widget(input, input_area);
+    f.set_cursor_position((
+        input_area.x + (display_width(&label) + state.stop_timeout_input.visual_cursor()) as u16,
+        input_area.y,
+    ));
+
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.stop_timeout_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+/// Renders the centered prune menu for a host, opened from the action menu
+pub fn render_prune_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let ViewState::PruneMenu(host_id) = &state.view_state else {
+        return;
+    };
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    // Same layout as the action menu: borders + rows + blank line + footer + padding
+    let popup_height = (PruneTarget::ALL.len() as u16 + 6).min(area.height.saturating_sub(4));
+    let popup_width = 50u16.min(area.width.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    // " Prune (host) "
+    let title = format!(
+        "{}({}) ",
+        msg.prune_title,
+        truncate_with_ellipsis(host_id, 20)
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+
+    f.render_widget(block, popup_area);
+
+    let list_items: Vec<ListItem> = PruneTarget::ALL
+        .iter()
+        .map(|target| {
+            ListItem::new(format!(" {}", msg.prune_target(*target)))
+                .style(Style::default().fg(Color::White))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, inner_area, &mut state.prune_menu_state);
+
+    // The footer turns into the confirmation after the first Enter
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = if state.prune_confirm.is_some() {
+        Paragraph::new(msg.prune_menu_confirm).style(styles.medium.add_modifier(Modifier::BOLD))
+    } else {
+        Paragraph::new(msg.action_footer).style(Style::default().fg(Color::Gray))
+    };
+    f.render_widget(footer.alignment(Alignment::Center), footer_area);
+}
+
+/// Renders a centered popup with how long to put a host in maintenance for, and ending
+/// it while the host is in maintenance
+pub fn render_maintenance_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let ViewState::MaintenanceMenu(host_id) = &state.view_state else {
+        return;
+    };
+    let choices = state.maintenance_choices(host_id);
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    // Same layout as the action menu: borders + rows + blank line + footer + padding
+    let popup_height = (choices.len() as u16 + 6).min(area.height.saturating_sub(4));
+    let popup_width = 50u16.min(area.width.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    // " Maintenance (host) "
+    let title = format!(
+        "{}({}) ",
+        msg.maintenance_title,
+        truncate_with_ellipsis(host_id, 20)
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+
+    f.render_widget(block, popup_area);
+
+    // " 30m", or " End maintenance (12m)" with the time left
+    let list_items: Vec<ListItem> = choices
+        .iter()
+        .map(|choice| {
+            let text = match choice {
+                Some(minutes) if minutes % 60 == 0 => format!(" {}h", minutes / 60),
+                Some(minutes) => format!(" {}m", minutes),
+                None => format!(
+                    " {} ({})",
+                    msg.maintenance_end,
+                    format_time_left(state.maintenance_left(host_id).unwrap_or_default())
+                ),
+            };
+            ListItem::new(text).style(Style::default().fg(Color::White))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, inner_area, &mut state.maintenance_menu_state);
+
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.action_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+/// Renders a centered popup with what of the container can be copied, with a preview
+pub fn render_copy_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let ViewState::CopyMenu(container_key) = &state.view_state else {
+        return;
+    };
+    let Some(container) = state.containers.get(container_key) else {
+        return;
+    };
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    // Same layout as the action menu: borders + rows + blank line + footer + padding
+    let popup_height = (CopyTarget::ALL.len() as u16 + 6).min(area.height.saturating_sub(4));
+    let popup_width = 60u16.min(area.width.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    // " Copy (name) "
+    let title = format!(
+        "{}({}) ",
+        msg.copy_title,
+        truncate_with_ellipsis(&container.name, 30)
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+
+    f.render_widget(block, popup_area);
+
+    // "> Full ID  3f2a1b9c…", the value cut to the popup
+    let preview_width = (inner_area.width as usize).saturating_sub(14);
+    let list_items: Vec<ListItem> = CopyTarget::ALL
+        .iter()
+        .map(|target| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {:<10}", msg.copy_target(*target)),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    truncate_with_ellipsis(&target.text(container), preview_width).into_owned(),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, inner_area, &mut state.copy_menu_state);
+
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.copy_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
 }
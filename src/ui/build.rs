@@ -0,0 +1,164 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{BuildField, BuildState, BuildStatus};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Width of the field labels in the build dialog
+const LABEL_WIDTH: usize = 6;
+
+/// Renders the build dialog while editing, the build output afterwards
+pub fn render_build(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let Some(build) = &mut state.build else {
+        return;
+    };
+
+    if build.status == BuildStatus::Editing {
+        render_build_dialog(f, build, styles);
+    } else {
+        render_build_output(f, area, build, styles);
+    }
+}
+
+/// Renders the centered dialog with the path and tag inputs
+fn render_build_dialog(f: &mut Frame, build: &BuildState, styles: &UiStyles) {
+    let msg = i18n::messages();
+    let area = f.area();
+
+    let popup_width = 70u16.min(area.width.saturating_sub(4));
+    let popup_height = 8u16.min(area.height.saturating_sub(2));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!("{}({}) ", msg.build_title, build.host_id))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(block, popup_area);
+
+    let inner_x = popup_area.x + 2;
+    let inner_width = popup_area.width.saturating_sub(4);
+
+    let fields = [
+        (BuildField::Context, msg.build_path, &build.context_input),
+        (BuildField::Tag, msg.build_tag, &build.tag_input),
+    ];
+    for (row, (field, label, input)) in fields.into_iter().enumerate() {
+        let y = popup_area.y + 1 + row as u16;
+        let focused = build.focus == field;
+        let label_style = if focused {
+            styles.header
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+
+        let line = Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", label, width = LABEL_WIDTH),
+                label_style,
+            ),
+            Span::raw(" "),
+            Span::styled(input.value().to_string(), Style::default().fg(Color::White)),
+        ]);
+        f.render_widget(Paragraph::new(line), Rect::new(inner_x, y, inner_width, 1));
+
+        if focused {
+            let cursor_x = inner_x + LABEL_WIDTH as u16 + 1 + input.visual_cursor() as u16;
+            f.set_cursor_position((cursor_x.min(inner_x + inner_width), y));
+        }
+    }
+
+    // Validation error, if any
+    if let Some(error) = &build.error {
+        let error_area = Rect::new(inner_x, popup_area.y + 4, inner_width, 1);
+        f.render_widget(
+            Paragraph::new(error.as_str()).style(styles.high),
+            error_area,
+        );
+    }
+
+    // Footer with keybindings
+    let footer_area = Rect::new(
+        inner_x,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        inner_width,
+        1,
+    );
+    let footer = Paragraph::new(msg.build_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+/// Renders the streamed build output with errors highlighted
+fn render_build_output(f: &mut Frame, area: Rect, build: &mut BuildState, styles: &UiStyles) {
+    let msg = i18n::messages();
+
+    // Visible height without the top and bottom border
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let num_lines = build.output.len();
+    let max_scroll = num_lines.saturating_sub(visible_height);
+
+    // Follow new output until the user scrolls up; scrolling back down resumes it
+    let scroll = if build.follow {
+        max_scroll
+    } else {
+        build.scroll_offset.min(max_scroll)
+    };
+    build.scroll_offset = scroll;
+    build.follow = scroll >= max_scroll;
+
+    let lines: Vec<Line> = build
+        .output
+        .iter()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|line| {
+            if line.is_error {
+                Line::styled(line.text.as_str(), styles.high)
+            } else {
+                Line::raw(line.text.as_str())
+            }
+        })
+        .collect();
+
+    let (status, status_style) = match build.status {
+        BuildStatus::Succeeded => (msg.build_succeeded, styles.low),
+        BuildStatus::Failed => (msg.build_failed, styles.high),
+        _ => (msg.build_running, styles.medium),
+    };
+
+    let title = Line::from(vec![
+        Span::raw(format!(
+            "{}: {} ({}) - {} ",
+            msg.build_output,
+            build.context_input.value(),
+            build.host_id,
+            msg.logs_return_hint
+        )),
+        Span::styled(status, status_style),
+    ]);
+
+    let output = Paragraph::new(lines).block(Block::default().title(title).style(styles.border));
+    f.render_widget(output, area);
+
+    let mut scrollbar_state = ScrollbarState::default()
+        .content_length(num_lines)
+        .viewport_content_length(visible_height)
+        .position(scroll);
+    let scrollbar = Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight);
+    f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+}
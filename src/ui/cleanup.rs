@@ -0,0 +1,199 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{CleanupCandidate, CleanupKind, CleanupState, cleanup_reclaimable};
+use crate::ui::formatters::{format_bytes, format_time_elapsed, truncate_with_ellipsis};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Maximum number of candidate names listed in the remove confirmation
+const MAX_CONFIRM_NAMES: usize = 8;
+
+/// Renders the cleanup candidates of all hosts with the space removing them would free
+pub fn render_cleanup(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let Some(cleanup) = &mut state.cleanup else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    let loading = if cleanup.loading.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", msg.loading)
+    };
+    let block = Block::default()
+        .title(format!(
+            "{} ({}, ~{} {}){} - {}",
+            msg.cleanup,
+            cleanup.candidates.len(),
+            format_bytes(cleanup_reclaimable(&cleanup.candidates) as u64),
+            msg.reclaimable,
+            loading,
+            msg.cleanup_footer
+        ))
+        .style(styles.border);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Errors above the table
+    let [errors_area, table_area] = Layout::vertical([
+        Constraint::Length(cleanup.errors.len() as u16),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+    let errors: Vec<Line> = cleanup
+        .errors
+        .iter()
+        .map(|error| Line::styled(error.as_str(), styles.high))
+        .collect();
+    f.render_widget(Paragraph::new(errors), errors_area);
+
+    if cleanup.candidates.is_empty() && cleanup.loading.is_empty() {
+        f.render_widget(Paragraph::new(msg.nothing_to_clean), table_area);
+        return;
+    }
+
+    let show_host_column = state.connected_hosts.len() > 1;
+
+    let mut header = Vec::new();
+    let mut widths = Vec::new();
+    if show_host_column {
+        header.push(Cell::from(msg.header_host));
+        widths.push(Constraint::Length(15));
+    }
+    header.extend([
+        Cell::from(msg.header_kind),
+        Cell::from(msg.header_name),
+        Cell::from(msg.header_since),
+        Cell::from(Line::from(msg.header_size).right_aligned()),
+    ]);
+    widths.extend([
+        Constraint::Length(20),
+        Constraint::Min(20),
+        Constraint::Length(16),
+        Constraint::Length(9),
+    ]);
+
+    let rows = cleanup
+        .candidates
+        .iter()
+        .map(|candidate| candidate_row(candidate, show_host_column, styles));
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).style(styles.header))
+        .row_highlight_style(styles.selected);
+    f.render_stateful_widget(table, table_area, &mut cleanup.table_state);
+
+    if cleanup.confirm.is_some() {
+        render_cleanup_confirmation(f, cleanup, styles);
+    }
+}
+
+/// Label of a kind of candidate
+fn kind_label(kind: CleanupKind) -> &'static str {
+    let msg = i18n::messages();
+    match kind {
+        CleanupKind::ExitedContainer => msg.cleanup_exited_container,
+        CleanupKind::DanglingImage => msg.cleanup_dangling_image,
+        CleanupKind::UnusedVolume => msg.cleanup_unused_volume,
+    }
+}
+
+/// Builds the row for a single candidate
+fn candidate_row(
+    candidate: &CleanupCandidate,
+    show_host_column: bool,
+    styles: &UiStyles,
+) -> Row<'static> {
+    let since = candidate
+        .since
+        .as_ref()
+        .map_or_else(|| "-".to_string(), |since| format_time_elapsed(Some(since)));
+    let size = candidate
+        .size
+        .map_or_else(|| "-".to_string(), |size| format_bytes(size as u64));
+
+    let mut cells = Vec::new();
+    if show_host_column {
+        cells.push(Cell::from(candidate.host_id.clone()));
+    }
+    cells.extend([
+        Cell::from(kind_label(candidate.kind)).style(styles.medium),
+        Cell::from(candidate.name.clone()),
+        Cell::from(since),
+        Cell::from(Line::from(size).right_aligned()),
+    ]);
+    Row::new(cells)
+}
+
+/// Renders the centered confirmation listing the candidates about to be removed
+fn render_cleanup_confirmation(f: &mut Frame, cleanup: &CleanupState, styles: &UiStyles) {
+    let msg = i18n::messages();
+    let area = f.area();
+
+    let candidates = cleanup.removal_candidates();
+    let mut lines: Vec<Line> = candidates
+        .iter()
+        .take(MAX_CONFIRM_NAMES)
+        .map(|candidate| {
+            Line::raw(format!(
+                " {} ({})",
+                truncate_with_ellipsis(&candidate.name, 30),
+                kind_label(candidate.kind)
+            ))
+        })
+        .collect();
+    if candidates.len() > MAX_CONFIRM_NAMES {
+        lines.push(Line::raw(" …"));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::styled(
+        format!(
+            " {}, ~{} {}",
+            candidates.len(),
+            format_bytes(cleanup_reclaimable(candidates.iter().copied()) as u64),
+            msg.reclaimable
+        ),
+        styles.medium,
+    ));
+
+    // Borders + lines + blank line + footer
+    let popup_height = (lines.len() as u16 + 4).min(area.height.saturating_sub(2));
+    let popup_width = 56u16.min(area.width.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(msg.cleanup_confirm_title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+    f.render_widget(Paragraph::new(lines), inner);
+
+    // Render footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.remove_orphans_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
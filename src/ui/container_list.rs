@@ -1,18 +1,32 @@
-use std::collections::VecDeque;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use chrono::{DateTime, Utc};
+
+use crate::core::anomalies::{Anomalies, AnomalyMetric};
 use crate::core::app_state::AppState;
+use crate::core::scripting::ScriptHooks;
+use crate::core::search_query::{LabelQuery, SearchQuery};
 use crate::core::types::{
-    Container, ContainerState, HealthStatus, SortField, SortState, BUCKET_DURATION_SECS,
+    COUNT_BUCKET_SECS, COUNT_HISTORY_SIZE, Container, ContainerKey, ContainerState, CountHistory,
+    HealthStatus, HostId, HostInfo, ListRow, MemoryDisplay, ProjectKey, ProjectKind, SortField,
+    SortState, current_history_bucket,
+};
+use crate::ui::formatters::{
+    display_width, format_bytes, format_bytes_per_sec, format_status, format_time_elapsed,
+    format_time_left, format_timestamp, truncate_with_ellipsis,
 };
-use crate::ui::formatters::{format_bytes, format_bytes_per_sec, format_time_elapsed};
+use crate::ui::host_summary::render_host_summary;
+use crate::ui::i18n;
 use crate::ui::render::UiStyles;
+use crate::ui::theme::{HIGH_MARKER, MEDIUM_MARKER};
 use ratatui::{
     Frame,
-    layout::Constraint,
-    style::{Color, Style},
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Row, Table},
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
 };
 
 /// Braille characters for sparkline vertical bars (0-4 rows filled)
@@ -40,6 +54,27 @@ const TICK_INTERVAL: usize = 5;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// (min, max) width of the Name column; it still expands into leftover space
+const NAME_WIDTH_RANGE: (u16, u16) = (8, 60);
+
+/// (min, max) width of the Host column
+const HOST_WIDTH_RANGE: (u16, u16) = (4, 30);
+
+/// (min, max) width of the Status column ("Exited (137) 5 minutes ago")
+const STATUS_WIDTH_RANGE: (u16, u16) = (6, 30);
+
+/// (min, max) width of the Platform column ("linux/arm/v7")
+const PLATFORM_WIDTH_RANGE: (u16, u16) = (8, 20);
+
+/// Terminal width from which the block I/O columns are shown
+const BLOCK_IO_MIN_WIDTH: u16 = 160;
+
+/// (min, max) width of the Created column
+const CREATED_WIDTH_RANGE: (u16, u16) = (15, 30);
+
+/// Table column spacing (ratatui's default)
+const COLUMN_SPACING: u16 = 1;
+
 /// Renders the container list view
 pub fn render_container_list(
     f: &mut Frame,
@@ -51,63 +86,338 @@ pub fn render_container_list(
     let width = area.width;
 
     // Determine if we should show progress bars based on terminal width
-    let show_progress_bars = width >= 128;
+    // (never over a slow link, where they change every frame)
+    let show_progress_bars = width >= 128 && !app_state.low_bandwidth;
 
     // Get global tick counter from wall clock time
-    // Using BUCKET_DURATION_SECS ensures ticks align with history sample rate
+    // Using the history bucket ensures ticks align with history sample rate
     // This keeps tick markers and data synchronized across all containers
-    let global_tick = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs() / BUCKET_DURATION_SECS)
-        .unwrap_or(0);
+    let global_tick = current_history_bucket();
 
     app_state.sort_containers();
 
-    // Use pre-sorted list instead of sorting every frame
-    let rows: Vec<Row> = app_state
-        .sorted_container_keys
-        .iter()
-        .filter_map(|key| app_state.containers.get(key))
-        .map(|c| create_container_row(c, styles, show_host_column, show_progress_bars, global_tick))
+    let visible_columns = VisibleColumns::new(app_state, width, show_host_column);
+    let now = Utc::now();
+    let timestamp_format = app_state
+        .absolute_timestamps
+        .then_some(app_state.timestamp_format.as_str());
+    let script_hooks = app_state.script_hooks.as_ref();
+    let restart_loops = app_state.restart_loop_keys();
+    let hosts_in_maintenance: HashSet<HostId> = app_state
+        .maintenance
+        .keys()
+        .filter(|host_id| app_state.in_maintenance(host_id))
+        .cloned()
         .collect();
 
-    let header = create_header_row(styles, show_host_column, app_state.sort_state);
-    let table = create_table(
-        rows,
-        header,
-        app_state.sorted_container_keys.len(),
+    // Size text columns to the longest visible value so long names aren't cut off needlessly
+    let visible_containers = || {
+        app_state
+            .sorted_container_keys
+            .iter()
+            .filter_map(|key| app_state.containers.get(key))
+    };
+    // Project headers share the Name column with the containers
+    let project_labels: Vec<String> = if app_state.group_by_project {
+        app_state
+            .list_rows
+            .iter()
+            .filter_map(|row| match row {
+                ListRow::Project(project) => Some(project_label(project, app_state)),
+                ListRow::Container(_) => None,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let text_widths = TextColumnWidths {
+        name: content_width(
+            visible_containers()
+                .map(|c| c.name.as_str())
+                .chain(project_labels.iter().map(String::as_str)),
+            NAME_WIDTH_RANGE,
+        ),
+        host: content_width(
+            visible_containers().map(|c| c.host_id.as_str()),
+            HOST_WIDTH_RANGE,
+        ),
+        status: if visible_columns.status {
+            let statuses: Vec<String> = visible_containers()
+                .map(|c| format_status(c, now))
+                .collect();
+            content_width(statuses.iter().map(String::as_str), STATUS_WIDTH_RANGE)
+        } else {
+            0
+        },
+        platform: content_width(
+            visible_containers().filter_map(|c| c.platform.as_ref().map(|p| p.name.as_str())),
+            PLATFORM_WIDTH_RANGE,
+        ),
+        created: match timestamp_format {
+            Some(format) => {
+                let dates: Vec<String> = visible_containers()
+                    .filter_map(|c| c.created.as_ref())
+                    .map(|created| format_timestamp(created, format))
+                    .collect();
+                content_width(dates.iter().map(String::as_str), CREATED_WIDTH_RANGE)
+            }
+            None => CREATED_WIDTH_RANGE.0,
+        },
+    };
+
+    let constraints = column_constraints(
+        visible_columns,
+        show_progress_bars,
+        app_state.cpu_normalized,
+        app_state.memory_display,
+        script_hooks,
+        text_widths,
+        width,
+    );
+
+    // Resolve the layout the same way the table will, so cells know their real width
+    // and can end with an ellipsis instead of being cut off silently
+    let columns = Layout::horizontal(constraints.clone())
+        .flex(Flex::Start)
+        .spacing(COLUMN_SPACING)
+        .split(Rect::new(0, 0, width, 1));
+    let name_index = visible_columns.name_index();
+    let host_offset = usize::from(visible_columns.host);
+    let rendered_widths = TextColumnWidths {
+        name: columns[name_index].width,
+        host: if visible_columns.host {
+            columns[name_index + 1].width
+        } else {
+            0
+        },
+        status: if visible_columns.status {
+            columns[name_index + host_offset + 1].width
+        } else {
+            0
+        },
+        platform: text_widths.platform,
+        created: text_widths.created,
+    };
+    // Label queries match nothing shown in the rows
+    let search_query = SearchQuery::parse(app_state.search_input.value());
+    let search = (!search_query.is_empty() && LabelQuery::parse(&search_query).is_none())
+        .then_some(&search_query);
+    let row_context = RowContext {
         styles,
-        show_host_column,
+        visible_columns,
         show_progress_bars,
+        global_tick,
+        script_hooks,
+        widths: rendered_widths,
+        now,
+        timestamp_format,
+        restart_loops: &restart_loops,
+        anomalies: app_state.anomalies.as_ref(),
+        hosts_in_maintenance: &hosts_in_maintenance,
+        host_info: app_state.cpu_normalized.then_some(&app_state.host_info),
+        memory_display: app_state.memory_display,
+        search,
+    };
+
+    // Zebra striping shades every other row
+    let zebra_style = |i: usize| match styles.zebra {
+        Some(zebra) if i % 2 == 1 => zebra,
+        _ => Style::default(),
+    };
+
+    // Use pre-sorted list instead of sorting every frame
+    let rows: Vec<Row> = if app_state.group_by_project {
+        // Headers are owned rows, built up front so the container rows can borrow alongside them
+        let mut headers: HashMap<&ProjectKey, Row> = app_state
+            .list_rows
+            .iter()
+            .filter_map(|row| match row {
+                ListRow::Project(project) => Some(project),
+                ListRow::Container(_) => None,
+            })
+            .map(|project| {
+                (
+                    project,
+                    create_project_row(project, app_state, &row_context),
+                )
+            })
+            .collect();
+        app_state
+            .list_rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| match row {
+                ListRow::Project(project) => headers.remove(project).unwrap_or_default(),
+                ListRow::Container(key) => app_state
+                    .containers
+                    .get(key)
+                    .map(|c| create_container_row(c, &row_context, zebra_style(i)))
+                    .unwrap_or_default(),
+            })
+            .collect()
+    } else {
+        visible_containers()
+            .enumerate()
+            .map(|(i, c)| create_container_row(c, &row_context, zebra_style(i)))
+            .collect()
+    };
+
+    // Rows below the title line, the blank line (or host summary) and the column headers
+    let visible_rows = area.height.saturating_sub(3) as usize;
+    app_state.list_viewport_height = visible_rows;
+    let position = list_position(&mut app_state.table_state, rows.len(), visible_rows);
+
+    let header = create_header_row(
+        styles,
+        visible_columns,
+        app_state.sort_state,
+        script_hooks,
+        app_state.cpu_normalized,
     );
+    let titles = table_titles(
+        title_counts(app_state, styles),
+        position.map(|position| Span::styled(position, styles.title_count)),
+        if app_state.low_bandwidth {
+            Vec::new()
+        } else {
+            count_trends(&app_state.container_counts, styles)
+        },
+        styles,
+        width,
+    );
+    let table = create_table(rows, header, titles, styles, constraints);
 
     f.render_stateful_widget(table, area, &mut app_state.table_state);
+
+    // In the blank line between the title and the column headers
+    if app_state.show_host_summary && area.height > 2 {
+        let summary_area = Rect::new(area.x, area.y + 1, area.width, 1);
+        render_host_summary(f, summary_area, app_state, styles);
+    }
+}
+
+/// Scrolls the table like it will be drawn (keeping the selected row in view) and returns
+/// the position in the list when it doesn't fit: "rows 21–60 of 432"
+fn list_position(
+    table_state: &mut TableState,
+    row_count: usize,
+    visible_rows: usize,
+) -> Option<String> {
+    if row_count <= visible_rows || visible_rows == 0 {
+        return None;
+    }
+
+    let mut offset = table_state.offset().min(row_count - 1);
+    if let Some(selected) = table_state.selected().map(|s| s.min(row_count - 1)) {
+        if selected >= offset + visible_rows {
+            offset = selected + 1 - visible_rows;
+        } else if selected < offset {
+            offset = selected;
+        }
+    }
+    *table_state.offset_mut() = offset;
+
+    let msg = i18n::messages();
+    Some(format!(
+        "{} {}–{} {} {}",
+        msg.list_rows,
+        offset + 1,
+        (offset + visible_rows).min(row_count),
+        msg.list_rows_of,
+        row_count
+    ))
+}
+
+/// Per-frame settings shared by every row of the table
+struct RowContext<'a> {
+    styles: &'a UiStyles,
+    visible_columns: VisibleColumns,
+    show_progress_bars: bool,
+    global_tick: u64,
+    script_hooks: Option<&'a ScriptHooks>,
+    widths: TextColumnWidths,
+    now: DateTime<Utc>,
+    /// Format for absolute Created timestamps (None for relative "2 hours ago")
+    timestamp_format: Option<&'a str>,
+    /// Containers flagged as restart looping
+    restart_loops: &'a HashSet<ContainerKey>,
+    /// Baselines for highlighting unusual stats, when configured
+    anomalies: Option<&'a Anomalies>,
+    /// Hosts in maintenance, whose containers are greyed out
+    hosts_in_maintenance: &'a HashSet<HostId>,
+    /// CPU counts of the hosts, when CPU usage is shown as a share of the whole host
+    host_info: Option<&'a HashMap<HostId, HostInfo>>,
+    /// What the memory column shows without sparklines
+    memory_display: MemoryDisplay,
+    /// The container filter, whose matches are highlighted in names, IDs and hosts
+    search: Option<&'a SearchQuery>,
 }
 
 /// Creates a table row for a single container
 fn create_container_row<'a>(
     container: &'a Container,
-    styles: &UiStyles,
-    show_host_column: bool,
-    show_progress_bars: bool,
-    global_tick: u64,
+    ctx: &RowContext,
+    base_style: Style,
 ) -> Row<'a> {
+    let RowContext {
+        styles,
+        visible_columns,
+        show_progress_bars,
+        global_tick,
+        script_hooks,
+        widths,
+        now,
+        timestamp_format,
+        restart_loops,
+        anomalies,
+        hosts_in_maintenance,
+        host_info,
+        memory_display,
+        search,
+    } = *ctx;
+
     // Check if container is running
     let is_running = container.state == ContainerState::Running;
 
+    // Stats far from the container's baseline stand out on top of their usual style
+    let key = container.key();
+    let with_anomaly = |style: Style, metric| {
+        if is_running && anomalies.is_some_and(|a| a.is_anomalous(&key, metric)) {
+            style.patch(styles.anomaly)
+        } else {
+            style
+        }
+    };
+
     // Only show stats for running containers
+    let deploy_positions = if is_running && show_progress_bars {
+        container.stats.deploy_positions()
+    } else {
+        Vec::new()
+    };
     let (cpu_bar, cpu_style) = if is_running {
+        let cpu_divisor = host_info.map_or(1.0, |host_info| {
+            let host_cpus = host_info
+                .get(&container.host_id)
+                .map_or(0, |info| info.cpus);
+            container.stats.host_cpu_divisor(host_cpus)
+        });
+        let cpu = container.stats.cpu / cpu_divisor;
         let display = if show_progress_bars {
-            create_cpu_sparkline(
-                &container.stats.cpu_history,
-                container.stats.cpu,
-                20,
-                global_tick,
-            )
+            let cpu_history: VecDeque<f64> = container
+                .stats
+                .cpu_history
+                .iter()
+                .map(|value| value / cpu_divisor)
+                .collect();
+            create_cpu_sparkline(&cpu_history, cpu, 20, global_tick, &deploy_positions)
         } else {
-            format!("{:5.1}%", container.stats.cpu)
+            format!("{:5.1}%", cpu)
         };
-        (display, get_percentage_style(container.stats.cpu, styles))
+        (
+            with_threshold_marker(display, cpu, styles),
+            get_percentage_style(cpu, styles),
+        )
     } else {
         (String::new(), Style::default())
     };
@@ -120,12 +430,20 @@ fn create_container_row<'a>(
                 container.stats.memory_limit_bytes,
                 20,
                 global_tick,
+                &deploy_positions,
             )
         } else {
-            format!("{:5.1}%", container.stats.memory)
+            let stats = &container.stats;
+            match memory_display {
+                MemoryDisplay::Percent => format!("{:5.1}%", stats.memory),
+                MemoryDisplay::Used => format!("{:>7}", format_bytes(stats.memory_used_bytes)),
+                MemoryDisplay::UsedLimit => {
+                    format_memory_used(stats.memory_used_bytes, stats.memory_limit_bytes)
+                }
+            }
         };
         (
-            display,
+            with_threshold_marker(display, container.stats.memory, styles),
             get_percentage_style(container.stats.memory, styles),
         )
     } else {
@@ -144,48 +462,309 @@ fn create_container_row<'a>(
         String::new()
     };
 
+    let (block_read, block_write) = if is_running {
+        (
+            format_bytes_per_sec(container.stats.block_read_bytes_per_sec),
+            format_bytes_per_sec(container.stats.block_write_bytes_per_sec),
+        )
+    } else {
+        (String::new(), String::new())
+    };
+
     // Format time elapsed since creation - show "N/A" for non-running containers
     let time_elapsed = if is_running {
-        format_time_elapsed(container.created.as_ref())
+        match (timestamp_format, container.created.as_ref()) {
+            (Some(format), Some(created)) => format_timestamp(created, format),
+            _ => format_time_elapsed(container.created.as_ref()),
+        }
     } else {
         "N/A".to_string()
     };
 
-    // Get status icon and color (health takes priority over state)
-    let (icon, icon_style) = get_status_icon(&container.state, &container.health, styles);
+    // Get status icon and color (a restart loop takes priority over health, health over state)
+    let (icon, icon_style) = if restart_loops.contains(&key) {
+        (styles.icons.restart_loop().to_string(), styles.restart_loop)
+    } else {
+        get_status_icon(&container.state, &container.health, styles)
+    };
 
-    let mut cells = vec![
-        Cell::from(container.id.as_str()).style(styles.container_id),
+    let mut cells = Vec::new();
+    let highlighted = |shown: Cow<'a, str>, full: &str| {
+        highlight_search_matches(shown, full, search, styles.search_match)
+    };
+    if visible_columns.id {
+        cells.push(
+            Cell::from(highlighted(
+                Cow::Borrowed(container.id.as_str()),
+                &container.id,
+            ))
+            .style(styles.container_id),
+        );
+    }
+    cells.extend([
         Cell::from(icon).style(icon_style),
-        Cell::from(container.name.as_str()),
-    ];
+        Cell::from(highlighted(
+            truncate_with_ellipsis(&container.name, widths.name as usize),
+            &container.name,
+        )),
+    ]);
+
+    if visible_columns.host {
+        cells.push(Cell::from(highlighted(
+            truncate_with_ellipsis(&container.host_id, widths.host as usize),
+            &container.host_id,
+        )));
+    }
+
+    if visible_columns.status {
+        let status = format_status(container, now);
+        cells.push(Cell::from(
+            truncate_with_ellipsis(&status, widths.status as usize).into_owned(),
+        ));
+    }
+
+    if visible_columns.restarts {
+        cells.push(create_restarts_cell(container, styles));
+    }
 
-    if show_host_column {
-        cells.push(Cell::from(container.host_id.as_str()));
+    if visible_columns.size {
+        // "-" until the on-demand size fetch has returned
+        let size = container
+            .size_rw
+            .map(|bytes| format_bytes(bytes.max(0) as u64))
+            .unwrap_or_else(|| "-".to_string());
+        cells.push(Cell::from(Line::from(size).right_aligned()));
+    }
+
+    if visible_columns.platform {
+        cells.push(create_platform_cell(container, styles));
     }
 
     cells.extend(vec![
-        Cell::from(cpu_bar).style(cpu_style),
-        Cell::from(memory_bar).style(memory_style),
-        Cell::from(Line::styled(network_tx, styles.network_tx).right_aligned()),
-        Cell::from(Line::styled(network_rx, styles.network_rx).right_aligned()),
-        Cell::from(time_elapsed).style(styles.created),
+        Cell::from(cpu_bar).style(with_anomaly(cpu_style, AnomalyMetric::Cpu)),
+        Cell::from(memory_bar).style(with_anomaly(memory_style, AnomalyMetric::Memory)),
+        Cell::from(
+            Line::styled(
+                network_tx,
+                with_anomaly(styles.network_tx, AnomalyMetric::NetworkTx),
+            )
+            .right_aligned(),
+        ),
+        Cell::from(
+            Line::styled(
+                network_rx,
+                with_anomaly(styles.network_rx, AnomalyMetric::NetworkRx),
+            )
+            .right_aligned(),
+        ),
     ]);
 
-    Row::new(cells)
+    if visible_columns.block_io {
+        cells.extend([
+            Cell::from(Line::from(block_read).right_aligned()),
+            Cell::from(Line::from(block_write).right_aligned()),
+        ]);
+    }
+
+    if visible_columns.pids {
+        cells.push(create_pids_cell(container, styles));
+    }
+
+    cells.push(Cell::from(time_elapsed).style(styles.created));
+
+    // Derived columns and row style from scripting hooks
+    let mut row_style = base_style;
+    if let Some(hooks) = script_hooks {
+        for column in &hooks.columns {
+            cells.push(Cell::from(
+                Line::from(hooks.column_value(column, container)).right_aligned(),
+            ));
+        }
+        if let Some(style) = hooks.row_style(container) {
+            row_style = row_style.patch(style);
+        }
+    }
+    if hosts_in_maintenance.contains(&container.host_id) {
+        row_style = row_style.add_modifier(Modifier::DIM);
+    }
+
+    Row::new(cells).style(row_style)
 }
 
-/// Creates a text-based progress bar with percentage (legacy, kept for tests)
-#[cfg(test)]
-fn create_progress_bar(percentage: f64, width: usize) -> String {
-    // Clamp the bar visual to 100%, but display the actual percentage value
-    let bar_percentage = percentage.clamp(0.0, 100.0);
-    let filled_width = ((bar_percentage / 100.0) * width as f64).round() as usize;
-    let empty_width = width.saturating_sub(filled_width);
+/// Creates the header row of a compose project or swarm stack: "▾ shop (2/3)" with
+/// running/total containers
+fn create_project_row(
+    project: &ProjectKey,
+    app_state: &AppState,
+    ctx: &RowContext,
+) -> Row<'static> {
+    let marker = if app_state.collapsed_projects.contains(project) {
+        "▸"
+    } else {
+        "▾"
+    };
+    let label = project_label(project, app_state);
+    let budgets = app_state.project_budgets(project);
 
-    let bar = format!("{}{}", "█".repeat(filled_width), "░".repeat(empty_width));
+    let mut cells = Vec::new();
+    if ctx.visible_columns.id {
+        cells.push(Cell::from(""));
+    }
+    cells.extend([
+        Cell::from(marker),
+        Cell::from(truncate_with_ellipsis(&label, ctx.widths.name as usize).into_owned()),
+    ]);
+
+    // The first budget's usage against it in the CPU and memory columns
+    if let Some((budget, usage)) = budgets.first() {
+        let columns = ctx.visible_columns;
+        let skipped = [
+            columns.host,
+            columns.status,
+            columns.restarts,
+            columns.size,
+            columns.platform,
+        ];
+        cells.extend(
+            skipped
+                .iter()
+                .filter(|shown| **shown)
+                .map(|_| Cell::from("")),
+        );
+
+        let cell = |text: Option<String>, over: bool| {
+            let cell = Cell::from(Line::from(text.unwrap_or_default()).right_aligned());
+            if over {
+                cell.style(ctx.styles.high)
+            } else {
+                cell
+            }
+        };
+        // Narrow columns only fit the share of the budget used
+        let cpu_text = budget.cpus.map(|cpus| {
+            if ctx.show_progress_bars {
+                format!("{:.1}/{} CPU", usage.cpus, cpus)
+            } else {
+                format!("{:.0}%", usage.cpus / cpus * 100.0)
+            }
+        });
+        let memory_text = budget.memory_bytes.map(|bytes| {
+            if ctx.show_progress_bars {
+                format!(
+                    "{}/{}",
+                    format_bytes(usage.memory_bytes),
+                    format_bytes(bytes)
+                )
+            } else {
+                format!("{:.0}%", usage.memory_bytes as f64 / bytes as f64 * 100.0)
+            }
+        });
+        cells.extend([
+            cell(cpu_text, budget.cpus_over(usage)),
+            cell(memory_text, budget.memory_over(usage)),
+        ]);
+    }
+
+    Row::new(cells).style(ctx.styles.title_name)
+}
+
+/// "shop (2/3)", marked when it is a swarm stack and followed by the label of the budget
+/// shown for the project
+fn project_label(project: &ProjectKey, app_state: &AppState) -> String {
+    let keys = app_state.project_containers(project);
+    let running = keys
+        .iter()
+        .filter_map(|key| app_state.containers.get(key))
+        .filter(|c| c.state == ContainerState::Running)
+        .count();
+    let mut label = format!("{} ({}/{})", project.name, running, keys.len());
+    if project.kind == ProjectKind::Stack {
+        label.push_str(&format!(" · {}", i18n::messages().stack));
+    }
+    if let Some((budget, _)) = app_state.project_budgets(project).first() {
+        label.push_str(&format!(" · {}", budget.label()));
+    }
+    label
+}
+
+/// Docker's restart count, plus restarts seen this session highlighted as a likely crash loop
+fn create_restarts_cell(container: &Container, styles: &UiStyles) -> Cell<'static> {
+    let line = if container.session_restarts > 0 {
+        Line::from(vec![
+            Span::raw(container.restart_count.to_string()),
+            Span::styled(format!(" (+{})", container.session_restarts), styles.high),
+        ])
+    } else {
+        Line::from(container.restart_count.to_string())
+    };
+    Cell::from(line.right_aligned())
+}
+
+/// The shown text of a cell (`full`, possibly truncated with an ellipsis) with the matches
+/// of the container filter in `style`, so it's clear why the row matched
+fn highlight_search_matches<'a>(
+    shown: Cow<'a, str>,
+    full: &str,
+    search: Option<&SearchQuery>,
+    style: Style,
+) -> Line<'a> {
+    let Some(search) = search else {
+        return Line::from(shown);
+    };
+    // A match cut off by the ellipsis is highlighted up to it
+    let visible = if shown == full {
+        full.len()
+    } else {
+        shown.len() - '…'.len_utf8()
+    };
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for range in search.find_iter(full) {
+        if range.start >= visible {
+            break;
+        }
+        let end = range.end.min(visible);
+        if range.start > pos {
+            spans.push(Span::raw(shown[pos..range.start].to_string()));
+        }
+        spans.push(Span::styled(shown[range.start..end].to_string(), style));
+        pos = end;
+    }
+    if spans.is_empty() {
+        return Line::from(shown);
+    }
+    if pos < shown.len() {
+        spans.push(Span::raw(shown[pos..].to_string()));
+    }
+    Line::from(spans)
+}
+
+/// The process count, colored by how close it is to the pids limit (if there is one)
+fn create_pids_cell(container: &Container, styles: &UiStyles) -> Cell<'static> {
+    if container.state != ContainerState::Running {
+        return Cell::from("");
+    }
+    let stats = &container.stats;
+    let line = Line::from(stats.pids.to_string()).right_aligned();
+    match stats.pids_limit {
+        Some(limit) => {
+            let percentage = stats.pids as f64 / limit as f64 * 100.0;
+            Cell::from(line).style(get_percentage_style(percentage, styles))
+        }
+        None => Cell::from(line),
+    }
+}
 
-    format!("{} {:5.1}%", bar, percentage)
+/// The image platform, highlighted when the container runs emulated ("-" until inspected)
+fn create_platform_cell(container: &Container, styles: &UiStyles) -> Cell<'static> {
+    match &container.platform {
+        Some(platform) if platform.emulated => {
+            Cell::from(platform.name.clone()).style(styles.medium)
+        }
+        Some(platform) => Cell::from(platform.name.clone()),
+        None => Cell::from("-"),
+    }
 }
 
 /// Creates a text-based progress bar with memory used/limit display (legacy, kept for tests)
@@ -204,10 +783,23 @@ fn create_memory_progress_bar(percentage: f64, used: u64, limit: u64, width: usi
 /// Box drawing character for sparkline borders
 const SPARKLINE_BORDER: char = '│';
 
+/// Replaces the sample at which a container was started or recreated
+const DEPLOY_MARKER: char = '┃';
+
 /// Creates a braille-based sparkline from historical percentage values
 /// Each character represents one sample, with height indicating the percentage
 /// Tick markers march with the data based on global_tick (wall clock time)
 fn create_sparkline(history: &VecDeque<f64>, width: usize, global_tick: u64) -> String {
+    create_marked_sparkline(history, width, global_tick, &[])
+}
+
+/// Creates a sparkline with deployment markers at the given history positions
+fn create_marked_sparkline(
+    history: &VecDeque<f64>,
+    width: usize,
+    global_tick: u64,
+    deploy_positions: &[usize],
+) -> String {
     let mut sparkline = String::with_capacity(width + 2); // +2 for borders
     let history_len = history.len();
 
@@ -228,7 +820,9 @@ fn create_sparkline(history: &VecDeque<f64>, width: usize, global_tick: u64) ->
         // Calculate tick position based on global time and position in history
         // As global_tick advances, tick positions shift left (newer tick enters from right)
         let tick_position = global_tick.saturating_sub(history_len as u64) + i as u64;
-        if tick_position % TICK_INTERVAL as u64 == 0 {
+        if deploy_positions.contains(&i) {
+            sparkline.push(DEPLOY_MARKER);
+        } else if tick_position.is_multiple_of(TICK_INTERVAL as u64) {
             sparkline.push(BRAILLE_BARS_WITH_TICK[bar_index]);
         } else {
             sparkline.push(BRAILLE_BARS[bar_index]);
@@ -258,8 +852,14 @@ fn percentage_to_bar_index(percentage: f64) -> usize {
 }
 
 /// Creates a CPU sparkline with percentage suffix
-fn create_cpu_sparkline(history: &VecDeque<f64>, current: f64, width: usize, global_tick: u64) -> String {
-    let sparkline = create_sparkline(history, width, global_tick);
+fn create_cpu_sparkline(
+    history: &VecDeque<f64>,
+    current: f64,
+    width: usize,
+    global_tick: u64,
+    deploy_positions: &[usize],
+) -> String {
+    let sparkline = create_marked_sparkline(history, width, global_tick, deploy_positions);
     format!("{} {:5.1}%", sparkline, current)
 }
 
@@ -270,9 +870,19 @@ fn create_memory_sparkline(
     limit: u64,
     width: usize,
     global_tick: u64,
+    deploy_positions: &[usize],
 ) -> String {
-    let sparkline = create_sparkline(history, width, global_tick);
-    format!("{} {}/{}", sparkline, format_bytes(used), format_bytes(limit))
+    let sparkline = create_marked_sparkline(history, width, global_tick, deploy_positions);
+    format!("{} {}", sparkline, format_memory_used(used, limit))
+}
+
+/// Formats memory used against its limit ("512 M/2 G")
+fn format_memory_used(used: u64, limit: u64) -> String {
+    // Windows containers report no limit
+    if limit == 0 {
+        return format_bytes(used);
+    }
+    format!("{}/{}", format_bytes(used), format_bytes(limit))
 }
 
 /// Returns the status icon and color based on container health (if available) or state
@@ -285,9 +895,9 @@ fn get_status_icon(
     if let Some(health_status) = health {
         let icon = styles.icons.health(health_status).to_string();
         let style = match health_status {
-            HealthStatus::Healthy => Style::default().fg(Color::Green),
-            HealthStatus::Unhealthy => Style::default().fg(Color::Red),
-            HealthStatus::Starting => Style::default().fg(Color::Yellow),
+            HealthStatus::Healthy => styles.low,
+            HealthStatus::Unhealthy => styles.high,
+            HealthStatus::Starting => styles.medium,
         };
         return (icon, style);
     }
@@ -295,18 +905,40 @@ fn get_status_icon(
     // Use state-based icon if no health check is configured
     let icon = styles.icons.state(state).to_string();
     let style = match state {
-        ContainerState::Running => Style::default().fg(Color::Green),
-        ContainerState::Paused => Style::default().fg(Color::Yellow),
-        ContainerState::Restarting => Style::default().fg(Color::Yellow),
-        ContainerState::Removing => Style::default().fg(Color::Yellow),
-        ContainerState::Exited => Style::default().fg(Color::Red),
-        ContainerState::Dead => Style::default().fg(Color::Red),
-        ContainerState::Created => Style::default().fg(Color::Cyan),
-        ContainerState::Unknown => Style::default().fg(Color::Gray),
+        ContainerState::Running => styles.low,
+        ContainerState::Paused => styles.medium,
+        ContainerState::Restarting => styles.medium,
+        ContainerState::Removing => styles.medium,
+        ContainerState::Exited => styles.high,
+        ContainerState::Dead => styles.high,
+        ContainerState::Created => styles.state_created,
+        ContainerState::Unknown => styles.state_unknown,
     };
     (icon, style)
 }
 
+/// Appends the threshold marker (if any) to a stats cell
+fn with_threshold_marker(mut display: String, value: f64, styles: &UiStyles) -> String {
+    if let Some(marker) = get_threshold_marker(value, styles) {
+        display.push(marker);
+    }
+    display
+}
+
+/// Returns the shape marker for a percentage when the theme reinforces thresholds
+/// Low values get a blank so columns stay aligned
+fn get_threshold_marker(value: f64, styles: &UiStyles) -> Option<char> {
+    if !styles.theme.threshold_markers() {
+        None
+    } else if value > 80.0 {
+        Some(HIGH_MARKER)
+    } else if value > 50.0 {
+        Some(MEDIUM_MARKER)
+    } else {
+        Some(' ')
+    }
+}
+
 /// Returns the appropriate style based on percentage value
 fn get_percentage_style(value: f64, styles: &UiStyles) -> Style {
     if value > 80.0 {
@@ -321,71 +953,216 @@ fn get_percentage_style(value: f64, styles: &UiStyles) -> Style {
 /// Creates the table header row
 fn create_header_row(
     styles: &UiStyles,
-    show_host_column: bool,
+    visible_columns: VisibleColumns,
     sort_state: SortState,
+    script_hooks: Option<&ScriptHooks>,
+    cpu_normalized: bool,
 ) -> Row<'static> {
     let sort_symbol = sort_state.direction.symbol();
     let sort_field = sort_state.field;
+    let msg = i18n::messages();
 
-    let mut headers = vec![
-        "ID".to_string(),
+    let mut headers = Vec::new();
+    if visible_columns.id {
+        headers.push(msg.header_id.to_string());
+    }
+    headers.extend([
         "".to_string(), // Status icon column (no header text)
         if sort_field == SortField::Name {
-            format!("Name {}", sort_symbol)
+            format!("{} {}", msg.header_name, sort_symbol)
         } else {
-            "Name".to_string()
+            msg.header_name.to_string()
         },
-    ];
+    ]);
+
+    if visible_columns.host {
+        headers.push(msg.header_host.to_string());
+    }
 
-    if show_host_column {
-        headers.push("Host".to_string());
+    if visible_columns.status {
+        headers.push(msg.header_status.to_string());
     }
 
+    if visible_columns.restarts {
+        headers.push(if sort_field == SortField::Restarts {
+            format!("{} {}", msg.header_restarts, sort_symbol)
+        } else {
+            msg.header_restarts.to_string()
+        });
+    }
+
+    if visible_columns.size {
+        headers.push(msg.header_size.to_string());
+    }
+
+    if visible_columns.platform {
+        headers.push(msg.header_platform.to_string());
+    }
+
+    let header_cpu = if cpu_normalized {
+        msg.header_cpu_host
+    } else {
+        msg.header_cpu
+    };
     headers.extend(vec![
         if sort_field == SortField::Cpu {
-            format!("CPU % {}", sort_symbol)
+            format!("{} {}", header_cpu, sort_symbol)
         } else {
-            "CPU %".to_string()
+            header_cpu.to_string()
         },
         if sort_field == SortField::Memory {
-            format!("Memory % {}", sort_symbol)
+            format!("{} {}", msg.header_memory, sort_symbol)
         } else {
-            "Memory %".to_string()
-        },
-        "NetTx/s".to_string(),
-        "NetRx/s".to_string(),
-        if sort_field == SortField::Uptime {
-            format!("Created {}", sort_symbol)
-        } else {
-            "Created".to_string()
+            msg.header_memory.to_string()
         },
+        msg.header_net_tx.to_string(),
+        msg.header_net_rx.to_string(),
     ]);
 
+    if visible_columns.block_io {
+        headers.extend([
+            if sort_field == SortField::BlockRead {
+                format!("{} {}", msg.header_block_read, sort_symbol)
+            } else {
+                msg.header_block_read.to_string()
+            },
+            if sort_field == SortField::BlockWrite {
+                format!("{} {}", msg.header_block_write, sort_symbol)
+            } else {
+                msg.header_block_write.to_string()
+            },
+        ]);
+    }
+
+    if visible_columns.pids {
+        headers.push(if sort_field == SortField::Pids {
+            format!("{} {}", msg.header_pids, sort_symbol)
+        } else {
+            msg.header_pids.to_string()
+        });
+    }
+
+    headers.push(if sort_field == SortField::Uptime {
+        format!("{} {}", msg.header_created, sort_symbol)
+    } else {
+        msg.header_created.to_string()
+    });
+
+    // Derived column headers; the script sort indicator goes on the first one
+    if let Some(hooks) = script_hooks {
+        for (i, column) in hooks.columns.iter().enumerate() {
+            headers.push(if i == 0 && sort_field == SortField::Script {
+                format!("{} {}", column.header, sort_symbol)
+            } else {
+                column.header.clone()
+            });
+        }
+    }
+
     Row::new(headers).style(styles.header)
 }
 
-/// Creates the complete table widget
-fn create_table<'a>(
-    rows: Vec<Row<'a>>,
-    header: Row<'static>,
-    container_count: usize,
-    styles: &UiStyles,
-    show_host_column: bool,
+/// Optional columns shown in the container list
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct VisibleColumns {
+    pub(crate) id: bool,
+    pub(crate) host: bool,
+    pub(crate) status: bool,
+    pub(crate) restarts: bool,
+    pub(crate) size: bool,
+    pub(crate) platform: bool,
+    pub(crate) block_io: bool,
+    pub(crate) pids: bool,
+}
+
+impl VisibleColumns {
+    /// The columns shown for the state at a terminal width
+    pub(crate) fn new(app_state: &AppState, width: u16, show_host_column: bool) -> Self {
+        Self {
+            id: app_state.show_id_column,
+            host: show_host_column,
+            status: app_state.show_status_column,
+            // Always shown while sorting by it, so the order makes sense
+            restarts: app_state.show_restarts_column
+                || app_state.sort_state.field == SortField::Restarts,
+            size: app_state.show_size_column,
+            platform: app_state.show_platform_column,
+            // Wide terminals only, unless sorting by them
+            block_io: width >= BLOCK_IO_MIN_WIDTH
+                || matches!(
+                    app_state.sort_state.field,
+                    SortField::BlockRead | SortField::BlockWrite
+                ),
+            pids: app_state.show_pids_column || app_state.sort_state.field == SortField::Pids,
+        }
+    }
+
+    /// Position of the Name column (after the optional ID and the status icon)
+    fn name_index(self) -> usize {
+        if self.id { 2 } else { 1 }
+    }
+}
+
+/// Widths for the text columns (desired from content, or as rendered)
+#[derive(Debug, Clone, Copy)]
+struct TextColumnWidths {
+    name: u16,
+    host: u16,
+    status: u16,
+    platform: u16,
+    created: u16,
+}
+
+/// Returns the longest value's width, clamped to the given (min, max) range
+fn content_width<'a>(values: impl Iterator<Item = &'a str>, (min, max): (u16, u16)) -> u16 {
+    let longest = values.map(display_width).max().unwrap_or(0);
+    (longest.min(max as usize) as u16).max(min)
+}
+
+/// Builds the table column constraints
+fn column_constraints(
+    visible_columns: VisibleColumns,
     show_progress_bars: bool,
-) -> Table<'a> {
-    let mut constraints = vec![
-        Constraint::Length(12), // Container ID
-        Constraint::Length(1),  // Status icon
-        Constraint::Min(8),     // Name (minimum 8, flexible)
-    ];
+    cpu_normalized: bool,
+    memory_display: MemoryDisplay,
+    script_hooks: Option<&ScriptHooks>,
+    text_widths: TextColumnWidths,
+    area_width: u16,
+) -> Vec<Constraint> {
+    let mut constraints = Vec::new();
+    if visible_columns.id {
+        constraints.push(Constraint::Length(12)); // Container ID
+    }
+    constraints.extend([
+        Constraint::Length(1),               // Status icon
+        Constraint::Min(NAME_WIDTH_RANGE.0), // Name (sized below, flexible)
+    ]);
+
+    if visible_columns.host {
+        constraints.push(Constraint::Length(text_widths.host)); // Host
+    }
+
+    if visible_columns.status {
+        constraints.push(Constraint::Length(text_widths.status)); // Status text
+    }
 
-    if show_host_column {
-        constraints.push(Constraint::Length(20)); // Host
+    if visible_columns.restarts {
+        constraints.push(Constraint::Length(10)); // Restarts ("12 (+3)")
+    }
+
+    if visible_columns.size {
+        constraints.push(Constraint::Length(9)); // Writable layer size ("512 M")
+    }
+
+    if visible_columns.platform {
+        constraints.push(Constraint::Length(text_widths.platform)); // Platform ("linux/arm64")
     }
 
     // Adjust column widths based on whether progress bars are shown
     let cpu_width = if show_progress_bars {
         30 // CPU sparkline (20 chars + 2 borders + " 100.0%")
+    } else if cpu_normalized {
+        12 // Just percentage, under a longer header ("CPU % host ▼")
     } else {
         7 // Just percentage (" 100.0%")
     };
@@ -393,7 +1170,11 @@ fn create_table<'a>(
     let mem_width = if show_progress_bars {
         35 // Memory sparkline (20 chars + 2 borders + " 999M/999M" + padding)
     } else {
-        7 // Just percentage (" 100.0%")
+        match memory_display {
+            MemoryDisplay::Percent => 7,    // Just percentage (" 100.0%")
+            MemoryDisplay::Used => 7,       // Just used (" 1023 M")
+            MemoryDisplay::UsedLimit => 13, // Used and limit ("1023 M/1023 M")
+        }
     };
 
     constraints.extend(vec![
@@ -401,23 +1182,189 @@ fn create_table<'a>(
         Constraint::Length(mem_width), // Memory
         Constraint::Length(12),        // Network TX (1.23MB/s)
         Constraint::Length(12),        // Network RX (4.56MB/s)
-        Constraint::Length(15),        // Created
     ]);
 
+    if visible_columns.block_io {
+        constraints.extend([
+            Constraint::Length(12), // Block read (1.23MB/s)
+            Constraint::Length(12), // Block write (4.56MB/s)
+        ]);
+    }
+
+    if visible_columns.pids {
+        constraints.push(Constraint::Length(7)); // PIDs ("PIDs ▼")
+    }
+
+    constraints.push(Constraint::Length(text_widths.created)); // Created
+
+    if let Some(hooks) = script_hooks {
+        constraints.extend(
+            hooks
+                .columns
+                .iter()
+                .map(|column| Constraint::Length(column.width)),
+        );
+    }
+
+    // Name grows to its longest value (and absorbs leftover space), but never
+    // squeezes the fixed-width columns; on narrow terminals it falls back to the minimum
+    let fixed_width: u16 = constraints
+        .iter()
+        .map(|c| match c {
+            Constraint::Length(w) => *w,
+            _ => 0,
+        })
+        .sum::<u16>()
+        + COLUMN_SPACING * (constraints.len() as u16 - 1);
+    let name_width = text_widths
+        .name
+        .min(area_width.saturating_sub(fixed_width))
+        .max(NAME_WIDTH_RANGE.0);
+    constraints[visible_columns.name_index()] = Constraint::Min(name_width);
+    constraints
+}
+
+/// Per-host running/total container trends for the title: "local │⣶⣶⣿⣤⣿│ 5/7"
+/// The sparkline shows the running count relative to the host's peak total
+fn count_trends(
+    container_counts: &HashMap<HostId, CountHistory>,
+    styles: &UiStyles,
+) -> Vec<Vec<Span<'static>>> {
+    let bucket = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / COUNT_BUCKET_SECS)
+        .unwrap_or(0);
+
+    let mut hosts: Vec<_> = container_counts.iter().collect();
+    hosts.sort_by_key(|(host_id, _)| *host_id);
+
+    hosts
+        .into_iter()
+        .map(|(host_id, history)| {
+            let samples = history.samples(bucket);
+            let peak = samples.iter().map(|s| s.total).max().unwrap_or(0).max(1);
+            let running: VecDeque<f64> = samples
+                .iter()
+                .map(|s| s.running as f64 / peak as f64 * 100.0)
+                .collect();
+            let current = history.current();
+
+            vec![
+                Span::styled(format!("  {} ", host_id), styles.title_help),
+                Span::raw(create_sparkline(&running, COUNT_HISTORY_SIZE, bucket)),
+                Span::styled(
+                    format!(" {}/{}", current.running, current.total),
+                    styles.title_count,
+                ),
+            ]
+        })
+        .collect()
+}
+
+/// Container count for the title, followed by the unacknowledged notifications if any,
+/// do-not-disturb mode and the hosts in maintenance:
+/// "5 containers - 2 unacknowledged (!) - DND - maintenance: db1 25m", the
+/// notifications in red while one of them is an error
+fn title_counts(app_state: &AppState, styles: &UiStyles) -> Vec<Span<'static>> {
+    let msg = i18n::messages();
+    let mut spans = vec![Span::styled(
+        format!(
+            "{} {}",
+            app_state.sorted_container_keys.len(),
+            msg.containers
+        ),
+        styles.title_count,
+    )];
+
+    let unacknowledged = app_state.unacknowledged_notifications();
+    if unacknowledged > 0 {
+        let style = if app_state
+            .notifications
+            .iter()
+            .any(|n| !n.acknowledged && n.kind.is_error())
+        {
+            styles.high
+        } else {
+            styles.medium
+        };
+        spans.push(Span::styled(" - ", styles.title_help));
+        spans.push(Span::styled(
+            format!("{} {} (!)", unacknowledged, msg.unacknowledged),
+            style,
+        ));
+    }
+    if app_state.do_not_disturb {
+        spans.push(Span::styled(" - ", styles.title_help));
+        spans.push(Span::styled(msg.do_not_disturb, styles.medium));
+    }
+    let mut maintenance: Vec<(&HostId, Duration)> = app_state
+        .maintenance
+        .keys()
+        .filter_map(|host_id| Some((host_id, app_state.maintenance_left(host_id)?)))
+        .collect();
+    if !maintenance.is_empty() {
+        maintenance.sort();
+        let hosts: Vec<String> = maintenance
+            .iter()
+            .map(|(host_id, left)| format!("{} {}", host_id, format_time_left(*left)))
+            .collect();
+        spans.push(Span::styled(" - ", styles.title_help));
+        spans.push(Span::styled(
+            format!("{}: {}", msg.maintenance, hosts.join(", ")),
+            styles.medium,
+        ));
+    }
+    spans
+}
+
+/// The left and right titles of the table
+fn table_titles(
+    title_counts: Vec<Span<'static>>,
+    position: Option<Span<'static>>,
+    count_trends: Vec<Vec<Span<'static>>>,
+    styles: &UiStyles,
+    width: u16,
+) -> (Line<'static>, Line<'static>) {
+    let msg = i18n::messages();
+
     // Build styled title: "dtop" in purple, version in gray, count in yellow
-    let title_left = Line::from(vec![
+    let mut title_left = Line::from(vec![
         Span::styled("dtop", styles.title_name),
         Span::styled(format!(" v{}", VERSION), styles.title_help),
         Span::styled(" - ", styles.title_help),
-        Span::styled(format!("{} containers", container_count), styles.title_count),
     ]);
+    title_left.spans.extend(title_counts);
 
-    // Help text right-aligned in dark gray
-    let title_right = Line::from(vec![
-        Span::styled("'?' help, 'q' quit", styles.title_help),
-    ])
-    .right_aligned();
+    // Help text right-aligned in dark gray, after the position in a long list
+    let mut title_right = Line::default().right_aligned();
+    if let Some(position) = position {
+        title_right.spans.extend([position, Span::raw("  ")]);
+    }
+    title_right
+        .spans
+        .push(Span::styled(msg.title_help, styles.title_help));
+
+    // Add the per-host count trends that fit next to the help text
+    let available = (width as usize).saturating_sub(title_right.width() + 1);
+    for trend in count_trends {
+        let trend_width: usize = trend.iter().map(Span::width).sum();
+        if title_left.width() + trend_width > available {
+            break;
+        }
+        title_left.spans.extend(trend);
+    }
 
+    (title_left, title_right)
+}
+
+/// Creates the complete table widget
+fn create_table<'a>(
+    rows: Vec<Row<'a>>,
+    header: Row<'static>,
+    (title_left, title_right): (Line<'static>, Line<'static>),
+    styles: &UiStyles,
+    constraints: Vec<Constraint>,
+) -> Table<'a> {
     Table::new(rows, constraints)
         .header(header)
         .block(
@@ -434,6 +1381,16 @@ fn create_table<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_content_width_clamps() {
+        let names = ["web", "a-very-long-compose-project-name-web-1"];
+        assert_eq!(content_width(names.into_iter(), (8, 60)), 38);
+        assert_eq!(content_width(names.into_iter(), (8, 20)), 20);
+        assert_eq!(content_width(["db"].into_iter(), (8, 60)), 8);
+        assert_eq!(content_width(std::iter::empty(), (4, 30)), 4);
+    }
 
     #[test]
     fn test_create_memory_progress_bar_format() {
@@ -481,8 +1438,16 @@ mod tests {
     #[test]
     fn test_percentage_to_bar_index_clamps() {
         // Values outside 0-100 should be clamped
-        assert_eq!(percentage_to_bar_index(-10.0), 0, "negative should clamp to 0");
-        assert_eq!(percentage_to_bar_index(150.0), 4, "over 100 should clamp to full");
+        assert_eq!(
+            percentage_to_bar_index(-10.0),
+            0,
+            "negative should clamp to 0"
+        );
+        assert_eq!(
+            percentage_to_bar_index(150.0),
+            4,
+            "over 100 should clamp to full"
+        );
     }
 
     #[test]
@@ -584,7 +1549,7 @@ mod tests {
         history.push_back(50.0);
         history.push_back(75.0);
 
-        let result = create_cpu_sparkline(&history, 42.5, 5, 2);
+        let result = create_cpu_sparkline(&history, 42.5, 5, 2, &[]);
         assert!(result.contains("42.5%"));
         assert_eq!(result.chars().filter(|c| *c == '%').count(), 1);
     }
@@ -594,7 +1559,8 @@ mod tests {
         let mut history = VecDeque::new();
         history.push_back(50.0);
 
-        let result = create_memory_sparkline(&history, 512 * 1024 * 1024, 1024 * 1024 * 1024, 5, 1);
+        let result =
+            create_memory_sparkline(&history, 512 * 1024 * 1024, 1024 * 1024 * 1024, 5, 1, &[]);
         assert!(result.contains("512 M/1 G"));
     }
 
@@ -627,6 +1593,17 @@ mod tests {
         assert_eq!(chars3[5], BRAILLE_BARS[3]); // no tick at sample 9
     }
 
+    #[test]
+    fn test_sparkline_deploy_marker() {
+        let history: VecDeque<f64> = [10.0, 10.0, 90.0, 90.0].into_iter().collect();
+
+        let sparkline = create_marked_sparkline(&history, 4, 1, &[2]);
+        let chars: Vec<char> = sparkline.chars().collect();
+        assert_eq!(chars[2], BRAILLE_BARS[0]);
+        assert_eq!(chars[3], DEPLOY_MARKER);
+        assert_eq!(chars[4], BRAILLE_BARS[4]);
+    }
+
     #[test]
     fn test_percentage_style_thresholds() {
         let styles = UiStyles::default();
@@ -686,4 +1663,42 @@ mod tests {
             "100% should be red"
         );
     }
+
+    #[test]
+    fn test_highlight_search_matches() {
+        let style = Style::default().add_modifier(Modifier::UNDERLINED);
+        let query = SearchQuery::parse("web");
+        let parts = |line: Line| {
+            line.spans
+                .iter()
+                .map(|span| (span.content.to_string(), span.style == style))
+                .collect::<Vec<_>>()
+        };
+
+        let line = highlight_search_matches("api-Web-1".into(), "api-Web-1", Some(&query), style);
+        assert_eq!(
+            parts(line),
+            vec![
+                ("api-".to_string(), false),
+                ("Web".to_string(), true),
+                ("-1".to_string(), false),
+            ]
+        );
+
+        // Cut by the ellipsis: highlighted up to it, or not at all when hidden
+        let line = highlight_search_matches("api-w…".into(), "api-web-1", Some(&query), style);
+        assert_eq!(
+            parts(line),
+            vec![
+                ("api-".to_string(), false),
+                ("w".to_string(), true),
+                ("…".to_string(), false),
+            ]
+        );
+        let line = highlight_search_matches("api…".into(), "api-web-1", Some(&query), style);
+        assert_eq!(parts(line), vec![("api…".to_string(), false)]);
+
+        let line = highlight_search_matches("api-web-1".into(), "api-web-1", None, style);
+        assert_eq!(parts(line), vec![("api-web-1".to_string(), false)]);
+    }
 }
@@ -0,0 +1,266 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::forecast::{FORECAST_WARNING, memory_exhaustion};
+use crate::core::types::{Container, ViewState};
+use crate::ui::formatters::{
+    format_bytes, format_bytes_per_sec, format_duration_precise, format_status,
+    format_time_elapsed, format_time_left, format_timestamp,
+};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Width of the label column in the details popup
+const LABEL_WIDTH: usize = 10;
+
+/// Renders a centered popup with the full, untruncated values of the selected container
+pub fn render_details_popup(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    // Only shown over the container list
+    if state.view_state != ViewState::ContainerList {
+        return;
+    }
+
+    let Some(key) = state.selected_container_key() else {
+        return;
+    };
+    let Some(container) = state.containers.get(key) else {
+        return;
+    };
+
+    let msg = i18n::messages();
+    let mut lines = detail_lines(container, state, styles);
+
+    // Actions and logs only ever reach this host's container, but say so
+    let hosts = state.hosts_sharing_name(key);
+    if !hosts.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            format!("⚠ {}: {}", msg.same_name_on_hosts, hosts.join(", ")),
+            styles.medium,
+        ));
+    }
+
+    let area = f.area();
+    let popup_width = 80u16.min(area.width.saturating_sub(4));
+    // Lines + borders + footer, extra room for wrapped long values
+    let popup_height = (lines.len() as u16 + 6).min(area.height.saturating_sub(2));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(msg.details_title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        popup_area.height.saturating_sub(3),
+    );
+
+    f.render_widget(block, popup_area);
+
+    let paragraph = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, inner_area);
+
+    // Footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.details_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+/// Builds the label/value lines for a container
+fn detail_lines(container: &Container, state: &AppState, styles: &UiStyles) -> Vec<Line<'static>> {
+    let msg = i18n::messages();
+    let stats = &container.stats;
+
+    let health = container
+        .health
+        .as_ref()
+        .map(|h| format!("{:?}", h))
+        .unwrap_or_else(|| "-".to_string());
+
+    let platform = match &container.platform {
+        Some(platform) if platform.emulated => {
+            format!("{} ({})", platform.name, msg.label_emulated)
+        }
+        Some(platform) => platform.name.clone(),
+        None => "-".to_string(),
+    };
+
+    let ports = if container.ports.is_empty() {
+        "-".to_string()
+    } else {
+        container
+            .ports
+            .iter()
+            .map(|port| port.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    // Both forms, with the one selected for the table first
+    let created = match container.created {
+        Some(created) => {
+            let absolute = format_timestamp(&created, &state.timestamp_format);
+            let relative = format_time_elapsed(Some(&created));
+            if state.absolute_timestamps {
+                format!("{} ({})", absolute, relative)
+            } else {
+                format!("{} ({})", relative, absolute)
+            }
+        }
+        None => msg.unknown.to_string(),
+    };
+
+    let rows = [
+        (
+            msg.header_id,
+            if state.show_full_id {
+                container.full_id.clone()
+            } else {
+                container.id.clone()
+            },
+        ),
+        (msg.header_name, container.name.clone()),
+        (msg.label_image, container.image.clone()),
+        (msg.header_platform, platform),
+        (msg.header_host, container.host_id.clone()),
+        (msg.label_ports, ports),
+        (
+            msg.label_state,
+            format_status(container, chrono::Utc::now()),
+        ),
+        (msg.label_health, health),
+        (
+            msg.label_time_to_healthy,
+            container
+                .time_to_healthy
+                .map(format_duration_precise)
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        (
+            msg.header_cpu,
+            format!("{:.1}%", stats.cpu / state.cpu_divisor(container)),
+        ),
+        (
+            msg.header_memory,
+            // Windows containers report no limit to give a percentage of
+            if stats.memory_limit_bytes == 0 {
+                format_bytes(stats.memory_used_bytes)
+            } else {
+                format!(
+                    "{:.1}% ({} / {})",
+                    stats.memory,
+                    format_bytes(stats.memory_used_bytes),
+                    format_bytes(stats.memory_limit_bytes)
+                )
+            },
+        ),
+        (
+            msg.header_net_tx,
+            format_bytes_per_sec(stats.network_tx_bytes_per_sec),
+        ),
+        (
+            msg.header_net_rx,
+            format_bytes_per_sec(stats.network_rx_bytes_per_sec),
+        ),
+        (
+            msg.header_block_read,
+            format_bytes_per_sec(stats.block_read_bytes_per_sec),
+        ),
+        (
+            msg.header_block_write,
+            format_bytes_per_sec(stats.block_write_bytes_per_sec),
+        ),
+        (
+            msg.header_pids,
+            match stats.pids_limit {
+                Some(limit) => format!("{} / {}", stats.pids, limit),
+                None => stats.pids.to_string(),
+            },
+        ),
+        (msg.header_created, created),
+    ];
+
+    let mut lines: Vec<Line<'static>> = rows
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", label, width = LABEL_WIDTH),
+                    styles.header,
+                ),
+                Span::raw(" "),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+
+    // When memory keeps growing towards the limit
+    if let Some(time_left) = memory_exhaustion(stats) {
+        let style = if time_left < FORECAST_WARNING {
+            styles.high
+        } else {
+            styles.medium
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", msg.label_oom_in, width = LABEL_WIDTH),
+                styles.header,
+            ),
+            Span::raw(" "),
+            Span::styled(format!("~{}", format_time_left(time_left)), style),
+        ]));
+    }
+
+    // With the command that restarts it, for use on the host
+    if let Some(unit) = container.systemd_unit() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", msg.label_systemd_unit, width = LABEL_WIDTH),
+                styles.header,
+            ),
+            Span::raw(" "),
+            Span::raw(unit.to_string()),
+            Span::styled(format!("  systemctl restart {}", unit), styles.created),
+        ]));
+    }
+
+    // Labels below the rows, one per line as searched (key=value)
+    if !container.labels.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(msg.label_labels, styles.header));
+        lines.extend(
+            container
+                .labels
+                .iter()
+                .map(|(key, value)| Line::raw(format!("  {}={}", key, value))),
+        );
+    }
+
+    lines
+}
@@ -0,0 +1,188 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{DiskUsageRow, DiskUsageState};
+use crate::ui::formatters::format_bytes;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the disk usage of all hosts per kind with the reclaimable space highlighted
+pub fn render_disk_usage(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let Some(disk_usage) = &mut state.disk_usage else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    let reclaimable: i64 = disk_usage.rows.iter().map(|row| row.reclaimable).sum();
+    let loading = if disk_usage.loading.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", msg.loading)
+    };
+    let freed = if disk_usage.reclaimed > 0 {
+        format!(", {} {}", format_bytes(disk_usage.reclaimed), msg.freed)
+    } else {
+        String::new()
+    };
+    let block = Block::default()
+        .title(format!(
+            "{} (~{} {}{}){} - {}",
+            msg.disk_usage,
+            format_bytes(reclaimable as u64),
+            msg.reclaimable,
+            freed,
+            loading,
+            msg.disk_usage_footer
+        ))
+        .style(styles.border);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Errors above the table
+    let [errors_area, table_area] = Layout::vertical([
+        Constraint::Length(disk_usage.errors.len() as u16),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+    let errors: Vec<Line> = disk_usage
+        .errors
+        .iter()
+        .map(|error| Line::styled(error.as_str(), styles.high))
+        .collect();
+    f.render_widget(Paragraph::new(errors), errors_area);
+
+    let show_host_column = state.connected_hosts.len() > 1;
+
+    let mut header = Vec::new();
+    let mut widths = Vec::new();
+    if show_host_column {
+        header.push(Cell::from(msg.header_host));
+        widths.push(Constraint::Length(15));
+    }
+    header.extend([
+        Cell::from(msg.header_kind),
+        Cell::from(Line::from(msg.header_total).right_aligned()),
+        Cell::from(Line::from(msg.header_active).right_aligned()),
+        Cell::from(Line::from(msg.header_size).right_aligned()),
+        Cell::from(Line::from(msg.header_reclaimable).right_aligned()),
+    ]);
+    widths.extend([
+        Constraint::Min(14),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(9),
+        Constraint::Length(18),
+    ]);
+
+    let rows = disk_usage
+        .rows
+        .iter()
+        .map(|row| disk_usage_row(row, show_host_column, styles));
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).style(styles.header))
+        .row_highlight_style(styles.selected);
+    f.render_stateful_widget(table, table_area, &mut disk_usage.table_state);
+
+    if disk_usage.confirm_prune {
+        render_prune_confirmation(f, disk_usage, styles);
+    }
+}
+
+/// Builds the row for the disk usage of one kind on a host
+fn disk_usage_row(row: &DiskUsageRow, show_host_column: bool, styles: &UiStyles) -> Row<'static> {
+    let msg = i18n::messages();
+
+    let reclaimable_style = if row.reclaimable > 0 {
+        styles.medium
+    } else {
+        Style::default()
+    };
+
+    let mut cells = Vec::new();
+    if show_host_column {
+        cells.push(Cell::from(row.host_id.clone()));
+    }
+    cells.extend([
+        Cell::from(msg.disk_usage_kind(row.kind)),
+        Cell::from(Line::from(row.total.to_string()).right_aligned()),
+        Cell::from(Line::from(row.active.to_string()).right_aligned()),
+        Cell::from(Line::from(format_bytes(row.size.max(0) as u64)).right_aligned()),
+        Cell::from(Line::from(reclaimable_text(row)).right_aligned()).style(reclaimable_style),
+    ]);
+    Row::new(cells)
+}
+
+/// "300 M (30%)"; the share of the size is left out when it is not known
+fn reclaimable_text(row: &DiskUsageRow) -> String {
+    let reclaimable = format_bytes(row.reclaimable.max(0) as u64);
+    if row.size > 0 {
+        let percent = row.reclaimable as f64 * 100.0 / row.size as f64;
+        format!("{} ({:.0}%)", reclaimable, percent.min(100.0))
+    } else {
+        reclaimable
+    }
+}
+
+/// Renders the centered confirmation for pruning the selected kind
+fn render_prune_confirmation(f: &mut Frame, disk_usage: &DiskUsageState, styles: &UiStyles) {
+    let Some(row) = disk_usage.selected() else {
+        return;
+    };
+    let msg = i18n::messages();
+    let area = f.area();
+
+    let lines = vec![
+        Line::raw(format!(" {}", msg.prune_description(row.kind))),
+        Line::raw(format!(" {}: {}", msg.header_host, row.host_id)),
+        Line::raw(""),
+        Line::styled(
+            format!(
+                " ~{} {}",
+                format_bytes(row.reclaimable.max(0) as u64),
+                msg.reclaimable
+            ),
+            styles.medium,
+        ),
+    ];
+
+    // Borders + lines + blank line + footer
+    let popup_height = (lines.len() as u16 + 4).min(area.height.saturating_sub(2));
+    let popup_width = 50u16.min(area.width.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(msg.prune_title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+    f.render_widget(Paragraph::new(lines), inner);
+
+    // Render footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.prune_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
@@ -0,0 +1,112 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::is_secret_env;
+use crate::ui::formatters::truncate_with_ellipsis;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders a centered popup with the environment variables of a container, the
+/// values of secret-looking ones masked until revealed
+pub fn render_env_vars(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let Some(env_vars) = &state.env_vars else {
+        return;
+    };
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    let popup_width = (area.width * 4 / 5).max(40).min(area.width);
+    let popup_height = (area.height * 7 / 10).max(8).min(area.height);
+    let popup_area = Rect::new(
+        (area.width.saturating_sub(popup_width)) / 2,
+        (area.height.saturating_sub(popup_height)) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let visible = env_vars.visible();
+    let count = match &env_vars.vars {
+        Some(vars) if visible.len() < vars.len() => format!("{}/{}", visible.len(), vars.len()),
+        Some(vars) => vars.len().to_string(),
+        None => msg.loading.to_string(),
+    };
+    let title = format!(
+        " {}: {} ({}) ",
+        msg.environment,
+        truncate_with_ellipsis(&state.container_label(&env_vars.container_key), 30),
+        count
+    );
+    let footer = if env_vars.reveal_secrets {
+        msg.env_footer_hide
+    } else {
+        msg.env_footer_show
+    };
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    let [inner, footer_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)])
+        .areas(block.inner(popup_area));
+    f.render_widget(block, popup_area);
+
+    let footer = Paragraph::new(footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+
+    // The search line stays on top while typing or with a filter applied
+    let input = &env_vars.search_input;
+    let searching = env_vars.search_editing || !input.value().is_empty();
+    let mut lines = Vec::new();
+    if searching {
+        lines.push(Line::styled(
+            format!("/{}", input.value()),
+            styles.search_bar,
+        ));
+    }
+
+    if let Some(error) = &env_vars.error {
+        lines.push(Line::styled(error.as_str(), styles.high));
+    } else if env_vars.vars.is_some() && visible.is_empty() {
+        lines.push(Line::from(msg.no_matches));
+    }
+
+    let rows = (inner.height as usize).saturating_sub(lines.len());
+    lines.extend(
+        visible
+            .iter()
+            .skip(env_vars.scroll_offset)
+            .take(rows)
+            .map(|(name, value)| {
+                let value_style = if is_secret_env(name) && !env_vars.reveal_secrets {
+                    styles.container_id
+                } else {
+                    Style::default()
+                };
+                Line::from(vec![
+                    Span::styled(name.to_string(), styles.title_name),
+                    Span::raw("="),
+                    Span::styled(value.to_string(), value_style),
+                ])
+            }),
+    );
+
+    f.render_widget(Paragraph::new(lines), inner);
+
+    if env_vars.search_editing {
+        // Cursor after the '/' and the typed text
+        f.set_cursor_position((inner.x + 1 + input.visual_cursor() as u16, inner.y));
+    }
+}
@@ -0,0 +1,90 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::core::app_state::AppState;
+use crate::ui::formatters::{format_timestamp, truncate_with_ellipsis};
+use crate::ui::i18n;
+use crate::ui::notifications::notification_text;
+use crate::ui::render::UiStyles;
+
+/// Renders a centered popup with the full error of a notification, one cause per line,
+/// as the panel and the banners cut it short
+pub fn render_error_detail(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let Some(detail) = &state.error_detail else {
+        return;
+    };
+
+    let area = f.area();
+    let msg = i18n::messages();
+    let notification = &detail.notification;
+
+    let popup_width = (area.width * 4 / 5).max(40).min(area.width);
+    let popup_height = (area.height * 3 / 5).max(8).min(area.height);
+    let popup_area = Rect::new(
+        (area.width.saturating_sub(popup_width)) / 2,
+        (area.height.saturating_sub(popup_height)) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let title = format!(
+        " {}: {} ",
+        msg.error_detail,
+        truncate_with_ellipsis(&notification.source, 40)
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.high)
+        .style(Style::default().bg(Color::Black));
+    let [inner, footer_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)])
+        .areas(block.inner(popup_area));
+    f.render_widget(block, popup_area);
+
+    let footer = Paragraph::new(msg.error_detail_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+
+    // When and what failed, then the error from its outermost cause inwards
+    // ("Restart failed", nothing more for a connection error)
+    let (summary, style) = notification_text(&notification.kind, styles);
+    let error = notification.kind.error().unwrap_or_default();
+    let what = summary
+        .strip_suffix(error)
+        .map_or(summary.as_str(), |what| what.trim_end_matches(": "));
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format_timestamp(&notification.time, "%Y-%m-%d %H:%M:%S"),
+                styles.log_timestamp,
+            ),
+            Span::raw(" "),
+            Span::styled(what.to_string(), style),
+        ]),
+        Line::default(),
+    ];
+    lines.extend(
+        detail
+            .chain()
+            .into_iter()
+            .enumerate()
+            .skip(detail.scroll_offset)
+            .map(|(depth, cause)| {
+                let indent = if depth == 0 { "" } else { "└ " };
+                Line::from(format!("{}{}", "  ".repeat(depth.saturating_sub(1)), indent) + cause)
+            }),
+    );
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    f.render_widget(paragraph, inner);
+}
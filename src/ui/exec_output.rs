@@ -0,0 +1,220 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Margin, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{ExecLine, ExecStatus, ViewState};
+use crate::ui::formatters::{display_width, truncate_with_ellipsis};
+use crate::ui::i18n;
+use crate::ui::log_view::{level_style, message_spans};
+use crate::ui::render::UiStyles;
+
+/// Renders a centered prompt for a command to run in a container
+pub fn render_exec_prompt(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let ViewState::ExecPrompt(container_key) = &state.view_state else {
+        return;
+    };
+    if !state.containers.contains_key(container_key) {
+        return;
+    }
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    let popup_width = 70u16.min(area.width.saturating_sub(4));
+    let popup_height = 5u16.min(area.height.saturating_sub(2));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let title = format!(
+        " {}: {} ",
+        msg.action_exec,
+        truncate_with_ellipsis(&state.container_label(container_key), 30)
+    );
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(block, popup_area);
+
+    let label = format!("{}: ", msg.exec_command_label);
+    let input_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    // Long commands scroll to keep the cursor in view
+    let input_width = (input_area.width as usize).saturating_sub(display_width(&label));
+    let scroll = state
+        .exec_input
+        .visual_scroll(input_width.saturating_sub(1));
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled(label.clone(), styles.header),
+        Span::raw(
+            state
+                .exec_input
+                .value()
+                .chars()
+                .skip(scroll)
+                .collect::<String>(),
+        ),
+    ]));
+    f.render_widget(input, input_area);
+    f.set_cursor_position((
+        input_area.x + (display_width(&label) + state.exec_input.visual_cursor() - scroll) as u16,
+        input_area.y,
+    ));
+
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.exec_prompt_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+/// Renders the output of a typed-in command in a popup over the container list
+pub fn render_exec_output_popup(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let area = f.area();
+    let popup_width = (area.width * 4 / 5).max(40).min(area.width);
+    let popup_height = (area.height * 7 / 10).max(8).min(area.height);
+    let popup_area = Rect::new(
+        (area.width.saturating_sub(popup_width)) / 2,
+        (area.height.saturating_sub(popup_height)) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+    render_exec_output(f, popup_area, state, styles);
+}
+
+/// Renders the output of a command run in a container, stderr highlighted
+pub fn render_exec_output(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let Some(name) = state
+        .exec_output
+        .as_ref()
+        .map(|exec_output| state.container_label(&exec_output.container_key))
+    else {
+        return;
+    };
+    let Some(exec_output) = &mut state.exec_output else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    // A popup is framed on all sides, the full screen pane only has a title
+    let popup = exec_output.popup;
+    let block = if popup {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(styles.header)
+            .style(Style::default().bg(Color::Black))
+    } else {
+        Block::default().style(styles.border)
+    };
+    let text_area = if popup {
+        block.inner(area)
+    } else {
+        // Below the title
+        Rect::new(
+            area.x,
+            area.y + 1,
+            area.width,
+            area.height.saturating_sub(1),
+        )
+    };
+    let visible_height = text_area.height as usize;
+    let num_lines = exec_output.lines.len();
+    let max_scroll = num_lines.saturating_sub(visible_height);
+
+    // Follow new output until the user scrolls up; scrolling back down resumes it
+    let scroll = if exec_output.follow {
+        max_scroll
+    } else {
+        exec_output.scroll_offset.min(max_scroll)
+    };
+    exec_output.scroll_offset = scroll;
+    exec_output.follow = scroll >= max_scroll;
+
+    let mut lines: Vec<Line> = exec_output
+        .lines
+        .iter()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|line| exec_line(line, styles))
+        .collect();
+
+    let (status, status_style) = match &exec_output.status {
+        ExecStatus::Running => (msg.exec_running.to_string(), styles.medium),
+        ExecStatus::Exited(Some(0)) => (format!("[{} 0]", msg.exec_exit_code), styles.low),
+        ExecStatus::Exited(Some(code)) => {
+            (format!("[{} {}]", msg.exec_exit_code, code), styles.high)
+        }
+        ExecStatus::Exited(None) => (format!("[{} ?]", msg.exec_exit_code), styles.medium),
+        ExecStatus::Failed(error) => {
+            // Nothing ran, so the error is the only output
+            lines.push(Line::styled(error.clone(), styles.high));
+            (msg.exec_failed.to_string(), styles.high)
+        }
+    };
+
+    let title = Line::from(vec![
+        Span::raw(format!(
+            "{}: {} ({}) - {} ",
+            msg.exec_output,
+            exec_output.command.as_deref().unwrap_or("…"),
+            name,
+            msg.logs_return_hint
+        )),
+        Span::styled(status, status_style),
+    ]);
+
+    f.render_widget(block.title(title), area);
+    f.render_widget(Paragraph::new(lines), text_area);
+
+    // On the popup's right border, only once there is more than fits
+    let scrollbar_area = if popup {
+        if num_lines <= visible_height {
+            return;
+        }
+        area.inner(Margin::new(0, 1))
+    } else {
+        area
+    };
+    let mut scrollbar_state = ScrollbarState::default()
+        .content_length(num_lines)
+        .viewport_content_length(visible_height)
+        .position(scroll);
+    let scrollbar = Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight);
+    f.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+}
+
+/// A line of output, colored like a log line; uncolored stderr text is highlighted
+fn exec_line(line: &ExecLine, styles: &UiStyles) -> Line<'static> {
+    let fallback = if line.is_stderr {
+        Some(styles.high)
+    } else {
+        line.entry
+            .level
+            .and_then(|level| level_style(level, styles))
+    };
+    Line::from(message_spans(&line.entry, fallback))
+}
@@ -0,0 +1,361 @@
+//! Export of the visible container list as a Markdown or HTML table
+//!
+//! The table has the columns and rows shown on screen (filter, sort, collapsed projects),
+//! with plain values in place of sparklines and icons, for pasting into runbooks and
+//! incident docs.
+
+use chrono::{Local, Utc};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+use std::path::PathBuf;
+
+use crate::core::app_state::AppState;
+use crate::core::clipboard;
+use crate::core::types::{
+    Container, ContainerState, ExportChoice, ExportFormat, ExportOutcome, ExportTarget, ListRow,
+};
+use crate::ui::container_list::VisibleColumns;
+use crate::ui::formatters::{
+    format_bytes, format_bytes_per_sec, format_status, format_time_elapsed, format_timestamp,
+};
+use crate::ui::i18n;
+use crate::ui::render::{UiStyles, show_host_column};
+
+/// Exports the container list as chosen, at the terminal width it is shown at
+pub fn export_view(state: &AppState, choice: ExportChoice, width: u16) -> ExportOutcome {
+    let (table, count) = export_table(state, choice.format, width);
+
+    match choice.target {
+        ExportTarget::Clipboard => match clipboard::copy(&table, state.is_ssh_session) {
+            Ok(()) => ExportOutcome::Copied(choice.format, count),
+            Err(e) => ExportOutcome::Failed(e),
+        },
+        ExportTarget::File => {
+            let path = PathBuf::from(format!(
+                "dtop-{}.{}",
+                Local::now().format("%Y%m%d-%H%M%S"),
+                choice.format.extension()
+            ));
+            match std::fs::write(&path, table) {
+                Ok(()) => ExportOutcome::Saved(path, count),
+                Err(e) => ExportOutcome::Failed(format!("{}: {}", path.display(), e)),
+            }
+        }
+    }
+}
+
+/// The container list as a table in the format, with the number of containers in it
+pub fn export_table(state: &AppState, format: ExportFormat, width: u16) -> (String, usize) {
+    let (headers, rows) = table_cells(state, width);
+    let table = match format {
+        ExportFormat::Markdown => markdown_table(&headers, &rows),
+        ExportFormat::Html => html_table(&headers, &rows),
+    };
+    (table, rows.len())
+}
+
+/// Header and row texts of the visible containers, in the order shown
+fn table_cells(state: &AppState, width: u16) -> (Vec<String>, Vec<Vec<String>>) {
+    let msg = i18n::messages();
+    let columns = VisibleColumns::new(state, width, show_host_column(state));
+    let script_hooks = state.script_hooks.as_ref();
+    let restart_loops = state.restart_loop_keys();
+
+    // The state takes the place of the status icon
+    let mut headers = Vec::new();
+    if columns.id {
+        headers.push(msg.header_id.to_string());
+    }
+    headers.extend([msg.header_name.to_string(), msg.label_state.to_string()]);
+    if columns.host {
+        headers.push(msg.header_host.to_string());
+    }
+    if columns.status {
+        headers.push(msg.header_status.to_string());
+    }
+    if columns.restarts {
+        headers.push(msg.header_restarts.to_string());
+    }
+    if columns.size {
+        headers.push(msg.header_size.to_string());
+    }
+    if columns.platform {
+        headers.push(msg.header_platform.to_string());
+    }
+    headers.extend([
+        msg.header_cpu.to_string(),
+        msg.header_memory.to_string(),
+        msg.header_net_tx.to_string(),
+        msg.header_net_rx.to_string(),
+    ]);
+    if columns.block_io {
+        headers.extend([
+            msg.header_block_read.to_string(),
+            msg.header_block_write.to_string(),
+        ]);
+    }
+    if columns.pids {
+        headers.push(msg.header_pids.to_string());
+    }
+    headers.push(msg.header_created.to_string());
+    if let Some(hooks) = script_hooks {
+        headers.extend(hooks.columns.iter().map(|column| column.header.clone()));
+    }
+
+    // Containers of collapsed projects are not on screen
+    let keys: Vec<_> = if state.group_by_project {
+        state
+            .list_rows
+            .iter()
+            .filter_map(|row| match row {
+                ListRow::Container(key) => Some(key),
+                ListRow::Project(_) => None,
+            })
+            .collect()
+    } else {
+        state.sorted_container_keys.iter().collect()
+    };
+
+    let now = Utc::now();
+    let rows = keys
+        .into_iter()
+        .filter_map(|key| state.containers.get(key))
+        .map(|container| {
+            let running = container.state == ContainerState::Running;
+            // Stats are only shown for running containers
+            let stat = |value: String| if running { value } else { String::new() };
+            let stats = &container.stats;
+
+            let mut cells = Vec::new();
+            if columns.id {
+                cells.push(container.id.clone());
+            }
+            let mut state_cell = state_text(container);
+            if restart_loops.contains(&container.key()) {
+                state_cell.push_str(", ");
+                state_cell.push_str(msg.restart_loop);
+            }
+            cells.extend([container.name.clone(), state_cell]);
+            if columns.host {
+                cells.push(container.host_id.clone());
+            }
+            if columns.status {
+                cells.push(format_status(container, now));
+            }
+            if columns.restarts {
+                cells.push(if container.session_restarts > 0 {
+                    format!(
+                        "{} (+{})",
+                        container.restart_count, container.session_restarts
+                    )
+                } else {
+                    container.restart_count.to_string()
+                });
+            }
+            if columns.size {
+                cells.push(
+                    container
+                        .size_rw
+                        .map(|bytes| format_bytes(bytes.max(0) as u64))
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+            }
+            if columns.platform {
+                cells.push(
+                    container
+                        .platform
+                        .as_ref()
+                        .map_or_else(|| "-".to_string(), |platform| platform.name.clone()),
+                );
+            }
+            cells.extend([
+                stat(format!("{:.1}%", stats.cpu)),
+                stat(format!("{:.1}%", stats.memory)),
+                stat(format_bytes_per_sec(stats.network_tx_bytes_per_sec)),
+                stat(format_bytes_per_sec(stats.network_rx_bytes_per_sec)),
+            ]);
+            if columns.block_io {
+                cells.extend([
+                    stat(format_bytes_per_sec(stats.block_read_bytes_per_sec)),
+                    stat(format_bytes_per_sec(stats.block_write_bytes_per_sec)),
+                ]);
+            }
+            if columns.pids {
+                cells.push(stat(stats.pids.to_string()));
+            }
+            cells.push(
+                match (running, state.absolute_timestamps, &container.created) {
+                    (false, _, _) => "N/A".to_string(),
+                    (true, true, Some(created)) => {
+                        format_timestamp(created, &state.timestamp_format)
+                    }
+                    _ => format_time_elapsed(container.created.as_ref()),
+                },
+            );
+            if let Some(hooks) = script_hooks {
+                cells.extend(
+                    hooks
+                        .columns
+                        .iter()
+                        .map(|column| hooks.column_value(column, container)),
+                );
+            }
+            cells
+        })
+        .collect();
+
+    (headers, rows)
+}
+
+/// "running (healthy)", "exited"
+fn state_text(container: &Container) -> String {
+    let state = format!("{:?}", container.state).to_lowercase();
+    match &container.health {
+        Some(health) => format!("{} ({})", state, format!("{:?}", health).to_lowercase()),
+        None => state,
+    }
+}
+
+/// A GitHub-flavored Markdown table
+fn markdown_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .map(|cell| cell.replace('|', "\\|").replace('\n', " "))
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut table = line(headers);
+    table.push_str(&line(&vec!["---".to_string(); headers.len()]));
+    for row in rows {
+        table.push_str(&line(row));
+    }
+    table
+}
+
+/// An HTML table, without a surrounding document so it can be pasted anywhere
+fn html_table(headers: &[String], rows: &[Vec<String>]) -> String {
+    let line = |tag: &str, cells: &[String]| {
+        let cells: String = cells
+            .iter()
+            .map(|cell| format!("<{tag}>{}</{tag}>", escape_html(cell)))
+            .collect();
+        format!("    <tr>{}</tr>\n", cells)
+    };
+
+    let mut table = String::from("<table>\n  <thead>\n");
+    table.push_str(&line("th", headers));
+    table.push_str("  </thead>\n  <tbody>\n");
+    for row in rows {
+        table.push_str(&line("td", row));
+    }
+    table.push_str("  </tbody>\n</table>\n");
+    table
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders a centered popup with the export choices
+pub fn render_export_menu(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let area = f.area();
+    let msg = i18n::messages();
+
+    // Same layout as the action menu: borders + rows + blank line + footer + padding
+    let popup_height = (ExportChoice::ALL.len() as u16 + 6).min(area.height.saturating_sub(4));
+    let popup_width = 40u16.min(area.width.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" {} ", msg.export_title))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+
+    f.render_widget(block, popup_area);
+
+    let list_items: Vec<ListItem> = ExportChoice::ALL
+        .iter()
+        .map(|choice| {
+            ListItem::new(format!(" {}", msg.export_choice(*choice)))
+                .style(Style::default().fg(Color::White))
+        })
+        .collect();
+
+    let list = List::new(list_items)
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    f.render_stateful_widget(list, inner_area, &mut state.export_menu_state);
+
+    // Render footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.action_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let headers = strings(&["Name", "CPU"]);
+        let rows = vec![strings(&["web|1", "5.0%"]), strings(&["db", ""])];
+        assert_eq!(
+            markdown_table(&headers, &rows),
+            "| Name | CPU |\n| --- | --- |\n| web\\|1 | 5.0% |\n| db |  |\n"
+        );
+    }
+
+    #[test]
+    fn test_html_table() {
+        let headers = strings(&["Name"]);
+        let rows = vec![strings(&["<web> & \"db\""])];
+        assert_eq!(
+            html_table(&headers, &rows),
+            "<table>\n  <thead>\n    <tr><th>Name</th></tr>\n  </thead>\n  <tbody>\n    \
+             <tr><td>&lt;web&gt; &amp; &quot;db&quot;</td></tr>\n  </tbody>\n</table>\n"
+        );
+    }
+}
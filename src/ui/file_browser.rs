@@ -0,0 +1,117 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{FileEntry, FileKind};
+use crate::ui::formatters::format_bytes;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the directory shown in the file browser, with the listing error and the
+/// download status above it
+pub fn render_file_browser(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let name = match &state.file_browser {
+        Some(browser) => state.container_label(&browser.container_key),
+        None => return,
+    };
+    let Some(browser) = &mut state.file_browser else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    let loading = if browser.loading {
+        format!(" {}", msg.loading)
+    } else {
+        String::new()
+    };
+    let block = Block::default()
+        .title(format!(
+            "{}: {} {} ({}){} - {}",
+            msg.files,
+            name,
+            browser.path,
+            browser.entries.len(),
+            loading,
+            msg.files_footer
+        ))
+        .style(styles.border);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut status = Vec::new();
+    if let Some(error) = &browser.error {
+        status.push(Line::styled(error.as_str(), styles.high));
+    }
+    if browser.downloads > 0 {
+        status.push(Line::styled(msg.downloading, styles.medium));
+    } else {
+        match &browser.last_download {
+            Some(Ok(path)) => status.push(Line::styled(
+                format!("{} {}", msg.saved_to, path.display()),
+                styles.low,
+            )),
+            Some(Err(error)) => status.push(Line::styled(error.as_str(), styles.high)),
+            None => {}
+        }
+    }
+    if browser.entries.is_empty() && !browser.loading && browser.error.is_none() {
+        status.push(Line::from(msg.empty_directory));
+    }
+
+    let [status_area, table_area] =
+        Layout::vertical([Constraint::Length(status.len() as u16), Constraint::Min(0)])
+            .areas(inner);
+    f.render_widget(Paragraph::new(status), status_area);
+
+    let header = Row::new(vec![
+        Cell::from(msg.header_name),
+        Cell::from(Line::from(msg.header_size).right_aligned()),
+        Cell::from(msg.header_owner),
+        Cell::from(msg.header_modified),
+    ])
+    .style(styles.header);
+
+    let rows = browser.entries.iter().map(|entry| file_row(entry, styles));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(30),
+            Constraint::Length(9),
+            Constraint::Length(10),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header)
+    .row_highlight_style(styles.selected);
+    f.render_stateful_widget(table, table_area, &mut browser.table_state);
+}
+
+/// Builds the row for a single entry ("conf.d/" for directories, "current -> /srv"
+/// for symlinks)
+fn file_row(entry: &FileEntry, styles: &UiStyles) -> Row<'static> {
+    let (name, style) = match (&entry.kind, &entry.link_target) {
+        (FileKind::Directory, _) => (format!("{}/", entry.name), styles.title_name),
+        (FileKind::Symlink, Some(target)) => {
+            (format!("{} -> {}", entry.name, target), styles.container_id)
+        }
+        _ => (entry.name.clone(), Style::default()),
+    };
+    let size = match entry.kind {
+        FileKind::File => format_bytes(entry.size),
+        _ => "-".to_string(),
+    };
+
+    Row::new(vec![
+        Cell::from(name).style(style),
+        Cell::from(Line::from(size).right_aligned()),
+        Cell::from(entry.owner.clone()),
+        Cell::from(entry.modified.clone()),
+    ])
+}
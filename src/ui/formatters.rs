@@ -1,7 +1,14 @@
 //! Formatting utilities for displaying values in the UI
 
-use chrono::Utc;
+use std::borrow::Cow;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Duration, Local, Utc};
 use timeago::Formatter;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::core::types::{Container, ContainerState, HealthStatus};
+use crate::ui::i18n;
 
 const KB: f64 = 1024.0;
 const MB: f64 = KB * 1024.0;
@@ -19,11 +26,29 @@ fn format_byte_value(
     let b = if include_b { "B" } else { "" };
 
     if value >= GB {
-        format!("{:.prec$}{separator}G{}{}", value / GB, b, suffix, prec = gb_prec)
+        format!(
+            "{:.prec$}{separator}G{}{}",
+            value / GB,
+            b,
+            suffix,
+            prec = gb_prec
+        )
     } else if value >= MB {
-        format!("{:.prec$}{separator}M{}{}", value / MB, b, suffix, prec = mb_prec)
+        format!(
+            "{:.prec$}{separator}M{}{}",
+            value / MB,
+            b,
+            suffix,
+            prec = mb_prec
+        )
     } else if value >= KB {
-        format!("{:.prec$}{separator}K{}{}", value / KB, b, suffix, prec = kb_prec)
+        format!(
+            "{:.prec$}{separator}K{}{}",
+            value / KB,
+            b,
+            suffix,
+            prec = kb_prec
+        )
     } else {
         format!("{:.prec$}{separator}B{}", value, suffix, prec = b_prec)
     }
@@ -45,14 +70,160 @@ pub fn format_bytes_per_sec(bytes_per_sec: f64) -> String {
 pub fn format_time_elapsed(created: Option<&chrono::DateTime<Utc>>) -> String {
     match created {
         Some(created_time) => {
-            let formatter = Formatter::new();
+            let formatter = Formatter::with_language((i18n::messages().relative_time)());
             let now = Utc::now();
             formatter.convert_chrono(*created_time, now)
         }
-        None => "Unknown".to_string(),
+        None => i18n::messages().unknown.to_string(),
     }
 }
 
+/// Formats a timestamp in local time with a chrono format string
+pub fn format_timestamp(time: &DateTime<Utc>, format: &str) -> String {
+    time.with_timezone(&Local).format(format).to_string()
+}
+
+/// Returns true if the chrono format string has no invalid specifiers
+/// (formatting with an invalid one would panic)
+pub fn is_valid_timestamp_format(format: &str) -> bool {
+    !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}
+
+/// Formats a duration the way Docker does ("Less than a second", "About an hour", "3 days")
+fn format_human_duration(duration: Duration) -> String {
+    let msg = i18n::messages();
+    let seconds = duration.num_seconds();
+    let minutes = duration.num_minutes();
+    // Docker rounds to the nearest hour from here on
+    let hours = (seconds + 1800) / 3600;
+
+    if seconds < 1 {
+        msg.duration_under_second.to_string()
+    } else if seconds == 1 {
+        msg.duration_second.to_string()
+    } else if seconds < 60 {
+        i18n::fill(msg.duration_seconds, &[&seconds])
+    } else if minutes == 1 {
+        msg.duration_minute.to_string()
+    } else if minutes < 60 {
+        i18n::fill(msg.duration_minutes, &[&minutes])
+    } else if hours == 1 {
+        msg.duration_hour.to_string()
+    } else if hours < 48 {
+        i18n::fill(msg.duration_hours, &[&hours])
+    } else if hours < 24 * 7 * 2 {
+        i18n::fill(msg.duration_days, &[&(hours / 24)])
+    } else if hours < 24 * 30 * 2 {
+        i18n::fill(msg.duration_weeks, &[&(hours / 24 / 7)])
+    } else if hours < 24 * 365 * 2 {
+        i18n::fill(msg.duration_months, &[&(hours / 24 / 30)])
+    } else {
+        i18n::fill(msg.duration_years, &[&(duration.num_hours() / 24 / 365)])
+    }
+}
+
+/// Formats a short measured duration precisely ("850ms", "12.3s", "2m 05s")
+pub fn format_duration_precise(duration: Duration) -> String {
+    let millis = duration.num_milliseconds().max(0);
+    if millis < 1000 {
+        format!("{}ms", millis)
+    } else if millis < 60_000 {
+        format!("{:.1}s", millis as f64 / 1000.0)
+    } else {
+        let seconds = millis / 1000;
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    }
+}
+
+/// Formats a forecast time left coarsely ("<1m", "25m", "1h 20m", "2d 3h")
+pub fn format_time_left(duration: std::time::Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    if minutes < 1 {
+        "<1m".to_string()
+    } else if minutes < 60 {
+        format!("{}m", minutes)
+    } else if minutes < 24 * 60 {
+        format!("{}h {}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}d {}h", minutes / (24 * 60), minutes / 60 % 24)
+    }
+}
+
+/// Formats a container's status like `docker ps` ("Up 3 hours (healthy)", "Exited (0) 2 days ago")
+pub fn format_status(container: &Container, now: DateTime<Utc>) -> String {
+    let msg = i18n::messages();
+    let up = || match container.started_at {
+        Some(started_at) => i18n::fill(msg.status_up, &[&format_human_duration(now - started_at)]),
+        None => msg.status_up_bare.to_string(),
+    };
+    let exited = |label: &str| {
+        let mut text = label.to_string();
+        if let Some(code) = container.exit_code {
+            text.push_str(&format!(" ({})", code));
+        }
+        if let Some(finished_at) = container.finished_at {
+            text.push(' ');
+            text.push_str(&i18n::fill(
+                msg.status_ago,
+                &[&format_human_duration(now - finished_at)],
+            ));
+        }
+        text
+    };
+
+    match container.state {
+        ContainerState::Running => match container.health {
+            Some(HealthStatus::Healthy) => i18n::fill(msg.status_healthy, &[&up()]),
+            Some(HealthStatus::Unhealthy) => i18n::fill(msg.status_unhealthy, &[&up()]),
+            Some(HealthStatus::Starting) => i18n::fill(msg.status_health_starting, &[&up()]),
+            None => up(),
+        },
+        ContainerState::Paused => i18n::fill(msg.status_paused, &[&up()]),
+        ContainerState::Restarting => exited(msg.status_restarting),
+        ContainerState::Exited => exited(msg.status_exited),
+        ContainerState::Created => msg.status_created.to_string(),
+        ContainerState::Dead => msg.status_dead.to_string(),
+        ContainerState::Removing => msg.status_removing.to_string(),
+        ContainerState::Unknown => msg.unknown.to_string(),
+    }
+}
+
+/// Number of terminal columns a string takes: CJK characters and most emoji take two,
+/// combining marks none
+pub fn display_width(s: &str) -> usize {
+    s.width()
+}
+
+/// Truncates a string to `max_width` display columns (not chars), appending "…" when cut
+pub fn truncate_with_ellipsis(s: &str, max_width: usize) -> Cow<'_, str> {
+    if display_width(s) <= max_width {
+        return Cow::Borrowed(s);
+    }
+    if max_width == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // One column is kept for the ellipsis
+    let mut width = 0;
+    let mut end = 0;
+    for (idx, ch) in s.char_indices() {
+        width += ch.width().unwrap_or(0);
+        if width > max_width - 1 {
+            break;
+        }
+        end = idx + ch.len_utf8();
+    }
+    Cow::Owned(format!("{}…", &s[..end]))
+}
+
+/// Truncates `s` to `width` terminal columns and pads it with spaces to exactly that width
+/// (`format!("{:<w$}")` pads by chars, which misaligns wide characters)
+pub fn pad_to_width(s: &str, width: usize) -> String {
+    let truncated = truncate_with_ellipsis(s, width);
+    let padding = width.saturating_sub(display_width(&truncated));
+    format!("{}{}", truncated, " ".repeat(padding))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +270,125 @@ mod tests {
         assert_eq!(format_bytes_per_sec(1048576.0), "1.00· MB");
         assert_eq!(format_bytes_per_sec(1073741824.0), "1.00· GB");
     }
+
+    #[test]
+    fn test_format_time_left() {
+        let secs = std::time::Duration::from_secs;
+        assert_eq!(format_time_left(secs(45)), "<1m");
+        assert_eq!(format_time_left(secs(25 * 60 + 30)), "25m");
+        assert_eq!(format_time_left(secs(80 * 60)), "1h 20m");
+        assert_eq!(format_time_left(secs(51 * 3600)), "2d 3h");
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("nginx", 10), "nginx");
+        assert_eq!(truncate_with_ellipsis("nginx", 5), "nginx");
+        assert_eq!(truncate_with_ellipsis("postgres-primary", 8), "postgre…");
+        assert_eq!(truncate_with_ellipsis("nginx", 0), "");
+        // Wide characters take two columns and are never split
+        assert_eq!(truncate_with_ellipsis("コンテナ名前", 12), "コンテナ名前");
+        assert_eq!(truncate_with_ellipsis("コンテナ名前", 7), "コンテ…");
+        assert_eq!(truncate_with_ellipsis("コンテナ名前", 4), "コ…");
+        assert_eq!(truncate_with_ellipsis("🚀rocket", 4), "🚀r…");
+        assert_eq!(truncate_with_ellipsis("é-api", 4), "é-a…");
+    }
+
+    #[test]
+    fn test_pad_to_width() {
+        assert_eq!(pad_to_width("web", 6), "web   ");
+        assert_eq!(pad_to_width("コンテナ", 10), "コンテナ  ");
+        assert_eq!(pad_to_width("コンテナ名前", 8), "コンテ… ");
+        assert_eq!(display_width(&pad_to_width("コンテナ名前", 7)), 7);
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("nginx"), 5);
+        assert_eq!(display_width("コンテナ"), 8);
+        assert_eq!(display_width("🚀"), 2);
+        // A combining accent takes no column of its own
+        assert_eq!(display_width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn test_timestamp_format() {
+        assert!(is_valid_timestamp_format("%Y-%m-%d %H:%M"));
+        assert!(is_valid_timestamp_format("%d/%m %H:%M:%S"));
+        assert!(!is_valid_timestamp_format("%Y-%Q"));
+
+        let time = DateTime::parse_from_rfc3339("2025-10-29T10:15:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let formatted = format_timestamp(&time, "%Y-%m-%d %H:%M");
+        assert_eq!(formatted.len(), "2025-10-29 10:15".len());
+        assert!(formatted.starts_with("2025-10-"));
+    }
+
+    #[test]
+    fn test_format_human_duration_matches_docker() {
+        assert_eq!(
+            format_human_duration(Duration::zero()),
+            "Less than a second"
+        );
+        assert_eq!(format_human_duration(Duration::seconds(1)), "1 second");
+        assert_eq!(format_human_duration(Duration::seconds(45)), "45 seconds");
+        assert_eq!(
+            format_human_duration(Duration::seconds(90)),
+            "About a minute"
+        );
+        assert_eq!(format_human_duration(Duration::minutes(59)), "59 minutes");
+        assert_eq!(
+            format_human_duration(Duration::minutes(70)),
+            "About an hour"
+        );
+        assert_eq!(format_human_duration(Duration::hours(3)), "3 hours");
+        assert_eq!(format_human_duration(Duration::days(3)), "3 days");
+        assert_eq!(format_human_duration(Duration::weeks(3)), "3 weeks");
+        assert_eq!(format_human_duration(Duration::days(90)), "3 months");
+        assert_eq!(format_human_duration(Duration::days(800)), "2 years");
+    }
+
+    #[test]
+    fn test_format_duration_precise() {
+        assert_eq!(
+            format_duration_precise(Duration::milliseconds(850)),
+            "850ms"
+        );
+        assert_eq!(
+            format_duration_precise(Duration::milliseconds(12_340)),
+            "12.3s"
+        );
+        assert_eq!(format_duration_precise(Duration::seconds(125)), "2m 05s");
+    }
+
+    #[test]
+    fn test_format_status() {
+        let now = Utc::now();
+        let mut container = Container {
+            health: Some(HealthStatus::Healthy),
+            started_at: Some(now - Duration::hours(3)),
+            ..Container::test_default()
+        };
+        assert_eq!(format_status(&container, now), "Up 3 hours (healthy)");
+
+        container.health = Some(HealthStatus::Starting);
+        assert_eq!(
+            format_status(&container, now),
+            "Up 3 hours (health: starting)"
+        );
+
+        container.state = ContainerState::Exited;
+        container.exit_code = Some(137);
+        container.finished_at = Some(now - Duration::minutes(5));
+        assert_eq!(format_status(&container, now), "Exited (137) 5 minutes ago");
+
+        container.state = ContainerState::Restarting;
+        container.exit_code = Some(2);
+        container.finished_at = None;
+        assert_eq!(format_status(&container, now), "Restarting (2)");
+
+        container.state = ContainerState::Created;
+        assert_eq!(format_status(&container, now), "Created");
+    }
 }
@@ -7,11 +7,67 @@ use ratatui::{
 };
 
 use crate::core::types::{ContainerState, HealthStatus};
+use crate::ui::formatters::display_width;
+use crate::ui::i18n;
 use crate::ui::render::UiStyles;
+use crate::ui::theme::{HIGH_MARKER, MEDIUM_MARKER};
+
+/// Column widths for the navigation rows (key, description, key, description, ...)
+const NAVIGATION_WIDTHS: [usize; 5] = [12, 28, 7, 15, 7];
+
+/// Column widths for the log scrolling row
+const LOG_SCROLLING_WIDTHS: [usize; 5] = [12, 17, 16, 15, 16];
+
+/// Column widths for the sorting rows
+const SORTING_WIDTHS: [usize; 5] = [12, 13, 12, 15, 16];
+
+/// Lays out a help row as padded columns; the last cell is never padded
+/// Translated text longer than its column still gets a single space separator
+fn help_row(cells: &[&str], widths: &[usize]) -> String {
+    let mut row = String::from("  ");
+    for (i, cell) in cells.iter().enumerate() {
+        row.push_str(cell);
+        if i + 1 < cells.len() {
+            let len = display_width(cell);
+            let width = widths.get(i).copied().unwrap_or(0).max(len + 1);
+            row.push_str(&" ".repeat(width - len));
+        }
+    }
+    row
+}
+
+/// Builds the threshold color legend
+/// Themes with shape markers describe levels by name and marker instead of color name
+fn threshold_legend(styles: &UiStyles) -> Line<'static> {
+    let msg = i18n::messages();
+    let (low, medium, high) = if styles.theme.threshold_markers() {
+        (
+            msg.level_low.to_string(),
+            format!("{} {}", msg.level_medium, MEDIUM_MARKER),
+            format!("{} {}", msg.level_high, HIGH_MARKER),
+        )
+    } else {
+        (
+            msg.green.to_string(),
+            msg.yellow.to_string(),
+            msg.red.to_string(),
+        )
+    };
+
+    Line::from(vec![
+        Span::styled(format!("  {}", low), styles.low),
+        Span::raw(" (0-50%)  "),
+        Span::styled(medium, styles.medium),
+        Span::raw(" (50-80%)  "),
+        Span::styled(high, styles.high),
+        Span::raw(" (>80%)"),
+    ])
+}
 
 /// Renders a centered help popup
 pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
     let area = f.area();
+    let msg = i18n::messages();
 
     // Create a centered popup (80% width, 50% height for compact layout)
     let popup_width = (area.width as f32 * 0.8) as u16;
@@ -27,7 +83,7 @@ pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
 
     // Render the popup block
     let block = Block::default()
-        .title(" Help - Press ? or ESC to close ")
+        .title(msg.help_title)
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_style(styles.header)
@@ -39,44 +95,88 @@ pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
     let help_text = vec![
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Navigation",
+            msg.navigation,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from(
-            "  ↑/↓, j/k    Navigate/scroll (1 line)    →/l    View logs      ←/h    Exit logs",
-        ),
-        Line::from(
-            "  Enter       Action menu                 Esc    Close menu     ?      Toggle help",
-        ),
-        Line::from(
-            "  a           Show all containers         /      Filter         o      Open Dozzle",
-        ),
+        Line::from(help_row(
+            &[
+                "↑/↓, j/k",
+                msg.navigate_scroll,
+                "→/l",
+                msg.view_logs,
+                "←/h",
+                msg.exit_logs,
+            ],
+            &NAVIGATION_WIDTHS,
+        )),
+        Line::from(help_row(
+            &[
+                "Enter",
+                msg.action_menu,
+                "Esc",
+                msg.close_menu,
+                "?",
+                msg.toggle_help,
+            ],
+            &NAVIGATION_WIDTHS,
+        )),
+        Line::from(help_row(
+            &["a", msg.show_all, "/", msg.filter, "o", msg.open_dozzle],
+            &NAVIGATION_WIDTHS,
+        )),
+        Line::from(help_row(
+            &["e", msg.open_port, "Ctrl+S", msg.export_list],
+            &NAVIGATION_WIDTHS,
+        )),
+        Line::from(help_row(
+            &[
+                "PgUp",
+                msg.page_up,
+                "PgDn",
+                msg.page_down,
+                "Home/End",
+                msg.top_bottom,
+            ],
+            &NAVIGATION_WIDTHS,
+        )),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Log View Scrolling",
+            msg.log_scrolling,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from(
-            "  g/G         Top/Bottom       Ctrl+U, b       Page up        Ctrl+D, Space   Page down",
-        ),
+        Line::from(help_row(
+            &[
+                "g/G",
+                msg.top_bottom,
+                "Ctrl+U, b",
+                msg.page_up,
+                "Ctrl+D, Space",
+                msg.page_down,
+            ],
+            &LOG_SCROLLING_WIDTHS,
+        )),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Sorting",
+            msg.sorting,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  u/U         Uptime       n/N         Name           c/C             CPU"),
-        Line::from(
-            "  m/M         Memory       s           Cycle          (press again to toggle asc/desc)",
-        ),
+        Line::from(help_row(
+            &["u/U", msg.uptime, "n/N", msg.name, "c/C", msg.cpu],
+            &SORTING_WIDTHS,
+        )),
+        Line::from(help_row(
+            &["m/M", msg.memory, "s", msg.cycle, msg.toggle_direction],
+            &SORTING_WIDTHS,
+        )),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Status Icons",
+            msg.status_icons,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
@@ -84,50 +184,43 @@ pub fn render_help_popup(f: &mut Frame, styles: &UiStyles) {
         Line::from(vec![
             Span::styled(
                 format!("{} ", styles.icons.health(&HealthStatus::Healthy)),
-                Style::default().fg(Color::Green),
+                styles.low,
             ),
-            Span::raw("Healthy  "),
+            Span::raw(format!("{}  ", msg.healthy)),
             Span::styled(
                 format!("{} ", styles.icons.health(&HealthStatus::Unhealthy)),
-                Style::default().fg(Color::Red),
+                styles.high,
             ),
-            Span::raw("Unhealthy  "),
+            Span::raw(format!("{}  ", msg.unhealthy)),
             Span::styled(
                 format!("{} ", styles.icons.health(&HealthStatus::Starting)),
-                Style::default().fg(Color::Yellow),
+                styles.medium,
             ),
-            Span::raw("Starting  "),
+            Span::raw(format!("{}  ", msg.starting)),
             Span::styled(
                 format!("{} ", styles.icons.state(&ContainerState::Running)),
-                Style::default().fg(Color::Green),
+                styles.low,
             ),
-            Span::raw("Running  "),
+            Span::raw(format!("{}  ", msg.running)),
             Span::styled(
                 format!("{} ", styles.icons.state(&ContainerState::Paused)),
-                Style::default().fg(Color::Yellow),
+                styles.medium,
             ),
-            Span::raw("Paused  "),
+            Span::raw(format!("{}  ", msg.paused)),
             Span::styled(
                 format!("{} ", styles.icons.state(&ContainerState::Exited)),
-                Style::default().fg(Color::Red),
+                styles.high,
             ),
-            Span::raw("Exited"),
+            Span::raw(msg.exited),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Colors",
+            msg.colors,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
-        Line::from(vec![
-            Span::styled("  Green", styles.low),
-            Span::raw(" (0-50%)  "),
-            Span::styled("Yellow", styles.medium),
-            Span::raw(" (50-80%)  "),
-            Span::styled("Red", styles.high),
-            Span::raw(" (>80%)"),
-        ]),
+        threshold_legend(styles),
     ];
 
     // Calculate inner area (inside the border)
@@ -0,0 +1,105 @@
+use chrono::TimeDelta;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::core::app_state::AppState;
+use crate::ui::formatters::{format_bytes, format_duration_precise};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Separator between the hosts of the strip
+const HOST_SEPARATOR: &str = "  │  ";
+
+/// Renders a one-line strip with each host's running/total containers and summed usage,
+/// against the host's capacity once known:
+/// "local 5/7 CPU 23.4% (8 CPUs) Memory 1.2 G/15.5 G load 0.42  │  prod 12/12 CPU 180.2% Memory 8 G"
+pub fn render_host_summary(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    let msg = i18n::messages();
+
+    let mut spans = Vec::new();
+    for (i, summary) in state.host_summaries().into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(HOST_SEPARATOR, styles.title_help));
+        }
+        // Hosts being reconnected show stale numbers
+        let host_style = if state.reconnecting_hosts.contains_key(&summary.host_id) {
+            styles.medium
+        } else {
+            styles.header
+        };
+        spans.push(Span::styled(summary.host_id.clone(), host_style));
+        if let Some((skew, _)) = state.clock_skews.get(&summary.host_id) {
+            spans.push(Span::styled(
+                format!(
+                    " ⚠ {}{}",
+                    if *skew > TimeDelta::zero() { "+" } else { "-" },
+                    format_duration_precise(skew.abs())
+                ),
+                styles.medium,
+            ));
+        }
+        let resources = state.host_resources.get(&summary.host_id);
+        if let Some((resources, _)) = resources {
+            if resources.disk_low() {
+                spans.push(Span::styled(
+                    format!(
+                        " ⚠ {} {:.0}%",
+                        msg.label_disk,
+                        resources.disk_used_percent()
+                    ),
+                    styles.high,
+                ));
+            }
+            if resources.load_high() {
+                spans.push(Span::styled(
+                    format!(" ⚠ {} {:.1}", msg.label_load, resources.load1),
+                    styles.medium,
+                ));
+            }
+        }
+        // Capacity of the host, once its info was read (0 when Docker didn't report it)
+        let info = state.host_info.get(&summary.host_id);
+        let cpus = info.map_or(0, |info| info.cpus);
+        let memory_bytes = info.map_or(0, |info| info.memory_bytes);
+
+        spans.extend([
+            Span::styled(
+                format!(" {}/{}", summary.counts.running, summary.counts.total),
+                styles.title_count,
+            ),
+            Span::styled(format!(" {} ", msg.cpu), styles.title_help),
+            Span::raw(format!("{:.1}%", summary.cpu)),
+        ]);
+        if cpus > 0 {
+            spans.push(Span::styled(
+                format!(" ({} {})", cpus, msg.cpus),
+                styles.title_help,
+            ));
+        }
+        spans.extend([
+            Span::styled(format!(" {} ", msg.memory), styles.title_help),
+            Span::raw(format_bytes(summary.memory_used_bytes)),
+        ]);
+        if memory_bytes > 0 {
+            spans.push(Span::raw(format!("/{}", format_bytes(memory_bytes))));
+        }
+
+        // The local load, or the probe's; a high load is already shown as a warning
+        let load1 = info
+            .and_then(|info| info.load1)
+            .or(resources.map(|(resources, _)| resources.load1));
+        let load_high = resources.is_some_and(|(resources, _)| resources.load_high());
+        if let Some(load1) = load1.filter(|_| !load_high) {
+            spans.extend([
+                Span::styled(format!(" {} ", msg.label_load), styles.title_help),
+                Span::raw(format!("{:.2}", load1)),
+            ]);
+        }
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
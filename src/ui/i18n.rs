@@ -0,0 +1,1762 @@
+//! Message catalog for user-facing UI strings
+//!
+//! The locale is chosen once at startup (config `locale`, then `LC_ALL`,
+//! `LC_MESSAGES`, `LANG`) and every render path looks strings up through
+//! [`messages`]. Each locale is a complete [`Messages`] value, so a missing
+//! translation is a compile error rather than a runtime fallback.
+
+use std::sync::OnceLock;
+
+use timeago::BoxedLanguage;
+use timeago::languages::boxup;
+use timeago::languages::english::English;
+use timeago::languages::german::German;
+use timeago::languages::spanish::Spanish;
+
+use crate::core::types::{
+    ContainerAction, CopyTarget, DiskUsageKind, ExportChoice, ExportFormat, ExportTarget,
+    PruneTarget,
+};
+
+/// Supported UI languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English (default)
+    #[default]
+    En,
+    /// Spanish
+    Es,
+    /// German
+    De,
+}
+
+impl Locale {
+    /// Detects the locale from the standard POSIX environment variables
+    /// The first non-empty variable wins; unsupported languages fall back to English
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns the message catalog for this locale
+    pub fn messages(self) -> &'static Messages {
+        match self {
+            Locale::En => &EN,
+            Locale::Es => &ES,
+            Locale::De => &DE,
+        }
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = String;
+
+    /// Accepts bare language codes ("de") as well as POSIX locale names ("de_DE.UTF-8")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match language.as_str() {
+            "en" | "c" | "posix" => Ok(Locale::En),
+            "es" => Ok(Locale::Es),
+            "de" => Ok(Locale::De),
+            _ => Err(format!("Invalid locale: '{}'. Use 'en', 'es', or 'de'", s)),
+        }
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Sets the UI locale for the lifetime of the process
+/// Only the first call has an effect
+pub fn init(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+/// Returns the message catalog for the active locale (English if not initialized)
+pub fn messages() -> &'static Messages {
+    LOCALE.get().copied().unwrap_or_default().messages()
+}
+
+/// Fills the `{}` placeholders of a catalog template in order; missing arguments
+/// leave their placeholder empty
+pub fn fill(template: &str, args: &[&dyn std::fmt::Display]) -> String {
+    let mut parts = template.split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    for (idx, part) in parts.enumerate() {
+        if let Some(arg) = args.get(idx) {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+/// All translatable UI strings
+pub struct Messages {
+    // Help popup
+    pub help_title: &'static str,
+    pub navigation: &'static str,
+    pub navigate_scroll: &'static str,
+    pub view_logs: &'static str,
+    pub exit_logs: &'static str,
+    pub action_menu: &'static str,
+    pub close_menu: &'static str,
+    pub toggle_help: &'static str,
+    pub show_all: &'static str,
+    pub filter: &'static str,
+    pub open_dozzle: &'static str,
+    pub open_port: &'static str,
+    pub export_list: &'static str,
+    pub log_scrolling: &'static str,
+    pub top_bottom: &'static str,
+    pub page_up: &'static str,
+    pub page_down: &'static str,
+    /// Position in a list taller than the screen: "rows 21–60 of 432"
+    pub list_rows: &'static str,
+    pub list_rows_of: &'static str,
+    pub sorting: &'static str,
+    pub uptime: &'static str,
+    pub name: &'static str,
+    pub cpu: &'static str,
+    pub cpus: &'static str,
+    pub memory: &'static str,
+    pub cycle: &'static str,
+    pub toggle_direction: &'static str,
+    pub status_icons: &'static str,
+    pub healthy: &'static str,
+    pub unhealthy: &'static str,
+    pub starting: &'static str,
+    pub running: &'static str,
+    pub paused: &'static str,
+    pub exited: &'static str,
+    pub colors: &'static str,
+    pub green: &'static str,
+    pub yellow: &'static str,
+    pub red: &'static str,
+    pub level_low: &'static str,
+    pub level_medium: &'static str,
+    pub level_high: &'static str,
+
+    // Action menu
+    pub actions: &'static str,
+    pub action_footer: &'static str,
+    pub action_footer_timeout: &'static str,
+    pub stop_timeout_label: &'static str,
+    pub stop_timeout_footer: &'static str,
+    pub action_start: &'static str,
+    pub action_stop: &'static str,
+    pub action_restart: &'static str,
+    pub action_remove: &'static str,
+    pub action_shell: &'static str,
+    pub action_exec: &'static str,
+    pub action_browse_files: &'static str,
+    pub action_healthcheck: &'static str,
+    pub action_pull: &'static str,
+    pub action_push: &'static str,
+    pub action_connect_network: &'static str,
+    pub action_disconnect_network: &'static str,
+    pub action_restart_unit: &'static str,
+    pub action_prune: &'static str,
+    pub action_maintenance: &'static str,
+    pub no_networks: &'static str,
+
+    // Details popup
+    pub details_title: &'static str,
+    pub details_footer: &'static str,
+    pub clock_ahead: &'static str,
+    pub clock_behind: &'static str,
+    pub disk_nearly_full: &'static str,
+    pub high_load: &'static str,
+    pub memory_limit_in: &'static str,
+    pub same_name_on_hosts: &'static str,
+    pub verify_stayed_up: &'static str,
+    pub verify_crashed: &'static str,
+    pub verify_exit: &'static str,
+    pub label_disk: &'static str,
+    pub label_load: &'static str,
+    pub label_free: &'static str,
+    pub replicas_title: &'static str,
+    pub replicas_footer: &'static str,
+    pub plan_title: &'static str,
+    pub plan_footer: &'static str,
+    pub no_replicas: &'static str,
+    pub label_average: &'static str,
+    pub build_title: &'static str,
+    pub build_path: &'static str,
+    pub build_tag: &'static str,
+    pub build_footer: &'static str,
+    pub build_output: &'static str,
+    pub build_running: &'static str,
+    pub build_succeeded: &'static str,
+    pub build_failed: &'static str,
+    pub exec_command_label: &'static str,
+    pub exec_prompt_footer: &'static str,
+    pub exec_output: &'static str,
+    pub exec_running: &'static str,
+    pub exec_exit_code: &'static str,
+    pub exec_failed: &'static str,
+    pub label_state: &'static str,
+    pub label_health: &'static str,
+    pub label_ports: &'static str,
+    pub label_labels: &'static str,
+    /// Details row with the systemd unit managing the container
+    pub label_systemd_unit: &'static str,
+    pub label_time_to_healthy: &'static str,
+    pub label_oom_in: &'static str,
+    pub label_image: &'static str,
+
+    // Container list
+    pub header_id: &'static str,
+    pub header_name: &'static str,
+    pub header_host: &'static str,
+    pub header_status: &'static str,
+    pub header_restarts: &'static str,
+    /// Appended to the exported state of a container stuck in a restart loop
+    pub restart_loop: &'static str,
+    pub header_size: &'static str,
+    pub header_platform: &'static str,
+    pub label_emulated: &'static str,
+    pub header_cpu: &'static str,
+    pub header_cpu_host: &'static str,
+    pub header_memory: &'static str,
+    pub header_net_tx: &'static str,
+    pub header_net_rx: &'static str,
+    pub header_block_read: &'static str,
+    pub header_block_write: &'static str,
+    pub header_pids: &'static str,
+    pub header_created: &'static str,
+    pub containers: &'static str,
+
+    // Export
+    pub export_title: &'static str,
+    pub export_copy_markdown: &'static str,
+    pub export_copy_html: &'static str,
+    pub export_save_markdown: &'static str,
+    pub export_save_html: &'static str,
+    pub export_copied: &'static str,
+    pub export_saved: &'static str,
+    pub export_failed: &'static str,
+    pub title_help: &'static str,
+
+    // Log view
+    pub logs: &'static str,
+    pub logs_return_hint: &'static str,
+    pub image_history: &'static str,
+    pub header_created_by: &'static str,
+    pub layers: &'static str,
+    pub processes: &'static str,
+    pub header_pid: &'static str,
+    pub header_user: &'static str,
+    pub header_command: &'static str,
+    pub loading: &'static str,
+    pub following: &'static str,
+    pub paused_logs: &'static str,
+    pub repeats_collapsed: &'static str,
+    pub idle: &'static str,
+    pub search: &'static str,
+    pub no_matches: &'static str,
+    pub unknown: &'static str,
+    pub reconnecting: &'static str,
+    pub attempt: &'static str,
+
+    // Volumes view
+    pub volumes: &'static str,
+    pub header_driver: &'static str,
+    pub header_mountpoint: &'static str,
+    pub header_used_by: &'static str,
+    pub orphaned: &'static str,
+    pub reclaimable: &'static str,
+    pub volumes_footer: &'static str,
+    pub remove_orphans_title: &'static str,
+    pub remove_orphans_footer: &'static str,
+
+    // Cleanup view
+    pub cleanup: &'static str,
+    pub header_kind: &'static str,
+    pub header_since: &'static str,
+    pub cleanup_exited_container: &'static str,
+    pub cleanup_dangling_image: &'static str,
+    pub cleanup_unused_volume: &'static str,
+    pub cleanup_footer: &'static str,
+    pub cleanup_confirm_title: &'static str,
+    pub nothing_to_clean: &'static str,
+
+    // Disk usage view
+    pub disk_usage: &'static str,
+    pub header_total: &'static str,
+    pub header_active: &'static str,
+    pub header_reclaimable: &'static str,
+    pub disk_usage_images: &'static str,
+    pub disk_usage_build_cache: &'static str,
+    pub disk_usage_footer: &'static str,
+    pub freed: &'static str,
+    pub prune_title: &'static str,
+    pub maintenance_title: &'static str,
+    pub maintenance_end: &'static str,
+    pub prune_images: &'static str,
+    pub prune_containers: &'static str,
+    pub prune_volumes: &'static str,
+    pub prune_build_cache: &'static str,
+    pub prune_footer: &'static str,
+
+    // Prune menu
+    pub prune_stopped_containers: &'static str,
+    pub prune_dangling_images: &'static str,
+    pub prune_unused_volumes: &'static str,
+    pub prune_unused_networks: &'static str,
+    pub prune_menu_confirm: &'static str,
+    pub pruned: &'static str,
+    pub removed: &'static str,
+    pub prune_failed: &'static str,
+
+    // Networks view
+    pub networks: &'static str,
+    pub header_scope: &'static str,
+    pub header_subnet: &'static str,
+    pub header_containers: &'static str,
+
+    // File browser
+    pub files: &'static str,
+    pub files_footer: &'static str,
+    pub header_owner: &'static str,
+    pub header_modified: &'static str,
+    pub empty_directory: &'static str,
+    pub downloading: &'static str,
+    pub saved_to: &'static str,
+
+    // Environment variables
+    pub environment: &'static str,
+    pub env_footer_show: &'static str,
+    pub env_footer_hide: &'static str,
+
+    // Events timeline
+    pub events: &'static str,
+    pub no_events: &'static str,
+    pub shell_sessions: &'static str,
+    pub shell_sessions_footer: &'static str,
+    pub no_shell_sessions: &'static str,
+    pub header_container: &'static str,
+    pub header_started: &'static str,
+
+    // Notifications panel
+    pub notifications: &'static str,
+    pub notifications_footer: &'static str,
+    pub no_notifications: &'static str,
+    pub error_detail: &'static str,
+    pub error_detail_footer: &'static str,
+    pub unacknowledged: &'static str,
+    pub header_time: &'static str,
+    pub header_source: &'static str,
+    pub header_message: &'static str,
+    pub action_failed: &'static str,
+    pub retrying: &'static str,
+    pub do_not_disturb: &'static str,
+    pub maintenance: &'static str,
+    pub stack: &'static str,
+    pub loop_render: &'static str,
+    pub loop_lag: &'static str,
+    pub loop_queue: &'static str,
+    pub bulk_done: &'static str,
+    pub bulk_failed: &'static str,
+    pub bulk_remaining: &'static str,
+
+    // Copy menu
+    pub copy_title: &'static str,
+    pub copy_full_id: &'static str,
+    pub copy_name: &'static str,
+    pub copy_host_id: &'static str,
+    pub copy_footer: &'static str,
+    pub copied: &'static str,
+    pub copy_failed: &'static str,
+
+    pub event_started: &'static str,
+    pub event_exited: &'static str,
+    pub event_removed: &'static str,
+    pub event_paused: &'static str,
+    pub event_unpaused: &'static str,
+    pub event_renamed: &'static str,
+    pub event_health: &'static str,
+    pub event_exec: &'static str,
+    pub event_logs_saved: &'static str,
+    pub event_logs_not_saved: &'static str,
+
+    // Search bar
+    pub filtering: &'static str,
+    pub case_sensitive: &'static str,
+    pub whole_word: &'static str,
+
+    // Errors, as templates whose `{}` placeholders are filled in order by [`fill`]
+    pub action_error: &'static str,
+    pub action_error_attempts: &'static str,
+    pub host_client_failed: &'static str,
+    pub host_filters_invalid: &'static str,
+    pub host_ping_failed: &'static str,
+    pub host_ping_timeout: &'static str,
+    pub host_not_connected: &'static str,
+    pub no_download_dir: &'static str,
+    pub download_failed: &'static str,
+    pub save_failed: &'static str,
+    pub archive_context_failed: &'static str,
+    pub archive_failed: &'static str,
+    pub build_path_missing: &'static str,
+    pub home_dir_unknown: &'static str,
+    pub no_dockerfile: &'static str,
+    pub invalid_dockerfile_path: &'static str,
+    pub path_not_found: &'static str,
+    pub build_error: &'static str,
+    pub list_files_failed: &'static str,
+    pub exec_detached: &'static str,
+    pub ls_exited: &'static str,
+    pub fetch_sizes_failed: &'static str,
+    pub fetch_image_history_failed: &'static str,
+    pub inspect_failed: &'static str,
+    pub pull_failed: &'static str,
+    pub push_failed: &'static str,
+    pub list_networks_failed: &'static str,
+    pub connect_network_failed: &'static str,
+    pub disconnect_network_failed: &'static str,
+    pub invalid_host_format: &'static str,
+    pub list_volumes_failed: &'static str,
+    pub list_containers_failed: &'static str,
+    pub list_images_failed: &'static str,
+    pub list_processes_failed: &'static str,
+    pub disk_usage_failed: &'static str,
+    pub no_disk_usage_totals: &'static str,
+    pub remove_volume_failed: &'static str,
+    pub remove_container_failed: &'static str,
+    pub remove_image_failed: &'static str,
+    pub prune_error: &'static str,
+    pub no_healthcheck: &'static str,
+    pub create_exec_failed: &'static str,
+    pub start_exec_failed: &'static str,
+    pub host_info_failed: &'static str,
+    pub unknown_data_root: &'static str,
+    pub unexpected_output: &'static str,
+    pub probe_create_failed: &'static str,
+    pub probe_start_failed: &'static str,
+    pub probe_failed: &'static str,
+    pub probe_output_failed: &'static str,
+    pub shell_label_no_value: &'static str,
+    pub shell_list_empty: &'static str,
+    pub systemctl_failed: &'static str,
+    pub invalid_unit_name: &'static str,
+    pub read_logs_failed: &'static str,
+    pub create_failed: &'static str,
+    pub write_failed: &'static str,
+    // Container status like `docker ps`, as templates filled by [`fill`]
+    pub status_up: &'static str,
+    pub status_up_bare: &'static str,
+    pub status_healthy: &'static str,
+    pub status_unhealthy: &'static str,
+    pub status_health_starting: &'static str,
+    pub status_paused: &'static str,
+    pub status_restarting: &'static str,
+    pub status_exited: &'static str,
+    pub status_ago: &'static str,
+    pub status_created: &'static str,
+    pub status_dead: &'static str,
+    pub status_removing: &'static str,
+    /// Durations, phrased to follow `status_up` and `status_ago` (German needs the dative)
+    pub duration_under_second: &'static str,
+    pub duration_second: &'static str,
+    pub duration_seconds: &'static str,
+    pub duration_minute: &'static str,
+    pub duration_minutes: &'static str,
+    pub duration_hour: &'static str,
+    pub duration_hours: &'static str,
+    pub duration_days: &'static str,
+    pub duration_weeks: &'static str,
+    pub duration_months: &'static str,
+    pub duration_years: &'static str,
+    /// Language of relative times ("2 hours ago")
+    pub relative_time: fn() -> BoxedLanguage,
+}
+
+impl Messages {
+    /// Returns the menu label for a container action
+    pub fn action(&self, action: ContainerAction) -> &'static str {
+        match action {
+            ContainerAction::Start => self.action_start,
+            ContainerAction::Stop => self.action_stop,
+            ContainerAction::Restart => self.action_restart,
+            ContainerAction::Remove => self.action_remove,
+            ContainerAction::Shell => self.action_shell,
+            ContainerAction::ExecCommand => self.action_exec,
+            ContainerAction::BrowseFiles => self.action_browse_files,
+            ContainerAction::RunHealthcheck => self.action_healthcheck,
+            ContainerAction::PullImage => self.action_pull,
+            ContainerAction::PushImage => self.action_push,
+            ContainerAction::ConnectNetwork => self.action_connect_network,
+            ContainerAction::DisconnectNetwork => self.action_disconnect_network,
+            ContainerAction::RestartUnit => self.action_restart_unit,
+            ContainerAction::Prune => self.action_prune,
+            ContainerAction::Maintenance => self.action_maintenance,
+        }
+    }
+
+    /// Returns the prune menu label for a target
+    pub fn prune_target(&self, target: PruneTarget) -> &'static str {
+        match target {
+            PruneTarget::StoppedContainers => self.prune_stopped_containers,
+            PruneTarget::DanglingImages => self.prune_dangling_images,
+            PruneTarget::UnusedVolumes => self.prune_unused_volumes,
+            PruneTarget::UnusedNetworks => self.prune_unused_networks,
+        }
+    }
+
+    /// Returns the copy menu label for a target
+    pub fn copy_target(&self, target: CopyTarget) -> &'static str {
+        match target {
+            CopyTarget::FullId => self.copy_full_id,
+            CopyTarget::Name => self.copy_name,
+            CopyTarget::HostAndId => self.copy_host_id,
+        }
+    }
+
+    /// Returns the export menu label for a choice
+    pub fn export_choice(&self, choice: ExportChoice) -> &'static str {
+        match (choice.format, choice.target) {
+            (ExportFormat::Markdown, ExportTarget::Clipboard) => self.export_copy_markdown,
+            (ExportFormat::Html, ExportTarget::Clipboard) => self.export_copy_html,
+            (ExportFormat::Markdown, ExportTarget::File) => self.export_save_markdown,
+            (ExportFormat::Html, ExportTarget::File) => self.export_save_html,
+        }
+    }
+
+    /// Returns the disk usage view label for a kind
+    pub fn disk_usage_kind(&self, kind: DiskUsageKind) -> &'static str {
+        match kind {
+            DiskUsageKind::Images => self.disk_usage_images,
+            DiskUsageKind::Containers => self.header_containers,
+            DiskUsageKind::Volumes => self.volumes,
+            DiskUsageKind::BuildCache => self.disk_usage_build_cache,
+        }
+    }
+
+    /// Returns what pruning a kind removes, for the prune confirmation
+    pub fn prune_description(&self, kind: DiskUsageKind) -> &'static str {
+        match kind {
+            DiskUsageKind::Images => self.prune_images,
+            DiskUsageKind::Containers => self.prune_containers,
+            DiskUsageKind::Volumes => self.prune_volumes,
+            DiskUsageKind::BuildCache => self.prune_build_cache,
+        }
+    }
+}
+
+static EN: Messages = Messages {
+    help_title: " Help - Press ? or ESC to close ",
+    navigation: "Navigation",
+    navigate_scroll: "Navigate/scroll (1 line)",
+    view_logs: "View logs",
+    exit_logs: "Exit logs",
+    action_menu: "Action menu",
+    close_menu: "Close menu",
+    toggle_help: "Toggle help",
+    show_all: "Show all containers",
+    filter: "Filter",
+    open_dozzle: "Open Dozzle",
+    open_port: "Open published HTTP port in browser",
+    export_list: "Export list (Markdown/HTML)",
+    log_scrolling: "Log View Scrolling",
+    top_bottom: "Top/Bottom",
+    page_up: "Page up",
+    page_down: "Page down",
+    list_rows: "rows",
+    list_rows_of: "of",
+    sorting: "Sorting",
+    uptime: "Uptime",
+    name: "Name",
+    cpu: "CPU",
+    cpus: "CPUs",
+    memory: "Memory",
+    cycle: "Cycle",
+    toggle_direction: "(press again to toggle asc/desc)",
+    status_icons: "Status Icons",
+    healthy: "Healthy",
+    unhealthy: "Unhealthy",
+    starting: "Starting",
+    running: "Running",
+    paused: "Paused",
+    exited: "Exited",
+    colors: "Colors",
+    green: "Green",
+    yellow: "Yellow",
+    red: "Red",
+    level_low: "Low",
+    level_medium: "Medium",
+    level_high: "High",
+
+    actions: "Actions",
+    action_footer: "↑/↓: Navigate  Enter: Execute  Esc/←: Cancel",
+    action_footer_timeout: "↑/↓: Navigate  Enter: Execute  T: Timeout  Esc: Cancel",
+    stop_timeout_label: "Seconds before killing",
+    stop_timeout_footer: "Enter: Run  Esc: Cancel",
+    action_start: "Start",
+    action_stop: "Stop",
+    action_restart: "Restart",
+    action_remove: "Remove",
+    action_shell: "Shell",
+    action_exec: "Exec command…",
+    action_browse_files: "Browse files",
+    action_healthcheck: "Run health check",
+    action_pull: "Pull image",
+    action_push: "Push image",
+    action_connect_network: "Connect network",
+    action_disconnect_network: "Disconnect network",
+    action_restart_unit: "Restart systemd unit",
+    action_prune: "Prune host…",
+    action_maintenance: "Host maintenance…",
+    no_networks: "No networks available",
+
+    details_title: " Details ",
+    details_footer: "Esc/i: Close  f: Full ID  y: Copy  Y: Copy labels",
+    clock_ahead: "clock ahead by",
+    clock_behind: "clock behind by",
+    disk_nearly_full: "disk nearly full",
+    high_load: "high load",
+    memory_limit_in: "memory limit in",
+    same_name_on_hosts: "Same name also on",
+    verify_stayed_up: "stayed up for",
+    verify_crashed: "crashed after",
+    verify_exit: "exit",
+    label_disk: "disk",
+    label_load: "load",
+    label_free: "free",
+    replicas_title: "Replicas",
+    replicas_footer: "Esc/v: Close",
+    plan_title: "Plan",
+    plan_footer: "Enter: Run  Esc: Cancel",
+    no_replicas: "No other running container shares this compose service or image",
+    label_average: "Average",
+    build_title: " Build image ",
+    build_path: "Path",
+    build_tag: "Tag",
+    build_footer: "Tab: Switch field  Enter: Build  Esc: Cancel",
+    build_output: "Build",
+    build_running: "[Building...]",
+    build_succeeded: "[Done]",
+    build_failed: "[Failed]",
+    exec_command_label: "Command",
+    exec_prompt_footer: "Enter: Run  Esc: Cancel",
+    exec_output: "Exec",
+    exec_running: "[Running...]",
+    exec_exit_code: "exit",
+    exec_failed: "[Failed]",
+    label_state: "State",
+    label_health: "Health",
+    label_ports: "Ports",
+    label_labels: "Labels",
+    label_systemd_unit: "Unit",
+    label_time_to_healthy: "Healthy in",
+    label_oom_in: "OOM in",
+    label_image: "Image",
+
+    header_id: "ID",
+    header_name: "Name",
+    header_host: "Host",
+    header_status: "Status",
+    header_restarts: "Restarts",
+    restart_loop: "restart loop",
+    header_size: "Size",
+    header_platform: "Platform",
+    label_emulated: "emulated",
+    header_cpu: "CPU %",
+    header_cpu_host: "CPU % host",
+    header_memory: "Memory %",
+    header_net_tx: "NetTx/s",
+    header_net_rx: "NetRx/s",
+    header_block_read: "BlkRead/s",
+    header_block_write: "BlkWrite/s",
+    header_pids: "PIDs",
+    header_created: "Created",
+    containers: "containers",
+    export_title: "Export",
+    export_copy_markdown: "Copy as Markdown",
+    export_copy_html: "Copy as HTML",
+    export_save_markdown: "Save as Markdown file",
+    export_save_html: "Save as HTML file",
+    export_copied: "Copied",
+    export_saved: "Saved",
+    export_failed: "Export failed",
+    title_help: "'?' help, 'q' quit",
+
+    logs: "Logs",
+    logs_return_hint: "Press ESC to return",
+    image_history: "Image history",
+    header_created_by: "Created By",
+    layers: "layers",
+    processes: "Processes",
+    header_pid: "PID",
+    header_user: "User",
+    header_command: "Command",
+    loading: "[Loading...]",
+    following: "[FOLLOWING]",
+    paused_logs: "[PAUSED]",
+    repeats_collapsed: "[COLLAPSED]",
+    idle: "idle",
+    search: "Search",
+    no_matches: "no matches",
+    unknown: "Unknown",
+    reconnecting: "reconnecting",
+    attempt: "attempt",
+
+    volumes: "Volumes",
+    header_driver: "Driver",
+    header_mountpoint: "Mountpoint",
+    header_used_by: "Used by",
+    orphaned: "orphaned",
+    reclaimable: "reclaimable",
+    volumes_footer: "x: Remove  D: Remove all orphaned  Esc/←: Back",
+    remove_orphans_title: " Remove orphaned volumes ",
+    remove_orphans_footer: "Enter: Remove  Esc: Cancel",
+
+    cleanup: "Cleanup",
+    header_kind: "Kind",
+    header_since: "Since",
+    cleanup_exited_container: "exited container",
+    cleanup_dangling_image: "dangling image",
+    cleanup_unused_volume: "unused volume",
+    cleanup_footer: "x: Remove  D: Remove all of this kind  Esc/←: Back",
+    cleanup_confirm_title: " Clean up ",
+    nothing_to_clean: "Nothing to clean up",
+
+    disk_usage: "Disk usage",
+    header_total: "Total",
+    header_active: "Active",
+    header_reclaimable: "Reclaimable",
+    disk_usage_images: "Images",
+    disk_usage_build_cache: "Build cache",
+    disk_usage_footer: "x: Prune  Esc/←: Back",
+    freed: "freed",
+    prune_title: " Prune ",
+    maintenance_title: " Maintenance ",
+    maintenance_end: "End maintenance",
+    prune_images: "Remove all images not used by a container",
+    prune_containers: "Remove all stopped containers",
+    prune_volumes: "Remove all volumes not used by a container",
+    prune_build_cache: "Remove the whole build cache",
+    prune_footer: "Enter: Prune  Esc: Cancel",
+
+    prune_stopped_containers: "Stopped containers",
+    prune_dangling_images: "Dangling images",
+    prune_unused_volumes: "Unused volumes",
+    prune_unused_networks: "Unused networks",
+    prune_menu_confirm: "Enter again to prune, Esc to cancel",
+    pruned: "pruned",
+    removed: "removed",
+    prune_failed: "Prune failed",
+
+    networks: "Networks",
+    header_scope: "Scope",
+    header_subnet: "Subnet",
+    header_containers: "Containers",
+
+    files: "Files",
+    files_footer: "Enter/→: Open  ←: Up  D: Download  Esc: Back",
+    header_owner: "Owner",
+    header_modified: "Modified",
+    empty_directory: "Empty directory",
+    downloading: "Downloading…",
+    saved_to: "Saved to",
+
+    environment: "Environment",
+    env_footer_show: "/: Search  *: Show secrets  Esc: Close",
+    env_footer_hide: "/: Search  *: Hide secrets  Esc: Close",
+
+    events: "Events",
+    no_events: "No events yet",
+    shell_sessions: "Shell sessions",
+    shell_sessions_footer: "Enter: Attach  Esc: Back",
+    no_shell_sessions: "No shell sessions. Ctrl+] in a shell detaches and keeps it running.",
+    header_container: "Container",
+    header_started: "Started",
+
+    notifications: "Notifications",
+    notifications_footer: "Enter: Acknowledge  i: Details  a: All  x: Clear  D: Clear acknowledged  Esc: Back",
+    no_notifications: "No notifications",
+    error_detail: "Error",
+    error_detail_footer: "y: Copy  Esc: Back",
+    unacknowledged: "unacknowledged",
+    header_time: "Time",
+    header_source: "Source",
+    header_message: "Message",
+    action_failed: "failed",
+    retrying: "retrying",
+    do_not_disturb: "do not disturb",
+    maintenance: "maintenance",
+    stack: "stack",
+    loop_render: "render",
+    loop_lag: "lag",
+    loop_queue: "queue",
+    bulk_done: "done",
+    bulk_failed: "failed",
+    bulk_remaining: "remaining",
+
+    copy_title: " Copy ",
+    copy_full_id: "Full ID",
+    copy_name: "Name",
+    copy_host_id: "host:ID",
+    copy_footer: "Enter/y: Copy  Esc: Cancel",
+    copied: "Copied",
+    copy_failed: "Copy failed",
+
+    event_started: "started",
+    event_exited: "exited",
+    event_removed: "removed",
+    event_paused: "paused",
+    event_unpaused: "unpaused",
+    event_renamed: "renamed from",
+    event_health: "health",
+    event_exec: "exec",
+    event_logs_saved: "logs saved",
+    event_logs_not_saved: "logs not saved",
+
+    filtering: "Filtering",
+    case_sensitive: "case-sensitive",
+    whole_word: "whole word",
+
+    action_error: "{} container failed: {}",
+    action_error_attempts: "{} container failed after {} attempts: {}",
+    host_client_failed: "Failed to create Docker client for host '{}': {}",
+    host_filters_invalid: "Failed to parse filters for host '{}': {}",
+    host_ping_failed: "Docker daemon ping failed for host '{}': {}",
+    host_ping_timeout: "Docker daemon ping timeout for host '{}' (>{}s)",
+    host_not_connected: "Host {} is not connected",
+    no_download_dir: "No download directory",
+    download_failed: "Failed to download {}: {}",
+    save_failed: "Failed to save {}: {}",
+    archive_context_failed: "Failed to archive build context: {}",
+    archive_failed: "Failed to archive {}: {}",
+    build_path_missing: "Enter a Dockerfile or build context path",
+    home_dir_unknown: "Could not determine the home directory",
+    no_dockerfile: "No Dockerfile in {}",
+    invalid_dockerfile_path: "Invalid Dockerfile path: {}",
+    path_not_found: "{} does not exist",
+    build_error: "Build failed: {}",
+    list_files_failed: "Failed to list files: {}",
+    exec_detached: "Exec started in detached mode unexpectedly",
+    ls_exited: "ls exited with {}",
+    fetch_sizes_failed: "Failed to fetch container sizes: {}",
+    fetch_image_history_failed: "Failed to fetch image history: {}",
+    inspect_failed: "Failed to inspect container: {}",
+    pull_failed: "Failed to pull {}: {}",
+    push_failed: "Failed to push {}: {}",
+    list_networks_failed: "Failed to list networks: {}",
+    connect_network_failed: "Failed to connect to network {}: {}",
+    disconnect_network_failed: "Failed to disconnect from network {}: {}",
+    invalid_host_format: "Invalid host format: '{}'. Use 'local', 'ssh://user@host[:port]', 'tcp://host:port', or 'tls://host:port'",
+    list_volumes_failed: "Failed to list volumes: {}",
+    list_containers_failed: "Failed to list containers: {}",
+    list_images_failed: "Failed to list images: {}",
+    list_processes_failed: "Failed to list processes: {}",
+    disk_usage_failed: "Failed to fetch disk usage: {}",
+    no_disk_usage_totals: "The Docker daemon does not report disk usage totals",
+    remove_volume_failed: "Failed to remove volume {}: {}",
+    remove_container_failed: "{}: Failed to remove container: {}",
+    remove_image_failed: "{}: Failed to remove image: {}",
+    prune_error: "Failed to prune: {}",
+    no_healthcheck: "No health check configured",
+    create_exec_failed: "Failed to create exec: {}",
+    start_exec_failed: "Failed to start exec: {}",
+    host_info_failed: "Failed to read host info: {}",
+    unknown_data_root: "Unknown data root",
+    unexpected_output: "Unexpected output: {}",
+    probe_create_failed: "Failed to create probe container: {}",
+    probe_start_failed: "Failed to start probe container: {}",
+    probe_failed: "Probe container failed: {}",
+    probe_output_failed: "Failed to read probe output: {}",
+    shell_label_no_value: "shell label '{}' needs a value (key=value)",
+    shell_list_empty: "shells for '{}' can't be empty",
+    systemctl_failed: "Failed to run systemctl: {}",
+    invalid_unit_name: "Invalid systemd unit name '{}'",
+    read_logs_failed: "Failed to read container logs: {}",
+    create_failed: "Failed to create {}: {}",
+    write_failed: "Failed to write {}: {}",
+    status_up: "Up {}",
+    status_up_bare: "Up",
+    status_healthy: "{} (healthy)",
+    status_unhealthy: "{} (unhealthy)",
+    status_health_starting: "{} (health: starting)",
+    status_paused: "{} (Paused)",
+    status_restarting: "Restarting",
+    status_exited: "Exited",
+    status_ago: "{} ago",
+    status_created: "Created",
+    status_dead: "Dead",
+    status_removing: "Removal In Progress",
+    duration_under_second: "Less than a second",
+    duration_second: "1 second",
+    duration_seconds: "{} seconds",
+    duration_minute: "About a minute",
+    duration_minutes: "{} minutes",
+    duration_hour: "About an hour",
+    duration_hours: "{} hours",
+    duration_days: "{} days",
+    duration_weeks: "{} weeks",
+    duration_months: "{} months",
+    duration_years: "{} years",
+    relative_time: || boxup(English),
+};
+
+static ES: Messages = Messages {
+    help_title: " Ayuda - Pulsa ? o ESC para cerrar ",
+    navigation: "Navegación",
+    navigate_scroll: "Navegar/desplazar (1 línea)",
+    view_logs: "Ver logs",
+    exit_logs: "Salir de logs",
+    action_menu: "Menú de acciones",
+    close_menu: "Cerrar menú",
+    toggle_help: "Mostrar ayuda",
+    show_all: "Mostrar todos",
+    filter: "Filtrar",
+    open_dozzle: "Abrir Dozzle",
+    open_port: "Abrir puerto HTTP publicado en el navegador",
+    export_list: "Exportar lista (Markdown/HTML)",
+    log_scrolling: "Desplazamiento de logs",
+    top_bottom: "Inicio/Final",
+    page_up: "Página arriba",
+    page_down: "Página abajo",
+    list_rows: "filas",
+    list_rows_of: "de",
+    sorting: "Ordenación",
+    uptime: "Tiempo activo",
+    name: "Nombre",
+    cpu: "CPU",
+    cpus: "CPUs",
+    memory: "Memoria",
+    cycle: "Rotar",
+    toggle_direction: "(pulsa de nuevo para invertir el orden)",
+    status_icons: "Iconos de estado",
+    healthy: "Saludable",
+    unhealthy: "No saludable",
+    starting: "Iniciando",
+    running: "En ejecución",
+    paused: "Pausado",
+    exited: "Detenido",
+    colors: "Colores",
+    green: "Verde",
+    yellow: "Amarillo",
+    red: "Rojo",
+    level_low: "Bajo",
+    level_medium: "Medio",
+    level_high: "Alto",
+
+    actions: "Acciones",
+    action_footer: "↑/↓: Navegar  Enter: Ejecutar  Esc/←: Cancelar",
+    action_footer_timeout: "↑/↓: Navegar  Enter: Ejecutar  T: Tiempo  Esc: Cancelar",
+    stop_timeout_label: "Segundos antes de matar",
+    stop_timeout_footer: "Enter: Ejecutar  Esc: Cancelar",
+    action_start: "Iniciar",
+    action_stop: "Detener",
+    action_restart: "Reiniciar",
+    action_remove: "Eliminar",
+    action_shell: "Shell",
+    action_exec: "Ejecutar comando…",
+    action_browse_files: "Explorar archivos",
+    action_healthcheck: "Ejecutar comprobación de salud",
+    action_pull: "Descargar imagen",
+    action_push: "Subir imagen",
+    action_connect_network: "Conectar red",
+    action_disconnect_network: "Desconectar red",
+    action_restart_unit: "Reiniciar unidad systemd",
+    action_prune: "Purgar host…",
+    action_maintenance: "Mantenimiento del host…",
+    no_networks: "No hay redes disponibles",
+
+    details_title: " Detalles ",
+    details_footer: "Esc/i: Cerrar  f: ID completo  y: Copiar  Y: Copiar etiquetas",
+    clock_ahead: "reloj adelantado",
+    clock_behind: "reloj atrasado",
+    disk_nearly_full: "disco casi lleno",
+    high_load: "carga alta",
+    memory_limit_in: "límite de memoria en",
+    same_name_on_hosts: "Mismo nombre también en",
+    verify_stayed_up: "sigue en marcha tras",
+    verify_crashed: "falló tras",
+    verify_exit: "salida",
+    label_disk: "disco",
+    label_load: "carga",
+    label_free: "libre",
+    replicas_title: "Réplicas",
+    replicas_footer: "Esc/v: Cerrar",
+    plan_title: "Plan",
+    plan_footer: "Enter: Ejecutar  Esc: Cancelar",
+    no_replicas: "Ningún otro contenedor en ejecución comparte este servicio de compose o imagen",
+    label_average: "Media",
+    build_title: " Construir imagen ",
+    build_path: "Ruta",
+    build_tag: "Etiqueta",
+    build_footer: "Tab: Cambiar campo  Enter: Construir  Esc: Cancelar",
+    build_output: "Construcción",
+    build_running: "[Construyendo...]",
+    build_succeeded: "[Listo]",
+    build_failed: "[Falló]",
+    exec_command_label: "Comando",
+    exec_prompt_footer: "Enter: Ejecutar  Esc: Cancelar",
+    exec_output: "Exec",
+    exec_running: "[Ejecutando...]",
+    exec_exit_code: "salida",
+    exec_failed: "[Falló]",
+    label_state: "Estado",
+    label_health: "Salud",
+    label_ports: "Puertos",
+    label_labels: "Etiquetas",
+    label_systemd_unit: "Unidad",
+    label_time_to_healthy: "Sano en",
+    label_oom_in: "OOM en",
+    label_image: "Imagen",
+
+    header_id: "ID",
+    header_name: "Nombre",
+    header_host: "Host",
+    header_status: "Estado",
+    header_restarts: "Reinicios",
+    restart_loop: "bucle de reinicios",
+    header_size: "Tamaño",
+    header_platform: "Plataforma",
+    label_emulated: "emulada",
+    header_cpu: "CPU %",
+    header_cpu_host: "CPU % host",
+    header_memory: "Memoria %",
+    header_net_tx: "Tx red/s",
+    header_net_rx: "Rx red/s",
+    header_block_read: "LecDisco/s",
+    header_block_write: "EscDisco/s",
+    header_pids: "PIDs",
+    header_created: "Creado",
+    containers: "contenedores",
+    export_title: "Exportar",
+    export_copy_markdown: "Copiar como Markdown",
+    export_copy_html: "Copiar como HTML",
+    export_save_markdown: "Guardar como archivo Markdown",
+    export_save_html: "Guardar como archivo HTML",
+    export_copied: "Copiado",
+    export_saved: "Guardado",
+    export_failed: "Error al exportar",
+    title_help: "'?' ayuda, 'q' salir",
+
+    logs: "Logs",
+    logs_return_hint: "Pulsa ESC para volver",
+    image_history: "Historial de imagen",
+    header_created_by: "Creado por",
+    layers: "capas",
+    processes: "Procesos",
+    header_pid: "PID",
+    header_user: "Usuario",
+    header_command: "Comando",
+    loading: "[Cargando...]",
+    following: "[SIGUIENDO]",
+    paused_logs: "[EN PAUSA]",
+    repeats_collapsed: "[AGRUPADO]",
+    idle: "inactivo",
+    search: "Buscar",
+    no_matches: "sin coincidencias",
+    unknown: "Desconocido",
+    reconnecting: "reconectando",
+    attempt: "intento",
+
+    volumes: "Volúmenes",
+    header_driver: "Driver",
+    header_mountpoint: "Punto de montaje",
+    header_used_by: "Usado por",
+    orphaned: "huérfanos",
+    reclaimable: "recuperables",
+    volumes_footer: "x: Eliminar  D: Eliminar todos los huérfanos  Esc/←: Volver",
+    remove_orphans_title: " Eliminar volúmenes huérfanos ",
+    remove_orphans_footer: "Enter: Eliminar  Esc: Cancelar",
+
+    cleanup: "Limpieza",
+    header_kind: "Tipo",
+    header_since: "Desde",
+    cleanup_exited_container: "contenedor detenido",
+    cleanup_dangling_image: "imagen huérfana",
+    cleanup_unused_volume: "volumen sin uso",
+    cleanup_footer: "x: Eliminar  D: Eliminar todos de este tipo  Esc/←: Volver",
+    cleanup_confirm_title: " Limpiar ",
+    nothing_to_clean: "Nada que limpiar",
+
+    disk_usage: "Uso de disco",
+    header_total: "Total",
+    header_active: "Activos",
+    header_reclaimable: "Recuperable",
+    disk_usage_images: "Imágenes",
+    disk_usage_build_cache: "Caché de build",
+    disk_usage_footer: "x: Purgar  Esc/←: Volver",
+    freed: "liberado",
+    prune_title: " Purgar ",
+    maintenance_title: " Mantenimiento ",
+    maintenance_end: "Terminar mantenimiento",
+    prune_images: "Eliminar todas las imágenes sin contenedor",
+    prune_containers: "Eliminar todos los contenedores detenidos",
+    prune_volumes: "Eliminar todos los volúmenes sin contenedor",
+    prune_build_cache: "Eliminar toda la caché de build",
+    prune_footer: "Enter: Purgar  Esc: Cancelar",
+
+    prune_stopped_containers: "Contenedores detenidos",
+    prune_dangling_images: "Imágenes huérfanas",
+    prune_unused_volumes: "Volúmenes sin uso",
+    prune_unused_networks: "Redes sin uso",
+    prune_menu_confirm: "Enter de nuevo para purgar, Esc para cancelar",
+    pruned: "purgado",
+    removed: "eliminados",
+    prune_failed: "Error al purgar",
+
+    networks: "Redes",
+    header_scope: "Ámbito",
+    header_subnet: "Subred",
+    header_containers: "Contenedores",
+
+    files: "Archivos",
+    files_footer: "Enter/→: Abrir  ←: Subir  D: Descargar  Esc: Volver",
+    header_owner: "Propietario",
+    header_modified: "Modificado",
+    empty_directory: "Directorio vacío",
+    downloading: "Descargando…",
+    saved_to: "Guardado en",
+
+    environment: "Entorno",
+    env_footer_show: "/: Buscar  *: Mostrar secretos  Esc: Cerrar",
+    env_footer_hide: "/: Buscar  *: Ocultar secretos  Esc: Cerrar",
+
+    events: "Eventos",
+    no_events: "Aún no hay eventos",
+    shell_sessions: "Sesiones de shell",
+    shell_sessions_footer: "Enter: Conectar  Esc: Volver",
+    no_shell_sessions: "No hay sesiones de shell. Ctrl+] en una shell la desconecta y la mantiene en ejecución.",
+    header_container: "Contenedor",
+    header_started: "Iniciada",
+
+    notifications: "Notificaciones",
+    notifications_footer: "Enter: Confirmar  i: Detalles  a: Todas  x: Borrar  D: Borrar confirmadas  Esc: Volver",
+    no_notifications: "No hay notificaciones",
+    error_detail: "Error",
+    error_detail_footer: "y: Copiar  Esc: Volver",
+    unacknowledged: "sin confirmar",
+    header_time: "Hora",
+    header_source: "Origen",
+    header_message: "Mensaje",
+    action_failed: "falló",
+    retrying: "reintentando",
+    do_not_disturb: "no molestar",
+    maintenance: "mantenimiento",
+    stack: "stack",
+    loop_render: "dibujo",
+    loop_lag: "retraso",
+    loop_queue: "cola",
+    bulk_done: "hechas",
+    bulk_failed: "fallidas",
+    bulk_remaining: "pendientes",
+
+    copy_title: " Copiar ",
+    copy_full_id: "ID completo",
+    copy_name: "Nombre",
+    copy_host_id: "host:ID",
+    copy_footer: "Enter/y: Copiar  Esc: Cancelar",
+    copied: "Copiado",
+    copy_failed: "Error al copiar",
+
+    event_started: "iniciado",
+    event_exited: "detenido",
+    event_removed: "eliminado",
+    event_paused: "pausado",
+    event_unpaused: "reanudado",
+    event_renamed: "renombrado desde",
+    event_health: "salud",
+    event_exec: "exec",
+    event_logs_saved: "logs guardados",
+    event_logs_not_saved: "logs no guardados",
+
+    filtering: "Filtrando",
+    case_sensitive: "distingue mayúsculas",
+    whole_word: "palabra completa",
+
+    action_error: "{} el contenedor falló: {}",
+    action_error_attempts: "{} el contenedor falló tras {} intentos: {}",
+    host_client_failed: "No se pudo crear el cliente de Docker para el host '{}': {}",
+    host_filters_invalid: "No se pudieron leer los filtros del host '{}': {}",
+    host_ping_failed: "El ping al daemon de Docker del host '{}' falló: {}",
+    host_ping_timeout: "Sin respuesta al ping del daemon de Docker del host '{}' (>{}s)",
+    host_not_connected: "El host {} no está conectado",
+    no_download_dir: "Sin directorio de descargas",
+    download_failed: "Error al descargar {}: {}",
+    save_failed: "Error al guardar {}: {}",
+    archive_context_failed: "Error al empaquetar el contexto de compilación: {}",
+    archive_failed: "Error al empaquetar {}: {}",
+    build_path_missing: "Introduce un Dockerfile o la ruta del contexto de compilación",
+    home_dir_unknown: "No se pudo determinar el directorio personal",
+    no_dockerfile: "No hay Dockerfile en {}",
+    invalid_dockerfile_path: "Ruta de Dockerfile no válida: {}",
+    path_not_found: "{} no existe",
+    build_error: "Error de compilación: {}",
+    list_files_failed: "Error al listar los archivos: {}",
+    exec_detached: "El exec se inició en modo separado inesperadamente",
+    ls_exited: "ls terminó con {}",
+    fetch_sizes_failed: "Error al obtener el tamaño de los contenedores: {}",
+    fetch_image_history_failed: "Error al obtener el historial de la imagen: {}",
+    inspect_failed: "Error al inspeccionar el contenedor: {}",
+    pull_failed: "Error al descargar {}: {}",
+    push_failed: "Error al subir {}: {}",
+    list_networks_failed: "Error al listar las redes: {}",
+    connect_network_failed: "Error al conectar a la red {}: {}",
+    disconnect_network_failed: "Error al desconectar de la red {}: {}",
+    invalid_host_format: "Formato de host no válido: '{}'. Usa 'local', 'ssh://user@host[:port]', 'tcp://host:port' o 'tls://host:port'",
+    list_volumes_failed: "Error al listar los volúmenes: {}",
+    list_containers_failed: "Error al listar los contenedores: {}",
+    list_images_failed: "Error al listar las imágenes: {}",
+    list_processes_failed: "Error al listar los procesos: {}",
+    disk_usage_failed: "Error al obtener el uso de disco: {}",
+    no_disk_usage_totals: "El daemon de Docker no informa del uso total de disco",
+    remove_volume_failed: "Error al eliminar el volumen {}: {}",
+    remove_container_failed: "{}: error al eliminar el contenedor: {}",
+    remove_image_failed: "{}: error al eliminar la imagen: {}",
+    prune_error: "Error al limpiar: {}",
+    no_healthcheck: "No hay comprobación de salud configurada",
+    create_exec_failed: "Error al crear el exec: {}",
+    start_exec_failed: "Error al iniciar el exec: {}",
+    host_info_failed: "Error al leer la información del host: {}",
+    unknown_data_root: "Directorio de datos desconocido",
+    unexpected_output: "Salida inesperada: {}",
+    probe_create_failed: "Error al crear el contenedor de sondeo: {}",
+    probe_start_failed: "Error al iniciar el contenedor de sondeo: {}",
+    probe_failed: "El contenedor de sondeo falló: {}",
+    probe_output_failed: "Error al leer la salida del sondeo: {}",
+    shell_label_no_value: "la etiqueta de shell '{}' necesita un valor (clave=valor)",
+    shell_list_empty: "los shells de '{}' no pueden estar vacíos",
+    systemctl_failed: "Error al ejecutar systemctl: {}",
+    invalid_unit_name: "Nombre de unidad systemd no válido '{}'",
+    read_logs_failed: "Error al leer los logs del contenedor: {}",
+    create_failed: "Error al crear {}: {}",
+    write_failed: "Error al escribir {}: {}",
+    status_up: "Activo desde hace {}",
+    status_up_bare: "Activo",
+    status_healthy: "{} (saludable)",
+    status_unhealthy: "{} (no saludable)",
+    status_health_starting: "{} (salud: iniciando)",
+    status_paused: "{} (En pausa)",
+    status_restarting: "Reiniciando",
+    status_exited: "Terminado",
+    status_ago: "hace {}",
+    status_created: "Creado",
+    status_dead: "Muerto",
+    status_removing: "Eliminación en curso",
+    duration_under_second: "menos de un segundo",
+    duration_second: "1 segundo",
+    duration_seconds: "{} segundos",
+    duration_minute: "alrededor de un minuto",
+    duration_minutes: "{} minutos",
+    duration_hour: "alrededor de una hora",
+    duration_hours: "{} horas",
+    duration_days: "{} días",
+    duration_weeks: "{} semanas",
+    duration_months: "{} meses",
+    duration_years: "{} años",
+    relative_time: || boxup(Spanish),
+};
+
+static DE: Messages = Messages {
+    help_title: " Hilfe - ? oder ESC zum Schließen ",
+    navigation: "Navigation",
+    navigate_scroll: "Navigieren/scrollen (1 Zeile)",
+    view_logs: "Logs anzeigen",
+    exit_logs: "Logs verlassen",
+    action_menu: "Aktionsmenü",
+    close_menu: "Menü schließen",
+    toggle_help: "Hilfe ein/aus",
+    show_all: "Alle Container",
+    filter: "Filtern",
+    open_dozzle: "Dozzle öffnen",
+    open_port: "Veröffentlichten HTTP-Port im Browser öffnen",
+    export_list: "Liste exportieren (Markdown/HTML)",
+    log_scrolling: "Log-Ansicht scrollen",
+    top_bottom: "Anfang/Ende",
+    page_up: "Seite hoch",
+    page_down: "Seite runter",
+    list_rows: "Zeilen",
+    list_rows_of: "von",
+    sorting: "Sortierung",
+    uptime: "Laufzeit",
+    name: "Name",
+    cpu: "CPU",
+    cpus: "CPUs",
+    memory: "Speicher",
+    cycle: "Wechseln",
+    toggle_direction: "(erneut drücken für auf-/absteigend)",
+    status_icons: "Statussymbole",
+    healthy: "Gesund",
+    unhealthy: "Ungesund",
+    starting: "Startet",
+    running: "Läuft",
+    paused: "Pausiert",
+    exited: "Beendet",
+    colors: "Farben",
+    green: "Grün",
+    yellow: "Gelb",
+    red: "Rot",
+    level_low: "Niedrig",
+    level_medium: "Mittel",
+    level_high: "Hoch",
+
+    actions: "Aktionen",
+    action_footer: "↑/↓: Navigieren  Enter: Ausführen  Esc/←: Abbrechen",
+    action_footer_timeout: "↑/↓: Navigieren  Enter: Ausführen  T: Timeout  Esc: Abbrechen",
+    stop_timeout_label: "Sekunden bis zum Beenden",
+    stop_timeout_footer: "Enter: Ausführen  Esc: Abbrechen",
+    action_start: "Starten",
+    action_stop: "Stoppen",
+    action_restart: "Neu starten",
+    action_remove: "Entfernen",
+    action_shell: "Shell",
+    action_exec: "Befehl ausführen…",
+    action_browse_files: "Dateien durchsuchen",
+    action_healthcheck: "Healthcheck ausführen",
+    action_pull: "Image ziehen",
+    action_push: "Image hochladen",
+    action_connect_network: "Netzwerk verbinden",
+    action_disconnect_network: "Netzwerk trennen",
+    action_restart_unit: "systemd-Unit neu starten",
+    action_prune: "Host bereinigen…",
+    action_maintenance: "Host-Wartung…",
+    no_networks: "Keine Netzwerke verfügbar",
+
+    details_title: " Details ",
+    details_footer: "Esc/i: Schließen  f: Volle ID  y: Kopieren  Y: Labels kopieren",
+    clock_ahead: "Uhr geht vor um",
+    clock_behind: "Uhr geht nach um",
+    disk_nearly_full: "Festplatte fast voll",
+    high_load: "hohe Last",
+    memory_limit_in: "Speicherlimit in",
+    same_name_on_hosts: "Gleicher Name auch auf",
+    verify_stayed_up: "läuft noch nach",
+    verify_crashed: "abgestürzt nach",
+    verify_exit: "Exit",
+    label_disk: "Platte",
+    label_load: "Last",
+    label_free: "frei",
+    replicas_title: "Replikate",
+    replicas_footer: "Esc/v: Schließen",
+    plan_title: "Plan",
+    plan_footer: "Enter: Ausführen  Esc: Abbrechen",
+    no_replicas: "Kein anderer laufender Container teilt diesen Compose-Dienst oder dieses Image",
+    label_average: "Mittelwert",
+    build_title: " Image bauen ",
+    build_path: "Pfad",
+    build_tag: "Tag",
+    build_footer: "Tab: Feld wechseln  Enter: Bauen  Esc: Abbrechen",
+    build_output: "Build",
+    build_running: "[Baut...]",
+    build_succeeded: "[Fertig]",
+    build_failed: "[Fehlgeschlagen]",
+    exec_command_label: "Befehl",
+    exec_prompt_footer: "Enter: Ausführen  Esc: Abbrechen",
+    exec_output: "Exec",
+    exec_running: "[Läuft...]",
+    exec_exit_code: "Exit-Code",
+    exec_failed: "[Fehlgeschlagen]",
+    label_state: "Status",
+    label_health: "Zustand",
+    label_ports: "Ports",
+    label_labels: "Labels",
+    label_systemd_unit: "Unit",
+    label_time_to_healthy: "Gesund in",
+    label_oom_in: "OOM in",
+    label_image: "Image",
+
+    header_id: "ID",
+    header_name: "Name",
+    header_host: "Host",
+    header_status: "Status",
+    header_restarts: "Neustarts",
+    restart_loop: "Neustartschleife",
+    header_size: "Größe",
+    header_platform: "Plattform",
+    label_emulated: "emuliert",
+    header_cpu: "CPU %",
+    header_cpu_host: "CPU % Host",
+    header_memory: "Speicher %",
+    header_net_tx: "NetTx/s",
+    header_net_rx: "NetRx/s",
+    header_block_read: "BlkRead/s",
+    header_block_write: "BlkWrite/s",
+    header_pids: "PIDs",
+    header_created: "Erstellt",
+    containers: "Container",
+    export_title: "Exportieren",
+    export_copy_markdown: "Als Markdown kopieren",
+    export_copy_html: "Als HTML kopieren",
+    export_save_markdown: "Als Markdown-Datei speichern",
+    export_save_html: "Als HTML-Datei speichern",
+    export_copied: "Kopiert",
+    export_saved: "Gespeichert",
+    export_failed: "Export fehlgeschlagen",
+    title_help: "'?' Hilfe, 'q' Beenden",
+
+    logs: "Logs",
+    logs_return_hint: "ESC zum Zurückkehren",
+    image_history: "Image-Verlauf",
+    header_created_by: "Erstellt durch",
+    layers: "Schichten",
+    processes: "Prozesse",
+    header_pid: "PID",
+    header_user: "Benutzer",
+    header_command: "Befehl",
+    loading: "[Lädt...]",
+    following: "[FOLGEN]",
+    paused_logs: "[PAUSIERT]",
+    repeats_collapsed: "[GEFALTET]",
+    idle: "inaktiv",
+    search: "Suche",
+    no_matches: "keine Treffer",
+    unknown: "Unbekannt",
+    reconnecting: "verbinde neu",
+    attempt: "Versuch",
+
+    volumes: "Volumes",
+    header_driver: "Treiber",
+    header_mountpoint: "Einhängepunkt",
+    header_used_by: "Verwendet von",
+    orphaned: "verwaist",
+    reclaimable: "freigebbar",
+    volumes_footer: "x: Entfernen  D: Alle verwaisten entfernen  Esc/←: Zurück",
+    remove_orphans_title: " Verwaiste Volumes entfernen ",
+    remove_orphans_footer: "Enter: Entfernen  Esc: Abbrechen",
+
+    cleanup: "Aufräumen",
+    header_kind: "Art",
+    header_since: "Seit",
+    cleanup_exited_container: "beendeter Container",
+    cleanup_dangling_image: "verwaistes Image",
+    cleanup_unused_volume: "ungenutztes Volume",
+    cleanup_footer: "x: Entfernen  D: Alle dieser Art entfernen  Esc/←: Zurück",
+    cleanup_confirm_title: " Aufräumen ",
+    nothing_to_clean: "Nichts aufzuräumen",
+
+    disk_usage: "Speicherbelegung",
+    header_total: "Gesamt",
+    header_active: "Aktiv",
+    header_reclaimable: "Freigebbar",
+    disk_usage_images: "Images",
+    disk_usage_build_cache: "Build-Cache",
+    disk_usage_footer: "x: Bereinigen  Esc/←: Zurück",
+    freed: "freigegeben",
+    prune_title: " Bereinigen ",
+    maintenance_title: " Wartung ",
+    maintenance_end: "Wartung beenden",
+    prune_images: "Alle Images ohne Container entfernen",
+    prune_containers: "Alle gestoppten Container entfernen",
+    prune_volumes: "Alle Volumes ohne Container entfernen",
+    prune_build_cache: "Den gesamten Build-Cache entfernen",
+    prune_footer: "Enter: Bereinigen  Esc: Abbrechen",
+
+    prune_stopped_containers: "Gestoppte Container",
+    prune_dangling_images: "Verwaiste Images",
+    prune_unused_volumes: "Ungenutzte Volumes",
+    prune_unused_networks: "Ungenutzte Netzwerke",
+    prune_menu_confirm: "Erneut Enter zum Bereinigen, Esc zum Abbrechen",
+    pruned: "bereinigt",
+    removed: "entfernt",
+    prune_failed: "Bereinigen fehlgeschlagen",
+
+    networks: "Netzwerke",
+    header_scope: "Bereich",
+    header_subnet: "Subnetz",
+    header_containers: "Container",
+
+    files: "Dateien",
+    files_footer: "Enter/→: Öffnen  ←: Hoch  D: Herunterladen  Esc: Zurück",
+    header_owner: "Besitzer",
+    header_modified: "Geändert",
+    empty_directory: "Leeres Verzeichnis",
+    downloading: "Wird heruntergeladen…",
+    saved_to: "Gespeichert unter",
+
+    environment: "Umgebung",
+    env_footer_show: "/: Suchen  *: Geheimnisse zeigen  Esc: Schließen",
+    env_footer_hide: "/: Suchen  *: Geheimnisse verbergen  Esc: Schließen",
+
+    events: "Ereignisse",
+    no_events: "Noch keine Ereignisse",
+    shell_sessions: "Shell-Sitzungen",
+    shell_sessions_footer: "Enter: Verbinden  Esc: Zurück",
+    no_shell_sessions: "Keine Shell-Sitzungen. Ctrl+] in einer Shell trennt sie und lässt sie weiterlaufen.",
+    header_container: "Container",
+    header_started: "Gestartet",
+
+    notifications: "Benachrichtigungen",
+    notifications_footer: "Enter: Bestätigen  i: Details  a: Alle  x: Löschen  D: Bestätigte löschen  Esc: Zurück",
+    no_notifications: "Keine Benachrichtigungen",
+    error_detail: "Fehler",
+    error_detail_footer: "y: Kopieren  Esc: Zurück",
+    unacknowledged: "unbestätigt",
+    header_time: "Zeit",
+    header_source: "Quelle",
+    header_message: "Meldung",
+    action_failed: "fehlgeschlagen",
+    retrying: "neuer Versuch",
+    do_not_disturb: "nicht stören",
+    maintenance: "Wartung",
+    stack: "Stack",
+    loop_render: "Zeichnen",
+    loop_lag: "Verzögerung",
+    loop_queue: "Warteschlange",
+    bulk_done: "erledigt",
+    bulk_failed: "fehlgeschlagen",
+    bulk_remaining: "ausstehend",
+
+    copy_title: " Kopieren ",
+    copy_full_id: "Volle ID",
+    copy_name: "Name",
+    copy_host_id: "Host:ID",
+    copy_footer: "Enter/y: Kopieren  Esc: Abbrechen",
+    copied: "Kopiert",
+    copy_failed: "Kopieren fehlgeschlagen",
+
+    event_started: "gestartet",
+    event_exited: "beendet",
+    event_removed: "entfernt",
+    event_paused: "pausiert",
+    event_unpaused: "fortgesetzt",
+    event_renamed: "umbenannt von",
+    event_health: "Health",
+    event_exec: "exec",
+    event_logs_saved: "Logs gespeichert",
+    event_logs_not_saved: "Logs nicht gespeichert",
+
+    filtering: "Filter",
+    case_sensitive: "Groß-/Kleinschreibung",
+    whole_word: "ganzes Wort",
+
+    action_error: "{} des Containers fehlgeschlagen: {}",
+    action_error_attempts: "{} des Containers nach {} Versuchen fehlgeschlagen: {}",
+    host_client_failed: "Docker-Client für Host '{}' konnte nicht erstellt werden: {}",
+    host_filters_invalid: "Filter für Host '{}' konnten nicht gelesen werden: {}",
+    host_ping_failed: "Ping des Docker-Daemons auf Host '{}' fehlgeschlagen: {}",
+    host_ping_timeout: "Zeitüberschreitung beim Ping des Docker-Daemons auf Host '{}' (>{}s)",
+    host_not_connected: "Host {} ist nicht verbunden",
+    no_download_dir: "Kein Download-Verzeichnis",
+    download_failed: "Download von {} fehlgeschlagen: {}",
+    save_failed: "{} konnte nicht gespeichert werden: {}",
+    archive_context_failed: "Build-Kontext konnte nicht archiviert werden: {}",
+    archive_failed: "{} konnte nicht archiviert werden: {}",
+    build_path_missing: "Dockerfile oder Pfad des Build-Kontexts eingeben",
+    home_dir_unknown: "Home-Verzeichnis konnte nicht ermittelt werden",
+    no_dockerfile: "Kein Dockerfile in {}",
+    invalid_dockerfile_path: "Ungültiger Dockerfile-Pfad: {}",
+    path_not_found: "{} existiert nicht",
+    build_error: "Build fehlgeschlagen: {}",
+    list_files_failed: "Dateien konnten nicht aufgelistet werden: {}",
+    exec_detached: "Exec wurde unerwartet im Hintergrund gestartet",
+    ls_exited: "ls beendet mit {}",
+    fetch_sizes_failed: "Containergrößen konnten nicht abgerufen werden: {}",
+    fetch_image_history_failed: "Image-Verlauf konnte nicht abgerufen werden: {}",
+    inspect_failed: "Container konnte nicht untersucht werden: {}",
+    pull_failed: "Pull von {} fehlgeschlagen: {}",
+    push_failed: "Push von {} fehlgeschlagen: {}",
+    list_networks_failed: "Netzwerke konnten nicht aufgelistet werden: {}",
+    connect_network_failed: "Verbindung mit Netzwerk {} fehlgeschlagen: {}",
+    disconnect_network_failed: "Trennen von Netzwerk {} fehlgeschlagen: {}",
+    invalid_host_format: "Ungültiges Host-Format: '{}'. Verwende 'local', 'ssh://user@host[:port]', 'tcp://host:port' oder 'tls://host:port'",
+    list_volumes_failed: "Volumes konnten nicht aufgelistet werden: {}",
+    list_containers_failed: "Container konnten nicht aufgelistet werden: {}",
+    list_images_failed: "Images konnten nicht aufgelistet werden: {}",
+    list_processes_failed: "Prozesse konnten nicht aufgelistet werden: {}",
+    disk_usage_failed: "Speicherbelegung konnte nicht abgerufen werden: {}",
+    no_disk_usage_totals: "Der Docker-Daemon meldet keine Gesamtspeicherbelegung",
+    remove_volume_failed: "Volume {} konnte nicht entfernt werden: {}",
+    remove_container_failed: "{}: Container konnte nicht entfernt werden: {}",
+    remove_image_failed: "{}: Image konnte nicht entfernt werden: {}",
+    prune_error: "Bereinigen fehlgeschlagen: {}",
+    no_healthcheck: "Kein Health-Check konfiguriert",
+    create_exec_failed: "Exec konnte nicht erstellt werden: {}",
+    start_exec_failed: "Exec konnte nicht gestartet werden: {}",
+    host_info_failed: "Host-Informationen konnten nicht gelesen werden: {}",
+    unknown_data_root: "Unbekanntes Datenverzeichnis",
+    unexpected_output: "Unerwartete Ausgabe: {}",
+    probe_create_failed: "Prüfcontainer konnte nicht erstellt werden: {}",
+    probe_start_failed: "Prüfcontainer konnte nicht gestartet werden: {}",
+    probe_failed: "Prüfcontainer fehlgeschlagen: {}",
+    probe_output_failed: "Ausgabe des Prüfcontainers konnte nicht gelesen werden: {}",
+    shell_label_no_value: "Shell-Label '{}' braucht einen Wert (Schlüssel=Wert)",
+    shell_list_empty: "Shells für '{}' dürfen nicht leer sein",
+    systemctl_failed: "systemctl konnte nicht ausgeführt werden: {}",
+    invalid_unit_name: "Ungültiger systemd-Unit-Name '{}'",
+    read_logs_failed: "Container-Logs konnten nicht gelesen werden: {}",
+    create_failed: "{} konnte nicht erstellt werden: {}",
+    write_failed: "{} konnte nicht geschrieben werden: {}",
+    status_up: "Läuft seit {}",
+    status_up_bare: "Läuft",
+    status_healthy: "{} (gesund)",
+    status_unhealthy: "{} (ungesund)",
+    status_health_starting: "{} (Zustand: startet)",
+    status_paused: "{} (Pausiert)",
+    status_restarting: "Neustart",
+    status_exited: "Beendet",
+    status_ago: "vor {}",
+    status_created: "Erstellt",
+    status_dead: "Tot",
+    status_removing: "Wird entfernt",
+    duration_under_second: "weniger als einer Sekunde",
+    duration_second: "1 Sekunde",
+    duration_seconds: "{} Sekunden",
+    duration_minute: "etwa einer Minute",
+    duration_minutes: "{} Minuten",
+    duration_hour: "etwa einer Stunde",
+    duration_hours: "{} Stunden",
+    duration_days: "{} Tagen",
+    duration_weeks: "{} Wochen",
+    duration_months: "{} Monaten",
+    duration_years: "{} Jahren",
+    relative_time: || boxup(German),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!("en".parse::<Locale>().unwrap(), Locale::En);
+        assert_eq!("ES".parse::<Locale>().unwrap(), Locale::Es);
+        assert_eq!("de_DE.UTF-8".parse::<Locale>().unwrap(), Locale::De);
+        assert_eq!("es-MX".parse::<Locale>().unwrap(), Locale::Es);
+        assert_eq!("C".parse::<Locale>().unwrap(), Locale::En);
+        assert_eq!("C.UTF-8".parse::<Locale>().unwrap(), Locale::En);
+    }
+
+    #[test]
+    fn test_parse_unsupported_locale() {
+        let result = "fr_FR.UTF-8".parse::<Locale>();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("fr_FR.UTF-8"));
+    }
+
+    #[test]
+    fn test_default_is_english() {
+        assert_eq!(Locale::default().messages().header_created, "Created");
+    }
+
+    #[test]
+    fn test_action_labels() {
+        assert_eq!(
+            Locale::De.messages().action(ContainerAction::Stop),
+            "Stoppen"
+        );
+        assert_eq!(
+            Locale::En.messages().action(ContainerAction::Restart),
+            "Restart"
+        );
+    }
+
+    #[test]
+    fn test_fill_template() {
+        assert_eq!(
+            fill(Locale::En.messages().action_error, &[&"Stop", &"boom"]),
+            "Stop container failed: boom"
+        );
+        assert_eq!(
+            fill(Locale::De.messages().host_not_connected, &[&"prod"]),
+            "Host prod ist nicht verbunden"
+        );
+        assert_eq!(fill("{} and {}", &[&1]), "1 and ");
+    }
+
+    #[test]
+    fn test_error_templates_match_across_locales() {
+        let templates = |msg: &Messages| {
+            [
+                msg.action_error,
+                msg.action_error_attempts,
+                msg.host_client_failed,
+                msg.host_filters_invalid,
+                msg.host_ping_failed,
+                msg.host_ping_timeout,
+                msg.host_not_connected,
+                msg.no_download_dir,
+                msg.download_failed,
+                msg.save_failed,
+                msg.archive_context_failed,
+                msg.archive_failed,
+                msg.build_path_missing,
+                msg.home_dir_unknown,
+                msg.no_dockerfile,
+                msg.invalid_dockerfile_path,
+                msg.path_not_found,
+                msg.build_error,
+                msg.list_files_failed,
+                msg.exec_detached,
+                msg.ls_exited,
+                msg.fetch_sizes_failed,
+                msg.fetch_image_history_failed,
+                msg.inspect_failed,
+                msg.pull_failed,
+                msg.push_failed,
+                msg.list_networks_failed,
+                msg.connect_network_failed,
+                msg.disconnect_network_failed,
+                msg.invalid_host_format,
+                msg.list_volumes_failed,
+                msg.list_containers_failed,
+                msg.list_images_failed,
+                msg.list_processes_failed,
+                msg.disk_usage_failed,
+                msg.no_disk_usage_totals,
+                msg.remove_volume_failed,
+                msg.remove_container_failed,
+                msg.remove_image_failed,
+                msg.prune_error,
+                msg.no_healthcheck,
+                msg.create_exec_failed,
+                msg.start_exec_failed,
+                msg.host_info_failed,
+                msg.unknown_data_root,
+                msg.unexpected_output,
+                msg.probe_create_failed,
+                msg.probe_start_failed,
+                msg.probe_failed,
+                msg.probe_output_failed,
+                msg.shell_label_no_value,
+                msg.shell_list_empty,
+                msg.systemctl_failed,
+                msg.invalid_unit_name,
+                msg.read_logs_failed,
+                msg.create_failed,
+                msg.write_failed,
+                msg.status_up,
+                msg.status_up_bare,
+                msg.status_healthy,
+                msg.status_unhealthy,
+                msg.status_health_starting,
+                msg.status_paused,
+                msg.status_restarting,
+                msg.status_exited,
+                msg.status_ago,
+                msg.status_created,
+                msg.status_dead,
+                msg.status_removing,
+                msg.duration_under_second,
+                msg.duration_second,
+                msg.duration_seconds,
+                msg.duration_minute,
+                msg.duration_minutes,
+                msg.duration_hour,
+                msg.duration_hours,
+                msg.duration_days,
+                msg.duration_weeks,
+                msg.duration_months,
+                msg.duration_years,
+            ]
+            .map(|template| template.matches("{}").count())
+        };
+        let english = templates(Locale::En.messages());
+        assert_eq!(templates(Locale::Es.messages()), english);
+        assert_eq!(templates(Locale::De.messages()), english);
+    }
+}
@@ -97,6 +97,14 @@ impl Icons {
         }
     }
 
+    /// Get icon for a container stuck in a restart loop
+    pub fn restart_loop(&self) -> &'static str {
+        match self.style {
+            IconStyle::Unicode => "∞",
+            IconStyle::Nerd => "\u{f0e2}", // nf-fa-rotate_left
+        }
+    }
+
     /// Get icon for container action
     pub fn action(&self, action: ContainerAction) -> &'static str {
         match self.style {
@@ -106,13 +114,33 @@ impl Icons {
                 ContainerAction::Restart => "↻",
                 ContainerAction::Remove => "✕",
                 ContainerAction::Shell => ">_",
+                ContainerAction::ExecCommand => "$",
+                ContainerAction::BrowseFiles => "▤",
+                ContainerAction::RunHealthcheck => "♥",
+                ContainerAction::PullImage => "↓",
+                ContainerAction::PushImage => "↑",
+                ContainerAction::ConnectNetwork => "⇄",
+                ContainerAction::DisconnectNetwork => "⇹",
+                ContainerAction::RestartUnit => "⟳",
+                ContainerAction::Prune => "✂",
+                ContainerAction::Maintenance => "⚒",
             },
             IconStyle::Nerd => match action {
-                ContainerAction::Start => "\u{f04b}",   // nf-fa-play
-                ContainerAction::Stop => "\u{f04d}",    // nf-fa-stop
-                ContainerAction::Restart => "\u{f01e}", // nf-fa-refresh
-                ContainerAction::Remove => "\u{f1f8}",  // nf-fa-trash
-                ContainerAction::Shell => "\u{f120}",   // nf-fa-terminal
+                ContainerAction::Start => "\u{f04b}",             // nf-fa-play
+                ContainerAction::Stop => "\u{f04d}",              // nf-fa-stop
+                ContainerAction::Restart => "\u{f01e}",           // nf-fa-refresh
+                ContainerAction::Remove => "\u{f1f8}",            // nf-fa-trash
+                ContainerAction::Shell => "\u{f120}",             // nf-fa-terminal
+                ContainerAction::ExecCommand => "\u{f121}",       // nf-fa-code
+                ContainerAction::BrowseFiles => "\u{f07c}",       // nf-fa-folder_open
+                ContainerAction::RunHealthcheck => "\u{f21e}",    // nf-fa-heartbeat
+                ContainerAction::PullImage => "\u{f019}",         // nf-fa-download
+                ContainerAction::PushImage => "\u{f093}",         // nf-fa-upload
+                ContainerAction::ConnectNetwork => "\u{f0c1}",    // nf-fa-link
+                ContainerAction::DisconnectNetwork => "\u{f127}", // nf-fa-chain_broken
+                ContainerAction::RestartUnit => "\u{f013}",       // nf-fa-cog
+                ContainerAction::Prune => "\u{f0c4}",             // nf-fa-scissors
+                ContainerAction::Maintenance => "\u{f0ad}",       // nf-fa-wrench
             },
         }
     }
@@ -0,0 +1,133 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    text::{Line, Span},
+    widgets::{Block, Cell, Paragraph, Row, Table, Wrap},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::ImageLayer;
+use crate::ui::formatters::{format_bytes, format_time_elapsed, format_timestamp};
+use crate::ui::i18n;
+
+use super::render::UiStyles;
+
+/// Renders the layer history of the viewed container's image
+pub fn render_image_history(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    let Some(history) = &state.image_history else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    // Summary after the image name once the layers are in
+    let summary = match &history.layers {
+        Some(layers) => {
+            let total: i64 = layers.iter().map(|layer| layer.size.max(0)).sum();
+            format!(
+                " ({} {}, {})",
+                layers.len(),
+                msg.layers,
+                format_bytes(total as u64)
+            )
+        }
+        None if history.error.is_none() => format!(" {}", msg.loading),
+        None => String::new(),
+    };
+
+    let block = Block::default()
+        .title(format!(
+            "{}: {}{} - {}",
+            msg.image_history, history.image, summary, msg.logs_return_hint
+        ))
+        .style(styles.border);
+
+    if let Some(error) = &history.error {
+        let paragraph = Paragraph::new(error.as_str())
+            .style(styles.high)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let layers = history.layers.as_deref().unwrap_or_default();
+    let rows = layers
+        .iter()
+        .skip(history.scroll_offset)
+        .map(|layer| layer_row(layer, state, styles));
+
+    let header = Row::new(vec![
+        Cell::from(msg.header_created),
+        Cell::from(Line::from(msg.header_size).right_aligned()),
+        Cell::from(msg.header_created_by),
+    ])
+    .style(styles.header);
+
+    let created_width = if state.absolute_timestamps { 20 } else { 15 };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(created_width),
+            Constraint::Length(9),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    f.render_widget(table, area);
+}
+
+/// Builds the row for a single layer
+fn layer_row(layer: &ImageLayer, state: &AppState, styles: &UiStyles) -> Row<'static> {
+    let created = match (&layer.created, state.absolute_timestamps) {
+        (Some(created), true) => format_timestamp(created, &state.timestamp_format),
+        (created, _) => format_time_elapsed(created.as_ref()),
+    };
+
+    Row::new(vec![
+        Cell::from(created).style(styles.created),
+        Cell::from(Line::from(format_bytes(layer.size.max(0) as u64)).right_aligned()),
+        Cell::from(layer_line(layer, styles)),
+    ])
+}
+
+/// Tags of the layer (if any) followed by the instruction that created it
+fn layer_line(layer: &ImageLayer, styles: &UiStyles) -> Line<'static> {
+    let mut spans = Vec::new();
+    if !layer.tags.is_empty() {
+        spans.push(Span::styled(
+            format!("[{}] ", layer.tags.join(", ")),
+            styles.container_id,
+        ));
+    }
+    spans.push(Span::raw(layer_command(&layer.created_by)));
+    Line::from(spans)
+}
+
+/// Shortens a layer's `CreatedBy` to the Dockerfile instruction
+fn layer_command(created_by: &str) -> String {
+    // Legacy builder metadata instructions: "/bin/sh -c #(nop)  CMD [...]"
+    let command = created_by
+        .strip_prefix("/bin/sh -c #(nop)")
+        .unwrap_or(created_by);
+    command.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_command() {
+        assert_eq!(
+            layer_command("/bin/sh -c #(nop)  CMD [\"nginx\" \"-g\"]"),
+            "CMD [\"nginx\" \"-g\"]"
+        );
+        assert_eq!(
+            layer_command("RUN /bin/sh -c apt-get update \t&& apt-get install -y curl"),
+            "RUN /bin/sh -c apt-get update && apt-get install -y curl"
+        );
+    }
+}
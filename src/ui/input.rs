@@ -4,6 +4,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use crate::core::types::{AppEvent, EventSender, SortField};
+use crate::docker::logs::LogLevel;
 
 /// Polls for keyboard input and terminal events
 /// Sends events for various key presses, mouse events, and terminal resize
@@ -15,8 +16,11 @@ pub fn keyboard_worker(tx: EventSender, paused: Arc<AtomicBool>) {
             continue;
         }
 
-        // Poll every 200ms - humans won't notice the difference
+        // Poll every 200ms - humans won't notice the difference.
+        // Check the pause again after polling: a shell session may have started
+        // meanwhile, and its keys and resizes must go to the container
         if event::poll(Duration::from_millis(200)).unwrap_or(false)
+            && !paused.load(Ordering::Relaxed)
             && let Ok(event) = event::read()
         {
             match event {
@@ -26,6 +30,12 @@ pub fn keyboard_worker(tx: EventSender, paused: Arc<AtomicBool>) {
                 Event::Resize(_, _) => {
                     let _ = tx.blocking_send(AppEvent::Resize);
                 }
+                Event::FocusGained => {
+                    let _ = tx.blocking_send(AppEvent::FocusChanged(true));
+                }
+                Event::FocusLost => {
+                    let _ = tx.blocking_send(AppEvent::FocusChanged(false));
+                }
                 _ => {}
             }
         }
@@ -55,6 +65,10 @@ fn handle_key_event(key: KeyEvent, tx: &EventSender) {
         KeyCode::Char('d') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
             let _ = tx.blocking_send(AppEvent::ScrollPageDown);
         }
+        // Ctrl+S to export the container list
+        KeyCode::Char('s') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+            let _ = tx.blocking_send(AppEvent::ShowExportMenu);
+        }
         KeyCode::Char('/') => {
             let _ = tx.blocking_send(AppEvent::EnterSearchMode);
         }
@@ -81,17 +95,113 @@ fn handle_key_event(key: KeyEvent, tx: &EventSender) {
         KeyCode::Char('o') => {
             let _ = tx.blocking_send(AppEvent::OpenDozzle);
         }
+        KeyCode::Char('e') => {
+            let _ = tx.blocking_send(AppEvent::OpenPort);
+        }
+        KeyCode::Char('!') => {
+            let _ = tx.blocking_send(AppEvent::ShowNotifications);
+        }
+        KeyCode::Char('Z') => {
+            let _ = tx.blocking_send(AppEvent::ToggleDoNotDisturb);
+        }
         KeyCode::Char('?') => {
             let _ = tx.blocking_send(AppEvent::ToggleHelp);
         }
+        KeyCode::F(12) => {
+            let _ = tx.blocking_send(AppEvent::ToggleLoopMetrics);
+        }
+        KeyCode::Char('i') => {
+            let _ = tx.blocking_send(AppEvent::ToggleDetails);
+            let _ = tx.blocking_send(AppEvent::ShowErrorDetail);
+        }
+        KeyCode::Char('v') => {
+            let _ = tx.blocking_send(AppEvent::ToggleReplicas);
+        }
+        KeyCode::Char('T') => {
+            let _ = tx.blocking_send(AppEvent::PromptStopTimeout);
+            let _ = tx.blocking_send(AppEvent::ShowProcessList);
+        }
+        KeyCode::Char('t') => {
+            let _ = tx.blocking_send(AppEvent::ToggleAbsoluteTimestamps);
+        }
+        KeyCode::Char('%') => {
+            let _ = tx.blocking_send(AppEvent::ToggleCpuNormalized);
+        }
+        KeyCode::Char('#') => {
+            let _ = tx.blocking_send(AppEvent::CycleMemoryDisplay);
+        }
+        KeyCode::Char('B') => {
+            let _ = tx.blocking_send(AppEvent::ShowBuildDialog);
+        }
+        KeyCode::Char('V') => {
+            let _ = tx.blocking_send(AppEvent::ShowVolumes);
+        }
+        KeyCode::Char('X') => {
+            let _ = tx.blocking_send(AppEvent::ShowCleanup);
+        }
+        KeyCode::Char('F') => {
+            let _ = tx.blocking_send(AppEvent::ShowDiskUsage);
+        }
+        KeyCode::Char('D') => {
+            let _ = tx.blocking_send(AppEvent::RemoveOrphanedVolumes);
+            let _ = tx.blocking_send(AppEvent::RemoveCleanupKind);
+            let _ = tx.blocking_send(AppEvent::ClearAcknowledgedNotifications);
+            let _ = tx.blocking_send(AppEvent::DownloadSelectedFile);
+        }
+        KeyCode::Char('x') => {
+            let _ = tx.blocking_send(AppEvent::RemoveSelectedVolume);
+            let _ = tx.blocking_send(AppEvent::RemoveCleanupSelected);
+            let _ = tx.blocking_send(AppEvent::PruneDiskUsageSelected);
+            let _ = tx.blocking_send(AppEvent::ClearNotification);
+        }
+        KeyCode::Char('p') => {
+            let _ = tx.blocking_send(AppEvent::ToggleGroupByProject);
+        }
+        KeyCode::Char('W') => {
+            let _ = tx.blocking_send(AppEvent::ShowNetworks);
+        }
+        KeyCode::Char('E') => {
+            let _ = tx.blocking_send(AppEvent::ShowTimeline);
+        }
+        KeyCode::Char('S') => {
+            let _ = tx.blocking_send(AppEvent::ShowShellSessions);
+        }
+        KeyCode::Char('H') => {
+            let _ = tx.blocking_send(AppEvent::ShowImageHistory);
+        }
+        KeyCode::Char('$') => {
+            let _ = tx.blocking_send(AppEvent::ShowEnvVars);
+        }
+        KeyCode::Char('*') => {
+            let _ = tx.blocking_send(AppEvent::ToggleSecrets);
+        }
+        KeyCode::Char('I') => {
+            let _ = tx.blocking_send(AppEvent::ToggleIdColumn);
+        }
+        KeyCode::Char('f') => {
+            let _ = tx.blocking_send(AppEvent::ToggleFullId);
+            let _ = tx.blocking_send(AppEvent::ToggleFollowLogs);
+        }
+        KeyCode::Char('y') => {
+            let _ = tx.blocking_send(AppEvent::ShowCopyMenu);
+            let _ = tx.blocking_send(AppEvent::CopyErrorDetail);
+        }
+        KeyCode::Char('Y') => {
+            let _ = tx.blocking_send(AppEvent::CopyLabels);
+        }
         KeyCode::Char('s') => {
             let _ = tx.blocking_send(AppEvent::CycleSortField);
         }
         KeyCode::Char('u') | KeyCode::Char('U') => {
             let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Uptime));
         }
-        KeyCode::Char('n') | KeyCode::Char('N') => {
+        KeyCode::Char('n') => {
             let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Name));
+            let _ = tx.blocking_send(AppEvent::LogSearchNext);
+        }
+        KeyCode::Char('N') => {
+            let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Name));
+            let _ = tx.blocking_send(AppEvent::LogSearchPrevious);
         }
         KeyCode::Char('c') | KeyCode::Char('C') => {
             let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Cpu));
@@ -99,8 +209,24 @@ fn handle_key_event(key: KeyEvent, tx: &EventSender) {
         KeyCode::Char('m') | KeyCode::Char('M') => {
             let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Memory));
         }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Restarts));
+        }
+        KeyCode::Char('O') => {
+            let _ = tx.blocking_send(AppEvent::SetSortField(SortField::BlockRead));
+        }
+        KeyCode::Char('w') => {
+            let _ = tx.blocking_send(AppEvent::SetSortField(SortField::BlockWrite));
+        }
+        KeyCode::Char('P') => {
+            let _ = tx.blocking_send(AppEvent::SetSortField(SortField::Pids));
+        }
+        KeyCode::Char('z') => {
+            let _ = tx.blocking_send(AppEvent::RefreshContainerSizes);
+        }
         KeyCode::Char('a') | KeyCode::Char('A') => {
             let _ = tx.blocking_send(AppEvent::ToggleShowAll);
+            let _ = tx.blocking_send(AppEvent::AcknowledgeAllNotifications);
         }
         KeyCode::Right | KeyCode::Char('l') => {
             let _ = tx.blocking_send(AppEvent::ShowLogView);
@@ -108,6 +234,14 @@ fn handle_key_event(key: KeyEvent, tx: &EventSender) {
         KeyCode::Left | KeyCode::Char('h') => {
             let _ = tx.blocking_send(AppEvent::ExitLogView);
         }
+        KeyCode::Char('d') => {
+            let _ = tx.blocking_send(AppEvent::ToggleCollapseRepeats);
+        }
+        // 1-4 hide or show a level in the log view
+        KeyCode::Char(digit @ '1'..='4') => {
+            let idx = digit as usize - '1' as usize;
+            let _ = tx.blocking_send(AppEvent::ToggleLogLevel(LogLevel::ALL[idx]));
+        }
         // g for scroll to top (vim/less style)
         KeyCode::Char('g') => {
             let _ = tx.blocking_send(AppEvent::ScrollToTop);
@@ -116,6 +250,18 @@ fn handle_key_event(key: KeyEvent, tx: &EventSender) {
         KeyCode::Char('G') => {
             let _ = tx.blocking_send(AppEvent::ScrollToBottom);
         }
+        KeyCode::Home => {
+            let _ = tx.blocking_send(AppEvent::ScrollToTop);
+        }
+        KeyCode::End => {
+            let _ = tx.blocking_send(AppEvent::ScrollToBottom);
+        }
+        KeyCode::PageUp => {
+            let _ = tx.blocking_send(AppEvent::ScrollPageUp);
+        }
+        KeyCode::PageDown => {
+            let _ = tx.blocking_send(AppEvent::ScrollPageDown);
+        }
         // Space for page down (less style)
         KeyCode::Char(' ') => {
             let _ = tx.blocking_send(AppEvent::ScrollPageDown);
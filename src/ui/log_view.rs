@@ -1,36 +1,135 @@
-use chrono::Local;
+use chrono::{Duration, Local};
 use ratatui::{
     Frame,
-    style::{Color, Modifier, Style},
+    layout::Rect,
+    style::{Modifier, Style},
     text::{Line, Span, Text},
     widgets::{Block, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
 };
 
 use crate::core::app_state::AppState;
-use crate::core::types::ContainerKey;
-use crate::docker::logs::LogEntry;
+use crate::core::search_query::SearchQuery;
+use crate::core::types::{ContainerKey, LogState};
+use crate::docker::logs::{LogEntry, LogLevel};
+use crate::ui::i18n;
 
 use super::render::UiStyles;
 
-/// Style for log timestamps (yellow + bold)
-const TIMESTAMP_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+/// Minimum silence between two log entries that gets a separator line
+const LOG_GAP_THRESHOLD: Duration = Duration::minutes(10);
 
-/// Format a log entry into a Line with timestamp and ANSI-parsed content
-fn format_log_entry(log_entry: &LogEntry) -> Line<'static> {
+/// Format a log entry into a Line with timestamp and ANSI-parsed content,
+/// with the matches of an applied search highlighted
+fn format_log_entry(
+    log_entry: &LogEntry,
+    query: Option<&SearchQuery>,
+    styles: &UiStyles,
+) -> Line<'static> {
     let local_timestamp = log_entry.timestamp.with_timezone(&Local);
     let timestamp_str = local_timestamp.format("%Y-%m-%d %H:%M:%S").to_string();
 
     // Create a line with timestamp + ANSI-parsed content
-    let mut line_spans = vec![Span::styled(timestamp_str, TIMESTAMP_STYLE), Span::raw(" ")];
+    let mut line_spans = vec![
+        Span::styled(timestamp_str, styles.log_timestamp),
+        Span::raw(" "),
+    ];
 
-    // Append all spans from the ANSI-parsed text (should be a single line)
-    if let Some(text_line) = log_entry.text.lines.first() {
-        line_spans.extend(text_line.spans.iter().cloned());
+    // Uncolored text takes the color of its level
+    let level_style = log_entry.level.and_then(|level| level_style(level, styles));
+    let spans = message_spans(log_entry, level_style);
+    match query {
+        Some(query) => line_spans.extend(highlight_matches(&spans, query)),
+        None => line_spans.extend(spans),
     }
 
     Line::from(line_spans)
 }
 
+/// The spans of the ANSI-parsed text (a single line), with `fallback` applied to
+/// the uncolored ones
+pub fn message_spans(log_entry: &LogEntry, fallback: Option<Style>) -> Vec<Span<'static>> {
+    let Some(text_line) = log_entry.text.lines.first() else {
+        return Vec::new();
+    };
+
+    text_line
+        .spans
+        .iter()
+        .map(|span| match fallback {
+            Some(style) if span.style == Style::default() => span.clone().style(style),
+            _ => span.clone(),
+        })
+        .collect()
+}
+
+/// Color for messages of a level (info keeps the default)
+pub fn level_style(level: LogLevel, styles: &UiStyles) -> Option<Style> {
+    match level {
+        LogLevel::Error => Some(styles.high),
+        LogLevel::Warn => Some(styles.medium),
+        LogLevel::Info => None,
+        LogLevel::Debug => Some(Style::default().add_modifier(Modifier::DIM)),
+    }
+}
+
+/// Splits the spans at the search matches and reverses the colors of the matched parts
+fn highlight_matches(spans: &[Span<'static>], query: &SearchQuery) -> Vec<Span<'static>> {
+    let text: String = spans.iter().map(|span| span.content.as_ref()).collect();
+    let matches: Vec<_> = query.find_iter(&text).collect();
+    if matches.is_empty() {
+        return spans.to_vec();
+    }
+
+    let mut highlighted = Vec::with_capacity(spans.len() + matches.len() * 2);
+    let mut span_start = 0;
+    for span in spans {
+        let span_end = span_start + span.content.len();
+        let mut pos = span_start;
+        for range in matches
+            .iter()
+            .filter(|range| range.start < span_end && range.end > span_start)
+        {
+            let start = range.start.max(span_start);
+            let end = range.end.min(span_end);
+            if start > pos {
+                highlighted.push(Span::styled(text[pos..start].to_string(), span.style));
+            }
+            highlighted.push(Span::styled(
+                text[start..end].to_string(),
+                span.style.add_modifier(Modifier::REVERSED),
+            ));
+            pos = end;
+        }
+        if pos < span_end {
+            highlighted.push(Span::styled(text[pos..span_end].to_string(), span.style));
+        }
+        span_start = span_end;
+    }
+    highlighted
+}
+
+/// Separator line for a long silence between two log entries ("— 2h idle —")
+fn gap_separator(previous: &LogEntry, next: &LogEntry, styles: &UiStyles) -> Option<Line<'static>> {
+    let gap = next.timestamp - previous.timestamp;
+    if gap < LOG_GAP_THRESHOLD {
+        return None;
+    }
+
+    let text = format!("— {} {} —", format_gap(gap), i18n::messages().idle);
+    Some(Line::styled(text, styles.log_timestamp).centered())
+}
+
+/// Compact gap duration ("45m", "2h", "3d")
+fn format_gap(gap: Duration) -> String {
+    if gap.num_hours() < 1 {
+        format!("{}m", gap.num_minutes())
+    } else if gap.num_days() < 1 {
+        format!("{}h", gap.num_hours())
+    } else {
+        format!("{}d", gap.num_days())
+    }
+}
+
 /// Renders the log view for a specific container
 pub fn render_log_view(
     f: &mut Frame,
@@ -50,12 +149,14 @@ pub fn render_log_view(
         return;
     }
 
+    let msg = i18n::messages();
+
     // Get container info
     let container_name = state
         .containers
         .get(container_key)
         .map(|c| c.name.as_str())
-        .unwrap_or("Unknown");
+        .unwrap_or(msg.unknown);
 
     // Get number of log entries
     let num_lines = log_state.log_entries.len();
@@ -68,55 +169,103 @@ pub fn render_log_view(
 
     // Calculate max scroll position (first line that can be at top of viewport)
     // If we have 100 lines and can show 20, max_scroll is 80 (lines 80-99 visible)
-    let max_scroll = num_lines.saturating_sub(visible_height);
+    // Entries hidden by the level filter don't take rows
+    let max_scroll = log_state.last_page_start(visible_height);
 
     // Determine actual scroll offset
-    let actual_scroll = if state.is_at_bottom {
+    let actual_scroll = if state.follow_logs {
         // Auto-scroll to bottom
         max_scroll
     } else {
         // Use manual scroll position, but clamp to max
-        log_state.scroll_offset.min(max_scroll)
+        // Reaching the bottom doesn't resume following, new lines stay below until 'f' or G
+        let top = if log_state.is_shown(log_state.scroll_offset) {
+            log_state.scroll_offset
+        } else {
+            log_state.step_shown(log_state.scroll_offset, 1)
+        };
+        top.min(max_scroll)
     };
 
-    // Update is_at_bottom based on actual position
-    state.is_at_bottom = actual_scroll >= max_scroll;
-
     // Update scroll offset to actual (for proper clamping)
     log_state.scroll_offset = actual_scroll;
 
     // Only format the visible portion of log entries for performance
-    // Calculate visible range based on scroll position and viewport height
-    let visible_start = actual_scroll;
-    let visible_end = (actual_scroll + visible_height).min(num_lines);
+    // Calculate visible range based on scroll position, viewport height and level filter
+    let shown: Vec<usize> = (actual_scroll..num_lines)
+        .filter(|&idx| log_state.is_shown(idx))
+        .take(visible_height)
+        .collect();
+    let visible_end = shown.last().map_or(actual_scroll, |&idx| idx + 1);
 
-    // Format only the visible log entries into lines
-    let visible_lines: Vec<_> = if visible_start < log_state.log_entries.len() {
-        log_state.log_entries[visible_start..visible_end]
-            .iter()
-            .map(format_log_entry)
-            .collect()
+    // Highlight the applied search, not the one still being typed
+    let query = SearchQuery::parse(log_state.search_input.value());
+    let query = (!log_state.search_editing && !query.is_empty()).then_some(&query);
+
+    // Format only the visible log entries into lines, with a separator before long silences
+    let entries = &log_state.log_entries;
+    let mut visible_lines = Vec::with_capacity(visible_height);
+    for idx in shown {
+        // Folded repeats count from the first entry of the run
+        let repeats = log_state.repeat_count(idx);
+        let first = idx + 1 - repeats;
+        if first > 0
+            && let Some(separator) = gap_separator(&entries[first - 1], &entries[first], styles)
+        {
+            visible_lines.push(separator);
+        }
+        let mut line = format_log_entry(&entries[idx], query, styles);
+        if repeats > 1 {
+            line.push_span(Span::styled(format!(" ×{}", repeats), styles.title_count));
+        }
+        if log_state.search_match == Some(idx) {
+            visible_lines.push(line.style(styles.selected));
+        } else {
+            visible_lines.push(line);
+        }
+    }
+
+    // Separators take rows: keep the newest lines when following, the oldest otherwise
+    let excess = visible_lines.len().saturating_sub(visible_height);
+    if state.follow_logs {
+        visible_lines.drain(..excess);
     } else {
-        vec![]
-    };
+        visible_lines.truncate(visible_height);
+    }
 
     let visible_text = Text::from(visible_lines);
 
-    // Determine status indicator - show only one of: [Loading...], [LIVE], or [XX%]
+    // Determine status indicator - show only one of: [Loading...], [FOLLOWING], or [PAUSED] [XX%]
     let status_indicator = if log_state.fetching_older {
         // Show loading indicator when fetching older logs
-        "[Loading...]".to_string()
-    } else if state.is_at_bottom {
-        // At bottom in auto-scroll mode, show LIVE
-        "[LIVE]".to_string()
+        msg.loading.to_string()
+    } else if state.follow_logs {
+        msg.following.to_string()
     } else if let Some(progress) = log_state.calculate_progress(actual_scroll) {
-        // Not at bottom, show progress percentage
+        // Paused, with the progress percentage
         if log_state.has_more_history || progress > 0.0 {
-            format!("[{:.0}%]", progress)
+            format!("{} [{:.0}%]", msg.paused_logs, progress)
         } else {
             // At the very beginning (0%)
-            "[0%]".to_string()
+            format!("{} [0%]", msg.paused_logs)
         }
+    } else {
+        msg.paused_logs.to_string()
+    };
+
+    // Levels still shown, when the level filter hides some
+    let level_filter = if log_state.hidden_levels.is_empty() {
+        String::new()
+    } else {
+        let shown_levels: Vec<&str> = LogLevel::ALL
+            .iter()
+            .filter(|level| !log_state.hidden_levels.contains(level))
+            .map(LogLevel::label)
+            .collect();
+        format!(" [{}]", shown_levels.join(" "))
+    };
+    let collapsed = if log_state.collapse_repeats {
+        format!(" {}", msg.repeats_collapsed)
     } else {
         String::new()
     };
@@ -126,8 +275,14 @@ pub fn render_log_view(
         .block(
             Block::default()
                 .title(format!(
-                    "Logs: {} ({}) - Press ESC to return {}",
-                    container_name, container_key.host_id, status_indicator
+                    "{}: {} ({}) - {} {}{}{}",
+                    msg.logs,
+                    container_name,
+                    container_key.host_id,
+                    msg.logs_return_hint,
+                    status_indicator,
+                    level_filter,
+                    collapsed
                 ))
                 .style(styles.border),
         )
@@ -144,4 +299,51 @@ pub fn render_log_view(
     let scrollbar = Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight);
 
     f.render_stateful_widget(scrollbar, size, &mut scrollbar_state);
+
+    if log_state.search_editing || query.is_some() {
+        let search_area = Rect {
+            y: size.y + size.height.saturating_sub(1),
+            height: 1,
+            ..size
+        };
+        render_log_search_bar(f, search_area, log_state, styles);
+    }
+}
+
+/// Renders the log search at the bottom: the input while typing, then the match position
+fn render_log_search_bar(f: &mut Frame, area: Rect, log_state: &LogState, styles: &UiStyles) {
+    let input = &log_state.search_input;
+
+    let search_text = if log_state.search_editing {
+        format!("/{}", input.value())
+    } else {
+        let msg = i18n::messages();
+        let query = SearchQuery::parse(input.value());
+        let matches: Vec<usize> = log_state
+            .log_entries
+            .iter()
+            .enumerate()
+            .filter(|(idx, entry)| log_state.is_shown(*idx) && query.matches(&entry.plain_text()))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let position = match log_state.search_match {
+            _ if matches.is_empty() => msg.no_matches.to_string(),
+            Some(current) => {
+                let nth = matches.iter().position(|&idx| idx == current).unwrap_or(0);
+                format!("{}/{}", nth + 1, matches.len())
+            }
+            None => matches.len().to_string(),
+        };
+        format!("{}: {} ({})", msg.search, query.pattern, position)
+    };
+
+    let search_widget = Paragraph::new(Line::styled(search_text, styles.search_bar));
+    f.render_widget(search_widget, area);
+
+    if log_state.search_editing {
+        // Cursor after the '/' and the typed text
+        let cursor_x = area.x + 1 + input.visual_cursor() as u16;
+        f.set_cursor_position((cursor_x, area.y));
+    }
 }
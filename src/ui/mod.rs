@@ -1,11 +1,33 @@
 pub mod action_menu;
+pub mod build;
+pub mod cleanup;
 pub mod container_list;
+pub mod details;
+pub mod disk_usage;
+pub mod env_vars;
+pub mod error_detail;
+pub mod exec_output;
+pub mod export;
+pub mod file_browser;
 pub mod formatters;
 pub mod help;
+pub mod host_summary;
+pub mod i18n;
 pub mod icons;
+pub mod image_history;
 pub mod input;
 pub mod log_view;
+pub mod network_list;
+pub mod network_picker;
+pub mod notifications;
+pub mod process_list;
+pub mod project_plan;
 pub mod render;
+pub mod replicas;
+pub mod shell_sessions;
+pub mod theme;
+pub mod timeline;
+pub mod volume_list;
 
 #[cfg(test)]
 mod ui_tests;
@@ -0,0 +1,115 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    text::Line,
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::NetworkInfo;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the networks of all hosts with their subnets and attached containers
+pub fn render_network_list(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let Some(network_list) = &mut state.network_list else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    let loading = if network_list.loading.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", msg.loading)
+    };
+    let block = Block::default()
+        .title(format!(
+            "{} ({}){} - {}",
+            msg.networks,
+            network_list.networks.len(),
+            loading,
+            msg.logs_return_hint
+        ))
+        .style(styles.border);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Errors above the table
+    let [errors_area, table_area] = Layout::vertical([
+        Constraint::Length(network_list.errors.len() as u16),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+    let errors: Vec<Line> = network_list
+        .errors
+        .iter()
+        .map(|error| Line::styled(error.as_str(), styles.high))
+        .collect();
+    f.render_widget(Paragraph::new(errors), errors_area);
+
+    let show_host_column = state.connected_hosts.len() > 1;
+
+    let mut header = Vec::new();
+    let mut widths = Vec::new();
+    if show_host_column {
+        header.push(Cell::from(msg.header_host));
+        widths.push(Constraint::Length(15));
+    }
+    header.extend([
+        Cell::from(msg.header_name),
+        Cell::from(msg.header_driver),
+        Cell::from(msg.header_scope),
+        Cell::from(msg.header_subnet),
+        Cell::from(msg.header_containers),
+    ]);
+    widths.extend([
+        Constraint::Min(18),
+        Constraint::Length(10),
+        Constraint::Length(8),
+        Constraint::Length(20),
+        Constraint::Min(20),
+    ]);
+
+    let rows = network_list
+        .networks
+        .iter()
+        .map(|network| network_row(network, show_host_column, styles));
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).style(styles.header))
+        .row_highlight_style(styles.selected);
+    f.render_stateful_widget(table, table_area, &mut network_list.table_state);
+}
+
+/// Builds the row for a single network ("2: db, web" for the attached containers)
+fn network_row(network: &NetworkInfo, show_host_column: bool, styles: &UiStyles) -> Row<'static> {
+    let subnets = if network.subnets.is_empty() {
+        "-".to_string()
+    } else {
+        network.subnets.join(", ")
+    };
+    let containers = if network.containers.is_empty() {
+        "-".to_string()
+    } else {
+        format!(
+            "{}: {}",
+            network.containers.len(),
+            network.containers.join(", ")
+        )
+    };
+
+    let mut cells = Vec::new();
+    if show_host_column {
+        cells.push(Cell::from(network.host_id.clone()));
+    }
+    cells.extend([
+        Cell::from(network.name.clone()),
+        Cell::from(network.driver.clone()),
+        Cell::from(network.scope.clone()),
+        Cell::from(subnets).style(styles.container_id),
+        Cell::from(containers),
+    ]);
+    Row::new(cells)
+}
@@ -0,0 +1,121 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::core::app_state::AppState;
+use crate::ui::formatters::truncate_with_ellipsis;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Maximum number of networks shown at once (the list scrolls beyond that)
+const MAX_VISIBLE_NETWORKS: u16 = 12;
+
+/// Renders a centered popup listing the networks to connect/disconnect the container to/from
+pub fn render_network_picker(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
+    let Some(container_name) = state
+        .network_picker
+        .as_ref()
+        .map(|picker| state.container_label(&picker.container_key))
+    else {
+        return;
+    };
+    let Some(picker) = &mut state.network_picker else {
+        return;
+    };
+
+    let area = f.area();
+    let msg = i18n::messages();
+
+    // One row for loading, errors and empty lists
+    let rows = match &picker.networks {
+        Some(networks) if !networks.is_empty() => (networks.len() as u16).min(MAX_VISIBLE_NETWORKS),
+        _ => 1,
+    };
+
+    // Same layout as the action menu: borders + rows + blank line + footer + padding
+    let popup_height = (rows + 6).min(area.height.saturating_sub(4));
+    let popup_width = 40u16.min(area.width.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let title = format!(
+        " {}: {} ",
+        msg.action(picker.action),
+        truncate_with_ellipsis(&container_name, 20)
+    );
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+
+    f.render_widget(block, popup_area);
+
+    match (&picker.networks, &picker.error) {
+        (_, Some(error)) => {
+            let paragraph = Paragraph::new(error.as_str())
+                .style(styles.high)
+                .wrap(Wrap { trim: false });
+            f.render_widget(paragraph, inner_area);
+        }
+        (Some(networks), None) if !networks.is_empty() => {
+            let list_items: Vec<ListItem> = networks
+                .iter()
+                .map(|network| {
+                    ListItem::new(format!(" {}", network)).style(Style::default().fg(Color::White))
+                })
+                .collect();
+
+            let list = List::new(list_items)
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("> ");
+
+            f.render_stateful_widget(list, inner_area, &mut picker.list_state);
+        }
+        (networks, None) => {
+            let text = if networks.is_some() {
+                msg.no_networks
+            } else {
+                msg.loading
+            };
+            let paragraph =
+                Paragraph::new(format!(" {}", text)).style(Style::default().fg(Color::Gray));
+            f.render_widget(paragraph, inner_area);
+        }
+    }
+
+    // Render footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.action_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
@@ -0,0 +1,188 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::core::alerts::AlertCondition;
+use crate::core::app_state::AppState;
+use crate::core::types::{
+    HealthStatus, Notification, NotificationKind, PruneReport, PruneTarget, VerifyOutcome,
+};
+use crate::ui::formatters::{format_bytes, format_timestamp, truncate_with_ellipsis};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the notifications kept this session, newest first
+pub fn render_notifications(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let msg = i18n::messages();
+
+    let block = Block::default()
+        .title(format!(
+            "{} ({}, {} {}) - {}",
+            msg.notifications,
+            state.notifications.len(),
+            state.unacknowledged_notifications(),
+            msg.unacknowledged,
+            msg.notifications_footer
+        ))
+        .style(styles.border);
+
+    if state.notifications.is_empty() {
+        f.render_widget(Paragraph::new(msg.no_notifications).block(block), area);
+        return;
+    }
+
+    let header = [
+        Cell::from(""),
+        Cell::from(msg.header_time),
+        Cell::from(msg.header_source),
+        Cell::from(msg.header_message),
+    ];
+    let widths = [
+        Constraint::Length(1),
+        Constraint::Length(8),
+        Constraint::Length(25),
+        Constraint::Min(20),
+    ];
+
+    let rows = state
+        .notifications
+        .iter()
+        .rev()
+        .map(|notification| notification_row(notification, styles));
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).style(styles.header))
+        .row_highlight_style(styles.selected)
+        .block(block);
+    f.render_stateful_widget(table, area, &mut state.notification_state);
+}
+
+/// Builds the row for a single notification, marked and bold until acknowledged
+fn notification_row(notification: &Notification, styles: &UiStyles) -> Row<'static> {
+    let (text, style) = notification_text(&notification.kind, styles);
+    let (marker, row_style) = if notification.acknowledged {
+        ("", Style::default())
+    } else {
+        ("●", Style::default().add_modifier(Modifier::BOLD))
+    };
+
+    Row::new([
+        Cell::from(marker).style(style),
+        Cell::from(format_timestamp(&notification.time, "%H:%M:%S")).style(styles.log_timestamp),
+        Cell::from(truncate_with_ellipsis(&notification.source, 25).into_owned()),
+        Cell::from(text).style(style),
+    ])
+    .style(row_style)
+}
+
+/// What a notification says, without its source: "Restart failed: no such container"
+pub fn notification_text(kind: &NotificationKind, styles: &UiStyles) -> (String, Style) {
+    let msg = i18n::messages();
+    match kind {
+        NotificationKind::ConnectionError(error) | NotificationKind::FetchFailed(error) => {
+            (error.clone(), styles.high)
+        }
+        NotificationKind::ActionFailed(action, error) => (
+            format!("{} {}: {}", msg.action(*action), msg.action_failed, error),
+            styles.high,
+        ),
+        NotificationKind::ActionRetrying(action, retry, error) => (
+            format!(
+                "{} {}, {} #{}: {}",
+                msg.action(*action),
+                msg.action_failed,
+                msg.retrying,
+                retry,
+                error
+            ),
+            styles.medium,
+        ),
+        NotificationKind::ActionVerified(action, outcome) => {
+            let (_, status, style) = verify_outcome_status(outcome, styles);
+            (format!("{}, {}", msg.action(*action), status), style)
+        }
+        NotificationKind::AlertFired(condition, value) => {
+            (alert_condition_text(*condition, *value), styles.high)
+        }
+        NotificationKind::PruneFinished(target, result) => {
+            let (_, text, style) = prune_outcome_text(*target, result, styles);
+            (text, style)
+        }
+    }
+}
+
+/// Icon, text and style of how a watched container fared: "stayed up 10s (healthy)"
+pub fn verify_outcome_status(
+    outcome: &VerifyOutcome,
+    styles: &UiStyles,
+) -> (&'static str, String, Style) {
+    let msg = i18n::messages();
+    match outcome {
+        VerifyOutcome::StayedUp { secs, health } => {
+            let (health_text, style) = match health {
+                Some(HealthStatus::Healthy) => (format!(" ({})", msg.healthy), styles.low),
+                Some(HealthStatus::Unhealthy) => (format!(" ({})", msg.unhealthy), styles.high),
+                Some(HealthStatus::Starting) => (format!(" ({})", msg.starting), styles.medium),
+                None => (String::new(), styles.low),
+            };
+            let icon = if outcome.is_ok() { "✓" } else { "✗" };
+            (
+                icon,
+                format!("{} {}s{}", msg.verify_stayed_up, secs, health_text),
+                style,
+            )
+        }
+        VerifyOutcome::Crashed { secs, exit_code } => {
+            let exit_text = exit_code
+                .map(|code| format!(" ({} {})", msg.verify_exit, code))
+                .unwrap_or_default();
+            (
+                "✗",
+                format!("{} {}s{}", msg.verify_crashed, secs, exit_text),
+                styles.high,
+            )
+        }
+    }
+}
+
+/// What an alert watches and the value that fired it: "CPU 95.2% > 90%"
+pub fn alert_condition_text(condition: AlertCondition, value: Option<f64>) -> String {
+    let msg = i18n::messages();
+    match (condition, value) {
+        (AlertCondition::Cpu(limit), Some(value)) => {
+            format!("{} {:.1}% > {}%", msg.cpu, value, limit)
+        }
+        (AlertCondition::Memory(limit), Some(value)) => {
+            format!("{} {:.1}% > {}%", msg.memory, value, limit)
+        }
+        _ => msg.unhealthy.to_string(),
+    }
+}
+
+/// Icon, text and style of a prune's result: "Stopped containers pruned, 3 removed, 5 M freed"
+pub fn prune_outcome_text(
+    target: PruneTarget,
+    result: &Result<PruneReport, String>,
+    styles: &UiStyles,
+) -> (&'static str, String, Style) {
+    let msg = i18n::messages();
+    match result {
+        Ok(report) => (
+            "✓",
+            format!(
+                "{} {}, {} {}, {} {}",
+                msg.prune_target(target),
+                msg.pruned,
+                report.removed,
+                msg.removed,
+                format_bytes(report.reclaimed),
+                msg.freed
+            ),
+            styles.low,
+        ),
+        Err(error) => ("✗", format!("{}: {}", msg.prune_failed, error), styles.high),
+    }
+}
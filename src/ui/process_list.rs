@@ -0,0 +1,89 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    text::Line,
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::ProcessInfo;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the processes of the viewed container, busiest first
+pub fn render_process_list(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    let Some(process_list) = &state.process_list else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    let name = state.container_label(&process_list.container_key);
+    let summary = match &process_list.processes {
+        Some(processes) => format!(" ({})", processes.len()),
+        None if process_list.error.is_none() => format!(" {}", msg.loading),
+        None => String::new(),
+    };
+
+    let block = Block::default()
+        .title(format!(
+            "{}: {}{} - {}",
+            msg.processes, name, summary, msg.logs_return_hint
+        ))
+        .style(styles.border);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // The error of the last refresh above the processes it left on screen
+    let error_height = u16::from(process_list.error.is_some());
+    let [error_area, table_area] =
+        Layout::vertical([Constraint::Length(error_height), Constraint::Min(0)]).areas(inner);
+    if let Some(error) = &process_list.error {
+        f.render_widget(
+            Paragraph::new(Line::styled(error.as_str(), styles.high)),
+            error_area,
+        );
+    }
+
+    let processes = process_list.processes.as_deref().unwrap_or_default();
+    let rows = processes
+        .iter()
+        .skip(process_list.scroll_offset)
+        .map(process_row);
+
+    let header = Row::new(vec![
+        Cell::from(Line::from(msg.header_pid).right_aligned()),
+        Cell::from(msg.header_user),
+        Cell::from(Line::from(msg.header_cpu).right_aligned()),
+        Cell::from(msg.header_command),
+    ])
+    .style(styles.header);
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(7),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header);
+
+    f.render_widget(table, table_area);
+}
+
+/// Builds the row for a single process
+fn process_row(process: &ProcessInfo) -> Row<'static> {
+    let cpu = process
+        .cpu
+        .map_or_else(|| "-".to_string(), |cpu| format!("{:.1}", cpu));
+
+    Row::new(vec![
+        Cell::from(Line::from(process.pid.clone()).right_aligned()),
+        Cell::from(process.user.clone()),
+        Cell::from(Line::from(cpu).right_aligned()),
+        Cell::from(process.command.clone()),
+    ])
+}
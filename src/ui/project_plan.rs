@@ -0,0 +1,89 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::ViewState;
+use crate::ui::formatters::truncate_with_ellipsis;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders a centered popup with the steps a project action will run, in order:
+/// "1. Stop  web-1, web-2" then "2. Stop  db-1"
+pub fn render_project_plan(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    let ViewState::ProjectPlan(project, action) = &state.view_state else {
+        return;
+    };
+
+    let msg = i18n::messages();
+    let steps = state.project_plan(project, *action);
+
+    let area = f.area();
+    let popup_width = 70u16.min(area.width.saturating_sub(4));
+    // Steps + borders, blank line and footer
+    let popup_height = (steps.len() as u16 + 4).min(area.height.saturating_sub(2));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " {}: {} {} ({}) ",
+            msg.plan_title,
+            msg.action(*action),
+            truncate_with_ellipsis(&project.name, 20),
+            truncate_with_ellipsis(&project.host_id, 10)
+        ))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        popup_area.height.saturating_sub(3),
+    );
+
+    f.render_widget(block, popup_area);
+
+    let lines: Vec<Line> = steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| {
+            let names: Vec<&str> = step
+                .containers
+                .iter()
+                .filter_map(|key| state.containers.get(key))
+                .map(|container| container.name.as_str())
+                .collect();
+            Line::from(vec![
+                Span::styled(format!("{}. ", i + 1), styles.title_help),
+                Span::styled(format!("{:<8}", msg.action(step.action)), styles.header),
+                Span::raw(names.join(", ")),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner_area);
+
+    // Footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.plan_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
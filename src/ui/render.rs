@@ -1,3 +1,4 @@
+use chrono::TimeDelta;
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
@@ -5,15 +6,50 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
+use std::time::Duration;
 
+use crate::core::alerts::{AlertSeverity, FiredAlert};
 use crate::core::app_state::AppState;
-use crate::core::types::ViewState;
+use crate::core::forecast::{FORECAST_WARNING, memory_exhaustion};
+use crate::core::search_query::SearchQuery;
+use crate::core::types::{
+    BuildStatus, BulkKind, BulkProgress, ContainerState, ExportOutcome, ViewState,
+};
 
-use crate::ui::action_menu::render_action_menu;
+use crate::ui::action_menu::{
+    render_action_menu, render_copy_menu, render_maintenance_menu, render_prune_menu,
+    render_stop_timeout_prompt,
+};
+use crate::ui::build::render_build;
+use crate::ui::cleanup::render_cleanup;
 use crate::ui::container_list::render_container_list;
+use crate::ui::details::render_details_popup;
+use crate::ui::disk_usage::render_disk_usage;
+use crate::ui::env_vars::render_env_vars;
+use crate::ui::error_detail::render_error_detail;
+use crate::ui::exec_output::{render_exec_output, render_exec_output_popup, render_exec_prompt};
+use crate::ui::export::render_export_menu;
+use crate::ui::file_browser::render_file_browser;
+use crate::ui::formatters::{
+    display_width, format_bytes, format_duration_precise, format_time_left, truncate_with_ellipsis,
+};
 use crate::ui::help::render_help_popup;
+use crate::ui::i18n;
 use crate::ui::icons::{IconStyle, Icons};
+use crate::ui::image_history::render_image_history;
 use crate::ui::log_view::render_log_view;
+use crate::ui::network_list::render_network_list;
+use crate::ui::network_picker::render_network_picker;
+use crate::ui::notifications::{
+    alert_condition_text, prune_outcome_text, render_notifications, verify_outcome_status,
+};
+use crate::ui::process_list::render_process_list;
+use crate::ui::project_plan::render_project_plan;
+use crate::ui::replicas::render_replicas_popup;
+use crate::ui::shell_sessions::render_shell_sessions;
+use crate::ui::theme::{Background, Theme};
+use crate::ui::timeline::render_timeline;
+use crate::ui::volume_list::render_volume_list;
 
 /// Pre-allocated styles to avoid recreation every frame
 pub struct UiStyles {
@@ -24,6 +60,8 @@ pub struct UiStyles {
     pub border: Style,
     pub selected: Style,
     pub search_bar: Style,
+    /// Text matching the container filter within the rows
+    pub search_match: Style,
     pub title_name: Style,
     pub title_count: Style,
     pub title_help: Style,
@@ -31,6 +69,19 @@ pub struct UiStyles {
     pub created: Style,
     pub network_tx: Style,
     pub network_rx: Style,
+    pub log_timestamp: Style,
+    /// Status icon color for created (never started) containers
+    pub state_created: Style,
+    /// Status icon color for containers in an unknown state
+    pub state_unknown: Style,
+    /// Status icon color for containers stuck in a restart loop
+    pub restart_loop: Style,
+    /// Added to stats far from the container's baseline
+    pub anomaly: Style,
+    /// Background for every other row when zebra striping is enabled
+    pub zebra: Option<Style>,
+    pub theme: Theme,
+    pub background: Background,
     pub icons: Icons,
 }
 
@@ -51,6 +102,10 @@ impl Default for UiStyles {
             search_bar: Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
+            // Yellow like the search bar, underlined to show on any selection style
+            search_match: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
             // Dark purple for app name/version (matches column headers)
             title_name: Style::default().fg(Color::Rgb(140, 100, 180)),
             // Yellow for container count
@@ -65,50 +120,161 @@ impl Default for UiStyles {
             network_tx: Style::default().fg(Color::Rgb(200, 100, 180)),
             // Cyan/blue for network RX (download)
             network_rx: Style::default().fg(Color::Rgb(100, 180, 220)),
+            // Yellow + bold for log timestamps
+            log_timestamp: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            state_created: Style::default().fg(Color::Cyan),
+            state_unknown: Style::default().fg(Color::Gray),
+            restart_loop: Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+            anomaly: Style::default().add_modifier(Modifier::REVERSED),
+            zebra: None,
+            theme: Theme::Default,
+            background: Background::Dark,
             icons: Icons::default(),
         }
     }
 }
 
 impl UiStyles {
-    /// Create UiStyles with a specific icon style
-    pub fn with_icon_style(icon_style: IconStyle) -> Self {
-        Self {
-            icons: Icons::new(icon_style),
-            ..Default::default()
+    /// Create UiStyles with a specific icon style, color theme, and terminal background
+    pub fn new(icon_style: IconStyle, theme: Theme, background: Background) -> Self {
+        let icons = Icons::new(icon_style);
+        let styles = match theme {
+            Theme::Default => Self {
+                icons,
+                ..Default::default()
+            },
+            Theme::Colorblind => Self::colorblind(icons),
+            Theme::HighContrast => Self::high_contrast(icons),
+        };
+        match background {
+            Background::Dark => styles,
+            Background::Light => styles.for_light_background(),
         }
     }
 }
 
+/// Whether the container list has a host column: only with containers from several hosts
+pub fn show_host_column(state: &AppState) -> bool {
+    let mut hosts = state.containers.keys().map(|key| &key.host_id);
+    hosts
+        .next()
+        .is_some_and(|first| hosts.any(|host_id| host_id != first))
+}
+
 /// Renders the main UI - either container list, log view, or action menu
 pub fn render_ui(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
     let size = f.area();
+    let show_host_column = show_host_column(state);
 
     // Render main content
     match &state.view_state {
         ViewState::ContainerList | ViewState::SearchMode => {
-            // Calculate unique hosts to determine if host column should be shown
-            let unique_hosts: std::collections::HashSet<_> =
-                state.containers.keys().map(|key| &key.host_id).collect();
-            let show_host_column = unique_hosts.len() > 1;
-
             render_container_list(f, size, state, styles, show_host_column);
         }
         ViewState::LogView(container_key) => {
             let container_key = container_key.clone();
             render_log_view(f, size, &container_key, state, styles);
         }
-        ViewState::ActionMenu(_) => {
+        ViewState::ImageHistory(_) => {
+            render_image_history(f, size, state, styles);
+        }
+        ViewState::ProcessList(_) => {
+            render_process_list(f, size, state, styles);
+        }
+        ViewState::FileBrowser(_) => {
+            render_file_browser(f, size, state, styles);
+        }
+        ViewState::Build => {
+            // The dialog is shown over the container list, the output full screen
+            if state
+                .build
+                .as_ref()
+                .is_some_and(|build| build.status == BuildStatus::Editing)
+            {
+                render_container_list(f, size, state, styles, show_host_column);
+            }
+            render_build(f, size, state, styles);
+        }
+        ViewState::ActionMenu(_) | ViewState::ProjectActionMenu(_) => {
             // First render the container list in the background
-            let unique_hosts: std::collections::HashSet<_> =
-                state.containers.keys().map(|key| &key.host_id).collect();
-            let show_host_column = unique_hosts.len() > 1;
-
             render_container_list(f, size, state, styles, show_host_column);
 
             // Then render the action menu on top
             render_action_menu(f, state, styles);
         }
+        ViewState::StopTimeoutPrompt(_, _) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_stop_timeout_prompt(f, state, styles);
+        }
+        ViewState::ProjectPlan(_, _) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_project_plan(f, state, styles);
+        }
+        ViewState::VolumeList => {
+            render_volume_list(f, size, state, styles);
+        }
+        ViewState::Cleanup => {
+            render_cleanup(f, size, state, styles);
+        }
+        ViewState::DiskUsage => {
+            render_disk_usage(f, size, state, styles);
+        }
+        ViewState::Timeline => {
+            render_timeline(f, size, state, styles);
+        }
+        ViewState::NetworkList => {
+            render_network_list(f, size, state, styles);
+        }
+        ViewState::ShellSessions => {
+            render_shell_sessions(f, size, state, styles);
+        }
+        ViewState::Notifications => {
+            render_notifications(f, size, state, styles);
+        }
+        ViewState::ErrorDetail => {
+            // Over the notifications panel it was opened from
+            render_notifications(f, size, state, styles);
+            render_error_detail(f, state, styles);
+        }
+        ViewState::ExecPrompt(_) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_exec_prompt(f, state, styles);
+        }
+        ViewState::EnvVars(_) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_env_vars(f, state, styles);
+        }
+        ViewState::ExecOutput(_) if state.exec_output.as_ref().is_some_and(|e| e.popup) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_exec_output_popup(f, state, styles);
+        }
+        ViewState::ExecOutput(_) => {
+            render_exec_output(f, size, state, styles);
+        }
+        ViewState::NetworkPicker(_) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_network_picker(f, state, styles);
+        }
+        ViewState::ExportMenu => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_export_menu(f, state, styles);
+        }
+        ViewState::PruneMenu(_) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_prune_menu(f, state, styles);
+        }
+        ViewState::MaintenanceMenu(_) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_maintenance_menu(f, state, styles);
+        }
+        ViewState::CopyMenu(_) => {
+            render_container_list(f, size, state, styles, show_host_column);
+            render_copy_menu(f, state, styles);
+        }
     }
 
     // Render search bar overlay if in SearchMode OR if there's an active filter
@@ -125,6 +291,16 @@ pub fn render_ui(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
         render_search_bar(f, search_area, state, styles);
     }
 
+    // Render container details popup if shown
+    if state.show_details {
+        render_details_popup(f, state, styles);
+    }
+
+    // Render replica comparison popup if shown
+    if state.show_replicas {
+        render_replicas_popup(f, state, styles);
+    }
+
     // Render help popup on top if shown
     if state.show_help {
         render_help_popup(f, styles);
@@ -132,6 +308,54 @@ pub fn render_ui(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
 
     // Render connection error notifications in top right corner
     render_error_notifications(f, state, styles);
+
+    // And the event loop indicator in the bottom right corner
+    if state.show_loop_metrics {
+        render_loop_metrics(f, state, styles);
+    }
+}
+
+/// Renders how the event loop keeps up on the bottom line, right aligned:
+/// "42 ev/s · 4 fps · render 3.1ms · lag 0.4ms · queue 2"
+/// Yellow once handling events holds back drawing noticeably, red when it stalls
+fn render_loop_metrics(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    const LAG_WARNING: Duration = Duration::from_millis(50);
+    const LAG_HIGH: Duration = Duration::from_millis(200);
+
+    let msg = i18n::messages();
+    let stats = &state.loop_metrics.stats;
+    let millis = |duration: Duration| duration.as_secs_f64() * 1000.0;
+    let text = format!(
+        " {:.0} ev/s · {:.0} fps · {} {:.1}ms · {} {:.1}ms · {} {} ",
+        stats.events_per_sec,
+        stats.fps,
+        msg.loop_render,
+        millis(stats.render_time),
+        msg.loop_lag,
+        millis(stats.lag),
+        msg.loop_queue,
+        stats.channel_depth
+    );
+    let style = if stats.lag >= LAG_HIGH {
+        styles.high
+    } else if stats.lag >= LAG_WARNING {
+        styles.medium
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    let area = f.area();
+    let width = (display_width(&text) as u16).min(area.width);
+    let indicator_area = Rect {
+        x: area.width - width,
+        y: area.height.saturating_sub(1),
+        width,
+        height: area.height.min(1),
+    };
+    f.render_widget(
+        Paragraph::new(text).style(style.bg(Color::Black)),
+        indicator_area,
+    );
 }
 
 /// Renders the search bar at the bottom of the screen (vi-style)
@@ -150,8 +374,27 @@ fn render_search_bar(
         // In search mode: show "/" prefix for editing
         format!("/{}", state.search_input.value())
     } else {
-        // Filter applied: show "Filtering: " prefix
-        format!("Filtering: {}", state.search_input.value())
+        // Filter applied: show "Filtering: " prefix with any active match modifiers
+        let msg = i18n::messages();
+        let query = SearchQuery::parse(state.search_input.value());
+        let modifiers: Vec<&str> = [
+            (query.case_sensitive, msg.case_sensitive),
+            (query.whole_word, msg.whole_word),
+        ]
+        .into_iter()
+        .filter_map(|(active, label)| active.then_some(label))
+        .collect();
+
+        if modifiers.is_empty() {
+            format!("{}: {}", msg.filtering, query.pattern)
+        } else {
+            format!(
+                "{}: {} ({})",
+                msg.filtering,
+                query.pattern,
+                modifiers.join(", ")
+            )
+        }
     };
 
     // Create a paragraph with the search text using the search_bar style
@@ -174,54 +417,354 @@ fn render_search_bar(
     }
 }
 
-/// Renders connection error notifications in the top right corner
+/// Banner text of an alert in effect: "⚠ web: CPU 95.2% > 90% (45s)"
+fn alert_text(state: &AppState, alert: &FiredAlert) -> String {
+    let name = state.container_label(&alert.key);
+    let secs = alert.since.elapsed().as_secs();
+    format!(
+        "⚠ {}: {} ({}s)",
+        name,
+        alert_condition_text(alert.rule.condition, alert.value),
+        secs
+    )
+}
+
+/// The progress of a bulk action, as "Restart web: ████░░░░ 4 done, 0 failed, 4 remaining"
+fn bulk_progress_text(progress: &BulkProgress) -> String {
+    const BAR_WIDTH: usize = 10;
+
+    let msg = i18n::messages();
+    let label = match &progress.kind {
+        BulkKind::Project(project, action) => format!("{} {}", msg.action(*action), project.name),
+        BulkKind::RemoveVolumes => msg.volumes.to_string(),
+        BulkKind::Cleanup => msg.cleanup.to_string(),
+    };
+    let ran = progress.done + progress.failed;
+    let filled = (ran * BAR_WIDTH)
+        .checked_div(progress.total)
+        .unwrap_or(BAR_WIDTH);
+    format!(
+        "{}: {}{} {} {}, {} {}, {} {}",
+        label,
+        "█".repeat(filled),
+        "░".repeat(BAR_WIDTH - filled),
+        progress.done,
+        msg.bulk_done,
+        progress.failed,
+        msg.bulk_failed,
+        progress.remaining(),
+        msg.bulk_remaining
+    )
+}
+
+/// Renders reconnecting badges and connection error notifications in the top right corner
 fn render_error_notifications(f: &mut Frame, state: &mut AppState, styles: &UiStyles) {
     // Clean up old errors (older than 10 seconds)
     state
         .connection_errors
         .retain(|_, (_, timestamp)| timestamp.elapsed().as_secs() < 10);
 
-    if state.connection_errors.is_empty() {
+    // Clock skew warnings show for a while after each measurement
+    let mut skews: Vec<_> = state
+        .clock_skews
+        .iter()
+        .filter(|(_, (_, measured_at))| measured_at.elapsed().as_secs() < 10)
+        .collect();
+    skews.sort_by_key(|(host_id, _)| *host_id);
+
+    // Disk and load warnings likewise, after each measurement that still finds them
+    let mut resources: Vec<_> = state
+        .host_resources
+        .iter()
+        .filter(|(_, (resources, measured_at))| {
+            measured_at.elapsed().as_secs() < 10 && (resources.disk_low() || resources.load_high())
+        })
+        .collect();
+    resources.sort_by_key(|(host_id, _)| *host_id);
+
+    // How watched containers fared after a Start or Restart, oldest first
+    state
+        .action_outcomes
+        .retain(|_, (_, _, reported_at)| reported_at.elapsed().as_secs() < 10);
+    let mut outcomes: Vec<_> = state.action_outcomes.iter().collect();
+    outcomes.sort_by_key(|(_, (_, _, reported_at))| *reported_at);
+
+    // Actions being retried, until they end
+    let mut retrying: Vec<_> = state.retrying_actions.iter().collect();
+    retrying.sort_by_key(|(key, _)| (&key.host_id, &key.container_id));
+
+    // The last export likewise
+    if state
+        .export_outcome
+        .as_ref()
+        .is_some_and(|(_, exported_at)| exported_at.elapsed().as_secs() >= 10)
+    {
+        state.export_outcome = None;
+    }
+
+    // And the last copy to the clipboard
+    if state
+        .copy_outcome
+        .as_ref()
+        .is_some_and(|(_, copied_at)| copied_at.elapsed().as_secs() >= 10)
+    {
+        state.copy_outcome = None;
+    }
+
+    // And the prunes
+    state
+        .prune_outcomes
+        .retain(|(_, _, _, pruned_at)| pruned_at.elapsed().as_secs() < 10);
+
+    // Bulk actions show while they run and for a while after they finish
+    state.bulk_progress.retain(|_, progress| {
+        progress
+            .finished_at
+            .is_none_or(|finished_at| finished_at.elapsed().as_secs() < 10)
+    });
+
+    // Alerts stay while their condition holds, only critical ones in do-not-disturb mode
+    // and none for hosts in maintenance
+    let alerts: Vec<(String, Style)> = state
+        .alerts
+        .as_ref()
+        .map(|alerts| alerts.firing())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|alert| state.containers.contains_key(&alert.key))
+        .filter(|alert| !state.in_maintenance(&alert.key.host_id))
+        .filter(|alert| !state.do_not_disturb || alert.rule.severity == AlertSeverity::Critical)
+        .map(|alert| {
+            let style = match alert.rule.severity {
+                AlertSeverity::Info => styles.medium,
+                AlertSeverity::Warning | AlertSeverity::Critical => styles.high,
+            };
+            (alert_text(state, alert), style)
+        })
+        .collect();
+
+    // Running containers whose memory will reach the limit within the hour, soonest first
+    let mut forecasts: Vec<_> = state
+        .containers
+        .values()
+        .filter(|c| c.state == ContainerState::Running)
+        .filter_map(|c| {
+            memory_exhaustion(&c.stats)
+                .filter(|time_left| *time_left < FORECAST_WARNING)
+                .map(|time_left| (time_left, c.key()))
+        })
+        .collect();
+    forecasts.sort_by_key(|(time_left, _)| *time_left);
+
+    if state.connection_errors.is_empty()
+        && state.reconnecting_hosts.is_empty()
+        && skews.is_empty()
+        && resources.is_empty()
+        && outcomes.is_empty()
+        && state.retrying_actions.is_empty()
+        && alerts.is_empty()
+        && forecasts.is_empty()
+        && state.export_outcome.is_none()
+        && state.copy_outcome.is_none()
+        && state.prune_outcomes.is_empty()
+        && state.bulk_progress.is_empty()
+    {
         return;
     }
 
+    let msg = i18n::messages();
     let screen_area = f.area();
 
-    // Stack errors vertically from the top
+    // Lost hosts first, they stay until the host answers again
+    let mut reconnecting: Vec<_> = state.reconnecting_hosts.iter().collect();
+    reconnecting.sort();
+    let notifications = reconnecting
+        .into_iter()
+        .map(|(host_id, attempt)| {
+            (
+                format!(
+                    "⟳ {}: {} ({} {})",
+                    host_id, msg.reconnecting, msg.attempt, attempt
+                ),
+                styles.medium,
+            )
+        })
+        .chain(
+            state
+                .connection_errors
+                .iter()
+                .map(|(host_id, (error_msg, _))| {
+                    // Shorten the error message if it's too long
+                    let error_text =
+                        format!("✗ {}: {}", host_id, truncate_with_ellipsis(error_msg, 80));
+                    (error_text, styles.high)
+                }),
+        )
+        .chain(skews.into_iter().map(|(host_id, (skew, _))| {
+            let direction = if *skew > TimeDelta::zero() {
+                msg.clock_ahead
+            } else {
+                msg.clock_behind
+            };
+            (
+                format!(
+                    "⚠ {}: {} {}",
+                    host_id,
+                    direction,
+                    format_duration_precise(skew.abs())
+                ),
+                styles.medium,
+            )
+        }))
+        .chain(resources.into_iter().flat_map(|(host_id, (resources, _))| {
+            let disk = resources.disk_low().then(|| {
+                (
+                    format!(
+                        "⚠ {}: {} ({:.0}%, {} {})",
+                        host_id,
+                        msg.disk_nearly_full,
+                        resources.disk_used_percent(),
+                        format_bytes(resources.disk_available_bytes),
+                        msg.label_free
+                    ),
+                    styles.high,
+                )
+            });
+            let load = resources.load_high().then(|| {
+                (
+                    format!(
+                        "⚠ {}: {} ({:.2}, {} CPUs)",
+                        host_id, msg.high_load, resources.load1, resources.cpus
+                    ),
+                    styles.medium,
+                )
+            });
+            disk.into_iter().chain(load)
+        }))
+        .chain(retrying.into_iter().map(|(key, (action, retry))| {
+            (
+                format!(
+                    "⟳ {}: {} {}, {} ({}/{})",
+                    state.container_label(key),
+                    msg.action(*action),
+                    msg.action_failed,
+                    msg.retrying,
+                    retry,
+                    state.action_retries
+                ),
+                styles.medium,
+            )
+        }))
+        .chain(outcomes.into_iter().map(|(key, (action, outcome, _))| {
+            let name = state.container_label(key);
+            let (icon, status, style) = verify_outcome_status(outcome, styles);
+            (
+                format!("{} {}: {}, {}", icon, name, msg.action(*action), status),
+                style,
+            )
+        }))
+        .chain(alerts)
+        .chain(forecasts.iter().map(|(time_left, key)| {
+            (
+                format!(
+                    "⚠ {}: {} ~{}",
+                    state.container_label(key),
+                    msg.memory_limit_in,
+                    format_time_left(*time_left)
+                ),
+                styles.high,
+            )
+        }))
+        .chain(state.export_outcome.as_ref().map(|(outcome, _)| {
+            let containers = |count: &usize| format!("{} {}", count, msg.containers);
+            match outcome {
+                ExportOutcome::Copied(format, count) => (
+                    format!(
+                        "✓ {}: {} ({})",
+                        msg.export_copied,
+                        containers(count),
+                        format.name()
+                    ),
+                    styles.low,
+                ),
+                ExportOutcome::Saved(path, count) => (
+                    format!(
+                        "✓ {}: {} ({})",
+                        msg.export_saved,
+                        path.display(),
+                        containers(count)
+                    ),
+                    styles.low,
+                ),
+                ExportOutcome::Failed(error) => {
+                    (format!("✗ {}: {}", msg.export_failed, error), styles.high)
+                }
+            }
+        }))
+        .chain(state.copy_outcome.as_ref().map(|(result, _)| match result {
+            // The first line of what was copied, as the labels are several
+            Ok(text) => {
+                let first_line = text.lines().next().unwrap_or_default();
+                let more = if text.lines().nth(1).is_some() {
+                    "…"
+                } else {
+                    ""
+                };
+                (
+                    format!(
+                        "✓ {}: {}{}",
+                        msg.copied,
+                        truncate_with_ellipsis(first_line, 60),
+                        more
+                    ),
+                    styles.low,
+                )
+            }
+            Err(error) => (format!("✗ {}: {}", msg.copy_failed, error), styles.high),
+        }))
+        .chain(
+            state
+                .prune_outcomes
+                .iter()
+                .map(|(host_id, target, result, _)| {
+                    let (icon, text, style) = prune_outcome_text(*target, result, styles);
+                    (format!("{} {}: {}", icon, host_id, text), style)
+                }),
+        )
+        .chain(state.bulk_progress.values().map(|progress| {
+            let style = match progress.finished_at {
+                None => styles.medium,
+                Some(_) if progress.failed > 0 => styles.high,
+                Some(_) => styles.low,
+            };
+            (bulk_progress_text(progress), style)
+        }));
+
+    // Stack notifications vertically from the top
     let mut y_offset = 0;
 
-    for (host_id, (error_msg, _)) in &state.connection_errors {
-        // Shorten the error message if it's too long and build error text directly
-        let error_text = if error_msg.len() > 80 {
-            format!("✗ {}: {}...", host_id, &error_msg[..77])
-        } else {
-            format!("✗ {}: {}", host_id, error_msg)
-        };
-        let error_width = (error_text.len() + 4).min(80) as u16; // +4 for borders and padding
-        let error_height = 3; // Border + text + border
+    for (text, style) in notifications {
+        let width = (text.len() + 4).min(80) as u16; // +4 for borders and padding
+        let height = 3; // Border + text + border
 
         // Position in top right corner, stacked vertically
-        let error_area = Rect {
-            x: screen_area.width.saturating_sub(error_width),
+        let area = Rect {
+            x: screen_area.width.saturating_sub(width),
             y: y_offset,
-            width: error_width,
-            height: error_height,
+            width,
+            height,
         };
 
-        // Create error notification with red styling from UiStyles
-        let error_widget = Paragraph::new(Line::from(vec![Span::styled(
-            error_text,
-            styles.high.add_modifier(Modifier::BOLD),
+        // Red for errors, yellow while reconnecting, using UiStyles
+        let widget = Paragraph::new(Line::from(vec![Span::styled(
+            text,
+            style.add_modifier(Modifier::BOLD),
         )]))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(styles.high),
-        )
+        .block(Block::default().borders(Borders::ALL).border_style(style))
         .alignment(Alignment::Left);
 
-        f.render_widget(error_widget, error_area);
+        f.render_widget(widget, area);
 
-        y_offset += error_height;
+        y_offset += height;
     }
 }
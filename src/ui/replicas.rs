@@ -0,0 +1,257 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{Container, ContainerStats, ViewState};
+use crate::ui::formatters::{format_bytes, format_bytes_per_sec, truncate_with_ellipsis};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Width of the Name (and Host) columns
+const NAME_WIDTH: u16 = 20;
+
+/// A compared stat: how to read it and how to show it
+struct Metric {
+    header: &'static str,
+    value: fn(&ContainerStats) -> f64,
+    format: fn(f64) -> String,
+    width: u16,
+}
+
+fn metrics() -> [Metric; 7] {
+    let msg = i18n::messages();
+    [
+        Metric {
+            header: msg.header_cpu,
+            value: |stats| stats.cpu,
+            format: |value| format!("{:.1}%", value),
+            width: 7,
+        },
+        Metric {
+            header: msg.memory,
+            value: |stats| stats.memory_used_bytes as f64,
+            format: |value| format_bytes(value as u64),
+            width: 8,
+        },
+        Metric {
+            header: msg.header_net_tx,
+            value: |stats| stats.network_tx_bytes_per_sec,
+            format: format_bytes_per_sec,
+            width: 11,
+        },
+        Metric {
+            header: msg.header_net_rx,
+            value: |stats| stats.network_rx_bytes_per_sec,
+            format: format_bytes_per_sec,
+            width: 11,
+        },
+        Metric {
+            header: msg.header_block_read,
+            value: |stats| stats.block_read_bytes_per_sec,
+            format: format_bytes_per_sec,
+            width: 11,
+        },
+        Metric {
+            header: msg.header_block_write,
+            value: |stats| stats.block_write_bytes_per_sec,
+            format: format_bytes_per_sec,
+            width: 11,
+        },
+        Metric {
+            header: msg.header_pids,
+            value: |stats| stats.pids as f64,
+            format: |value| format!("{:.0}", value),
+            width: 6,
+        },
+    ]
+}
+
+/// Renders a centered popup comparing the stats of the selected container's replicas,
+/// with the lowest and highest value of each stat highlighted and the average below
+pub fn render_replicas_popup(f: &mut Frame, state: &AppState, styles: &UiStyles) {
+    // Only shown over the container list
+    if state.view_state != ViewState::ContainerList {
+        return;
+    }
+
+    let Some(selected) = state
+        .selected_container_key()
+        .and_then(|key| state.containers.get(key))
+    else {
+        return;
+    };
+
+    let msg = i18n::messages();
+    let replicas: Vec<&Container> = state
+        .selected_container_key()
+        .map(|key| state.replica_containers(key))
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key| state.containers.get(key))
+        .collect();
+    let show_host = replicas.iter().any(|c| c.host_id != selected.host_id);
+
+    // Replicas + header + average + borders, blank lines and footer
+    let area = f.area();
+    let content_rows = if replicas.len() < 2 {
+        2
+    } else {
+        replicas.len() as u16 + 3
+    };
+    let popup_width = 110u16.min(area.width.saturating_sub(4));
+    let popup_height = (content_rows + 4).min(area.height.saturating_sub(2));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let group = match (&selected.compose_project, &selected.compose_service) {
+        (Some(project), Some(service)) => format!("{}/{}", project, service),
+        _ => selected.image.clone(),
+    };
+    let block = Block::default()
+        .title(format!(
+            " {}: {} ",
+            msg.replicas_title,
+            truncate_with_ellipsis(&group, 60)
+        ))
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + 1,
+        popup_area.width.saturating_sub(4),
+        popup_area.height.saturating_sub(3),
+    );
+
+    f.render_widget(block, popup_area);
+
+    if replicas.len() < 2 {
+        let paragraph = Paragraph::new(msg.no_replicas)
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, inner_area);
+    } else {
+        let table = replicas_table(&replicas, selected, show_host, styles);
+        f.render_widget(table, inner_area);
+    }
+
+    // Footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.replicas_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}
+
+/// One row per replica plus the average, with each stat's extremes highlighted
+fn replicas_table<'a>(
+    replicas: &[&'a Container],
+    selected: &Container,
+    show_host: bool,
+    styles: &UiStyles,
+) -> Table<'a> {
+    let msg = i18n::messages();
+    let metrics = metrics();
+
+    // (min, max) of each stat; equal values are not worth highlighting
+    let ranges: Vec<(f64, f64)> = metrics
+        .iter()
+        .map(|metric| {
+            replicas
+                .iter()
+                .map(|c| (metric.value)(&c.stats))
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                    (min.min(v), max.max(v))
+                })
+        })
+        .collect();
+
+    let mut header = vec![Cell::from(msg.header_name)];
+    if show_host {
+        header.push(Cell::from(msg.header_host));
+    }
+    header.extend(
+        metrics
+            .iter()
+            .map(|metric| Cell::from(Line::from(metric.header).right_aligned())),
+    );
+
+    let mut rows: Vec<Row> = replicas
+        .iter()
+        .map(|container| {
+            // The selected container is the one being compared
+            let name_style = if container.id == selected.id && container.host_id == selected.host_id
+            {
+                styles.header
+            } else {
+                Style::default()
+            };
+            let mut cells = vec![
+                Cell::from(truncate_with_ellipsis(&container.name, NAME_WIDTH as usize))
+                    .style(name_style),
+            ];
+            if show_host {
+                cells.push(Cell::from(truncate_with_ellipsis(
+                    &container.host_id,
+                    NAME_WIDTH as usize,
+                )));
+            }
+            cells.extend(metrics.iter().zip(&ranges).map(|(metric, &(min, max))| {
+                let value = (metric.value)(&container.stats);
+                let style = if max <= min {
+                    Style::default()
+                } else if value >= max {
+                    styles.high
+                } else if value <= min {
+                    styles.low
+                } else {
+                    Style::default()
+                };
+                Cell::from(Line::from((metric.format)(value)).right_aligned()).style(style)
+            }));
+            Row::new(cells)
+        })
+        .collect();
+
+    let mut average = vec![Cell::from(msg.label_average).style(styles.header)];
+    if show_host {
+        average.push(Cell::from(""));
+    }
+    average.extend(metrics.iter().map(|metric| {
+        let sum: f64 = replicas.iter().map(|c| (metric.value)(&c.stats)).sum();
+        let value = sum / replicas.len() as f64;
+        Cell::from(Line::from((metric.format)(value)).right_aligned()).style(styles.header)
+    }));
+    rows.push(Row::new(average).top_margin(1));
+
+    let mut constraints = vec![Constraint::Min(NAME_WIDTH)];
+    if show_host {
+        constraints.push(Constraint::Length(NAME_WIDTH));
+    }
+    constraints.extend(
+        metrics
+            .iter()
+            .map(|metric| Constraint::Length(metric.width)),
+    );
+
+    Table::new(rows, constraints)
+        .header(Row::new(header).style(styles.header))
+        .column_spacing(2)
+}
@@ -0,0 +1,72 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Cell, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::ShellSessionInfo;
+use crate::ui::formatters::format_time_elapsed;
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the shell sessions kept running in the background
+pub fn render_shell_sessions(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let msg = i18n::messages();
+
+    let block = Block::default()
+        .title(format!(
+            "{} ({}) - {}",
+            msg.shell_sessions,
+            state.shell_sessions.len(),
+            msg.shell_sessions_footer
+        ))
+        .style(styles.border);
+
+    if state.shell_sessions.is_empty() {
+        f.render_widget(Paragraph::new(msg.no_shell_sessions).block(block), area);
+        return;
+    }
+
+    let show_host_column = state.connected_hosts.len() > 1;
+
+    let mut header = vec![Cell::from("#")];
+    let mut widths = vec![Constraint::Length(4)];
+    if show_host_column {
+        header.push(Cell::from(msg.header_host));
+        widths.push(Constraint::Length(15));
+    }
+    header.extend([
+        Cell::from(msg.header_container),
+        Cell::from(msg.header_started),
+    ]);
+    widths.extend([Constraint::Min(20), Constraint::Length(16)]);
+
+    let rows = state
+        .shell_sessions
+        .iter()
+        .map(|session| session_row(session, show_host_column, styles));
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).style(styles.header))
+        .row_highlight_style(styles.selected)
+        .block(block);
+    f.render_stateful_widget(table, area, &mut state.shell_session_state);
+}
+
+/// Builds the row for a single session
+fn session_row(
+    session: &ShellSessionInfo,
+    show_host_column: bool,
+    styles: &UiStyles,
+) -> Row<'static> {
+    let mut cells = vec![Cell::from(session.id.to_string()).style(styles.container_id)];
+    if show_host_column {
+        cells.push(Cell::from(session.key.host_id.clone()));
+    }
+    cells.extend([
+        Cell::from(session.name.clone()),
+        Cell::from(format_time_elapsed(Some(&session.started_at))),
+    ]);
+    Row::new(cells)
+}
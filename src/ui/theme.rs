@@ -0,0 +1,691 @@
+//! Color themes for the UI
+//!
+//! The default palette encodes thresholds with green/yellow/red only. The
+//! accessible palettes swap those for colors that stay distinguishable with
+//! red-green color vision deficiencies (or maximize contrast), and add shape
+//! markers next to percentages so the level never depends on color alone.
+//!
+//! Every theme also has a light-background variant, picked automatically by
+//! querying the terminal background color at startup. On terminals without
+//! true color support, RGB colors are mapped to the nearest palette entry.
+//!
+//! Row striping and the selected-row highlight can be adjusted on top of any
+//! theme, for terminals where a gray background is hard to tell apart.
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::ui::icons::Icons;
+use crate::ui::render::UiStyles;
+
+/// Color theme selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// Original green/yellow/red palette
+    #[default]
+    Default,
+    /// Blue/yellow/vermillion palette safe for deuteranopia and protanopia
+    Colorblind,
+    /// Bright, bold colors on plain backgrounds
+    HighContrast,
+}
+
+impl Theme {
+    /// Returns true if thresholds should be reinforced with shape markers
+    pub fn threshold_markers(self) -> bool {
+        self != Theme::Default
+    }
+}
+
+impl std::str::FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(Theme::Default),
+            "colorblind" | "deuteranopia" | "protanopia" => Ok(Theme::Colorblind),
+            "high-contrast" | "high_contrast" | "highcontrast" => Ok(Theme::HighContrast),
+            _ => Err(format!(
+                "Invalid theme: '{}'. Use 'default', 'colorblind', or 'high-contrast'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Theme::Default => write!(f, "default"),
+            Theme::Colorblind => write!(f, "colorblind"),
+            Theme::HighContrast => write!(f, "high-contrast"),
+        }
+    }
+}
+
+/// Terminal background brightness, used to pick the light or dark variant of a theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Background {
+    /// Dark background with light text (default)
+    #[default]
+    Dark,
+    /// Light background with dark text
+    Light,
+}
+
+impl Background {
+    /// Queries the terminal background color (OSC 11) and classifies it by lightness
+    /// Returns None if the terminal doesn't answer or stdout isn't a terminal
+    pub fn detect() -> Option<Self> {
+        let options = terminal_colorsaurus::QueryOptions::default();
+        match terminal_colorsaurus::background_color(options) {
+            Ok(color) => Some(Self::from_lightness(color.perceived_lightness())),
+            Err(e) => {
+                tracing::debug!("Terminal background detection failed: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Classifies a perceived lightness (0.0 = black, 1.0 = white)
+    fn from_lightness(lightness: f32) -> Self {
+        if lightness > 0.5 {
+            Background::Light
+        } else {
+            Background::Dark
+        }
+    }
+}
+
+impl std::str::FromStr for Background {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dark" => Ok(Background::Dark),
+            "light" => Ok(Background::Light),
+            _ => Err(format!(
+                "Invalid background: '{}'. Use 'auto', 'dark', or 'light'",
+                s
+            )),
+        }
+    }
+}
+
+/// How the selected row in the container list is highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStyle {
+    /// Gray background
+    Background,
+    /// Swap foreground and background colors
+    Reverse,
+    /// Underlined text
+    Underline,
+}
+
+impl std::str::FromStr for SelectionStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "background" | "bg" => Ok(SelectionStyle::Background),
+            "reverse" | "reversed" => Ok(SelectionStyle::Reverse),
+            "underline" | "underlined" => Ok(SelectionStyle::Underline),
+            _ => Err(format!(
+                "Invalid selection style: '{}'. Use 'background', 'reverse', or 'underline'",
+                s
+            )),
+        }
+    }
+}
+
+/// How many colors the terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSupport {
+    /// 24-bit RGB (default)
+    #[default]
+    TrueColor,
+    /// xterm 256-color palette
+    Ansi256,
+    /// The basic 16 ANSI colors
+    Ansi16,
+}
+
+impl ColorSupport {
+    /// Detects color support from the COLORTERM and TERM environment variables
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    pub(crate) fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorSupport::TrueColor;
+        }
+        match term {
+            Some(term) if term.contains("256color") => ColorSupport::Ansi256,
+            Some(term)
+                if term.ends_with("-color")
+                    || term.ends_with("-16color")
+                    || term.ends_with("-8color")
+                    || matches!(
+                        term,
+                        "linux"
+                            | "xterm"
+                            | "screen"
+                            | "tmux"
+                            | "vt100"
+                            | "vt220"
+                            | "ansi"
+                            | "rxvt"
+                            | "cygwin"
+                            | "dumb"
+                    ) =>
+            {
+                ColorSupport::Ansi16
+            }
+            // Unknown or unset TERM (e.g. Windows Terminal): assume a modern terminal
+            _ => ColorSupport::TrueColor,
+        }
+    }
+
+    /// Converts a color to the nearest one this terminal can display
+    pub fn downgrade(self, color: Color) -> Color {
+        let Color::Rgb(r, g, b) = color else {
+            return color;
+        };
+        match self {
+            ColorSupport::TrueColor => color,
+            ColorSupport::Ansi256 => Color::Indexed(nearest_ansi256(r, g, b)),
+            ColorSupport::Ansi16 => nearest_ansi16(r, g, b),
+        }
+    }
+}
+
+impl std::str::FromStr for ColorSupport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "truecolor" | "24bit" => Ok(ColorSupport::TrueColor),
+            "256" => Ok(ColorSupport::Ansi256),
+            "16" => Ok(ColorSupport::Ansi16),
+            _ => Err(format!(
+                "Invalid color mode: '{}'. Use 'auto', 'truecolor', '256', or '16'",
+                s
+            )),
+        }
+    }
+}
+
+/// xterm's default RGB values for the 16 ANSI colors
+const ANSI16_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel levels of the 6x6x6 color cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let dr = r1 as i32 - r2 as i32;
+    let dg = g1 as i32 - g2 as i32;
+    let db = b1 as i32 - b2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16_PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Picks the closer of the nearest color cube entry and the nearest grayscale ramp entry
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |v: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (v as i32 - **level as i32).abs())
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube_rgb = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube = (16 + 36 * ri + 6 * gi + bi) as u8;
+
+    // Grayscale ramp 232..=255 covers 8, 18, ..., 238
+    let avg = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = (avg.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + gray_step * 10;
+    let gray = 232 + gray_step;
+
+    if distance((gray_value, gray_value, gray_value), (r, g, b)) < distance(cube_rgb, (r, g, b)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Marker appended to percentages in the medium (50-80%) range
+pub const MEDIUM_MARKER: char = '▲';
+
+/// Marker appended to percentages in the high (>80%) range
+pub const HIGH_MARKER: char = '■';
+
+impl UiStyles {
+    /// Okabe-Ito based palette: blue (low), yellow (medium), vermillion (high)
+    pub(super) fn colorblind(icons: Icons) -> Self {
+        let blue = Color::Rgb(86, 180, 233);
+        let yellow = Color::Rgb(240, 228, 66);
+        let vermillion = Color::Rgb(213, 94, 0);
+
+        Self {
+            high: Style::default().fg(vermillion).add_modifier(Modifier::BOLD),
+            medium: Style::default().fg(yellow),
+            low: Style::default().fg(blue),
+            search_bar: Style::default().fg(yellow).add_modifier(Modifier::BOLD),
+            search_match: Style::default()
+                .fg(yellow)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            title_count: Style::default().fg(yellow),
+            // Reddish purple stays distinct from the threshold colors
+            state_created: Style::default().fg(Color::Rgb(204, 121, 167)),
+            theme: Theme::Colorblind,
+            icons,
+            ..Default::default()
+        }
+    }
+
+    /// Bright ANSI colors with bold text and reversed selection
+    pub(super) fn high_contrast(icons: Icons) -> Self {
+        Self {
+            high: Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+            medium: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            low: Style::default()
+                .fg(Color::LightGreen)
+                .add_modifier(Modifier::BOLD),
+            header: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            border: Style::default().fg(Color::White),
+            selected: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            search_bar: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            search_match: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            title_name: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            title_count: Style::default().fg(Color::LightYellow),
+            title_help: Style::default().fg(Color::Gray),
+            container_id: Style::default().fg(Color::Gray),
+            created: Style::default().fg(Color::Gray),
+            network_tx: Style::default().fg(Color::LightMagenta),
+            network_rx: Style::default().fg(Color::LightCyan),
+            state_created: Style::default().fg(Color::LightCyan),
+            state_unknown: Style::default().fg(Color::White),
+            restart_loop: Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+            // The selection is already reversed
+            anomaly: Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            theme: Theme::HighContrast,
+            icons,
+            ..Default::default()
+        }
+    }
+
+    /// Replaces RGB colors with the nearest ones the terminal supports
+    pub fn downgrade_colors(self, support: ColorSupport) -> Self {
+        if support == ColorSupport::TrueColor {
+            return self;
+        }
+        let adapt = |style: Style| Style {
+            fg: style.fg.map(|c| support.downgrade(c)),
+            bg: style.bg.map(|c| support.downgrade(c)),
+            ..style
+        };
+        Self {
+            high: adapt(self.high),
+            medium: adapt(self.medium),
+            low: adapt(self.low),
+            header: adapt(self.header),
+            border: adapt(self.border),
+            selected: adapt(self.selected),
+            search_bar: adapt(self.search_bar),
+            search_match: adapt(self.search_match),
+            title_name: adapt(self.title_name),
+            title_count: adapt(self.title_count),
+            title_help: adapt(self.title_help),
+            container_id: adapt(self.container_id),
+            created: adapt(self.created),
+            network_tx: adapt(self.network_tx),
+            network_rx: adapt(self.network_rx),
+            log_timestamp: adapt(self.log_timestamp),
+            state_created: adapt(self.state_created),
+            state_unknown: adapt(self.state_unknown),
+            restart_loop: adapt(self.restart_loop),
+            anomaly: adapt(self.anomaly),
+            zebra: self.zebra.map(adapt),
+            theme: self.theme,
+            background: self.background,
+            icons: self.icons,
+        }
+    }
+
+    /// Overrides the theme's selected-row highlight
+    pub fn with_selection_style(self, selection: SelectionStyle) -> Self {
+        let selected = match selection {
+            SelectionStyle::Background => {
+                let bg = match self.background {
+                    Background::Dark => Color::DarkGray,
+                    Background::Light => Color::Rgb(210, 210, 210),
+                };
+                Style::default().bg(bg)
+            }
+            SelectionStyle::Reverse => Style::default().add_modifier(Modifier::REVERSED),
+            SelectionStyle::Underline => Style::default().add_modifier(Modifier::UNDERLINED),
+        };
+        Self {
+            selected: selected.add_modifier(Modifier::BOLD),
+            ..self
+        }
+    }
+
+    /// Shades every other row with a background just off the terminal's own
+    pub fn with_zebra_striping(self) -> Self {
+        let stripe = match (self.background, self.theme) {
+            (Background::Dark, Theme::HighContrast) => Color::Rgb(48, 48, 48),
+            (Background::Dark, _) => Color::Rgb(32, 32, 32),
+            (Background::Light, Theme::HighContrast) => Color::Rgb(220, 220, 220),
+            (Background::Light, _) => Color::Rgb(238, 238, 238),
+        };
+        Self {
+            zebra: Some(Style::default().bg(stripe)),
+            ..self
+        }
+    }
+
+    /// Leaves low thresholds, network rates and alternate rows uncolored, so the cells
+    /// changing every frame are sent without color codes over a slow link
+    pub fn with_fewer_colors(self) -> Self {
+        Self {
+            low: Style::default(),
+            network_tx: Style::default(),
+            network_rx: Style::default(),
+            zebra: None,
+            ..self
+        }
+    }
+
+    /// Darkens the palette so text stays readable on light backgrounds
+    /// (yellow and light gray text are nearly invisible on white)
+    pub(super) fn for_light_background(self) -> Self {
+        let amber = Color::Rgb(170, 110, 0);
+        let gray = Color::Rgb(120, 120, 120);
+        let theme = self.theme;
+        let dark_selected = self.selected;
+
+        let base = Self {
+            medium: Style::default().fg(amber),
+            selected: Style::default()
+                .bg(Color::Rgb(210, 210, 210))
+                .add_modifier(Modifier::BOLD),
+            search_bar: Style::default().fg(amber).add_modifier(Modifier::BOLD),
+            search_match: Style::default()
+                .fg(amber)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            title_count: Style::default().fg(amber),
+            title_help: Style::default().fg(gray),
+            container_id: Style::default().fg(gray),
+            created: Style::default().fg(gray),
+            log_timestamp: Style::default().fg(amber).add_modifier(Modifier::BOLD),
+            state_unknown: Style::default().fg(gray),
+            background: Background::Light,
+            ..self
+        };
+
+        match theme {
+            Theme::Default => Self {
+                high: Style::default().fg(Color::Rgb(190, 0, 0)),
+                low: Style::default().fg(Color::Rgb(0, 130, 0)),
+                header: Style::default()
+                    .fg(Color::Rgb(100, 50, 150))
+                    .add_modifier(Modifier::BOLD),
+                title_name: Style::default().fg(Color::Rgb(100, 50, 150)),
+                network_tx: Style::default().fg(Color::Rgb(170, 40, 140)),
+                network_rx: Style::default().fg(Color::Rgb(0, 110, 170)),
+                state_created: Style::default().fg(Color::Rgb(0, 130, 150)),
+                ..base
+            },
+            Theme::Colorblind => Self {
+                low: Style::default().fg(Color::Rgb(0, 114, 178)),
+                ..base
+            },
+            Theme::HighContrast => Self {
+                high: Style::default()
+                    .fg(Color::Rgb(170, 0, 0))
+                    .add_modifier(Modifier::BOLD),
+                medium: Style::default()
+                    .fg(Color::Rgb(140, 90, 0))
+                    .add_modifier(Modifier::BOLD),
+                low: Style::default()
+                    .fg(Color::Rgb(0, 100, 0))
+                    .add_modifier(Modifier::BOLD),
+                header: Style::default()
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                border: Style::default().fg(Color::Black),
+                // Reversed selection works on any background
+                selected: dark_selected,
+                title_name: Style::default()
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+                title_help: Style::default().fg(Color::DarkGray),
+                container_id: Style::default().fg(Color::DarkGray),
+                created: Style::default().fg(Color::DarkGray),
+                network_tx: Style::default().fg(Color::Magenta),
+                network_rx: Style::default().fg(Color::Blue),
+                state_created: Style::default().fg(Color::Blue),
+                state_unknown: Style::default().fg(Color::Black),
+                ..base
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::icons::IconStyle;
+
+    #[test]
+    fn test_parse_theme() {
+        assert_eq!("default".parse::<Theme>().unwrap(), Theme::Default);
+        assert_eq!("Colorblind".parse::<Theme>().unwrap(), Theme::Colorblind);
+        assert_eq!("deuteranopia".parse::<Theme>().unwrap(), Theme::Colorblind);
+        assert_eq!(
+            "high-contrast".parse::<Theme>().unwrap(),
+            Theme::HighContrast
+        );
+        assert!("neon".parse::<Theme>().is_err());
+    }
+
+    #[test]
+    fn test_threshold_markers() {
+        assert!(!Theme::Default.threshold_markers());
+        assert!(Theme::Colorblind.threshold_markers());
+        assert!(Theme::HighContrast.threshold_markers());
+    }
+
+    #[test]
+    fn test_colorblind_avoids_red_green() {
+        let styles = UiStyles::new(Default::default(), Theme::Colorblind, Background::Dark);
+        for style in [styles.low, styles.medium, styles.high] {
+            assert!(!matches!(style.fg, Some(Color::Red) | Some(Color::Green)));
+        }
+    }
+
+    #[test]
+    fn test_selection_style_parsing() {
+        assert_eq!(
+            "reverse".parse::<SelectionStyle>(),
+            Ok(SelectionStyle::Reverse)
+        );
+        assert_eq!(
+            "Underline".parse::<SelectionStyle>(),
+            Ok(SelectionStyle::Underline)
+        );
+        assert_eq!(
+            "bg".parse::<SelectionStyle>(),
+            Ok(SelectionStyle::Background)
+        );
+        assert!("blink".parse::<SelectionStyle>().is_err());
+    }
+
+    #[test]
+    fn test_selection_style_overrides_theme() {
+        let styles = UiStyles::new(IconStyle::Unicode, Theme::Default, Background::Dark)
+            .with_selection_style(SelectionStyle::Underline);
+        assert_eq!(styles.selected.bg, None);
+        assert!(styles.selected.add_modifier.contains(Modifier::UNDERLINED));
+
+        let styles = UiStyles::new(IconStyle::Unicode, Theme::HighContrast, Background::Light)
+            .with_selection_style(SelectionStyle::Background);
+        assert_eq!(styles.selected.bg, Some(Color::Rgb(210, 210, 210)));
+        assert!(!styles.selected.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_fewer_colors() {
+        let styles = UiStyles::new(IconStyle::Unicode, Theme::Default, Background::Dark)
+            .with_zebra_striping()
+            .with_fewer_colors();
+        assert_eq!(styles.low, Style::default());
+        assert_eq!(styles.network_rx, Style::default());
+        assert_eq!(styles.zebra, None);
+        // Warnings keep their color
+        assert_ne!(styles.high, Style::default());
+    }
+
+    #[test]
+    fn test_zebra_striping_matches_background() {
+        let dark = UiStyles::new(IconStyle::Unicode, Theme::Default, Background::Dark);
+        assert_eq!(dark.zebra, None);
+        assert_eq!(
+            dark.with_zebra_striping().zebra.and_then(|s| s.bg),
+            Some(Color::Rgb(32, 32, 32))
+        );
+
+        let light = UiStyles::new(IconStyle::Unicode, Theme::Default, Background::Light)
+            .with_zebra_striping()
+            .downgrade_colors(ColorSupport::Ansi16);
+        assert_eq!(light.zebra.and_then(|s| s.bg), Some(Color::Gray));
+    }
+
+    #[test]
+    fn test_background_from_lightness() {
+        assert_eq!(Background::from_lightness(0.1), Background::Dark);
+        assert_eq!(Background::from_lightness(0.9), Background::Light);
+        assert_eq!("light".parse::<Background>().unwrap(), Background::Light);
+        assert!("auto".parse::<Background>().is_err());
+    }
+
+    #[test]
+    fn test_light_background_avoids_yellow_text() {
+        for theme in [Theme::Default, Theme::Colorblind, Theme::HighContrast] {
+            let styles = UiStyles::new(Default::default(), theme, Background::Light);
+            assert_eq!(styles.theme, theme);
+            for style in [
+                styles.medium,
+                styles.search_bar,
+                styles.search_match,
+                styles.log_timestamp,
+            ] {
+                assert!(!matches!(
+                    style.fg,
+                    Some(Color::Yellow) | Some(Color::LightYellow)
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_support_detection() {
+        assert_eq!(
+            ColorSupport::from_env(Some("truecolor"), Some("xterm")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("linux")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("xterm-color")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(ColorSupport::from_env(None, None), ColorSupport::TrueColor);
+    }
+
+    #[test]
+    fn test_downgrade_to_ansi16() {
+        let support = ColorSupport::Ansi16;
+        assert_eq!(support.downgrade(Color::Rgb(250, 10, 10)), Color::LightRed);
+        assert_eq!(
+            support.downgrade(Color::Rgb(100, 100, 100)),
+            Color::DarkGray
+        );
+        // Named colors pass through untouched
+        assert_eq!(support.downgrade(Color::Cyan), Color::Cyan);
+    }
+
+    #[test]
+    fn test_downgrade_to_ansi256() {
+        let support = ColorSupport::Ansi256;
+        assert_eq!(
+            support.downgrade(Color::Rgb(255, 0, 0)),
+            Color::Indexed(196)
+        );
+        assert_eq!(support.downgrade(Color::Rgb(0, 0, 0)), Color::Indexed(16));
+        assert_eq!(
+            support.downgrade(Color::Rgb(80, 80, 80)),
+            Color::Indexed(239)
+        );
+    }
+
+    #[test]
+    fn test_downgrade_styles_leaves_no_rgb() {
+        let styles = UiStyles::default().downgrade_colors(ColorSupport::Ansi16);
+        for style in [styles.header, styles.title_help, styles.network_tx] {
+            assert!(!matches!(style.fg, Some(Color::Rgb(..))));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{HealthStatus, TimelineEntry, TimelineKind};
+use crate::ui::formatters::{format_timestamp, pad_to_width};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Renders the container events of all hosts, newest first
+pub fn render_timeline(f: &mut Frame, area: Rect, state: &AppState, styles: &UiStyles) {
+    let msg = i18n::messages();
+
+    let block = Block::default()
+        .title(format!(
+            "{} ({}) - {}",
+            msg.events,
+            state.timeline.len(),
+            msg.logs_return_hint
+        ))
+        .style(styles.border);
+
+    if state.timeline.is_empty() {
+        f.render_widget(Paragraph::new(msg.no_events).block(block), area);
+        return;
+    }
+
+    let show_host = state.connected_hosts.len() > 1;
+    let lines: Vec<Line> = state
+        .timeline
+        .iter()
+        .rev()
+        .skip(state.timeline_scroll)
+        .take(area.height as usize)
+        .map(|entry| timeline_line(entry, show_host, styles))
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// "10:15:30  web  exited (137)"
+fn timeline_line(entry: &TimelineEntry, show_host: bool, styles: &UiStyles) -> Line<'static> {
+    let msg = i18n::messages();
+
+    let (text, style) = match &entry.kind {
+        TimelineKind::Started => (msg.event_started.to_string(), styles.low),
+        TimelineKind::Exited(Some(code)) if *code != 0 => {
+            (format!("{} ({})", msg.event_exited, code), styles.high)
+        }
+        TimelineKind::Exited(_) => (msg.event_exited.to_string(), Style::default()),
+        TimelineKind::Removed => (msg.event_removed.to_string(), Style::default()),
+        TimelineKind::Paused => (msg.event_paused.to_string(), Style::default()),
+        TimelineKind::Unpaused => (msg.event_unpaused.to_string(), Style::default()),
+        TimelineKind::Renamed(previous) => (
+            format!("{} {}", msg.event_renamed, previous),
+            Style::default(),
+        ),
+        TimelineKind::HealthChanged(health) => {
+            let (label, style) = match health {
+                HealthStatus::Healthy => (msg.healthy, styles.low),
+                HealthStatus::Unhealthy => (msg.unhealthy, styles.high),
+                HealthStatus::Starting => (msg.starting, Style::default()),
+            };
+            (format!("{}: {}", msg.event_health, label), style)
+        }
+        TimelineKind::Exec(command) => (format!("{}: {}", msg.event_exec, command), styles.medium),
+        TimelineKind::LogSnapshot(Ok(path)) => (
+            format!("{}: {}", msg.event_logs_saved, path.display()),
+            Style::default(),
+        ),
+        TimelineKind::LogSnapshot(Err(error)) => (
+            format!("{}: {}", msg.event_logs_not_saved, error),
+            styles.high,
+        ),
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format_timestamp(&entry.time, "%H:%M:%S"),
+            styles.log_timestamp,
+        ),
+        Span::raw("  "),
+    ];
+    if show_host {
+        spans.push(Span::styled(
+            format!("{} ", pad_to_width(&entry.key.host_id, 15)),
+            styles.container_id,
+        ));
+    }
+    spans.push(Span::styled(
+        format!("{} ", pad_to_width(&entry.name, 25)),
+        styles.title_name,
+    ));
+    spans.push(Span::styled(text, style));
+
+    Line::from(spans)
+}
@@ -2,13 +2,13 @@
 mod tests {
     use crate::core::app_state::AppState;
     use crate::core::types::{
-        Container, ContainerKey, ContainerState, ContainerStats, SortField, ViewState,
+        Container, ContainerKey, ContainerState, ContainerStats, HealthStatus, SortField, ViewState,
     };
     use crate::ui::render::{UiStyles, render_ui};
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
     use ratatui::buffer::Buffer;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use tokio::sync::mpsc;
 
     /// Helper function to convert Buffer to a string representation
@@ -46,6 +46,11 @@ mod tests {
         AppState::new(HashMap::new(), tx, false, SortField::Uptime)
     }
 
+    /// Key of a test container, whose full ID is its short ID padded with zeros
+    fn test_key(host_id: &str, id: &str) -> ContainerKey {
+        ContainerKey::new(host_id.to_string(), format!("{:0<64}", id))
+    }
+
     /// Helper function to create a test container
     fn create_test_container(
         id: &str,
@@ -63,10 +68,25 @@ mod tests {
 
         Container {
             id: id.to_string(),
+            full_id: format!("{:0<64}", id),
             name: name.to_string(),
+            image: String::new(),
             state: ContainerState::Running,
             health: None,
             created,
+            started_at: None,
+            finished_at: None,
+            exit_code: None,
+            restart_count: 0,
+            session_restarts: 0,
+            size_rw: None,
+            platform: None,
+            time_to_healthy: None,
+            ports: Vec::new(),
+            labels: BTreeMap::new(),
+            compose_service: None,
+            compose_depends_on: Vec::new(),
+            compose_project: None,
             stats: ContainerStats {
                 cpu,
                 memory,
@@ -121,7 +141,7 @@ mod tests {
         ];
 
         for container in containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -172,7 +192,7 @@ mod tests {
         ];
 
         for container in containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -232,7 +252,7 @@ mod tests {
         ];
 
         for container in containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -259,12 +279,12 @@ mod tests {
         // Add a container
         let container =
             create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
 
         // Switch to log view
         state.view_state = ViewState::LogView(key.clone());
-        state.is_at_bottom = true;
+        state.follow_logs = true;
 
         // Create empty log state
         use crate::core::types::LogState;
@@ -293,12 +313,12 @@ mod tests {
         // Add a container
         let container =
             create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
 
         // Switch to log view and add some log lines
         state.view_state = ViewState::LogView(key.clone());
-        state.is_at_bottom = true;
+        state.follow_logs = true;
 
         // Create log entries instead of formatted text
         use crate::core::types::LogState;
@@ -359,12 +379,12 @@ mod tests {
         // Add a container
         let container =
             create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
 
         // Switch to log view with manual scroll
         state.view_state = ViewState::LogView(key.clone());
-        state.is_at_bottom = false; // Manual scroll mode
+        state.follow_logs = false; // Manual scroll mode
 
         // Create log state with log content
         use crate::core::types::LogState;
@@ -434,7 +454,7 @@ mod tests {
         ];
 
         for container in running_containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -443,20 +463,50 @@ mod tests {
         let stopped_containers = vec![
             Container {
                 id: "stop12345678".to_string(),
+                full_id: format!("{:0<64}", "stop12345678"),
                 name: "old-redis".to_string(),
+                image: String::new(),
                 state: ContainerState::Exited,
                 health: None,
                 created: Some(Utc::now() - chrono::Duration::days(1)),
+                started_at: None,
+                finished_at: None,
+                exit_code: None,
+                restart_count: 0,
+                session_restarts: 0,
+                size_rw: None,
+                platform: None,
+                time_to_healthy: None,
+                ports: Vec::new(),
+                labels: BTreeMap::new(),
+                compose_service: None,
+                compose_depends_on: Vec::new(),
+                compose_project: None,
                 stats: ContainerStats::default(), // Stats should not be shown
                 host_id: "local".to_string(),
                 dozzle_url: None,
             },
             Container {
                 id: "dead12345678".to_string(),
+                full_id: format!("{:0<64}", "dead12345678"),
                 name: "failed-app".to_string(),
+                image: String::new(),
                 state: ContainerState::Dead,
                 health: None,
                 created: Some(Utc::now() - chrono::Duration::hours(3)),
+                started_at: None,
+                finished_at: None,
+                exit_code: None,
+                restart_count: 0,
+                session_restarts: 0,
+                size_rw: None,
+                platform: None,
+                time_to_healthy: None,
+                ports: Vec::new(),
+                labels: BTreeMap::new(),
+                compose_service: None,
+                compose_depends_on: Vec::new(),
+                compose_project: None,
                 stats: ContainerStats::default(), // Stats should not be shown
                 host_id: "local".to_string(),
                 dozzle_url: None,
@@ -464,7 +514,7 @@ mod tests {
         ];
 
         for container in stopped_containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -495,7 +545,7 @@ mod tests {
         let container =
             create_test_container("abc123456789", "nginx", "local", 45.5, 62.3, 1024.0, 2048.0);
 
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
         state.sorted_container_keys.push(key);
 
@@ -515,8 +565,11 @@ mod tests {
         // Verify that sparklines are present (containing braille characters)
         // Using braille patterns: ⠀ (empty), ⣀, ⣤, ⣶, ⣿ (full)
         assert!(
-            output.contains('⠀') || output.contains('⣀') || output.contains('⣤')
-                || output.contains('⣶') || output.contains('⣿'),
+            output.contains('⠀')
+                || output.contains('⣀')
+                || output.contains('⣤')
+                || output.contains('⣶')
+                || output.contains('⣿'),
             "Wide terminal (150 chars) should display sparkline graphs"
         );
 
@@ -544,7 +597,7 @@ mod tests {
         ];
 
         for container in containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -591,7 +644,7 @@ mod tests {
         ];
 
         for container in containers {
-            let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+            let key = container.key();
             state.containers.insert(key.clone(), container);
             state.sorted_container_keys.push(key);
         }
@@ -629,7 +682,7 @@ mod tests {
         // Add a container
         let container =
             create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
         state.sorted_container_keys.push(key);
 
@@ -666,7 +719,7 @@ mod tests {
         // Add a running container
         let container =
             create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
         state.sorted_container_keys.push(key.clone());
         state.table_state.select(Some(0));
@@ -697,6 +750,517 @@ mod tests {
         assert_snapshot_with_redaction!(output);
     }
 
+    #[test]
+    fn test_prune_menu() {
+        use crate::core::types::{AppEvent, PruneReport, PruneTarget};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+
+        // Prune is the second to last action, before maintenance
+        state.handle_event(AppEvent::EnterPressed);
+        let prune_index = state.actions_for(&state.containers[&key]).len() - 2;
+        state.action_menu_state.select(Some(prune_index));
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.view_state, ViewState::PruneMenu("local".to_string()));
+
+        // The first Enter only asks for confirmation, moving away drops it
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.prune_confirm, Some(PruneTarget::DanglingImages));
+
+        let backend = TestBackend::new(120, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Prune (local)"));
+        assert!(output.contains("Unused networks"));
+        assert!(output.contains("Enter again to prune"));
+
+        state.handle_event(AppEvent::SelectActionUp);
+        assert_eq!(state.prune_confirm, None);
+
+        // Esc closes the confirmation first, then the menu
+        state.handle_event(AppEvent::EnterPressed);
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.prune_confirm, None);
+        assert!(matches!(state.view_state, ViewState::PruneMenu(_)));
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        state.handle_event(AppEvent::PruneFinished(
+            "local".to_string(),
+            PruneTarget::StoppedContainers,
+            Ok(PruneReport {
+                removed: 3,
+                reclaimed: 5 * 1024 * 1024,
+            }),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("local: Stopped containers pruned, 3 removed, 5 M freed"));
+    }
+
+    #[test]
+    fn test_stop_timeout_prompt() {
+        use crate::core::types::{AppEvent, ContainerAction};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut state = create_test_app_state();
+        state.stop_timeout = 30;
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "postgres",
+                "local",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            )],
+        ));
+        let container_key = test_key("local", "abc123456789");
+
+        // T only applies to Stop and Restart
+        state.handle_event(AppEvent::EnterPressed);
+        state.handle_event(AppEvent::PromptStopTimeout);
+        assert_eq!(
+            state.view_state,
+            ViewState::ActionMenu(container_key.clone())
+        );
+
+        // Shell, Exec command, Browse files, Run health check, Stop
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::PromptStopTimeout);
+        assert_eq!(
+            state.view_state,
+            ViewState::StopTimeoutPrompt(container_key, ContainerAction::Stop)
+        );
+        assert_eq!(state.stop_timeout_input.value(), "30");
+
+        // Only digits are taken
+        let key = |code| AppEvent::SearchKeyEvent(KeyEvent::new(code, KeyModifiers::NONE));
+        state.handle_event(key(KeyCode::Backspace));
+        state.handle_event(key(KeyCode::Backspace));
+        for c in "12x0".chars() {
+            state.handle_event(key(KeyCode::Char(c)));
+        }
+        assert_eq!(state.stop_timeout_input.value(), "120");
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Stop: postgres"));
+        assert!(output.contains("Seconds before killing: 120"));
+
+        // Esc leaves the configured timeout alone
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert_eq!(state.stop_timeout, 30);
+    }
+
+    #[test]
+    fn test_alert_banners() {
+        use crate::cli::config::{AlertRuleConfig, AlertsConfig};
+        use crate::core::alerts::Alerts;
+        use crate::core::types::{AppEvent, ContainerStats, HealthStatus, RenderAction};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let rule = |metric: &str, above| AlertRuleConfig {
+            metric: metric.to_string(),
+            above,
+            for_secs: None,
+            severity: None,
+        };
+        state.alerts = Alerts::from_config(&AlertsConfig {
+            rules: vec![rule("cpu", Some(90.0)), rule("unhealthy", None)],
+            ..Default::default()
+        })
+        .unwrap();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("abc123456789", "api", "local", 1.0, 1.0, 0.0, 0.0),
+                create_test_container("def123456789", "worker", "local", 1.0, 1.0, 0.0, 0.0),
+            ],
+        ));
+        let api = test_key("local", "abc123456789");
+        let worker = test_key("local", "def123456789");
+
+        let cpu = |cpu| ContainerStats {
+            cpu,
+            ..Default::default()
+        };
+        let action = state.handle_event(AppEvent::ContainerStat(api.clone(), cpu(95.0)));
+        assert_eq!(action, RenderAction::Render);
+        state.handle_event(AppEvent::ContainerHealthChanged(
+            worker,
+            HealthStatus::Unhealthy,
+            chrono::Utc::now(),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⚠ api: CPU 95.0% > 90% (0s)"));
+        assert!(output.contains("⚠ worker: Unhealthy (0s)"));
+
+        // Cleared once the CPU drops again
+        state.handle_event(AppEvent::ContainerStat(api, cpu(20.0)));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("api: CPU"));
+        assert!(output.contains("⚠ worker: Unhealthy"));
+    }
+
+    #[test]
+    fn test_do_not_disturb_hides_non_critical_alerts() {
+        use crate::cli::config::{AlertRuleConfig, AlertsConfig};
+        use crate::core::alerts::Alerts;
+        use crate::core::types::{AppEvent, ContainerStats, HealthStatus};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let rule = |metric: &str, above, severity: &str| AlertRuleConfig {
+            metric: metric.to_string(),
+            above,
+            for_secs: None,
+            severity: Some(severity.to_string()),
+        };
+        state.alerts = Alerts::from_config(&AlertsConfig {
+            rules: vec![
+                rule("cpu", Some(90.0), "info"),
+                rule("unhealthy", None, "critical"),
+            ],
+            ..Default::default()
+        })
+        .unwrap();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("abc123456789", "api", "local", 1.0, 1.0, 0.0, 0.0),
+                create_test_container("def123456789", "worker", "local", 1.0, 1.0, 0.0, 0.0),
+            ],
+        ));
+        state.handle_event(AppEvent::ToggleDoNotDisturb);
+        assert!(state.do_not_disturb);
+
+        state.handle_event(AppEvent::ContainerStat(
+            test_key("local", "abc123456789"),
+            ContainerStats {
+                cpu: 95.0,
+                ..Default::default()
+            },
+        ));
+        state.handle_event(AppEvent::ContainerHealthChanged(
+            test_key("local", "def123456789"),
+            HealthStatus::Unhealthy,
+            chrono::Utc::now(),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("do not disturb"));
+        assert!(!output.contains("api: CPU"));
+        assert!(output.contains("⚠ worker: Unhealthy"));
+        // Both are kept in the notifications panel
+        assert_eq!(state.unacknowledged_notifications(), 2);
+
+        state.handle_event(AppEvent::ToggleDoNotDisturb);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⚠ api: CPU 95.0% > 90%"));
+    }
+
+    #[test]
+    fn test_maintenance_suppresses_alerts() {
+        use crate::cli::config::{AlertRuleConfig, AlertsConfig};
+        use crate::core::alerts::Alerts;
+        use crate::core::types::{AppEvent, ContainerStats};
+        use ratatui::style::Modifier;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.alerts = Alerts::from_config(&AlertsConfig {
+            rules: vec![AlertRuleConfig {
+                metric: "cpu".to_string(),
+                above: Some(90.0),
+                for_secs: None,
+                severity: Some("critical".to_string()),
+            }],
+            ..Default::default()
+        })
+        .unwrap();
+
+        let key = test_key("local", "abc123456789");
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "api",
+                "local",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            )],
+        ));
+
+        // Maintenance is the last action, 30 minutes the second duration
+        state.handle_event(AppEvent::EnterPressed);
+        let maintenance_index = state.actions_for(&state.containers[&key]).len() - 1;
+        state.action_menu_state.select(Some(maintenance_index));
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(
+            state.view_state,
+            ViewState::MaintenanceMenu("local".to_string())
+        );
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.in_maintenance(&"local".to_string()));
+
+        state.handle_event(AppEvent::ContainerStat(
+            key.clone(),
+            ContainerStats {
+                cpu: 95.0,
+                ..Default::default()
+            },
+        ));
+        state.handle_event(AppEvent::ConnectionError(
+            "local".to_string(),
+            "connection refused".to_string(),
+        ));
+        assert_eq!(state.unacknowledged_notifications(), 0);
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("maintenance: local 29m"));
+        assert!(!output.contains("api: CPU"));
+        // The host's containers are greyed out
+        let buffer = terminal.backend().buffer();
+        let api_row = (0..buffer.area.height)
+            .find(|&y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .contains("api")
+            })
+            .unwrap();
+        assert!(buffer[(2, api_row)].modifier.contains(Modifier::DIM));
+
+        // The menu now starts with ending it
+        state.handle_event(AppEvent::EnterPressed);
+        state.action_menu_state.select(Some(maintenance_index));
+        state.handle_event(AppEvent::EnterPressed);
+        state.handle_event(AppEvent::EnterPressed);
+        assert!(!state.in_maintenance(&"local".to_string()));
+
+        // Alerts that keep firing show again once it ends
+        state.handle_event(AppEvent::ContainerStat(
+            key,
+            ContainerStats {
+                cpu: 96.0,
+                ..Default::default()
+            },
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⚠ api: CPU"));
+        assert!(!output.contains("maintenance"));
+    }
+
+    #[test]
+    fn test_action_verification_notifications() {
+        use crate::core::types::{AppEvent, ContainerAction, HealthStatus, VerifyOutcome};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("abc123456789", "api", "local", 1.0, 1.0, 0.0, 0.0),
+                create_test_container("def123456789", "worker", "local", 1.0, 1.0, 0.0, 0.0),
+            ],
+        ));
+
+        state.handle_event(AppEvent::ActionVerified(
+            test_key("local", "abc123456789"),
+            ContainerAction::Restart,
+            VerifyOutcome::StayedUp {
+                secs: 10,
+                health: Some(HealthStatus::Healthy),
+            },
+        ));
+        state.handle_event(AppEvent::ActionVerified(
+            test_key("local", "def123456789"),
+            ContainerAction::Start,
+            VerifyOutcome::Crashed {
+                secs: 2,
+                exit_code: Some(1),
+            },
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("✓ api: Restart, stayed up for 10s (Healthy)"));
+        assert!(output.contains("✗ worker: Start, crashed after 2s (exit 1)"));
+    }
+
+    #[test]
+    fn test_clock_skew_warning() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = true;
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "prod".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "api",
+                "prod",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            )],
+        ));
+
+        // Within the threshold nothing is reported
+        state.handle_event(AppEvent::HostClockSkew(
+            "prod".to_string(),
+            chrono::TimeDelta::milliseconds(800),
+        ));
+        assert!(state.clock_skews.is_empty());
+
+        state.handle_event(AppEvent::HostClockSkew(
+            "prod".to_string(),
+            chrono::TimeDelta::seconds(-42),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⚠ prod: clock behind by 42.0s"));
+        assert!(output.contains("prod ⚠ -42.0s"));
+
+        // Back in sync after a reconnect
+        state.handle_event(AppEvent::HostClockSkew(
+            "prod".to_string(),
+            chrono::TimeDelta::zero(),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("⚠"));
+    }
+
+    #[test]
+    fn test_host_resources_warning() {
+        use crate::core::types::{AppEvent, HostResources};
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = true;
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "prod".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "api",
+                "prod",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            )],
+        ));
+
+        const GIB: u64 = 1024 * 1024 * 1024;
+        state.handle_event(AppEvent::HostResources(
+            "prod".to_string(),
+            HostResources {
+                disk_used_bytes: 93 * GIB,
+                disk_available_bytes: 7 * GIB,
+                load1: 9.2,
+                cpus: 4,
+            },
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⚠ prod: disk nearly full (93%, 7 G free)"));
+        assert!(output.contains("⚠ prod: high load (9.20, 4 CPUs)"));
+        assert!(output.contains("prod ⚠ disk 93% ⚠ load 9.2"));
+
+        // Space freed and load gone on the next measurement
+        state.handle_event(AppEvent::HostResources(
+            "prod".to_string(),
+            HostResources {
+                disk_used_bytes: 60 * GIB,
+                disk_available_bytes: 40 * GIB,
+                load1: 0.5,
+                cpus: 4,
+            },
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("⚠"));
+    }
+
     #[test]
     fn test_connection_error_notification() {
         let mut state = create_test_app_state();
@@ -705,7 +1269,7 @@ mod tests {
         // Add a successful container
         let container =
             create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
-        let key = ContainerKey::new(container.host_id.clone(), container.id.clone());
+        let key = container.key();
         state.containers.insert(key.clone(), container);
         state.sorted_container_keys.push(key);
 
@@ -740,4 +1304,3529 @@ mod tests {
 
         assert_snapshot_with_redaction!(output);
     }
+
+    #[test]
+    fn test_long_names_use_space_from_short_hosts() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let long_name = "myproject-backend-worker-service-1";
+        let containers = vec![
+            create_test_container("abc123456789", long_name, "local", 5.0, 10.0, 0.0, 0.0),
+            create_test_container("def987654321", "db", "prod", 5.0, 10.0, 0.0, 0.0),
+        ];
+
+        for container in containers {
+            let key = container.key();
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+
+        let backend = TestBackend::new(120, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(
+            output.contains(long_name),
+            "Name column should grow into space the short Host column doesn't need"
+        );
+    }
+
+    #[test]
+    fn test_truncated_name_shows_ellipsis_and_details_popup() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let long_name = "myproject-backend-worker-service-with-a-very-long-name-1";
+        let container =
+            create_test_container("abc123456789", long_name, "local", 5.0, 10.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key);
+        state.table_state.select(Some(0));
+
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains(long_name), "Name should be truncated");
+        assert!(
+            output.contains('…'),
+            "Truncated name should end with an ellipsis"
+        );
+
+        state.show_details = true;
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(
+            output.contains(long_name),
+            "Details popup should show the full name"
+        );
+        assert!(output.contains("Esc/i: Close"));
+    }
+
+    #[test]
+    fn test_duplicate_names_across_hosts() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        for (id, name, host) in [
+            ("abc123456789", "web", "local"),
+            ("def987654321", "web", "remote"),
+            ("fed111111111", "db", "remote"),
+        ] {
+            let container = create_test_container(id, name, host, 1.0, 1.0, 0.0, 0.0);
+            let key = container.key();
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+        state.table_state.select(Some(0));
+
+        let local_web = test_key("local", "abc123456789");
+        let remote_web = test_key("remote", "def987654321");
+        let db = test_key("remote", "fed111111111");
+        assert_eq!(state.hosts_sharing_name(&local_web), ["remote"]);
+        assert_eq!(state.container_label(&local_web), "web@local");
+        assert_eq!(state.container_label(&remote_web), "web@remote");
+        assert_eq!(state.container_label(&db), "db");
+
+        state.show_details = true;
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Same name also on: remote"));
+    }
+
+    #[test]
+    fn test_ports_in_details() {
+        use crate::core::types::PortMapping;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut container =
+            create_test_container("abc123456789", "nginx", "local", 5.0, 10.0, 0.0, 0.0);
+        container.ports = vec![
+            PortMapping {
+                host_ip: None,
+                host_port: 8080,
+                container_port: 80,
+                protocol: "tcp".to_string(),
+            },
+            PortMapping {
+                host_ip: Some("127.0.0.1".to_string()),
+                host_port: 5353,
+                container_port: 53,
+                protocol: "udp".to_string(),
+            },
+        ];
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key);
+        state.table_state.select(Some(0));
+
+        state.show_details = true;
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("8080:80, 127.0.0.1:5353:53/udp"));
+    }
+
+    #[test]
+    fn test_label_search_and_details() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut payments =
+            create_test_container("abc123456789", "api", "local", 5.0, 10.0, 0.0, 0.0);
+        payments.labels = BTreeMap::from([
+            ("role".to_string(), "db".to_string()),
+            ("team".to_string(), "payments".to_string()),
+        ]);
+        let mut search =
+            create_test_container("def123456789", "search", "local", 5.0, 10.0, 0.0, 0.0);
+        search.labels = BTreeMap::from([("team".to_string(), "search".to_string())]);
+        for container in [payments, search] {
+            state.containers.insert(container.key(), container);
+        }
+
+        let names = |state: &AppState| -> Vec<String> {
+            let mut names: Vec<String> = state
+                .sorted_container_keys
+                .iter()
+                .map(|key| state.containers[key].name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+        for (query, expected) in [
+            ("team=payments", vec!["api"]),
+            ("label:role=db", vec!["api"]),
+            ("label:team", vec!["api", "search"]),
+            ("team=", vec!["api", "search"]),
+            ("payments", vec![]),
+        ] {
+            state.search_input = tui_input::Input::new(query.to_string());
+            state.force_sort_containers();
+            assert_eq!(names(&state), expected, "query {}", query);
+        }
+
+        // The details popup lists the labels
+        state.search_input = tui_input::Input::new("team=pay".to_string());
+        state.force_sort_containers();
+        state.table_state.select(Some(0));
+        state.show_details = true;
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Labels"));
+        assert!(output.contains("role=db"));
+        assert!(output.contains("team=payments"));
+    }
+
+    #[test]
+    fn test_systemd_unit_action_and_details() {
+        use crate::core::types::ContainerAction;
+        use crate::docker::connection::DockerHost;
+        use crate::docker::systemd::CommandHost;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut web = create_test_container("abc123456789", "web", "local", 5.0, 10.0, 0.0, 0.0);
+        web.labels = BTreeMap::from([(
+            "PODMAN_SYSTEMD_UNIT".to_string(),
+            "container-web.service".to_string(),
+        )]);
+        let plain = create_test_container("def123456789", "db", "local", 5.0, 10.0, 0.0, 0.0);
+
+        let docker = bollard::Docker::connect_with_http(
+            "tcp://localhost:2375",
+            1,
+            bollard::API_DEFAULT_VERSION,
+        )
+        .unwrap();
+        let mut host = DockerHost::new("local".to_string(), docker, None, HashMap::new());
+
+        // Only offered where systemctl can be run
+        state
+            .connected_hosts
+            .insert("local".to_string(), host.clone());
+        assert!(
+            !state
+                .actions_for(&web)
+                .contains(&ContainerAction::RestartUnit)
+        );
+        host.command_host = Some(CommandHost::Local);
+        state.connected_hosts.insert("local".to_string(), host);
+        assert!(
+            state
+                .actions_for(&web)
+                .contains(&ContainerAction::RestartUnit)
+        );
+        assert!(
+            !state
+                .actions_for(&plain)
+                .contains(&ContainerAction::RestartUnit)
+        );
+
+        // The details popup names the unit and how to restart it
+        state.containers.insert(web.key(), web);
+        state.force_sort_containers();
+        state.table_state.select(Some(0));
+        state.show_details = true;
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("systemctl restart container-web.service"));
+    }
+
+    #[test]
+    fn test_time_to_healthy_in_details() {
+        use crate::core::types::{AppEvent, HealthStatus};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let started_at = chrono::Utc::now() - chrono::Duration::minutes(5);
+        let mut container =
+            create_test_container("abc123456789", "nginx", "local", 5.0, 10.0, 0.0, 0.0);
+        container.started_at = Some(started_at);
+        container.health = Some(HealthStatus::Starting);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+
+        let healthy_at = started_at + chrono::Duration::milliseconds(12_300);
+        state.handle_event(AppEvent::ContainerHealthChanged(
+            key.clone(),
+            HealthStatus::Healthy,
+            healthy_at,
+        ));
+
+        // Recovering from unhealthy later is not a start
+        state.handle_event(AppEvent::ContainerHealthChanged(
+            key.clone(),
+            HealthStatus::Unhealthy,
+            healthy_at + chrono::Duration::minutes(1),
+        ));
+        state.handle_event(AppEvent::ContainerHealthChanged(
+            key.clone(),
+            HealthStatus::Healthy,
+            healthy_at + chrono::Duration::minutes(2),
+        ));
+        assert_eq!(
+            state.containers[&key].time_to_healthy,
+            Some(chrono::Duration::milliseconds(12_300))
+        );
+
+        state.show_details = true;
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Healthy in 12.3s"));
+    }
+
+    #[test]
+    fn test_hidden_id_column_and_full_id_details() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 5.0, 10.0, 0.0, 0.0);
+        let full_id = container.full_id.clone();
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key);
+        state.table_state.select(Some(0));
+        state.show_id_column = false;
+
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("nginx"));
+        assert!(
+            !output.contains("abc123456789"),
+            "ID column should be hidden"
+        );
+
+        state.show_details = true;
+        state.show_full_id = true;
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(
+            output.contains(&full_id),
+            "Details popup should show the full ID"
+        );
+    }
+
+    #[test]
+    fn test_status_text_column() {
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut running =
+            create_test_container("abc123456789", "nginx", "local", 5.0, 10.0, 0.0, 0.0);
+        running.started_at = Some(chrono::Utc::now() - chrono::Duration::hours(3));
+        running.health = Some(HealthStatus::Healthy);
+        let mut exited =
+            create_test_container("def987654321", "worker", "local", 0.0, 0.0, 0.0, 0.0);
+        exited.state = ContainerState::Exited;
+        exited.exit_code = Some(137);
+
+        for container in [running, exited] {
+            let key = container.key();
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+        state.show_status_column = true;
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Status"));
+        assert!(output.contains("Up 3 hours (healthy)"));
+        assert!(output.contains("Exited (137)"));
+    }
+
+    #[test]
+    fn test_restarts_column_when_sorting_by_restarts() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut flaky = create_test_container("abc123456789", "flaky", "local", 1.0, 1.0, 0.0, 0.0);
+        flaky.restart_count = 4;
+        let stable = create_test_container("def987654321", "stable", "local", 1.0, 1.0, 0.0, 0.0);
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![flaky.clone(), stable],
+        ));
+        // Seen starting again twice during the session
+        state.handle_event(AppEvent::ContainerCreated(Box::new(flaky.clone())));
+        state.handle_event(AppEvent::ContainerCreated(Box::new(flaky)));
+        state.handle_event(AppEvent::SetSortField(SortField::Restarts));
+
+        let key = test_key("local", "abc123456789");
+        assert_eq!(state.containers[&key].session_restarts, 2);
+        assert_eq!(state.sorted_container_keys[0], key);
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Restarts"));
+        assert!(output.contains("4 (+2)"));
+    }
+
+    #[test]
+    fn test_restart_loop_flagged() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut flaky = create_test_container("abc123456789", "flaky", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![flaky.clone()],
+        ));
+        let key = test_key("local", "abc123456789");
+
+        // Three restarts are still within the default threshold
+        for _ in 0..3 {
+            state.handle_event(AppEvent::ContainerCreated(Box::new(flaky.clone())));
+        }
+        assert!(!state.in_restart_loop(&key));
+
+        // Docker counted two more restarts than there were events for
+        flaky.restart_count = 2;
+        state.handle_event(AppEvent::ContainerCreated(Box::new(flaky)));
+        assert!(state.in_restart_loop(&key));
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("∞"));
+
+        // Restarts outside the window no longer count
+        state.restart_loop_window = std::time::Duration::ZERO;
+        assert!(!state.in_restart_loop(&key));
+    }
+
+    #[test]
+    fn test_anomaly_highlighted() {
+        use crate::cli::config::AnomaliesConfig;
+        use crate::core::anomalies::Anomalies;
+        use crate::core::types::AppEvent;
+        use ratatui::style::Modifier;
+
+        let mut state = create_test_app_state();
+        state.anomalies = Some(Anomalies::from_config(&AnomaliesConfig::default()).unwrap());
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 10.0, 20.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web.clone()],
+        ));
+        let key = test_key("local", "abc123456789");
+        let mut stats = web.stats.clone();
+        for _ in 0..40 {
+            state.handle_event(AppEvent::ContainerStat(key.clone(), stats.clone()));
+        }
+        stats.cpu = 85.0;
+        state.handle_event(AppEvent::ContainerStat(key, stats));
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+
+        // Only the CPU cell stands out, memory is as usual
+        let highlighted: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .filter(|cell| cell.modifier.contains(Modifier::REVERSED))
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(highlighted.contains("85"));
+        assert!(!highlighted.contains("20"));
+    }
+
+    #[test]
+    fn test_memory_exhaustion_forecast_banner() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        // 1 MB more every 30 seconds, 20 MB short of the limit: full in about 10 minutes
+        const MB: u64 = 1024 * 1024;
+        let mut web = create_test_container("abc123456789", "web", "local", 1.0, 90.0, 0.0, 0.0);
+        web.stats.memory_limit_bytes = 200 * MB;
+        web.stats.memory_used_bytes = 180 * MB;
+        let now_bucket = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            / crate::core::types::MEMORY_TREND_BUCKET_SECS;
+        web.stats.memory_trend = (0..20)
+            .map(|i| (now_bucket - 20 + i, (160 + i) * MB))
+            .collect();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web.clone()],
+        ));
+
+        // New stats keep the trend
+        state.handle_event(AppEvent::ContainerStat(
+            test_key("local", "abc123456789"),
+            web.stats.clone(),
+        ));
+        let stats = &state.containers[&test_key("local", "abc123456789")].stats;
+        assert!(stats.memory_trend.len() > 20);
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("⚠ web: memory limit in ~"));
+    }
+
+    #[test]
+    fn test_deploy_marker_on_recreate() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut old = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        old.stats.cpu_history = [5.0, 5.0, 5.0].into_iter().collect();
+        old.stats.memory_history = [5.0, 5.0, 5.0].into_iter().collect();
+        old.stats.history_samples = 3;
+        let old_key = test_key("local", "abc123456789");
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![old],
+        ));
+
+        // Recreated under the same name: the graphs continue with a marker
+        state.handle_event(AppEvent::ContainerDestroyed(old_key));
+        let new = create_test_container("def987654321", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let new_key = test_key("local", "def987654321");
+        state.handle_event(AppEvent::ContainerCreated(Box::new(new)));
+        state.handle_event(AppEvent::ContainerStat(
+            new_key.clone(),
+            ContainerStats {
+                cpu: 90.0,
+                memory: 90.0,
+                ..Default::default()
+            },
+        ));
+
+        let stats = &state.containers[&new_key].stats;
+        assert_eq!(stats.cpu_history.len(), 4);
+        assert_eq!(stats.deploy_positions(), vec![3]);
+
+        let backend = TestBackend::new(150, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("┃│"));
+    }
+
+    #[test]
+    fn test_absolute_timestamps_toggle() {
+        use crate::core::types::AppEvent;
+        use chrono::{DateTime, Utc};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut container =
+            create_test_container("abc123456789", "nginx", "local", 1.0, 1.0, 0.0, 0.0);
+        container.created = Some(
+            DateTime::parse_from_rfc3339("2025-06-15T12:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        state.timestamp_format = "[%Y-%m]".to_string();
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("[2025-06]"));
+
+        state.handle_event(AppEvent::ToggleAbsoluteTimestamps);
+        assert!(state.absolute_timestamps);
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("[2025-06]"));
+    }
+
+    #[test]
+    fn test_size_column_after_fetch() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let containers = vec![
+            create_test_container("abc123456789", "nginx", "local", 1.0, 1.0, 0.0, 0.0),
+            create_test_container("def987654321", "postgres", "local", 1.0, 1.0, 0.0, 0.0),
+        ];
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            containers,
+        ));
+        state.show_size_column = true;
+        state.handle_event(AppEvent::ContainerSizes(
+            "local".to_string(),
+            Ok(vec![(
+                format!("{:0<64}", "abc123456789"),
+                300 * 1024 * 1024,
+            )]),
+        ));
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Size"));
+        assert!(output.contains("300 M"));
+        // Not fetched yet for postgres
+        let postgres_line = output.lines().find(|l| l.contains("postgres")).unwrap();
+        assert!(postgres_line.contains(" - "));
+
+        // A failed fetch is reported instead of leaving the column empty without a word
+        state.handle_event(AppEvent::ContainerSizes(
+            "local".to_string(),
+            Err("Failed to fetch container sizes: timed out".to_string()),
+        ));
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notifications[0].source, "local");
+        assert!(state.notifications[0].kind.is_error());
+    }
+
+    #[test]
+    fn test_block_io_columns() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut nginx = create_test_container("abc123456789", "nginx", "local", 1.0, 1.0, 0.0, 0.0);
+        nginx.stats.block_write_bytes_per_sec = 2048.0;
+        let mut postgres =
+            create_test_container("def987654321", "postgres", "local", 1.0, 1.0, 0.0, 0.0);
+        postgres.stats.block_write_bytes_per_sec = 4.0 * 1024.0 * 1024.0;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![nginx, postgres],
+        ));
+
+        let render = |state: &mut AppState, width: u16| {
+            let mut terminal = Terminal::new(TestBackend::new(width, 10)).unwrap();
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        // Hidden on narrower terminals
+        assert!(!render(&mut state, 120).contains("BlkWrite/s"));
+        assert!(render(&mut state, 170).contains("BlkWrite/s"));
+
+        // Shown while sorting by them, heaviest writer first
+        state.handle_event(AppEvent::SetSortField(SortField::BlockWrite));
+        let output = render(&mut state, 120);
+        assert!(output.contains("BlkWrite/s ▼"));
+        let postgres_row = output.find("postgres").unwrap();
+        let nginx_row = output.find("nginx").unwrap();
+        assert!(postgres_row < nginx_row);
+    }
+
+    #[test]
+    fn test_pids_column() {
+        use crate::core::types::AppEvent;
+        use ratatui::style::Color;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut nginx = create_test_container("abc123456789", "nginx", "local", 1.0, 1.0, 0.0, 0.0);
+        nginx.stats.pids = 5;
+        let mut forky = create_test_container("def987654321", "forky", "local", 1.0, 1.0, 0.0, 0.0);
+        forky.stats.pids = 95;
+        forky.stats.pids_limit = Some(100);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![nginx, forky],
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        assert!(!buffer_to_string(terminal.backend().buffer()).contains("PIDs"));
+
+        // Shown while sorting by it, most processes first
+        state.handle_event(AppEvent::SetSortField(SortField::Pids));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("PIDs ▼"));
+        assert!(output.find("forky").unwrap() < output.find("nginx").unwrap());
+
+        // Close to its limit
+        let (x, y) = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .find(|&(x, y)| buffer[(x, y)].symbol() == "9" && buffer[(x + 1, y)].symbol() == "5")
+            .unwrap();
+        assert_eq!(buffer[(x, y)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_replicas_popup() {
+        use crate::core::types::AppEvent;
+        use ratatui::style::Color;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let replica = |id: &str, name: &str, cpu: f64| {
+            let mut container = create_test_container(id, name, "local", cpu, 1.0, 0.0, 0.0);
+            container.compose_project = Some("shop".to_string());
+            container.compose_service = Some("web".to_string());
+            container
+        };
+        let mut db =
+            create_test_container("ddd000000000", "shop-db-1", "local", 2.0, 1.0, 0.0, 0.0);
+        db.compose_project = Some("shop".to_string());
+        db.compose_service = Some("db".to_string());
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                replica("aaa000000000", "shop-web-1", 10.0),
+                replica("bbb000000000", "shop-web-2", 12.0),
+                replica("ccc000000000", "shop-web-3", 87.5),
+                db,
+            ],
+        ));
+
+        let web_1 = test_key("local", "aaa000000000");
+        assert_eq!(state.replica_containers(&web_1).len(), 3);
+
+        let row = |state: &AppState, id: &str| {
+            state
+                .sorted_container_keys
+                .iter()
+                .position(|key| key.short_id() == id)
+        };
+        state.table_state.select(row(&state, "aaa000000000"));
+        state.handle_event(AppEvent::ToggleReplicas);
+        assert!(state.show_replicas);
+
+        let mut terminal = Terminal::new(TestBackend::new(130, 30)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("Replicas: shop/web"));
+        assert!(output.contains("Average"));
+        assert!(output.contains("36.5%"));
+
+        // The outlier's CPU is highlighted
+        let line = output.lines().position(|l| l.contains("87.5%")).unwrap();
+        let column = output.lines().nth(line).unwrap()[..]
+            .split("87.5%")
+            .next()
+            .unwrap()
+            .chars()
+            .count();
+        assert_eq!(buffer[(column as u16, line as u16)].fg, Color::Red);
+
+        // A container without replicas says so
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert!(!state.show_replicas);
+        state.table_state.select(row(&state, "ddd000000000"));
+        state.handle_event(AppEvent::ToggleReplicas);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Replicas: shop/db"));
+        assert!(output.contains("No other running container"));
+    }
+
+    #[test]
+    fn test_host_summary_strip() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = true;
+        let styles = UiStyles::default();
+
+        let mut stopped = create_test_container("ccc000000000", "old", "local", 0.0, 0.0, 0.0, 0.0);
+        stopped.state = ContainerState::Exited;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                create_test_container("aaa000000000", "web", "local", 0.0, 0.0, 0.0, 0.0),
+                create_test_container("bbb000000000", "db", "local", 0.0, 0.0, 0.0, 0.0),
+                stopped,
+            ],
+        ));
+        state.handle_event(AppEvent::InitialContainerList(
+            "prod".to_string(),
+            vec![create_test_container(
+                "ddd000000000",
+                "api",
+                "prod",
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+            )],
+        ));
+
+        // Updated from the stats events
+        for (id, cpu, memory_used_bytes) in [
+            ("aaa000000000", 12.5, 256 * 1024 * 1024),
+            ("bbb000000000", 20.0, 768 * 1024 * 1024),
+        ] {
+            state.handle_event(AppEvent::ContainerStat(
+                test_key("local", id),
+                ContainerStats {
+                    cpu,
+                    memory_used_bytes,
+                    ..Default::default()
+                },
+            ));
+        }
+
+        let summaries = state.host_summaries();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].host_id, "local");
+        assert_eq!(summaries[0].counts.running, 2);
+        assert_eq!(summaries[0].counts.total, 3);
+        assert_eq!(summaries[0].cpu, 32.5);
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        let strip = output.lines().nth(1).unwrap();
+        assert!(strip.contains("local 2/3 CPU 32.5% Memory 1 G"));
+        assert!(strip.contains("│  prod 1/1"));
+    }
+
+    #[test]
+    fn test_platform_column() {
+        use crate::core::types::{AppEvent, ImagePlatform};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let containers = vec![
+            create_test_container("abc123456789", "nginx", "local", 1.0, 1.0, 0.0, 0.0),
+            create_test_container("def987654321", "postgres", "local", 1.0, 1.0, 0.0, 0.0),
+        ];
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            containers,
+        ));
+        state.show_platform_column = true;
+        state.handle_event(AppEvent::ContainerPlatform(
+            test_key("local", "abc123456789"),
+            ImagePlatform {
+                name: "linux/arm/v7".to_string(),
+                emulated: true,
+            },
+        ));
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Platform"));
+        let nginx_line = output.lines().find(|l| l.contains("nginx")).unwrap();
+        assert!(nginx_line.contains("linux/arm/v7"));
+        // Not inspected yet for postgres
+        let postgres_line = output.lines().find(|l| l.contains("postgres")).unwrap();
+        assert!(postgres_line.contains(" - "));
+    }
+
+    #[test]
+    fn test_image_history_view() {
+        use crate::core::types::{AppEvent, ImageHistoryState, ImageLayer};
+        use chrono::Utc;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let key = test_key("local", "abc123456789");
+        state.view_state = ViewState::ImageHistory(key.clone());
+        state.image_history = Some(ImageHistoryState {
+            container_key: key.clone(),
+            image: "nginx:latest".to_string(),
+            layers: None,
+            error: None,
+            scroll_offset: 0,
+        });
+
+        let layers = vec![
+            ImageLayer {
+                created: Some(Utc::now() - chrono::Duration::days(3)),
+                created_by: "/bin/sh -c #(nop)  CMD [\"nginx\"]".to_string(),
+                size: 0,
+                tags: vec!["nginx:latest".to_string()],
+            },
+            ImageLayer {
+                created: Some(Utc::now() - chrono::Duration::days(3)),
+                created_by: "RUN /bin/sh -c apt-get install -y nginx".to_string(),
+                size: 50 * 1024 * 1024,
+                tags: vec![],
+            },
+        ];
+        state.handle_event(AppEvent::ImageHistoryLoaded(key, Ok(layers)));
+
+        let backend = TestBackend::new(100, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Image history: nginx:latest (2 layers, 50 M)"));
+        assert!(output.contains("[nginx:latest] CMD [\"nginx\"]"));
+        assert!(output.contains("RUN /bin/sh -c apt-get install -y nginx"));
+
+        // Esc goes back to the container list
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.image_history.is_none());
+    }
+
+    #[test]
+    fn test_build_dialog_and_output() {
+        use crate::core::types::{AppEvent, BuildLine, BuildStatus};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "nginx",
+                "local",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            )],
+        ));
+        state.handle_event(AppEvent::ShowBuildDialog);
+        assert_eq!(state.view_state, ViewState::Build);
+
+        // Typing goes into the focused field, shortcuts like 'q' don't fire
+        let key = |code| AppEvent::SearchKeyEvent(KeyEvent::new(code, KeyModifiers::NONE));
+        state.handle_event(key(KeyCode::Backspace));
+        for c in "./app".chars() {
+            state.handle_event(key(KeyCode::Char(c)));
+        }
+        state.handle_event(key(KeyCode::Tab));
+        for c in "myapp:dev".chars() {
+            state.handle_event(key(KeyCode::Char(c)));
+        }
+        state.handle_event(AppEvent::Quit);
+        assert!(!state.should_quit);
+
+        let backend = TestBackend::new(100, 15);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Build image (local)"));
+        assert!(output.contains("Path   ./app"));
+        assert!(output.contains("Tag    myapp:dev"));
+
+        // Output panel once the build is running
+        state.build.as_mut().unwrap().status = BuildStatus::Running;
+        state.handle_event(AppEvent::BuildOutput(BuildLine {
+            text: "Step 1/2 : FROM alpine".to_string(),
+            is_error: false,
+        }));
+        state.handle_event(AppEvent::BuildFinished(Err(
+            "The command '/bin/sh -c make' returned a non-zero code: 2".to_string(),
+        )));
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Build: ./app (local)"));
+        assert!(output.contains("[Failed]"));
+        assert!(output.contains("Step 1/2 : FROM alpine"));
+        assert!(output.contains("returned a non-zero code: 2"));
+
+        // Esc closes the panel
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.build.is_none());
+    }
+
+    #[test]
+    fn test_colorblind_theme_threshold_markers() {
+        use crate::ui::icons::IconStyle;
+        use crate::ui::theme::{Background, Theme};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::new(IconStyle::Unicode, Theme::Colorblind, Background::Dark);
+
+        // One container per threshold level (low, medium, high)
+        let containers = vec![
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0),
+            create_test_container(
+                "def987654321",
+                "postgres",
+                "local",
+                65.8,
+                78.3,
+                5120.0,
+                10240.0,
+            ),
+            create_test_container("ghi111222333", "redis", "local", 92.1, 85.0, 512.0, 1024.0),
+        ];
+
+        for container in containers {
+            let key = container.key();
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                render_ui(f, &mut state, &styles);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer().clone();
+        let output = buffer_to_string(&buffer);
+
+        assert!(output.contains("65.8%▲"), "Medium CPU should have a marker");
+        assert!(output.contains("92.1%■"), "High CPU should have a marker");
+
+        assert_snapshot_with_redaction!(output);
+    }
+
+    #[test]
+    fn test_export_view() {
+        use crate::core::types::{
+            AppEvent, ContainerState, ExportChoice, ExportFormat, ExportOutcome, ExportTarget,
+            HealthStatus, RenderAction,
+        };
+        use crate::ui::export::export_table;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut web = create_test_container("abc123456789", "web", "local", 25.5, 45.2, 0.0, 0.0);
+        web.health = Some(HealthStatus::Healthy);
+        let mut db = create_test_container("def123456789", "db", "local", 0.0, 0.0, 0.0, 0.0);
+        db.state = ContainerState::Exited;
+        for container in [web, db] {
+            state.containers.insert(container.key(), container);
+        }
+        state.show_all_containers = true;
+        state.sort_state = crate::core::types::SortState::new(SortField::Name);
+        state.force_sort_containers();
+
+        // The rows and columns on screen, stats only for running containers
+        let (table, count) = export_table(&state, ExportFormat::Markdown, 100);
+        assert_eq!(count, 2);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| ID | Name | State | CPU % | Memory % | NetTx/s | NetRx/s | Created |"
+        );
+        assert!(lines[2].starts_with("| def123456789 | db | exited |  |  |  |  |"));
+        assert!(lines[3].starts_with("| abc123456789 | web | running (healthy) | 25.5% | 45.2% |"));
+
+        // The search filter applies too
+        state.search_input = tui_input::Input::new("web".to_string());
+        state.force_sort_containers();
+        let (table, count) = export_table(&state, ExportFormat::Html, 100);
+        assert_eq!(count, 1);
+        assert!(table.contains("<td>web</td>"));
+        assert!(!table.contains("<td>db</td>"));
+
+        // Ctrl+S opens the menu, Enter hands the choice to the main loop
+        state.handle_event(AppEvent::ShowExportMenu);
+        assert_eq!(state.view_state, ViewState::ExportMenu);
+        state.handle_event(AppEvent::SelectActionDown);
+        assert_eq!(
+            state.handle_event(AppEvent::EnterPressed),
+            RenderAction::Export(ExportChoice {
+                format: ExportFormat::Html,
+                target: ExportTarget::Clipboard,
+            })
+        );
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        state.handle_event(AppEvent::ViewExported(ExportOutcome::Copied(
+            ExportFormat::Html,
+            1,
+        )));
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("✓ Copied: 1 containers (HTML)"));
+    }
+
+    #[test]
+    fn test_network_picker() {
+        use crate::core::types::{AppEvent, ContainerAction, NetworkPickerState};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container =
+            create_test_container("abc123456789", "nginx", "local", 25.5, 45.2, 1024.0, 2048.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+
+        state.view_state = ViewState::NetworkPicker(key.clone());
+        state.network_picker = Some(NetworkPickerState {
+            container_key: key.clone(),
+            action: ContainerAction::ConnectNetwork,
+            networks: None,
+            error: None,
+            list_state: Default::default(),
+        });
+
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Connect network: nginx"));
+        assert!(output.contains("Loading"));
+
+        let networks = vec!["backend".to_string(), "bridge".to_string()];
+        state.handle_event(AppEvent::NetworksLoaded(key.clone(), Ok(networks)));
+
+        // Selection stays within the list
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::SelectActionDown);
+        let picker = state.network_picker.as_ref().unwrap();
+        assert_eq!(picker.list_state.selected(), Some(1));
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("backend"));
+        assert!(output.contains(">  bridge"));
+
+        // Results for another container are ignored
+        let other = test_key("local", "def456");
+        state.handle_event(AppEvent::NetworksLoaded(other, Ok(vec![])));
+        assert_eq!(
+            state
+                .network_picker
+                .as_ref()
+                .unwrap()
+                .networks
+                .as_ref()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Esc goes back to the container list
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.network_picker.is_none());
+    }
+
+    #[test]
+    fn test_volume_list_orphans() {
+        use crate::core::types::{AppEvent, VolumeInfo};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::ShowVolumes);
+        assert_eq!(state.view_state, ViewState::VolumeList);
+
+        let volume = |name: &str, size: Option<i64>, containers: usize| VolumeInfo {
+            host_id: "local".to_string(),
+            name: name.to_string(),
+            driver: "local".to_string(),
+            mountpoint: format!("/var/lib/docker/volumes/{}/_data", name),
+            size,
+            containers,
+        };
+        let volumes = vec![
+            volume("postgres_data", Some(300 * 1024 * 1024), 1),
+            volume("old_cache", Some(100 * 1024 * 1024), 0),
+            volume("nfs_share", None, 0),
+        ];
+        state.handle_event(AppEvent::VolumesLoaded("local".to_string(), Ok(volumes)));
+
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Volumes (3, 2 orphaned, ~100 M reclaimable)"));
+        assert!(output.contains("postgres_data"));
+        assert!(output.contains("/var/lib/docker/volumes/"));
+
+        // 'x' ignores a volume in use (sorted last), and confirms removing just the selected orphan
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::RemoveSelectedVolume);
+        assert!(!state.volume_list.as_ref().unwrap().confirm_remove);
+
+        state.handle_event(AppEvent::ScrollUp);
+        state.handle_event(AppEvent::RemoveSelectedVolume);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains(" old_cache"));
+        assert!(!output.contains(" nfs_share"));
+        assert!(output.contains("1 orphaned, ~100 M reclaimable"));
+        state.handle_event(AppEvent::CancelActionMenu);
+
+        // 'D' asks for confirmation first, Esc only closes the confirmation
+        state.handle_event(AppEvent::RemoveOrphanedVolumes);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Remove orphaned volumes"));
+        assert!(output.contains(" old_cache"));
+        assert!(output.contains(" nfs_share"));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert!(!state.volume_list.as_ref().unwrap().confirm_remove);
+        assert_eq!(state.view_state, ViewState::VolumeList);
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.volume_list.is_none());
+    }
+
+    #[test]
+    fn test_disk_usage_prune() {
+        use crate::core::types::{AppEvent, DiskUsageKind, DiskUsageRow};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::ShowDiskUsage);
+        assert_eq!(state.view_state, ViewState::DiskUsage);
+
+        const MB: i64 = 1024 * 1024;
+        let row = |kind, total, active, size, reclaimable| DiskUsageRow {
+            host_id: "local".to_string(),
+            kind,
+            total,
+            active,
+            size,
+            reclaimable,
+        };
+        let rows = vec![
+            row(DiskUsageKind::BuildCache, 0, 0, 0, 0),
+            row(DiskUsageKind::Images, 5, 2, 1000 * MB, 300 * MB),
+            row(DiskUsageKind::Volumes, 3, 3, 50 * MB, 0),
+        ];
+        state.handle_event(AppEvent::DiskUsageLoaded("local".to_string(), Ok(rows)));
+
+        let backend = TestBackend::new(100, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Disk usage (~300 M reclaimable)"));
+        assert!(output.contains("300 M (30%)"));
+        // Sorted in `docker system df` order
+        let images = output.find("Images").unwrap();
+        assert!(images < output.find("Volumes").unwrap());
+        assert!(output.find("Volumes").unwrap() < output.find("Build cache").unwrap());
+
+        // 'x' ignores a kind with nothing reclaimable
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::PruneDiskUsageSelected);
+        assert!(!state.disk_usage.as_ref().unwrap().confirm_prune);
+
+        state.handle_event(AppEvent::ScrollUp);
+        state.handle_event(AppEvent::PruneDiskUsageSelected);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Remove all images not used by a container"));
+
+        // Esc only closes the confirmation
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert!(!state.disk_usage.as_ref().unwrap().confirm_prune);
+        assert_eq!(state.view_state, ViewState::DiskUsage);
+
+        state.handle_event(AppEvent::DiskUsagePruned(
+            "local".to_string(),
+            Ok(300 * MB as u64),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("300 M freed"));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.disk_usage.is_none());
+    }
+
+    #[test]
+    fn test_cleanup_suggestions() {
+        use crate::core::types::{AppEvent, CleanupCandidate, CleanupKind, CleanupSelection};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::ShowCleanup);
+        assert_eq!(state.view_state, ViewState::Cleanup);
+
+        let candidate = |kind, name: &str, size: Option<i64>| CleanupCandidate {
+            host_id: "local".to_string(),
+            kind,
+            id: name.to_string(),
+            name: name.to_string(),
+            since: None,
+            size,
+        };
+        let candidates = vec![
+            candidate(
+                CleanupKind::UnusedVolume,
+                "old_cache",
+                Some(100 * 1024 * 1024),
+            ),
+            candidate(
+                CleanupKind::DanglingImage,
+                "0123456789ab",
+                Some(50 * 1024 * 1024),
+            ),
+            candidate(CleanupKind::ExitedContainer, "migrate", Some(1024)),
+            candidate(CleanupKind::ExitedContainer, "backup", None),
+        ];
+        state.handle_event(AppEvent::CleanupLoaded("local".to_string(), Ok(candidates)));
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Cleanup (4, ~150 M reclaimable)"));
+
+        // Listed by kind, containers first
+        let cleanup = state.cleanup.as_ref().unwrap();
+        let names: Vec<&str> = cleanup.candidates.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["backup", "migrate", "0123456789ab", "old_cache"]);
+
+        // 'D' confirms removing every candidate of the selected one's kind
+        state.handle_event(AppEvent::RemoveCleanupKind);
+        assert_eq!(
+            state.cleanup.as_ref().unwrap().confirm,
+            Some(CleanupSelection::Kind(CleanupKind::ExitedContainer))
+        );
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Clean up"));
+        assert!(output.contains(" backup (exited container)"));
+        assert!(output.contains(" migrate (exited container)"));
+        assert!(!output.contains(" old_cache (unused volume)"));
+
+        // Esc only closes the confirmation, 'x' confirms the selected candidate
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::Cleanup);
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::RemoveCleanupSelected);
+        let cleanup = state.cleanup.as_ref().unwrap();
+        assert_eq!(
+            cleanup
+                .removal_candidates()
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>(),
+            ["0123456789ab"]
+        );
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.cleanup.is_none());
+    }
+
+    #[test]
+    fn test_process_list_view() {
+        use crate::core::types::{AppEvent, ProcessInfo, ProcessListState};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+
+        // Opened by hand: the test state has no host to run `top` against
+        let key = test_key("local", "abc123456789");
+        state.process_list = Some(ProcessListState {
+            container_key: key.clone(),
+            processes: None,
+            error: None,
+            scroll_offset: 0,
+            refresh_handle: None,
+        });
+        state.view_state = ViewState::ProcessList(key.clone());
+
+        let process = |pid: &str, cpu: Option<f64>, command: &str| ProcessInfo {
+            pid: pid.to_string(),
+            user: "root".to_string(),
+            cpu,
+            command: command.to_string(),
+        };
+        state.handle_event(AppEvent::ProcessesLoaded(
+            key.clone(),
+            Ok(vec![
+                process("29", Some(12.5), "nginx: worker process"),
+                process("1", None, "nginx: master process"),
+            ]),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Processes: web (2)"));
+        assert!(output.contains("12.5"));
+        assert!(output.contains("nginx: worker process"));
+
+        // A failed refresh keeps the last processes on screen
+        state.handle_event(AppEvent::ProcessesLoaded(
+            key,
+            Err("Failed to list processes: gone".to_string()),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Failed to list processes: gone"));
+        assert!(output.contains("nginx: master process"));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.process_list.is_none());
+    }
+
+    #[test]
+    fn test_file_browser_view() {
+        use crate::core::types::{AppEvent, FileBrowserState, FileEntry, FileKind};
+        use crate::ui::formatters::format_bytes;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+
+        // Opened by hand: the test state has no host to run `ls` in
+        let key = test_key("local", "abc123456789");
+        state.file_browser = Some(FileBrowserState {
+            container_key: key.clone(),
+            path: "/etc/nginx".to_string(),
+            entries: Vec::new(),
+            loading: true,
+            error: None,
+            downloads: 0,
+            last_download: None,
+            table_state: Default::default(),
+        });
+        state.view_state = ViewState::FileBrowser(key.clone());
+
+        let entry = |name: &str, kind, link_target: Option<&str>| FileEntry {
+            name: name.to_string(),
+            kind,
+            size: 2048,
+            owner: "root".to_string(),
+            modified: "Oct 17 10:15".to_string(),
+            link_target: link_target.map(str::to_string),
+        };
+        // Going up selects the directory just left
+        state.handle_event(AppEvent::FilesLoaded(
+            key.clone(),
+            "/etc".to_string(),
+            Ok(vec![
+                entry("certs", FileKind::Directory, None),
+                entry("nginx", FileKind::Directory, None),
+                entry("ssl", FileKind::Symlink, Some("/srv/ssl")),
+                entry("hosts", FileKind::File, None),
+            ]),
+        ));
+        let browser = state.file_browser.as_ref().unwrap();
+        assert_eq!(browser.path, "/etc");
+        assert_eq!(browser.table_state.selected(), Some(1));
+
+        state.handle_event(AppEvent::FileDownloaded(
+            key.clone(),
+            Ok(std::path::PathBuf::from("/home/me/Downloads/web/hosts")),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Files: web /etc (4)"));
+        assert!(output.contains("Saved to /home/me/Downloads/web/hosts"));
+        assert!(output.contains("nginx/"));
+        assert!(output.contains("ssl -> /srv/ssl"));
+        assert!(output.contains(&format_bytes(2048)));
+
+        // A directory that can't be listed keeps the current one on screen
+        state.handle_event(AppEvent::FilesLoaded(
+            key,
+            "/etc/ssl".to_string(),
+            Err("ls: /etc/ssl/: Permission denied".to_string()),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Permission denied"));
+        assert!(output.contains("hosts"));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.file_browser.is_none());
+    }
+
+    #[test]
+    fn test_env_vars_popup() {
+        use crate::core::types::{AppEvent, EnvVarsState};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+
+        // Opened by hand: the test state has no host to inspect
+        let key = test_key("local", "abc123456789");
+        state.env_vars = Some(EnvVarsState {
+            container_key: key.clone(),
+            vars: None,
+            error: None,
+            search_input: Default::default(),
+            search_editing: false,
+            reveal_secrets: false,
+            scroll_offset: 0,
+        });
+        state.view_state = ViewState::EnvVars(key.clone());
+        state.handle_event(AppEvent::EnvVarsLoaded(
+            key.clone(),
+            Ok(vec![
+                ("DB_PASSWORD".to_string(), "hunter2".to_string()),
+                ("NGINX_PORT".to_string(), "8080".to_string()),
+                ("PATH".to_string(), "/usr/bin".to_string()),
+            ]),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        // Secrets are masked until '*'
+        let output = draw(&mut state);
+        assert!(output.contains("Environment: web (3)"));
+        assert!(output.contains("NGINX_PORT=8080"));
+        assert!(output.contains("DB_PASSWORD=••••••••"));
+        assert!(!output.contains("hunter2"));
+
+        state.handle_event(AppEvent::ToggleSecrets);
+        assert!(draw(&mut state).contains("DB_PASSWORD=hunter2"));
+
+        // Typed keys filter instead of running shortcuts
+        state.handle_event(AppEvent::EnterSearchMode);
+        let key_event = |code| AppEvent::SearchKeyEvent(KeyEvent::new(code, KeyModifiers::NONE));
+        for c in "port".chars() {
+            state.handle_event(key_event(KeyCode::Char(c)));
+        }
+        state.handle_event(AppEvent::ToggleSecrets);
+        state.handle_event(AppEvent::EnterPressed);
+        let output = draw(&mut state);
+        assert!(output.contains("Environment: web (1/3)"));
+        assert!(output.contains("NGINX_PORT=8080"));
+        assert!(!output.contains("PATH=/usr/bin"));
+        assert!(!output.contains("DB_PASSWORD"));
+
+        // Esc clears the search, then closes
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert!(draw(&mut state).contains("PATH=/usr/bin"));
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.env_vars.is_none());
+    }
+
+    #[test]
+    fn test_container_count_trend_in_title() {
+        use crate::core::types::{AppEvent, ContainerCounts};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let worker = create_test_container("def987654321", "worker", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web, worker],
+        ));
+
+        let key = test_key("local", "def987654321");
+        state.handle_event(AppEvent::ContainerExited(key, Some(1)));
+
+        let history = &state.container_counts["local"];
+        assert_eq!(
+            history.current(),
+            ContainerCounts {
+                running: 1,
+                total: 2
+            }
+        );
+
+        let backend = TestBackend::new(150, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        let title = output.lines().next().unwrap();
+        assert!(title.contains("local │"), "title: {}", title);
+        assert!(title.contains("│ 1/2"), "title: {}", title);
+
+        // Left out when it doesn't fit next to the help text
+        let backend = TestBackend::new(60, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.lines().next().unwrap().contains("1/2"));
+    }
+
+    #[test]
+    fn test_container_renamed_and_limits_updated() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+
+        let mut container =
+            create_test_container("abc123456789", "web", "local", 1.0, 25.0, 0.0, 0.0);
+        container.stats.memory_used_bytes = 256 * 1024 * 1024;
+        container.stats.memory_limit_bytes = 1024 * 1024 * 1024;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+
+        let key = test_key("local", "abc123456789");
+        state.handle_event(AppEvent::ContainerRenamed(
+            key.clone(),
+            "web-blue".to_string(),
+        ));
+        assert_eq!(state.containers[&key].name, "web-blue");
+
+        state.handle_event(AppEvent::ContainerLimitsUpdated(
+            key.clone(),
+            Some(512 * 1024 * 1024),
+        ));
+        let stats = &state.containers[&key].stats;
+        assert_eq!(stats.memory_limit_bytes, 512 * 1024 * 1024);
+        assert_eq!(stats.memory, 50.0);
+
+        // Unlimited: the next stats sample reports the host memory
+        state.handle_event(AppEvent::ContainerLimitsUpdated(key.clone(), None));
+        assert_eq!(
+            state.containers[&key].stats.memory_limit_bytes,
+            512 * 1024 * 1024
+        );
+    }
+
+    #[test]
+    fn test_container_pause_changed() {
+        use crate::core::types::{AppEvent, ContainerState};
+
+        let mut state = create_test_app_state();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        let key = test_key("local", "abc123456789");
+
+        state.handle_event(AppEvent::ContainerPauseChanged(key.clone(), true));
+        assert_eq!(state.containers[&key].state, ContainerState::Paused);
+        // Still listed without show all, like `docker ps`
+        assert_eq!(state.sorted_container_keys, vec![key.clone()]);
+
+        state.handle_event(AppEvent::ContainerPauseChanged(key.clone(), false));
+        assert_eq!(state.containers[&key].state, ContainerState::Running);
+
+        // A stopped container can't be paused
+        state.handle_event(AppEvent::ContainerExited(key.clone(), Some(0)));
+        state.handle_event(AppEvent::ContainerPauseChanged(key.clone(), true));
+        assert_eq!(state.containers[&key].state, ContainerState::Exited);
+    }
+
+    #[test]
+    fn test_timeline_shows_exec_sessions() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        let key = test_key("local", "abc123456789");
+
+        state.handle_event(AppEvent::ContainerExec(
+            key.clone(),
+            "/bin/bash".to_string(),
+        ));
+        state.handle_event(AppEvent::ContainerRenamed(key.clone(), "api".to_string()));
+        state.handle_event(AppEvent::ContainerExited(key.clone(), Some(137)));
+
+        state.handle_event(AppEvent::ShowTimeline);
+        assert_eq!(state.view_state, ViewState::Timeline);
+
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Events (3)"));
+
+        // Newest first
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[1].contains("api") && lines[1].contains("exited (137)"));
+        assert!(lines[2].contains("api") && lines[2].contains("renamed from web"));
+        assert!(lines[3].contains("web") && lines[3].contains("exec: /bin/bash"));
+
+        // Scrolling skips the newest entries, but never past the oldest
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::ScrollDown);
+        state.handle_event(AppEvent::ScrollDown);
+        assert_eq!(state.timeline_scroll, 2);
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_timeline_shows_log_snapshots() {
+        use crate::core::types::AppEvent;
+        use std::path::PathBuf;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "db", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        let key = test_key("local", "abc123456789");
+
+        state.handle_event(AppEvent::LogSnapshotTaken(
+            key.clone(),
+            Ok(PathBuf::from("/logs/local/db-20260304-050607.log")),
+        ));
+        state.handle_event(AppEvent::LogSnapshotTaken(
+            key,
+            Err("Failed to write /logs: Permission denied".to_string()),
+        ));
+
+        state.handle_event(AppEvent::ShowTimeline);
+        let backend = TestBackend::new(100, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[1].contains("logs not saved: Failed to write /logs: Permission denied"));
+        assert!(lines[2].contains("logs saved: /logs/local/db-20260304-050607.log"));
+    }
+
+    #[test]
+    fn test_log_view_marks_idle_gaps() {
+        use crate::core::types::LogState;
+        use crate::docker::logs::LogEntry;
+        use chrono::{Duration, TimeZone, Utc};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.view_state = ViewState::LogView(key.clone());
+        state.follow_logs = true;
+
+        let base = Utc.with_ymd_and_hms(2025, 10, 29, 10, 15, 30).unwrap();
+        let entry = |offset: Duration, text: &str| {
+            LogEntry::parse(&format!(
+                "{}Z {}",
+                (base + offset).format("%Y-%m-%dT%H:%M:%S"),
+                text
+            ))
+            .unwrap()
+        };
+
+        // 6 entries and a separator fill the 7 rows exactly
+        let mut log_state = LogState::new(key.clone(), None);
+        log_state.log_entries = vec![
+            entry(Duration::zero(), "first"),
+            entry(Duration::seconds(1), "second"),
+            entry(Duration::minutes(5), "after a short pause"),
+            entry(Duration::hours(2), "woke up"),
+            entry(Duration::hours(2) + Duration::seconds(1), "still awake"),
+            entry(Duration::hours(2) + Duration::seconds(2), "newest"),
+        ];
+        state.log_state = Some(log_state);
+
+        let backend = TestBackend::new(80, 9);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+
+        assert_eq!(output.matches("idle").count(), 1);
+        assert!(output.contains("— 1h idle —"));
+        assert!(output.contains("first"));
+        assert!(output.contains("newest"));
+
+        let lines: Vec<&str> = output.lines().collect();
+        let separator = lines.iter().position(|line| line.contains("idle")).unwrap();
+        assert!(lines[separator - 1].contains("after a short pause"));
+        assert!(lines[separator + 1].contains("woke up"));
+    }
+
+    #[test]
+    fn test_log_view_search() {
+        use crate::core::types::{AppEvent, LogState};
+        use crate::docker::logs::LogEntry;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        use ratatui::style::Modifier;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.view_state = ViewState::LogView(key.clone());
+        state.follow_logs = true;
+
+        let mut log_state = LogState::new(key.clone(), None);
+        log_state.log_entries = [
+            "GET /health",
+            "error: disk full",
+            "GET /api",
+            "Error again",
+            "done",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, text)| LogEntry::parse(&format!("2025-10-29T10:15:3{}Z {}", i, text)).unwrap())
+        .collect();
+        state.log_state = Some(log_state);
+
+        // While typing, shortcut keys are text
+        state.handle_event(AppEvent::EnterSearchMode);
+        for c in "error".chars() {
+            state.handle_event(AppEvent::SearchKeyEvent(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )));
+        }
+        state.handle_event(AppEvent::Quit);
+        state.handle_event(AppEvent::ExitLogView);
+        assert!(!state.should_quit);
+        assert!(matches!(state.view_state, ViewState::LogView(_)));
+
+        // Enter jumps to the newest match, n/N move between matches and wrap around
+        state.handle_event(AppEvent::EnterPressed);
+        let search_match = |state: &AppState| state.log_state.as_ref().unwrap().search_match;
+        assert_eq!(search_match(&state), Some(3));
+        state.handle_event(AppEvent::LogSearchPrevious);
+        assert_eq!(search_match(&state), Some(1));
+        state.handle_event(AppEvent::LogSearchPrevious);
+        assert_eq!(search_match(&state), Some(3));
+        state.handle_event(AppEvent::LogSearchNext);
+        assert_eq!(search_match(&state), Some(1));
+
+        let backend = TestBackend::new(80, 9);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+        assert!(output.contains("Search: error (1/2)"));
+
+        // Only the matched text is highlighted
+        let highlighted: String = buffer
+            .content()
+            .iter()
+            .filter(|cell| cell.modifier.contains(Modifier::REVERSED))
+            .map(|cell| cell.symbol())
+            .collect();
+        assert_eq!(highlighted, "errorError");
+
+        // Esc clears the search first, then leaves the log view
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert!(matches!(state.view_state, ViewState::LogView(_)));
+        assert_eq!(search_match(&state), None);
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_log_view_follow_toggle() {
+        use crate::core::types::{AppEvent, LogState};
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.view_state = ViewState::LogView(key.clone());
+
+        let entry = |i: usize, text: &str| {
+            LogEntry::parse(&format!("2025-10-29T10:15:{:02}Z {}", i, text)).unwrap()
+        };
+        let mut log_state = LogState::new(key.clone(), None);
+        log_state.log_entries = (0..10).map(|i| entry(i, &format!("line {}", i))).collect();
+        state.log_state = Some(log_state);
+
+        let backend = TestBackend::new(80, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut render = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        assert!(render(&mut state).contains("[FOLLOWING]"));
+
+        // Paused at the bottom, new lines don't push the content
+        state.handle_event(AppEvent::ToggleFollowLogs);
+        state.handle_event(AppEvent::LogLine(key.clone(), entry(10, "newest")));
+        let output = render(&mut state);
+        assert!(output.contains("[PAUSED]"));
+        assert!(output.contains("line 9"));
+        assert!(!output.contains("newest"));
+
+        // Scrolling down to the bottom doesn't resume following
+        for _ in 0..5 {
+            state.handle_event(AppEvent::ScrollDown);
+        }
+        assert!(render(&mut state).contains("[PAUSED]"));
+        assert!(!state.follow_logs);
+
+        state.handle_event(AppEvent::ToggleFollowLogs);
+        state.handle_event(AppEvent::LogLine(key.clone(), entry(11, "even newer")));
+        let output = render(&mut state);
+        assert!(output.contains("[FOLLOWING]"));
+        assert!(output.contains("even newer"));
+    }
+
+    #[test]
+    fn test_log_view_level_filter() {
+        use crate::core::types::{AppEvent, LogState};
+        use crate::docker::logs::{LogEntry, LogLevel};
+        use ratatui::style::Color;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.view_state = ViewState::LogView(key.clone());
+
+        let mut log_state = LogState::new(key.clone(), None);
+        log_state.log_entries = [
+            "DEBUG cache warmed",
+            "INFO listening on :8080",
+            "WARN slow query",
+            "plain line",
+            "ERROR disk full",
+            "DEBUG retrying",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, text)| LogEntry::parse(&format!("2025-10-29T10:15:3{}Z {}", i, text)).unwrap())
+        .collect();
+        state.log_state = Some(log_state);
+
+        state.handle_event(AppEvent::ToggleLogLevel(LogLevel::Debug));
+        state.handle_event(AppEvent::ToggleLogLevel(LogLevel::Info));
+
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let output = buffer_to_string(buffer);
+
+        assert!(output.contains("[ERROR WARN]"));
+        assert!(output.contains("slow query"));
+        assert!(output.contains("plain line"));
+        assert!(output.contains("disk full"));
+        assert!(!output.contains("listening"));
+        assert!(!output.contains("retrying"));
+
+        // Errors are colored by their level
+        let row = output
+            .lines()
+            .position(|line| line.contains("disk full"))
+            .unwrap();
+        let col = output.lines().nth(row).unwrap().find("disk").unwrap();
+        assert_eq!(buffer[(col as u16, row as u16)].fg, Color::Red);
+
+        // Scrolling skips hidden entries
+        state.log_state.as_mut().unwrap().scroll_offset = 2;
+        state.follow_logs = false;
+        state.handle_event(AppEvent::ScrollUp);
+        assert_eq!(state.log_state.as_ref().unwrap().scroll_offset, 2);
+        state.handle_event(AppEvent::ScrollDown);
+        assert_eq!(state.log_state.as_ref().unwrap().scroll_offset, 3);
+
+        state.handle_event(AppEvent::ToggleLogLevel(LogLevel::Info));
+        state.handle_event(AppEvent::ScrollUp);
+        assert_eq!(state.log_state.as_ref().unwrap().scroll_offset, 2);
+        state.handle_event(AppEvent::ScrollUp);
+        assert_eq!(state.log_state.as_ref().unwrap().scroll_offset, 1);
+    }
+
+    #[test]
+    fn test_log_view_collapse_repeats() {
+        use crate::core::types::{AppEvent, LogState};
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.view_state = ViewState::LogView(key.clone());
+
+        let mut log_state = LogState::new(key.clone(), None);
+        log_state.log_entries = [
+            "starting",
+            "retry 1/5 in 100ms",
+            "retry 2/5 in 200ms",
+            "retry 3/5 in 400ms",
+            "connected",
+            "connected",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, text)| LogEntry::parse(&format!("2025-10-29T10:15:3{}Z {}", i, text)).unwrap())
+        .collect();
+        state.log_state = Some(log_state);
+
+        let backend = TestBackend::new(80, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut render = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        assert_eq!(render(&mut state).matches("retry").count(), 3);
+
+        // Repeats fold into their newest line with a counter
+        state.handle_event(AppEvent::ToggleCollapseRepeats);
+        let output = render(&mut state);
+        assert!(output.contains("[COLLAPSED]"));
+        assert_eq!(output.matches("retry").count(), 1);
+        assert!(output.contains("retry 3/5 in 400ms ×3"));
+        assert!(output.contains("connected ×2"));
+        assert!(output.contains("starting"));
+
+        state.handle_event(AppEvent::ToggleCollapseRepeats);
+        assert_eq!(render(&mut state).matches("retry").count(), 3);
+    }
+
+    #[test]
+    fn test_log_view_remembers_position() {
+        use crate::core::types::AppEvent;
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+
+        let container = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        let key = container.key();
+        state.containers.insert(key.clone(), container);
+        state.sorted_container_keys.push(key.clone());
+        state.table_state.select(Some(0));
+
+        let entries: Vec<LogEntry> = (0..50)
+            .map(|i| LogEntry::parse(&format!("2025-10-29T10:{:02}:00Z line {}", i, i)).unwrap())
+            .collect();
+
+        state.handle_event(AppEvent::ShowLogView);
+        state.handle_event(AppEvent::LogBatchPrepend(
+            key.clone(),
+            entries.clone(),
+            false,
+        ));
+        {
+            let log_state = state.log_state.as_mut().unwrap();
+            log_state.scroll_offset = 12;
+            log_state.search_input = "line 3".into();
+        }
+        state.follow_logs = false;
+        state.handle_event(AppEvent::ExitLogView);
+
+        // Reopening restores the search right away and the position once the logs arrive
+        state.handle_event(AppEvent::ShowLogView);
+        let log_state = state.log_state.as_ref().unwrap();
+        assert_eq!(log_state.search_input.value(), "line 3");
+        assert!(!state.follow_logs);
+
+        state.handle_event(AppEvent::LogBatchPrepend(
+            key.clone(),
+            entries.clone(),
+            false,
+        ));
+        assert_eq!(state.log_state.as_ref().unwrap().scroll_offset, 12);
+        assert!(!state.follow_logs);
+
+        // Leaving while following the newest logs starts at the bottom next time
+        state.handle_event(AppEvent::ScrollToBottom);
+        state.handle_event(AppEvent::ExitLogView);
+        state.handle_event(AppEvent::ShowLogView);
+        assert!(state.follow_logs);
+    }
+
+    #[test]
+    fn test_network_list() {
+        use crate::core::types::{AppEvent, NetworkInfo};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::ShowNetworks);
+        assert_eq!(state.view_state, ViewState::NetworkList);
+
+        let network =
+            |name: &str, driver: &str, subnets: &[&str], containers: &[&str]| NetworkInfo {
+                host_id: "local".to_string(),
+                name: name.to_string(),
+                driver: driver.to_string(),
+                scope: "local".to_string(),
+                subnets: subnets.iter().map(|s| s.to_string()).collect(),
+                containers: containers.iter().map(|c| c.to_string()).collect(),
+            };
+        let networks = vec![
+            network("bridge", "bridge", &["172.17.0.0/16"], &[]),
+            network("app_default", "bridge", &["172.18.0.0/16"], &["db", "web"]),
+            network("none", "null", &[], &[]),
+        ];
+        state.handle_event(AppEvent::NetworkListLoaded(
+            "local".to_string(),
+            Ok(networks),
+        ));
+
+        let backend = TestBackend::new(100, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Networks (3)"));
+
+        // Sorted by name
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[2].contains("app_default") && lines[2].contains("2: db, web"));
+        assert!(lines[2].contains("172.18.0.0/16"));
+        assert!(lines[3].contains("bridge"));
+
+        state.handle_event(AppEvent::ScrollDown);
+        assert_eq!(
+            state.network_list.as_ref().unwrap().table_state.selected(),
+            Some(1)
+        );
+
+        state.handle_event(AppEvent::ExitLogView);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.network_list.is_none());
+    }
+
+    #[test]
+    fn test_project_budget_in_grouped_view() {
+        use crate::core::budgets::Budget;
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let in_team = |id: &str, name: &str, cpu: f64| {
+            let mut container = create_test_container(id, name, "local", cpu, 1.0, 0.0, 0.0);
+            container.compose_project = Some("shop".to_string());
+            container.labels = BTreeMap::from([("team".to_string(), "payments".to_string())]);
+            container
+        };
+        state.budgets = vec![Budget {
+            key: "team".to_string(),
+            value: "payments".to_string(),
+            cpus: Some(1.0),
+            memory_bytes: None,
+        }];
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                in_team("bbb222222222", "shop-web", 80.0),
+                in_team("ccc333333333", "shop-db", 40.0),
+            ],
+        ));
+        state.handle_event(AppEvent::ToggleGroupByProject);
+
+        let render = |state: &mut AppState, width: u16| {
+            let mut terminal = Terminal::new(TestBackend::new(width, 12)).unwrap();
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        let output = render(&mut state, 120);
+        assert!(output.contains("shop (2/2) · team=payments"));
+        assert!(output.contains("120%"));
+
+        // Wide enough for sparklines, the usage is spelled out
+        state.show_id_column = false;
+        let output = render(&mut state, 150);
+        assert!(output.contains("1.2/1 CPU"));
+    }
+
+    #[test]
+    fn test_group_by_project() {
+        use crate::core::types::{AppEvent, ListRow};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let in_project = |id: &str, name: &str, project: Option<&str>| {
+            let mut container = create_test_container(id, name, "local", 1.0, 1.0, 0.0, 0.0);
+            container.compose_project = project.map(str::to_string);
+            container
+        };
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                in_project("aaa111111111", "standalone", None),
+                in_project("bbb222222222", "shop-web", Some("shop")),
+                in_project("ccc333333333", "shop-db", Some("shop")),
+            ],
+        ));
+
+        state.handle_event(AppEvent::ToggleGroupByProject);
+        assert!(state.group_by_project);
+        assert_eq!(state.list_rows.len(), 4);
+        assert!(matches!(state.list_rows[0], ListRow::Project(_)));
+        assert_eq!(state.selected_project().unwrap().name, "shop");
+
+        let backend = TestBackend::new(100, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("▾"));
+        assert!(output.contains("shop (2/2)"));
+
+        // Left on a header collapses the project, right expands it again
+        state.handle_event(AppEvent::ExitLogView);
+        assert_eq!(state.list_rows.len(), 2);
+        state.handle_event(AppEvent::ShowLogView);
+        assert_eq!(state.list_rows.len(), 4);
+
+        // Enter on a header opens the project's action menu
+        state.handle_event(AppEvent::EnterPressed);
+        assert!(matches!(state.view_state, ViewState::ProjectActionMenu(_)));
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        // Toggling back restores the plain list
+        state.handle_event(AppEvent::ToggleGroupByProject);
+        assert!(state.list_rows.is_empty());
+        assert_eq!(state.row_count(), 3);
+    }
+
+    #[test]
+    fn test_group_by_swarm_stack() {
+        use crate::core::types::{AppEvent, ProjectKind, STACK_NAMESPACE_LABEL};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let task = |id: &str, name: &str, stack: &str| {
+            let mut container = create_test_container(id, name, "local", 1.0, 1.0, 0.0, 0.0);
+            container
+                .labels
+                .insert(STACK_NAMESPACE_LABEL.to_string(), stack.to_string());
+            container
+        };
+        let mut compose_shop =
+            create_test_container("ccc333333333", "shop-db", "local", 1.0, 1.0, 0.0, 0.0);
+        compose_shop.compose_project = Some("shop".to_string());
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                task("aaa111111111", "shop_web.1.x1", "shop"),
+                task("bbb222222222", "shop_web.2.x2", "shop"),
+                compose_shop,
+            ],
+        ));
+
+        // A stack and a compose project of the same name are grouped apart
+        state.handle_event(AppEvent::ToggleGroupByProject);
+        assert_eq!(state.list_rows.len(), 5);
+        let project = state.selected_project().unwrap().clone();
+        assert_eq!(project.kind, ProjectKind::Compose);
+        state.handle_event(AppEvent::SelectNext);
+        state.handle_event(AppEvent::SelectNext);
+        let stack = state.selected_project().unwrap().clone();
+        assert_eq!(stack.kind, ProjectKind::Stack);
+        assert_eq!(state.project_containers(&stack).len(), 2);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("shop (1/1)"));
+        assert!(output.contains("shop (2/2) · stack"));
+
+        // Stacks collapse too, but have no project actions
+        state.handle_event(AppEvent::ExitLogView);
+        assert_eq!(state.list_rows.len(), 3);
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_project_plan() {
+        use crate::core::types::{AppEvent, ContainerAction, PlanStep};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let service = |id: &str, name: &str, depends_on: &[&str]| {
+            let mut container = create_test_container(id, name, "local", 1.0, 1.0, 0.0, 0.0);
+            container.compose_project = Some("shop".to_string());
+            container.compose_service = Some(name.trim_start_matches("shop-").to_string());
+            container.compose_depends_on = depends_on.iter().map(|s| s.to_string()).collect();
+            container
+        };
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                service("aaa111111111", "shop-web", &["api"]),
+                service("bbb222222222", "shop-api", &["db", "cache"]),
+                service("ccc333333333", "shop-worker", &["db"]),
+                service("ddd444444444", "shop-db", &[]),
+            ],
+        ));
+        state.handle_event(AppEvent::ToggleGroupByProject);
+
+        let key = |id: &str| test_key("local", id);
+        let project = state.selected_project().unwrap().clone();
+
+        // Dependents stop first; "cache" isn't part of the project, so it doesn't hold back api
+        assert_eq!(
+            state.project_plan(&project, ContainerAction::Stop),
+            vec![
+                PlanStep {
+                    action: ContainerAction::Stop,
+                    containers: vec![key("aaa111111111")],
+                },
+                PlanStep {
+                    action: ContainerAction::Stop,
+                    containers: vec![key("bbb222222222"), key("ccc333333333")],
+                },
+                PlanStep {
+                    action: ContainerAction::Stop,
+                    containers: vec![key("ddd444444444")],
+                },
+            ]
+        );
+        // Nothing to start, all are running
+        assert!(
+            state
+                .project_plan(&project, ContainerAction::Start)
+                .is_empty()
+        );
+        let restart = state.project_plan(&project, ContainerAction::Restart);
+        assert_eq!(restart.len(), 6);
+        assert_eq!(restart[3].action, ContainerAction::Start);
+        assert_eq!(restart[3].containers, vec![key("ddd444444444")]);
+
+        // Picking Stop in the project's menu shows the plan before running it
+        state.handle_event(AppEvent::EnterPressed);
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(
+            state.view_state,
+            ViewState::ProjectPlan(project.clone(), ContainerAction::Stop)
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Plan: Stop shop (local)"));
+        assert!(output.contains("1. Stop    shop-web"));
+        assert!(output.contains("2. Stop    shop-api, shop-worker"));
+        assert!(output.contains("3. Stop    shop-db"));
+
+        // Esc backs out without running anything
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_host_reconnecting() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let container =
+            |id: &str, name: &str| create_test_container(id, name, "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![
+                container("aaa111111111", "web"),
+                container("bbb222222222", "db"),
+            ],
+        ));
+        let web = test_key("local", "aaa111111111");
+        state.containers.get_mut(&web).unwrap().session_restarts = 2;
+
+        // The badge shows while the host is being retried
+        state.handle_event(AppEvent::HostReconnecting("local".to_string(), 3));
+        let backend = TestBackend::new(100, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("local: reconnecting (attempt 3)"));
+
+        // Back online: the fresh list replaces the stale one
+        state.handle_event(AppEvent::HostReconnected("local".to_string()));
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container("aaa111111111", "web")],
+        ));
+        assert!(state.reconnecting_hosts.is_empty());
+        assert_eq!(state.containers.len(), 1);
+        assert_eq!(state.sorted_container_keys, vec![web.clone()]);
+        assert_eq!(state.containers[&web].session_restarts, 2);
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("reconnecting"));
+    }
+
+    #[test]
+    fn test_shell_sessions_view() {
+        use crate::core::types::{AppEvent, RenderAction, ShellSessionInfo};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let session = |id: usize, name: &str| ShellSessionInfo {
+            id,
+            key: ContainerKey::new("local".to_string(), format!("{}00000000000", id)),
+            name: name.to_string(),
+            started_at: chrono::Utc::now(),
+        };
+        state.handle_event(AppEvent::ShellSessionStarted(session(1, "web")));
+        state.handle_event(AppEvent::ShellSessionStarted(session(2, "db")));
+
+        state.handle_event(AppEvent::ShowShellSessions);
+        assert_eq!(state.view_state, ViewState::ShellSessions);
+
+        let backend = TestBackend::new(80, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Shell sessions (2)"));
+        assert!(output.contains("web"));
+        assert!(output.contains("db"));
+
+        // Enter re-attaches to the selected session
+        state.handle_event(AppEvent::ScrollDown);
+        assert_eq!(
+            state.handle_event(AppEvent::EnterPressed),
+            RenderAction::AttachShell(2)
+        );
+
+        // A session whose shell exited leaves the list
+        state.handle_event(AppEvent::ShellSessionEnded(2));
+        let ids: Vec<usize> = state.shell_sessions.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![1]);
+        assert_eq!(state.shell_session_state.selected(), Some(0));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_notifications_panel() {
+        use crate::core::types::{AppEvent, ContainerAction};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+        state.handle_event(AppEvent::ConnectionError(
+            "remote".to_string(),
+            "connection refused".to_string(),
+        ));
+        // A repeat of the same error is not listed twice
+        state.handle_event(AppEvent::ConnectionError(
+            "remote".to_string(),
+            "connection refused".to_string(),
+        ));
+        state.handle_event(AppEvent::ActionError(
+            test_key("local", "abc123456789"),
+            ContainerAction::Restart,
+            "container is paused".to_string(),
+        ));
+        assert_eq!(state.unacknowledged_notifications(), 2);
+
+        // Still counted in the title after the banners are gone
+        state.connection_errors.clear();
+        let backend = TestBackend::new(120, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("2 unacknowledged (!)"));
+
+        state.handle_event(AppEvent::ShowNotifications);
+        assert_eq!(state.view_state, ViewState::Notifications);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Notifications (2, 2 unacknowledged)"));
+        assert!(output.contains("Restart failed: container is paused"));
+        assert!(output.contains("connection refused"));
+
+        // Enter acknowledges the newest and moves on to the next
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.unacknowledged_notifications(), 1);
+        assert_eq!(state.notification_state.selected(), Some(1));
+
+        // Only the acknowledged one is cleared
+        state.handle_event(AppEvent::ClearAcknowledgedNotifications);
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notifications[0].source, "remote");
+
+        state.handle_event(AppEvent::AcknowledgeAllNotifications);
+        assert_eq!(state.unacknowledged_notifications(), 0);
+        state.handle_event(AppEvent::ClearNotification);
+        assert!(state.notifications.is_empty());
+        assert_eq!(state.notification_state.selected(), None);
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+    }
+
+    #[test]
+    fn test_copy_menu() {
+        use crate::core::types::{AppEvent, CopyTarget};
+        use std::time::Instant;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web.clone()],
+        ));
+        assert_eq!(
+            CopyTarget::HostAndId.text(&web),
+            format!("local:{}", web.full_id)
+        );
+
+        state.handle_event(AppEvent::ShowCopyMenu);
+        assert_eq!(
+            state.view_state,
+            ViewState::CopyMenu(test_key("local", "abc123456789"))
+        );
+        state.handle_event(AppEvent::SelectActionDown);
+        assert_eq!(state.copy_menu_state.selected(), Some(1));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Copy (web)"));
+        assert!(output.contains(">  Name      web"));
+        assert!(output.contains("host:ID   local:abc123456789"));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        // What was copied is confirmed
+        state.copy_outcome = Some((Ok("local:abc123456789".to_string()), Instant::now()));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("✓ Copied: local:abc123456789"));
+    }
+
+    #[test]
+    fn test_exec_command_prompt() {
+        use crate::core::types::{AppEvent, ExecLine, ExecOutputState, ExecStatus};
+        use crate::docker::logs::LogEntry;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+        let key = test_key("local", "abc123456789");
+
+        // Shell, Exec command
+        state.handle_event(AppEvent::EnterPressed);
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.view_state, ViewState::ExecPrompt(key.clone()));
+
+        // Keys are text, 'q' doesn't quit
+        for c in "env | grep q".chars() {
+            state.handle_event(AppEvent::SearchKeyEvent(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )));
+            state.handle_event(AppEvent::Quit);
+        }
+        assert!(!state.should_quit);
+        assert_eq!(state.exec_input.value(), "env | grep q");
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Exec command…: web"));
+        assert!(output.contains("Command: env | grep q"));
+
+        // The output is shown in a popup over the container list
+        let mut exec_output = ExecOutputState::new(key.clone());
+        exec_output.popup = true;
+        state.exec_output = Some(exec_output);
+        state.view_state = ViewState::ExecOutput(key.clone());
+        state.handle_event(AppEvent::ExecStarted(
+            key.clone(),
+            "/bin/sh -c env | grep q".to_string(),
+        ));
+        state.handle_event(AppEvent::ExecOutput(
+            key.clone(),
+            ExecLine {
+                entry: LogEntry::from_message(chrono::Utc::now(), "QUEUE=jobs"),
+                is_stderr: false,
+            },
+        ));
+        state.handle_event(AppEvent::ExecFinished(
+            key.clone(),
+            ExecStatus::Exited(Some(0)),
+        ));
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        // Framed, with the container list still visible around it
+        assert!(output.contains("abc1234567│QUEUE=jobs"));
+        assert!(output.contains("[exit 0]"));
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+
+        // The command is kept to run again
+        state.handle_event(AppEvent::EnterPressed);
+        state.handle_event(AppEvent::SelectActionDown);
+        state.handle_event(AppEvent::EnterPressed);
+        assert_eq!(state.exec_input.value(), "env | grep q");
+    }
+
+    #[test]
+    fn test_exec_output_view() {
+        use crate::core::types::{AppEvent, ExecLine, ExecOutputState, ExecStatus};
+        use crate::docker::logs::LogEntry;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let key = test_key("local", "abc123456789");
+        state.exec_output = Some(ExecOutputState::new(key.clone()));
+        state.view_state = ViewState::ExecOutput(key.clone());
+
+        let line = |text: &str, is_stderr: bool| ExecLine {
+            entry: LogEntry::from_message(chrono::Utc::now(), text),
+            is_stderr,
+        };
+        state.handle_event(AppEvent::ExecStarted(
+            key.clone(),
+            "curl -f http://localhost/".to_string(),
+        ));
+        state.handle_event(AppEvent::ExecOutput(key.clone(), line("checking", false)));
+        state.handle_event(AppEvent::ExecOutput(
+            key.clone(),
+            line("connection refused", true),
+        ));
+        state.handle_event(AppEvent::ExecFinished(
+            key.clone(),
+            ExecStatus::Exited(Some(7)),
+        ));
+
+        let backend = TestBackend::new(80, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("Exec: curl -f http://localhost/"));
+        assert!(output.contains("[exit 7]"));
+        assert!(output.contains("checking"));
+        assert!(output.contains("connection refused"));
+
+        // Output for another container is ignored
+        let other = test_key("local", "def456789012");
+        state.handle_event(AppEvent::ExecOutput(other, line("stray", false)));
+        assert_eq!(state.exec_output.as_ref().unwrap().lines.len(), 2);
+
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::ContainerList);
+        assert!(state.exec_output.is_none());
+    }
+
+    #[test]
+    fn test_bulk_progress_banner() {
+        use crate::core::types::{AppEvent, BulkKind, BulkProgress};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        // Registered by hand: the test state has no host to run the items on
+        state.bulk_progress.insert(
+            7,
+            BulkProgress {
+                kind: BulkKind::Cleanup,
+                total: 4,
+                done: 0,
+                failed: 0,
+                finished_at: None,
+            },
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        state.handle_event(AppEvent::BulkItemDone(7, true));
+        state.handle_event(AppEvent::BulkItemDone(7, false));
+        let output = draw(&mut state);
+        assert!(output.contains("Cleanup: █████░░░░░ 1 done, 1 failed, 2 remaining"));
+        assert!(state.bulk_progress[&7].finished_at.is_none());
+
+        // Stopping keeps what was left, and the banner until it times out
+        state.handle_event(AppEvent::BulkStopped(7));
+        assert!(state.bulk_progress[&7].finished_at.is_some());
+        assert!(draw(&mut state).contains("1 done, 1 failed, 2 remaining"));
+
+        // Events for an unknown run are ignored
+        state.handle_event(AppEvent::BulkItemDone(8, true));
+        assert_eq!(state.bulk_progress.len(), 1);
+    }
+
+    #[test]
+    fn test_action_retries() {
+        use crate::core::types::{AppEvent, ContainerAction};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        state.action_retries = 3;
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+
+        let key = test_key("local", "abc123456789");
+        let mut terminal = Terminal::new(TestBackend::new(120, 12)).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        state.handle_event(AppEvent::ActionRetrying(
+            key.clone(),
+            ContainerAction::Restart,
+            1,
+            "daemon busy".to_string(),
+        ));
+        state.handle_event(AppEvent::ActionRetrying(
+            key.clone(),
+            ContainerAction::Restart,
+            2,
+            "daemon busy".to_string(),
+        ));
+        assert!(draw(&mut state).contains("web: Restart failed, retrying (2/3)"));
+
+        // The banner goes once the action ends, each retry stays in the panel
+        state.handle_event(AppEvent::ActionSuccess(key, ContainerAction::Restart));
+        assert!(state.retrying_actions.is_empty());
+        state.handle_event(AppEvent::ShowNotifications);
+        let output = draw(&mut state);
+        assert!(!output.contains("(2/3)"));
+        assert!(output.contains("Restart failed, retrying #1: daemon busy"));
+        assert!(output.contains("Restart failed, retrying #2: daemon busy"));
+    }
+
+    #[test]
+    fn test_error_detail_popup() {
+        use crate::core::types::{AppEvent, ContainerAction};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let web = create_test_container("abc123456789", "web", "local", 1.0, 1.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![web],
+        ));
+        state.handle_event(AppEvent::ActionError(
+            test_key("local", "abc123456789"),
+            ContainerAction::Restart,
+            "Failed to restart container: Docker responded with status code 500: \
+             driver failed programming external connectivity on endpoint web"
+                .to_string(),
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        state.handle_event(AppEvent::ShowNotifications);
+        state.handle_event(AppEvent::ShowErrorDetail);
+        assert_eq!(state.view_state, ViewState::ErrorDetail);
+        assert_eq!(state.unacknowledged_notifications(), 0);
+
+        // One cause per line, none of it cut short
+        let output = draw(&mut state);
+        assert!(output.contains("Error: web"));
+        assert!(output.contains("Restart failed"));
+        let shown = |output: &str| output.matches("Failed to restart container").count();
+        assert_eq!(shown(&output), 2); // In the panel too
+        assert!(output.contains("└ Docker responded with status code 500"));
+        assert!(
+            output.contains("  └ driver failed programming external connectivity on endpoint web")
+        );
+        assert!(output.contains("y: Copy"));
+
+        state.handle_event(AppEvent::ScrollDown);
+        assert_eq!(state.error_detail.as_ref().unwrap().scroll_offset, 1);
+        assert_eq!(shown(&draw(&mut state)), 1);
+
+        // Esc goes back to the panel
+        state.handle_event(AppEvent::CancelActionMenu);
+        assert_eq!(state.view_state, ViewState::Notifications);
+        assert!(state.error_detail.is_none());
+    }
+
+    #[test]
+    fn test_loop_metrics_indicator() {
+        use crate::core::loop_metrics::LoopStats;
+        use crate::core::types::AppEvent;
+        use std::time::Duration;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        assert!(!draw(&mut state).contains("ev/s"));
+
+        state.handle_event(AppEvent::ToggleLoopMetrics);
+        state.loop_metrics.stats = LoopStats {
+            events_per_sec: 42.4,
+            fps: 4.0,
+            render_time: Duration::from_micros(3100),
+            lag: Duration::from_micros(400),
+            channel_depth: 2,
+        };
+        let output = draw(&mut state);
+        let last_line = output.lines().last().unwrap();
+        assert!(last_line.ends_with("42 ev/s · 4 fps · render 3.1ms · lag 0.4ms · queue 2 "));
+    }
+
+    #[test]
+    fn test_host_info_capacity() {
+        use crate::core::types::{AppEvent, HostInfo, RenderAction};
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = true;
+        let styles = UiStyles::default();
+
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![create_test_container(
+                "abc123456789",
+                "api",
+                "local",
+                12.5,
+                1.0,
+                0.0,
+                0.0,
+            )],
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 12)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("CPUs"));
+
+        const GIB: u64 = 1024 * 1024 * 1024;
+        let info = HostInfo {
+            cpus: 8,
+            memory_bytes: 16 * GIB,
+            load1: Some(0.42),
+        };
+        assert_eq!(
+            state.handle_event(AppEvent::HostInfo("local".to_string(), info.clone())),
+            RenderAction::Render
+        );
+        // The same numbers again don't redraw
+        assert_eq!(
+            state.handle_event(AppEvent::HostInfo("local".to_string(), info)),
+            RenderAction::None
+        );
+
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("CPU 12.5% (8 CPUs) Memory"));
+        assert!(output.contains("/16 G load 0.42"));
+    }
+
+    #[test]
+    fn test_cpu_normalized_to_host() {
+        use crate::core::types::{AppEvent, HostInfo};
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = false;
+        let styles = UiStyles::default();
+
+        let mut busy =
+            create_test_container("abc123456789", "busy", "local", 200.0, 10.0, 0.0, 0.0);
+        busy.stats.online_cpus = 8;
+        // Stats without a CPU count fall back to the host's
+        let idle = create_test_container("def123456789", "idle", "local", 40.0, 10.0, 0.0, 0.0);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![busy, idle],
+        ));
+        state.handle_event(AppEvent::HostInfo(
+            "local".to_string(),
+            HostInfo {
+                cpus: 4,
+                memory_bytes: 0,
+                load1: None,
+            },
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("200.0%"));
+        assert!(output.contains(" 40.0%"));
+
+        state.handle_event(AppEvent::ToggleCpuNormalized);
+        assert!(state.cpu_normalized);
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("CPU % host"));
+        assert!(output.contains(" 25.0%"));
+        assert!(output.contains(" 10.0%"));
+        assert!(!output.contains("200.0%"));
+    }
+
+    #[tokio::test]
+    async fn test_cpu_normalized_persists_across_config_reload() {
+        use crate::cli::config::Config;
+        use crate::core::types::AppEvent;
+        use std::time::Duration;
+
+        let path =
+            std::env::temp_dir().join(format!("dtop-cpu-normalized-{}.yaml", std::process::id()));
+        std::fs::write(&path, "# Mine\ntheme: colorblind # easier to read\n").unwrap();
+
+        // The file is written in the background
+        async fn reload(path: &std::path::Path, expected: bool) -> Config {
+            for _ in 0..100 {
+                let config = Config::load_from(path).unwrap();
+                if config.cpu_normalized == Some(expected) {
+                    return config;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            panic!("cpu_normalized was not saved as {}", expected);
+        }
+
+        let mut state = create_test_app_state();
+        state.config_path = Some(path.clone());
+        state.handle_event(AppEvent::ToggleCpuNormalized);
+        let config = reload(&path, true).await;
+        assert_eq!(config.theme.as_deref(), Some("colorblind"));
+
+        state.handle_event(AppEvent::ToggleCpuNormalized);
+        reload(&path, false).await;
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "# Mine\ntheme: colorblind # easier to read\ncpu_normalized: false\n"
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_frame_skipped_when_nothing_changed() {
+        use crate::core::types::{AppEvent, Damage};
+        use std::time::Duration;
+
+        let mut state = create_test_app_state();
+        let container = create_test_container("abc123456789", "web", "local", 12.0, 30.0, 0.0, 0.0);
+        let key = container.key();
+        let stats = container.stats.clone();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+        assert!(state.frame_due(Duration::from_millis(500)));
+
+        // Drawn; the same stats again change nothing shown (no new sparkline sample while
+        // the history bucket stays the same)
+        state.damage = Damage::None;
+        state
+            .containers
+            .get_mut(&key)
+            .unwrap()
+            .stats
+            .last_history_bucket = u64::MAX;
+        state.handle_event(AppEvent::ContainerStat(key.clone(), stats.clone()));
+        assert_eq!(state.damage, Damage::None);
+        assert!(!state.frame_due(Duration::from_millis(500)));
+        // Clock-driven content still moves now and then
+        assert!(state.frame_due(Duration::from_secs(2)));
+
+        // A visible change is drawn with the list on screen only
+        let mut busier = stats;
+        busier.cpu = 50.0;
+        state.handle_event(AppEvent::ContainerStat(key.clone(), busier.clone()));
+        assert_eq!(state.damage, Damage::Stats);
+        assert!(state.frame_due(Duration::from_millis(500)));
+
+        state.handle_event(AppEvent::ShowTimeline);
+        state.damage = Damage::None;
+        busier.cpu = 80.0;
+        state.handle_event(AppEvent::ContainerStat(key, busier));
+        assert_eq!(state.damage, Damage::Stats);
+        assert!(!state.frame_due(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_memory_display_modes() {
+        use crate::core::types::{AppEvent, MemoryDisplay};
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = false;
+        let styles = UiStyles::default();
+
+        let mut container =
+            create_test_container("abc123456789", "web", "local", 5.0, 25.0, 0.0, 0.0);
+        container.stats.memory_used_bytes = 512 * 1024 * 1024;
+        container.stats.memory_limit_bytes = 2 * 1024 * 1024 * 1024;
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+
+        // Too narrow for sparklines
+        let mut terminal = Terminal::new(TestBackend::new(100, 6)).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+        assert!(draw(&mut state).contains(" 25.0%"));
+
+        state.handle_event(AppEvent::CycleMemoryDisplay);
+        assert_eq!(state.memory_display, MemoryDisplay::Used);
+        let output = draw(&mut state);
+        assert!(output.contains("512 M"));
+        assert!(!output.contains("25.0%"));
+
+        state.handle_event(AppEvent::CycleMemoryDisplay);
+        assert!(draw(&mut state).contains("512 M/2 G"));
+
+        state.handle_event(AppEvent::CycleMemoryDisplay);
+        assert_eq!(state.memory_display, MemoryDisplay::Percent);
+    }
+
+    #[test]
+    fn test_low_bandwidth_drops_sparklines() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        state.show_host_summary = false;
+        let styles = UiStyles::default();
+
+        let mut container =
+            create_test_container("abc123456789", "web", "local", 5.0, 25.0, 0.0, 0.0);
+        container.stats.cpu_history.extend([10.0, 20.0]);
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            vec![container],
+        ));
+
+        // Wide enough for sparklines
+        let mut terminal = Terminal::new(TestBackend::new(160, 6)).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(output.contains("│"));
+
+        state.low_bandwidth = true;
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        let output = buffer_to_string(terminal.backend().buffer());
+        assert!(!output.contains("│"));
+        assert!(output.contains("  5.0%"));
+    }
+
+    #[test]
+    fn test_refresh_adapts_to_focus_and_input() {
+        use crate::core::types::{AppEvent, RenderAction};
+        use crossterm::event::{KeyCode, KeyEvent};
+        use std::time::{Duration, Instant};
+
+        let mut state = create_test_app_state();
+        assert_eq!(state.frame_interval(), Duration::from_millis(500));
+        assert!(!state.stats_throttled());
+
+        // Out of focus, both slow down until focus comes back, which redraws at once
+        assert_eq!(
+            state.handle_event(AppEvent::FocusChanged(false)),
+            RenderAction::None
+        );
+        assert_eq!(state.frame_interval(), Duration::from_secs(5));
+        assert!(state.stats_throttled());
+        assert_eq!(
+            state.handle_event(AppEvent::FocusChanged(true)),
+            RenderAction::Render
+        );
+        assert_eq!(state.frame_interval(), Duration::from_millis(500));
+
+        // Hundreds of containers slow down between interactions only
+        let containers = (0..200)
+            .map(|i| {
+                create_test_container(&format!("{:012}", i), "web", "local", 1.0, 1.0, 0.0, 0.0)
+            })
+            .collect();
+        state.handle_event(AppEvent::InitialContainerList(
+            "local".to_string(),
+            containers,
+        ));
+        state.last_input = Instant::now() - Duration::from_secs(60);
+        assert_eq!(state.frame_interval(), Duration::from_secs(2));
+        assert!(state.stats_throttled());
+
+        state.handle_event(AppEvent::SearchKeyEvent(KeyEvent::from(KeyCode::Down)));
+        assert_eq!(state.frame_interval(), Duration::from_millis(500));
+        assert!(!state.stats_throttled());
+    }
+
+    #[test]
+    fn test_filter_matches_highlighted_in_rows() {
+        use ratatui::style::Modifier;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        for container in [
+            create_test_container("abc123456789", "nginx-proxy", "local", 5.0, 10.0, 0.0, 0.0),
+            create_test_container("def987654321", "api-nginx", "local", 5.0, 10.0, 0.0, 0.0),
+        ] {
+            let key = container.key();
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+        state.search_input = tui_input::Input::new("NGINX".to_string());
+
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+
+        // Only the matched part of each name stands out
+        let highlighted: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .filter(|cell| cell.modifier.contains(Modifier::UNDERLINED))
+            .map(|cell| cell.symbol())
+            .collect();
+        assert_eq!(highlighted, "nginxnginx");
+    }
+
+    #[test]
+    fn test_frame_interval_follows_refresh() {
+        use crate::core::types::AppEvent;
+        use std::time::Duration;
+
+        let mut state = create_test_app_state();
+        state.refresh_interval = Duration::from_secs(1);
+        assert_eq!(state.frame_interval(), Duration::from_secs(1));
+        state.low_bandwidth = true;
+        assert_eq!(state.frame_interval(), Duration::from_secs(2));
+
+        // The slower intervals never refresh faster than asked
+        state.refresh_interval = Duration::from_secs(8);
+        assert_eq!(state.frame_interval(), Duration::from_secs(8));
+        state.handle_event(AppEvent::FocusChanged(false));
+        assert_eq!(state.frame_interval(), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn test_container_list_paging_and_position() {
+        use crate::core::types::AppEvent;
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        for i in 0..50 {
+            let container = create_test_container(
+                &format!("{:012}", i),
+                &format!("web-{:02}", i),
+                "local",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            );
+            let key = container.key();
+            state.containers.insert(key.clone(), container);
+            state.sorted_container_keys.push(key);
+        }
+        state.table_state.select(Some(0));
+
+        let backend = TestBackend::new(120, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        };
+
+        let output = draw(&mut state);
+        let page = state.list_viewport_height;
+        assert!(page > 0 && page < 50);
+        assert!(output.contains(&format!("rows 1–{} of 50", page)));
+
+        // A page down moves the selection by the rows on screen, scrolling the list
+        state.handle_event(AppEvent::ScrollPageDown);
+        assert_eq!(state.table_state.selected(), Some(page));
+        let output = draw(&mut state);
+        assert!(output.contains(&format!("rows 2–{} of 50", page + 1)));
+
+        state.handle_event(AppEvent::ScrollToBottom);
+        assert_eq!(state.table_state.selected(), Some(49));
+        let output = draw(&mut state);
+        assert!(output.contains(&format!("rows {}–50 of 50", 51 - page)));
+
+        state.handle_event(AppEvent::ScrollPageDown);
+        assert_eq!(state.table_state.selected(), Some(49));
+        state.handle_event(AppEvent::ScrollPageUp);
+        assert_eq!(state.table_state.selected(), Some(49 - page));
+        state.handle_event(AppEvent::ScrollToTop);
+        assert_eq!(state.table_state.selected(), Some(0));
+
+        // No position while everything fits
+        let backend = TestBackend::new(120, 60);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| render_ui(f, &mut state, &styles))
+            .unwrap();
+        assert!(!buffer_to_string(terminal.backend().buffer()).contains("rows"));
+    }
+
+    #[test]
+    fn test_selection_kept_across_resorts_and_show_all() {
+        use crate::core::types::{AppEvent, ContainerState, SortState};
+
+        let mut state = create_test_app_state();
+        let styles = UiStyles::default();
+        for i in 0..50 {
+            let mut container = create_test_container(
+                &format!("{:012}", i),
+                &format!("web-{:02}", i),
+                "local",
+                1.0,
+                1.0,
+                0.0,
+                0.0,
+            );
+            // Every fifth container is stopped
+            if i % 5 == 0 {
+                container.state = ContainerState::Exited;
+            }
+            state.containers.insert(container.key(), container);
+        }
+        state.show_all_containers = true;
+        state.sort_state = SortState::new(SortField::Name);
+        state.force_sort_containers();
+        state.table_state.select(Some(0));
+
+        let backend = TestBackend::new(120, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut draw = |state: &mut AppState| {
+            terminal.draw(|f| render_ui(f, state, &styles)).unwrap();
+        };
+        draw(&mut state);
+
+        // A stopped container far down the list, a few lines from the top of the screen
+        state.handle_event(AppEvent::ScrollToBottom);
+        draw(&mut state);
+        for _ in 0..4 {
+            state.handle_event(AppEvent::SelectPrevious);
+        }
+        draw(&mut state);
+        let key = state.selected_container_key().cloned().unwrap();
+        assert_eq!(key, test_key("local", "000000000045"));
+        let screen_row = state.table_state.selected().unwrap() - state.table_state.offset();
+
+        // Hiding stopped containers moves the selection, showing them brings it back
+        state.handle_event(AppEvent::ToggleShowAll);
+        assert_ne!(state.selected_container_key(), Some(&key));
+        state.handle_event(AppEvent::ToggleShowAll);
+        assert_eq!(state.selected_container_key(), Some(&key));
+        draw(&mut state);
+        assert_eq!(
+            state.table_state.selected().unwrap() - state.table_state.offset(),
+            screen_row
+        );
+
+        // A re-sort keeps the container selected, on the same line of the screen
+        state.handle_event(AppEvent::SetSortField(SortField::Name));
+        assert_eq!(state.selected_container_key(), Some(&key));
+        assert_eq!(state.table_state.selected(), Some(4));
+        assert_eq!(state.table_state.offset(), 0);
+
+        // Moving the selection while a container is hidden forgets it
+        state.handle_event(AppEvent::ToggleShowAll);
+        state.handle_event(AppEvent::ScrollToTop);
+        state.handle_event(AppEvent::ToggleShowAll);
+        assert_eq!(
+            state.selected_container_key(),
+            Some(&test_key("local", "000000000049"))
+        );
+    }
 }
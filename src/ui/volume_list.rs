@@ -0,0 +1,192 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+};
+
+use crate::core::app_state::AppState;
+use crate::core::types::{VolumeInfo, VolumeListState};
+use crate::ui::formatters::{format_bytes, truncate_with_ellipsis};
+use crate::ui::i18n;
+use crate::ui::render::UiStyles;
+
+/// Maximum number of volume names listed in the remove confirmation
+const MAX_CONFIRM_NAMES: usize = 8;
+
+/// Renders the volumes of all hosts with the orphaned ones highlighted
+pub fn render_volume_list(f: &mut Frame, area: Rect, state: &mut AppState, styles: &UiStyles) {
+    let Some(volume_list) = &mut state.volume_list else {
+        return;
+    };
+
+    let msg = i18n::messages();
+
+    let loading = if volume_list.loading.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", msg.loading)
+    };
+    let block = Block::default()
+        .title(format!(
+            "{} ({}, {}){} - {}",
+            msg.volumes,
+            volume_list.volumes.len(),
+            orphan_summary(volume_list),
+            loading,
+            msg.volumes_footer
+        ))
+        .style(styles.border);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Errors above the table
+    let [errors_area, table_area] = Layout::vertical([
+        Constraint::Length(volume_list.errors.len() as u16),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+    let errors: Vec<Line> = volume_list
+        .errors
+        .iter()
+        .map(|error| Line::styled(error.as_str(), styles.high))
+        .collect();
+    f.render_widget(Paragraph::new(errors), errors_area);
+
+    let show_host_column = state.connected_hosts.len() > 1;
+
+    let mut header = Vec::new();
+    let mut widths = Vec::new();
+    if show_host_column {
+        header.push(Cell::from(msg.header_host));
+        widths.push(Constraint::Length(15));
+    }
+    header.extend([
+        Cell::from(msg.header_name),
+        Cell::from(msg.header_driver),
+        Cell::from(msg.header_mountpoint),
+        Cell::from(Line::from(msg.header_size).right_aligned()),
+        Cell::from(Line::from(msg.header_used_by).right_aligned()),
+    ]);
+    widths.extend([
+        Constraint::Min(20),
+        Constraint::Length(10),
+        Constraint::Min(20),
+        Constraint::Length(9),
+        Constraint::Length(14),
+    ]);
+
+    let rows = volume_list
+        .volumes
+        .iter()
+        .map(|volume| volume_row(volume, show_host_column, styles));
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).style(styles.header))
+        .row_highlight_style(styles.selected);
+    f.render_stateful_widget(table, table_area, &mut volume_list.table_state);
+
+    if volume_list.confirm_remove {
+        render_remove_confirmation(f, volume_list, styles);
+    }
+}
+
+/// Builds the row for a single volume
+fn volume_row(volume: &VolumeInfo, show_host_column: bool, styles: &UiStyles) -> Row<'static> {
+    let msg = i18n::messages();
+
+    let size = volume
+        .size
+        .map_or_else(|| "-".to_string(), |size| format_bytes(size as u64));
+    let (used_by, used_by_style) = if volume.is_orphaned() {
+        (msg.orphaned.to_string(), styles.medium)
+    } else {
+        (volume.containers.to_string(), Style::default())
+    };
+
+    let mut cells = Vec::new();
+    if show_host_column {
+        cells.push(Cell::from(volume.host_id.clone()));
+    }
+    cells.extend([
+        Cell::from(volume.name.clone()),
+        Cell::from(volume.driver.clone()),
+        Cell::from(volume.mountpoint.clone()).style(styles.container_id),
+        Cell::from(Line::from(size).right_aligned()),
+        Cell::from(Line::from(used_by).right_aligned()).style(used_by_style),
+    ]);
+    Row::new(cells)
+}
+
+/// Summary of all orphaned volumes, for the title
+fn orphan_summary(volume_list: &VolumeListState) -> String {
+    removal_summary(volume_list.orphans().count(), volume_list.reclaimable())
+}
+
+/// "3 orphaned, ~1.2G reclaimable"
+fn removal_summary(count: usize, reclaimable: i64) -> String {
+    let msg = i18n::messages();
+    format!(
+        "{} {}, ~{} {}",
+        count,
+        msg.orphaned,
+        format_bytes(reclaimable as u64),
+        msg.reclaimable
+    )
+}
+
+/// Renders the centered confirmation listing the volumes about to be removed
+fn render_remove_confirmation(f: &mut Frame, volume_list: &VolumeListState, styles: &UiStyles) {
+    let msg = i18n::messages();
+    let area = f.area();
+
+    let orphans = volume_list.removal_candidates();
+    let reclaimable = orphans.iter().filter_map(|volume| volume.size).sum();
+    let mut lines: Vec<Line> = orphans
+        .iter()
+        .take(MAX_CONFIRM_NAMES)
+        .map(|volume| Line::raw(format!(" {}", truncate_with_ellipsis(&volume.name, 40))))
+        .collect();
+    if orphans.len() > MAX_CONFIRM_NAMES {
+        lines.push(Line::raw(" …"));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::styled(
+        format!(" {}", removal_summary(orphans.len(), reclaimable)),
+        styles.medium,
+    ));
+
+    // Borders + lines + blank line + footer
+    let popup_height = (lines.len() as u16 + 4).min(area.height.saturating_sub(2));
+    let popup_width = 50u16.min(area.width.saturating_sub(4));
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the background area first to prevent bleed-through
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(msg.remove_orphans_title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_style(styles.header)
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+    f.render_widget(Paragraph::new(lines), inner);
+
+    // Render footer with keybindings
+    let footer_area = Rect::new(
+        popup_area.x + 2,
+        popup_area.y + popup_area.height.saturating_sub(2),
+        popup_area.width.saturating_sub(4),
+        1,
+    );
+    let footer = Paragraph::new(msg.remove_orphans_footer)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+}